@@ -0,0 +1,27 @@
+// Palette-indexed sprite layers (dyed hair / clothes): look the frame's 8-bit
+// palette index up in a 256x1 palette texture. Both textures are read with
+// `textureLoad`, so indices are never filtered into neighbouring entries.
+#import bevy_pbr::forward_io::VertexOutput
+
+struct PaletteSprite {
+    uv_rect: vec4<f32>, // xy = frame min, zw = frame size, in atlas UVs
+    tint: vec4<f32>,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> sprite: PaletteSprite;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var index_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(2) var palette_texture: texture_2d<f32>;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+#ifdef VERTEX_UVS_A
+    let uv = sprite.uv_rect.xy + in.uv * sprite.uv_rect.zw;
+#else
+    let uv = sprite.uv_rect.xy;
+#endif
+    let size = textureDimensions(index_texture);
+    let texel = min(vec2<u32>(uv * vec2<f32>(size)), size - vec2<u32>(1u));
+    // R8Unorm: the stored byte comes back as index / 255.
+    let index = u32(round(textureLoad(index_texture, texel, 0).r * 255.0));
+    return textureLoad(palette_texture, vec2<u32>(index, 0u), 0) * sprite.tint;
+}
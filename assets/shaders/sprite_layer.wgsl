@@ -0,0 +1,55 @@
+// Shared sprite-layer material: one material per atlas page, the frame picked
+// per instance. Each layer's `MeshTag` is its SPR frame index into `regions`,
+// so every layer showing a frame from the same page draws with one bind group
+// and batches with the others.
+#import bevy_pbr::{
+    mesh_functions,
+    forward_io::{Vertex, VertexOutput},
+    view_transformations::position_world_to_clip,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> tint: vec4<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var page_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(2) var page_sampler: sampler;
+// One rect per SPR frame: xy = frame min, zw = frame size, in atlas UVs.
+@group(#{MATERIAL_BIND_GROUP}) @binding(3) var<storage, read> regions: array<vec4<f32>>;
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    out.world_position = mesh_functions::mesh_position_local_to_world(
+        world_from_local,
+        vec4<f32>(vertex.position, 1.0),
+    );
+    out.position = position_world_to_clip(out.world_position.xyz);
+
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(
+        vertex.normal,
+        vertex.instance_index,
+    );
+#endif
+
+#ifdef VERTEX_UVS_A
+    let frame = min(mesh_functions::get_tag(vertex.instance_index), arrayLength(&regions) - 1u);
+    let region = regions[frame];
+    out.uv = region.xy + vertex.uv * region.zw;
+#endif
+
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+#ifdef VERTEX_UVS_A
+    return textureSample(page_texture, page_sampler, in.uv) * tint;
+#else
+    return tint;
+#endif
+}
@@ -0,0 +1,34 @@
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{alpha_discard, apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+
+struct TerrainLighting {
+    params: vec4<f32>, // x = tile color strength (0 = off, 1 = full GND color)
+};
+
+// StandardMaterial owns bindings 0-99; extension bindings start at 100.
+@group(#{MATERIAL_BIND_GROUP}) @binding(100)
+var<uniform> terrain: TerrainLighting;
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var lit = in;
+#ifdef VERTEX_COLORS
+    // The GND tile color rides the vertex color; fade it toward white so the
+    // texture shows untinted when baked tile lighting is off.
+    lit.color = mix(vec4<f32>(1.0), in.color, terrain.params.x);
+#endif
+
+    var pbr_input = pbr_input_from_standard_material(lit, is_front);
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+    out.color = apply_pbr_lighting(pbr_input);
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+    return out;
+}
@@ -13,8 +13,9 @@ use bevy_auto_plugin::prelude::*;
 /// Registered observer:
 /// - name_request_observer
 ///
-/// Registered system:
+/// Registered systems:
 /// - name_response_handler_system
+/// - forget_unregistered_hover
 #[derive(AutoPlugin)]
 #[auto_plugin(impl_plugin_trait)]
 pub struct EntityHoverDomainPlugin;
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use bevy::app::{PluginGroupBuilder, ScheduleRunnerPlugin};
+use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::settings::{RenderCreation, WgpuSettings};
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+
+/// Tick rate of a headless app; matches a 60 Hz display.
+const HEADLESS_TICK: Duration = Duration::from_nanos(16_666_667);
+
+/// Present in apps built with [`HeadlessPlugins`].
+#[derive(Resource, Debug, Default)]
+pub struct Headless;
+
+/// `DefaultPlugins` without a window, a winit event loop or a GPU device, for
+/// bots and scripted tests that drive the full login → zone flow.
+///
+/// Assets, time, input and every asset type the domain plugins touch stay
+/// registered, so `MapPlugin`/`CoreGamePlugins` and the network adapter can be
+/// added on top unchanged. Without a GPU there is no `RenderApp`: material
+/// plugins skip their render setup and plugins that need the device (the
+/// particle renderer) are left out by their owners. The screen-space UI is the
+/// caller's to leave out.
+pub struct HeadlessPlugins;
+
+impl PluginGroup for HeadlessPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        DefaultPlugins
+            .build()
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: RenderCreation::Automatic(Box::new(WgpuSettings {
+                    backends: None,
+                    ..default()
+                })),
+                ..default()
+            })
+            .disable::<WinitPlugin>()
+            .add(ScheduleRunnerPlugin::run_loop(HEADLESS_TICK))
+            .add(HeadlessMarkerPlugin)
+    }
+}
+
+struct HeadlessMarkerPlugin;
+
+impl Plugin for HeadlessMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Headless>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::RenderApp;
+
+    #[test]
+    fn headless_apps_have_no_window_or_render_world() {
+        let mut app = App::new();
+        app.add_plugins(HeadlessPlugins);
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        assert!(app.get_sub_app(RenderApp).is_none());
+        assert!(app.world().contains_resource::<Headless>());
+        let windows = app.world_mut().query::<&Window>().iter(app.world()).count();
+        assert_eq!(windows, 0);
+    }
+
+    #[test]
+    fn the_game_plugins_run_headless() {
+        let mut app = App::new();
+        app.add_plugins((HeadlessPlugins, crate::MapPlugin, crate::CoreGamePlugins));
+        app.finish();
+        app.cleanup();
+        for _ in 0..3 {
+            app.update();
+        }
+    }
+}
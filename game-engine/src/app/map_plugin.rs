@@ -1,6 +1,8 @@
 use crate::{
     app::map_domain_plugin::MapDomainPlugin,
-    presentation::rendering::{lighting::EnhancedLightingPlugin, water::WaterMaterial},
+    presentation::rendering::{
+        lighting::EnhancedLightingPlugin, terrain_material::TerrainMaterial, water::WaterMaterial,
+    },
 };
 use bevy::prelude::*;
 
@@ -15,6 +17,7 @@ pub struct MapPlugin;
 impl Plugin for MapPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            MaterialPlugin::<TerrainMaterial>::default(),
             MaterialPlugin::<WaterMaterial>::default(),
             EnhancedLightingPlugin,
             MapDomainPlugin,
@@ -4,6 +4,7 @@ pub mod character_domain_plugin;
 pub mod combat_plugin;
 pub mod entity_hover_plugin;
 pub mod entity_spawning_plugin;
+pub mod headless;
 pub mod input_plugin;
 pub mod map_domain_plugin;
 pub mod map_plugin;
@@ -19,6 +20,7 @@ pub use character_domain_plugin::CharacterDomainAutoPlugin;
 pub use combat_plugin::CombatDomainPlugin;
 pub use entity_hover_plugin::EntityHoverDomainPlugin;
 pub use entity_spawning_plugin::EntitySpawningDomainPlugin;
+pub use headless::{Headless, HeadlessPlugins};
 pub use input_plugin::InputPlugin;
 pub use map_domain_plugin::MapDomainPlugin;
 pub use map_plugin::MapPlugin;
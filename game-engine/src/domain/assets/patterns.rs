@@ -1,4 +1,7 @@
 use crate::domain::entities::character::components::Gender;
+use crate::infrastructure::encoding::names::{
+    ACCESSORY, BODY, DORAM, FEMALE, HEAD, HUMAN, MALE, MONSTER, SHIELD, USER_INTERFACE,
+};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -31,8 +34,8 @@ pub fn normalize_path(path: &str) -> String {
 /// Helper to convert Korean gender strings to enum
 pub fn parse_gender(s: &str) -> Option<Gender> {
     match s {
-        "남" => Some(Gender::Male),
-        "여" => Some(Gender::Female),
+        MALE => Some(Gender::Male),
+        FEMALE => Some(Gender::Female),
         _ => None,
     }
 }
@@ -40,11 +43,11 @@ pub fn parse_gender(s: &str) -> Option<Gender> {
 /// Generate hair sprite path (for consistency with parsing)
 pub fn hair_sprite_path(gender: Gender, style_id: u16) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/인간족/머리통/{}/{}_{}.spr",
+        "ro://data/sprite/{HUMAN}/{HEAD}/{}/{}_{}.spr",
         sex, style_id, sex
     )
 }
@@ -52,8 +55,8 @@ pub fn hair_sprite_path(gender: Gender, style_id: u16) -> String {
 /// Generate hair palette path
 pub fn hair_palette_path(style_id: u16, gender: Gender, color_id: u16) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
         "ro://data/palette/머리/{}_{}_{}.pal",
@@ -61,26 +64,59 @@ pub fn hair_palette_path(style_id: u16, gender: Gender, color_id: u16) -> String
     )
 }
 
+/// Generate body (clothes dye) palette path
+pub fn body_palette_path(gender: Gender, job_name: &str, color_id: u16) -> String {
+    let sex = match gender {
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
+    };
+    format!("ro://data/palette/몸/{}_{}_{}.pal", job_name, sex, color_id)
+}
+
 /// Generate body sprite path
 pub fn body_sprite_path(gender: Gender, job_name: &str) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
+    };
+    format!(
+        "ro://data/sprite/{HUMAN}/{BODY}/{}/{}_{}.spr",
+        sex, job_name, sex
+    )
+}
+
+/// Generate Doram body sprite path
+pub fn doram_body_sprite_path(gender: Gender, job_name: &str) -> String {
+    let sex = match gender {
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/인간족/몸통/{}/{}_{}.spr",
+        "ro://data/sprite/{DORAM}/{BODY}/{}/{}_{}.spr",
         sex, job_name, sex
     )
 }
 
+/// Generate alternate outfit (costume) body sprite path
+pub fn costume_body_sprite_path(gender: Gender, job_name: &str, outfit: u16) -> String {
+    let sex = match gender {
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
+    };
+    format!(
+        "ro://data/sprite/{HUMAN}/{BODY}/{}/costume_{}/{}_{}_{}.spr",
+        sex, outfit, job_name, sex, outfit
+    )
+}
+
 /// Generate head sprite path
 pub fn head_sprite_path(gender: Gender, style_id: u16) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/인간족/머리통/{}/{}_{}.spr",
+        "ro://data/sprite/{HUMAN}/{HEAD}/{}/{}_{}.spr",
         sex, style_id, sex
     )
 }
@@ -88,23 +124,29 @@ pub fn head_sprite_path(gender: Gender, style_id: u16) -> String {
 /// Generate head action path
 pub fn head_action_path(gender: Gender, style_id: u16) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/인간족/머리통/{}/{}_{}.act",
+        "ro://data/sprite/{HUMAN}/{HEAD}/{}/{}_{}.act",
         sex, style_id, sex
     )
 }
 
 /// Generate mob sprite path
 pub fn mob_sprite_path(sprite_name: &str) -> String {
-    format!("ro://data/sprite/몬스터/{}.spr", sprite_name.to_lowercase())
+    format!(
+        "ro://data/sprite/{MONSTER}/{}.spr",
+        sprite_name.to_lowercase()
+    )
 }
 
 /// Generate mob action path
 pub fn mob_action_path(sprite_name: &str) -> String {
-    format!("ro://data/sprite/몬스터/{}.act", sprite_name.to_lowercase())
+    format!(
+        "ro://data/sprite/{MONSTER}/{}.act",
+        sprite_name.to_lowercase()
+    )
 }
 
 /// Generate NPC sprite path
@@ -119,17 +161,27 @@ pub fn npc_action_path(sprite_name: &str) -> String {
 
 /// Generate the inventory icon BMP path for an item resource name.
 pub fn item_icon_path(resource_name: &str) -> String {
-    format!("ro://data/texture/유저인터페이스/item/{resource_name}.bmp")
+    format!("ro://data/texture/{USER_INTERFACE}/item/{resource_name}.bmp")
 }
 
 /// Generate the item-details illustration BMP path for an item resource name.
 pub fn item_collection_path(resource_name: &str) -> String {
-    format!("ro://data/texture/유저인터페이스/collection/{resource_name}.bmp")
+    format!("ro://data/texture/{USER_INTERFACE}/collection/{resource_name}.bmp")
 }
 
 /// Generate the minimap BMP path for a map name.
 pub fn minimap_path(map_name: &str) -> String {
-    format!("ro://data/texture/유저인터페이스/map/{map_name}.bmp")
+    format!("ro://data/texture/{USER_INTERFACE}/map/{map_name}.bmp")
+}
+
+/// Generate the NPC cutin illustration BMP path. Scripts usually name the bitmap
+/// without its extension; an explicit `.bmp` is kept as-is.
+pub fn cutin_illust_path(image: &str) -> String {
+    let image = image
+        .strip_suffix(".bmp")
+        .or_else(|| image.strip_suffix(".BMP"))
+        .unwrap_or(image);
+    format!("ro://data/texture/{USER_INTERFACE}/illust/{image}.bmp")
 }
 
 /// Generate the ground-drop collection sprite path for an item resource name.
@@ -181,10 +233,13 @@ pub fn frozen_ice_action_path() -> String {
 /// `accname` comes from the accessory db and already carries its leading separator (e.g. `"_고글"`).
 pub fn headgear_sprite_path(gender: Gender, accname: &str) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
-    format!("ro://data/sprite/악세사리/{}/{}{}.spr", sex, sex, accname)
+    format!(
+        "ro://data/sprite/{ACCESSORY}/{}/{}{}.spr",
+        sex, sex, accname
+    )
 }
 
 /// Generate headgear (accessory) action path.
@@ -196,11 +251,11 @@ pub fn headgear_action_path(gender: Gender, accname: &str) -> String {
 /// `suffix` comes from the weapon db and already carries its leading separator (e.g. `"_검"`).
 pub fn weapon_sprite_path(gender: Gender, job_name: &str, suffix: &str) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/인간족/{}/{}_{}{}.spr",
+        "ro://data/sprite/{HUMAN}/{}/{}_{}{}.spr",
         job_name, job_name, sex, suffix
     )
 }
@@ -215,11 +270,11 @@ pub fn weapon_action_path(gender: Gender, job_name: &str, suffix: &str) -> Strin
 /// without a leading separator.
 pub fn shield_sprite_path(gender: Gender, job_name: &str, suffix: &str) -> String {
     let sex = match gender {
-        Gender::Male => "남",
-        Gender::Female => "여",
+        Gender::Male => MALE,
+        Gender::Female => FEMALE,
     };
     format!(
-        "ro://data/sprite/방패/{}/{}_{}_{}_방패.spr",
+        "ro://data/sprite/{SHIELD}/{}/{}_{}_{}_{SHIELD}.spr",
         job_name, job_name, sex, suffix
     )
 }
@@ -252,6 +307,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cutin_illust_path_appends_extension_once() {
+        assert_eq!(
+            cutin_illust_path("kafra_01"),
+            "ro://data/texture/유저인터페이스/illust/kafra_01.bmp"
+        );
+        assert_eq!(
+            cutin_illust_path("kafra_01.bmp"),
+            "ro://data/texture/유저인터페이스/illust/kafra_01.bmp"
+        );
+    }
+
     #[test]
     fn minimap_path_builds_correct_url() {
         assert_eq!(
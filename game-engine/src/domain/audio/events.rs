@@ -63,7 +63,7 @@ pub struct MuteBgmEvent {
 #[reflect(Debug)]
 #[auto_add_message(plugin = crate::app::audio_plugin::AudioPlugin)]
 pub struct PlayMobSfx {
-    /// Entity carrying the `SpatialAudioEmitter` (the mob root).
+    /// Entity carrying the `SpatialAudioEmitter` (the mob or player root).
     pub emitter: Entity,
     /// Raw decoded sound filename from the ACT (e.g. "포링.wav" or "monster\\xxx.wav").
     pub sound: String,
@@ -84,6 +84,16 @@ pub struct PlaySkillSfx {
     pub sound: String,
 }
 
+/// Event requesting a non-positional interface sound (menus, character select).
+/// Played on the `SfxChannel`, so it follows the SFX volume and mute settings.
+#[derive(Message, Debug, Clone, Reflect)]
+#[reflect(Debug)]
+#[auto_add_message(plugin = crate::app::audio_plugin::AudioPlugin)]
+pub struct PlayUiSfx {
+    /// Sound path relative to `data/wav/` (e.g. "버튼소리.wav").
+    pub sound: String,
+}
+
 /// Event to change the SFX volume.
 #[derive(Message, Debug, Clone, Copy, Reflect)]
 #[reflect(Debug)]
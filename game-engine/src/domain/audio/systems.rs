@@ -1,7 +1,7 @@
 use super::{
     events::{
         MuteAmbienceEvent, MuteBgmEvent, MuteSfxEvent, PlayBgmEvent, PlayMobSfx, PlaySkillSfx,
-        SetAmbienceVolumeEvent, SetBgmVolumeEvent, SetSfxVolumeEvent, StopBgmEvent,
+        PlayUiSfx, SetAmbienceVolumeEvent, SetBgmVolumeEvent, SetSfxVolumeEvent, StopBgmEvent,
     },
     resources::{AmbienceChannel, AudioSettings, BgmManager, BgmNameTable, SfxChannel},
 };
@@ -339,6 +339,22 @@ pub fn play_skill_sfx(
     }
 }
 
+/// Play interface sounds on the `SfxChannel` without a spatial emitter.
+#[auto_add_system(
+    plugin = crate::app::audio_plugin::AudioPlugin,
+    schedule = Update
+)]
+pub fn play_ui_sfx(
+    mut events: MessageReader<PlayUiSfx>,
+    asset_server: Res<AssetServer>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+) {
+    for event in events.read() {
+        let source: Handle<AudioSource> = asset_server.load(mob_sfx_path(&event.sound));
+        sfx_channel.play(source);
+    }
+}
+
 #[auto_add_system(
     plugin = crate::app::audio_plugin::AudioPlugin,
     schedule = Startup
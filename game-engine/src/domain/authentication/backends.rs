@@ -0,0 +1,259 @@
+//! Auth backends: what happens between the player pressing "Login" and the
+//! `ConnectLogin` command.
+//!
+//! Most servers take the typed credentials as they are ([`ClassicLogin`]).
+//! Some front their login server with an HTTP API that trades the password for
+//! a token first ([`HttpTokenLogin`]). The active backend is the
+//! [`ActiveAuthBackend`] resource, picked from `[server.auth]` in
+//! `clientinfo.toml`; an app can replace it with its own [`AuthBackend`].
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_init_resource;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+
+use crate::infrastructure::config::AuthBackendConfig;
+
+/// Credentials as the login server receives them.
+#[derive(Debug, Clone)]
+pub struct LoginCredentials {
+    pub username: String,
+    /// The password, or a token the login server accepts in its place.
+    pub secret: SecretString,
+}
+
+pub type AuthFuture = Pin<Box<dyn Future<Output = Result<LoginCredentials, String>> + Send>>;
+
+pub trait AuthBackend: Send + Sync + 'static {
+    fn name(&self) -> &str;
+
+    /// Turn what the player typed into what the login server receives. The
+    /// future is polled on the IO task pool, which asset loading shares, so
+    /// blocking work belongs on a thread of its own.
+    fn authenticate(&self, username: String, password: SecretString) -> AuthFuture;
+}
+
+/// The auth backend login attempts go through.
+#[derive(Resource, Clone)]
+#[auto_init_resource(plugin = crate::app::authentication_plugin::AuthenticationPlugin)]
+pub struct ActiveAuthBackend(pub Arc<dyn AuthBackend>);
+
+impl ActiveAuthBackend {
+    /// The backend `[server.auth]` asks for, or why it can't work.
+    pub fn from_config(config: &AuthBackendConfig) -> Result<Self, String> {
+        config.validate()?;
+        Ok(match config {
+            AuthBackendConfig::Classic => Self(Arc::new(ClassicLogin)),
+            AuthBackendConfig::HttpToken { url, token_field } => Self(Arc::new(
+                HttpTokenLogin::new(url.clone(), token_field.clone()),
+            )),
+        })
+    }
+
+    /// Refuses every login with `reason`, so a broken `[server.auth]` shows up
+    /// on the login screen instead of quietly logging in some other way.
+    pub fn misconfigured(reason: String) -> Self {
+        Self(Arc::new(MisconfiguredLogin { reason }))
+    }
+}
+
+impl Default for ActiveAuthBackend {
+    fn default() -> Self {
+        Self(Arc::new(ClassicLogin))
+    }
+}
+
+/// Hands the typed credentials straight to the login server.
+pub struct ClassicLogin;
+
+impl AuthBackend for ClassicLogin {
+    fn name(&self) -> &str {
+        "classic"
+    }
+
+    fn authenticate(&self, username: String, password: SecretString) -> AuthFuture {
+        Box::pin(async move {
+            Ok(LoginCredentials {
+                username,
+                secret: password,
+            })
+        })
+    }
+}
+
+struct MisconfiguredLogin {
+    reason: String,
+}
+
+impl AuthBackend for MisconfiguredLogin {
+    fn name(&self) -> &str {
+        "misconfigured"
+    }
+
+    fn authenticate(&self, _username: String, _password: SecretString) -> AuthFuture {
+        let reason = format!("login is misconfigured: {}", self.reason);
+        Box::pin(async move { Err(reason) })
+    }
+}
+
+/// How long the login API gets for the whole exchange.
+const LOGIN_API_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Trades the credentials for a token at an HTTP endpoint, then logs in with
+/// the token as the password.
+#[derive(Clone)]
+pub struct HttpTokenLogin {
+    pub url: String,
+    /// Response field holding the token.
+    pub token_field: String,
+    agent: ureq::Agent,
+}
+
+/// A top-level value of the token response; only strings are of interest.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ResponseValue {
+    String(String),
+    #[allow(dead_code)]
+    Other(serde::de::IgnoredAny),
+}
+
+/// The token out of a decoded response body.
+fn token_from_response(
+    mut fields: HashMap<String, ResponseValue>,
+    token_field: &str,
+) -> Result<String, String> {
+    match fields.remove(token_field) {
+        Some(ResponseValue::String(token)) if !token.is_empty() => Ok(token),
+        Some(_) => Err(format!("login API returned an unusable `{token_field}`")),
+        None => Err(format!("login API response has no `{token_field}`")),
+    }
+}
+
+impl HttpTokenLogin {
+    pub fn new(url: String, token_field: String) -> Self {
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(LOGIN_API_TIMEOUT))
+            .build()
+            .into();
+        Self {
+            url,
+            token_field,
+            agent,
+        }
+    }
+
+    fn exchange(&self, username: &str, password: &SecretString) -> Result<String, String> {
+        #[derive(serde::Serialize)]
+        struct TokenRequest<'a> {
+            username: &'a str,
+            password: &'a str,
+        }
+
+        let mut response = self
+            .agent
+            .post(&self.url)
+            .send_json(TokenRequest {
+                username,
+                password: password.expose_secret(),
+            })
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(401 | 403) => "invalid username or password".to_string(),
+                e => format!("login API request failed: {e}"),
+            })?;
+        let fields = response
+            .body_mut()
+            .read_json::<HashMap<String, ResponseValue>>()
+            .map_err(|e| format!("login API returned an unreadable response: {e}"))?;
+        token_from_response(fields, &self.token_field)
+    }
+}
+
+impl AuthBackend for HttpTokenLogin {
+    fn name(&self) -> &str {
+        "http_token"
+    }
+
+    fn authenticate(&self, username: String, password: SecretString) -> AuthFuture {
+        // ureq blocks; keep it off the shared IO pool.
+        let backend = self.clone();
+        let (sender, receiver) = async_channel::bounded(1);
+        let spawned = std::thread::Builder::new()
+            .name("http-token-login".to_string())
+            .spawn(move || {
+                let result = backend
+                    .exchange(&username, &password)
+                    .map(|token| LoginCredentials {
+                        username,
+                        secret: SecretString::from(token),
+                    });
+                let _ = sender.send_blocking(result);
+            });
+        Box::pin(async move {
+            spawned.map_err(|e| format!("could not start the login API request: {e}"))?;
+            receiver
+                .recv()
+                .await
+                .map_err(|_| "login API request stopped unexpectedly".to_string())?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::tasks::block_on;
+
+    #[test]
+    fn classic_login_passes_the_credentials_through() {
+        let backend = ActiveAuthBackend::default();
+        let credentials = block_on(
+            backend
+                .0
+                .authenticate("player".into(), SecretString::from("hunter2")),
+        )
+        .unwrap();
+        assert_eq!(credentials.username, "player");
+        assert_eq!(credentials.secret.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn invalid_config_is_rejected_and_refuses_logins() {
+        let config = AuthBackendConfig::HttpToken {
+            url: "example.org/login".to_string(),
+            token_field: "token".to_string(),
+        };
+        let reason = ActiveAuthBackend::from_config(&config).err().unwrap();
+        let backend = ActiveAuthBackend::misconfigured(reason);
+        let result = block_on(
+            backend
+                .0
+                .authenticate("player".into(), SecretString::from("hunter2")),
+        );
+        assert!(result.unwrap_err().contains("http(s)"));
+    }
+
+    #[test]
+    fn token_is_read_from_the_configured_field() {
+        let string = |s: &str| ResponseValue::String(s.to_string());
+        let other = || ResponseValue::Other(serde::de::IgnoredAny);
+
+        let ok = HashMap::from([
+            ("access_token".to_string(), string("abc")),
+            ("expires_in".to_string(), other()),
+        ]);
+        assert_eq!(token_from_response(ok, "access_token"), Ok("abc".into()));
+
+        let missing = HashMap::from([("token".to_string(), string("abc"))]);
+        assert!(token_from_response(missing, "access_token").is_err());
+
+        let not_a_string = HashMap::from([("token".to_string(), other())]);
+        assert!(token_from_response(not_a_string, "token").is_err());
+    }
+}
@@ -1,3 +1,6 @@
+pub mod backends;
 pub mod events;
 pub mod models;
+pub mod retry;
 pub mod systems;
+pub mod teardown;
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_init_resource;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Resource)]
 #[auto_init_resource(plugin = crate::app::authentication_plugin::AuthenticationPlugin)]
@@ -8,6 +9,7 @@ pub struct ServerConfiguration {
     pub login_server_address: String,
     pub client_version: u32,
     pub default_port: u16,
+    pub connect_timeout: Duration,
 }
 
 impl Default for ServerConfiguration {
@@ -16,6 +18,7 @@ impl Default for ServerConfiguration {
             login_server_address: "127.0.0.1:6900".to_string(),
             client_version: 1,
             default_port: 6900,
+            connect_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -0,0 +1,310 @@
+//! Automatic retry of transiently refused logins.
+//!
+//! When the login server refuses for a reason that clears up on its own —
+//! full, under maintenance, the previous session still logging out — the last
+//! `ConnectLogin` is sent again after [`login_retry_delay`], up to
+//! [`LOGIN_RETRY_LIMIT`] times. A [`LoginRetry`] resource exists while one is
+//! pending, so the login screen can show why and when; a new login attempt
+//! from the user cancels it, and it only fires while still on the login screen.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource};
+use net_contract::commands::ConnectLogin;
+use net_contract::events::{LoginAccepted, LoginRefusalReason, LoginRefused};
+
+use super::teardown::SessionTeardown;
+use crate::core::state::GameState;
+use crate::domain::system_sets::AuthenticationSystems;
+use crate::presentation::ui::events::LoginAttemptEvent;
+
+/// Retries after one login attempt before the refusal is left to the user.
+pub const LOGIN_RETRY_LIMIT: u32 = 5;
+
+/// 5 s before the first retry, doubling up to a minute.
+pub fn login_retry_delay(attempt: u32) -> Duration {
+    Duration::from_secs((5u64 << attempt.saturating_sub(1).min(4)).min(60))
+}
+
+/// A retry waiting out its delay.
+#[derive(Resource, Debug)]
+pub struct LoginRetry {
+    request: ConnectLogin,
+    attempt: u32,
+    reason: LoginRefusalReason,
+    timer: Timer,
+}
+
+impl LoginRetry {
+    /// 1 for the first retry.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub fn reason(&self) -> LoginRefusalReason {
+        self.reason
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+}
+
+/// The last login request and how often it has been retried. It holds the
+/// password as `ConnectLogin` does, until the login is accepted, refused for
+/// good, or the session is torn down.
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::app::authentication_plugin::AuthenticationPlugin)]
+pub struct LastLoginRequest {
+    request: Option<ConnectLogin>,
+    retries: u32,
+}
+
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = Update,
+    config(in_set = AuthenticationSystems::LoginResponse)
+)]
+pub fn track_login_requests(
+    mut commands: Commands,
+    mut attempts: MessageReader<LoginAttemptEvent>,
+    mut requests: MessageReader<ConnectLogin>,
+    mut accepted: MessageReader<LoginAccepted>,
+    mut last: ResMut<LastLoginRequest>,
+) {
+    if attempts.read().count() > 0 {
+        last.retries = 0;
+        commands.remove_resource::<LoginRetry>();
+    }
+    if let Some(request) = requests.read().last() {
+        last.request = Some(request.clone());
+    }
+    if accepted.read().count() > 0 {
+        *last = LastLoginRequest::default();
+        commands.remove_resource::<LoginRetry>();
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = Update,
+    config(in_set = AuthenticationSystems::LoginResponse, after = track_login_requests)
+)]
+pub fn schedule_login_retry(
+    mut commands: Commands,
+    mut refusals: MessageReader<LoginRefused>,
+    mut last: ResMut<LastLoginRequest>,
+) {
+    let Some(refusal) = refusals.read().last() else {
+        return;
+    };
+    let reason = refusal.reason();
+    let Some(request) = last.request.clone() else {
+        return;
+    };
+    if !reason.is_transient() || last.retries >= LOGIN_RETRY_LIMIT {
+        *last = LastLoginRequest::default();
+        return;
+    }
+    last.retries += 1;
+    let delay = login_retry_delay(last.retries);
+    info!(
+        "Login refused ({reason:?}); retry {} of {LOGIN_RETRY_LIMIT} in {}s",
+        last.retries,
+        delay.as_secs()
+    );
+    commands.insert_resource(LoginRetry {
+        request,
+        attempt: last.retries,
+        reason,
+        timer: Timer::new(delay, TimerMode::Once),
+    });
+}
+
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = Update,
+    config(
+        in_set = AuthenticationSystems::LoginResponse,
+        after = schedule_login_retry,
+        run_if = in_state(GameState::Login)
+    )
+)]
+pub fn retry_refused_login(
+    mut commands: Commands,
+    time: Res<Time>,
+    retry: Option<ResMut<LoginRetry>>,
+    mut connect_login: MessageWriter<ConnectLogin>,
+) {
+    let Some(mut retry) = retry else {
+        return;
+    };
+    if !retry.timer.tick(time.delta()).is_finished() {
+        return;
+    }
+    info!("Retrying login (attempt {})", retry.attempt);
+    connect_login.write(retry.request.clone());
+    commands.remove_resource::<LoginRetry>();
+}
+
+/// Drops the remembered credentials and any pending retry with the session.
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = SessionTeardown
+)]
+pub fn forget_login_request(mut commands: Commands, mut last: ResMut<LastLoginRequest>) {
+    *last = LastLoginRequest::default();
+    commands.remove_resource::<LoginRetry>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use secrecy::SecretString;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Login)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)))
+            .add_message::<LoginAttemptEvent>()
+            .add_message::<ConnectLogin>()
+            .add_message::<LoginAccepted>()
+            .add_message::<LoginRefused>()
+            .init_resource::<LastLoginRequest>()
+            .add_systems(
+                Update,
+                (
+                    track_login_requests,
+                    schedule_login_retry,
+                    retry_refused_login,
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn connect() -> ConnectLogin {
+        ConnectLogin {
+            address: "127.0.0.1:6900".to_string(),
+            username: "player".to_string(),
+            password: "secret".to_string(),
+            client_version: 0,
+            build: "test".to_string(),
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+
+    fn refuse(app: &mut App, error_code: u8) {
+        app.world_mut().write_message(LoginRefused {
+            username: "player".to_string(),
+            error_code,
+            error_message: String::new(),
+            block_date: None,
+        });
+    }
+
+    fn sent(app: &App) -> usize {
+        app.world()
+            .resource::<Messages<ConnectLogin>>()
+            .iter_current_update_messages()
+            .count()
+    }
+
+    #[test]
+    fn delays_double_up_to_a_minute() {
+        let delays: Vec<u64> = (1..=LOGIN_RETRY_LIMIT)
+            .map(|attempt| login_retry_delay(attempt).as_secs())
+            .collect();
+        assert_eq!(delays, [5, 10, 20, 40, 60]);
+    }
+
+    #[test]
+    fn a_full_server_is_retried_after_the_delay() {
+        let mut app = app();
+        app.world_mut().write_message(connect());
+        app.update();
+        refuse(&mut app, 7);
+        app.update();
+
+        let retry = app.world().resource::<LoginRetry>();
+        assert_eq!(retry.attempt(), 1);
+        assert_eq!(retry.reason(), LoginRefusalReason::ServerFull);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().contains_resource::<LoginRetry>());
+        app.update();
+        assert!(!app.world().contains_resource::<LoginRetry>());
+        assert_eq!(sent(&app), 1, "the request went out again");
+    }
+
+    #[test]
+    fn wrong_passwords_and_new_attempts_are_not_retried() {
+        let mut app = app();
+        app.world_mut().write_message(connect());
+        app.update();
+        refuse(&mut app, 1);
+        app.update();
+        assert!(!app.world().contains_resource::<LoginRetry>());
+
+        refuse(&mut app, 7);
+        app.update();
+        assert!(app.world().contains_resource::<LoginRetry>());
+        app.world_mut().write_message(LoginAttemptEvent {
+            username: "player".to_string(),
+            password: SecretString::from("secret"),
+        });
+        app.update();
+        assert!(!app.world().contains_resource::<LoginRetry>());
+    }
+
+    #[test]
+    fn a_final_refusal_forgets_the_password() {
+        let mut app = app();
+        app.world_mut().write_message(connect());
+        app.update();
+        assert!(app.world().resource::<LastLoginRequest>().request.is_some());
+
+        refuse(&mut app, 1);
+        app.update();
+        assert!(app.world().resource::<LastLoginRequest>().request.is_none());
+    }
+
+    #[test]
+    fn a_pending_retry_waits_for_the_login_screen() {
+        let mut app = app();
+        app.world_mut().write_message(connect());
+        app.update();
+        refuse(&mut app, 7);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Connecting);
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(app.world().contains_resource::<LoginRetry>());
+        assert_eq!(sent(&app), 0);
+    }
+
+    #[test]
+    fn teardown_forgets_the_request_and_the_retry() {
+        let mut app = app();
+        app.add_systems(SessionTeardown, forget_login_request);
+        app.world_mut().write_message(connect());
+        app.update();
+        refuse(&mut app, 7);
+        app.update();
+
+        app.world_mut().run_schedule(SessionTeardown);
+        app.update();
+        assert!(!app.world().contains_resource::<LoginRetry>());
+        assert!(app.world().resource::<LastLoginRequest>().request.is_none());
+    }
+}
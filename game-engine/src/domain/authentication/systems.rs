@@ -1,8 +1,10 @@
 use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task, block_on, poll_once};
 use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource};
 use net_contract::commands::{ConnectCharServer, ConnectLogin};
 use secrecy::ExposeSecret;
 
+use super::backends::{ActiveAuthBackend, LoginCredentials};
 use super::{events::*, models::*};
 use crate::{
     core::state::GameState,
@@ -11,44 +13,48 @@ use crate::{
     presentation::ui::events::{LoginAttemptEvent, ServerSelectedEvent},
 };
 use net_contract::dto::NetworkError;
-use net_contract::events::{LoginAccepted, LoginRefused};
+use net_contract::events::{LoginAccepted, LoginConnectionFailed, LoginRefused};
 use net_contract::state::UserSession;
 
+/// A login attempt waiting on its auth backend.
+#[derive(Resource)]
+pub struct PendingAuthentication {
+    username: String,
+    task: Task<Result<LoginCredentials, String>>,
+}
+
 /// System to handle login attempts from the UI
 ///
 /// When a user submits login credentials via the UI, this system:
-/// 1. Opens a QUIC connection to the login server
-/// 2. Arms the login state machine with the credentials
-/// 3. Emits a LoginAttemptStartedEvent for UI feedback
+/// 1. Hands them to the active auth backend on the IO task pool
+/// 2. Emits a LoginAttemptStartedEvent for UI feedback
 ///
-/// The response (success/failure) is handled by other systems that listen
-/// to LoginAccepted and LoginRefused protocol events.
+/// `finish_pending_authentication` takes it from there.
 #[auto_add_system(
     plugin = crate::app::authentication_plugin::AuthenticationPlugin,
     schedule = Update,
     config(in_set = AuthenticationSystems::LoginAttempt)
 )]
 pub fn handle_login_attempts(
+    mut commands: Commands,
     mut login_attempts: MessageReader<LoginAttemptEvent>,
     mut login_started_events: MessageWriter<LoginAttemptStartedEvent>,
-    mut connect_login: MessageWriter<ConnectLogin>,
-    auth_context: Res<AuthenticationContext>,
+    backend: Res<ActiveAuthBackend>,
 ) {
     for attempt in login_attempts.read() {
-        let server_address = &auth_context.server_config.login_server_address;
-        let client_version = auth_context.server_config.client_version;
         let username = &attempt.username;
-        let password = attempt.password.expose_secret();
-
-        info!("Login attempt for user: {}", username);
-        debug!("Requesting QUIC login to server: {}", server_address);
+        info!(
+            "Login attempt for user: {} (auth: {})",
+            username,
+            backend.0.name()
+        );
 
-        connect_login.write(ConnectLogin {
-            address: server_address.clone(),
+        let future = backend
+            .0
+            .authenticate(username.clone(), attempt.password.clone());
+        commands.insert_resource(PendingAuthentication {
             username: username.clone(),
-            password: password.to_string(),
-            client_version,
-            build: "lifthrasir".to_string(),
+            task: IoTaskPool::get().spawn(future),
         });
 
         login_started_events.write(LoginAttemptStartedEvent {
@@ -57,6 +63,61 @@ pub fn handle_login_attempts(
     }
 }
 
+/// System to finish a login attempt once its auth backend is done
+///
+/// On success this:
+/// 1. Opens a QUIC connection to the login server
+/// 2. Arms the login state machine with the backend's credentials
+///
+/// The response (success/failure) is handled by other systems that listen
+/// to LoginAccepted and LoginRefused protocol events. A backend failure is
+/// reported like a refused login.
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = Update,
+    config(in_set = AuthenticationSystems::LoginAttempt, after = handle_login_attempts)
+)]
+pub fn finish_pending_authentication(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingAuthentication>>,
+    mut connect_login: MessageWriter<ConnectLogin>,
+    mut failures: MessageWriter<LoginFailureEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    auth_context: Res<AuthenticationContext>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    let Some(result) = block_on(poll_once(&mut pending.task)) else {
+        return;
+    };
+    commands.remove_resource::<PendingAuthentication>();
+
+    match result {
+        Ok(credentials) => {
+            let server_address = &auth_context.server_config.login_server_address;
+            debug!("Requesting QUIC login to server: {}", server_address);
+
+            connect_login.write(ConnectLogin {
+                address: server_address.clone(),
+                username: credentials.username,
+                password: credentials.secret.expose_secret().to_string(),
+                client_version: auth_context.server_config.client_version,
+                build: "lifthrasir".to_string(),
+                connect_timeout: auth_context.server_config.connect_timeout,
+            });
+        }
+        Err(reason) => {
+            warn!("Authentication for {} failed: {}", pending.username, reason);
+            failures.write(LoginFailureEvent {
+                error: NetworkError::AuthenticationFailed { reason },
+                username: pending.username.clone(),
+            });
+            next_state.set(GameState::Login);
+        }
+    }
+}
+
 /// System to handle successful login from protocol layer
 ///
 /// When the login server accepts the login (LoginResponse proto),
@@ -111,13 +172,13 @@ pub fn handle_login_refused(
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     for event in protocol_events.read() {
-        warn!("Login refused with error code: {}", event.error_code);
+        warn!(
+            "Login refused with error code {} ({:?})",
+            event.error_code,
+            event.reason()
+        );
 
-        let reason = if event.error_message.is_empty() {
-            format!("Login refused by server (error code: {})", event.error_code)
-        } else {
-            event.error_message.clone()
-        };
+        let reason = event.description();
 
         domain_events.write(LoginFailureEvent {
             error: NetworkError::AuthenticationFailed { reason },
@@ -128,20 +189,53 @@ pub fn handle_login_refused(
     }
 }
 
+/// System to handle a login connection that never reached the server
+///
+/// The adapter reports bad addresses, unreachable hosts and connect timeouts as
+/// `LoginConnectionFailed`; its typed error goes to the login screen as is.
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = Update,
+    config(in_set = AuthenticationSystems::LoginResponse)
+)]
+pub fn handle_login_connection_failed(
+    mut protocol_events: MessageReader<LoginConnectionFailed>,
+    mut domain_events: MessageWriter<LoginFailureEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in protocol_events.read() {
+        warn!("Login connection failed: {}", event.error);
+        domain_events.write(LoginFailureEvent {
+            error: event.error.clone(),
+            username: event.username.clone(),
+        });
+        next_state.set(GameState::Login);
+    }
+}
+
 // ============================================================================
 // Configuration and Client Initialization Systems
 // ============================================================================
 
-/// Resource to hold the client config handle
+/// Where the client config is looked for, in order: our TOML, then the
+/// `clientinfo.xml` a server pack ships in its data folder or GRF.
+const CLIENT_CONFIG_PATHS: [&str; 2] = ["config/clientinfo.toml", "ro://data/clientinfo.xml"];
+
+/// Resource to hold the client config handle and which of
+/// [`CLIENT_CONFIG_PATHS`] it was loaded from
 #[derive(Resource)]
-struct ClientConfigHandle(Handle<ClientConfig>);
+struct ClientConfigHandle {
+    handle: Handle<ClientConfig>,
+    path_index: usize,
+}
 
 /// Resource to track if config is already loaded
 #[derive(Resource, Default)]
 #[auto_init_resource(plugin = crate::app::authentication_plugin::AuthenticationPlugin)]
 struct ConfigLoaded(bool);
 
-/// System to load client configuration (runs only once)
+/// System to load client configuration, moving on to the next of
+/// [`CLIENT_CONFIG_PATHS`] while the current one fails to load
 #[auto_add_system(
     plugin = crate::app::authentication_plugin::AuthenticationPlugin,
     schedule = Update,
@@ -152,11 +246,25 @@ fn load_client_config(
     asset_server: Res<AssetServer>,
     config_handle: Option<Res<ClientConfigHandle>>,
 ) {
-    if config_handle.is_none() {
-        let handle = asset_server.load::<ClientConfig>("config/clientinfo.toml");
-        commands.insert_resource(ClientConfigHandle(handle));
-        debug!("Loading client configuration from config/clientinfo.toml");
-    }
+    let path_index = match config_handle {
+        None => 0,
+        Some(current) if asset_server.load_state(&current.handle).is_failed() => {
+            let next = current.path_index + 1;
+            let Some(next_path) = CLIENT_CONFIG_PATHS.get(next) else {
+                return;
+            };
+            warn!(
+                "No usable {}; trying {}",
+                CLIENT_CONFIG_PATHS[current.path_index], next_path
+            );
+            next
+        }
+        Some(_) => return,
+    };
+    let path = CLIENT_CONFIG_PATHS[path_index];
+    let handle = asset_server.load::<ClientConfig>(path);
+    commands.insert_resource(ClientConfigHandle { handle, path_index });
+    debug!("Loading client configuration from {}", path);
 }
 
 /// System to check if config is loaded and apply it
@@ -170,22 +278,32 @@ fn check_client_config_loaded(
     client_configs: Res<Assets<ClientConfig>>,
     mut config_loaded: ResMut<ConfigLoaded>,
     mut auth_context: ResMut<AuthenticationContext>,
+    mut auth_backend: ResMut<ActiveAuthBackend>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     if let Some(handle) = config_handle
         && !config_loaded.0
-        && let Some(config) = client_configs.get(&handle.0)
+        && let Some(config) = client_configs.get(&handle.handle)
     {
         auth_context.server_config = ServerConfiguration {
             login_server_address: config.server.to_address(),
             client_version: config.server.client_version,
             default_port: config.server.port,
+            connect_timeout: std::time::Duration::from_secs(config.server.connect_timeout_secs),
         };
+        *auth_backend = ActiveAuthBackend::from_config(&config.server.auth).unwrap_or_else(|e| {
+            error!(
+                "Rejecting [server.auth] in {}: {}",
+                CLIENT_CONFIG_PATHS[handle.path_index], e
+            );
+            ActiveAuthBackend::misconfigured(e)
+        });
 
         info!(
-            "Client configured - Server: {}, Version: {}",
+            "Client configured - Server: {}, Version: {}, Auth: {}",
             auth_context.server_config.login_server_address,
-            auth_context.server_config.client_version
+            auth_context.server_config.client_version,
+            auth_backend.0.name()
         );
 
         // Mark as loaded to prevent repeated execution
@@ -218,18 +336,16 @@ pub fn handle_server_selection(
     };
 
     for event in server_events.read() {
-        info!("Server selected: {}", event.server.name);
+        info!(
+            "Server selected: {} ({} online)",
+            event.server.name, event.server.users
+        );
 
         session.selected_server = Some(event.server.clone());
 
         commands.insert_resource(session.clone());
 
-        let ip_bytes = event.server.ip.to_be_bytes();
-        let server_ip = format!(
-            "{}.{}.{}.{}",
-            ip_bytes[0], ip_bytes[1], ip_bytes[2], ip_bytes[3]
-        );
-        let address = format!("{}:{}", server_ip, event.server.port);
+        let address = event.server.address();
 
         info!("Connecting to character server at {}", address);
 
@@ -242,3 +358,64 @@ pub fn handle_server_selection(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use net_contract::dto::{ServerInfo, ServerType};
+    use net_contract::state::SessionTokens;
+
+    fn server(name: &str, port: u16, users: u16) -> ServerInfo {
+        ServerInfo {
+            ip: u32::from_be_bytes([10, 0, 0, 1]),
+            port,
+            name: name.into(),
+            users,
+            server_type: ServerType::Normal,
+            new_server: 0,
+        }
+    }
+
+    #[test]
+    fn selection_connects_to_the_chosen_char_server() {
+        let servers = vec![server("Asgard", 6121, 40), server("Midgard", 6122, 310)];
+        let mut app = App::new();
+        app.add_message::<ServerSelectedEvent>()
+            .add_message::<ConnectCharServer>()
+            .insert_resource(UserSession {
+                username: "tester".into(),
+                tokens: SessionTokens {
+                    login_id1: 11,
+                    account_id: 2000001,
+                    login_id2: 22,
+                    character_server_info: None,
+                },
+                login_timestamp: std::time::SystemTime::now(),
+                last_login_ip: 0,
+                sex: 1,
+                server_list: servers.clone(),
+                selected_server: None,
+                auth_token: String::new(),
+            })
+            .add_systems(Update, handle_server_selection);
+
+        app.world_mut().write_message(ServerSelectedEvent {
+            server: servers[1].clone(),
+        });
+        app.update();
+
+        let connects: Vec<ConnectCharServer> = app
+            .world_mut()
+            .resource_mut::<Messages<ConnectCharServer>>()
+            .drain()
+            .collect();
+        assert_eq!(connects.len(), 1);
+        assert_eq!(connects[0].address, "10.0.0.1:6122");
+        let selected = app
+            .world()
+            .resource::<UserSession>()
+            .selected_server
+            .clone();
+        assert_eq!(selected.map(|server| server.users), Some(310));
+    }
+}
@@ -0,0 +1,169 @@
+//! Session teardown when the client falls back to the login screen.
+//!
+//! Any state can lead back to login (a refused login, a zone disconnect, the
+//! char server dropping), and everything the old account left behind has to go
+//! before the next login or it replays into the new session. Rather than each
+//! module guessing which transition ends a session, [`SessionTeardownPlugin`]
+//! runs the [`SessionTeardown`] schedule from the `OnExit` of every other state
+//! whenever the state being entered is `Login`. Modules add their cleanup there.
+
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_add_system;
+use net_contract::commands::{ConnectCharServer, ConnectZone, EndSession};
+use net_contract::events::{
+    CharacterServerConnected, LoginAccepted, ZoneEntered, ZoneServerInfoReceived,
+};
+use net_contract::state::UserSession;
+
+use crate::core::state::GameState;
+
+/// Cleanup run once per return to login, after the state has switched.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionTeardown;
+
+/// States a session can be left from. Everything but `Login` itself: `Loading`
+/// doubles as the map-load screen, so a drop mid-load ends a session too.
+const SESSION_STATES: [GameState; 7] = [
+    GameState::Loading,
+    GameState::Connecting,
+    GameState::ServerSelection,
+    GameState::CharacterSelection,
+    GameState::CharacterCreation,
+    GameState::InGame,
+    GameState::Paused,
+];
+
+pub struct SessionTeardownPlugin;
+
+impl Plugin for SessionTeardownPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(SessionTeardown);
+        // `State` already holds the entered state while `OnExit` runs.
+        for state in SESSION_STATES {
+            app.add_systems(
+                OnExit(state),
+                run_session_teardown.run_if(in_state(GameState::Login)),
+            );
+        }
+        // Flow messages still in flight from the old session: a late
+        // `ZoneServerInfoReceived` would otherwise reconnect to the zone.
+        app.add_systems(
+            SessionTeardown,
+            (
+                clear_messages::<LoginAccepted>,
+                clear_messages::<CharacterServerConnected>,
+                clear_messages::<ZoneServerInfoReceived>,
+                clear_messages::<ZoneEntered>,
+                clear_messages::<ConnectCharServer>,
+                clear_messages::<ConnectZone>,
+            ),
+        );
+    }
+}
+
+fn run_session_teardown(world: &mut World) {
+    world.run_schedule(SessionTeardown);
+}
+
+fn clear_messages<M: Message>(messages: Option<ResMut<Messages<M>>>) {
+    if let Some(mut messages) = messages {
+        messages.clear();
+    }
+}
+
+/// Drops the account session and has the adapter close its connections.
+#[auto_add_system(
+    plugin = crate::app::authentication_plugin::AuthenticationPlugin,
+    schedule = SessionTeardown
+)]
+pub fn end_user_session(mut commands: Commands, mut end_session: MessageWriter<EndSession>) {
+    info!("Returning to login: ending the account session");
+    commands.remove_resource::<UserSession>();
+    end_session.write(EndSession);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use net_contract::dto::ZoneServerInfo;
+
+    fn teardown_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, SessionTeardownPlugin));
+        app.init_state::<GameState>();
+        app.add_message::<EndSession>()
+            .add_message::<LoginAccepted>()
+            .add_message::<CharacterServerConnected>()
+            .add_message::<ZoneServerInfoReceived>()
+            .add_message::<ZoneEntered>()
+            .add_message::<ConnectCharServer>()
+            .add_message::<ConnectZone>()
+            .add_systems(SessionTeardown, end_user_session);
+        app
+    }
+
+    fn go_to(app: &mut App, state: GameState) {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(state);
+        app.update();
+    }
+
+    fn ended_sessions(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<EndSession>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn returning_to_login_from_character_selection_ends_the_session() {
+        let mut app = teardown_app();
+        go_to(&mut app, GameState::CharacterSelection);
+        assert_eq!(ended_sessions(&mut app), 0);
+
+        app.insert_resource(session());
+        app.world_mut().write_message(ZoneServerInfoReceived {
+            zone_server_info: ZoneServerInfo {
+                char_id: 150001,
+                map_name: "prontera".into(),
+                ip: [127, 0, 0, 1],
+                port: 5121,
+                auth_token: vec![],
+            },
+        });
+        go_to(&mut app, GameState::Login);
+
+        assert_eq!(ended_sessions(&mut app), 1);
+        assert!(app.world().get_resource::<UserSession>().is_none());
+        assert!(
+            app.world()
+                .resource::<Messages<ZoneServerInfoReceived>>()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn moving_between_session_states_keeps_the_session() {
+        let mut app = teardown_app();
+        go_to(&mut app, GameState::ServerSelection);
+        go_to(&mut app, GameState::CharacterSelection);
+        go_to(&mut app, GameState::InGame);
+
+        assert_eq!(ended_sessions(&mut app), 0);
+    }
+
+    fn session() -> UserSession {
+        UserSession::from(&LoginAccepted {
+            account_id: 2000001,
+            login_id1: 1,
+            login_id2: 2,
+            sex: 1,
+            server_list: vec![],
+            username: "tester".into(),
+            auth_token: String::new(),
+        })
+    }
+}
@@ -0,0 +1,210 @@
+//! Keeps the camera out of cliffs, walls and map models.
+//!
+//! Runs after the follow system has placed the camera: a ray from just above
+//! the player to the camera is tested against the terrain heightfield and the
+//! bounding boxes of visible `CameraOccluder` meshes (a ceiling hidden on an
+//! indoor map doesn't count), and the camera is pulled in to just short of the
+//! first hit. Nothing is remembered between frames — the
+//! follow system's smoothing starts from the pulled-in position, so once the
+//! obstacle clears the camera eases back out on its own.
+
+use bevy::camera::primitives::Aabb;
+use bevy::math::bounding::{Aabb3d, RayCast3d};
+use bevy::prelude::*;
+
+use super::components::{CameraFollowTarget, CameraOccluder};
+use super::sequence::CameraSequencePlayer;
+use crate::domain::world::components::MapLoader;
+use crate::infrastructure::assets::loaders::RoAltitudeAsset;
+
+/// How far above the player's feet the ray starts (-Y is up), so the ground
+/// the player stands on never counts as an obstacle.
+const PIVOT_HEIGHT: f32 = 10.0;
+/// Gap kept between the camera and whatever it was pulled in front of.
+const CLEARANCE: f32 = 3.0;
+/// The camera is never pulled closer to the pivot than this.
+const MIN_DISTANCE: f32 = 15.0;
+/// Terrain march step; matches the cursor raycast's.
+const TERRAIN_STEP: f32 = 2.0;
+const BISECT_STEPS: u32 = 8;
+
+pub fn resolve_camera_collision(
+    sequence: Res<CameraSequencePlayer>,
+    map_loader_query: Query<&MapLoader>,
+    altitude_assets: Res<Assets<RoAltitudeAsset>>,
+    occluders: Query<(&Aabb, &GlobalTransform, &InheritedVisibility), With<CameraOccluder>>,
+    mut camera_query: Query<(&mut Transform, &CameraFollowTarget), With<Camera3d>>,
+) {
+    // Sequences frame their own shots.
+    if sequence.is_playing() {
+        return;
+    }
+
+    let altitude = map_loader_query
+        .single()
+        .ok()
+        .and_then(|map_loader| map_loader.altitude.as_ref())
+        .and_then(|handle| altitude_assets.get(handle));
+
+    for (mut camera_transform, follow_target) in camera_query.iter_mut() {
+        let pivot = follow_target.cached_position + Vec3::NEG_Y * PIVOT_HEIGHT;
+        let to_camera = camera_transform.translation - pivot;
+        let distance = to_camera.length();
+        let Ok(direction) = Dir3::new(to_camera) else {
+            continue;
+        };
+
+        let terrain = altitude.and_then(|altitude| {
+            terrain_hit(pivot, direction, distance, |p| {
+                altitude
+                    .altitude
+                    .get_terrain_height_at_position(p)
+                    .map(|height| p.y - height)
+            })
+        });
+        let ray = RayCast3d::new(pivot, direction, distance);
+        let model = occluder_hit(
+            &ray,
+            occluders
+                .iter()
+                .filter(|(_, _, visibility)| visibility.get())
+                .map(|(aabb, transform, _)| world_aabb(aabb, transform)),
+        );
+
+        let Some(hit) = [terrain, model].into_iter().flatten().reduce(f32::min) else {
+            continue;
+        };
+
+        camera_transform.translation = pivot + direction * pulled_in_distance(hit, distance);
+        camera_transform.look_at(follow_target.smoothed_look_at, Vec3::NEG_Y);
+    }
+}
+
+/// Distance along the ray at which it first goes from above the terrain to
+/// below it. `signed_gap` is the point's height minus the ground's (negative
+/// above ground, -Y up) and `None` off the map. A ray that starts underground
+/// only counts once it has come back up and gone down again.
+fn terrain_hit(
+    origin: Vec3,
+    direction: Dir3,
+    max_distance: f32,
+    signed_gap: impl Fn(Vec3) -> Option<f32>,
+) -> Option<f32> {
+    let mut above = 0.0;
+    let mut above_gap = signed_gap(origin);
+    let mut crossing = None;
+    let mut travelled = 0.0;
+    while travelled < max_distance {
+        travelled = (travelled + TERRAIN_STEP).min(max_distance);
+        let gap = signed_gap(origin + direction * travelled);
+        if let (Some(prev), Some(cur)) = (above_gap, gap)
+            && prev < 0.0
+            && cur >= 0.0
+        {
+            crossing = Some((above, travelled));
+            break;
+        }
+        above = travelled;
+        above_gap = gap;
+    }
+
+    let (mut lo, mut hi) = crossing?;
+    for _ in 0..BISECT_STEPS {
+        let mid = (lo + hi) * 0.5;
+        match signed_gap(origin + direction * mid) {
+            Some(gap) if gap >= 0.0 => hi = mid,
+            _ => lo = mid,
+        }
+    }
+    Some(lo)
+}
+
+/// A mesh's local bounding box carried into world space (the box of the
+/// transformed box, so rotated models get a slightly looser fit).
+pub(crate) fn world_aabb(aabb: &Aabb, transform: &GlobalTransform) -> Aabb3d {
+    let affine = transform.affine();
+    let matrix = affine.matrix3;
+    let center = affine.transform_point3a(aabb.center);
+    let half = aabb.half_extents;
+    let half_size =
+        matrix.x_axis.abs() * half.x + matrix.y_axis.abs() * half.y + matrix.z_axis.abs() * half.z;
+    Aabb3d::new(center, half_size)
+}
+
+/// Nearest box the ray enters. Boxes the ray starts inside are skipped: the
+/// player standing under a bridge or in a doorway shouldn't pin the camera.
+fn occluder_hit(ray: &RayCast3d, boxes: impl IntoIterator<Item = Aabb3d>) -> Option<f32> {
+    boxes
+        .into_iter()
+        .filter_map(|aabb| ray.aabb_intersection_at(&aabb))
+        .filter(|&t| t > 0.0)
+        .reduce(f32::min)
+}
+
+fn pulled_in_distance(hit: f32, distance: f32) -> f32 {
+    (hit - CLEARANCE).max(MIN_DISTANCE).min(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flat ground at y = 0 with a cliff rising to y = -50 from x = 40 on.
+    fn cliff(p: Vec3) -> Option<f32> {
+        let height = if p.x >= 40.0 { -50.0 } else { 0.0 };
+        Some(p.y - height)
+    }
+
+    #[test]
+    fn terrain_hit_finds_the_cliff_face() {
+        let hit = terrain_hit(Vec3::new(0.0, -10.0, 0.0), Dir3::X, 100.0, cliff);
+        let hit = hit.expect("the ray runs into the cliff");
+        assert!((38.0..=40.0).contains(&hit), "hit at {hit}");
+
+        assert_eq!(
+            terrain_hit(Vec3::new(0.0, -10.0, 0.0), Dir3::X, 30.0, cliff),
+            None
+        );
+    }
+
+    #[test]
+    fn terrain_hit_ignores_a_ray_starting_underground() {
+        assert_eq!(
+            terrain_hit(Vec3::new(50.0, -10.0, 0.0), Dir3::X, 30.0, cliff),
+            None
+        );
+    }
+
+    #[test]
+    fn occluder_hit_skips_boxes_around_the_origin() {
+        let ray = RayCast3d::new(Vec3::ZERO, Dir3::X, 100.0);
+        let around_origin = Aabb3d::new(Vec3::ZERO, Vec3::splat(5.0));
+        let wall = Aabb3d::new(Vec3::new(60.0, 0.0, 0.0), Vec3::new(2.0, 20.0, 20.0));
+        let behind = Aabb3d::new(Vec3::new(-60.0, 0.0, 0.0), Vec3::splat(5.0));
+
+        let hit = occluder_hit(&ray, [around_origin, wall, behind]);
+        assert_eq!(hit, Some(58.0));
+        assert_eq!(occluder_hit(&ray, [around_origin, behind]), None);
+    }
+
+    #[test]
+    fn world_aabb_follows_translation_and_scale() {
+        let aabb = Aabb {
+            center: Vec3A::new(1.0, 0.0, 0.0),
+            half_extents: Vec3A::splat(1.0),
+        };
+        let transform = GlobalTransform::from(
+            Transform::from_xyz(10.0, 0.0, 0.0).with_scale(Vec3::new(2.0, 1.0, 1.0)),
+        );
+        let world = world_aabb(&aabb, &transform);
+        assert_eq!(world.min, Vec3A::new(10.0, -1.0, -1.0));
+        assert_eq!(world.max, Vec3A::new(14.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn pulled_in_distance_keeps_clearance_and_a_minimum() {
+        assert_eq!(pulled_in_distance(50.0, 200.0), 50.0 - CLEARANCE);
+        assert_eq!(pulled_in_distance(1.0, 200.0), MIN_DISTANCE);
+        assert_eq!(pulled_in_distance(1.0, 10.0), 10.0);
+    }
+}
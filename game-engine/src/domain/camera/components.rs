@@ -56,8 +56,8 @@ impl CameraFollowTarget {
 /// - `rotation_sensitivity`: Degrees per pixel for rotation (0.3 recommended)
 /// - `yaw`: Current horizontal rotation in radians (0.0 = facing north)
 /// - `pitch`: Current vertical rotation in radians (+45° default, looking down; -Y up)
-/// - `min_pitch`: Minimum pitch angle to prevent camera flipping
-/// - `max_pitch`: Maximum pitch angle to prevent camera flipping
+/// - `min_pitch`: Minimum pitch angle (flattest view)
+/// - `max_pitch`: Maximum pitch angle (steepest view)
 ///
 /// # Smoothing Algorithm
 /// Uses split-axis exponential decay interpolation:
@@ -104,10 +104,10 @@ pub struct CameraFollowSettings {
     /// With -Y up, positive = camera above looking down (RO style: +45° default).
     pub pitch: f32,
 
-    /// Minimum pitch angle in radians (prevents camera flipping)
+    /// Minimum pitch angle in radians (flattest allowed view)
     pub min_pitch: f32,
 
-    /// Maximum pitch angle in radians (prevents camera flipping)
+    /// Maximum pitch angle in radians (steepest allowed view)
     pub max_pitch: f32,
 }
 
@@ -142,9 +142,48 @@ impl Default for CameraFollowSettings {
             yaw: 0.0,
             pitch: PI / 4.0, // +45 degrees in radians
 
-            // Pitch limits to prevent gimbal lock (±89 degrees)
-            min_pitch: -89.0 * PI / 180.0,
-            max_pitch: 89.0 * PI / 180.0,
+            // Original-client tilt range; `CameraControls` widens it for free look
+            min_pitch: 30.0 * PI / 180.0,
+            max_pitch: 60.0 * PI / 180.0,
         }
     }
 }
+
+/// The angles and distance the camera is actually showing. Input and presets
+/// move `CameraFollowSettings` instantly; the view eases after them so rotating,
+/// zooming and the R reset glide instead of snapping.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CameraView {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+}
+
+impl CameraView {
+    /// Start out showing exactly what `settings` asks for.
+    pub fn from_settings(settings: &CameraFollowSettings) -> Self {
+        Self {
+            yaw: settings.yaw,
+            pitch: settings.pitch,
+            distance: settings.offset.length(),
+        }
+    }
+
+    /// Move a `decay` fraction of the way toward `settings`. Yaw turns the
+    /// short way round, so a reset after several spins doesn't unwind them.
+    pub fn ease_toward(&mut self, settings: &CameraFollowSettings, decay: f32) {
+        use std::f32::consts::{PI, TAU};
+
+        let yaw_gap = (settings.yaw - self.yaw + PI).rem_euclid(TAU) - PI;
+        self.yaw += yaw_gap * decay;
+        self.pitch = self.pitch.lerp(settings.pitch, decay);
+        self.distance = self.distance.lerp(settings.offset.length(), decay);
+    }
+}
+
+/// Map geometry the camera may not pass through. Tagged on map model meshes;
+/// their bounding boxes block the line from the player to the camera.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct CameraOccluder;
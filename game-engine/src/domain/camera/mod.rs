@@ -1,15 +1,29 @@
+pub mod collision;
 pub mod components;
 pub mod resources;
+pub mod sequence;
 pub mod systems;
 
 use bevy::prelude::*;
 
-pub use resources::CameraRotationDelta;
+pub use resources::{CameraControls, CameraRotationDelta};
+pub use sequence::{
+    CameraSequence, CameraSequenceFinished, CameraSequencePlayer, CinematicFade,
+    PlayCameraSequence, SequenceStep, StopCameraSequence,
+};
 pub use systems::CameraSpawned;
 
 use crate::core::state::GameState;
+use crate::domain::system_sets::CameraSystems;
+use collision::resolve_camera_collision;
 use resources::{ActiveCameraProfile, IndoorMapTable};
-use systems::{apply_camera_map_profile, load_indoor_map_table, spawn_camera_on_player_ready};
+use sequence::{
+    advance_camera_sequence, handle_camera_sequence_requests, stop_camera_sequence_on_exit,
+};
+use systems::{
+    apply_camera_controls, apply_camera_map_profile, camera_follow_system, load_indoor_map_table,
+    spawn_camera_on_player_ready,
+};
 
 pub struct CameraPlugin;
 
@@ -17,8 +31,14 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CameraSpawned>();
         app.init_resource::<CameraRotationDelta>();
+        app.init_resource::<CameraControls>();
         app.init_resource::<IndoorMapTable>();
         app.init_resource::<ActiveCameraProfile>();
+        app.init_resource::<CameraSequencePlayer>();
+        app.init_resource::<CinematicFade>();
+        app.add_message::<PlayCameraSequence>();
+        app.add_message::<StopCameraSequence>();
+        app.add_message::<CameraSequenceFinished>();
         app.add_systems(Startup, load_indoor_map_table);
         app.add_systems(
             PostUpdate,
@@ -26,7 +46,22 @@ impl Plugin for CameraPlugin {
         );
         app.add_systems(
             Update,
-            apply_camera_map_profile.run_if(in_state(GameState::InGame)),
+            (apply_camera_map_profile, apply_camera_controls)
+                .before(CameraSystems::Follow)
+                .run_if(in_state(GameState::InGame)),
         );
+        app.add_systems(
+            Update,
+            resolve_camera_collision
+                .in_set(CameraSystems::Follow)
+                .after(camera_follow_system),
+        );
+        app.add_systems(
+            Update,
+            (handle_camera_sequence_requests, advance_camera_sequence)
+                .chain()
+                .in_set(CameraSystems::Sequence),
+        );
+        app.add_systems(OnExit(GameState::InGame), stop_camera_sequence_on_exit);
     }
 }
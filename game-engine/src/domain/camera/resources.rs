@@ -21,6 +21,53 @@ pub struct ActiveCameraProfile {
     pub indoor: bool,
 }
 
+/// Player-tunable camera limits, mirrored from the persisted `Settings.camera`
+/// by the settings layer. Angles are in radians.
+///
+/// The zoom range applies to outdoor maps (indoor maps keep their own tighter
+/// preset). `free_look` lifts the pitch limits so the camera can swing from
+/// nearly level to straight down.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CameraControls {
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// How fast the view eases toward a new rotation or zoom; 0 snaps.
+    pub view_smoothing_speed: f32,
+    pub free_look: bool,
+}
+
+impl CameraControls {
+    /// Free-look pitch range: short of level and of straight down, where the
+    /// look-at basis degenerates.
+    pub const FREE_LOOK_PITCH: (f32, f32) = (5.0_f32.to_radians(), 85.0_f32.to_radians());
+
+    /// The pitch range currently in force.
+    pub fn pitch_range(&self) -> (f32, f32) {
+        if self.free_look {
+            Self::FREE_LOOK_PITCH
+        } else {
+            (self.min_pitch, self.max_pitch)
+        }
+    }
+}
+
+impl Default for CameraControls {
+    /// The original client's feel: a 45° view that tilts only between 30° and
+    /// 60°, zooming between 100 and 250 units.
+    fn default() -> Self {
+        Self {
+            min_distance: 100.0,
+            max_distance: 250.0,
+            min_pitch: 30.0_f32.to_radians(),
+            max_pitch: 60.0_f32.to_radians(),
+            view_smoothing_speed: 10.0,
+            free_look: false,
+        }
+    }
+}
+
 /// Resource that accumulates camera rotation deltas from mouse input.
 ///
 /// # Purpose
@@ -0,0 +1,421 @@
+//! Scripted camera sequences (intros, cutscene-style server events).
+//!
+//! A [`CameraSequence`] is an ordered list of [`SequenceStep`]s played one after
+//! another on the follow camera: fly along a spline, frame an entity, fade the
+//! screen, or hold the shot. Anything can start one by writing
+//! [`PlayCameraSequence`]; a network adapter with scripted-camera packets can map
+//! them onto the same message. While a sequence plays, `camera_follow_system`
+//! stands down and resumes (smoothly, from wherever the sequence left the camera)
+//! once it ends.
+//!
+//! The engine only tracks the fade level in [`CinematicFade`]; the UI layer draws
+//! the overlay.
+
+use bevy::prelude::*;
+
+use super::components::CameraFollowSettings;
+use crate::domain::input::UiFocus;
+
+/// One shot of a [`CameraSequence`]. Durations are in seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceStep {
+    /// Fly through every point of `points` (Catmull-Rom) while looking at `look_at`.
+    Spline {
+        points: Vec<Vec3>,
+        look_at: Vec3,
+        duration: f32,
+    },
+    /// Glide to `distance` from `entity`, keeping the camera's current bearing.
+    Focus {
+        entity: Entity,
+        distance: f32,
+        duration: f32,
+    },
+    /// Fade the screen overlay to `alpha` (0 = clear, 1 = black).
+    Fade { alpha: f32, duration: f32 },
+    /// Hold the current shot.
+    Wait { duration: f32 },
+}
+
+impl SequenceStep {
+    fn duration(&self) -> f32 {
+        match self {
+            Self::Spline { duration, .. }
+            | Self::Focus { duration, .. }
+            | Self::Fade { duration, .. }
+            | Self::Wait { duration } => *duration,
+        }
+    }
+}
+
+/// An ordered camera script, built step by step:
+///
+/// ```ignore
+/// CameraSequence::default()
+///     .lock_input()
+///     .fade(1.0, 0.0)
+///     .spline(vec![a, b, c], town_center, 6.0)
+///     .fade(0.0, 1.0)
+///     .focus(npc, 80.0, 1.5)
+///     .wait(2.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CameraSequence {
+    pub steps: Vec<SequenceStep>,
+    /// Suppress gameplay input (clicks, hotkeys, camera drag) until the sequence ends.
+    pub lock_input: bool,
+}
+
+impl CameraSequence {
+    pub fn lock_input(mut self) -> Self {
+        self.lock_input = true;
+        self
+    }
+
+    pub fn spline(mut self, points: Vec<Vec3>, look_at: Vec3, duration: f32) -> Self {
+        self.steps.push(SequenceStep::Spline {
+            points,
+            look_at,
+            duration,
+        });
+        self
+    }
+
+    pub fn focus(mut self, entity: Entity, distance: f32, duration: f32) -> Self {
+        self.steps.push(SequenceStep::Focus {
+            entity,
+            distance,
+            duration,
+        });
+        self
+    }
+
+    pub fn fade(mut self, alpha: f32, duration: f32) -> Self {
+        self.steps.push(SequenceStep::Fade { alpha, duration });
+        self
+    }
+
+    pub fn wait(mut self, duration: f32) -> Self {
+        self.steps.push(SequenceStep::Wait { duration });
+        self
+    }
+}
+
+/// Start `sequence`, replacing any sequence already playing.
+#[derive(Message, Debug, Clone)]
+pub struct PlayCameraSequence {
+    pub sequence: CameraSequence,
+}
+
+/// Abort the playing sequence and hand the camera back to the player.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct StopCameraSequence;
+
+/// A sequence ended; `interrupted` when it was stopped or replaced early.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CameraSequenceFinished {
+    pub interrupted: bool,
+}
+
+/// Current cinematic fade level, mirrored onto a full-screen overlay by the UI.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CinematicFade {
+    pub alpha: f32,
+}
+
+/// The sequence currently driving the camera, if any.
+#[derive(Resource, Debug, Default)]
+pub struct CameraSequencePlayer {
+    active: Option<ActiveSequence>,
+}
+
+impl CameraSequencePlayer {
+    pub fn is_playing(&self) -> bool {
+        self.active.is_some()
+    }
+}
+
+#[derive(Debug)]
+struct ActiveSequence {
+    sequence: CameraSequence,
+    step: usize,
+    elapsed: f32,
+    /// Camera pose and fade level when the current step started.
+    from: Option<(Transform, f32)>,
+}
+
+/// Smoothstep easing so camera moves start and stop gently.
+fn ease(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Uniform Catmull-Rom position at `t` (0..=1 across the whole path). The curve
+/// passes through every point; the end points are mirrored as phantom controls.
+fn catmull_rom(points: &[Vec3], t: f32) -> Option<Vec3> {
+    let (&first, &last) = (points.first()?, points.last()?);
+    if points.len() == 1 {
+        return Some(first);
+    }
+
+    let segments = points.len() - 1;
+    let scaled = t.clamp(0.0, 1.0) * segments as f32;
+    let index = (scaled.floor() as usize).min(segments - 1);
+    let local = scaled - index as f32;
+
+    let p1 = points[index];
+    let p2 = points[index + 1];
+    let p0 = if index == 0 {
+        2.0 * first - p2
+    } else {
+        points[index - 1]
+    };
+    let p3 = points
+        .get(index + 2)
+        .copied()
+        .unwrap_or_else(|| 2.0 * last - p1);
+
+    let (t2, t3) = (local * local, local * local * local);
+    Some(
+        0.5 * ((2.0 * p1)
+            + (p2 - p0) * local
+            + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+            + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3),
+    )
+}
+
+/// Where the camera should sit to frame `target` from `distance`, keeping the
+/// bearing it currently has (or the default follow angle when it sits on top).
+fn focus_position(camera: Vec3, target: Vec3, distance: f32) -> Vec3 {
+    let direction = (camera - target)
+        .try_normalize()
+        .unwrap_or_else(|| CameraFollowSettings::default().offset.normalize());
+    target + direction * distance
+}
+
+fn end_sequence(
+    player: &mut CameraSequencePlayer,
+    fade: &mut CinematicFade,
+    ui_focus: &mut UiFocus,
+    finished: &mut MessageWriter<CameraSequenceFinished>,
+    interrupted: bool,
+) {
+    if player.active.take().is_none() {
+        return;
+    }
+    fade.alpha = 0.0;
+    ui_focus.cutscene_active = false;
+    finished.write(CameraSequenceFinished { interrupted });
+}
+
+/// Start or stop sequences on request. A new sequence interrupts the one playing.
+pub fn handle_camera_sequence_requests(
+    mut play: MessageReader<PlayCameraSequence>,
+    mut stop: MessageReader<StopCameraSequence>,
+    mut player: ResMut<CameraSequencePlayer>,
+    mut fade: ResMut<CinematicFade>,
+    mut ui_focus: ResMut<UiFocus>,
+    mut finished: MessageWriter<CameraSequenceFinished>,
+) {
+    if !stop.is_empty() {
+        stop.clear();
+        end_sequence(&mut player, &mut fade, &mut ui_focus, &mut finished, true);
+    }
+
+    let Some(request) = play.read().last() else {
+        return;
+    };
+    end_sequence(&mut player, &mut fade, &mut ui_focus, &mut finished, true);
+
+    debug!(
+        "Starting camera sequence: {} step(s), input locked: {}",
+        request.sequence.steps.len(),
+        request.sequence.lock_input
+    );
+    ui_focus.cutscene_active = request.sequence.lock_input;
+    player.active = Some(ActiveSequence {
+        sequence: request.sequence.clone(),
+        step: 0,
+        elapsed: 0.0,
+        from: None,
+    });
+}
+
+/// Play the current step on the follow camera and advance through the script.
+/// Waits for the camera to exist, so a sequence requested on map entry starts
+/// once the camera spawns.
+pub fn advance_camera_sequence(
+    time: Res<Time>,
+    mut player: ResMut<CameraSequencePlayer>,
+    mut fade: ResMut<CinematicFade>,
+    mut ui_focus: ResMut<UiFocus>,
+    mut finished: MessageWriter<CameraSequenceFinished>,
+    mut cameras: Query<&mut Transform, (With<Camera3d>, With<CameraFollowSettings>)>,
+    targets: Query<&GlobalTransform, Without<CameraFollowSettings>>,
+) {
+    let Some(active) = player.active.as_mut() else {
+        return;
+    };
+    let Ok(mut camera) = cameras.single_mut() else {
+        return;
+    };
+
+    let Some(step) = active.sequence.steps.get(active.step) else {
+        end_sequence(&mut player, &mut fade, &mut ui_focus, &mut finished, false);
+        return;
+    };
+
+    let (from_pose, from_alpha) = *active.from.get_or_insert((*camera, fade.alpha));
+    active.elapsed += time.delta_secs();
+    let duration = step.duration();
+    let progress = if duration > 0.0 {
+        (active.elapsed / duration).min(1.0)
+    } else {
+        1.0
+    };
+
+    match step {
+        SequenceStep::Spline {
+            points, look_at, ..
+        } => {
+            if let Some(position) = catmull_rom(points, ease(progress)) {
+                *camera = Transform::from_translation(position).looking_at(*look_at, Vec3::NEG_Y);
+            }
+        }
+        SequenceStep::Focus {
+            entity, distance, ..
+        } => match targets.get(*entity) {
+            Ok(target) => {
+                let target = target.translation();
+                let end = focus_position(from_pose.translation, target, *distance);
+                let position = from_pose.translation.lerp(end, ease(progress));
+                *camera = Transform::from_translation(position).looking_at(target, Vec3::NEG_Y);
+            }
+            Err(_) => {
+                warn!("Camera sequence focus target {entity:?} is gone; skipping step");
+                active.elapsed = duration;
+            }
+        },
+        SequenceStep::Fade { alpha, .. } => {
+            fade.alpha = from_alpha.lerp(alpha.clamp(0.0, 1.0), progress);
+        }
+        SequenceStep::Wait { .. } => {}
+    }
+
+    if active.elapsed >= duration {
+        active.step += 1;
+        active.elapsed = 0.0;
+        active.from = None;
+    }
+}
+
+/// Leaving the world aborts the sequence so the fade and input lock can't linger.
+pub fn stop_camera_sequence_on_exit(
+    mut player: ResMut<CameraSequencePlayer>,
+    mut fade: ResMut<CinematicFade>,
+    mut ui_focus: ResMut<UiFocus>,
+    mut finished: MessageWriter<CameraSequenceFinished>,
+) {
+    end_sequence(&mut player, &mut fade, &mut ui_focus, &mut finished, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn catmull_rom_passes_through_every_point() {
+        let points = [
+            Vec3::ZERO,
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 10.0),
+        ];
+        assert_eq!(catmull_rom(&points, 0.0), Some(points[0]));
+        assert!(catmull_rom(&points, 0.5).unwrap().distance(points[1]) < 1e-4);
+        assert!(catmull_rom(&points, 1.0).unwrap().distance(points[2]) < 1e-4);
+        assert_eq!(catmull_rom(&[], 0.5), None);
+    }
+
+    #[test]
+    fn focus_keeps_bearing_at_requested_distance() {
+        let position = focus_position(Vec3::new(0.0, -300.0, 0.0), Vec3::ZERO, 100.0);
+        assert!(position.distance(Vec3::new(0.0, -100.0, 0.0)) < 1e-4);
+    }
+
+    fn sequence_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<CameraSequencePlayer>()
+            .init_resource::<CinematicFade>()
+            .init_resource::<UiFocus>()
+            .add_message::<PlayCameraSequence>()
+            .add_message::<StopCameraSequence>()
+            .add_message::<CameraSequenceFinished>()
+            .add_systems(
+                Update,
+                (handle_camera_sequence_requests, advance_camera_sequence).chain(),
+            );
+        app.world_mut().spawn((
+            Camera3d::default(),
+            Transform::default(),
+            CameraFollowSettings::default(),
+        ));
+        app
+    }
+
+    fn step_secs(app: &mut App, secs: f32) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            secs,
+        )));
+        app.update();
+    }
+
+    #[test]
+    fn sequence_locks_input_fades_and_finishes() {
+        let mut app = sequence_app();
+        app.world_mut().write_message(PlayCameraSequence {
+            sequence: CameraSequence::default().lock_input().fade(1.0, 0.2),
+        });
+        step_secs(&mut app, 0.0);
+        assert!(app.world().resource::<UiFocus>().cutscene_active);
+        assert!(app.world().resource::<CameraSequencePlayer>().is_playing());
+
+        step_secs(&mut app, 0.1);
+        let alpha = app.world().resource::<CinematicFade>().alpha;
+        assert!(alpha > 0.0 && alpha < 1.0);
+
+        step_secs(&mut app, 0.1);
+        step_secs(&mut app, 0.0);
+        assert!(!app.world().resource::<CameraSequencePlayer>().is_playing());
+        assert!(!app.world().resource::<UiFocus>().cutscene_active);
+        assert_eq!(app.world().resource::<CinematicFade>().alpha, 0.0);
+        let finished: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<CameraSequenceFinished>>()
+            .drain()
+            .collect();
+        assert_eq!(finished, [CameraSequenceFinished { interrupted: false }]);
+    }
+
+    #[test]
+    fn stop_interrupts_the_playing_sequence() {
+        let mut app = sequence_app();
+        app.world_mut().write_message(PlayCameraSequence {
+            sequence: CameraSequence::default().lock_input().wait(10.0),
+        });
+        app.update();
+        app.world_mut().write_message(StopCameraSequence);
+        app.update();
+
+        assert!(!app.world().resource::<CameraSequencePlayer>().is_playing());
+        assert!(!app.world().resource::<UiFocus>().cutscene_active);
+        let finished: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<CameraSequenceFinished>>()
+            .drain()
+            .collect();
+        assert_eq!(finished, [CameraSequenceFinished { interrupted: true }]);
+    }
+}
@@ -2,8 +2,9 @@ use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 
-use super::components::{CameraFollowSettings, CameraFollowTarget};
-use super::resources::{ActiveCameraProfile, CameraRotationDelta, IndoorMapTable};
+use super::components::{CameraFollowSettings, CameraFollowTarget, CameraView};
+use super::resources::{ActiveCameraProfile, CameraControls, CameraRotationDelta, IndoorMapTable};
+use super::sequence::CameraSequencePlayer;
 use crate::domain::entities::markers::LocalPlayer;
 use crate::domain::input::UiFocus;
 use crate::domain::system_sets::CameraSystems;
@@ -43,31 +44,53 @@ fn normalize_map_name(map_name: &str) -> String {
 
 /// Apply the indoor or outdoor camera preset to the follow settings.
 /// Both presets reset yaw/pitch to the default diagonal and recompute the offset.
-fn apply_camera_profile(settings: &mut CameraFollowSettings, indoor: bool) {
+fn apply_camera_profile(
+    settings: &mut CameraFollowSettings,
+    indoor: bool,
+    controls: &CameraControls,
+) {
     let defaults = CameraFollowSettings::default();
     settings.rotation_locked = indoor;
     settings.pitch = defaults.pitch;
 
-    let (yaw, min, max, distance) = if indoor {
-        (
-            INDOOR_YAW,
-            INDOOR_MIN_DISTANCE,
-            INDOOR_MAX_DISTANCE,
-            INDOOR_DISTANCE,
-        )
+    let (yaw, distance) = if indoor {
+        settings.min_distance = INDOOR_MIN_DISTANCE;
+        settings.max_distance = INDOOR_MAX_DISTANCE;
+        (INDOOR_YAW, INDOOR_DISTANCE)
     } else {
-        (
-            defaults.yaw,
-            defaults.min_distance,
-            defaults.max_distance,
-            defaults.offset.length(),
-        )
+        (defaults.yaw, defaults.offset.length())
     };
 
     settings.yaw = yaw;
-    settings.min_distance = min;
-    settings.max_distance = max;
     settings.offset = offset_from_angles(yaw, settings.pitch, distance);
+    apply_controls(settings, controls, indoor);
+}
+
+/// Impose the player's pitch range (and, outdoors, zoom range) on the follow
+/// settings, pulling the current pitch and distance inside them.
+fn apply_controls(settings: &mut CameraFollowSettings, controls: &CameraControls, indoor: bool) {
+    let (min_pitch, max_pitch) = controls.pitch_range();
+    settings.min_pitch = min_pitch;
+    settings.max_pitch = max_pitch;
+    settings.pitch = settings.pitch.clamp(min_pitch, max_pitch);
+
+    if !indoor {
+        settings.min_distance = controls.min_distance;
+        settings.max_distance = controls.max_distance;
+    }
+    let distance = settings
+        .offset
+        .length()
+        .clamp(settings.min_distance, settings.max_distance);
+    settings.offset = offset_from_angles(settings.yaw, settings.pitch, distance);
+}
+
+/// Fraction of the remaining gap to close this frame at `speed`; 0 snaps.
+fn smoothing_decay(speed: f32, delta: f32) -> f32 {
+    if speed <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - (-speed * delta).exp()).clamp(0.0, 1.0)
 }
 
 // =============================================================================
@@ -138,6 +161,7 @@ pub fn spawn_camera_on_player_ready(
         // layer (apply_camera_effects) per the graphics settings.
         Transform::from_translation(camera_position).looking_at(player_position, Vec3::NEG_Y),
         CameraFollowTarget::new(player_entity, player_position),
+        CameraView::from_settings(&settings),
         settings,
         // Opts this camera into mesh picking (MeshPickingSettings::require_markers).
         bevy::picking::mesh_picking::MeshPickingCamera,
@@ -172,6 +196,8 @@ pub fn update_camera_target_cache(
 }
 
 /// Main camera follow system with smooth interpolation, zoom control, and rotation.
+/// Stands down while a camera sequence drives the camera.
+#[allow(clippy::too_many_arguments)]
 #[auto_add_system(
     plugin = crate::LifthrasirPlugin,
     schedule = Update,
@@ -184,18 +210,29 @@ pub fn camera_follow_system(
     mut mouse_wheel_events: MessageReader<MouseWheel>,
     mut rotation_delta: ResMut<CameraRotationDelta>,
     active_profile: Res<ActiveCameraProfile>,
+    controls: Res<CameraControls>,
+    sequence: Res<CameraSequencePlayer>,
     mut camera_query: Query<
         (
             &mut Transform,
             &mut CameraFollowTarget,
             &mut CameraFollowSettings,
+            &mut CameraView,
         ),
         With<Camera3d>,
     >,
 ) {
+    if sequence.is_playing() {
+        // Drop input gathered during the sequence so it can't jolt the camera after.
+        rotation_delta.clear();
+        mouse_wheel_events.clear();
+        return;
+    }
+
     let delta = time.delta_secs();
 
-    for (mut camera_transform, mut follow_target, mut settings) in camera_query.iter_mut() {
+    for (mut camera_transform, mut follow_target, mut settings, mut view) in camera_query.iter_mut()
+    {
         let target_position = follow_target.cached_position;
 
         // Camera rotation (right-click drag) — disabled on indoor maps. The delta is
@@ -261,7 +298,7 @@ pub fn camera_follow_system(
         // Reset zoom and rotation (R key) — respects the active map profile so it
         // can't unlock the camera on an indoor map.
         if !ui_focus.text_input_active && keyboard_input.just_pressed(KeyCode::KeyR) {
-            apply_camera_profile(&mut settings, active_profile.indoor);
+            apply_camera_profile(&mut settings, active_profile.indoor, &controls);
             debug!(
                 "Camera reset to {} profile: yaw={:.2}deg, pitch={:.2}deg, distance={:.1}",
                 if active_profile.indoor {
@@ -275,8 +312,15 @@ pub fn camera_follow_system(
             );
         }
 
+        // Ease the view toward the requested rotation and zoom
+        view.ease_toward(
+            &settings,
+            smoothing_decay(controls.view_smoothing_speed, delta),
+        );
+
         // Smooth follow
-        let desired_position = target_position + settings.offset;
+        let desired_position =
+            target_position + offset_from_angles(view.yaw, view.pitch, view.distance);
         let current_position = camera_transform.translation;
 
         let decay_horizontal = 1.0 - (-settings.horizontal_smoothing_speed * delta).exp();
@@ -351,6 +395,7 @@ pub fn apply_camera_map_profile(
     indoor_table: Res<IndoorMapTable>,
     table_assets: Res<Assets<IndoorMapTableAsset>>,
     mut active_profile: ResMut<ActiveCameraProfile>,
+    controls: Res<CameraControls>,
     mut camera_query: Query<&mut CameraFollowSettings, With<Camera3d>>,
 ) {
     let Some(spawn_context) = spawn_context else {
@@ -373,7 +418,7 @@ pub fn apply_camera_map_profile(
 
     let mut applied = false;
     for mut settings in camera_query.iter_mut() {
-        apply_camera_profile(&mut settings, indoor);
+        apply_camera_profile(&mut settings, indoor, &controls);
         applied = true;
     }
 
@@ -387,3 +432,87 @@ pub fn apply_camera_map_profile(
         active_profile.indoor = indoor;
     }
 }
+
+/// Re-apply the player's camera limits whenever they change, and to a freshly
+/// spawned camera.
+pub fn apply_camera_controls(
+    controls: Res<CameraControls>,
+    active_profile: Res<ActiveCameraProfile>,
+    new_cameras: Query<(), Added<CameraFollowSettings>>,
+    mut camera_query: Query<&mut CameraFollowSettings, With<Camera3d>>,
+) {
+    if !controls.is_changed() && new_cameras.is_empty() {
+        return;
+    }
+    for mut settings in &mut camera_query {
+        apply_controls(&mut settings, &controls, active_profile.indoor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn controls_clamp_pitch_and_outdoor_zoom() {
+        let controls = CameraControls {
+            min_distance: 120.0,
+            max_distance: 160.0,
+            ..Default::default()
+        };
+        let mut settings = CameraFollowSettings {
+            pitch: 80.0_f32.to_radians(),
+            ..Default::default()
+        };
+
+        apply_controls(&mut settings, &controls, false);
+
+        assert_eq!(settings.pitch, controls.max_pitch);
+        assert!((settings.offset.length() - 160.0).abs() < 1e-3);
+        assert_eq!(settings.min_distance, 120.0);
+    }
+
+    #[test]
+    fn free_look_widens_the_pitch_range() {
+        let controls = CameraControls {
+            free_look: true,
+            ..Default::default()
+        };
+        let mut settings = CameraFollowSettings {
+            pitch: 80.0_f32.to_radians(),
+            ..Default::default()
+        };
+
+        apply_controls(&mut settings, &controls, false);
+
+        assert_eq!(settings.pitch, 80.0_f32.to_radians());
+        assert_eq!(settings.max_pitch, CameraControls::FREE_LOOK_PITCH.1);
+    }
+
+    #[test]
+    fn indoor_profile_keeps_its_own_zoom_range() {
+        let mut settings = CameraFollowSettings::default();
+        apply_camera_profile(&mut settings, true, &CameraControls::default());
+
+        assert_eq!(settings.min_distance, INDOOR_MIN_DISTANCE);
+        assert_eq!(settings.max_distance, INDOOR_MAX_DISTANCE);
+        assert!(settings.rotation_locked);
+    }
+
+    #[test]
+    fn view_eases_toward_the_settings_the_short_way_round() {
+        use std::f32::consts::TAU;
+
+        let settings = CameraFollowSettings::default();
+        let mut view = CameraView {
+            yaw: settings.yaw + 2.0 * TAU + 0.2,
+            ..CameraView::from_settings(&settings)
+        };
+
+        view.ease_toward(&settings, 0.5);
+        assert!((view.yaw - (settings.yaw + 2.0 * TAU + 0.1)).abs() < 1e-4);
+
+        view.ease_toward(&settings, smoothing_decay(0.0, 0.016));
+        assert!((view.yaw - (settings.yaw + 2.0 * TAU)).abs() < 1e-4);
+    }
+}
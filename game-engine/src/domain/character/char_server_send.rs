@@ -1,14 +1,14 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use net_contract::commands::{
-    CreateCharacter, DeleteCharacter, RefreshCharacterList, SelectCharacter,
+    CreateCharacter, DeleteCharacter, RefreshCharacterList, RenameCharacter, SelectCharacter,
 };
 
 use crate::domain::character::events::{
-    CreateCharacterRequestEvent, DeleteCharacterRequestEvent, RefreshCharacterListEvent,
-    SelectCharacterEvent,
+    CharacterRenameFailedEvent, CreateCharacterRequestEvent, DeleteCharacterRequestEvent,
+    RefreshCharacterListEvent, RenameCharacterRequestEvent, SelectCharacterEvent,
 };
-use crate::domain::character::forms::CharacterCreationForm;
+use crate::domain::character::forms::{CharacterCreationForm, validate_character_name};
 
 /// Flattens a validated creation form into the primitive `CreateCharacter` command.
 fn form_to_create_character(form: &CharacterCreationForm) -> CreateCharacter {
@@ -74,6 +74,34 @@ pub fn char_send_delete(
     }
 }
 
+/// Bridges a UI rename request onto the outbound `RenameCharacter` command.
+///
+/// The new name goes through the creation name rules first; a rejected name is
+/// reported back as a failure without reaching the server.
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update
+)]
+pub fn char_send_rename(
+    mut events: MessageReader<RenameCharacterRequestEvent>,
+    mut commands: MessageWriter<RenameCharacter>,
+    mut failures: MessageWriter<CharacterRenameFailedEvent>,
+) {
+    for ev in events.read() {
+        if let Err(e) = validate_character_name(&ev.new_name) {
+            failures.write(CharacterRenameFailedEvent {
+                character_id: ev.character_id,
+                error: e.to_string(),
+            });
+            continue;
+        }
+        commands.write(RenameCharacter {
+            char_id: ev.character_id,
+            new_name: ev.new_name.clone(),
+        });
+    }
+}
+
 /// Bridges a UI refresh request onto the outbound `RefreshCharacterList` command.
 #[auto_add_system(
     plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
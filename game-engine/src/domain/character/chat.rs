@@ -6,6 +6,9 @@
 //! `send::social` system turns that into a `ChatRequest` on the QUIC GAMEPLAY
 //! channel. Incoming chat arrives separately as `ChatHeard` (read by the UI).
 //!
+//! GM `@` and `#` commands are plain chat lines too: rAthena strips the
+//! `"<name> : "` prefix and runs them, so they take the same path unchanged.
+//!
 //! This was previously the Tauri bridge's `handle_chat_request`; it now lives in
 //! the engine so the native UI only has to emit a plain event.
 
@@ -61,6 +64,15 @@ mod tests {
         assert_eq!(format_chat_message("Hero", "hello"), "Hero : hello");
     }
 
+    #[test]
+    fn at_commands_go_out_as_chat() {
+        assert_eq!(
+            format_chat_message("GM", "@warp prontera 150 150"),
+            "GM : @warp prontera 150 150"
+        );
+        assert_eq!(format_chat_message("GM", "#heal Hero"), "GM : #heal Hero");
+    }
+
     #[test]
     fn preserves_message_spacing() {
         assert_eq!(
@@ -0,0 +1,263 @@
+//! Session chat history.
+//!
+//! Every chat line the player has seen is kept in [`ChatHistory`], tagged with
+//! the [`ChatChannel`] it arrived on, so the chat box can build its tabs from
+//! one log instead of its rendered text nodes. The log is capped at
+//! [`ChatHistory::capacity`] lines. It survives warps, which cycle `InGame`,
+//! and is cleared when the player goes back to character selection.
+//!
+//! aesir delivers public chat (`ChatHeard`) and server announcements
+//! (`AnnouncementReceived`) today; the other channels are recorded by whoever
+//! produces them through [`ChatHistory::push`].
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use net_contract::events::{AnnouncementReceived, ChatHeard};
+
+use crate::core::state::GameState;
+
+/// Lines kept before the oldest are dropped.
+pub const DEFAULT_CHAT_HISTORY_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatChannel {
+    Public,
+    Whisper,
+    Party,
+    Guild,
+    /// Server broadcasts and client notices.
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatEntry {
+    pub channel: ChatChannel,
+    /// The speaker's gid, when the line came from an entity.
+    pub sender: Option<u32>,
+    pub text: String,
+    /// `0xRRGGBB` the server asked for; `None` uses the channel's color.
+    pub color: Option<u32>,
+    /// `Time::elapsed` when the line arrived.
+    pub received_at: Duration,
+}
+
+/// Which lines a chat tab shows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChatFilter {
+    /// Channels to include; empty includes every channel.
+    pub channels: Vec<ChatChannel>,
+    /// Case-insensitive substring the text must contain.
+    pub containing: Option<String>,
+}
+
+impl ChatFilter {
+    pub fn channels(channels: impl IntoIterator<Item = ChatChannel>) -> Self {
+        Self {
+            channels: channels.into_iter().collect(),
+            containing: None,
+        }
+    }
+
+    pub fn matches(&self, entry: &ChatEntry) -> bool {
+        (self.channels.is_empty() || self.channels.contains(&entry.channel))
+            && self
+                .containing
+                .as_deref()
+                .is_none_or(|needle| entry.text.to_lowercase().contains(&needle.to_lowercase()))
+    }
+}
+
+#[derive(Resource, Debug)]
+#[auto_init_resource(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct ChatHistory {
+    entries: VecDeque<ChatEntry>,
+    capacity: usize,
+}
+
+impl Default for ChatHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CHAT_HISTORY_CAPACITY)
+    }
+}
+
+impl ChatHistory {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends `entry`, dropping the oldest line once the cap is reached.
+    pub fn push(&mut self, entry: ChatEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Every line, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &ChatEntry> {
+        self.entries.iter()
+    }
+
+    /// The newest `limit` lines passing `filter`, oldest first.
+    pub fn recent(&self, filter: &ChatFilter, limit: usize) -> Vec<&ChatEntry> {
+        let mut lines: Vec<&ChatEntry> = self
+            .entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .take(limit)
+            .collect();
+        lines.reverse();
+        lines
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(run_if = in_state(GameState::InGame))
+)]
+pub fn record_chat_history(
+    mut heard: MessageReader<ChatHeard>,
+    mut announcements: MessageReader<AnnouncementReceived>,
+    mut history: ResMut<ChatHistory>,
+    time: Res<Time>,
+) {
+    let received_at = time.elapsed();
+    for chat in heard.read() {
+        history.push(ChatEntry {
+            channel: ChatChannel::Public,
+            sender: Some(chat.gid),
+            text: chat.message.clone(),
+            color: None,
+            received_at,
+        });
+    }
+    for announcement in announcements.read() {
+        history.push(ChatEntry {
+            channel: ChatChannel::System,
+            sender: None,
+            text: announcement.text.clone(),
+            color: (announcement.color != 0).then_some(announcement.color),
+            received_at,
+        });
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = OnEnter(GameState::CharacterSelection)
+)]
+pub fn clear_chat_history(mut history: ResMut<ChatHistory>) {
+    history.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(channel: ChatChannel, text: &str) -> ChatEntry {
+        ChatEntry {
+            channel,
+            sender: None,
+            text: text.to_string(),
+            color: None,
+            received_at: Duration::ZERO,
+        }
+    }
+
+    fn texts(lines: Vec<&ChatEntry>) -> Vec<&str> {
+        lines.into_iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn history_drops_the_oldest_lines_past_capacity() {
+        let mut history = ChatHistory::with_capacity(2);
+        for text in ["one", "two", "three"] {
+            history.push(entry(ChatChannel::Public, text));
+        }
+        let lines: Vec<&str> = history.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(lines, ["two", "three"]);
+    }
+
+    #[test]
+    fn recent_filters_by_channel_and_text_and_keeps_order() {
+        let mut history = ChatHistory::default();
+        history.push(entry(ChatChannel::Public, "Selling Red Potion"));
+        history.push(entry(ChatChannel::Party, "heal pls"));
+        history.push(entry(ChatChannel::System, "Server restart in 5 minutes"));
+        history.push(entry(ChatChannel::Party, "omw"));
+
+        let party = ChatFilter::channels([ChatChannel::Party]);
+        assert_eq!(texts(history.recent(&party, 10)), ["heal pls", "omw"]);
+        assert_eq!(texts(history.recent(&party, 1)), ["omw"]);
+
+        let search = ChatFilter {
+            containing: Some("RED".into()),
+            ..Default::default()
+        };
+        assert_eq!(texts(history.recent(&search, 10)), ["Selling Red Potion"]);
+    }
+
+    #[test]
+    fn heard_chat_and_announcements_land_on_their_channels() {
+        use net_contract::events::AnnouncementStyle;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ChatHistory>();
+        app.add_message::<ChatHeard>();
+        app.add_message::<AnnouncementReceived>();
+        app.add_systems(Update, record_chat_history);
+
+        app.world_mut().write_message(ChatHeard {
+            gid: 7,
+            message: "Hero : hi".into(),
+        });
+        app.world_mut().write_message(AnnouncementReceived {
+            text: "Welcome".into(),
+            color: 0,
+            style: AnnouncementStyle::Top,
+            source_name: String::new(),
+        });
+        app.update();
+
+        let history = app.world().resource::<ChatHistory>();
+        let lines: Vec<_> = history
+            .iter()
+            .map(|line| (line.channel, line.sender, line.color))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (ChatChannel::Public, Some(7), None),
+                (ChatChannel::System, None, None)
+            ]
+        );
+    }
+}
@@ -32,6 +32,14 @@ pub struct SelectCharacterEvent {
     pub slot: u8,
 }
 
+/// The pointer entered (`Some(slot)`) or left (`None`) a character-select slot.
+/// Drives the hover cue and lets any overlay sync its highlight with the roster.
+#[derive(Message, Debug, Clone, Copy)]
+#[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct CharacterHoveredEvent {
+    pub slot: Option<u8>,
+}
+
 #[derive(Message, Debug)]
 #[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
 pub struct CreateCharacterRequestEvent {
@@ -54,6 +62,20 @@ pub struct DeleteCharacterRequestEvent {
     pub character_id: u32,
 }
 
+#[derive(Message, Debug)]
+#[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct RenameCharacterRequestEvent {
+    pub character_id: u32,
+    pub new_name: String,
+}
+
+#[derive(Message, Debug)]
+#[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct CharacterRenameFailedEvent {
+    pub character_id: u32,
+    pub error: String,
+}
+
 #[derive(Message, Debug)]
 #[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
 pub struct RefreshCharacterListEvent;
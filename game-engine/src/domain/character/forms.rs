@@ -25,21 +25,26 @@ impl Default for CharacterCreationForm {
 
 impl CharacterCreationForm {
     pub fn validate(&self) -> Result<(), CharacterCreationError> {
-        if self.name.is_empty() {
-            return Err(CharacterCreationError::NameEmpty);
-        }
-        if self.name.len() < 4 {
-            return Err(CharacterCreationError::NameTooShort);
-        }
-        if self.name.len() > 23 {
-            return Err(CharacterCreationError::NameTooLong);
-        }
-        if !self.name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err(CharacterCreationError::NameInvalidCharacters);
-        }
+        validate_character_name(&self.name)
+    }
+}
 
-        Ok(())
+/// Name rules shared by character creation and rename.
+pub fn validate_character_name(name: &str) -> Result<(), CharacterCreationError> {
+    if name.is_empty() {
+        return Err(CharacterCreationError::NameEmpty);
+    }
+    if name.len() < 4 {
+        return Err(CharacterCreationError::NameTooShort);
+    }
+    if name.len() > 23 {
+        return Err(CharacterCreationError::NameTooLong);
     }
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(CharacterCreationError::NameInvalidCharacters);
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
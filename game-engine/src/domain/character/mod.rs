@@ -1,10 +1,12 @@
 pub mod char_server_send;
 pub mod chat;
+pub mod chat_history;
 pub mod events;
 pub mod forms;
 pub mod local_player;
 pub mod map_loading;
 pub mod plugin;
+pub mod preload;
 pub mod selection;
 pub mod zone;
 
@@ -12,5 +14,9 @@ pub use events::*;
 pub use forms::*;
 pub use map_loading::MapLoadingTimer;
 pub use plugin::CharacterDomainPlugin;
+pub use preload::{
+    CharacterSelectionAssetsPreloaded, PreloadAssetKind, PreloadCharacterSelectionAssets,
+    PreloadItem, PreloadOutcome,
+};
 
 pub use crate::domain::entities::character::components::Gender;
@@ -0,0 +1,315 @@
+//! One-shot warm-up of the assets the character-select screen draws.
+//!
+//! `PreloadCharacterSelectionAssets` starts loading every roster character's
+//! body and hair sprites (SPR + ACT) and hair palette in one go. Once each of
+//! them has either loaded or failed, `CharacterSelectionAssetsPreloaded`
+//! reports the outcome item by item, so the screen can say exactly what is
+//! missing instead of showing an empty card. The loaded handles stay held
+//! until the next preload or the end of the session, so the previews built
+//! from the same paths find them already in memory.
+
+use bevy::asset::{LoadState, UntypedHandle};
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use super::events::CharacterListReceivedEvent;
+use super::selection::{DomainCharacterRoster, build_character_list_event};
+use crate::domain::system_sets::CharacterFlowSystems;
+use crate::infrastructure::assets::loaders::{RoActAsset, RoPaletteAsset, RoSpriteAsset};
+use crate::infrastructure::job::registry::JobSpriteRegistry;
+
+/// Warm every asset the current roster needs. A request made while a previous
+/// batch is still loading replaces it; only the latest batch is reported.
+#[derive(Message, Debug, Default)]
+#[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct PreloadCharacterSelectionAssets;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreloadAssetKind {
+    BodySprite,
+    BodyAction,
+    HairSprite,
+    HairAction,
+    HairPalette,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreloadOutcome {
+    Loaded,
+    Failed(String),
+}
+
+/// One asset of the batch and how its load ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadItem {
+    pub slot: u8,
+    pub kind: PreloadAssetKind,
+    pub path: String,
+    pub outcome: PreloadOutcome,
+}
+
+/// Every item of a finished preload, in roster order.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct CharacterSelectionAssetsPreloaded {
+    pub items: Vec<PreloadItem>,
+}
+
+impl CharacterSelectionAssetsPreloaded {
+    pub fn failures(&self) -> impl Iterator<Item = &PreloadItem> {
+        self.items
+            .iter()
+            .filter(|item| item.outcome != PreloadOutcome::Loaded)
+    }
+
+    pub fn all_loaded(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+struct PendingPreload {
+    slot: u8,
+    kind: PreloadAssetKind,
+    path: String,
+    handle: UntypedHandle,
+}
+
+/// The batch in flight, plus the handles of the last finished one.
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin)]
+pub struct CharacterSelectionPreload {
+    pending: Option<Vec<PendingPreload>>,
+    warmed: Vec<UntypedHandle>,
+}
+
+impl CharacterSelectionPreload {
+    pub fn is_loading(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+fn preload_in_flight(preload: Res<CharacterSelectionPreload>) -> bool {
+    preload.is_loading()
+}
+
+/// `foo.spr` -> `foo.act`.
+fn action_path(sprite_path: &str) -> String {
+    let stem = sprite_path.strip_suffix(".spr").unwrap_or(sprite_path);
+    format!("{stem}.act")
+}
+
+/// The assets each occupied slot of `list` needs, in slot order.
+fn preload_items(list: &CharacterListReceivedEvent) -> Vec<(u8, PreloadAssetKind, String)> {
+    let mut items = Vec::new();
+    for (slot, character) in list.characters.iter().enumerate() {
+        let Some(character) = character else {
+            continue;
+        };
+        let slot = slot as u8;
+        items.push((
+            slot,
+            PreloadAssetKind::BodySprite,
+            character.body_sprite_path.clone(),
+        ));
+        items.push((
+            slot,
+            PreloadAssetKind::BodyAction,
+            action_path(&character.body_sprite_path),
+        ));
+        items.push((
+            slot,
+            PreloadAssetKind::HairSprite,
+            character.hair_sprite_path.clone(),
+        ));
+        items.push((
+            slot,
+            PreloadAssetKind::HairAction,
+            action_path(&character.hair_sprite_path),
+        ));
+        if let Some(palette) = &character.hair_palette_path {
+            items.push((slot, PreloadAssetKind::HairPalette, palette.clone()));
+        }
+    }
+    items
+}
+
+/// How a load ended, or `None` while it is still in progress.
+fn settled(state: LoadState) -> Option<PreloadOutcome> {
+    match state {
+        LoadState::Loaded => Some(PreloadOutcome::Loaded),
+        LoadState::Failed(err) => Some(PreloadOutcome::Failed(err.to_string())),
+        LoadState::NotLoaded | LoadState::Loading => None,
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::CharacterList)
+)]
+pub fn start_character_selection_preload(
+    mut requests: MessageReader<PreloadCharacterSelectionAssets>,
+    roster: Res<DomainCharacterRoster>,
+    job_registry: Option<Res<JobSpriteRegistry>>,
+    asset_server: Res<AssetServer>,
+    mut preload: ResMut<CharacterSelectionPreload>,
+) {
+    if requests.read().last().is_none() {
+        return;
+    }
+
+    let list = build_character_list_event(&roster, job_registry.as_deref());
+    let pending = preload_items(&list)
+        .into_iter()
+        .map(|(slot, kind, path)| {
+            let handle = match kind {
+                PreloadAssetKind::BodySprite | PreloadAssetKind::HairSprite => {
+                    asset_server.load::<RoSpriteAsset>(&path).untyped()
+                }
+                PreloadAssetKind::BodyAction | PreloadAssetKind::HairAction => {
+                    asset_server.load::<RoActAsset>(&path).untyped()
+                }
+                PreloadAssetKind::HairPalette => {
+                    asset_server.load::<RoPaletteAsset>(&path).untyped()
+                }
+            };
+            PendingPreload {
+                slot,
+                kind,
+                path,
+                handle,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    debug!("Preloading {} character-select assets", pending.len());
+    preload.pending = Some(pending);
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(
+        in_set = CharacterFlowSystems::CharacterList,
+        after = start_character_selection_preload,
+        run_if = preload_in_flight
+    )
+)]
+pub fn report_character_selection_preload(
+    asset_server: Res<AssetServer>,
+    mut preload: ResMut<CharacterSelectionPreload>,
+    mut reports: MessageWriter<CharacterSelectionAssetsPreloaded>,
+) {
+    let Some(pending) = preload.pending.as_ref() else {
+        return;
+    };
+    let Some(outcomes) = pending
+        .iter()
+        .map(|item| settled(asset_server.load_state(&item.handle)))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
+    let pending = preload.pending.take().unwrap_or_default();
+    let mut items = Vec::with_capacity(pending.len());
+    let mut warmed = Vec::with_capacity(pending.len());
+    for (item, outcome) in pending.into_iter().zip(outcomes) {
+        if let PreloadOutcome::Failed(reason) = &outcome {
+            warn!(
+                "Character-select asset {:?} for slot {} failed to load ({}): {}",
+                item.kind, item.slot, item.path, reason
+            );
+        }
+        items.push(PreloadItem {
+            slot: item.slot,
+            kind: item.kind,
+            path: item.path,
+            outcome,
+        });
+        warmed.push(item.handle);
+    }
+
+    preload.warmed = warmed;
+    reports.write(CharacterSelectionAssetsPreloaded { items });
+}
+
+/// The warmed assets belong to the account whose roster they were for.
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = crate::domain::authentication::teardown::SessionTeardown
+)]
+pub fn clear_character_selection_preload(mut preload: ResMut<CharacterSelectionPreload>) {
+    *preload = CharacterSelectionPreload::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::character::events::CharacterInfoWithJobName;
+
+    fn character(hair_palette_path: Option<&str>) -> CharacterInfoWithJobName {
+        CharacterInfoWithJobName {
+            base: Default::default(),
+            job_name: "Novice".to_string(),
+            body_sprite_path: "ro://data/sprite/body.spr".to_string(),
+            hair_sprite_path: "ro://data/sprite/hair.spr".to_string(),
+            hair_palette_path: hair_palette_path.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn every_occupied_slot_gets_its_sprites_actions_and_palette() {
+        let list = CharacterListReceivedEvent {
+            characters: vec![
+                None,
+                Some(character(Some("ro://data/palette/hair.pal"))),
+                Some(character(None)),
+            ],
+            max_slots: 3,
+            available_slots: 3,
+            display_pages: 1,
+        };
+
+        let items = preload_items(&list);
+        let slot_one = items
+            .iter()
+            .filter(|(slot, ..)| *slot == 1)
+            .map(|(_, kind, path)| (*kind, path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            slot_one,
+            [
+                (PreloadAssetKind::BodySprite, "ro://data/sprite/body.spr"),
+                (PreloadAssetKind::BodyAction, "ro://data/sprite/body.act"),
+                (PreloadAssetKind::HairSprite, "ro://data/sprite/hair.spr"),
+                (PreloadAssetKind::HairAction, "ro://data/sprite/hair.act"),
+                (PreloadAssetKind::HairPalette, "ro://data/palette/hair.pal"),
+            ]
+        );
+        assert_eq!(items.iter().filter(|(slot, ..)| *slot == 2).count(), 4);
+        assert!(items.iter().all(|(slot, ..)| *slot != 0));
+    }
+
+    #[test]
+    fn report_lists_only_failed_items_as_failures() {
+        let item = |kind, outcome| PreloadItem {
+            slot: 0,
+            kind,
+            path: String::new(),
+            outcome,
+        };
+        let report = CharacterSelectionAssetsPreloaded {
+            items: vec![
+                item(PreloadAssetKind::BodySprite, PreloadOutcome::Loaded),
+                item(
+                    PreloadAssetKind::HairPalette,
+                    PreloadOutcome::Failed("not found".to_string()),
+                ),
+            ],
+        };
+        assert!(!report.all_loaded());
+        let failures = report.failures().map(|f| f.kind).collect::<Vec<_>>();
+        assert_eq!(failures, [PreloadAssetKind::HairPalette]);
+    }
+}
@@ -1,5 +1,6 @@
 use super::events::*;
 use crate::core::state::GameState;
+use crate::domain::audio::events::PlayUiSfx;
 use crate::domain::entities::character::components::{
     CharacterInfo,
     visual::{CharacterDirection, CharacterSprite},
@@ -9,7 +10,8 @@ use crate::infrastructure::job::registry::JobSpriteRegistry;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 use net_contract::events::{
-    CharacterCreated, CharacterCreationFailed, CharacterDeleted, CharacterServerConnected,
+    CharacterCreated, CharacterCreationFailed, CharacterDeleted, CharacterRenameFailed,
+    CharacterRenamed, CharacterServerConnected,
 };
 
 /// Domain-owned snapshot of the character-select roster.
@@ -22,7 +24,7 @@ pub struct DomainCharacterRoster {
     pub display_pages: u32,
 }
 
-fn build_character_list_event(
+pub(crate) fn build_character_list_event(
     roster: &DomainCharacterRoster,
     job_registry: Option<&JobSpriteRegistry>,
 ) -> CharacterListReceivedEvent {
@@ -89,6 +91,15 @@ pub fn handle_request_character_list(
     }
 }
 
+/// The roster belongs to the account that fetched it.
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = crate::domain::authentication::teardown::SessionTeardown
+)]
+pub fn clear_character_roster(mut roster: ResMut<DomainCharacterRoster>) {
+    *roster = DomainCharacterRoster::default();
+}
+
 #[auto_add_system(
     plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
     schedule = Update,
@@ -154,6 +165,46 @@ pub fn handle_character_deleted_protocol(
     }
 }
 
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::CharacterRename)
+)]
+pub fn handle_character_renamed_protocol(
+    mut protocol_events: MessageReader<CharacterRenamed>,
+    mut roster: ResMut<DomainCharacterRoster>,
+    mut refresh_events: MessageWriter<RefreshCharacterListEvent>,
+) {
+    for event in protocol_events.read() {
+        if let Some(character) = roster
+            .characters
+            .iter_mut()
+            .find(|character| character.char_id == event.char_id)
+        {
+            character.name.clone_from(&event.new_name);
+            character.rename = 0;
+        }
+        refresh_events.write(RefreshCharacterListEvent);
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::CharacterRename)
+)]
+pub fn handle_character_rename_failed_protocol(
+    mut protocol_events: MessageReader<CharacterRenameFailed>,
+    mut domain_events: MessageWriter<CharacterRenameFailedEvent>,
+) {
+    for event in protocol_events.read() {
+        domain_events.write(CharacterRenameFailedEvent {
+            character_id: event.char_id,
+            error: event.error.description().to_string(),
+        });
+    }
+}
+
 #[auto_add_system(
     plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
     schedule = Update,
@@ -230,6 +281,39 @@ pub fn handle_character_created(
     }
 }
 
+/// Classic char-select cue played when the pointer lands on an occupied slot.
+const CHARACTER_HOVER_SOUND: &str = "버튼소리.wav";
+
+/// The hover cue for `slot`: only occupied slots answer, matching the classic
+/// client where empty slots stay silent.
+fn hover_sound(roster: &DomainCharacterRoster, slot: Option<u8>) -> Option<&'static str> {
+    let slot = slot?;
+    roster
+        .characters
+        .iter()
+        .any(|character| character.char_num == slot)
+        .then_some(CHARACTER_HOVER_SOUND)
+}
+
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::CharacterSelection)
+)]
+pub fn handle_character_hover(
+    mut events: MessageReader<CharacterHoveredEvent>,
+    roster: Res<DomainCharacterRoster>,
+    mut sfx: MessageWriter<PlayUiSfx>,
+) {
+    for event in events.read() {
+        if let Some(sound) = hover_sound(&roster, event.slot) {
+            sfx.write(PlayUiSfx {
+                sound: sound.into(),
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,4 +353,15 @@ mod tests {
         assert_eq!(placed.base.name, "Vidar");
         assert_eq!(placed.base.char_num, 17);
     }
+
+    #[test]
+    fn hover_sound_only_for_occupied_slots() {
+        let roster = DomainCharacterRoster {
+            characters: vec![dto_character(2, "Vidar")],
+            ..Default::default()
+        };
+        assert_eq!(hover_sound(&roster, Some(2)), Some(CHARACTER_HOVER_SOUND));
+        assert_eq!(hover_sound(&roster, Some(0)), None);
+        assert_eq!(hover_sound(&roster, None), None);
+    }
 }
@@ -1,6 +1,7 @@
 use super::events::MapLoadingStarted;
 use super::map_loading::MapLoadingTimer;
 use crate::core::state::GameState;
+use crate::domain::authentication::teardown::SessionTeardown;
 use crate::domain::entities::markers::LocalPlayer;
 use crate::domain::entities::registry::EntityRegistry;
 use crate::domain::system_sets::CharacterFlowSystems;
@@ -9,8 +10,8 @@ use crate::domain::world::spawn_context::MapSpawnContext;
 use crate::domain::world::warp::Warping;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
-use net_contract::commands::{ConnectZone, LeaveZone};
-use net_contract::events::{ZoneEntered, ZoneServerInfoReceived};
+use net_contract::commands::{ConnectCharServer, ConnectZone, LeaveZone};
+use net_contract::events::{ZoneEntered, ZoneResumeFailed, ZoneServerInfoReceived};
 use net_contract::state::{UserSession, ZoneSession};
 
 #[auto_add_system(
@@ -79,10 +80,29 @@ pub fn handle_zone_entered(
 
 type ZoneSessionEntities = Or<(With<LocalPlayer>, With<MapScoped>)>;
 
+/// Drops every client-side trace of the zone session: tells the adapter to leave,
+/// clears the map entry resources, despawns the world and empties the registry.
+fn clear_zone_session(
+    commands: &mut Commands,
+    leave_zone: &mut MessageWriter<LeaveZone>,
+    registry: &mut EntityRegistry,
+    world_entities: &Query<Entity, ZoneSessionEntities>,
+) {
+    leave_zone.write(LeaveZone);
+    commands.remove_resource::<MapSpawnContext>();
+    commands.remove_resource::<MapLoadingTimer>();
+    commands.remove_resource::<Warping>();
+
+    for entity in world_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    registry.clear();
+}
+
 /// Clears all client-side zone state when returning to login.
 #[auto_add_system(
     plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
-    schedule = OnEnter(GameState::Login)
+    schedule = SessionTeardown
 )]
 pub fn teardown_zone_session_on_login(
     mut commands: Commands,
@@ -90,31 +110,150 @@ pub fn teardown_zone_session_on_login(
     mut registry: ResMut<EntityRegistry>,
     world_entities: Query<Entity, ZoneSessionEntities>,
 ) {
-    leave_zone.write(LeaveZone);
-    commands.remove_resource::<MapSpawnContext>();
-    commands.remove_resource::<MapLoadingTimer>();
-    commands.remove_resource::<Warping>();
+    clear_zone_session(
+        &mut commands,
+        &mut leave_zone,
+        &mut registry,
+        &world_entities,
+    );
+}
 
-    for entity in world_entities.iter() {
-        commands.entity(entity).despawn();
+/// Falls back to character selection once the adapter gives up resuming a dropped
+/// zone session: tears the world down and reconnects to the char server the
+/// player originally picked. `handle_character_server_connected` then lands on
+/// `CharacterSelection`. Without a selected server there is nothing to go back to,
+/// so the client returns to login instead.
+#[allow(clippy::too_many_arguments)]
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::ZoneEntry)
+)]
+pub fn fallback_to_character_selection(
+    mut events: MessageReader<ZoneResumeFailed>,
+    mut commands: Commands,
+    mut leave_zone: MessageWriter<LeaveZone>,
+    mut registry: ResMut<EntityRegistry>,
+    world_entities: Query<Entity, ZoneSessionEntities>,
+    user_session: Option<Res<UserSession>>,
+    mut connect_char: MessageWriter<ConnectCharServer>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    if events.read().count() == 0 {
+        return;
     }
-    registry.clear();
+
+    clear_zone_session(
+        &mut commands,
+        &mut leave_zone,
+        &mut registry,
+        &world_entities,
+    );
+
+    let Some((session, server)) = user_session
+        .as_ref()
+        .and_then(|session| Some((session, session.selected_server.as_ref()?)))
+    else {
+        error!("Zone resume failed without a selected char server; returning to login");
+        game_state.set(GameState::Login);
+        return;
+    };
+
+    info!(
+        "Zone resume failed; reconnecting to char server {}",
+        server.name
+    );
+    connect_char.write(ConnectCharServer {
+        address: server.address(),
+        account_id: session.tokens.account_id,
+        login_id1: session.tokens.login_id1,
+        login_id2: session.tokens.login_id2,
+        sex: session.sex as u32,
+    });
+    game_state.set(GameState::Connecting);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use net_contract::dto::{ServerInfo, ServerType};
+    use net_contract::state::SessionTokens;
+
+    fn session_with_server() -> UserSession {
+        let server = ServerInfo {
+            ip: u32::from_be_bytes([127, 0, 0, 1]),
+            port: 6121,
+            name: "Asgard".into(),
+            users: 0,
+            server_type: ServerType::Normal,
+            new_server: 0,
+        };
+        UserSession {
+            username: "tester".into(),
+            tokens: SessionTokens {
+                login_id1: 11,
+                account_id: 2000001,
+                login_id2: 22,
+                character_server_info: Some(server.clone()),
+            },
+            login_timestamp: std::time::SystemTime::now(),
+            last_login_ip: 0,
+            sex: 1,
+            server_list: vec![server.clone()],
+            selected_server: Some(server),
+            auth_token: String::new(),
+        }
+    }
 
     #[test]
-    fn teardown_on_login_clears_session_and_world_entities() {
+    fn resume_failure_tears_down_and_reconnects_to_char_server() {
         let mut app = App::new();
         app.add_plugins(bevy::state::app::StatesPlugin);
         app.init_state::<GameState>();
+        app.add_message::<ZoneResumeFailed>();
+        app.add_message::<LeaveZone>();
+        app.add_message::<ConnectCharServer>();
+        app.init_resource::<EntityRegistry>();
+        app.insert_resource(session_with_server());
+        app.insert_resource(MapSpawnContext::new("prontera".into(), 100, 100, 42));
+        app.add_systems(Update, fallback_to_character_selection);
+
+        let player = app.world_mut().spawn(LocalPlayer).id();
+        app.world_mut().write_message(ZoneResumeFailed {
+            reason: "gone".into(),
+        });
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(player).is_err());
+        assert!(app.world().get_resource::<MapSpawnContext>().is_none());
+        let connects: Vec<ConnectCharServer> = app
+            .world_mut()
+            .resource_mut::<Messages<ConnectCharServer>>()
+            .drain()
+            .collect();
+        assert_eq!(connects.len(), 1);
+        assert_eq!(connects[0].address, "127.0.0.1:6121");
+        assert_eq!(connects[0].account_id, 2000001);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Connecting
+        );
+    }
+
+    #[test]
+    fn teardown_on_login_clears_session_and_world_entities() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy::state::app::StatesPlugin,
+            crate::domain::authentication::teardown::SessionTeardownPlugin,
+        ));
+        app.init_state::<GameState>();
         app.add_message::<LeaveZone>();
         app.init_resource::<EntityRegistry>();
         app.insert_resource(MapSpawnContext::new("prontera".into(), 100, 100, 42));
         app.insert_resource(MapLoadingTimer::new("prontera".into()));
-        app.add_systems(OnEnter(GameState::Login), teardown_zone_session_on_login);
+        app.add_systems(SessionTeardown, teardown_zone_session_on_login);
 
         let player = app.world_mut().spawn(LocalPlayer).id();
         let terrain = app.world_mut().spawn(MapScoped).id();
@@ -4,23 +4,13 @@ use crate::domain::{
         character::components::status::CharacterStatus, character::states::AnimationState,
         markers::LocalPlayer,
     },
+    input::LockedTarget,
     system_sets::CombatSystems,
 };
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 use moonshine_behavior::prelude::*;
 
-/// Detects the local player's own death.
-///
-/// A player's own death is signalled only by the HP param reaching 0 — the
-/// server sends no vanish/`UnitLeft` for self (that goes to other nearby
-/// players). We therefore watch the *applied* HP on the `LocalPlayer`
-/// (`CharacterStatus.hp`, written by `update_character_status_system` after it
-/// drains `PendingStatusParams`) rather than the raw `ParamChanged` message, so
-/// a buffered/initial login param is never mis-read as a death.
-///
-/// `Without<DeadEntity>` makes this idempotent: once the corpse marker is on,
-/// the entity no longer matches and re-death is a no-op.
 type LocalDeathQuery<'w, 's> = Query<
     'w,
     's,
@@ -36,12 +26,30 @@ type LocalDeathQuery<'w, 's> = Query<
     ),
 >;
 
+/// Detects the local player's own death.
+///
+/// A player's own death is signalled only by the HP param reaching 0 — the
+/// server sends no vanish/`UnitLeft` for self (that goes to other nearby
+/// players). We therefore watch the *applied* HP on the `LocalPlayer`
+/// (`CharacterStatus.hp`, written by `update_character_status_system` after it
+/// drains `PendingStatusParams`) rather than the raw `ParamChanged` message, so
+/// a buffered/initial login param is never mis-read as a death.
+///
+/// `Without<DeadEntity>` makes this idempotent: once the corpse marker is on,
+/// the entity no longer matches and re-death is a no-op.
+///
+/// Dying also drops the locked attack target, so a respawned player comes back
+/// idle instead of resuming the combat-ready pose against the unit that killed it.
 #[auto_add_system(
     plugin = crate::app::combat_plugin::CombatDomainPlugin,
     schedule = Update,
     config(in_set = CombatSystems::HandleDeath)
 )]
-pub fn detect_local_death(mut commands: Commands, mut player: LocalDeathQuery) {
+pub fn detect_local_death(
+    mut commands: Commands,
+    mut player: LocalDeathQuery,
+    mut locked_target: ResMut<LockedTarget>,
+) {
     let Ok((entity, status, mut behavior)) = player.single_mut() else {
         return;
     };
@@ -50,6 +58,7 @@ pub fn detect_local_death(mut commands: Commands, mut player: LocalDeathQuery) {
         return;
     }
 
+    *locked_target = LockedTarget::default();
     commands.entity(entity).insert(DeadEntity);
     behavior.reset();
     behavior.start(AnimationState::Dead);
@@ -105,6 +114,14 @@ pub fn recover_local_from_hp(mut commands: Commands, mut player: LocalReviveQuer
     behavior.reset();
 }
 
+/// Run condition that is false while the local player lies dead. Gameplay
+/// input (keyboard hotbar, sit, terrain clicks) is gated on it so a corpse can't
+/// walk, sit or cast; the death dialog's backdrop already swallows pointer input,
+/// this covers the keys and the frames before the dialog spawns.
+pub fn local_player_alive(dead: Query<(), (With<LocalPlayer>, With<DeadEntity>)>) -> bool {
+    dead.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +129,7 @@ mod tests {
     fn death_app() -> App {
         let mut app = App::new();
         app.add_plugins(BehaviorPlugin::<AnimationState>::default())
+            .init_resource::<LockedTarget>()
             .add_systems(
                 Update,
                 (
@@ -149,6 +167,34 @@ mod tests {
         assert_eq!(state(&app, player), AnimationState::Dead);
     }
 
+    #[test]
+    fn dying_drops_the_locked_target_and_gates_input() {
+        let mut app = death_app();
+        app.world_mut().resource_mut::<LockedTarget>().gid = Some(9);
+        let player = app
+            .world_mut()
+            .spawn((LocalPlayer, AnimationState::Idle, status(1)))
+            .id();
+        assert!(
+            app.world_mut()
+                .run_system_cached(local_player_alive)
+                .unwrap()
+        );
+
+        app.world_mut()
+            .get_mut::<CharacterStatus>(player)
+            .unwrap()
+            .hp = 0;
+        app.update();
+
+        assert_eq!(app.world().resource::<LockedTarget>().gid, None);
+        assert!(
+            !app.world_mut()
+                .run_system_cached(local_player_alive)
+                .unwrap()
+        );
+    }
+
     #[test]
     fn hp_zero_without_local_player_does_nothing() {
         let mut app = death_app();
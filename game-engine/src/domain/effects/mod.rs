@@ -25,4 +25,6 @@ pub use systems::{
     follow_effect_anchor, initialize_effect_layers, interpolate_layer_frame,
     order_effect_layers_by_depth, rebuild_effect_layers, spawn_effect,
 };
-pub use triggers::{on_ground_skill, on_skill_damage, on_skill_effect, on_special_effect};
+pub use triggers::{
+    on_ground_skill, on_skill_damage, on_skill_effect, on_special_effect, on_teleport_vanish,
+};
@@ -63,8 +63,8 @@ pub(super) fn apply_animation_part(
     mut transform: Mut<Transform>,
     mut visibility: Mut<Visibility>,
 ) {
-    if let Some(texture) = animation.textures.get(part.texture_index) {
-        set_layer_texture(materials, &material.0, texture);
+    if let Some((texture, region)) = animation.frame_texture(part.texture_index) {
+        set_layer_texture(materials, &material.0, texture, region.uv);
     }
 
     let scale_x = part.scale.x
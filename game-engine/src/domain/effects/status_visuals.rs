@@ -12,8 +12,10 @@ use super::systems::spawn_effect;
 use super::triggers::{descriptor_tint, load_effect};
 use crate::domain::assets::patterns;
 use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
+use crate::domain::entities::character::components::UnitState;
+use crate::domain::entities::components::PendingDespawn;
 use crate::domain::entities::registry::EntityRegistry;
-use crate::domain::entities::sprite_rendering::components::RenderLayer;
+use crate::domain::entities::sprite_rendering::components::{LayerShading, RenderLayer};
 use crate::domain::settings::resources::Settings;
 use crate::domain::sprite::tags::{
     LAYER_EFFECT, Z_OFFSET_PER_LAYER, layer_depth_bias, layer_order,
@@ -22,6 +24,7 @@ use crate::infrastructure::assets::animation_processor::RoAnimationProcessor;
 use crate::infrastructure::assets::loaders::{RoActAsset, RoSpriteAsset};
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
 use crate::infrastructure::effect::StatusEffectCatalog;
+use crate::presentation::rendering::PaletteSpriteMaterial;
 
 /// aesir `OPT1_*` body-state wire ids (`Aesir.ZoneServer.Mmo.Opt1`, the rAthena
 /// `e_sc_opt1` table). Single-valued: `UnitStateChanged.body_state` carries at
@@ -31,10 +34,17 @@ const OPT1_STONE: u32 = 1;
 const OPT1_FREEZE: u32 = 2;
 const OPT1_STONEWAIT: u32 = 6;
 
+/// aesir `OPT2_*` health-state bits (`Aesir.ZoneServer.Mmo.Opt2`, the rAthena
+/// `e_sc_opt2` table). A bitmask, unlike `OPT1_*`; only the poison bits tint.
+const OPT2_POISON: u32 = 0x1;
+const OPT2_DPOISON: u32 = 0x80;
+
 /// Frozen-solid tint (ice blue), multiplied into every sprite layer material.
 const ICE_BLUE: Color = Color::srgb(0.5, 0.75, 1.0);
 /// Petrified tint (stone gray).
 const STONE_GRAY: Color = Color::srgb(0.5, 0.5, 0.5);
+/// Poisoned tint (the reference client's purple).
+const POISON_PURPLE: Color = Color::srgb(0.8, 0.55, 1.0);
 
 /// Colour multiplied into a unit's sprite layers while a body-state pose is
 /// active. Read every frame by [`apply_body_state_tint`]; its absence means the
@@ -71,6 +81,11 @@ fn body_state_tint(body_state: u32) -> Option<Color> {
     }
 }
 
+/// Maps a health-state bitmask to its tint, or `None` when no tinted bit is set.
+fn health_state_tint(health_state: u32) -> Option<Color> {
+    (health_state & (OPT2_POISON | OPT2_DPOISON) != 0).then_some(POISON_PURPLE)
+}
+
 /// Reconciles a unit's freeze/stone visuals with its current `body_state`:
 /// insert tint + animation pause when a petrify/freeze pose is active, remove
 /// both otherwise. Consumes both channels that carry `body_state` — live
@@ -180,34 +195,63 @@ fn apply_body_state(
     despawn_frozen_overlay(commands, entity, children_query, overlays);
 }
 
-/// Multiplies each sprite layer's material `base_color` by its parent unit's
-/// [`BodyStateTint`], or resets it to white when the unit has none. This rides
-/// the same per-frame path as the layer texture write, because those materials
-/// are rewritten unconditionally every frame (retained-phase re-queue) — a
-/// one-shot tint write would be lost. Covers every layer uniformly (body, head,
+/// Sets each sprite layer's tint to its parent unit's [`BodyStateTint`], else
+/// the poison tint of its [`UnitState`] health state, or back to white when
+/// the unit has neither. A body pose outranks poison, as in the reference
+/// client. A corpse's fade ([`PendingDespawn::opacity`]) scales the alpha of
+/// whichever tint applies. Atlas layers take the tint through
+/// their [`LayerShading`], which picks the tinted shared material on the next
+/// frame sync; dyed hair/body layers drawn with `PaletteSpriteMaterial` have it
+/// written into their own material. Covers every layer uniformly (body, head,
 /// weapon, headgear, cart) since they are all `RenderLayer` children of the unit.
 pub fn apply_body_state_tint(
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    layers: Query<(&MeshMaterial3d<StandardMaterial>, &ChildOf), With<RenderLayer>>,
+    mut palette_materials: ResMut<Assets<PaletteSpriteMaterial>>,
+    mut layers: Query<(&mut LayerShading, &ChildOf), With<RenderLayer>>,
+    dyed_layers: Query<(&MeshMaterial3d<PaletteSpriteMaterial>, &ChildOf), With<RenderLayer>>,
     tints: Query<&BodyStateTint>,
+    unit_states: Query<&UnitState>,
+    fading: Query<&PendingDespawn>,
 ) {
-    for (material_handle, child_of) in &layers {
-        let desired = tints
-            .get(child_of.parent())
-            .map_or(Color::WHITE, |tint| tint.0);
-
-        // Read before mutating: `get_mut` marks the material changed (a retained-
-        // phase re-queue) every call, so touch it only when the colour actually
-        // differs. The steady state (untinted white) then costs a read, not a
-        // write, keeping this off the per-frame asset-mutation hot path.
-        if materials.get(&material_handle.0).map(|m| m.base_color) == Some(desired) {
+    let desired = |child_of: &ChildOf| {
+        let unit = child_of.parent();
+        let tint = tints
+            .get(unit)
+            .ok()
+            .map(|tint| tint.0)
+            .or_else(|| {
+                unit_states
+                    .get(unit)
+                    .ok()
+                    .and_then(|state| health_state_tint(state.health_state))
+            })
+            .unwrap_or(Color::WHITE);
+        match fading.get(unit) {
+            Ok(pending) => tint.with_alpha(tint.alpha() * pending.opacity()),
+            Err(_) => tint,
+        }
+    };
+
+    for (mut shading, child_of) in &mut layers {
+        let tint = desired(child_of);
+        if shading.tint != tint {
+            shading.tint = tint;
+        }
+    }
+
+    for (material_handle, child_of) in &dyed_layers {
+        let desired = desired(child_of);
+        if palette_materials
+            .get(&material_handle.0)
+            .map(|m| m.params.tint)
+            == Some(desired.to_linear())
+        {
             continue;
         }
 
-        let Some(mut material) = materials.get_mut(&material_handle.0) else {
+        let Some(mut material) = palette_materials.get_mut(&material_handle.0) else {
             continue;
         };
-        material.base_color = desired;
+        material.set_tint(desired);
     }
 }
 
@@ -727,6 +771,7 @@ mod tests {
                 body_state,
                 health_state: 0,
                 effect_state: 0,
+                virtue: 0,
                 head: 0,
                 weapon: 0,
                 shield: 0,
@@ -778,6 +823,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn only_poison_health_bits_tint() {
+        assert_eq!(health_state_tint(OPT2_POISON), Some(POISON_PURPLE));
+        assert_eq!(health_state_tint(OPT2_DPOISON | 0x2), Some(POISON_PURPLE));
+        assert_eq!(health_state_tint(0x2), None);
+        assert_eq!(health_state_tint(0), None);
+    }
+
     #[test]
     fn stonewait_inserts_gray_tint() {
         let mut app = app();
@@ -24,6 +24,7 @@ use crate::domain::combat::events::{DamageDisplayType, DisplayDamageNumber};
 use crate::domain::combat::systems::start_attack_animation;
 use crate::domain::entities::character::states::AnimationState;
 use crate::domain::entities::registry::EntityRegistry;
+use crate::domain::entities::spawning::{EntityVanishRequested, VanishType};
 use crate::domain::world::components::MapLoader;
 use crate::infrastructure::assets::loaders::RoAltitudeAsset;
 use crate::infrastructure::effect::{
@@ -34,6 +35,9 @@ use net_contract::events::{
     GroundSkillPlaced, SkillDamageReceived, SkillEffectShown, SpecialEffectShown,
 };
 
+/// AL_TELEPORT, whose effect also marks a unit that vanished by teleporting.
+const TELEPORT_SKILL_ID: u32 = 26;
+
 /// Despawn timer for repeating `SpecialEffect` visuals: `SpecialEffect` is
 /// fire-and-forget with no removal packet, so a `repeating` catalog entry
 /// (e.g. EF_STORMGUST, EF_MAGNUS) would otherwise never set `finished` and
@@ -529,6 +533,42 @@ pub fn on_special_effect(
     }
 }
 
+/// `EntityVanishRequested` for a teleport: the unit is despawned at once, so
+/// leave the teleport effect and its sound where it stood.
+pub fn on_teleport_vanish(
+    trigger: On<EntityVanishRequested>,
+    mut commands: Commands,
+    catalog: Option<Res<EffectCatalog>>,
+    asset_server: Res<AssetServer>,
+    transforms: Query<&Transform>,
+    mut sfx: MessageWriter<PlaySkillSfx>,
+) {
+    if trigger.event().vanish_type != VanishType::Teleported {
+        return;
+    }
+    let Ok(transform) = transforms.get(trigger.entity) else {
+        debug!("No transform for teleported unit {}", trigger.entity);
+        return;
+    };
+    let Some(descriptor) = catalog.as_ref().and_then(|c| c.get(TELEPORT_SKILL_ID)) else {
+        debug!("No effect catalog entry for the teleport effect");
+        return;
+    };
+    let Some(effect) = load_effect(&asset_server, descriptor) else {
+        return;
+    };
+
+    let emitter = spawn_effect(
+        &mut commands,
+        effect,
+        EffectAnchor::Position(transform.translation),
+        descriptor.repeating,
+        descriptor_tint(descriptor),
+        None,
+    );
+    play_sound(&mut sfx, descriptor, emitter);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,7 +8,7 @@ use crate::infrastructure::assets::animation_processor::RoAnimationProcessor;
 use crate::infrastructure::assets::loaders::{RoActAsset, RoSpriteAsset};
 use crate::infrastructure::assets::ro_animation_asset::{ActionData, RoAnimationAsset};
 
-/// Shared, processed emote animation plus per-action thumbnail images.
+/// Shared, processed emote animation plus per-action thumbnails.
 ///
 /// `emotion.act` is direction-less: it carries one action per emote (98 actions
 /// covering emote ids `0..MAX_EMOTE_ID` plus newer client emotes), NOT the
@@ -19,7 +19,15 @@ use crate::infrastructure::assets::ro_animation_asset::{ActionData, RoAnimationA
 #[derive(Resource)]
 pub struct EmoteAssets {
     pub animation: Handle<RoAnimationAsset>,
-    pub thumbnails: Vec<Handle<Image>>,
+    pub thumbnails: Vec<EmoteThumbnail>,
+}
+
+/// One emote's representative frame: an atlas page and the frame's pixel rect
+/// on it, ready for an `ImageNode`'s `image` and `rect`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmoteThumbnail {
+    pub image: Handle<Image>,
+    pub rect: Option<Rect>,
 }
 
 /// SPR/ACT handles still loading for the shared emote sprite. Mirrors the cart's
@@ -83,14 +91,17 @@ pub fn finalize_emote_assets(
 /// Alignment is load-bearing (id == action index == thumbnail index), so an
 /// action with no representative part yields a placeholder handle rather than
 /// shifting later ids.
-fn action_thumbnails(animation: &RoAnimationAsset) -> Vec<Handle<Image>> {
+fn action_thumbnails(animation: &RoAnimationAsset) -> Vec<EmoteThumbnail> {
     animation
         .actions
         .iter()
         .map(|action| {
             representative_texture_index(action)
-                .and_then(|index| animation.textures.get(index))
-                .cloned()
+                .and_then(|index| animation.frame_texture(index))
+                .map(|(image, region)| EmoteThumbnail {
+                    image: image.clone(),
+                    rect: Some(region.rect.as_rect()),
+                })
                 .unwrap_or_default()
         })
         .collect()
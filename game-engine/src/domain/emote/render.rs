@@ -8,6 +8,7 @@ use crate::domain::audio::events::PlaySkillSfx;
 use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
 use crate::domain::entities::registry::EntityRegistry;
 use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::infrastructure::assets::atlas_uv_transform;
 use crate::infrastructure::assets::ro_animation_asset::{FramePart, RoAnimationAsset};
 use crate::utils::constants::SPRITE_WORLD_SCALE;
 
@@ -107,11 +108,12 @@ pub fn spawn_emote(
         }
 
         let first = frame_part(animation, action_index, 0);
-        let texture = first.and_then(|part| animation.textures.get(part.texture_index).cloned());
+        let frame = first.and_then(|part| animation.frame_texture(part.texture_index));
         let scale = first.map(part_scale).unwrap_or(Vec3::ONE);
 
         let material = materials.add(StandardMaterial {
-            base_color_texture: texture,
+            base_color_texture: frame.map(|(texture, _)| texture.clone()),
+            uv_transform: frame.map_or_else(default, |(_, region)| atlas_uv_transform(region.uv)),
             alpha_mode: AlphaMode::Blend,
             unlit: true,
             cull_mode: None,
@@ -188,8 +190,8 @@ pub fn advance_and_despawn_emotes(
             continue;
         };
 
-        if let Some(texture) = animation.textures.get(part.texture_index) {
-            set_layer_texture(&mut materials, &material.0, texture);
+        if let Some((texture, region)) = animation.frame_texture(part.texture_index) {
+            set_layer_texture(&mut materials, &material.0, texture, region.uv);
         }
         transform.scale = part_scale(part);
     }
@@ -227,7 +229,8 @@ mod tests {
 
     fn animation(actions: usize) -> RoAnimationAsset {
         RoAnimationAsset {
-            textures: vec![Handle::default()],
+            atlas_pages: vec![Handle::default()],
+            frame_regions: vec![default()],
             actions: (0..actions).map(|_| action(2, 100.0)).collect(),
             ..default()
         }
@@ -4,6 +4,17 @@ use bevy::{
     prelude::*,
 };
 use bevy_auto_plugin::prelude::*;
+use bevy_persistent::prelude::Persistent;
+
+use crate::domain::camera::components::CameraView;
+use crate::domain::entities::components::NetworkEntity;
+use crate::domain::entities::sprite_rendering::components::{MobSprite, PlayerSprite};
+use crate::domain::settings::resources::Settings;
+use crate::domain::system_sets::CameraSystems;
+
+/// Camera distance at which fixed-size sprites are drawn at their native
+/// scale: the default follow camera's (an offset of 150 up and 150 back).
+const FIXED_SIZE_REFERENCE_DISTANCE: f32 = 150.0 * std::f32::consts::SQRT_2;
 
 /// Marker component for entities that should always face the camera
 #[derive(Component, Debug, Clone, Copy)]
@@ -111,6 +122,8 @@ fn setup_shared_sprite_quad(mut commands: Commands, mut meshes: ResMut<Assets<Me
 /// The equipment-window preview camera is excluded; its `PreviewBillboard`
 /// layer is faced separately by `preview_billboard_rotation_system`. Only one
 /// such camera exists per screen, so `single()` is unambiguous.
+/// Billboards already facing the camera aren't written, so a still camera
+/// doesn't mark every layer's transform changed and re-propagate it.
 /// Runs after TransformPropagate to ensure proper ordering
 #[auto_add_system(
     plugin = crate::domain::entities::billboard::BillboardPlugin,
@@ -126,7 +139,9 @@ fn billboard_rotation_system(
     };
 
     for mut billboard_transform in billboard_query.iter_mut() {
-        billboard_transform.rotation = camera_transform.rotation;
+        if billboard_transform.rotation != camera_transform.rotation {
+            billboard_transform.rotation = camera_transform.rotation;
+        }
     }
 }
 
@@ -146,7 +161,56 @@ fn preview_billboard_rotation_system(
     };
 
     for mut billboard_transform in billboard_query.iter_mut() {
-        billboard_transform.rotation = camera_transform.rotation;
+        if billboard_transform.rotation != camera_transform.rotation {
+            billboard_transform.rotation = camera_transform.rotation;
+        }
+    }
+}
+
+/// Query: world units whose sprite layers hang off their root, which the
+/// fixed-size mode scales as a whole (feet stay on the ground).
+type ScaledUnitQuery<'w, 's> = Query<
+    'w,
+    's,
+    &'static mut Transform,
+    (
+        With<NetworkEntity>,
+        Or<(With<PlayerSprite>, With<MobSprite>)>,
+        Without<Camera3d>,
+    ),
+>;
+
+/// Root scale that cancels the zoom of a camera `distance` away, so units
+/// keep the on-screen size they have at the default zoom.
+fn fixed_size_scale(distance: f32) -> f32 {
+    distance / FIXED_SIZE_REFERENCE_DISTANCE
+}
+
+/// With the fixed sprite size setting on, scales every world unit against the
+/// follow camera's zoom the way the original client keeps its sprites a
+/// constant size; with it off, units are left at scale 1. Preview characters
+/// carry no `NetworkEntity` and are never scaled.
+#[auto_add_system(
+    plugin = crate::domain::entities::billboard::BillboardPlugin,
+    schedule = Update,
+    config(after = CameraSystems::Follow)
+)]
+fn scale_billboards_with_zoom(
+    settings: Res<Persistent<Settings>>,
+    camera_query: Query<&CameraView, ActiveCameraFilter>,
+    mut units: ScaledUnitQuery,
+) {
+    let scale = camera_query
+        .single()
+        .ok()
+        .filter(|_| settings.graphics.fixed_sprite_size)
+        .map_or(1.0, |view| fixed_size_scale(view.distance));
+    let scale = Vec3::splat(scale);
+
+    for mut transform in &mut units {
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
     }
 }
 
@@ -172,6 +236,15 @@ mod tests {
         Quat::from_rotation_x(0.4)
     }
 
+    #[test]
+    fn fixed_size_scale_cancels_zoom_around_the_default_distance() {
+        assert_eq!(fixed_size_scale(FIXED_SIZE_REFERENCE_DISTANCE), 1.0);
+        assert!(fixed_size_scale(100.0) < 1.0);
+        assert!(fixed_size_scale(250.0) > 1.0);
+        // Twice as far away is drawn twice as large, so it looks the same size.
+        assert_eq!(fixed_size_scale(200.0), 2.0 * fixed_size_scale(100.0));
+    }
+
     #[test]
     fn world_billboard_faces_primary_camera_with_a_second_camera_present() {
         let mut app = App::new();
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+/// Last HP the server reported for a unit, from its spawn or a
+/// `UnitHpChanged` update. Only present on units whose spawn carried a
+/// non-zero `max_hp`; aesir leaves both at zero for units whose HP it hides.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Health {
+    pub hp: u32,
+    pub max_hp: u32,
+}
+
+impl Health {
+    /// Remaining HP as a `0.0..=1.0` fraction. Zero `max_hp` reads as empty.
+    pub fn fraction(&self) -> f32 {
+        if self.max_hp == 0 {
+            return 0.0;
+        }
+        (self.hp.min(self.max_hp) as f32) / (self.max_hp as f32)
+    }
+
+    /// Whether the unit has taken damage it has not yet recovered.
+    pub fn is_damaged(&self) -> bool {
+        self.hp < self.max_hp
+    }
+}
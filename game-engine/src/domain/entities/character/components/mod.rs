@@ -1,11 +1,13 @@
 pub mod action_mapping;
 pub mod core;
 pub mod equipment;
+pub mod health;
 pub mod status;
 pub mod unit_state;
 pub mod visual;
 
 pub use core::*;
 pub use equipment::*;
+pub use health::*;
 pub use status::*;
 pub use unit_state::*;
@@ -54,6 +54,14 @@ impl Behavior for AnimationState {
     }
 }
 
+impl AnimationState {
+    /// States that show the plain standing pose and may vary it on long idle.
+    /// The engaged stance and sitting keep a fixed pose.
+    pub fn allows_idle_variation(self) -> bool {
+        matches!(self, AnimationState::Idle)
+    }
+}
+
 impl From<AnimationState> for ActionType {
     fn from(state: AnimationState) -> Self {
         match state {
@@ -97,6 +105,14 @@ mod tests {
         assert!(!AnimationState::PickingUp.filter_next(&AnimationState::Sitting));
     }
 
+    #[test]
+    fn only_plain_idle_varies_its_pose() {
+        assert!(AnimationState::Idle.allows_idle_variation());
+        assert!(!AnimationState::CombatReady.allows_idle_variation());
+        assert!(!AnimationState::Sitting.allows_idle_variation());
+        assert!(!AnimationState::Walking.allows_idle_variation());
+    }
+
     #[test]
     fn dead_is_still_terminal() {
         assert!(!AnimationState::Dead.filter_next(&AnimationState::PickingUp));
@@ -1,4 +1,6 @@
+use super::status_icons::ActiveStatus;
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Default, Component, Reflect)]
 pub struct StatusEffects {
@@ -11,6 +13,11 @@ pub struct StatusEffects {
     pub hiding: bool,
     pub cloaking: bool,
     pub dead: bool,
+    /// Every EFST icon active on the unit, with its countdown. Written without
+    /// triggering change detection, so the flags above stay the only thing the
+    /// animation observers react to.
+    #[reflect(ignore)]
+    pub efsts: HashMap<u32, ActiveStatus>,
 }
 
 impl StatusEffects {
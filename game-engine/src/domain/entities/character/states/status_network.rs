@@ -1,17 +1,35 @@
-use super::observers::handle_status_effect_state_changes;
+use super::observers::{handle_status_effect_state_changes, observe_status_effects_changes};
 use super::status_effects::StatusEffects;
+use super::status_icons::ActiveStatus;
+use crate::domain::entities::character::components::UnitState;
+use crate::domain::entities::character::systems::unit_state::{
+    OPTION_CLOAK, OPTION_HIDE, apply_unit_state,
+};
 use crate::domain::entities::registry::EntityRegistry;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use net_contract::events::StatusEffectChanged;
+use std::time::Duration;
 
 /// rAthena EFST id for Play Dead (SC_TRICKDEAD). Aesir carries no opt field for
 /// it, so this icon toggle is the only signal that drives the dead pose.
 const EFST_TRICKDEAD: u32 = 29;
 
-/// Folds inbound EFST toggles into each unit's [`StatusEffects`] so the animation
-/// observer can react. Only EFSTs that map onto a tracked field touch the
-/// component, which keeps change detection from firing on unrelated icons.
+/// aesir `OPT1_*` body-state ids (single-valued) and `OPT2_*` health-state bits
+/// behind the legacy [`StatusEffects`] flags.
+const OPT1_STONE: u32 = 1;
+const OPT1_FREEZE: u32 = 2;
+const OPT1_STUN: u32 = 3;
+const OPT1_SLEEP: u32 = 4;
+const OPT1_STONEWAIT: u32 = 6;
+const OPT2_POISON: u32 = 0x1;
+const OPT2_BLEEDING: u32 = 0x40;
+const OPT2_DPOISON: u32 = 0x80;
+
+/// Folds inbound EFST toggles into each unit's [`StatusEffects`]: every icon
+/// goes into `efsts` with its countdown, and Play Dead also drives the `dead`
+/// flag for the animation observer. Only that flag is written through change
+/// detection, so unrelated icons don't wake the observers.
 #[auto_add_system(
     plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
     schedule = Update,
@@ -20,13 +38,10 @@ const EFST_TRICKDEAD: u32 = 29;
 pub fn apply_status_effect_changes(
     mut events: MessageReader<StatusEffectChanged>,
     registry: Res<EntityRegistry>,
+    time: Res<Time>,
     mut characters: Query<&mut StatusEffects>,
 ) {
     for event in events.read() {
-        if event.efst != EFST_TRICKDEAD {
-            continue;
-        }
-
         let Some(entity) = registry.get_entity(event.unit_id) else {
             continue;
         };
@@ -35,10 +50,110 @@ pub fn apply_status_effect_changes(
             continue;
         };
 
-        status.dead = event.on;
+        let efsts = &mut status.bypass_change_detection().efsts;
+        if event.on {
+            efsts.insert(
+                event.efst,
+                ActiveStatus {
+                    total_ms: event.total_ms,
+                    permanent: event.remain_ms == 0,
+                    expires_at: (event.remain_ms != 0)
+                        .then(|| time.elapsed() + Duration::from_millis(event.remain_ms as u64)),
+                },
+            );
+        } else {
+            efsts.remove(&event.efst);
+        }
+
+        if event.efst == EFST_TRICKDEAD {
+            status.dead = event.on;
+        }
+    }
+}
+
+/// Drops timed EFSTs whose countdown has run out, for units the server never
+/// sends the matching `on = false` for (out of view when it ended).
+#[auto_add_system(
+    plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
+    schedule = Update
+)]
+pub fn expire_status_effects(time: Res<Time>, mut characters: Query<&mut StatusEffects>) {
+    let now = time.elapsed();
+    for mut status in &mut characters {
+        let expired = status
+            .efsts
+            .values()
+            .any(|active| active.expires_at.is_some_and(|at| at <= now));
+        if expired {
+            status
+                .bypass_change_detection()
+                .efsts
+                .retain(|_, active| active.expires_at.is_none_or(|at| at > now));
+        }
+    }
+}
+
+/// Mirrors a unit's legacy [`UnitState`] (opt1 body pose, opt2 health bits,
+/// option hide/cloak) onto the [`StatusEffects`] flags, so the animation and
+/// visual observers see stun, sleep, freeze, stone, poison, bleeding and
+/// hiding however the server delivered them. Flags are only written when they
+/// differ, to keep change detection honest.
+#[auto_add_system(
+    plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
+    schedule = Update,
+    config(
+        after = apply_unit_state,
+        before = handle_status_effect_state_changes,
+        before = observe_status_effects_changes
+    )
+)]
+pub fn apply_unit_state_flags(
+    mut characters: Query<(&UnitState, &mut StatusEffects), Changed<UnitState>>,
+) {
+    for (state, mut status) in &mut characters {
+        let flags = legacy_flags(state);
+        if current_flags(&status) != flags {
+            [
+                status.stunned,
+                status.frozen,
+                status.petrified,
+                status.sleeping,
+                status.poisoned,
+                status.bleeding,
+                status.hiding,
+                status.cloaking,
+            ] = flags;
+        }
     }
 }
 
+/// `[stunned, frozen, petrified, sleeping, poisoned, bleeding, hiding, cloaking]`.
+fn legacy_flags(state: &UnitState) -> [bool; 8] {
+    [
+        state.body_state == OPT1_STUN,
+        state.body_state == OPT1_FREEZE,
+        matches!(state.body_state, OPT1_STONE | OPT1_STONEWAIT),
+        state.body_state == OPT1_SLEEP,
+        state.health_state & (OPT2_POISON | OPT2_DPOISON) != 0,
+        state.health_state & OPT2_BLEEDING != 0,
+        state.effect_state & OPTION_HIDE != 0,
+        state.effect_state & OPTION_CLOAK != 0,
+    ]
+}
+
+fn current_flags(status: &StatusEffects) -> [bool; 8] {
+    [
+        status.stunned,
+        status.frozen,
+        status.petrified,
+        status.sleeping,
+        status.poisoned,
+        status.bleeding,
+        status.hiding,
+        status.cloaking,
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,23 +162,35 @@ mod tests {
         let mut app = App::new();
         app.add_message::<StatusEffectChanged>()
             .init_resource::<EntityRegistry>()
-            .add_systems(Update, apply_status_effect_changes);
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (apply_status_effect_changes, apply_unit_state_flags),
+            );
         app
     }
 
     fn emit(app: &mut App, unit_id: u32, efst: u32, on: bool) {
+        emit_timed(app, unit_id, efst, on, 0);
+    }
+
+    fn emit_timed(app: &mut App, unit_id: u32, efst: u32, on: bool, remain_ms: u32) {
         app.world_mut()
             .resource_mut::<Messages<StatusEffectChanged>>()
             .write(StatusEffectChanged {
                 unit_id,
                 efst,
                 on,
-                total_ms: 0,
-                remain_ms: 0,
+                total_ms: remain_ms,
+                remain_ms,
             });
         app.update();
     }
 
+    fn status(app: &App, entity: Entity) -> &StatusEffects {
+        app.world().entity(entity).get::<StatusEffects>().unwrap()
+    }
+
     #[test]
     fn trickdead_toggles_dead_field() {
         let mut app = app();
@@ -108,4 +235,56 @@ mod tests {
                 .dead
         );
     }
+
+    #[test]
+    fn every_efst_is_tracked_with_its_duration() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(StatusEffects::default()).id();
+        app.world_mut()
+            .resource_mut::<EntityRegistry>()
+            .register_entity(7, entity);
+
+        emit_timed(&mut app, 7, 12, true, 60_000);
+        emit(&mut app, 7, 46, true);
+
+        let efsts = &status(&app, entity).efsts;
+        assert_eq!(efsts[&12].total_ms, 60_000);
+        assert!(efsts[&12].expires_at.is_some());
+        assert!(efsts[&46].permanent);
+
+        emit(&mut app, 7, 12, false);
+        assert!(!status(&app, entity).efsts.contains_key(&12));
+    }
+
+    #[test]
+    fn unit_state_drives_the_legacy_flags() {
+        let mut app = app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                StatusEffects::default(),
+                UnitState {
+                    body_state: OPT1_STUN,
+                    health_state: OPT2_POISON | OPT2_BLEEDING,
+                    effect_state: OPTION_CLOAK,
+                    virtue: 0,
+                },
+            ))
+            .id();
+        app.update();
+
+        let flags = status(&app, entity);
+        assert!(flags.stunned && flags.poisoned && flags.bleeding && flags.cloaking);
+        assert!(!flags.frozen && !flags.sleeping && !flags.hiding);
+
+        app.world_mut().entity_mut(entity).insert(UnitState {
+            body_state: OPT1_STONEWAIT,
+            ..default()
+        });
+        app.update();
+
+        let flags = status(&app, entity);
+        assert!(flags.petrified);
+        assert!(!flags.stunned && !flags.poisoned && !flags.cloaking);
+    }
 }
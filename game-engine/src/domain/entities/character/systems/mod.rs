@@ -1,5 +1,7 @@
 pub mod status_update;
+pub mod unit_health;
 pub mod unit_state;
 
 pub use status_update::*;
+pub use unit_health::*;
 pub use unit_state::*;
@@ -0,0 +1,122 @@
+use crate::domain::entities::character::components::Health;
+use crate::domain::entities::registry::EntityRegistry;
+use crate::domain::system_sets::EntityLifecycleSystems;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_add_system;
+use net_contract::events::{UnitEntered, UnitHpChanged};
+
+/// Stores spawn HP and `UnitHpChanged` (ZC_NOTIFY_MONSTER_HP) updates on
+/// [`Health`]. The worldspace HP bar reads it off monsters. Ordered after
+/// entity spawning so the entered unit is registered when we resolve it.
+#[auto_add_system(
+    plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
+    schedule = Update,
+    config(after = EntityLifecycleSystems::Spawning)
+)]
+pub fn apply_unit_health(
+    mut entered: MessageReader<UnitEntered>,
+    mut changes: MessageReader<UnitHpChanged>,
+    registry: Res<EntityRegistry>,
+    mut commands: Commands,
+) {
+    // Spawns first: an HP update in the same frame is the newer value.
+    let spawns = entered
+        .read()
+        .filter(|event| event.max_hp > 0)
+        .map(|event| (event.gid, event.hp, event.max_hp));
+    let updates = changes
+        .read()
+        .map(|event| (event.gid, event.hp, event.max_hp));
+
+    for (gid, hp, max_hp) in spawns.chain(updates) {
+        let Some(entity) = registry.get_entity(gid) else {
+            continue;
+        };
+        commands.entity(entity).insert(Health { hp, max_hp });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_message::<UnitHpChanged>()
+            .add_message::<UnitEntered>()
+            .init_resource::<EntityRegistry>()
+            .add_systems(Update, apply_unit_health);
+        app
+    }
+
+    fn register(app: &mut App, gid: u32) -> Entity {
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<EntityRegistry>()
+            .register_entity(gid, entity);
+        entity
+    }
+
+    fn hp_changed(app: &mut App, gid: u32, hp: u32, max_hp: u32) {
+        app.world_mut()
+            .resource_mut::<Messages<UnitHpChanged>>()
+            .write(UnitHpChanged { gid, hp, max_hp });
+        app.update();
+    }
+
+    #[test]
+    fn hp_update_is_stored_on_the_resolved_unit() {
+        let mut app = app();
+        let entity = register(&mut app, 7);
+
+        hp_changed(&mut app, 7, 40, 100);
+        assert_eq!(
+            app.world().get::<Health>(entity),
+            Some(&Health {
+                hp: 40,
+                max_hp: 100
+            })
+        );
+
+        hp_changed(&mut app, 7, 10, 100);
+        assert_eq!(app.world().get::<Health>(entity).unwrap().hp, 10);
+    }
+
+    #[test]
+    fn unknown_unit_is_a_no_op() {
+        let mut app = app();
+        let entity = register(&mut app, 7);
+
+        hp_changed(&mut app, 999, 40, 100);
+
+        assert!(app.world().get::<Health>(entity).is_none());
+    }
+
+    #[test]
+    fn fraction_clamps_and_tolerates_zero_max() {
+        assert_eq!(
+            Health {
+                hp: 25,
+                max_hp: 100
+            }
+            .fraction(),
+            0.25
+        );
+        assert_eq!(
+            Health {
+                hp: 150,
+                max_hp: 100
+            }
+            .fraction(),
+            1.0
+        );
+        assert_eq!(Health { hp: 0, max_hp: 0 }.fraction(), 0.0);
+        assert!(
+            !Health {
+                hp: 100,
+                max_hp: 100
+            }
+            .is_damaged()
+        );
+    }
+}
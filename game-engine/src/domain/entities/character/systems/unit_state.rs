@@ -1,13 +1,14 @@
 use crate::domain::entities::character::components::UnitState;
 use crate::domain::entities::registry::EntityRegistry;
+use crate::domain::system_sets::EntityLifecycleSystems;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
-use net_contract::events::UnitStateChanged;
+use net_contract::events::{UnitEntered, UnitStateChanged};
 
 // option (effect_state) bitmask ids. Values are the rAthena `e_option`
 // constants aesir sends (Aesir.ZoneServer.Mmo.Option).
-const OPTION_HIDE: u32 = 2;
-const OPTION_CLOAK: u32 = 4;
+pub(crate) const OPTION_HIDE: u32 = 2;
+pub(crate) const OPTION_CLOAK: u32 = 4;
 
 /// Pushcart mount bits. A merchant with any cart tier sets one of these in
 /// `effect_state`; we render a single sprite regardless of tier, so
@@ -26,42 +27,67 @@ impl UnitState {
     }
 }
 
-/// Consumes the legacy `UnitStateChange` channel: stores all four state fields
-/// on [`UnitState`] and renders the cheap high-value subset.
+/// Consumes the legacy `UnitStateChange` channel and the same four fields on
+/// `UnitEntered`: stores them on [`UnitState`] and renders the cheap
+/// high-value subset. Reading the spawn too means a unit that enters view
+/// already hidden, cloaked or poisoned looks it from its first frame, since no
+/// follow-up state change arrives in that case. Ordered after entity spawning
+/// so the entered unit is registered when we resolve it.
 ///
-/// ponytail: only hide/cloak visibility (option) renders here. Deliberately
-/// deferred and stored-only:
+/// ponytail: only hide/cloak visibility (option) renders here. Elsewhere or
+/// deferred:
 /// - freeze/stone body poses (opt1) render in `domain/effects/status_visuals.rs`
 ///   (`body_state_visuals`): tint + `AnimationPaused`, off the combat
 ///   `AnimationState` machine so the HitStun revert can't fight them.
-/// - stun/sleep body poses (opt1): still stored-only; no visual yet.
-/// - poison/curse/silence tint (health_state/opt2).
+/// - poison tint (health_state/opt2) is read off [`UnitState`] by
+///   `apply_body_state_tint` in the same module.
+/// - stun/sleep body poses (opt1) and curse/silence (opt2): stored-only.
 /// - mount/orc-head and the other option bits. (The cart bits are consumed by
 ///   `apply_cart_mount` in the sprite-rendering domain.)
 /// - virtue (opt3).
 #[auto_add_system(
     plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
-    schedule = Update
+    schedule = Update,
+    config(after = EntityLifecycleSystems::Spawning)
 )]
 pub fn apply_unit_state(
     mut events: MessageReader<UnitStateChanged>,
+    mut entered: MessageReader<UnitEntered>,
     registry: Res<EntityRegistry>,
     mut commands: Commands,
     mut visibilities: Query<&mut Visibility>,
 ) {
-    for event in events.read() {
-        let Some(entity) = registry.get_entity(event.unit_id) else {
+    // Spawns first: a state change in the same frame is the newer value.
+    let spawns = entered.read().map(|event| {
+        (
+            event.gid,
+            UnitState {
+                body_state: event.body_state,
+                health_state: event.health_state,
+                effect_state: event.effect_state,
+                virtue: event.virtue,
+            },
+        )
+    });
+    let changes = events.read().map(|event| {
+        (
+            event.unit_id,
+            UnitState {
+                body_state: event.body_state,
+                health_state: event.health_state,
+                effect_state: event.effect_state,
+                virtue: event.virtue,
+            },
+        )
+    });
+
+    for (unit_id, state) in spawns.chain(changes) {
+        let Some(entity) = registry.get_entity(unit_id) else {
             continue;
         };
 
-        commands.entity(entity).insert(UnitState {
-            body_state: event.body_state,
-            health_state: event.health_state,
-            effect_state: event.effect_state,
-            virtue: event.virtue,
-        });
-
-        apply_hide_cloak(entity, event.effect_state, &mut visibilities);
+        commands.entity(entity).insert(state);
+        apply_hide_cloak(entity, state.effect_state, &mut visibilities);
     }
 }
 
@@ -92,6 +118,7 @@ mod tests {
     fn app() -> App {
         let mut app = App::new();
         app.add_message::<UnitStateChanged>()
+            .add_message::<UnitEntered>()
             .init_resource::<EntityRegistry>()
             .add_systems(Update, apply_unit_state);
         app
@@ -175,6 +202,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn a_unit_entering_cloaked_spawns_hidden() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Visibility::Inherited).id();
+        register(&mut app, 7, entity);
+
+        app.world_mut()
+            .resource_mut::<Messages<UnitEntered>>()
+            .write(UnitEntered {
+                gid: 7,
+                aid: 0,
+                object_type: 5,
+                job: 1002,
+                x: 0,
+                y: 0,
+                dir: 0,
+                speed: 0,
+                hp: 0,
+                max_hp: 0,
+                clevel: 0,
+                body_state: 0,
+                health_state: 1,
+                effect_state: OPTION_CLOAK,
+                virtue: 0,
+                head: 0,
+                weapon: 0,
+                shield: 0,
+                accessory: 0,
+                accessory2: 0,
+                accessory3: 0,
+                head_palette: 0,
+                body_palette: 0,
+                head_dir: 0,
+                robe: 0,
+                guild_id: 0,
+                guild_name: String::new(),
+                emblem_id: 0,
+                sex: 0,
+                is_boss: false,
+                name: String::new(),
+                moving: false,
+                dst_x: 0,
+                dst_y: 0,
+                move_start_time: 0,
+            });
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<Visibility>(entity).unwrap(),
+            Visibility::Hidden
+        );
+        assert_eq!(
+            app.world().get::<UnitState>(entity).unwrap().health_state,
+            1
+        );
+    }
+
     #[test]
     fn is_cart_mounted_reflects_cart_bits() {
         assert!(!UnitState::default().is_cart_mounted());
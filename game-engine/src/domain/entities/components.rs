@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use super::spawning::events::VanishType;
 use super::types::ObjectType;
 
 // =============================================================================
@@ -79,15 +80,24 @@ pub struct GuildIdentity {
 #[derive(Component, Debug, Clone, Copy)]
 pub struct SpawnGuildIdentityKnown;
 
-/// Pending despawn component for deferred entity removal
+/// Seconds a dead unit's corpse stays fully visible for its death animation.
+const DEATH_HOLD_SECS: f32 = 3.5;
+/// Seconds the corpse then takes to fade out before it is despawned.
+const DEATH_FADE_SECS: f32 = 1.5;
+/// Fade opacity moves in steps this fine, so a fading unit reuses a handful of
+/// tinted shared materials instead of making one per frame.
+const DEATH_FADE_STEPS: f32 = 16.0;
+
+/// Pending despawn component for deferred entity removal: a dead unit that
+/// shows its death animation, then fades out.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct PendingDespawn {
-    pub vanish_type: u8,
+    pub vanish_type: VanishType,
     pub marked_at: std::time::Instant,
 }
 
 impl PendingDespawn {
-    pub fn new(vanish_type: u8) -> Self {
+    pub fn new(vanish_type: VanishType) -> Self {
         Self {
             vanish_type,
             marked_at: std::time::Instant::now(),
@@ -95,6 +105,34 @@ impl PendingDespawn {
     }
 
     pub fn has_timed_out(&self) -> bool {
-        self.marked_at.elapsed().as_secs() >= 5
+        self.marked_at.elapsed().as_secs_f32() >= DEATH_HOLD_SECS + DEATH_FADE_SECS
+    }
+
+    /// How opaque the unit is drawn now.
+    pub fn opacity(&self) -> f32 {
+        death_fade_opacity(self.marked_at.elapsed().as_secs_f32())
+    }
+}
+
+/// Opacity of a corpse `elapsed` seconds after its death vanish.
+fn death_fade_opacity(elapsed: f32) -> f32 {
+    let remaining = 1.0 - ((elapsed - DEATH_HOLD_SECS) / DEATH_FADE_SECS).clamp(0.0, 1.0);
+    (remaining * DEATH_FADE_STEPS).ceil() / DEATH_FADE_STEPS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpse_holds_then_fades_out_in_steps() {
+        assert_eq!(death_fade_opacity(0.0), 1.0);
+        assert_eq!(death_fade_opacity(DEATH_HOLD_SECS), 1.0);
+        assert_eq!(
+            death_fade_opacity(DEATH_HOLD_SECS + DEATH_FADE_SECS / 2.0),
+            0.5
+        );
+        assert_eq!(death_fade_opacity(DEATH_HOLD_SECS + DEATH_FADE_SECS), 0.0);
+        assert_eq!(death_fade_opacity(60.0), 0.0);
     }
 }
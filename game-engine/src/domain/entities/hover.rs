@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 
+use super::registry::EntityUnregistered;
+
 #[derive(Component, Reflect)]
 pub struct HoveredEntity;
 
@@ -22,3 +24,57 @@ pub struct EntityHoverExited {
     #[event_target]
     pub entity: Entity,
 }
+
+/// A unit that vanishes under the cursor gets no `Pointer<Out>`, so without
+/// this the cursor would stay on it and terrain hover would never come back.
+#[auto_add_system(
+    plugin = crate::app::entity_hover_plugin::EntityHoverDomainPlugin,
+    schedule = Update
+)]
+pub fn forget_unregistered_hover(
+    mut unregistered: MessageReader<EntityUnregistered>,
+    mut hovered: ResMut<CurrentlyHoveredEntity>,
+) {
+    for event in unregistered.read() {
+        if hovered.entity == Some(event.entity) {
+            hovered.entity = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vanished_unit_stops_being_hovered() {
+        let mut app = App::new();
+        app.add_message::<EntityUnregistered>()
+            .init_resource::<CurrentlyHoveredEntity>()
+            .add_systems(Update, forget_unregistered_hover);
+        let unit = Entity::from_bits(7);
+        app.world_mut()
+            .resource_mut::<CurrentlyHoveredEntity>()
+            .entity = Some(unit);
+
+        app.world_mut().write_message(EntityUnregistered {
+            gid: 1,
+            entity: Entity::from_bits(8),
+        });
+        app.update();
+        assert_eq!(
+            app.world().resource::<CurrentlyHoveredEntity>().entity,
+            Some(unit)
+        );
+
+        app.world_mut().write_message(EntityUnregistered {
+            gid: 2,
+            entity: unit,
+        });
+        app.update();
+        assert_eq!(
+            app.world().resource::<CurrentlyHoveredEntity>().entity,
+            None
+        );
+    }
+}
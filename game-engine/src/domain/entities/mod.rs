@@ -16,4 +16,4 @@ pub mod systems;
 pub mod types;
 
 pub use hover_plugin::EntityHoverPlugin;
-pub use registry::EntityRegistry;
+pub use registry::{EntityRegistered, EntityRegistry, EntityUnregistered};
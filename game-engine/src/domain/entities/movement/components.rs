@@ -263,6 +263,23 @@ impl MovementTarget {
         (elapsed_ms / total_duration_ms).min(1.0)
     }
 
+    /// Re-time the rest of the walk for a speed change without moving the entity.
+    ///
+    /// `progress` is elapsed time over the whole path's duration, so swapping the
+    /// speed alone would jump the entity along (or back) its path. Shifting
+    /// `start_time` keeps the current progress at `new_ms_per_cell`; only the
+    /// remaining distance runs at the new pace and the arrival time moves with it.
+    pub fn retime(&mut self, old_ms_per_cell: f32, new_ms_per_cell: f32) {
+        let progress = self.progress(old_ms_per_cell);
+        if progress >= 1.0 {
+            return;
+        }
+        let elapsed_ms = progress * self.total_path_length * new_ms_per_cell;
+        self.start_time = std::time::Instant::now()
+            .checked_sub(std::time::Duration::from_secs_f32(elapsed_ms / 1000.0))
+            .unwrap_or_else(std::time::Instant::now);
+    }
+
     /// Check if movement is complete
     pub fn is_complete(&self, speed_ms_per_cell: f32) -> bool {
         self.progress(speed_ms_per_cell) >= 1.0
@@ -433,6 +450,27 @@ mod tests {
         assert!((0.0..=0.1).contains(&progress));
     }
 
+    #[test]
+    fn retime_keeps_progress_across_a_speed_change() {
+        let mut target = MovementTarget::new_with_elapsed(
+            0,
+            0,
+            10,
+            0,
+            Vec3::ZERO,
+            Vec3::new(50.0, 0.0, 0.0),
+            0,
+            300,
+        );
+        let before = target.progress(100.0);
+
+        target.retime(100.0, 200.0);
+
+        assert!((target.progress(200.0) - before).abs() < 0.01);
+        // The remaining 7 cells now take 1400ms instead of 700ms.
+        assert!(target.progress(200.0) < 0.35);
+    }
+
     #[test]
     fn test_movement_state_default() {
         let state = MovementState::default();
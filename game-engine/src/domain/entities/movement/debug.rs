@@ -0,0 +1,279 @@
+//! Interpolation debug overlay.
+//!
+//! Ctrl+Shift+I toggles a gizmo overlay for the selected units (the local
+//! player, the locked target and the hovered unit): red squares are the raw
+//! cells the server reported (snapshot samples, `SelfMove` starts and server
+//! stops), the green line is where the unit was actually drawn, and a yellow
+//! line joins the newest server cell to the drawn position, so a desync shows
+//! up as a long yellow line instead of a vague "it rubber-banded".
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use super::events::{MovementConfirmed, MovementStopped, StopReason};
+use super::interpolate::world_from_cell;
+use super::snapshot::SnapshotBuffer;
+use crate::core::state::GameState;
+use crate::domain::entities::hover::CurrentlyHoveredEntity;
+use crate::domain::entities::markers::LocalPlayer;
+use crate::domain::input::{LockedTarget, ui_unfocused};
+
+/// Distance (world units) a unit must move before the drawn trail gets a new point.
+const TRAIL_STEP: f32 = 0.25;
+
+const SERVER_COLOR: Color = Color::srgb(1.0, 0.25, 0.25);
+const RENDERED_COLOR: Color = Color::srgb(0.25, 1.0, 0.4);
+const DRIFT_COLOR: Color = Color::srgb(1.0, 0.9, 0.2);
+
+/// Whether the overlay is on, and how much history each trail keeps.
+#[derive(Resource, Debug)]
+#[auto_init_resource(plugin = crate::app::movement_plugin::MovementDomainPlugin)]
+pub struct InterpolationDebug {
+    pub enabled: bool,
+    /// Points kept per trail, for both the server cells and the drawn positions.
+    pub trail_len: usize,
+}
+
+impl Default for InterpolationDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            trail_len: 64,
+        }
+    }
+}
+
+/// Server-reported cells and drawn positions of a unit under the overlay.
+#[derive(Component, Debug, Default)]
+pub struct InterpolationTrail {
+    server: VecDeque<(u16, u16)>,
+    rendered: VecDeque<Vec3>,
+}
+
+impl InterpolationTrail {
+    /// Record a server-reported cell; repeats of the newest cell are skipped.
+    pub fn push_server(&mut self, cell: (u16, u16), max_len: usize) {
+        if self.server.back() == Some(&cell) {
+            return;
+        }
+        self.server.push_back(cell);
+        while self.server.len() > max_len {
+            self.server.pop_front();
+        }
+    }
+
+    /// Record a drawn position once it is [`TRAIL_STEP`] away from the last one.
+    pub fn push_rendered(&mut self, position: Vec3, max_len: usize) {
+        if self
+            .rendered
+            .back()
+            .is_some_and(|last| last.distance(position) < TRAIL_STEP)
+        {
+            return;
+        }
+        self.rendered.push_back(position);
+        while self.rendered.len() > max_len {
+            self.rendered.pop_front();
+        }
+    }
+
+    pub fn server(&self) -> &VecDeque<(u16, u16)> {
+        &self.server
+    }
+
+    pub fn rendered(&self) -> &VecDeque<Vec3> {
+        &self.rendered
+    }
+}
+
+fn interpolation_debug_enabled(debug: Res<InterpolationDebug>) -> bool {
+    debug.enabled
+}
+
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = Update,
+    config(run_if = in_state(GameState::InGame).and_then(ui_unfocused))
+)]
+pub fn toggle_interpolation_debug(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut debug: ResMut<InterpolationDebug>,
+    trails: Query<Entity, With<InterpolationTrail>>,
+) {
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if !(ctrl && shift && keys.just_pressed(KeyCode::KeyI)) {
+        return;
+    }
+
+    debug.enabled = !debug.enabled;
+    let state = if debug.enabled { "on" } else { "off" };
+    info!("Interpolation debug overlay {state}");
+    if !debug.enabled {
+        for entity in &trails {
+            commands.entity(entity).remove::<InterpolationTrail>();
+        }
+    }
+}
+
+/// Give the selected units a trail and take it off units no longer selected.
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = Update,
+    config(
+        after = toggle_interpolation_debug,
+        run_if = in_state(GameState::InGame).and_then(interpolation_debug_enabled)
+    )
+)]
+pub fn select_interpolation_debug_targets(
+    mut commands: Commands,
+    locked: Option<Res<LockedTarget>>,
+    hovered: Option<Res<CurrentlyHoveredEntity>>,
+    local_player: Query<Entity, With<LocalPlayer>>,
+    trails: Query<Entity, With<InterpolationTrail>>,
+    units: Query<(), With<Transform>>,
+) {
+    let selected: Vec<Entity> = local_player
+        .iter()
+        .chain(locked.and_then(|locked| locked.entity))
+        .chain(hovered.and_then(|hovered| hovered.entity))
+        .filter(|&entity| units.contains(entity))
+        .collect();
+
+    for entity in &trails {
+        if !selected.contains(&entity) {
+            commands.entity(entity).remove::<InterpolationTrail>();
+        }
+    }
+    for entity in selected {
+        if !trails.contains(entity) {
+            commands
+                .entity(entity)
+                .insert(InterpolationTrail::default());
+        }
+    }
+}
+
+/// `SelfMove`: the server's start cell for the walk.
+#[auto_observer(plugin = crate::app::movement_plugin::MovementDomainPlugin)]
+pub fn record_confirmed_move_cell(
+    trigger: On<MovementConfirmed>,
+    debug: Res<InterpolationDebug>,
+    mut trails: Query<&mut InterpolationTrail>,
+) {
+    let event = trigger.event();
+    if let Ok(mut trail) = trails.get_mut(event.entity) {
+        trail.push_server((event.src_x, event.src_y), debug.trail_len);
+    }
+}
+
+/// A stop the server forced, at the cell it reported.
+#[auto_observer(plugin = crate::app::movement_plugin::MovementDomainPlugin)]
+pub fn record_server_stop_cell(
+    trigger: On<MovementStopped>,
+    debug: Res<InterpolationDebug>,
+    mut trails: Query<&mut InterpolationTrail>,
+) {
+    let event = trigger.event();
+    if event.reason != StopReason::ServerInterrupted {
+        return;
+    }
+    if let Ok(mut trail) = trails.get_mut(event.entity) {
+        trail.push_server((event.x, event.y), debug.trail_len);
+    }
+}
+
+/// Add the newest snapshot cell and the drawn position to each trail.
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = PostUpdate,
+    config(
+        after = bevy::transform::TransformSystems::Propagate,
+        run_if = interpolation_debug_enabled
+    )
+)]
+pub fn record_interpolation_trails(
+    debug: Res<InterpolationDebug>,
+    mut trails: Query<(
+        &mut InterpolationTrail,
+        &GlobalTransform,
+        Option<&SnapshotBuffer>,
+    )>,
+) {
+    for (mut trail, transform, buffer) in &mut trails {
+        if let Some(sample) = buffer.and_then(|buffer| buffer.samples().back()) {
+            trail.push_server((sample.x, sample.y), debug.trail_len);
+        }
+        trail.push_rendered(transform.translation(), debug.trail_len);
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = PostUpdate,
+    config(
+        after = record_interpolation_trails,
+        run_if = interpolation_debug_enabled
+    )
+)]
+pub fn draw_interpolation_trails(
+    mut gizmos: Gizmos,
+    trails: Query<(&InterpolationTrail, &GlobalTransform)>,
+) {
+    const CELL_MARKER: Vec2 = Vec2::splat(2.0);
+    const LIFT: Vec3 = Vec3::new(0.0, 0.3, 0.0);
+
+    for (trail, transform) in &trails {
+        let position = transform.translation();
+        // Server cells carry no height; draw them at the unit's current one.
+        let server: Vec<Vec3> = trail
+            .server()
+            .iter()
+            .map(|&(x, y)| world_from_cell(x as f32, y as f32).with_y(position.y) + LIFT)
+            .collect();
+
+        for &cell in &server {
+            gizmos.rect(
+                Isometry3d::new(cell, Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                CELL_MARKER,
+                SERVER_COLOR,
+            );
+        }
+        gizmos.linestrip(server.iter().copied(), SERVER_COLOR.with_alpha(0.5));
+        gizmos.linestrip(trail.rendered().iter().map(|&p| p + LIFT), RENDERED_COLOR);
+        gizmos.sphere(
+            Isometry3d::from_translation(position + LIFT),
+            0.6,
+            RENDERED_COLOR,
+        );
+
+        if let Some(&newest) = server.last() {
+            gizmos.line(newest, position + LIFT, DRIFT_COLOR);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trails_skip_repeats_and_keep_the_newest_points() {
+        let mut trail = InterpolationTrail::default();
+        for cell in [(1, 1), (1, 1), (2, 1), (3, 1), (4, 1)] {
+            trail.push_server(cell, 3);
+        }
+        assert_eq!(
+            trail.server().iter().copied().collect::<Vec<_>>(),
+            vec![(2, 1), (3, 1), (4, 1)]
+        );
+
+        trail.push_rendered(Vec3::ZERO, 3);
+        trail.push_rendered(Vec3::new(0.1, 0.0, 0.0), 3);
+        trail.push_rendered(Vec3::new(1.0, 0.0, 0.0), 3);
+        assert_eq!(trail.rendered().len(), 2);
+    }
+}
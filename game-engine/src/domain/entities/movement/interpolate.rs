@@ -198,7 +198,7 @@ pub fn interpolate_remote_entities_system(
 
 /// World position for a fractional cell. `spawn_coords_to_world_position` only takes
 /// integer cells, so we replicate its linear `cell * 5.0` mapping for the fractional case.
-fn world_from_cell(x: f32, y: f32) -> Vec3 {
+pub(super) fn world_from_cell(x: f32, y: f32) -> Vec3 {
     Vec3::new(x * RO_UNITS_PER_CELL, 0.0, y * RO_UNITS_PER_CELL)
 }
 
@@ -1,7 +1,9 @@
 pub mod components;
+pub mod debug;
 pub mod events;
 pub mod interpolate;
 pub mod plugin;
+pub mod prediction;
 pub mod snapshot;
 pub mod systems;
 
@@ -10,8 +10,10 @@ use bevy::prelude::*;
 /// # System Flow
 ///
 /// 1. `send_movement_requests_observer` - Consumes MovementRequested, sends to server
+///    (`predict_requested_movement` starts walking the local path right away)
 /// 2. Server validates and responds with ZC_NOTIFY_PLAYERMOVE
-/// 3. `handle_movement_confirmed_system` - Starts interpolation, updates direction
+/// 3. `handle_movement_confirmed_system` - Reconciles the prediction or starts interpolation
+///    (`expire_move_predictions` rolls back requests the server never confirmed)
 /// 4. `interpolate_movement_system` - Runs every frame to move character smoothly
 /// 5. `handle_server_stop_system` - Cleanup when movement completes
 /// 6. `update_entity_altitude_system` - Updates entity height based on terrain
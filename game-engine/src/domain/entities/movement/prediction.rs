@@ -0,0 +1,315 @@
+//! Client-side prediction for the local player's walk.
+//!
+//! A click starts walking along the locally computed path at once instead of
+//! waiting a round trip for `SelfMove`. [`PredictedMove`] tracks the requests the
+//! server has not answered yet and how the confirmation is settled:
+//!
+//! - `SelfMove` for the newest request confirms it. The walk continues untouched
+//!   unless the server started it more than [`RECONCILE_SNAP_CELLS`] away from
+//!   where the client did; then the confirmed path replaces it from the server's
+//!   start cell.
+//! - `SelfMove` for an older request that a newer click replaced is dropped.
+//! - Any other `SelfMove` (a server-initiated walk) is authoritative and blends
+//!   from the current position, as before prediction.
+//! - No answer within [`PREDICTION_TIMEOUT`] means the server refused the move
+//!   (aesir has no explicit rejection), so the player rolls back to the cell the
+//!   first unanswered request started from and stops.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use moonshine_behavior::prelude::*;
+
+use super::components::MovementState;
+use super::events::{MovementRequested, MovementStopped, StopReason};
+use super::systems::movement_target;
+use crate::{
+    core::state::GameState,
+    domain::{
+        entities::{
+            character::{
+                components::visual::{CharacterDirection, Direction},
+                states::AnimationState,
+            },
+            pathfinding::WalkablePath,
+        },
+        system_sets::MovementSystems,
+    },
+    utils::coordinates::{spawn_coords_to_world_position, world_position_to_spawn_coords},
+};
+
+/// How long a request may go unanswered before it counts as rejected.
+const PREDICTION_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Start-cell disagreement (Chebyshev cells) beyond which a confirmed
+/// prediction snaps onto the server's path instead of carrying on.
+const RECONCILE_SNAP_CELLS: u16 = 2;
+
+/// One unanswered move request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingMove {
+    from: (u16, u16),
+    dest: (u16, u16),
+}
+
+/// How a server confirmation settles the prediction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reconcile {
+    /// The newest request was confirmed; keep the predicted walk.
+    Accept,
+    /// A request a newer click replaced was confirmed; ignore it.
+    Superseded,
+    /// The server's walk differs; follow it, jumping to its start when `snap`.
+    Correct { snap: bool },
+}
+
+/// Unanswered move requests of the local player, oldest first.
+#[derive(Component, Debug)]
+pub struct PredictedMove {
+    pending: Vec<PendingMove>,
+    timeout: Timer,
+}
+
+impl PredictedMove {
+    fn new(from: (u16, u16), dest: (u16, u16)) -> Self {
+        Self {
+            pending: vec![PendingMove { from, dest }],
+            timeout: Timer::new(PREDICTION_TIMEOUT, TimerMode::Once),
+        }
+    }
+
+    /// Queue a newer request; the rollback cell stays the oldest one's start.
+    fn push(&mut self, from: (u16, u16), dest: (u16, u16)) {
+        self.pending.push(PendingMove { from, dest });
+        self.timeout.reset();
+    }
+
+    /// Cell the player returns to if every pending request is rejected.
+    pub fn rollback_cell(&self) -> Option<(u16, u16)> {
+        self.pending.first().map(|pending| pending.from)
+    }
+
+    /// No requests left to confirm.
+    pub fn is_settled(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Settle a server confirmation of the walk `src -> dest`.
+    pub fn reconcile(&mut self, src: (u16, u16), dest: (u16, u16)) -> Reconcile {
+        let Some(index) = self.pending.iter().position(|p| p.dest == dest) else {
+            self.pending.clear();
+            return Reconcile::Correct { snap: false };
+        };
+        if index + 1 < self.pending.len() {
+            self.pending.drain(..=index);
+            return Reconcile::Superseded;
+        }
+
+        let from = self.pending[index].from;
+        self.pending.clear();
+        let drift = from.0.abs_diff(src.0).max(from.1.abs_diff(src.1));
+        if drift > RECONCILE_SNAP_CELLS {
+            Reconcile::Correct { snap: true }
+        } else {
+            Reconcile::Accept
+        }
+    }
+}
+
+type PredictingMoverQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        Option<&'static WalkablePath>,
+        Option<&'static MovementState>,
+        Option<&'static mut PredictedMove>,
+    ),
+>;
+
+/// Starts walking as soon as a move is requested, along the path the click
+/// handler computed, and records the request for reconciliation.
+#[auto_observer(plugin = crate::app::movement_plugin::MovementDomainPlugin)]
+pub fn predict_requested_movement(
+    trigger: On<MovementRequested>,
+    mut commands: Commands,
+    mut query: PredictingMoverQuery,
+    mut behaviors: Query<BehaviorMut<AnimationState>>,
+) {
+    let event = trigger.event();
+    let entity = event.entity;
+    let Ok((transform, path, state, prediction)) = query.get_mut(entity) else {
+        return;
+    };
+
+    let src = world_position_to_spawn_coords(transform.translation, 0, 0);
+    let dest = (event.dest_x, event.dest_y);
+    if src == dest {
+        return;
+    }
+    let path = path.filter(|path| path.final_destination == dest);
+    let src_world_pos = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+    let target = movement_target(src, src_world_pos, dest, path, 0);
+
+    let facing =
+        Direction::from_movement_vector(dest.0 as f32 - src.0 as f32, dest.1 as f32 - src.1 as f32);
+    let already_walking = state == Some(&MovementState::Moving);
+
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.insert((target, MovementState::Moving, CharacterDirection { facing }));
+    match prediction {
+        Some(mut prediction) => prediction.push(src, dest),
+        None => {
+            entity_commands.insert(PredictedMove::new(src, dest));
+        }
+    }
+
+    if !already_walking && let Ok(mut behavior) = behaviors.get_mut(entity) {
+        behavior.start(AnimationState::Walking);
+    }
+
+    debug!(
+        "Predicting walk for {:?}: ({}, {}) -> ({}, {})",
+        entity, src.0, src.1, dest.0, dest.1
+    );
+}
+
+/// Rolls back predictions the server never confirmed: back to the cell the
+/// first unanswered request started from, standing.
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = Update,
+    config(
+        in_set = MovementSystems::Confirm,
+        after = super::systems::handle_movement_confirmed_system,
+        run_if = in_state(GameState::InGame)
+    )
+)]
+pub fn expire_move_predictions(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut PredictedMove, &mut Transform)>,
+) {
+    for (entity, mut prediction, mut transform) in &mut query {
+        if !prediction.timeout.tick(time.delta()).is_finished() {
+            continue;
+        }
+        commands.entity(entity).remove::<PredictedMove>();
+        let Some((x, y)) = prediction.rollback_cell() else {
+            continue;
+        };
+
+        warn!(
+            "Move not confirmed within {:?}; rolling {:?} back to ({}, {})",
+            PREDICTION_TIMEOUT, entity, x, y
+        );
+        let origin = spawn_coords_to_world_position(x, y, 0, 0);
+        transform.translation.x = origin.x;
+        transform.translation.z = origin.z;
+        commands.trigger(MovementStopped {
+            entity,
+            x,
+            y,
+            reason: StopReason::Blocked,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::movement::components::MovementTarget;
+    use bevy::time::TimeUpdateStrategy;
+
+    #[test]
+    fn newest_confirmation_is_accepted() {
+        let mut prediction = PredictedMove::new((10, 10), (20, 10));
+        assert_eq!(prediction.reconcile((11, 10), (20, 10)), Reconcile::Accept);
+        assert!(prediction.is_settled());
+    }
+
+    #[test]
+    fn diverging_start_snaps_to_the_server_path() {
+        let mut prediction = PredictedMove::new((10, 10), (20, 10));
+        assert_eq!(
+            prediction.reconcile((15, 10), (20, 10)),
+            Reconcile::Correct { snap: true }
+        );
+    }
+
+    #[test]
+    fn older_confirmation_is_superseded() {
+        let mut prediction = PredictedMove::new((10, 10), (20, 10));
+        prediction.push((12, 10), (12, 20));
+
+        assert_eq!(
+            prediction.reconcile((10, 10), (20, 10)),
+            Reconcile::Superseded
+        );
+        assert!(!prediction.is_settled());
+        assert_eq!(prediction.rollback_cell(), Some((12, 10)));
+        assert_eq!(prediction.reconcile((12, 10), (12, 20)), Reconcile::Accept);
+    }
+
+    #[test]
+    fn unrequested_walk_is_a_correction() {
+        let mut prediction = PredictedMove::new((10, 10), (20, 10));
+        assert_eq!(
+            prediction.reconcile((10, 10), (5, 5)),
+            Reconcile::Correct { snap: false }
+        );
+        assert!(prediction.is_settled());
+    }
+
+    #[test]
+    fn request_walks_immediately_and_rolls_back_when_unconfirmed() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<GameState>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                200,
+            )))
+            .add_observer(predict_requested_movement)
+            .add_systems(Update, expire_move_predictions);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+
+        let start = spawn_coords_to_world_position(10, 10, 0, 0);
+        let player = app
+            .world_mut()
+            .spawn((Transform::from_translation(start), MovementState::Idle))
+            .id();
+        app.world_mut().trigger(MovementRequested {
+            entity: player,
+            dest_x: 20,
+            dest_y: 10,
+            direction: 0,
+        });
+        app.update();
+
+        assert!(app.world().get::<MovementTarget>(player).is_some());
+        assert_eq!(
+            *app.world().get::<MovementState>(player).unwrap(),
+            MovementState::Moving
+        );
+
+        // Pretend the walk progressed, then let the timeout lapse unanswered.
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .x += 20.0;
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert!(app.world().get::<PredictedMove>(player).is_none());
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert_eq!(transform.translation.x, start.x);
+        assert_eq!(transform.translation.z, start.z);
+    }
+}
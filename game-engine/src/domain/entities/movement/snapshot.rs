@@ -6,6 +6,11 @@
 //! per-entity sample buffer ([`SnapshotBuffer`]) from [`SnapshotReceived`].
 //!
 //! It does NOT move entities — interpolation reads these buffers in a later step.
+//!
+//! A unit that enters view mid-walk (aesir's form of `ZC_NOTIFY_MOVE`) announces
+//! its destination up front; [`walk_samples`] turns that path into *planned*
+//! samples so it walks from the first frame. The first real snapshot replaces
+//! whatever part of the plan it overtakes.
 
 use std::collections::VecDeque;
 
@@ -13,6 +18,7 @@ use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 
 use crate::core::state::GameState;
+use crate::domain::entities::character::components::visual::Direction;
 use crate::domain::entities::registry::EntityRegistry;
 use net_contract::events::SnapshotReceived;
 
@@ -49,10 +55,60 @@ pub struct SnapshotSample {
     pub move_state: u8,
 }
 
+/// Walk cost of a diagonal step relative to a straight one (aesir's 14/10).
+const DIAGONAL_COST: f32 = 1.4;
+
+/// Planned samples for a walk along `waypoints` (corners, start included) that
+/// began at server time `start_tick`: moving at every corner, idle on arrival.
+/// Empty when there is nowhere to walk.
+pub fn walk_samples(
+    waypoints: &[(u16, u16)],
+    start_tick: u64,
+    ms_per_cell: f32,
+) -> Vec<SnapshotSample> {
+    let mut samples = Vec::with_capacity(waypoints.len());
+    let mut tick = start_tick as f32;
+    let mut dir = 0;
+    for pair in waypoints.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        let dx = x1 as f32 - x0 as f32;
+        let dy = y1 as f32 - y0 as f32;
+        if dx == 0.0 && dy == 0.0 {
+            continue;
+        }
+        dir = Direction::from_movement_vector(dx, dy) as u8;
+        samples.push(SnapshotSample {
+            server_tick: tick as u64,
+            x: x0,
+            y: y0,
+            dir,
+            move_state: 1,
+        });
+        let diagonal = dx.abs().min(dy.abs());
+        let straight = dx.abs().max(dy.abs()) - diagonal;
+        tick += (straight + diagonal * DIAGONAL_COST) * ms_per_cell;
+    }
+    if let Some(&(x, y)) = waypoints.last()
+        && !samples.is_empty()
+    {
+        samples.push(SnapshotSample {
+            server_tick: tick as u64,
+            x,
+            y,
+            dir,
+            move_state: 0,
+        });
+    }
+    samples
+}
+
 /// Ring of recent [`SnapshotSample`]s for a remote entity, ordered by `server_tick`.
+///
+/// The newest `planned` samples are a predicted walk rather than server reports.
 #[derive(Component, Default, Debug)]
 pub struct SnapshotBuffer {
     samples: VecDeque<SnapshotSample>,
+    planned: usize,
 }
 
 impl SnapshotBuffer {
@@ -63,15 +119,35 @@ impl SnapshotBuffer {
         buffer
     }
 
+    /// Create a buffer holding a planned walk (see [`walk_samples`]). The plan is
+    /// not trimmed to capacity, so a long path survives until snapshots take over.
+    pub fn planned(samples: Vec<SnapshotSample>) -> Self {
+        Self {
+            planned: samples.len(),
+            samples: samples.into(),
+        }
+    }
+
     /// Append a sample, dropping out-of-order/duplicate ticks and trimming to capacity.
+    /// A server sample first discards the planned samples it overtakes.
     pub fn push(&mut self, sample: SnapshotSample) {
+        while self.planned > 0
+            && self
+                .samples
+                .back()
+                .is_some_and(|last| last.server_tick >= sample.server_tick)
+        {
+            self.samples.pop_back();
+            self.planned -= 1;
+        }
+        self.planned = 0;
         if let Some(last) = self.samples.back()
             && sample.server_tick <= last.server_tick
         {
             return;
         }
         self.samples.push_back(sample);
-        if self.samples.len() > BUFFER_CAPACITY {
+        while self.samples.len() > BUFFER_CAPACITY {
             self.samples.pop_front();
         }
     }
@@ -193,6 +269,43 @@ mod tests {
         assert_eq!(newest, Some((BUFFER_CAPACITY as u64 + 3) * 10));
     }
 
+    #[test]
+    fn walk_samples_time_each_leg_by_walk_speed() {
+        let samples = walk_samples(&[(10, 10), (13, 10), (15, 12)], 1_000, 100.0);
+
+        let ticks: Vec<u64> = samples.iter().map(|s| s.server_tick).collect();
+        assert_eq!(ticks, vec![1_000, 1_300, 1_580]);
+        assert_eq!((samples[2].x, samples[2].y), (15, 12));
+        assert_eq!(samples[0].dir, Direction::East as u8);
+        assert_eq!(samples[1].dir, Direction::NorthEast as u8);
+        assert!(samples[..2].iter().all(|s| s.move_state == 1));
+        assert_eq!(samples[2].move_state, 0);
+
+        assert!(walk_samples(&[(5, 5)], 0, 100.0).is_empty());
+        assert!(walk_samples(&[(5, 5), (5, 5)], 0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn server_sample_replaces_overtaken_plan() {
+        let mut buffer = SnapshotBuffer::planned(walk_samples(&[(0, 0), (10, 0)], 100, 100.0));
+        assert_eq!(buffer.samples().len(), 2);
+
+        // The server reports the walk at tick 600, before the planned arrival at 1100.
+        buffer.push(SnapshotSample {
+            server_tick: 600,
+            x: 5,
+            y: 0,
+            dir: Direction::East as u8,
+            move_state: 1,
+        });
+        let ticks: Vec<u64> = buffer.samples().iter().map(|s| s.server_tick).collect();
+        assert_eq!(ticks, vec![100, 600]);
+
+        // Later samples are plain server history again.
+        buffer.push(sample(700));
+        assert_eq!(buffer.samples().len(), 3);
+    }
+
     #[test]
     fn server_clock_offset_and_now() {
         let clock = ServerClock {
@@ -1,5 +1,6 @@
 use super::components::{MovementSpeed, MovementState, MovementTarget};
 use super::events::{MovementConfirmed, MovementRequested, MovementStopped, StopReason};
+use super::prediction::{PredictedMove, Reconcile};
 use crate::{
     core::state::GameState,
     domain::{
@@ -51,6 +52,53 @@ pub fn send_movement_requests_observer(
     });
 }
 
+/// Movement target from `src` to `dest`, following `path`'s waypoints when it has
+/// more than one (the caller checks the path leads to `dest`).
+pub(super) fn movement_target(
+    src: (u16, u16),
+    src_world_pos: Vec3,
+    dest: (u16, u16),
+    path: Option<&WalkablePath>,
+    start_tick: u32,
+) -> MovementTarget {
+    let dest_world_pos = spawn_coords_to_world_position(dest.0, dest.1, 0, 0);
+    match path {
+        Some(path) if path.waypoints.len() > 1 => {
+            let waypoint_world_positions: Vec<Vec3> = path
+                .waypoints
+                .iter()
+                .map(|(x, y)| spawn_coords_to_world_position(*x, *y, 0, 0))
+                .collect();
+
+            debug!(
+                "Creating multi-waypoint movement target with {} waypoints",
+                waypoint_world_positions.len()
+            );
+
+            MovementTarget::new_with_waypoints(
+                src.0,
+                src.1,
+                dest.0,
+                dest.1,
+                src_world_pos,
+                dest_world_pos,
+                start_tick,
+                waypoint_world_positions,
+                path.waypoints.clone(),
+            )
+        }
+        _ => MovementTarget::new(
+            src.0,
+            src.1,
+            dest.0,
+            dest.1,
+            src_world_pos,
+            dest_world_pos,
+            start_tick,
+        ),
+    }
+}
+
 /// Local-player-space view of a `SelfMoved`, casting proto u32 coords back to the
 /// u16 cell space the interpolation path uses.
 struct MovementConfirmedFields {
@@ -78,6 +126,7 @@ pub fn handle_movement_confirmed_system(
     movement_states: Query<&MovementState>,
     mut behaviors: Query<BehaviorMut<AnimationState>>,
     pathfinding_grid: Option<Res<CurrentMapPathfindingGrid>>,
+    mut predictions: Query<&mut PredictedMove>,
 ) {
     for moved in server_events.read() {
         // SelfMove targets the local player (the proto carries no entity id).
@@ -107,7 +156,42 @@ pub fn handle_movement_confirmed_system(
             entity, event.src_x, event.src_y, event.dest_x, event.dest_y, event.server_tick
         );
 
-        let (actual_src_x, actual_src_y, src_world_pos) = if existing_target.is_some() {
+        // Settle any client-side prediction first: a confirmed prediction keeps
+        // walking as-is, a superseded one is dropped, anything else is corrected
+        // onto the server's path below.
+        let mut snap_to_server = false;
+        if let Ok(mut prediction) = predictions.get_mut(entity) {
+            let outcome =
+                prediction.reconcile((event.src_x, event.src_y), (event.dest_x, event.dest_y));
+            if prediction.is_settled() {
+                commands.entity(entity).remove::<PredictedMove>();
+            }
+            match outcome {
+                Reconcile::Superseded => {
+                    debug!(
+                        "Ignoring confirmation of superseded move to ({}, {})",
+                        event.dest_x, event.dest_y
+                    );
+                    continue;
+                }
+                Reconcile::Accept => {
+                    commands.trigger(MovementConfirmed {
+                        entity,
+                        src_x: event.src_x,
+                        src_y: event.src_y,
+                        dest_x: event.dest_x,
+                        dest_y: event.dest_y,
+                        server_tick: event.server_tick,
+                    });
+                    continue;
+                }
+                Reconcile::Correct { snap } => snap_to_server = snap,
+            }
+        }
+
+        let (actual_src_x, actual_src_y, src_world_pos) = if existing_target.is_some()
+            && !snap_to_server
+        {
             let current_pos = transform.translation;
             let (current_x, current_y) =
                 crate::utils::coordinates::world_position_to_spawn_coords(current_pos, 0, 0);
@@ -124,9 +208,8 @@ pub fn handle_movement_confirmed_system(
             (event.src_x, event.src_y, pos)
         };
 
-        let dest_world_pos = spawn_coords_to_world_position(event.dest_x, event.dest_y, 0, 0);
-
         let path_to_use = walkable_path
+            .filter(|_| !snap_to_server)
             .filter(|path| {
                 let destination_matches = path.final_destination == (event.dest_x, event.dest_y);
                 if destination_matches {
@@ -178,42 +261,13 @@ pub fn handle_movement_confirmed_system(
             path_to_use
         };
 
-        let target = if let Some(path) = path_to_use {
-            let waypoint_world_positions: Vec<Vec3> = path
-                .waypoints
-                .iter()
-                .map(|(x, y)| spawn_coords_to_world_position(*x, *y, 0, 0))
-                .collect();
-
-            let waypoint_cell_coords = path.waypoints.clone();
-
-            debug!(
-                "Creating multi-waypoint movement target with {} waypoints",
-                waypoint_world_positions.len()
-            );
-
-            MovementTarget::new_with_waypoints(
-                actual_src_x,
-                actual_src_y,
-                event.dest_x,
-                event.dest_y,
-                src_world_pos,
-                dest_world_pos,
-                event.server_tick,
-                waypoint_world_positions,
-                waypoint_cell_coords,
-            )
-        } else {
-            MovementTarget::new(
-                actual_src_x,
-                actual_src_y,
-                event.dest_x,
-                event.dest_y,
-                src_world_pos,
-                dest_world_pos,
-                event.server_tick,
-            )
-        };
+        let target = movement_target(
+            (actual_src_x, actual_src_y),
+            src_world_pos,
+            (event.dest_x, event.dest_y),
+            path_to_use.as_ref(),
+            event.server_tick,
+        );
 
         let dx = (event.dest_x as f32) - (actual_src_x as f32);
         let dy = (event.dest_y as f32) - (actual_src_y as f32);
@@ -356,11 +410,99 @@ pub fn handle_server_stop_system(
     }
 }
 
+/// Re-paths walks whose remaining route runs into a cell that became blocked at
+/// runtime (an Ice Wall going up across it). The new path starts from where the
+/// unit is now and still ends at the server's destination; if the wall cuts the
+/// destination off entirely, the walk is left alone for the server's stop to end.
+#[auto_add_system(
+    plugin = crate::app::movement_plugin::MovementDomainPlugin,
+    schedule = Update,
+    config(
+        in_set = MovementSystems::Confirm,
+        run_if = in_state(GameState::InGame)
+            .and_then(resource_exists_and_changed::<CurrentMapPathfindingGrid>)
+    )
+)]
+pub fn reroute_blocked_paths(
+    mut commands: Commands,
+    pathfinding_grid: Res<CurrentMapPathfindingGrid>,
+    walkers: Query<(
+        Entity,
+        &Transform,
+        &WalkablePath,
+        &MovementTarget,
+        &MovementSpeed,
+    )>,
+) {
+    let grid = &pathfinding_grid.0;
+    for (entity, transform, path, target, speed) in &walkers {
+        let current = world_position_to_spawn_coords(transform.translation, 0, 0);
+        let remaining = remaining_waypoints(current, path, target, speed);
+        if grid.is_path_walkable(&remaining) {
+            continue;
+        }
+
+        let dest = path.final_destination;
+        let Some(waypoints) = find_path(grid, current, dest) else {
+            debug!(
+                "Path of {:?} to ({}, {}) is blocked with no way around",
+                entity, dest.0, dest.1
+            );
+            continue;
+        };
+
+        debug!(
+            "Rerouting {:?} around a blocked cell: {} waypoints to ({}, {})",
+            entity,
+            waypoints.len(),
+            dest.0,
+            dest.1
+        );
+        let new_path = WalkablePath::new(waypoints, dest);
+        let current_world_pos = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+        let new_target = movement_target(
+            current,
+            current_world_pos,
+            dest,
+            Some(&new_path),
+            target.start_tick,
+        );
+        commands.entity(entity).insert((new_path, new_target));
+    }
+}
+
+/// The part of `path` still ahead of a unit standing on `current`: from there
+/// through every waypoint it has not passed yet, judged by how far along the
+/// walk the interpolation is.
+fn remaining_waypoints(
+    current: (u16, u16),
+    path: &WalkablePath,
+    target: &MovementTarget,
+    speed: &MovementSpeed,
+) -> Vec<(u16, u16)> {
+    let travelled = target.progress(speed.ms_per_cell) * target.total_path_length_world;
+    let passed = target
+        .segment_distances
+        .iter()
+        .take_while(|&&distance| distance <= travelled)
+        .count()
+        .min(path.waypoints.len());
+    std::iter::once(current)
+        .chain(path.waypoints[passed..].iter().copied())
+        .collect()
+}
+
 /// Mirrors the server's walk-speed param (var 0) into the local player's
 /// interpolation speed. The server steps its position at `walk_speed` ms per
 /// cell; without this the sprite always covers cells at the default 150 and
 /// drifts behind (or ahead of) the authoritative walk whenever a status
 /// changes the speed (cart weight, Agi buffs, Quagmire, Free Cast).
+///
+/// A change mid-walk re-times the active target (see [`MovementTarget::retime`])
+/// so the sprite keeps its place on the path and only the remainder speeds up
+/// or slows down; the walk animation follows the new speed through
+/// `sync_sprite_action_impl`. Remote units only announce their speed when they
+/// enter view, which refreshes the same component.
 #[auto_add_system(
     plugin = crate::app::movement_plugin::MovementDomainPlugin,
     schedule = Update,
@@ -368,6 +510,7 @@ pub fn handle_server_stop_system(
 )]
 pub fn sync_walk_speed_from_params(
     mut events: MessageReader<StatusParameterChanged>,
+    mut walkers: Query<(&mut MovementSpeed, Option<&mut MovementTarget>)>,
     mut commands: Commands,
 ) {
     for event in events.read() {
@@ -375,11 +518,20 @@ pub fn sync_walk_speed_from_params(
             continue;
         }
 
+        let speed = MovementSpeed::from_server_speed(event.new_value as u16);
+        if let Ok((mut current, target)) = walkers.get_mut(event.entity) {
+            if let Some(mut target) = target {
+                target.retime(current.ms_per_cell, speed.ms_per_cell);
+            }
+            *current = speed;
+            continue;
+        }
+
         let Ok(mut entity_commands) = commands.get_entity(event.entity) else {
             continue;
         };
 
-        entity_commands.insert(MovementSpeed::from_server_speed(event.new_value as u16));
+        entity_commands.insert(speed);
     }
 }
 
@@ -520,6 +672,7 @@ pub fn update_entity_altitude_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::pathfinding::PathfindingGrid;
 
     fn cancel_app() -> App {
         let mut app = App::new();
@@ -594,6 +747,67 @@ mod tests {
         assert!(app.world().get::<MovementTarget>(entity).is_some());
     }
 
+    fn reroute_app(grid: PathfindingGrid) -> App {
+        let mut app = App::new();
+        app.insert_resource(CurrentMapPathfindingGrid(grid))
+            .add_systems(Update, reroute_blocked_paths);
+        app
+    }
+
+    fn walking_along(app: &mut App, src: (u16, u16), dest: (u16, u16)) -> Entity {
+        let grid = &app.world().resource::<CurrentMapPathfindingGrid>().0;
+        let path = WalkablePath::new(find_path(grid, src, dest).unwrap(), dest);
+        let src_world_pos = spawn_coords_to_world_position(src.0, src.1, 0, 0);
+        let target = movement_target(src, src_world_pos, dest, Some(&path), 0);
+        app.world_mut()
+            .spawn((
+                path,
+                target,
+                MovementSpeed::default(),
+                Transform::from_translation(src_world_pos),
+            ))
+            .id()
+    }
+
+    fn block(app: &mut App, cell: (u16, u16)) {
+        app.world_mut()
+            .resource_mut::<CurrentMapPathfindingGrid>()
+            .0
+            .block_cell(cell.0, cell.1);
+    }
+
+    #[test]
+    fn walk_through_a_new_wall_is_rerouted_around_it() {
+        let mut app = reroute_app(PathfindingGrid::open(20, 20));
+        let entity = walking_along(&mut app, (2, 5), (12, 5));
+        block(&mut app, (7, 5));
+
+        app.update();
+
+        let grid = &app.world().resource::<CurrentMapPathfindingGrid>().0;
+        let path = app.world().get::<WalkablePath>(entity).unwrap();
+        assert_eq!(path.final_destination, (12, 5));
+        assert_eq!(path.waypoints.first(), Some(&(2, 5)));
+        assert!(grid.is_path_walkable(&path.waypoints));
+        let target = app.world().get::<MovementTarget>(entity).unwrap();
+        assert_eq!(
+            target.waypoints.as_ref().unwrap().len(),
+            path.waypoints.len()
+        );
+    }
+
+    #[test]
+    fn wall_off_the_route_leaves_the_walk_alone() {
+        let mut app = reroute_app(PathfindingGrid::open(20, 20));
+        let entity = walking_along(&mut app, (2, 5), (12, 5));
+        block(&mut app, (7, 9));
+
+        app.update();
+
+        let path = app.world().get::<WalkablePath>(entity).unwrap();
+        assert_eq!(path.waypoints, vec![(2, 5), (12, 5)]);
+    }
+
     #[test]
     fn test_direction_from_movement() {
         assert_eq!(Direction::from_movement_vector(1.0, 0.0), Direction::East);
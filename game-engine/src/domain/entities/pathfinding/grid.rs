@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use crate::infrastructure::ro_formats::gat::RoAltitude;
 
+/// Walkability of the current map: the static GAT cells plus runtime obstacles
+/// (Ice Wall and the like) layered on top.
 #[derive(Clone)]
 pub struct PathfindingGrid {
     width: u32,
     height: u32,
     walkability: Vec<bool>,
+    /// Cells blocked at runtime, counted so overlapping obstacles only free a
+    /// cell once the last of them is gone.
+    blocked: HashMap<(u16, u16), u32>,
 }
 
 impl PathfindingGrid {
@@ -23,6 +30,7 @@ impl PathfindingGrid {
             width,
             height,
             walkability,
+            blocked: HashMap::new(),
         }
     }
 
@@ -32,7 +40,44 @@ impl PathfindingGrid {
         }
 
         let index = (y as usize) * (self.width as usize) + (x as usize);
-        self.walkability.get(index).copied().unwrap_or(false)
+        self.walkability.get(index).copied().unwrap_or(false) && !self.blocked.contains_key(&(x, y))
+    }
+
+    /// Block a cell until a matching `unblock_cell`.
+    pub fn block_cell(&mut self, x: u16, y: u16) {
+        *self.blocked.entry((x, y)).or_default() += 1;
+    }
+
+    /// Release one `block_cell` on this cell. Unblocking a cell that was never
+    /// blocked is a no-op, so a stale release can't open up static walls.
+    pub fn unblock_cell(&mut self, x: u16, y: u16) {
+        if let Some(count) = self.blocked.get_mut(&(x, y)) {
+            *count -= 1;
+            if *count == 0 {
+                self.blocked.remove(&(x, y));
+            }
+        }
+    }
+
+    /// Whether every cell the straight segments between `waypoints` pass
+    /// through is walkable.
+    pub fn is_path_walkable(&self, waypoints: &[(u16, u16)]) -> bool {
+        waypoints.windows(2).all(|segment| {
+            let (from, to) = (segment[0], segment[1]);
+            let dx = to.0 as i32 - from.0 as i32;
+            let dy = to.1 as i32 - from.1 as i32;
+            let steps = dx.abs().max(dy.abs());
+            (0..=steps).all(|step| {
+                let t = if steps == 0 {
+                    0.0
+                } else {
+                    step as f32 / steps as f32
+                };
+                let x = (from.0 as f32 + dx as f32 * t).round() as u16;
+                let y = (from.1 as f32 + dy as f32 * t).round() as u16;
+                self.is_walkable(x, y)
+            })
+        })
     }
 
     pub fn width(&self) -> u32 {
@@ -43,3 +88,49 @@ impl PathfindingGrid {
         self.height
     }
 }
+
+#[cfg(test)]
+impl PathfindingGrid {
+    /// A fully walkable `width` x `height` grid.
+    pub(crate) fn open(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            walkability: vec![true; (width * height) as usize],
+            blocked: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocked_cells_stay_blocked_until_every_obstacle_is_gone() {
+        let mut grid = PathfindingGrid::open(4, 4);
+        grid.block_cell(1, 1);
+        grid.block_cell(1, 1);
+        assert!(!grid.is_walkable(1, 1));
+
+        grid.unblock_cell(1, 1);
+        assert!(!grid.is_walkable(1, 1));
+        grid.unblock_cell(1, 1);
+        assert!(grid.is_walkable(1, 1));
+
+        grid.unblock_cell(1, 1);
+        grid.block_cell(1, 1);
+        assert!(!grid.is_walkable(1, 1));
+    }
+
+    #[test]
+    fn path_walkability_covers_the_cells_between_waypoints() {
+        let mut grid = PathfindingGrid::open(8, 8);
+        let path = [(0, 0), (4, 0), (4, 4)];
+        assert!(grid.is_path_walkable(&path));
+
+        grid.block_cell(4, 2);
+        assert!(!grid.is_path_walkable(&path));
+        assert!(grid.is_path_walkable(&path[..2]));
+    }
+}
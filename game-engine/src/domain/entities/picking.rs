@@ -5,14 +5,29 @@ use crate::domain::entities::components::NetworkEntity;
 use crate::domain::entities::hover::{
     CurrentlyHoveredEntity, EntityHoverEntered, EntityHoverExited, HoveredEntity,
 };
-use crate::domain::entities::markers::{Mob, Npc};
+use crate::domain::entities::markers::{LocalPlayer, Mob, Npc};
 use crate::domain::entities::types::ObjectType;
 use crate::domain::input::terrain_raycast::TerrainRaycastCache;
-use crate::domain::input::{CursorChangeRequest, CursorType, LockedTarget, TargetingMode};
+use crate::domain::input::{
+    CursorChangeRequest, CursorTarget, CursorType, LockedTarget, TargetingMode,
+};
 use crate::domain::item_drop::HoveredFloorItem;
 use crate::domain::item_drop::components::FloorItem;
 use crate::domain::item_drop::pickup::{PendingPickups, PickupInfo};
 use crate::domain::skill::{CastTarget, SkillCastResolved};
+use crate::utils::coordinates::world_position_to_spawn_coords;
+
+/// Farthest an NPC may be, in cells on either axis, for a click to start a
+/// conversation. Matches the server's view range (AREA_SIZE); CZ_CONTACTNPC
+/// from farther away is rejected, so the click is not sent at all.
+pub const NPC_TALK_RANGE: u16 = 14;
+
+/// Whether an NPC at `npc` is close enough to the player at `player` to talk to.
+pub fn within_talk_range(player: Vec3, npc: Vec3) -> bool {
+    let (player_x, player_y) = world_position_to_spawn_coords(player, 0, 0);
+    let (npc_x, npc_y) = world_position_to_spawn_coords(npc, 0, 0);
+    player_x.abs_diff(npc_x) <= NPC_TALK_RANGE && player_y.abs_diff(npc_y) <= NPC_TALK_RANGE
+}
 
 /// Root entity that owns the picked body billboard. The billboard is a
 /// `ChildOf` the `NetworkEntity`/`FloorItem` root, so hover/click intent is
@@ -49,16 +64,16 @@ pub fn on_sprite_over(
 
     let (is_mob, is_npc, is_item) = kinds.get(root).unwrap_or((false, false, false));
     let is_skill_unit = net.is_some_and(|net| net.object_type == ObjectType::SkillUnit);
-    let cursor_type = if is_mob || is_skill_unit {
-        CursorType::Attack
+    let target = if is_mob || is_skill_unit {
+        CursorTarget::Attackable
     } else if is_npc {
-        CursorType::Talk
+        CursorTarget::Npc
     } else if is_item {
-        CursorType::Add
+        CursorTarget::FloorItem
     } else {
-        CursorType::Default
+        CursorTarget::Ground { walkable: true }
     };
-    cursor.write(CursorChangeRequest::new(cursor_type));
+    cursor.write(CursorChangeRequest::new(CursorType::for_target(target)));
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -87,12 +102,11 @@ pub fn on_sprite_out(
         hovered_item.0 = None;
     }
 
-    let cursor_type = if cache.is_walkable {
-        CursorType::Default
-    } else {
-        CursorType::Impossible
-    };
-    cursor.write(CursorChangeRequest::new(cursor_type));
+    cursor.write(CursorChangeRequest::new(CursorType::for_target(
+        CursorTarget::Ground {
+            walkable: cache.is_walkable,
+        },
+    )));
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -102,6 +116,8 @@ pub fn on_sprite_click(
     nets: Query<&NetworkEntity>,
     kinds: Query<(Has<Mob>, Has<Npc>)>,
     floor_items: Query<&FloorItem>,
+    transforms: Query<&GlobalTransform>,
+    player: Query<&GlobalTransform, With<LocalPlayer>>,
     mut targeting: ResMut<TargetingMode>,
     mut attacks: MessageWriter<AttackRequested>,
     mut pickups: MessageWriter<PickupRequested>,
@@ -147,8 +163,20 @@ pub fn on_sprite_click(
     }
 
     if is_npc {
-        if let Ok(net) = nets.get(root) {
+        let Ok(net) = nets.get(root) else {
+            return;
+        };
+        let in_range = player
+            .single()
+            .ok()
+            .zip(transforms.get(root).ok())
+            .is_some_and(|(player, npc)| {
+                within_talk_range(player.translation(), npc.translation())
+            });
+        if in_range {
             talks.write(TalkToNpc { npc_id: net.gid });
+        } else {
+            debug!("NPC {} is out of talk range", net.gid);
         }
         return;
     }
@@ -167,3 +195,21 @@ pub fn on_sprite_click(
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::coordinates::spawn_coords_to_world_position;
+
+    fn cell(x: u16, y: u16) -> Vec3 {
+        spawn_coords_to_world_position(x, y, 0, 0)
+    }
+
+    #[test]
+    fn talk_range_is_a_square_around_the_player() {
+        assert!(within_talk_range(cell(100, 100), cell(100, 100)));
+        assert!(within_talk_range(cell(100, 100), cell(114, 86)));
+        assert!(!within_talk_range(cell(100, 100), cell(115, 100)));
+        assert!(!within_talk_range(cell(100, 100), cell(100, 85)));
+    }
+}
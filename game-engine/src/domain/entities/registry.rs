@@ -1,58 +1,60 @@
-//! Entity Registry for Multi-Entity Support
+//! Entity Registry
 //!
-//! This module provides entity lookup for all network entities (players, NPCs, mobs, etc.)
-//! when multi-entity movement is implemented.
+//! The one lookup from server unit ids (the `gid` in every in-game packet) to
+//! client entities. Spawning registers a unit when it enters view, and
+//! vanishing, despawn cleanup and map changes unregister it. Zone handlers
+//! resolve packets through [`EntityRegistry::get_entity`] instead of keeping
+//! lookups of their own.
 //!
-//! # Status: NOT YET IMPLEMENTED
-//!
-//! The current codebase assumes a single local player entity. This registry
-//! design is documented here as a reference for future implementation when
-//! the server starts sending movement packets for other players, NPCs, and mobs.
-//!
-//! # Architecture
-//!
-//! The EntityRegistry provides bidirectional mapping between server-side
-//! Account IDs (used in network packets) and client-side Entity IDs (used by Bevy ECS).
-//!
-//! ## When to Implement
-//!
-//! Implement this when:
-//! - Testing with multiple players on the same map
-//! - Implementing NPC spawning and movement
-//! - Server sends `ZC_NOTIFY_MOVE` (0x007B) or other multi-entity packets
-//!
-//! ## Usage Pattern
+//! Every change to the index is published once per frame as an
+//! [`EntityRegistered`] or [`EntityUnregistered`] message, so systems that
+//! track units (hover, targets, labels) can react to a unit leaving without
+//! watching the despawn paths themselves.
 //!
 //! ```rust,ignore
 //! // When spawning an entity from a network packet
 //! let entity = commands.spawn(/* entity components */).id();
-//! entity_registry.register_entity(account_id, entity);
-//!
-//! // When the local player logs in
-//! entity_registry.set_local_player(player_entity, player_account_id);
+//! entity_registry.register_entity(gid, entity);
 //!
-//! // When receiving movement packets with account_id
-//! if let Some(entity) = entity_registry.get_entity(packet.account_id) {
-//!     // Apply movement to the entity
+//! // When receiving packets keyed on the gid
+//! if let Some(entity) = entity_registry.get_entity(packet.gid) {
+//!     // Apply the update to the entity
 //! }
 //!
 //! // When an entity despawns
-//! entity_registry.unregister_entity(account_id);
+//! entity_registry.unregister_entity_by_aid(gid);
 //! ```
 
 use bevy::prelude::*;
-use bevy_auto_plugin::prelude::auto_init_resource;
+use bevy_auto_plugin::prelude::{auto_add_message, auto_add_system, auto_init_resource};
 use std::collections::HashMap;
 
-/// Maps server Account IDs to client Entity IDs for multi-entity support
-///
-/// # Implementation Notes
-///
-/// - **Thread Safety**: This will be a Bevy `Resource`, so it's automatically
-///   handled by Bevy's ECS scheduling
-/// - **Local Player**: Tracked separately for quick access (most queries are for local player)
-/// - **Cleanup**: When entities despawn, they must be unregistered to prevent stale references
-/// - **Validation**: Consider adding debug assertions to catch double-registration bugs
+use crate::domain::system_sets::EntityLifecycleSystems;
+
+/// A unit id now resolves to `entity`.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+#[auto_add_message(plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin)]
+pub struct EntityRegistered {
+    pub gid: u32,
+    pub entity: Entity,
+}
+
+/// A unit id no longer resolves to `entity`: it vanished, despawned, was
+/// replaced by a newer entity or the map changed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+#[auto_add_message(plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin)]
+pub struct EntityUnregistered {
+    pub gid: u32,
+    pub entity: Entity,
+}
+
+/// A change to the index, not yet published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegistryChange {
+    Registered(u32, Entity),
+    Unregistered(u32, Entity),
+}
+
 /// Maps the server unit id to client entities. aesir keys every in-game packet on
 /// char_id (the `NetworkEntity::gid` field), so despite the historical `account_id`
 /// naming below, the id stored here is the char_id.
@@ -70,6 +72,9 @@ pub struct EntityRegistry {
 
     /// The local player's unit id (char_id)
     local_player_account_id: Option<u32>,
+
+    /// Changes since the last [`publish_registry_lifecycle`].
+    changes: Vec<RegistryChange>,
 }
 
 impl EntityRegistry {
@@ -77,17 +82,23 @@ impl EntityRegistry {
     ///
     /// This should be called when spawning any entity from a network packet.
     pub fn register_entity(&mut self, account_id: u32, entity: Entity) {
-        // Debug check for double-registration
-        if let Some(old_entity) = self.account_to_entity.insert(account_id, entity)
-            && old_entity != entity
-        {
-            warn!(
-                "Account ID {} was already registered to entity {:?}, replacing with {:?}",
-                account_id, old_entity, entity
-            );
+        match self.account_to_entity.insert(account_id, entity) {
+            Some(old_entity) if old_entity == entity => return,
+            Some(old_entity) => {
+                warn!(
+                    "Account ID {} was already registered to entity {:?}, replacing with {:?}",
+                    account_id, old_entity, entity
+                );
+                self.entity_to_account.remove(&old_entity);
+                self.changes
+                    .push(RegistryChange::Unregistered(account_id, old_entity));
+            }
+            None => {}
         }
 
         self.entity_to_account.insert(entity, account_id);
+        self.changes
+            .push(RegistryChange::Registered(account_id, entity));
 
         debug!(
             "Registered entity: account_id={}, entity={:?}",
@@ -99,6 +110,8 @@ impl EntityRegistry {
     pub fn unregister_entity_by_aid(&mut self, account_id: u32) {
         if let Some(entity) = self.account_to_entity.remove(&account_id) {
             self.entity_to_account.remove(&entity);
+            self.changes
+                .push(RegistryChange::Unregistered(account_id, entity));
 
             // Clear local player cache if it was the local player
             if self.local_player_account_id == Some(account_id) {
@@ -117,6 +130,8 @@ impl EntityRegistry {
     pub fn unregister_entity(&mut self, entity: Entity) {
         if let Some(account_id) = self.entity_to_account.remove(&entity) {
             self.account_to_entity.remove(&account_id);
+            self.changes
+                .push(RegistryChange::Unregistered(account_id, entity));
 
             // Clear local player cache if it was the local player
             if self.local_player_entity == Some(entity) {
@@ -189,8 +204,8 @@ impl EntityRegistry {
     /// by `despawn_map_scoped`, so their stale `char_id -> Entity` entries must be
     /// dropped. The local player survives the warp, so its registration is kept.
     pub fn clear_non_local(&mut self) {
-        self.account_to_entity.clear();
-        self.entity_to_account.clear();
+        let local = self.local_player_entity;
+        self.drain_all(|entity| Some(entity) != local);
 
         if let (Some(entity), Some(account_id)) =
             (self.local_player_entity, self.local_player_account_id)
@@ -204,29 +219,51 @@ impl EntityRegistry {
 
     /// Clear all registrations (useful for map changes or disconnection)
     pub fn clear(&mut self) {
-        self.account_to_entity.clear();
-        self.entity_to_account.clear();
+        self.drain_all(|_| true);
         self.local_player_entity = None;
         self.local_player_account_id = None;
 
         debug!("Cleared all entity registrations");
     }
+
+    /// Empties the index, recording an unregistration for each entity `report`
+    /// accepts.
+    fn drain_all(&mut self, mut report: impl FnMut(Entity) -> bool) {
+        self.entity_to_account.clear();
+        for (account_id, entity) in self.account_to_entity.drain() {
+            if report(entity) {
+                self.changes
+                    .push(RegistryChange::Unregistered(account_id, entity));
+            }
+        }
+    }
 }
 
-// TODO: Implement cleanup system for despawned entities
-//
-// Add a system that runs when NetworkEntity entities despawn:
-//
-// ```rust,ignore
-// fn cleanup_despawned_entities(
-//     mut registry: ResMut<EntityRegistry>,
-//     mut removed: RemovedComponents<NetworkEntity>,
-// ) {
-//     for entity in removed.read() {
-//         registry.unregister_entity(entity);
-//     }
-// }
-// ```
+/// Publishes the frame's registry changes, after every lifecycle system has run.
+#[auto_add_system(
+    plugin = crate::domain::entities::character::UnifiedCharacterEntityPlugin,
+    schedule = Update,
+    config(after = EntityLifecycleSystems::Despawning)
+)]
+pub fn publish_registry_lifecycle(
+    mut registry: ResMut<EntityRegistry>,
+    mut registered: MessageWriter<EntityRegistered>,
+    mut unregistered: MessageWriter<EntityUnregistered>,
+) {
+    if registry.changes.is_empty() {
+        return;
+    }
+    for change in registry.changes.drain(..) {
+        match change {
+            RegistryChange::Registered(gid, entity) => {
+                registered.write(EntityRegistered { gid, entity });
+            }
+            RegistryChange::Unregistered(gid, entity) => {
+                unregistered.write(EntityUnregistered { gid, entity });
+            }
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -314,6 +351,68 @@ mod tests {
         assert_eq!(registry.entity_count(), 1);
     }
 
+    #[test]
+    fn replacing_an_entity_unregisters_the_old_one() {
+        let mut registry = EntityRegistry::default();
+        let old = Entity::from_bits(2);
+        let new = Entity::from_bits(3);
+
+        registry.register_entity(200, old);
+        registry.register_entity(200, old);
+        registry.register_entity(200, new);
+
+        assert_eq!(registry.get_account_id(old), None);
+        assert_eq!(registry.get_entity(200), Some(new));
+        assert_eq!(
+            registry.changes,
+            [
+                RegistryChange::Registered(200, old),
+                RegistryChange::Unregistered(200, old),
+                RegistryChange::Registered(200, new),
+            ]
+        );
+    }
+
+    #[test]
+    fn map_change_reports_only_remote_units_as_unregistered() {
+        let mut registry = EntityRegistry::default();
+        let player = Entity::from_bits(1);
+        let remote = Entity::from_bits(2);
+        registry.set_local_player(player, 100);
+        registry.register_entity(200, remote);
+        registry.changes.clear();
+
+        registry.clear_non_local();
+
+        assert_eq!(
+            registry.changes,
+            [RegistryChange::Unregistered(200, remote)]
+        );
+    }
+
+    #[test]
+    fn changes_are_published_once_as_messages() {
+        let mut app = App::new();
+        app.add_message::<EntityRegistered>()
+            .add_message::<EntityUnregistered>()
+            .init_resource::<EntityRegistry>()
+            .add_systems(Update, publish_registry_lifecycle);
+        let entity = app.world_mut().spawn_empty().id();
+        {
+            let mut registry = app.world_mut().resource_mut::<EntityRegistry>();
+            registry.register_entity(7, entity);
+            registry.unregister_entity(entity);
+        }
+
+        app.update();
+
+        let registered = app.world().resource::<Messages<EntityRegistered>>();
+        let unregistered = app.world().resource::<Messages<EntityUnregistered>>();
+        assert_eq!(registered.len(), 1);
+        assert_eq!(unregistered.len(), 1);
+        assert!(app.world().resource::<EntityRegistry>().changes.is_empty());
+    }
+
     #[test]
     fn test_clear_non_local_without_local_player() {
         let mut registry = EntityRegistry::default();
@@ -71,16 +71,47 @@ pub struct RequestEntityVanish {
     pub vanish_type: u8,
 }
 
+/// Why a unit left view: the type byte of ZC_NOTIFY_VANISH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VanishType {
+    /// Walked out of view range; also any type the client doesn't know.
+    OutOfSight,
+    Died,
+    LoggedOut,
+    /// Teleported or warped away.
+    Teleported,
+}
+
+impl VanishType {
+    pub fn from_reason(reason: u32) -> Self {
+        match reason {
+            1 => VanishType::Died,
+            2 => VanishType::LoggedOut,
+            3 => VanishType::Teleported,
+            _ => VanishType::OutOfSight,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            VanishType::OutOfSight => "out of sight",
+            VanishType::Died => "died",
+            VanishType::LoggedOut => "logged out",
+            VanishType::Teleported => "teleported",
+        }
+    }
+}
+
 /// Entity-targeted event for vanish requests
 ///
-/// This observer event is triggered when an entity needs to vanish.
-/// An observer will check if the entity is moving and either:
-/// - Mark it with PendingDespawn if moving (defer despawn until movement completes)
-/// - Trigger DespawnEntity immediately if idle
+/// This observer event is triggered when an entity needs to vanish. Each
+/// [`VanishType`] disappears differently: a death plays out and fades (via
+/// PendingDespawn), a teleport leaves the teleport effect behind, and the rest
+/// are removed at once.
 #[derive(EntityEvent, Debug, Clone)]
 pub struct EntityVanishRequested {
     #[event_target]
     pub entity: Entity,
     pub aid: u32,
-    pub vanish_type: u8,
+    pub vanish_type: VanishType,
 }
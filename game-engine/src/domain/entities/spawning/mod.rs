@@ -3,5 +3,5 @@ pub mod plugin;
 pub mod remote_equipment;
 pub mod systems;
 
-pub use events::{DespawnEntity, SpawnEntity};
+pub use events::{DespawnEntity, EntityVanishRequested, SpawnEntity, VanishType};
 pub use plugin::EntitySpawningPlugin;
@@ -12,9 +12,15 @@ use crate::{
             },
             components::{GuildIdentity, NetworkEntity, PendingDespawn, SpawnGuildIdentityKnown},
             markers::*,
-            movement::components::{MovementSpeed, MovementState},
+            movement::{
+                components::{MovementSpeed, MovementState},
+                snapshot::{SnapshotBuffer, walk_samples},
+            },
+            pathfinding::{CurrentMapPathfindingGrid, PathfindingGrid, find_path},
             registry::EntityRegistry,
-            spawning::events::{DespawnEntity, EntityVanishRequested, PendingSpawnBuffer},
+            spawning::events::{
+                DespawnEntity, EntityVanishRequested, PendingSpawnBuffer, VanishType,
+            },
             sprite_rendering::{
                 components::{EntitySpriteData, EntitySpriteInfo},
                 events::RequestSpriteSpawn,
@@ -63,8 +69,8 @@ struct SpawnFields {
 
 impl From<&UnitEntered> for SpawnFields {
     fn from(e: &UnitEntered) -> Self {
-        // remote movement is driven by snapshot interpolation, so the spawn path
-        // always sets up a standing entity; `UnitEntered`'s move fields are ignored here.
+        // remote movement is driven by snapshot interpolation; `UnitEntered`'s move
+        // fields only seed the planned walk (see `walk_buffer`).
         SpawnFields {
             aid: e.aid,
             gid: e.gid,
@@ -94,6 +100,22 @@ impl From<&UnitEntered> for SpawnFields {
     }
 }
 
+/// Planned [`SnapshotBuffer`] for a unit that entered view mid-walk, along the
+/// client's path to the announced destination (a straight line without a grid).
+fn walk_buffer(unit: &UnitEntered, grid: Option<&PathfindingGrid>) -> Option<SnapshotBuffer> {
+    if !unit.moving {
+        return None;
+    }
+    let src = (unit.x as u16, unit.y as u16);
+    let dst = (unit.dst_x as u16, unit.dst_y as u16);
+    let waypoints = grid
+        .and_then(|grid| find_path(grid, src, dst))
+        .unwrap_or_else(|| vec![src, dst]);
+    let ms_per_cell = MovementSpeed::from_server_speed(unit.speed as u16).ms_per_cell;
+    let samples = walk_samples(&waypoints, unit.move_start_time, ms_per_cell);
+    (!samples.is_empty()).then(|| SnapshotBuffer::planned(samples))
+}
+
 /// Spawn network entities from UnitEntered events
 #[auto_add_system(
     plugin = crate::app::entity_spawning_plugin::EntitySpawningDomainPlugin,
@@ -109,16 +131,25 @@ pub fn spawn_network_entity_system(
     mut spawn_events: MessageReader<UnitEntered>,
     mut entity_registry: ResMut<EntityRegistry>,
     job_registry: Option<Res<JobSpriteRegistry>>,
+    pathfinding: Option<Res<CurrentMapPathfindingGrid>>,
 ) {
+    let grid = pathfinding.as_deref().map(|current| &current.0);
     for unit in spawn_events.read() {
         let event = SpawnFields::from(unit);
+        let walk = walk_buffer(unit, grid);
 
         // Check if entity already exists (e.g., spawned from character selection or re-entering view)
         if let Some(existing_entity) = entity_registry.get_entity(event.gid) {
-            // Re-entering view: de-queue any pending despawn. Remote movement is now driven
-            // by snapshot interpolation, so we no longer forward a per-step destination here.
+            // Re-entering view: de-queue any pending despawn and, when it came back
+            // mid-walk, restart its interpolation from the announced path. The
+            // entry is the only place a remote unit's walk speed is announced, so
+            // refresh it too: it may have changed (Agi Up, a mount) while away.
             let mut entity = commands.entity(existing_entity);
             entity.remove::<PendingDespawn>();
+            entity.insert(MovementSpeed::from_server_speed(event.speed));
+            if let Some(walk) = walk {
+                entity.insert(walk);
+            }
             if event.object_type == crate::domain::entities::types::ObjectType::Pc {
                 entity.insert(SpawnGuildIdentityKnown);
                 if event.guild_id != 0 {
@@ -288,17 +319,25 @@ pub fn spawn_network_entity_system(
         entity_cmd.insert((AnimationState::Idle, StatusEffects::default()));
 
         // Remote entities are placed standing; their position is driven by snapshot
-        // interpolation via `interpolate_remote_entities_system`.
+        // interpolation via `interpolate_remote_entities_system`, which starts on the
+        // planned walk when the unit entered view moving.
         entity_cmd.insert((
             MovementState::Idle,
             MovementSpeed::from_server_speed(event.speed),
             Grounded,
         ));
-
-        debug!(
-            "Entity {} spawned IDLE at ({}, {})",
-            event.name, event.position.0, event.position.1
-        );
+        if let Some(walk) = walk {
+            debug!(
+                "Entity {} entered walking ({}, {}) -> ({}, {})",
+                event.name, unit.x, unit.y, unit.dst_x, unit.dst_y
+            );
+            entity_cmd.insert(walk);
+        } else {
+            debug!(
+                "Entity {} spawned IDLE at ({}, {})",
+                event.name, event.position.0, event.position.1
+            );
+        }
 
         let entity_id = entity_cmd.id();
 
@@ -469,7 +508,7 @@ pub fn bridge_vanish_requests_system(
         commands.trigger(EntityVanishRequested {
             entity,
             aid: request.gid,
-            vanish_type: request.reason as u8,
+            vanish_type: VanishType::from_reason(request.reason),
         });
     }
 }
@@ -477,25 +516,19 @@ pub fn bridge_vanish_requests_system(
 /// Observer for entity vanish requests
 ///
 /// When an entity vanishes (moves out of range, dies, logs out, or teleports),
-/// death (vanish_type 1) is deferred via PendingDespawn so combat::handle_death can
-/// play the death animation; every other vanish despawns immediately.
+/// death is deferred via PendingDespawn so combat::handle_death can play the
+/// death animation before the corpse fades; every other vanish despawns
+/// immediately. A teleport's effect is left behind by the effects domain.
 #[auto_observer(plugin = crate::app::entity_spawning_plugin::EntitySpawningDomainPlugin)]
 pub fn on_entity_vanish_request(trigger: On<EntityVanishRequested>, mut commands: Commands) {
     let event = trigger.event();
     let entity = trigger.entity;
-
-    let vanish_reason = match event.vanish_type {
-        0 => "out of sight",
-        1 => "died",
-        2 => "logged out",
-        3 => "teleported",
-        _ => "unknown",
-    };
+    let vanish_reason = event.vanish_type.label();
 
     // Only death defers. Remote entities are snapshot-interpolated, so a vanished unit
     // receives no further updates to "finish" a move with - deferring on a stale Moving
     // state (its last snapshot was mid-walk) leaves it frozen on screen until the timeout.
-    if event.vanish_type == 1 {
+    if event.vanish_type == VanishType::Died {
         debug!(
             "Entity {:?} (AID {}) deferring despawn ({})",
             entity, event.aid, vanish_reason
@@ -520,8 +553,8 @@ pub fn on_entity_vanish_request(trigger: On<EntityVanishRequested>, mut commands
 
 /// Despawn death entities once their deferral timeout expires.
 ///
-/// Only death (vanish_type 1) entities carry PendingDespawn (see on_entity_vanish_request);
-/// they stay on screen for their death animation and despawn when the timeout elapses. The
+/// Only dead entities carry PendingDespawn (see on_entity_vanish_request); they stay
+/// on screen for their death animation, fade out, and despawn when the timeout elapses. The
 /// DespawnEntity observer despawns the whole entity, so there is no component to remove here.
 #[auto_add_system(
     plugin = crate::app::entity_spawning_plugin::EntitySpawningDomainPlugin,
@@ -627,6 +660,7 @@ mod tests {
             body_state: 0,
             health_state: 0,
             effect_state: 0,
+            virtue: 0,
             head: 12,
             weapon: 0,
             shield: 0,
@@ -668,6 +702,68 @@ mod tests {
         app
     }
 
+    #[test]
+    fn unit_entering_mid_walk_gets_planned_path() {
+        let mut app = app();
+        let mut walker = unit(0, "", 0);
+        walker.moving = true;
+        walker.dst_x = 104;
+        walker.dst_y = 200;
+        walker.move_start_time = 9_000;
+        app.world_mut().write_message(walker);
+        app.update();
+
+        let entity = app
+            .world()
+            .resource::<EntityRegistry>()
+            .get_entity(150_001)
+            .expect("spawned");
+        let buffer = app
+            .world()
+            .get::<SnapshotBuffer>(entity)
+            .expect("planned walk");
+        let ends: Vec<_> = buffer
+            .samples()
+            .iter()
+            .map(|s| (s.x, s.y, s.server_tick, s.move_state))
+            .collect();
+        assert_eq!(ends, vec![(100, 200, 9_000, 1), (104, 200, 9_600, 0)]);
+    }
+
+    #[test]
+    fn reentering_unit_takes_its_announced_walk_speed() {
+        let mut app = app();
+        app.world_mut().write_message(unit(0, "", 0));
+        app.update();
+
+        let mut hasted = unit(0, "", 0);
+        hasted.speed = 100;
+        app.world_mut().write_message(hasted);
+        app.update();
+
+        let entity = app
+            .world()
+            .resource::<EntityRegistry>()
+            .get_entity(150_001)
+            .expect("spawned");
+        let speed = app.world().get::<MovementSpeed>(entity).expect("speed");
+        assert_eq!(speed.ms_per_cell, 100.0);
+    }
+
+    #[test]
+    fn standing_unit_has_no_planned_path() {
+        let mut app = app();
+        app.world_mut().write_message(unit(0, "", 0));
+        app.update();
+
+        let entity = app
+            .world()
+            .resource::<EntityRegistry>()
+            .get_entity(150_001)
+            .expect("spawned");
+        assert!(app.world().get::<SnapshotBuffer>(entity).is_none());
+    }
+
     #[test]
     fn guilded_remote_pc_receives_spawn_identity() {
         let mut app = app();
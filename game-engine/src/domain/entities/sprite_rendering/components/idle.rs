@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use super::EntitySpriteData;
+
+/// Timing of the idle sub-animation for one entity kind: after standing still
+/// for a random rest, the unit holds one of its layout's idle poses (the RO
+/// head turns) briefly, then faces forward again.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IdleVariationProfile {
+    pub min_rest: Duration,
+    pub max_rest: Duration,
+    pub hold: Duration,
+}
+
+impl IdleVariationProfile {
+    /// Occasional glances: long enough apart that a crowd doesn't twitch.
+    pub const CHARACTER: Self = Self {
+        min_rest: Duration::from_secs(4),
+        max_rest: Duration::from_secs(10),
+        hold: Duration::from_millis(900),
+    };
+}
+
+/// Which entity kinds vary their idle pose, and how.
+#[derive(Resource, Debug, Clone)]
+#[auto_init_resource(plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin)]
+pub struct IdleVariationConfig {
+    pub character: Option<IdleVariationProfile>,
+}
+
+impl Default for IdleVariationConfig {
+    fn default() -> Self {
+        Self {
+            character: Some(IdleVariationProfile::CHARACTER),
+        }
+    }
+}
+
+impl IdleVariationConfig {
+    pub fn profile_for(&self, sprite_data: &EntitySpriteData) -> Option<IdleVariationProfile> {
+        match sprite_data {
+            EntitySpriteData::Character { .. } => self.character,
+            // Mob and NPC idle actions already animate, and drops are a
+            // single frame: none of them has alternate poses to pick from.
+            EntitySpriteData::Mob { .. }
+            | EntitySpriteData::Npc { .. }
+            | EntitySpriteData::Item { .. } => None,
+        }
+    }
+}
+
+/// Per-entity idle pose schedule. `pose` is the idle-action frame to show
+/// instead of frame 0, while one is being held.
+#[derive(Component, Debug)]
+pub struct IdleVariation {
+    profile: IdleVariationProfile,
+    timer: Timer,
+    pose: Option<usize>,
+    rng: u32,
+}
+
+impl IdleVariation {
+    /// `seed` decorrelates units spawned together; any value works.
+    pub fn new(profile: IdleVariationProfile, seed: u32) -> Self {
+        let mut variation = Self {
+            profile,
+            timer: Timer::new(Duration::ZERO, TimerMode::Once),
+            pose: None,
+            rng: seed | 1,
+        };
+        variation.rest();
+        variation
+    }
+
+    pub fn pose(&self) -> Option<usize> {
+        self.pose
+    }
+
+    /// Advance the schedule while idle, choosing from the layout's `poses`.
+    pub fn tick(&mut self, delta: Duration, poses: &[usize]) {
+        if poses.is_empty() || !self.timer.tick(delta).just_finished() {
+            return;
+        }
+        if self.pose.is_some() {
+            self.rest();
+        } else {
+            let pick = self.next_random() as usize % poses.len();
+            self.pose = Some(poses[pick]);
+            self.timer = Timer::new(self.profile.hold, TimerMode::Once);
+        }
+    }
+
+    /// Face forward and restart the rest; called whenever the unit leaves idle
+    /// so a pose never carries over into the next time it stands still.
+    pub fn reset(&mut self) {
+        if self.pose.is_some() || self.timer.elapsed() > Duration::ZERO {
+            self.rest();
+        }
+    }
+
+    fn rest(&mut self) {
+        let min = self.profile.min_rest.as_millis() as u64;
+        let span = (self.profile.max_rest.as_millis() as u64).saturating_sub(min);
+        let jitter = u64::from(self.next_random()) % (span + 1);
+        self.pose = None;
+        self.timer = Timer::new(Duration::from_millis(min + jitter), TimerMode::Once);
+    }
+
+    /// xorshift32: cheap, and plenty for picking glance timings.
+    fn next_random(&mut self) -> u32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: IdleVariationProfile = IdleVariationProfile {
+        min_rest: Duration::from_secs(2),
+        max_rest: Duration::from_secs(3),
+        hold: Duration::from_millis(500),
+    };
+
+    #[test]
+    fn holds_a_pose_after_the_rest_then_faces_forward() {
+        let mut idle = IdleVariation::new(PROFILE, 7);
+        idle.tick(Duration::from_millis(1999), &[1, 2]);
+        assert_eq!(idle.pose(), None);
+
+        idle.tick(Duration::from_millis(1001), &[1, 2]);
+        assert!(matches!(idle.pose(), Some(1 | 2)));
+
+        idle.tick(PROFILE.hold, &[1, 2]);
+        assert_eq!(idle.pose(), None);
+    }
+
+    #[test]
+    fn layouts_without_poses_never_vary() {
+        let mut idle = IdleVariation::new(PROFILE, 7);
+        idle.tick(Duration::from_secs(60), &[]);
+        assert_eq!(idle.pose(), None);
+    }
+
+    #[test]
+    fn leaving_idle_drops_the_pose_and_restarts_the_rest() {
+        let mut idle = IdleVariation::new(PROFILE, 7);
+        idle.tick(PROFILE.max_rest, &[1]);
+        assert_eq!(idle.pose(), Some(1));
+
+        idle.reset();
+        assert_eq!(idle.pose(), None);
+        idle.tick(Duration::from_millis(1999), &[1]);
+        assert_eq!(idle.pose(), None);
+    }
+
+    #[test]
+    fn only_characters_vary_by_default() {
+        let config = IdleVariationConfig::default();
+        let mob = EntitySpriteData::Mob {
+            sprite_name: "poring".into(),
+        };
+        assert_eq!(config.profile_for(&mob), None);
+        let character = EntitySpriteData::Character {
+            job_id: 0,
+            gender: crate::domain::entities::character::components::Gender::Male,
+            head: 1,
+        };
+        assert_eq!(
+            config.profile_for(&character),
+            Some(IdleVariationProfile::CHARACTER)
+        );
+    }
+}
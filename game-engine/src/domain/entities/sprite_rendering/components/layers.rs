@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::infrastructure::assets::RoPaletteAsset;
+use crate::presentation::rendering::SpriteLayerMaterial;
+
 /// Marker component for head sprite layers.
 #[derive(Component, Default)]
 pub struct HeadLayer;
@@ -22,7 +25,8 @@ pub struct CartLayer {
 /// Head uses the same frame index to get its attach point for synchronized positioning.
 #[derive(Component, Default, PartialEq)]
 pub struct BodyAttachPoint {
-    pub attach_point: Vec2,
+    /// The current body frame's ACT anchor; `None` when the frame has none.
+    pub attach_point: Option<Vec2>,
     pub frame_index: usize,
     pub layer_pos: Vec2,
 }
@@ -32,7 +36,8 @@ pub struct BodyAttachPoint {
 /// head aligns to the body via `BodyAttachPoint`.
 #[derive(Component, Default, PartialEq)]
 pub struct HeadAttachPoint {
-    pub attach_point: Vec2,
+    /// The current head frame's ACT anchor; `None` when the frame has none.
+    pub attach_point: Option<Vec2>,
     pub frame_index: usize,
     pub layer_pos: Vec2,
 }
@@ -43,3 +48,37 @@ pub struct HeadAttachPoint {
 pub struct HeadAttachment {
     pub body_entity: Entity,
 }
+
+/// The dye palette a player's hair or body layer should be drawn with, derived
+/// from the unit's `CharacterAppearance`. Absent when the color is the default.
+#[derive(Component, Debug, Clone)]
+pub struct LayerDye {
+    pub path: String,
+    pub palette: Handle<RoPaletteAsset>,
+}
+
+/// On a layer drawn with `PaletteSpriteMaterial`: the shared atlas material it
+/// used before, put back if the dye is removed.
+#[derive(Component, Debug, Clone)]
+pub struct DyedLayer {
+    pub atlas: Handle<SpriteLayerMaterial>,
+}
+
+/// What a layer's shared `SpriteLayerMaterial` is picked by, besides the atlas
+/// page of its current frame: its transparent-sort depth bias and the status
+/// tint of its unit. Layers with equal shading on the same page share one
+/// material.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LayerShading {
+    pub depth_bias: f32,
+    pub tint: Color,
+}
+
+impl LayerShading {
+    pub fn new(depth_bias: f32) -> Self {
+        Self {
+            depth_bias,
+            tint: Color::WHITE,
+        }
+    }
+}
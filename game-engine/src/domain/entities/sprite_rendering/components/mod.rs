@@ -1,7 +1,12 @@
+mod idle;
 mod layers;
 mod ro_sprite;
 
-pub use layers::{BodyAttachPoint, CartLayer, HeadAttachPoint, HeadAttachment, HeadLayer};
+pub use idle::{IdleVariation, IdleVariationConfig, IdleVariationProfile};
+pub use layers::{
+    BodyAttachPoint, CartLayer, DyedLayer, HeadAttachPoint, HeadAttachment, HeadLayer, LayerDye,
+    LayerShading,
+};
 pub use ro_sprite::{MobSprite, PlayerSprite, RoSpriteGeneric};
 
 use std::collections::HashMap;
@@ -69,7 +74,7 @@ pub struct RenderLayer {
     /// Equipment slot this layer represents (None for body/head)
     pub equipment_slot: Option<EquipmentSlot>,
 
-    /// Texture handles to keep images alive (prevents GC)
+    /// Atlas page handles to keep images alive (prevents GC)
     pub textures: Vec<Handle<Image>>,
 }
 
@@ -114,6 +119,11 @@ pub struct ShadowRenderLayer;
 #[derive(Component, Clone, Debug, Default)]
 pub struct PendingRenderLayers;
 
+/// Stand-in billboard shown under a unit while its layers are still being built.
+/// Despawned once the unit has a body layer or stops waiting for one.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SpritePlaceholder;
+
 /// Describes what kind of sprite to load for an entity
 #[derive(Clone, Debug)]
 pub enum EntitySpriteData {
@@ -169,3 +179,59 @@ impl Default for SpriteHierarchyConfig {
         }
     }
 }
+
+/// On a unit whose sprite is off-screen or too far from the camera. The layer
+/// sync systems skip culled units, so they stop rewriting textures and
+/// transforms nobody can see; animation is clock-driven, so a unit resumes
+/// on the right frame the moment it comes back into view.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct SpriteCulled;
+
+/// On a unit drawn at low detail because it is far from the camera and the
+/// sprite LOD setting is on: its body animates on a coarse tick (the head and
+/// weapon follow the body's frame), and it gets no hover nameplate.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct SpriteLod;
+
+/// The sphere a unit's body sprite stays inside over every frame of its
+/// animation, relative to the unit's feet. Measured once the body animation
+/// has loaded, and again when it changes (job change).
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct SpriteBounds {
+    /// The body animation these bounds were measured from.
+    pub animation: AssetId<RoAnimationAsset>,
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+/// Tunables for sprite culling.
+#[derive(Resource, Debug, Clone)]
+#[auto_init_resource(plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin)]
+pub struct SpriteCullingConfig {
+    pub enabled: bool,
+    /// Units farther than this from the camera are culled and not drawn.
+    pub max_distance: f32,
+    /// Added to a unit's measured [`SpriteBounds`] radius for what is drawn
+    /// past the body: head, headgear, weapon.
+    pub bounds_margin: f32,
+    /// Frustum sphere radius for a unit whose body has not been measured yet.
+    pub bounds_radius: f32,
+    /// With sprite LOD on, units farther than this are drawn at low detail.
+    pub lod_distance: f32,
+    /// Animation tick of a low-detail unit: it shows a new frame at most once
+    /// per this many milliseconds.
+    pub lod_frame_ms: u32,
+}
+
+impl Default for SpriteCullingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_distance: 600.0,
+            bounds_margin: 15.0,
+            bounds_radius: 30.0,
+            lod_distance: 300.0,
+            lod_frame_ms: 200,
+        }
+    }
+}
@@ -45,6 +45,17 @@ impl<T: ActionLayout> RoSpriteGeneric<T> {
         T::calculate_action_index(self.action_type, self.direction)
     }
 
+    /// The action `animation` plays for the current action and direction,
+    /// and whether it is drawn mirrored (see [`ActionLayout::resolve_action`]).
+    pub fn resolve_action(&self, animation: &RoAnimationAsset) -> (usize, bool) {
+        T::resolve_action(self.action_type, self.direction, animation.actions.len())
+    }
+
+    /// Whether the current frames come from a mirrored direction.
+    pub fn is_mirrored(&self, animation: &RoAnimationAsset) -> bool {
+        self.resolve_action(animation).1
+    }
+
     pub fn is_looping(&self) -> bool {
         T::is_looping(self.action_type)
     }
@@ -71,6 +82,19 @@ impl<T: ActionLayout> RoSpriteGeneric<T> {
         self.fixed_duration_ms = duration_ms;
     }
 
+    /// Change the per-frame delay multiplier without a jump in the animation:
+    /// `start_time` is rebased so the frame showing at `game_time_ms` stays put
+    /// and only the remaining frames play at the new rate.
+    pub fn set_speed_factor(&mut self, speed_factor: f32, game_time_ms: u32) {
+        if self.speed_factor == speed_factor {
+            return;
+        }
+        let elapsed = game_time_ms.saturating_sub(self.start_time) as f32;
+        let rescaled = elapsed * speed_factor / self.speed_factor.max(f32::EPSILON);
+        self.start_time = game_time_ms.saturating_sub(rescaled as u32);
+        self.speed_factor = speed_factor;
+    }
+
     pub fn set_direction(&mut self, direction: Direction) {
         self.direction = direction;
     }
@@ -80,7 +104,7 @@ impl<T: ActionLayout> RoSpriteGeneric<T> {
         animation: &'a RoAnimationAsset,
         game_time_ms: u32,
     ) -> Option<&'a FrameData> {
-        let action_index = T::validate_action_index(self.action_index(), animation.actions.len());
+        let (action_index, _) = self.resolve_action(animation);
         let action_data = animation.actions.get(action_index)?;
 
         if action_data.frames.is_empty() {
@@ -93,13 +117,13 @@ impl<T: ActionLayout> RoSpriteGeneric<T> {
     }
 
     pub fn get_static_frame<'a>(&self, animation: &'a RoAnimationAsset) -> Option<&'a FrameData> {
-        let action_index = T::validate_action_index(self.action_index(), animation.actions.len());
+        let (action_index, _) = self.resolve_action(animation);
         let action_data = animation.actions.get(action_index)?;
         action_data.frames.first()
     }
 
     pub fn get_frame_index(&self, animation: &RoAnimationAsset, game_time_ms: u32) -> usize {
-        let action_index = T::validate_action_index(self.action_index(), animation.actions.len());
+        let (action_index, _) = self.resolve_action(animation);
         let Some(action_data) = animation.actions.get(action_index) else {
             return 0;
         };
@@ -196,4 +220,16 @@ mod tests {
         sprite.set_action(ActionType::Idle, 700);
         assert_eq!(sprite.fixed_duration_ms, None);
     }
+
+    #[test]
+    fn test_speed_change_keeps_the_current_walk_frame() {
+        let mut sprite = PlayerSprite::default();
+        sprite.set_action(ActionType::Walk, 1000);
+        assert_eq!(sprite.frame_index(8, 100.0, 1250), 2);
+
+        sprite.set_speed_factor(2.0, 1250);
+        assert_eq!(sprite.frame_index(8, 100.0, 1250), 2);
+        assert_eq!(sprite.frame_index(8, 100.0, 1449), 2);
+        assert_eq!(sprite.frame_index(8, 100.0, 1450), 3);
+    }
 }
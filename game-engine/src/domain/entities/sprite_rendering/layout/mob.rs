@@ -62,4 +62,43 @@ mod tests {
         assert!(!MobLayout::is_looping(ActionType::Attack));
         assert!(!MobLayout::is_looping(ActionType::Dead));
     }
+
+    #[test]
+    fn test_mob_eight_direction_act_resolves_unmirrored() {
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Walk, Direction::East, 40),
+            (14, false)
+        );
+    }
+
+    #[test]
+    fn test_mob_five_direction_act_mirrors_the_east_side() {
+        // Five actions (idle, walk, attack, hit, dead) of five directions each.
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Idle, Direction::North, 25),
+            (4, false)
+        );
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Walk, Direction::NorthEast, 25),
+            (8, true)
+        );
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Attack, Direction::SouthEast, 25),
+            (11, true)
+        );
+    }
+
+    #[test]
+    fn test_mob_missing_action_keeps_facing_in_idle() {
+        // An NPC with only its eight idle directions.
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Walk, Direction::West, 8),
+            (2, false)
+        );
+        // A single-pose prop never turns.
+        assert_eq!(
+            MobLayout::resolve_action(ActionType::Idle, Direction::East, 1),
+            (0, false)
+        );
+    }
 }
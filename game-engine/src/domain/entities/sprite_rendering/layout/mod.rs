@@ -4,4 +4,4 @@ mod trait_def;
 
 pub use mob::MobLayout;
 pub use player::PlayerLayout;
-pub use trait_def::ActionLayout;
+pub use trait_def::{ActionLayout, directions_per_action, stored_direction};
@@ -26,6 +26,11 @@ impl ActionLayout for PlayerLayout {
             ActionType::Idle | ActionType::Walk | ActionType::Sit | ActionType::ReadyFight
         )
     }
+
+    /// Idle frames 1 and 2 turn the head right and left; frame 0 faces ahead.
+    fn idle_pose_frames() -> &'static [usize] {
+        &[1, 2]
+    }
 }
 
 #[cfg(test)]
@@ -118,6 +123,11 @@ mod tests {
         assert!(!PlayerLayout::is_looping(ActionType::Dead));
     }
 
+    #[test]
+    fn test_idle_pose_frames_are_the_head_turns() {
+        assert_eq!(PlayerLayout::idle_pose_frames(), &[1, 2]);
+    }
+
     #[test]
     fn test_validate_action_index() {
         assert_eq!(PlayerLayout::validate_action_index(10, 100), 10);
@@ -9,7 +9,65 @@ pub trait ActionLayout: Send + Sync + 'static {
 
     fn is_looping(action_type: ActionType) -> bool;
 
+    /// Idle-action frames that are alternate standing poses rather than steps
+    /// of an animation, shown one at a time by `IdleVariation`. Empty when the
+    /// idle action simply loops.
+    fn idle_pose_frames() -> &'static [usize] {
+        &[]
+    }
+
     fn validate_action_index(index: usize, total_actions: usize) -> usize {
         if index >= total_actions { 0 } else { index }
     }
+
+    /// The ACT action to play for `action_type` facing `direction`, and whether
+    /// its frames must be drawn mirrored. Adapts to how many directions the
+    /// ACT stores per action (see [`stored_direction`]); an action the ACT
+    /// lacks falls back to idle in the same direction.
+    fn resolve_action(
+        action_type: ActionType,
+        direction: Direction,
+        total_actions: usize,
+    ) -> (usize, bool) {
+        let per_action = directions_per_action(total_actions);
+        let (stored, mirrored) = stored_direction(direction, per_action);
+        let group = Self::action_offset(action_type) / 8;
+        let index = group * per_action + stored;
+        if index < total_actions {
+            return (index, mirrored);
+        }
+        let idle = Self::action_offset(ActionType::Idle) / 8 * per_action + stored;
+        if idle < total_actions {
+            (idle, mirrored)
+        } else {
+            (0, false)
+        }
+    }
+}
+
+/// How many directions an ACT stores per action, judged from its action
+/// count: eight as usual; five (south round to north) for sprites that mirror
+/// the east-facing three; one for signs and props that never turn.
+pub fn directions_per_action(total_actions: usize) -> usize {
+    if total_actions.is_multiple_of(8) {
+        8
+    } else if total_actions.is_multiple_of(5) {
+        5
+    } else if total_actions < 8 {
+        1
+    } else {
+        8
+    }
+}
+
+/// Which of the stored directions shows `direction`, and whether it is drawn
+/// mirrored. A five-direction ACT shows north-east, east and south-east as its
+/// north-west, west and south-west flipped horizontally.
+pub fn stored_direction(direction: Direction, per_action: usize) -> (usize, bool) {
+    let direction = direction as usize;
+    match per_action {
+        1 => (0, false),
+        5 if direction > 4 => (8 - direction, true),
+        _ => (direction, false),
+    }
 }
@@ -7,7 +7,8 @@ pub mod systems;
 
 pub use components::{
     EffectType, EntitySpriteData, EntitySpriteInfo, PendingRenderLayers, PlayerAppearance,
-    RenderLayer, ShadowRenderLayer, SpriteHierarchyConfig,
+    RenderLayer, ShadowRenderLayer, SpriteBounds, SpriteCulled, SpriteCullingConfig,
+    SpriteHierarchyConfig, SpriteLod, SpritePlaceholder,
 };
 pub use events::SpawnSpriteEvent;
 pub use kinds::{EffectLayer, SpriteLayer, SpriteRoot};
@@ -1,5 +1,6 @@
 use crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin;
 use crate::domain::entities::billboard::BillboardPlugin;
+use crate::presentation::rendering::{PaletteSpriteMaterial, SpriteLayerMaterial};
 use bevy::prelude::*;
 
 /// Wrapper plugin for sprite rendering.
@@ -7,12 +8,19 @@ use bevy::prelude::*;
 /// All domain logic (resources, events, systems) is handled by SpriteRenderingDomainPlugin.
 /// This wrapper exists for organizational purposes and maintains the public API.
 ///
-/// Includes BillboardPlugin for 3D billboard sprites using Mesh3d/MeshMaterial3d.
+/// Includes BillboardPlugin for 3D billboard sprites using Mesh3d/MeshMaterial3d,
+/// the shared `SpriteLayerMaterial` pipeline sprite layers draw with, and the
+/// `PaletteSpriteMaterial` pipeline dyed hair/body layers draw with.
 pub struct GenericSpriteRenderingPlugin;
 
 impl Plugin for GenericSpriteRenderingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((SpriteRenderingDomainPlugin, BillboardPlugin));
+        app.add_plugins((
+            SpriteRenderingDomainPlugin,
+            BillboardPlugin,
+            MaterialPlugin::<SpriteLayerMaterial>::default(),
+            MaterialPlugin::<PaletteSpriteMaterial>::default(),
+        ));
         debug!("GenericSpriteRenderingPlugin initialized with Billboard system");
     }
 }
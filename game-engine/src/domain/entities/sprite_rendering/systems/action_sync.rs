@@ -36,6 +36,7 @@ type SpriteActionQuery<'w, 's, T> = Query<
         Changed<AnimationState>,
         Added<RoSpriteGeneric<T>>,
         Changed<CombatMotion>,
+        Changed<MovementSpeed>,
     )>,
 >;
 
@@ -76,7 +77,7 @@ fn sync_sprite_action_impl<T: ActionLayout>(time: &Res<Time>, query: &mut Sprite
         let duration_ms = attack_timer
             .filter(|_| is_attack(action_type))
             .map(|timer| timer.timer.duration().as_millis() as u32);
-        ro_sprite.speed_factor = walk_speed_factor(action_type, movement_speed);
+        ro_sprite.set_speed_factor(walk_speed_factor(action_type, movement_speed), game_time_ms);
         ro_sprite.set_action_with_duration(action_type, duration_ms, game_time_ms);
     }
 }
@@ -85,7 +86,8 @@ fn sync_sprite_action_impl<T: ActionLayout>(time: &Res<Time>, query: &mut Sprite
 /// unit doesn't replay its walk cycle several times while crawling across one
 /// cell. Slower-than-standard units (most mobs) get a factor > 1 (longer per-frame
 /// delay); standard 150ms/cell units keep the ACT's natural rate. Non-walk actions
-/// always play at their natural rate.
+/// always play at their natural rate. A speed change mid-walk (the speed param,
+/// a unit re-entering view) re-runs the sync so the stride follows at once.
 fn walk_speed_factor(action_type: ActionType, movement_speed: Option<&MovementSpeed>) -> f32 {
     if action_type != ActionType::Walk {
         return 1.0;
@@ -349,6 +351,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn speed_change_mid_walk_retimes_the_walk_cycle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, sync_player_sprite_action);
+
+        let unit = app
+            .world_mut()
+            .spawn((
+                AnimationState::Walking,
+                MovementSpeed::from_server_speed(150),
+                PlayerSprite::default(),
+            ))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<PlayerSprite>(unit).unwrap().speed_factor,
+            1.0
+        );
+
+        *app.world_mut().get_mut::<MovementSpeed>(unit).unwrap() =
+            MovementSpeed::from_server_speed(300);
+        app.update();
+
+        let sprite = app.world().get::<PlayerSprite>(unit).unwrap();
+        assert_eq!(sprite.speed_factor, 2.0);
+        assert_eq!(sprite.action_type, ActionType::Walk);
+    }
+
     #[test]
     fn direction_sync_ignores_equipment_preview_camera() {
         // With the equipment preview camera present, the world direction system must
@@ -4,10 +4,11 @@ use bevy_auto_plugin::prelude::*;
 use crate::domain::audio::events::PlayMobSfx;
 use crate::domain::effects::AnimationPaused;
 use crate::domain::entities::sprite_rendering::components::{
-    BodyAttachPoint, HeadLayer, MobSprite, PlayerSprite, RenderLayer, RoSpriteGeneric,
+    BodyAttachPoint, HeadLayer, RenderLayer, RoSpriteGeneric, SpriteCulled, SpriteCullingConfig,
+    SpriteLod,
 };
-use crate::domain::entities::sprite_rendering::layout::ActionLayout;
-use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::domain::entities::sprite_rendering::layout::{ActionLayout, MobLayout, PlayerLayout};
+use crate::domain::entities::sprite_rendering::systems::{LayerMaterialHandles, LayerMaterials};
 use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
 use crate::utils::constants::SPRITE_WORLD_SCALE;
@@ -18,25 +19,51 @@ type BodyLayerQuery<'w, 's> = Query<
     (
         &'static RenderLayer,
         &'static ChildOf,
-        &'static MeshMaterial3d<StandardMaterial>,
+        LayerMaterialHandles,
         &'static mut Transform,
         &'static mut BodyAttachPoint,
     ),
     Without<HeadLayer>,
 >;
 
+type BodyParentQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        &'static RoSpriteGeneric<T>,
+        Option<&'static AnimationPaused>,
+        Has<SpriteLod>,
+    ),
+    Without<SpriteCulled>,
+>;
+
+/// The clock a layer animates by: the captured pause time for a frozen unit,
+/// and the live clock rounded down to `lod_frame_ms` steps for a low-detail one.
+fn layer_time(
+    game_time_ms: u32,
+    paused: Option<&AnimationPaused>,
+    lod_frame_ms: Option<u32>,
+) -> u32 {
+    let time = paused.map_or(game_time_ms, |p| p.at_ms);
+    match lod_frame_ms {
+        Some(step) if step > 0 => time - time % step,
+        _ => time,
+    }
+}
+
 fn sync_body_layer_impl<T: ActionLayout>(
     game_time_ms: u32,
+    lod_frame_ms: u32,
     animations: &Res<Assets<RoAnimationAsset>>,
-    materials: &mut Assets<StandardMaterial>,
-    parent_query: &Query<(&RoSpriteGeneric<T>, Option<&AnimationPaused>)>,
+    materials: &mut LayerMaterials,
+    parent_query: &BodyParentQuery<T>,
     layer_query: &mut BodyLayerQuery,
     mut sfx: Option<&mut MessageWriter<PlayMobSfx>>,
 ) {
-    for (layer, child_of, material_handle, mut transform, mut attach_point) in
+    for (layer, child_of, material_handles, mut transform, mut attach_point) in
         layer_query.iter_mut()
     {
-        let Ok((ro_sprite, paused)) = parent_query.get(child_of.parent()) else {
+        let Ok((ro_sprite, paused, lod)) = parent_query.get(child_of.parent()) else {
             continue;
         };
 
@@ -48,7 +75,8 @@ fn sync_body_layer_impl<T: ActionLayout>(
         // showing when the pause began: feed that captured timestamp instead of
         // the live clock. The head and weapon layers ride the body's published
         // frame index, so freezing the body alone holds the whole character.
-        let effective_time = paused.map_or(game_time_ms, |p| p.at_ms);
+        // The same goes for the coarse tick of a low-detail unit.
+        let effective_time = layer_time(game_time_ms, paused, lod.then_some(lod_frame_ms));
 
         let frame_index = ro_sprite.get_frame_index(animation, effective_time);
         let Some(frame) = ro_sprite.get_frame(animation, effective_time) else {
@@ -59,21 +87,21 @@ fn sync_body_layer_impl<T: ActionLayout>(
         // `as_mut()` reborrows the Option each iteration (MessageWriter is not DerefMut).
         if let Some(writer) = sfx.as_mut()
             && frame_index != attach_point.frame_index
-            && let Some(name) = frame
-                .sound_id
-                .and_then(|id| animation.sounds.get(id as usize))
-                .filter(|name| !name.is_empty())
+            && let Some(name) = animation.frame_sound(frame)
         {
             writer.write(PlayMobSfx {
                 emitter: child_of.parent(),
-                sound: name.clone(),
+                sound: name.to_string(),
             });
         }
 
         if let Some(part) = frame.parts.first() {
-            if let Some(texture) = animation.textures.get(part.texture_index) {
-                set_layer_texture(materials, &material_handle.0, texture);
-            }
+            materials.show_frame(
+                material_handles,
+                layer.animation.id(),
+                animation,
+                part.texture_index,
+            );
 
             let sprite_width = part.texture_size.x;
             let sprite_height = part.texture_size.y;
@@ -81,7 +109,9 @@ fn sync_body_layer_impl<T: ActionLayout>(
             let mut scale_x = part.scale.x * sprite_width * SPRITE_WORLD_SCALE;
             let scale_y = part.scale.y * sprite_height * SPRITE_WORLD_SCALE;
 
-            if part.mirror {
+            // Five-direction ACTs draw the east-facing directions as their
+            // west-facing counterparts flipped.
+            if part.mirror != ro_sprite.is_mirrored(animation) {
                 scale_x = -scale_x;
             }
 
@@ -103,7 +133,7 @@ fn sync_body_layer_impl<T: ActionLayout>(
         }
 
         attach_point.set_if_neq(BodyAttachPoint {
-            attach_point: frame.attach_point.unwrap_or(attach_point.attach_point),
+            attach_point: frame.attach_point,
             frame_index,
             layer_pos: frame
                 .parts
@@ -121,14 +151,16 @@ fn sync_body_layer_impl<T: ActionLayout>(
 pub fn sync_player_body_layer(
     time: Res<Time>,
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    parent_query: Query<(&PlayerSprite, Option<&AnimationPaused>)>,
+    mut materials: LayerMaterials,
+    config: Res<SpriteCullingConfig>,
+    parent_query: BodyParentQuery<PlayerLayout>,
     mut layer_query: BodyLayerQuery,
     mut sfx_writer: MessageWriter<PlayMobSfx>,
 ) {
     let game_time_ms = (time.elapsed_secs() * 1000.0) as u32;
     sync_body_layer_impl(
         game_time_ms,
+        config.lod_frame_ms,
         &animations,
         &mut materials,
         &parent_query,
@@ -145,14 +177,16 @@ pub fn sync_player_body_layer(
 pub fn sync_mob_body_layer(
     time: Res<Time>,
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    parent_query: Query<(&MobSprite, Option<&AnimationPaused>)>,
+    mut materials: LayerMaterials,
+    config: Res<SpriteCullingConfig>,
+    parent_query: BodyParentQuery<MobLayout>,
     mut layer_query: BodyLayerQuery,
     mut sfx_writer: MessageWriter<PlayMobSfx>,
 ) {
     let game_time_ms = (time.elapsed_secs() * 1000.0) as u32;
     sync_body_layer_impl(
         game_time_ms,
+        config.lod_frame_ms,
         &animations,
         &mut materials,
         &parent_query,
@@ -160,3 +194,18 @@ pub fn sync_mob_body_layer(
         Some(&mut sfx_writer),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn low_detail_units_step_on_the_coarse_tick() {
+        assert_eq!(layer_time(1_234, None, None), 1_234);
+        assert_eq!(layer_time(1_234, None, Some(200)), 1_200);
+        assert_eq!(layer_time(1_234, None, Some(0)), 1_234);
+
+        let paused = AnimationPaused { at_ms: 950 };
+        assert_eq!(layer_time(1_234, Some(&paused), Some(200)), 800);
+    }
+}
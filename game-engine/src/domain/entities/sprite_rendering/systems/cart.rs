@@ -1,3 +1,4 @@
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 use bevy_persistent::prelude::Persistent;
@@ -8,8 +9,10 @@ use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
 use crate::domain::entities::character::components::visual::{ActionType, Direction};
 use crate::domain::entities::character::systems::CART_MASK;
 use crate::domain::entities::registry::EntityRegistry;
-use crate::domain::entities::sprite_rendering::components::{CartLayer, PlayerSprite, RenderLayer};
-use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::domain::entities::sprite_rendering::components::{
+    CartLayer, LayerShading, PlayerSprite, RenderLayer, SpriteCulled,
+};
+use crate::domain::entities::sprite_rendering::systems::{LayerMaterialHandles, LayerMaterials};
 use crate::domain::settings::resources::Settings;
 use crate::domain::sprite::tags::{
     LAYER_CART, SPRITE_BASE_Y_OFFSET, Z_OFFSET_PER_LAYER, layer_depth_bias, layer_order,
@@ -17,7 +20,8 @@ use crate::domain::sprite::tags::{
 use crate::domain::system_sets::{EntityLifecycleSystems, SpriteRenderingSystems};
 use crate::infrastructure::assets::animation_processor::RoAnimationProcessor;
 use crate::infrastructure::assets::loaders::{RoActAsset, RoSpriteAsset};
-use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
+use crate::infrastructure::assets::ro_animation_asset::{ActionData, RoAnimationAsset};
+use crate::presentation::rendering::SpriteLayerMaterial;
 use crate::utils::constants::SPRITE_WORLD_SCALE;
 
 /// SPR/ACT handles still loading for a cart child. Kept on the child itself so
@@ -63,7 +67,6 @@ pub fn apply_cart_mount(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     shared_quad: Res<SharedSpriteQuad>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     cart_layers: CartOwnerQuery,
 ) {
     for event in state_changes.read() {
@@ -81,7 +84,6 @@ pub fn apply_cart_mount(
             &mut commands,
             &asset_server,
             &shared_quad,
-            &mut materials,
         );
     }
 
@@ -96,7 +98,6 @@ pub fn apply_cart_mount(
             &mut commands,
             &asset_server,
             &shared_quad,
-            &mut materials,
         );
     }
 }
@@ -110,7 +111,6 @@ fn apply_cart_state(
     commands: &mut Commands,
     asset_server: &AssetServer,
     shared_quad: &SharedSpriteQuad,
-    materials: &mut Assets<StandardMaterial>,
 ) {
     let mounted = effect_state & CART_MASK != 0;
     // NOTE: the spawn/despawn are deferred commands, so two cart-mount events
@@ -124,7 +124,7 @@ fn apply_cart_state(
         .collect();
 
     match (mounted, existing.is_empty()) {
-        (true, true) => spawn_cart_layer(commands, entity, asset_server, shared_quad, materials),
+        (true, true) => spawn_cart_layer(commands, entity, asset_server, shared_quad),
         (false, false) => {
             for child in existing {
                 commands.entity(child).despawn();
@@ -147,27 +147,21 @@ fn spawn_cart_layer(
     parent: Entity,
     asset_server: &AssetServer,
     shared_quad: &SharedSpriteQuad,
-    materials: &mut Assets<StandardMaterial>,
 ) {
     let z_offset = layer_order(LAYER_CART) as f32 * Z_OFFSET_PER_LAYER;
 
     for part in 0..CART_ACT_PARTS {
-        let material = materials.add(StandardMaterial {
-            base_color_texture: None,
-            alpha_mode: AlphaMode::Blend,
-            unlit: true,
-            cull_mode: None,
-            // Later ACT parts stack on top within the cart's own bias slot.
-            depth_bias: layer_depth_bias(LAYER_CART) + part as f32 * 0.01,
-            ..default()
-        });
+        // Later ACT parts stack on top within the cart's own bias slot.
+        let shading = LayerShading::new(layer_depth_bias(LAYER_CART) + part as f32 * 0.01);
 
         // Later ACT layers draw on top, so give each part a tiny z step.
         let part_z = z_offset + part as f32 * 0.001;
 
         commands.spawn((
             Mesh3d(shared_quad.mesh.clone()),
-            MeshMaterial3d(material),
+            MeshMaterial3d::<SpriteLayerMaterial>::default(),
+            MeshTag(0),
+            shading,
             Billboard,
             RenderLayer::body(Handle::default(), LAYER_CART, Vec::new()),
             CartLayer { part },
@@ -218,7 +212,7 @@ pub fn finalize_cart_layer(
             settings.graphics.upscaling,
         );
 
-        render_layer.textures = animation.textures.clone();
+        render_layer.textures = animation.atlas_pages.clone();
         render_layer.animation = animations.add(animation);
         commands.entity(entity).remove::<CartAnimationPending>();
         debug!("cart: animation finalized for {entity:?}");
@@ -232,7 +226,7 @@ type CartLayerQuery<'w, 's> = Query<
         &'static RenderLayer,
         &'static CartLayer,
         &'static ChildOf,
-        &'static MeshMaterial3d<StandardMaterial>,
+        LayerMaterialHandles,
         &'static mut Transform,
         &'static mut Visibility,
     ),
@@ -262,6 +256,18 @@ fn cart_behind_offset(direction: Direction) -> Vec2 {
     }
 }
 
+/// The wheel frame to show: rolling on the cart's own delay while walking,
+/// stretched by the walker's speed factor like the body's walk cycle so a slow
+/// merchant's wheels don't spin past the ground they cover; the first frame
+/// otherwise.
+fn cart_frame_index(sprite: &PlayerSprite, action: &ActionData, game_time_ms: u32) -> usize {
+    if sprite.action_type != ActionType::Walk || action.frames.is_empty() {
+        return 0;
+    }
+    let delay = (action.delay_ms * sprite.speed_factor).max(1.0);
+    (game_time_ms as f32 / delay) as usize % action.frames.len()
+}
+
 /// Drives each cart quad per frame off its parent's `PlayerSprite`: the cart
 /// ACT is direction-only (8 actions), so the parent's facing picks the action
 /// and the wheel frames animate on the cart's own delay while walking.
@@ -275,8 +281,8 @@ fn cart_behind_offset(direction: Direction) -> Vec2 {
 pub fn sync_cart_layer(
     time: Res<Time>,
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    parent_query: Query<&PlayerSprite>,
+    mut materials: LayerMaterials,
+    parent_query: Query<&PlayerSprite, Without<SpriteCulled>>,
     mut cart_query: CartLayerQuery,
 ) {
     let game_time_ms = (time.elapsed_secs() * 1000.0) as u32;
@@ -307,12 +313,7 @@ pub fn sync_cart_layer(
             continue;
         }
 
-        let frame_index = if ro_sprite.action_type == ActionType::Walk {
-            let delay = action_data.delay_ms.max(1.0);
-            (game_time_ms as f32 / delay) as usize % action_data.frames.len()
-        } else {
-            0
-        };
+        let frame_index = cart_frame_index(ro_sprite, action_data, game_time_ms);
 
         let Some(frame) = action_data.frames.get(frame_index) else {
             visibility.set_if_neq(Visibility::Hidden);
@@ -324,9 +325,12 @@ pub fn sync_cart_layer(
             continue;
         };
 
-        if let Some(texture) = animation.textures.get(part.texture_index) {
-            set_layer_texture(&mut materials, &material_handle.0, texture);
-        }
+        materials.show_frame(
+            material_handle,
+            layer.animation.id(),
+            animation,
+            part.texture_index,
+        );
 
         let mut scale_x = part.scale.x * part.texture_size.x * SPRITE_WORLD_SCALE;
         let scale_y = part.scale.y * part.texture_size.y * SPRITE_WORLD_SCALE;
@@ -357,6 +361,7 @@ pub fn sync_cart_layer(
 mod tests {
     use super::*;
     use crate::domain::entities::billboard::create_sprite_quad_mesh;
+    use crate::domain::entities::sprite_rendering::systems::init_layer_materials;
     use crate::infrastructure::assets::loaders::{RoActAsset, RoSpriteAsset};
 
     const OPTION_CART1: u32 = 0x08;
@@ -364,7 +369,6 @@ mod tests {
     fn app() -> App {
         let mut app = App::new();
         app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()))
-            .init_asset::<StandardMaterial>()
             .init_asset::<Mesh>()
             .init_asset::<RoSpriteAsset>()
             .init_asset::<RoActAsset>()
@@ -418,6 +422,7 @@ mod tests {
                 body_state: 0,
                 health_state: 0,
                 effect_state,
+                virtue: 0,
                 head: 0,
                 weapon: 0,
                 shield: 0,
@@ -496,7 +501,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
         app.init_asset::<RoAnimationAsset>();
-        app.init_asset::<StandardMaterial>();
+        init_layer_materials(&mut app);
         app.add_systems(Update, sync_cart_layer);
         app.update();
     }
@@ -534,4 +539,22 @@ mod tests {
 
         assert!(cart_children(&mut app, unit).is_empty());
     }
+
+    #[test]
+    fn cart_wheels_roll_at_the_walkers_pace() {
+        let action = ActionData {
+            frames: vec![default(); 4],
+            delay_ms: 100.0,
+        };
+        let mut sprite = PlayerSprite::default();
+        assert_eq!(cart_frame_index(&sprite, &action, 250), 0);
+
+        sprite.action_type = ActionType::Walk;
+        assert_eq!(cart_frame_index(&sprite, &action, 250), 2);
+        assert_eq!(cart_frame_index(&sprite, &action, 450), 0);
+
+        // Half speed: twice as long per frame.
+        sprite.speed_factor = 2.0;
+        assert_eq!(cart_frame_index(&sprite, &action, 250), 1);
+    }
 }
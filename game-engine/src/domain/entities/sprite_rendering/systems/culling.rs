@@ -0,0 +1,445 @@
+use bevy::camera::primitives::{Frustum, Sphere};
+use bevy::camera::visibility::VisibilityRange;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_persistent::prelude::Persistent;
+
+use crate::domain::entities::billboard::{EquipmentPreviewCamera, PreviewBillboard};
+use crate::domain::entities::markers::LocalPlayer;
+use crate::domain::entities::sprite_rendering::components::{
+    BodyAttachPoint, HeadLayer, MobSprite, PlayerSprite, RenderLayer, ShadowRenderLayer,
+    SpriteBounds, SpriteCulled, SpriteCullingConfig, SpriteLod,
+};
+use crate::domain::settings::resources::Settings;
+use crate::domain::system_sets::SpriteRenderingSystems;
+use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
+use crate::utils::constants::SPRITE_WORLD_SCALE;
+
+/// Sprites stand up from their feet toward -Y; until a unit's body is measured
+/// its culling sphere is centered roughly at the middle of a standing character.
+const FALLBACK_CENTER_HEIGHT: f32 = 15.0;
+
+/// Everything drawn for a unit that the visibility range applies to.
+type RangedLayerFilter = Or<(With<RenderLayer>, With<ShadowRenderLayer>)>;
+type NewRangedLayerFilter = Or<(Added<RenderLayer>, Added<ShadowRenderLayer>)>;
+
+type CullingCameraFilter = (With<Camera3d>, Without<EquipmentPreviewCamera>);
+
+type CullableSpriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static GlobalTransform,
+        Option<&'static SpriteBounds>,
+        Option<&'static Children>,
+        Has<SpriteCulled>,
+    ),
+    (
+        Or<(With<PlayerSprite>, With<MobSprite>)>,
+        Without<LocalPlayer>,
+    ),
+>;
+
+type LodSpriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static GlobalTransform, Has<SpriteLod>),
+    (
+        Or<(With<PlayerSprite>, With<MobSprite>)>,
+        Without<LocalPlayer>,
+    ),
+>;
+
+type BodyLayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static RenderLayer, &'static ChildOf),
+    (With<BodyAttachPoint>, Without<HeadLayer>),
+>;
+
+/// The sphere around `(center, half diagonal)` quads that holds all of them,
+/// whichever way each quad is turned to face the camera.
+fn enclosing_sphere(quads: &[(Vec2, f32)]) -> Option<(Vec2, f32)> {
+    let (min, max) = quads.iter().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), &(center, half)| (min.min(center - half), max.max(center + half)),
+    );
+    if quads.is_empty() {
+        return None;
+    }
+    let center = (min + max) / 2.0;
+    let radius = quads
+        .iter()
+        .map(|&(quad, half)| quad.distance(center) + half)
+        .fold(0.0, f32::max);
+    Some((center, radius))
+}
+
+/// Bounds of every frame the body layer can show, laid out as `sync_body_layer`
+/// places them (the first part of each frame, centered at its ACT position).
+fn body_frame_bounds(animation: &RoAnimationAsset) -> Option<(Vec3, f32)> {
+    let quads: Vec<(Vec2, f32)> = animation
+        .actions
+        .iter()
+        .flat_map(|action| &action.frames)
+        .filter_map(|frame| frame.parts.first())
+        .map(|part| {
+            let center = Vec2::new(part.position.x, -part.position.y) * SPRITE_WORLD_SCALE;
+            let size = (part.scale * part.texture_size * SPRITE_WORLD_SCALE).abs();
+            (center, size.length() / 2.0)
+        })
+        .collect();
+    let (center, radius) = enclosing_sphere(&quads)?;
+    Some((center.extend(0.0), radius))
+}
+
+/// Measure each unit's [`SpriteBounds`] from its body animation once that has
+/// loaded, and again whenever the body animation is swapped.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::AnimationSync, before = cull_sprites_outside_view)
+)]
+pub fn measure_sprite_bounds(
+    mut commands: Commands,
+    animations: Res<Assets<RoAnimationAsset>>,
+    body_layers: BodyLayerQuery,
+    bounds: Query<&SpriteBounds>,
+) {
+    for (layer, child_of) in &body_layers {
+        let unit = child_of.parent();
+        let animation_id = layer.animation.id();
+        if bounds
+            .get(unit)
+            .is_ok_and(|bounds| bounds.animation == animation_id)
+        {
+            continue;
+        }
+        let Some((center, radius)) = animations.get(animation_id).and_then(body_frame_bounds)
+        else {
+            continue;
+        };
+        commands.entity(unit).insert(SpriteBounds {
+            animation: animation_id,
+            center,
+            radius,
+        });
+    }
+}
+
+/// Whether a unit standing at `feet` can be seen by a camera at `camera`.
+fn sprite_in_view(
+    config: &SpriteCullingConfig,
+    frustum: &Frustum,
+    camera: Vec3,
+    feet: Vec3,
+    bounds: Option<&SpriteBounds>,
+) -> bool {
+    if camera.distance(feet) > config.max_distance {
+        return false;
+    }
+    let (center, radius) = bounds.map_or(
+        (Vec3::NEG_Y * FALLBACK_CENTER_HEIGHT, config.bounds_radius),
+        |bounds| (bounds.center, bounds.radius + config.bounds_margin),
+    );
+    frustum.intersects_sphere(
+        &Sphere {
+            center: (feet + center).into(),
+            radius,
+        },
+        true,
+    )
+}
+
+/// Mark units outside the active camera's view with `SpriteCulled` so the layer
+/// sync systems skip them. The local player is never culled, nor is the
+/// equipment-window preview character, which the world camera never sees.
+/// With no single active camera nothing is culled.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::AnimationSync)
+)]
+pub fn cull_sprites_outside_view(
+    mut commands: Commands,
+    config: Res<SpriteCullingConfig>,
+    camera_query: Query<(&GlobalTransform, &Frustum), CullingCameraFilter>,
+    sprites: CullableSpriteQuery,
+    preview_layers: Query<(), With<PreviewBillboard>>,
+) {
+    let camera = camera_query.single().ok().filter(|_| config.enabled);
+
+    for (entity, transform, bounds, children, culled) in sprites.iter() {
+        let in_preview = children
+            .is_some_and(|children| children.iter().any(|child| preview_layers.contains(child)));
+        let visible = camera.is_none_or(|(camera_transform, frustum)| {
+            in_preview
+                || sprite_in_view(
+                    &config,
+                    frustum,
+                    camera_transform.translation(),
+                    transform.translation(),
+                    bounds,
+                )
+        });
+
+        if visible && culled {
+            commands.entity(entity).remove::<SpriteCulled>();
+        } else if !visible && !culled {
+            commands.entity(entity).insert(SpriteCulled);
+        }
+    }
+}
+
+/// Whether a unit standing at `feet` is far enough from a camera at `camera` to
+/// be drawn at low detail.
+fn sprite_far(config: &SpriteCullingConfig, camera: Vec3, feet: Vec3) -> bool {
+    camera.distance(feet) > config.lod_distance
+}
+
+/// Mark units beyond `lod_distance` with [`SpriteLod`] while the sprite LOD
+/// setting is on. The local player is never marked.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::AnimationSync)
+)]
+pub fn mark_far_sprites_lod(
+    mut commands: Commands,
+    config: Res<SpriteCullingConfig>,
+    settings: Res<Persistent<Settings>>,
+    camera_query: Query<&GlobalTransform, CullingCameraFilter>,
+    sprites: LodSpriteQuery,
+) {
+    let camera = camera_query
+        .single()
+        .ok()
+        .filter(|_| settings.graphics.sprite_lod);
+
+    for (entity, transform, lod) in &sprites {
+        let far = camera.is_some_and(|camera| {
+            sprite_far(&config, camera.translation(), transform.translation())
+        });
+        if far && !lod {
+            commands.entity(entity).insert(SpriteLod);
+        } else if !far && lod {
+            commands.entity(entity).remove::<SpriteLod>();
+        }
+    }
+}
+
+/// Stop drawing layers (and shadow blobs) beyond `max_distance` so far units
+/// that are culled (and therefore frozen) aren't left standing on screen.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn)
+)]
+pub fn apply_sprite_visibility_range(
+    mut commands: Commands,
+    config: Res<SpriteCullingConfig>,
+    new_layers: Query<Entity, NewRangedLayerFilter>,
+    all_layers: Query<Entity, RangedLayerFilter>,
+) {
+    let range = config
+        .enabled
+        .then(|| VisibilityRange::abrupt(0.0, config.max_distance));
+    let apply = |commands: &mut Commands, entity: Entity| match &range {
+        Some(range) => {
+            commands.entity(entity).insert(range.clone());
+        }
+        None => {
+            commands.entity(entity).remove::<VisibilityRange>();
+        }
+    };
+
+    if config.is_changed() {
+        for entity in all_layers.iter() {
+            apply(&mut commands, entity);
+        }
+    } else {
+        for entity in new_layers.iter() {
+            apply(&mut commands, entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::camera::{CameraProjection, PerspectiveProjection};
+    use bevy::math::primitives::ViewFrustum;
+
+    use crate::domain::sprite::tags::LAYER_BODY;
+    use crate::infrastructure::assets::ro_animation_asset::{ActionData, FrameData, FramePart};
+
+    fn camera_frustum(transform: &GlobalTransform) -> Frustum {
+        let projection = PerspectiveProjection {
+            aspect_ratio: 16.0 / 9.0,
+            ..default()
+        };
+        let clip_from_world = projection.get_clip_from_view() * transform.to_matrix().inverse();
+        Frustum(ViewFrustum::from_clip_from_world(&clip_from_world))
+    }
+
+    #[test]
+    fn sprites_behind_the_camera_or_too_far_are_out_of_view() {
+        let config = SpriteCullingConfig::default();
+        let camera = GlobalTransform::from(
+            Transform::from_xyz(0.0, -150.0, -150.0).looking_at(Vec3::ZERO, Vec3::NEG_Y),
+        );
+        let frustum = camera_frustum(&camera);
+        let eye = camera.translation();
+
+        assert!(sprite_in_view(&config, &frustum, eye, Vec3::ZERO, None));
+        assert!(sprite_in_view(
+            &config,
+            &frustum,
+            eye,
+            Vec3::new(20.0, 0.0, 20.0),
+            None
+        ));
+        assert!(!sprite_in_view(
+            &config,
+            &frustum,
+            eye,
+            Vec3::new(0.0, 0.0, -400.0),
+            None
+        ));
+
+        let tight = SpriteCullingConfig {
+            max_distance: 100.0,
+            ..config
+        };
+        assert!(!sprite_in_view(&tight, &frustum, eye, Vec3::ZERO, None));
+    }
+
+    fn body_animation(parts: &[(Vec2, Vec2)]) -> RoAnimationAsset {
+        let frames = parts
+            .iter()
+            .map(|&(position, texture_size)| FrameData {
+                parts: vec![FramePart {
+                    texture_index: 0,
+                    transform: Mat4::IDENTITY,
+                    position,
+                    scale: Vec2::ONE,
+                    texture_size,
+                    color: Color::WHITE,
+                    mirror: false,
+                }],
+                size: texture_size,
+                offset: Vec2::ZERO,
+                attach_point: None,
+                sound_id: None,
+                is_attack_frame: false,
+            })
+            .collect();
+        RoAnimationAsset {
+            atlas_pages: Vec::new(),
+            frame_regions: Vec::new(),
+            index_pages: Vec::new(),
+            index_regions: Vec::new(),
+            actions: vec![ActionData {
+                frames,
+                delay_ms: 100.0,
+            }],
+            layer: LAYER_BODY,
+            sounds: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn only_units_past_the_lod_distance_are_far() {
+        let config = SpriteCullingConfig::default();
+        let camera = Vec3::new(0.0, -150.0, -150.0);
+
+        assert!(!sprite_far(&config, camera, Vec3::ZERO));
+        assert!(sprite_far(&config, camera, Vec3::new(0.0, 0.0, 400.0)));
+    }
+
+    #[test]
+    fn bounds_cover_every_body_frame() {
+        // A 100x200 px standing frame and a 300 px wide frame off to the side,
+        // both lifted off the feet by their ACT position.
+        let animation = body_animation(&[
+            (Vec2::new(0.0, 100.0), Vec2::new(100.0, 200.0)),
+            (Vec2::new(150.0, 50.0), Vec2::new(300.0, 100.0)),
+        ]);
+        let (center, radius) = body_frame_bounds(&animation).unwrap();
+
+        for (quad_center, corner) in [
+            (Vec2::new(0.0, -20.0), Vec2::new(10.0, 20.0)),
+            (Vec2::new(30.0, -10.0), Vec2::new(30.0, 10.0)),
+        ] {
+            for sign in [Vec2::ONE, -Vec2::ONE, Vec2::new(1.0, -1.0)] {
+                let point = (quad_center + corner * sign).extend(0.0);
+                assert!(point.distance(center) <= radius + 1e-3, "{point} outside");
+            }
+        }
+        assert!(body_frame_bounds(&body_animation(&[])).is_none());
+    }
+
+    #[test]
+    fn measured_bounds_keep_a_large_sprite_in_view() {
+        let config = SpriteCullingConfig::default();
+        let camera = GlobalTransform::from(
+            Transform::from_xyz(0.0, -150.0, -150.0).looking_at(Vec3::ZERO, Vec3::NEG_Y),
+        );
+        let frustum = camera_frustum(&camera);
+        let eye = camera.translation();
+
+        // Feet just past the bottom of the view: the default sphere misses,
+        // but a tall sprite standing there still reaches into the picture.
+        let feet = (1..2_000)
+            .map(|step| Vec3::new(0.0, 0.0, -(step as f32)))
+            .find(|&feet| !sprite_in_view(&config, &frustum, eye, feet, None))
+            .unwrap();
+        let tall = SpriteBounds {
+            animation: AssetId::default(),
+            center: Vec3::new(0.0, -60.0, 0.0),
+            radius: 60.0,
+        };
+        assert!(sprite_in_view(&config, &frustum, eye, feet, Some(&tall)));
+    }
+
+    #[test]
+    fn culled_marker_follows_the_camera_view() {
+        let mut app = App::new();
+        app.init_resource::<SpriteCullingConfig>();
+        app.add_systems(Update, cull_sprites_outside_view);
+
+        let camera = GlobalTransform::from(
+            Transform::from_xyz(0.0, -150.0, -150.0).looking_at(Vec3::ZERO, Vec3::NEG_Y),
+        );
+        app.world_mut()
+            .spawn((Camera3d::default(), camera, camera_frustum(&camera)));
+
+        let spawn_unit = |app: &mut App, at: Vec3| {
+            app.world_mut()
+                .spawn((MobSprite::default(), GlobalTransform::from_translation(at)))
+                .id()
+        };
+        let near = spawn_unit(&mut app, Vec3::ZERO);
+        let behind = spawn_unit(&mut app, Vec3::new(0.0, 0.0, -400.0));
+        let player = app
+            .world_mut()
+            .spawn((
+                PlayerSprite::default(),
+                LocalPlayer,
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -400.0)),
+            ))
+            .id();
+
+        app.update();
+        assert!(!app.world().entity(near).contains::<SpriteCulled>());
+        assert!(app.world().entity(behind).contains::<SpriteCulled>());
+        assert!(!app.world().entity(player).contains::<SpriteCulled>());
+
+        app.world_mut()
+            .resource_mut::<SpriteCullingConfig>()
+            .enabled = false;
+        app.update();
+        assert!(!app.world().entity(behind).contains::<SpriteCulled>());
+    }
+}
@@ -0,0 +1,373 @@
+//! Hair and clothes dyes.
+//!
+//! A dyed hair or body layer draws with `PaletteSpriteMaterial`: the SPR's
+//! palette indices stay on the GPU as an index atlas and the dye's `.pal` is a
+//! 256x1 lookup texture, so a new color only swaps that texture instead of
+//! rebuilding the layer's frames. Undyed layers (color 0) keep the shared RGBA
+//! atlas `SpriteLayerMaterial` built from the sprite's own palette.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use moonshine_tag::Tag;
+
+use crate::domain::assets::patterns;
+use crate::domain::entities::character::components::{CharacterAppearance, CharacterData};
+use crate::domain::entities::registry::EntityRegistry;
+use crate::domain::entities::sprite_rendering::components::{
+    DyedLayer, LayerDye, LayerShading, RenderLayer,
+};
+use crate::domain::sprite::tags::{LAYER_BODY, LAYER_HEAD};
+use crate::domain::system_sets::SpriteRenderingSystems;
+use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
+use crate::infrastructure::assets::{RoPaletteAsset, palette_lookup_image};
+use crate::infrastructure::job::registry::JobSpriteRegistry;
+use crate::presentation::rendering::{PaletteSpriteMaterial, SpriteLayerMaterial};
+use net_contract::events::UnitSpriteChanged;
+
+/// `LOOK_HAIR_COLOR` / `LOOK_CLOTHES_COLOR`: the dye look slots.
+const LOOK_HAIR_COLOR: u32 = 6;
+const LOOK_CLOTHES_COLOR: u32 = 7;
+
+/// The dye palette for a layer of a unit, or `None` when the layer takes no dye
+/// or wears the default color. Clothes palettes are named after the job sprite.
+pub(crate) fn dye_palette_path(
+    layer: Tag,
+    appearance: &CharacterAppearance,
+    job_id: u16,
+    job_registry: Option<&JobSpriteRegistry>,
+) -> Option<String> {
+    if layer == LAYER_HEAD && appearance.hair_color != 0 {
+        Some(patterns::hair_palette_path(
+            appearance.hair_style,
+            appearance.gender,
+            appearance.hair_color,
+        ))
+    } else if layer == LAYER_BODY && appearance.clothes_color != 0 {
+        let job_name = job_registry?.get_sprite_name(job_id as u32)?;
+        Some(patterns::body_palette_path(
+            appearance.gender,
+            job_name,
+            appearance.clothes_color,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Apply dye changes broadcast by the server (stylist, dye NPCs) to the unit's
+/// appearance; `request_layer_dyes` picks them up from there.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn)
+)]
+pub fn apply_dye_look_changes(
+    mut sprite_changes: MessageReader<UnitSpriteChanged>,
+    registry: Res<EntityRegistry>,
+    mut appearances: Query<&mut CharacterAppearance>,
+) {
+    for change in sprite_changes.read() {
+        if change.type_ != LOOK_HAIR_COLOR && change.type_ != LOOK_CLOTHES_COLOR {
+            continue;
+        }
+        let Some(entity) = registry.get_entity(change.gid) else {
+            continue;
+        };
+        let Ok(mut appearance) = appearances.get_mut(entity) else {
+            continue;
+        };
+
+        let color = change.val as u16;
+        if change.type_ == LOOK_HAIR_COLOR {
+            appearance.hair_color = color;
+        } else {
+            appearance.clothes_color = color;
+        }
+    }
+}
+
+/// Keep each hair/body layer's `LayerDye` in step with its unit's appearance
+/// and job, starting the palette load when the dye changes.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn, after = apply_dye_look_changes)
+)]
+pub fn request_layer_dyes(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    job_registry: Option<Res<JobSpriteRegistry>>,
+    units: Query<(Ref<CharacterAppearance>, Ref<CharacterData>)>,
+    layers: Query<(Entity, Ref<RenderLayer>, &ChildOf, Option<&LayerDye>)>,
+) {
+    for (entity, layer, child_of, current) in &layers {
+        let Ok((appearance, character)) = units.get(child_of.parent()) else {
+            continue;
+        };
+        if !(layer.is_added() || appearance.is_changed() || character.is_changed()) {
+            continue;
+        }
+
+        let path = dye_palette_path(
+            layer.layer,
+            &appearance,
+            character.job_id,
+            job_registry.as_deref(),
+        );
+        if current.map(|dye| &dye.path) == path.as_ref() {
+            continue;
+        }
+
+        match path {
+            Some(path) => {
+                commands.entity(entity).insert(LayerDye {
+                    palette: asset_server.load(&path),
+                    path,
+                });
+            }
+            None => {
+                commands.entity(entity).remove::<LayerDye>();
+            }
+        }
+    }
+}
+
+/// Drop the lookup textures of palettes that were unloaded or changed, so the
+/// cache only holds images for palettes still in use.
+fn forget_stale_lookups<'a>(
+    lookups: &mut HashMap<AssetId<RoPaletteAsset>, Handle<Image>>,
+    events: impl IntoIterator<Item = &'a AssetEvent<RoPaletteAsset>>,
+) {
+    for event in events {
+        if let AssetEvent::Removed { id }
+        | AssetEvent::Unused { id }
+        | AssetEvent::Modified { id } = event
+        {
+            lookups.remove(id);
+        }
+    }
+}
+
+type DyeLayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static RenderLayer,
+        &'static LayerDye,
+        &'static LayerShading,
+        Option<&'static MeshMaterial3d<SpriteLayerMaterial>>,
+        Option<&'static MeshMaterial3d<PaletteSpriteMaterial>>,
+    ),
+>;
+
+/// Move layers whose dye palette has loaded onto `PaletteSpriteMaterial`,
+/// point already dyed layers at a new palette, and put undyed layers back on
+/// their atlas material. A palette that fails to load leaves the layer in its
+/// default colors. Lookup textures are cached per palette until it unloads.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn, after = request_layer_dyes)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn swap_dyed_layer_materials(
+    mut commands: Commands,
+    palettes: Res<Assets<RoPaletteAsset>>,
+    animations: Res<Assets<RoAnimationAsset>>,
+    mut images: ResMut<Assets<Image>>,
+    mut lookups: Local<HashMap<AssetId<RoPaletteAsset>, Handle<Image>>>,
+    mut palette_events: MessageReader<AssetEvent<RoPaletteAsset>>,
+    mut palette_materials: ResMut<Assets<PaletteSpriteMaterial>>,
+    dyed: DyeLayerQuery,
+    undyed: Query<(Entity, &DyedLayer), Without<LayerDye>>,
+) {
+    forget_stale_lookups(&mut lookups, palette_events.read());
+
+    for (entity, layer, dye, shading, atlas, palette_material) in &dyed {
+        let Some(palette) = palettes.get(&dye.palette) else {
+            continue;
+        };
+        let lookup = lookups
+            .entry(dye.palette.id())
+            .or_insert_with(|| images.add(palette_lookup_image(&palette.colors)))
+            .clone();
+
+        if let Some(palette_material) = palette_material {
+            let stale = palette_materials
+                .get(&palette_material.0)
+                .is_some_and(|material| material.palette != lookup);
+            if stale && let Some(mut material) = palette_materials.get_mut(&palette_material.0) {
+                material.palette = lookup;
+            }
+            continue;
+        }
+
+        let Some(atlas) = atlas else {
+            continue;
+        };
+        let Some((indices, region)) = animations
+            .get(&layer.animation)
+            .and_then(|animation| animation.index_texture(0))
+        else {
+            continue;
+        };
+        let mut material = PaletteSpriteMaterial::new(indices.clone(), lookup, region.uv);
+        material.set_tint(shading.tint);
+        material.depth_bias = shading.depth_bias;
+
+        commands
+            .entity(entity)
+            .remove::<MeshMaterial3d<SpriteLayerMaterial>>()
+            .insert((
+                MeshMaterial3d(palette_materials.add(material)),
+                DyedLayer {
+                    atlas: atlas.0.clone(),
+                },
+            ));
+    }
+
+    for (entity, dyed_layer) in &undyed {
+        commands
+            .entity(entity)
+            .remove::<(MeshMaterial3d<PaletteSpriteMaterial>, DyedLayer)>()
+            .insert(MeshMaterial3d(dyed_layer.atlas.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::character::components::{CharacterStats, Gender};
+    use bevy::asset::AssetPlugin;
+
+    const GID: u32 = 150_002;
+
+    fn appearance(hair_color: u16, clothes_color: u16) -> CharacterAppearance {
+        CharacterAppearance {
+            gender: Gender::Female,
+            hair_style: 2,
+            hair_color,
+            clothes_color,
+        }
+    }
+
+    fn registry() -> JobSpriteRegistry {
+        JobSpriteRegistry::from_job_data(lifthrasir_data::JobData::default())
+    }
+
+    #[test]
+    fn only_dyed_hair_and_body_layers_get_a_palette() {
+        let registry = registry();
+        let dyed = appearance(3, 1);
+
+        assert_eq!(
+            dye_palette_path(LAYER_HEAD, &dyed, 0, Some(&registry)).as_deref(),
+            Some("ro://data/palette/머리/2_여_3.pal")
+        );
+        let body = dye_palette_path(LAYER_BODY, &dyed, 0, Some(&registry)).unwrap();
+        assert!(body.starts_with("ro://data/palette/몸/") && body.ends_with("_여_1.pal"));
+
+        let plain = appearance(0, 0);
+        assert_eq!(
+            dye_palette_path(LAYER_HEAD, &plain, 0, Some(&registry)),
+            None
+        );
+        assert_eq!(
+            dye_palette_path(LAYER_BODY, &plain, 0, Some(&registry)),
+            None
+        );
+        assert_eq!(dye_palette_path(LAYER_BODY, &dyed, 0, None), None);
+    }
+
+    #[test]
+    fn lookups_of_unloaded_or_changed_palettes_are_dropped() {
+        let id = |n| AssetId::<RoPaletteAsset>::Uuid {
+            uuid: bevy::asset::uuid::Uuid::from_u128(n),
+        };
+        let mut lookups: HashMap<_, _> = (1..=4).map(|n| (id(n), Handle::default())).collect();
+
+        forget_stale_lookups(
+            &mut lookups,
+            &[
+                AssetEvent::Removed { id: id(1) },
+                AssetEvent::Unused { id: id(2) },
+                AssetEvent::Modified { id: id(3) },
+                AssetEvent::LoadedWithDependencies { id: id(4) },
+            ],
+        );
+
+        assert_eq!(lookups.keys().copied().collect::<Vec<_>>(), vec![id(4)]);
+    }
+
+    #[test]
+    fn dye_changes_follow_the_server_look() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<RoPaletteAsset>()
+            .init_resource::<EntityRegistry>()
+            .insert_resource(registry())
+            .add_message::<UnitSpriteChanged>()
+            .add_systems(Update, (apply_dye_look_changes, request_layer_dyes).chain());
+
+        let head_layer = app
+            .world_mut()
+            .spawn(RenderLayer::body(Handle::default(), LAYER_HEAD, Vec::new()))
+            .id();
+        let character = app
+            .world_mut()
+            .spawn((
+                appearance(0, 0),
+                CharacterData {
+                    name: "tester".into(),
+                    job_id: 0,
+                    level: 1,
+                    experience: 0,
+                    stats: CharacterStats {
+                        str: 1,
+                        agi: 1,
+                        vit: 1,
+                        int: 1,
+                        dex: 1,
+                        luk: 1,
+                        max_hp: 40,
+                        current_hp: 40,
+                        max_sp: 11,
+                        current_sp: 11,
+                    },
+                    slot: 0,
+                },
+            ))
+            .add_child(head_layer)
+            .id();
+        app.world_mut()
+            .resource_mut::<EntityRegistry>()
+            .register_entity(GID, character);
+
+        app.update();
+        assert!(app.world().get::<LayerDye>(head_layer).is_none());
+
+        let send = |app: &mut App, val| {
+            app.world_mut().write_message(UnitSpriteChanged {
+                gid: GID,
+                type_: LOOK_HAIR_COLOR,
+                val,
+                val2: 0,
+            });
+            app.update();
+        };
+
+        send(&mut app, 4);
+        assert_eq!(
+            app.world()
+                .get::<LayerDye>(head_layer)
+                .map(|dye| dye.path.as_str()),
+            Some("ro://data/palette/머리/2_여_4.pal")
+        );
+
+        send(&mut app, 0);
+        assert!(app.world().get::<LayerDye>(head_layer).is_none());
+    }
+}
@@ -1,15 +1,17 @@
-use super::super::components::{EffectType, PlayerAppearance, RenderLayer};
+use super::super::components::{EffectType, LayerShading, PlayerAppearance, RenderLayer};
 use crate::domain::assets::patterns;
 use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
 use crate::domain::entities::character::components::Gender;
 use crate::domain::entities::character::components::core::CharacterData;
 use crate::domain::entities::character::components::equipment::EquipmentSlot;
+use crate::domain::entities::sprite_rendering::systems::LayerMaterials;
 use crate::domain::sprite::tags::{Z_OFFSET_PER_LAYER, equipment_slot_to_tag};
 use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::animation_processing_system::PendingAnimations;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
 use crate::infrastructure::job::registry::JobSpriteRegistry;
 use crate::{AccessoryDb, WeaponDb};
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 
@@ -225,7 +227,7 @@ pub fn finalize_equipment_layers(
     mut players: Query<(Entity, &mut PlayerAppearance)>,
     alive: Query<Entity>,
     shared_quad: Res<SharedSpriteQuad>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: LayerMaterials,
 ) {
     // Claim only equipment layers; body/head/cart completions belong to the
     // other finalizers sharing this queue and must stay untouched.
@@ -264,27 +266,22 @@ pub fn finalize_equipment_layers(
             let z_offset =
                 crate::domain::sprite::tags::layer_order(layer_tag) as f32 * Z_OFFSET_PER_LAYER;
 
-            let first_texture = animation.textures.first().cloned().unwrap_or_default();
-
-            let material = materials.add(StandardMaterial {
-                base_color_texture: Some(first_texture),
-                alpha_mode: AlphaMode::Blend,
-                unlit: true,
-                cull_mode: None,
-                depth_bias: crate::domain::sprite::tags::layer_depth_bias(layer_tag),
-                ..default()
-            });
+            let shading =
+                LayerShading::new(crate::domain::sprite::tags::layer_depth_bias(layer_tag));
+            let material = materials.frame_material(animation_handle.id(), animation, 0, shading);
 
             let child = commands
                 .spawn((
                     Mesh3d(shared_quad.mesh.clone()),
                     MeshMaterial3d(material),
+                    MeshTag(0),
+                    shading,
                     Billboard,
                     RenderLayer::equipment(
                         animation_handle,
                         layer_tag,
                         slot,
-                        animation.textures.clone(),
+                        animation.atlas_pages.clone(),
                     ),
                     Transform::from_translation(Vec3::new(0.0, 0.0, z_offset)),
                     GlobalTransform::default(),
@@ -401,6 +398,7 @@ mod tests {
 
     mod finalize {
         use super::super::*;
+        use crate::domain::entities::sprite_rendering::systems::init_layer_materials;
         use crate::domain::sprite::tags::{LAYER_BODY, LAYER_WEAPON};
         use crate::infrastructure::assets::animation_processing_system::PendingAnimation;
         use bevy::asset::AssetPlugin;
@@ -410,12 +408,12 @@ mod tests {
             let mut app = App::new();
             app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
                 .init_asset::<RoAnimationAsset>()
-                .init_asset::<StandardMaterial>()
                 .init_resource::<PendingAnimations>()
                 .insert_resource(SharedSpriteQuad {
                     mesh: Handle::default(),
                 })
                 .add_systems(Update, finalize_equipment_layers);
+            init_layer_materials(&mut app);
             app
         }
 
@@ -4,10 +4,11 @@ use bevy_auto_plugin::prelude::*;
 use crate::domain::entities::billboard::EquipmentPreviewCamera;
 use crate::domain::entities::character::components::visual::ActionType;
 use crate::domain::entities::sprite_rendering::components::{
-    BodyAttachPoint, HeadAttachPoint, HeadAttachment, HeadLayer, PlayerSprite, RenderLayer,
+    BodyAttachPoint, HeadAttachPoint, HeadAttachment, HeadLayer, IdleVariation, PlayerSprite,
+    RenderLayer, SpriteCulled,
 };
 use crate::domain::entities::sprite_rendering::layout::{ActionLayout, PlayerLayout};
-use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::domain::entities::sprite_rendering::systems::{LayerMaterialHandles, LayerMaterials};
 use crate::domain::sprite::tags::{LAYER_BODY, Z_OFFSET_PER_LAYER, layer_order};
 use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
@@ -20,7 +21,7 @@ type HeadLayerQuery<'w, 's> = Query<
         &'static HeadAttachment,
         &'static RenderLayer,
         &'static ChildOf,
-        &'static MeshMaterial3d<StandardMaterial>,
+        LayerMaterialHandles,
         &'static mut Transform,
         &'static mut HeadAttachPoint,
     ),
@@ -46,6 +47,21 @@ pub(crate) fn head_screen_offset(
     head_layer_pos + (body_attach - head_attach)
 }
 
+/// Offset of a child layer (head, headgear, weapon) relative to the entity
+/// origin in RO screen space. Like the original client, the anchor correction
+/// only applies when both the parent's and the child's current frames carry an
+/// attach point; otherwise the child sits at its raw layer position.
+pub(crate) fn anchored_screen_offset(
+    layer_pos: Vec2,
+    parent_attach: Option<Vec2>,
+    child_attach: Option<Vec2>,
+) -> Vec2 {
+    match (parent_attach, child_attach) {
+        (Some(parent), Some(child)) => head_screen_offset(layer_pos, parent, child),
+        _ => layer_pos,
+    }
+}
+
 /// Head offset relative to the rendered body layer, in screen space
 /// (stored coordinates: X right, Y up).
 pub(crate) fn head_billboard_delta(screen_offset: Vec2, body_layer_pos: Vec2) -> Vec2 {
@@ -57,18 +73,18 @@ fn body_idle_attach(
     animations: &Assets<RoAnimationAsset>,
     body_render_layer: &RenderLayer,
     action_index: usize,
-) -> Option<(Vec2, Vec2)> {
+) -> Option<(Option<Vec2>, Vec2)> {
     let body_animation = animations.get(&body_render_layer.animation)?;
     let body_action = body_animation.actions.get(action_index)?;
     let frame = body_action.frames.first()?;
-    let attach_point = frame.attach_point?;
     let layer_pos = frame.parts.first()?.position;
-    Some((attach_point, layer_pos))
+    Some((frame.attach_point, layer_pos))
 }
 
 /// Synchronizes the head layer with the body: texture, scale, and position all
 /// come from the same frame so the head follows the body during walk/attack.
-/// Idle stays on frame 0 to avoid the doridori head cycle.
+/// Idle stays on frame 0 to avoid the doridori head cycle, except while the
+/// unit's `IdleVariation` holds one of the head-turn poses.
 ///
 /// Body and head are separate billboard quads, so the head offset must be
 /// applied in billboard space (rotated by the camera rotation). A parent-space
@@ -81,9 +97,9 @@ fn body_idle_attach(
 )]
 pub fn sync_player_head_layer(
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: LayerMaterials,
     camera_query: Query<&Transform, CameraFilter>,
-    parent_query: Query<&PlayerSprite>,
+    parent_query: Query<(&PlayerSprite, Option<&IdleVariation>), Without<SpriteCulled>>,
     body_query: Query<(&BodyAttachPoint, &RenderLayer, &Transform), Without<HeadLayer>>,
     mut head_query: HeadLayerQuery,
 ) {
@@ -100,7 +116,7 @@ pub fn sync_player_head_layer(
             continue;
         };
 
-        let Ok(ro_sprite) = parent_query.get(child_of.parent()) else {
+        let Ok((ro_sprite, idle_variation)) = parent_query.get(child_of.parent()) else {
             continue;
         };
 
@@ -121,12 +137,11 @@ pub fn sync_player_head_layer(
         }
 
         let head_frame_index = if ro_sprite.action_type == ActionType::Idle {
-            0
+            idle_variation.and_then(IdleVariation::pose).unwrap_or(0)
         } else {
-            body_attach
-                .frame_index
-                .min(head_action.frames.len().saturating_sub(1))
-        };
+            body_attach.frame_index
+        }
+        .min(head_action.frames.len().saturating_sub(1));
 
         let Some(head_frame) = head_action.frames.get(head_frame_index) else {
             continue;
@@ -136,9 +151,12 @@ pub fn sync_player_head_layer(
             continue;
         };
 
-        if let Some(texture) = head_animation.textures.get(part.texture_index) {
-            set_layer_texture(&mut materials, &material_handle.0, texture);
-        }
+        materials.show_frame(
+            material_handle,
+            head_layer.animation.id(),
+            head_animation,
+            part.texture_index,
+        );
 
         let mut scale_x = part.scale.x * part.texture_size.x * SPRITE_WORLD_SCALE;
         let scale_y = part.scale.y * part.texture_size.y * SPRITE_WORLD_SCALE;
@@ -149,18 +167,10 @@ pub fn sync_player_head_layer(
 
         let new_scale = Vec3::new(scale_x, scale_y, 1.0);
 
-        let Some(head_attach) = head_frame.attach_point else {
-            let current = *transform;
-            transform.set_if_neq(Transform {
-                scale: new_scale,
-                ..current
-            });
-            continue;
-        };
-
-        // During idle the head is pinned to frame 0, so the body attach data
-        // must come from frame 0 too: the published attach point cycles
-        // through the doridori poses and would make the pinned head twitch.
+        // During idle the head ignores the body's frame (it shows frame 0 or a
+        // held head-turn pose), so the body attach data must come from frame 0:
+        // the published attach point cycles through the doridori poses and
+        // would make the pinned head twitch.
         let (body_attach_point, body_layer_pos) = if ro_sprite.action_type == ActionType::Idle {
             body_idle_attach(&animations, body_render_layer, action_index)
                 .unwrap_or((body_attach.attach_point, body_attach.layer_pos))
@@ -168,7 +178,8 @@ pub fn sync_player_head_layer(
             (body_attach.attach_point, body_attach.layer_pos)
         };
 
-        let screen_offset = head_screen_offset(part.position, body_attach_point, head_attach);
+        let screen_offset =
+            anchored_screen_offset(part.position, body_attach_point, head_frame.attach_point);
         let delta = head_billboard_delta(screen_offset, body_layer_pos) * SPRITE_WORLD_SCALE;
         let world_delta = camera_transform.rotation * delta.extend(0.0);
 
@@ -183,7 +194,7 @@ pub fn sync_player_head_layer(
         });
 
         head_attach_point.set_if_neq(HeadAttachPoint {
-            attach_point: head_attach,
+            attach_point: head_frame.attach_point,
             frame_index: head_frame_index,
             layer_pos: part.position,
         });
@@ -229,4 +240,23 @@ mod tests {
         // (the 67-25 gap from the ACT data) and 1 pixel to the left.
         assert_eq!(delta, Vec2::new(-1.0, 42.0));
     }
+
+    #[test]
+    fn anchor_correction_needs_both_attach_points() {
+        let corrected = anchored_screen_offset(HEAD_LAYER_POS, Some(BODY_ATTACH), Some(Vec2::ZERO));
+        assert_eq!(corrected, HEAD_LAYER_POS + BODY_ATTACH);
+
+        // A frame without an anchor keeps its raw layer position instead of
+        // taking a stale correction from another frame.
+        for (parent, child) in [
+            (Some(BODY_ATTACH), None),
+            (None, Some(HEAD_ATTACH)),
+            (None, None),
+        ] {
+            assert_eq!(
+                anchored_screen_offset(HEAD_LAYER_POS, parent, child),
+                HEAD_LAYER_POS
+            );
+        }
+    }
 }
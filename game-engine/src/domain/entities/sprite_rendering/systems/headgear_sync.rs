@@ -6,13 +6,13 @@ use bevy_auto_plugin::prelude::*;
 use crate::domain::entities::billboard::EquipmentPreviewCamera;
 use crate::domain::entities::character::components::equipment::EquipmentSlot;
 use crate::domain::entities::sprite_rendering::components::{
-    HeadAttachPoint, HeadLayer, PlayerSprite, RenderLayer,
+    HeadAttachPoint, HeadLayer, PlayerSprite, RenderLayer, SpriteCulled,
 };
 use crate::domain::entities::sprite_rendering::layout::{ActionLayout, PlayerLayout};
 use crate::domain::entities::sprite_rendering::systems::head_sync::{
-    head_billboard_delta, head_screen_offset,
+    anchored_screen_offset, head_billboard_delta,
 };
-use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::domain::entities::sprite_rendering::systems::{LayerMaterialHandles, LayerMaterials};
 use crate::domain::sprite::tags::{LAYER_HEAD, Z_OFFSET_PER_LAYER, layer_order};
 use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
@@ -24,7 +24,7 @@ type HeadgearLayerQuery<'w, 's> = Query<
     (
         &'static RenderLayer,
         &'static ChildOf,
-        &'static MeshMaterial3d<StandardMaterial>,
+        LayerMaterialHandles,
         &'static mut Transform,
     ),
     Without<HeadLayer>,
@@ -40,7 +40,7 @@ type CameraFilter = (
 
 /// Per-frame snapshot the head publishes for headgear to align to.
 struct HeadAnchor {
-    attach_point: Vec2,
+    attach_point: Option<Vec2>,
     frame_index: usize,
     layer_pos: Vec2,
     translation: Vec3,
@@ -64,9 +64,9 @@ fn is_headgear_slot(slot: EquipmentSlot) -> bool {
 )]
 pub fn sync_headgear_layer(
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: LayerMaterials,
     camera_query: Query<&Transform, CameraFilter>,
-    parent_query: Query<&PlayerSprite>,
+    parent_query: Query<&PlayerSprite, Without<SpriteCulled>>,
     head_query: Query<(&HeadAttachPoint, &ChildOf, &Transform), With<HeadLayer>>,
     mut headgear_query: HeadgearLayerQuery,
 ) {
@@ -131,9 +131,12 @@ pub fn sync_headgear_layer(
             continue;
         };
 
-        if let Some(texture) = animation.textures.get(part.texture_index) {
-            set_layer_texture(&mut materials, &material_handle.0, texture);
-        }
+        materials.show_frame(
+            material_handle,
+            render_layer.animation.id(),
+            animation,
+            part.texture_index,
+        );
 
         let mut scale_x = part.scale.x * part.texture_size.x * SPRITE_WORLD_SCALE;
         let scale_y = part.scale.y * part.texture_size.y * SPRITE_WORLD_SCALE;
@@ -144,17 +147,8 @@ pub fn sync_headgear_layer(
 
         let new_scale = Vec3::new(scale_x, scale_y, 1.0);
 
-        let Some(headgear_attach) = frame.attach_point else {
-            let current = *transform;
-            transform.set_if_neq(Transform {
-                scale: new_scale,
-                ..current
-            });
-            continue;
-        };
-
         let screen_offset =
-            head_screen_offset(part.position, head_anchor.attach_point, headgear_attach);
+            anchored_screen_offset(part.position, head_anchor.attach_point, frame.attach_point);
         let delta = head_billboard_delta(screen_offset, head_anchor.layer_pos) * SPRITE_WORLD_SCALE;
         let world_delta = camera_transform.rotation * delta.extend(0.0);
 
@@ -179,6 +173,7 @@ pub fn sync_headgear_layer(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::sprite_rendering::systems::head_sync::head_screen_offset;
 
     // Stored coordinates (Y negated during extraction, so Y is up). The head's
     // published attach data plays the role the body plays for the head.
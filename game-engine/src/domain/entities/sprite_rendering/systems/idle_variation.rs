@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use crate::domain::effects::AnimationPaused;
+use crate::domain::entities::character::states::AnimationState;
+use crate::domain::entities::sprite_rendering::components::{IdleVariation, PlayerSprite};
+use crate::domain::entities::sprite_rendering::layout::{ActionLayout, PlayerLayout};
+use crate::domain::system_sets::SpriteRenderingSystems;
+
+type IdlePlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static AnimationState, &'static mut IdleVariation),
+    (With<PlayerSprite>, Without<AnimationPaused>),
+>;
+
+/// Run each character's idle pose schedule while it stands still. Any other
+/// state resets it, so the rest always restarts from the moment the unit
+/// stops; a frozen unit keeps whatever pose it was caught in.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::TransformUpdate, before = crate::domain::entities::sprite_rendering::systems::head_sync::sync_player_head_layer)
+)]
+pub fn advance_player_idle_variation(time: Res<Time>, mut query: IdlePlayerQuery) {
+    for (state, mut idle) in &mut query {
+        if state.allows_idle_variation() {
+            idle.tick(time.delta(), PlayerLayout::idle_pose_frames());
+        } else {
+            idle.reset();
+        }
+    }
+}
@@ -1,10 +1,16 @@
 pub mod action_sync;
 pub mod body_sync;
 pub mod cart;
+pub mod culling;
+pub mod dye;
 pub mod events;
 pub mod head_sync;
 pub mod headgear_sync;
+pub mod idle_variation;
 pub mod job_change;
+pub mod placeholder;
+pub mod shadow;
+pub mod shared_materials;
 pub mod spawn;
 pub mod update;
 pub mod weapon_motion;
@@ -16,36 +22,146 @@ pub use action_sync::{
 };
 pub use body_sync::{sync_mob_body_layer, sync_player_body_layer};
 pub use cart::{apply_cart_mount, finalize_cart_layer, sync_cart_layer};
+pub use culling::{apply_sprite_visibility_range, cull_sprites_outside_view};
+pub use dye::{apply_dye_look_changes, request_layer_dyes, swap_dyed_layer_materials};
 pub use events::{
     EquipmentChangeEvent, StatusEffectVisualEvent, handle_equipment_changes,
     handle_status_effect_visuals,
 };
 pub use head_sync::sync_player_head_layer;
 pub use headgear_sync::sync_headgear_layer;
+pub use idle_variation::advance_player_idle_variation;
 pub use job_change::apply_base_look_changes;
+pub use placeholder::{clear_sprite_placeholders, spawn_sprite_placeholders};
+pub use shadow::{fit_unit_shadows, spawn_unit_shadows};
+pub use shared_materials::{SharedLayerMaterials, refresh_shared_layer_materials};
 pub use spawn::spawn_sprite_hierarchy;
 pub use update::cleanup_orphaned_sprites;
 pub use weapon_motion::sync_weapon_combat_motion;
 pub use weapon_sync::sync_weapon_layer;
 
+use bevy::ecs::system::SystemParam;
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
+use bevy::render::storage::ShaderBuffer;
 
-/// Point the layer material at `texture`.
+use crate::domain::entities::sprite_rendering::components::LayerShading;
+use crate::infrastructure::assets::atlas_uv_transform;
+use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
+use crate::presentation::rendering::{PaletteSpriteMaterial, SpriteLayerMaterial};
+
+/// Point a sprite quad's `StandardMaterial` at the `uv` region of the atlas
+/// page `texture`. Sprite layers draw with shared materials instead (see
+/// `shared_materials`); effect and emote quads still own theirs.
 ///
 /// NOTE: the write is deliberately unconditional. Marking the material
 /// modified every frame is load-bearing: Bevy's retained transparent phase
 /// freezes an item's sort position (`mesh_center`) at queue time and only
-/// re-queues on respecialization (e.g. a material change). The unit's
-/// near-coplanar layer quads rely on that per-frame re-queue for a fresh,
-/// correctly ordered blend sort as units and the camera move; gating this
-/// write scrambles the body/head/headgear stacking. If it ever needs to be
-/// cheaper, re-queueing must be forced another way.
+/// re-queues on respecialization (e.g. a material change), and these quads
+/// rely on that per-frame re-queue for a fresh blend sort as they and the
+/// camera move. If it ever needs to be cheaper, re-queueing must be forced
+/// another way.
 pub(crate) fn set_layer_texture(
     materials: &mut Assets<StandardMaterial>,
     handle: &Handle<StandardMaterial>,
     texture: &Handle<Image>,
+    uv: Rect,
 ) {
     if let Some(mut material) = materials.get_mut(handle) {
         material.base_color_texture = Some(texture.clone());
+        material.uv_transform = atlas_uv_transform(uv);
+    }
+}
+
+/// A layer's material: the shared atlas `SpriteLayerMaterial`, or the
+/// palette-indexed material a dyed hair/body layer is switched to (see `dye`),
+/// plus the `MeshTag` that selects the frame and the shading the shared
+/// material is picked by.
+pub type LayerMaterialHandles = (
+    Option<&'static mut MeshMaterial3d<SpriteLayerMaterial>>,
+    Option<&'static MeshMaterial3d<PaletteSpriteMaterial>>,
+    &'static mut MeshTag,
+    &'static LayerShading,
+);
+
+/// What a [`LayerMaterialHandles`] query yields for one layer.
+pub type LayerMaterialHandlesItem<'a> = (
+    Option<Mut<'a, MeshMaterial3d<SpriteLayerMaterial>>>,
+    Option<&'a MeshMaterial3d<PaletteSpriteMaterial>>,
+    Mut<'a, MeshTag>,
+    &'a LayerShading,
+);
+
+#[derive(SystemParam)]
+pub struct LayerMaterials<'w> {
+    atlas: ResMut<'w, Assets<SpriteLayerMaterial>>,
+    palette: ResMut<'w, Assets<PaletteSpriteMaterial>>,
+    buffers: ResMut<'w, Assets<ShaderBuffer>>,
+    shared: ResMut<'w, SharedLayerMaterials>,
+}
+
+impl LayerMaterials<'_> {
+    /// The shared material for SPR frame `texture_index` of `animation`, or
+    /// the default handle if the frame has no atlas page.
+    pub fn frame_material(
+        &mut self,
+        animation: AssetId<RoAnimationAsset>,
+        asset: &RoAnimationAsset,
+        texture_index: usize,
+        shading: LayerShading,
+    ) -> Handle<SpriteLayerMaterial> {
+        asset
+            .frame_regions
+            .get(texture_index)
+            .and_then(|region| {
+                self.shared.material(
+                    &mut self.atlas,
+                    &mut self.buffers,
+                    animation,
+                    asset,
+                    region.page,
+                    shading,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Show SPR frame `texture_index` of `animation` on a layer, whichever
+    /// material it draws with: point its `MeshTag` at the frame and, for an
+    /// atlas layer, switch it to the shared material of the frame's page and
+    /// the layer's shading. A dyed layer has no index pixels for RGBA frames
+    /// and keeps its previous frame on those.
+    pub fn show_frame(
+        &mut self,
+        (atlas, palette, mut tag, shading): LayerMaterialHandlesItem,
+        animation: AssetId<RoAnimationAsset>,
+        asset: &RoAnimationAsset,
+        texture_index: usize,
+    ) {
+        if let Some(palette) = palette {
+            if let Some((indices, region)) = asset.index_texture(texture_index)
+                && let Some(mut material) = self.palette.get_mut(&palette.0)
+            {
+                material.set_frame(indices, region);
+            }
+        } else if let Some(mut atlas) = atlas
+            && asset.frame_regions.get(texture_index).is_some()
+        {
+            let material = self.frame_material(animation, asset, texture_index, *shading);
+            if atlas.0 != material {
+                atlas.0 = material;
+            }
+            tag.set_if_neq(MeshTag(texture_index as u32));
+        }
     }
 }
+
+/// Registers what [`LayerMaterials`] reads, for tests that run layer systems
+/// without the render plugins.
+#[cfg(test)]
+pub(crate) fn init_layer_materials(app: &mut App) {
+    app.init_asset::<SpriteLayerMaterial>()
+        .init_asset::<PaletteSpriteMaterial>()
+        .init_asset::<ShaderBuffer>()
+        .init_resource::<SharedLayerMaterials>();
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use super::super::components::{BodyAttachPoint, PendingRenderLayers, SpritePlaceholder};
+use super::spawn::{finalize_render_layers, link_head_to_body};
+use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
+use crate::domain::system_sets::SpriteRenderingSystems;
+use crate::utils::constants::SPRITE_WORLD_SCALE;
+
+/// Placeholder footprint in sprite pixels, roughly a standing novice.
+const PLACEHOLDER_SIZE: Vec2 = Vec2::new(24.0, 56.0);
+const PLACEHOLDER_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.35);
+
+fn has_body_layer(
+    entity: Entity,
+    children: &Query<&Children>,
+    body_layers: &Query<(), With<BodyAttachPoint>>,
+) -> bool {
+    children
+        .get(entity)
+        .is_ok_and(|children| children.iter().any(|child| body_layers.contains(child)))
+}
+
+/// Gives a unit that just started waiting on its layers a faint stand-in billboard, so
+/// units whose animations are still decoding don't pop in from nothing. Units rebuilding over an existing body (e.g. equipment) get none.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn, after = finalize_render_layers)
+)]
+pub fn spawn_sprite_placeholders(
+    mut commands: Commands,
+    waiting: Query<Entity, Added<PendingRenderLayers>>,
+    children: Query<&Children>,
+    body_layers: Query<(), With<BodyAttachPoint>>,
+    shared_quad: Res<SharedSpriteQuad>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    for entity in &waiting {
+        if has_body_layer(entity, &children, &body_layers) {
+            continue;
+        }
+        let material = material
+            .get_or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: PLACEHOLDER_COLOR,
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    cull_mode: None,
+                    ..default()
+                })
+            })
+            .clone();
+        let size = PLACEHOLDER_SIZE * SPRITE_WORLD_SCALE;
+        commands.spawn((
+            SpritePlaceholder,
+            Mesh3d(shared_quad.mesh.clone()),
+            MeshMaterial3d(material),
+            Billboard,
+            // World up is -Y for unit sprites (see `sync_mob_body_layer`): lift the
+            // quad by half its height so it stands on the unit's feet.
+            Transform::from_xyz(0.0, -size.y / 2.0, 0.0).with_scale(size.extend(1.0)),
+            Visibility::default(),
+            ChildOf(entity),
+        ));
+    }
+}
+
+/// Removes a placeholder once its unit has a real body layer, or has stopped waiting
+/// without ever getting one (e.g. an unknown job sprite).
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn, after = link_head_to_body)
+)]
+pub fn clear_sprite_placeholders(
+    mut commands: Commands,
+    placeholders: Query<(Entity, &ChildOf), With<SpritePlaceholder>>,
+    waiting: Query<(), With<PendingRenderLayers>>,
+    children: Query<&Children>,
+    body_layers: Query<(), With<BodyAttachPoint>>,
+) {
+    for (placeholder, child_of) in &placeholders {
+        let unit = child_of.parent();
+        if waiting.contains(unit) && !has_body_layer(unit, &children, &body_layers) {
+            continue;
+        }
+        commands.entity(placeholder).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_systems(Update, clear_sprite_placeholders);
+        app
+    }
+
+    #[test]
+    fn placeholder_survives_while_the_unit_is_still_waiting() {
+        let mut app = app();
+        let unit = app.world_mut().spawn(PendingRenderLayers).id();
+        let placeholder = app
+            .world_mut()
+            .spawn((SpritePlaceholder, ChildOf(unit)))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get_entity(placeholder).is_ok());
+    }
+
+    #[test]
+    fn placeholder_is_removed_once_the_body_layer_exists() {
+        let mut app = app();
+        let unit = app.world_mut().spawn(PendingRenderLayers).id();
+        let placeholder = app
+            .world_mut()
+            .spawn((SpritePlaceholder, ChildOf(unit)))
+            .id();
+        app.world_mut()
+            .spawn((BodyAttachPoint::default(), ChildOf(unit)));
+
+        app.update();
+
+        assert!(app.world().get_entity(placeholder).is_err());
+    }
+
+    #[test]
+    fn placeholder_is_removed_when_the_unit_stops_waiting() {
+        let mut app = app();
+        let unit = app.world_mut().spawn_empty().id();
+        let placeholder = app
+            .world_mut()
+            .spawn((SpritePlaceholder, ChildOf(unit)))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get_entity(placeholder).is_err());
+    }
+}
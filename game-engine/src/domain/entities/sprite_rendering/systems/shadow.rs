@@ -0,0 +1,189 @@
+//! Shadow blobs: the soft dark disc the classic client draws under every unit.
+//!
+//! Each character, monster and NPC gets a flat [`ShadowRenderLayer`] child on
+//! the ground under its feet. The unit root already follows the terrain
+//! height, so the blob stays anchored to the ground as the unit walks. Its
+//! size starts at one cell and is refit from the unit's measured
+//! [`SpriteBounds`], so a Poring gets a small blob and a boss a wide one.
+//! Floor items get none. The texture is a generated soft circle, so there is
+//! no dependency on `data/sprite/shadow.spr`.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_auto_plugin::prelude::*;
+
+use super::super::components::{MobSprite, PlayerSprite, ShadowRenderLayer, SpriteBounds};
+use super::culling::measure_sprite_bounds;
+use crate::domain::entities::billboard::SharedSpriteQuad;
+use crate::domain::item_drop::components::FloorItem;
+use crate::domain::system_sets::SpriteRenderingSystems;
+
+const SHADOW_TEXTURE_SIZE: u32 = 64;
+const SHADOW_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.45);
+/// Height above the ground (world up is `-Y`), enough to stay clear of the
+/// terrain without floating.
+const SHADOW_LIFT: f32 = 0.15;
+/// Diameter before the body has been measured: one cell.
+const DEFAULT_SHADOW_DIAMETER: f32 = 5.0;
+/// Blob diameter per unit of measured sprite radius, and its limits.
+const SHADOW_DIAMETER_PER_RADIUS: f32 = 0.5;
+const MIN_SHADOW_DIAMETER: f32 = 3.0;
+const MAX_SHADOW_DIAMETER: f32 = 16.0;
+
+type NewUnitSpriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    Entity,
+    (
+        Or<(Added<PlayerSprite>, Added<MobSprite>)>,
+        Without<FloorItem>,
+    ),
+>;
+
+/// Blob diameter for a unit whose body sprite fits a sphere of `radius`.
+fn shadow_diameter(radius: f32) -> f32 {
+    (radius * SHADOW_DIAMETER_PER_RADIUS).clamp(MIN_SHADOW_DIAMETER, MAX_SHADOW_DIAMETER)
+}
+
+/// White disc whose alpha fades smoothly from the center to a soft edge; the
+/// material tints it to the shadow color.
+fn soft_shadow_image() -> Image {
+    let mut data = Vec::with_capacity((SHADOW_TEXTURE_SIZE * SHADOW_TEXTURE_SIZE * 4) as usize);
+    for y in 0..SHADOW_TEXTURE_SIZE {
+        for x in 0..SHADOW_TEXTURE_SIZE {
+            let dx = (x as f32 + 0.5) / SHADOW_TEXTURE_SIZE as f32 * 2.0 - 1.0;
+            let dy = (y as f32 + 0.5) / SHADOW_TEXTURE_SIZE as f32 * 2.0 - 1.0;
+            let dist = (dx * dx + dy * dy).sqrt();
+            // Solid core, then a smoothstep fade over the outer half.
+            let t = ((1.0 - dist) * 2.0).clamp(0.0, 1.0);
+            let alpha = (t * t * (3.0 - 2.0 * t) * 255.0) as u8;
+            data.extend_from_slice(&[255, 255, 255, alpha]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: SHADOW_TEXTURE_SIZE,
+            height: SHADOW_TEXTURE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Lays a shadow blob under each unit whose sprite was just set up.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::HierarchySpawn)
+)]
+pub fn spawn_unit_shadows(
+    mut commands: Commands,
+    units: NewUnitSpriteQuery,
+    shared_quad: Res<SharedSpriteQuad>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    for unit in &units {
+        let material = material
+            .get_or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: SHADOW_COLOR,
+                    base_color_texture: Some(images.add(soft_shadow_image())),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    cull_mode: None,
+                    ..default()
+                })
+            })
+            .clone();
+        commands.spawn((
+            ShadowRenderLayer,
+            Mesh3d(shared_quad.mesh.clone()),
+            MeshMaterial3d(material),
+            // The sprite quad stands in the XY plane; lay it flat on the ground.
+            Transform::from_xyz(0.0, -SHADOW_LIFT, 0.0)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::splat(DEFAULT_SHADOW_DIAMETER)),
+            Visibility::default(),
+            ChildOf(unit),
+        ));
+    }
+}
+
+/// Sizes each blob to its unit's measured body sprite.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::AnimationSync, after = measure_sprite_bounds)
+)]
+pub fn fit_unit_shadows(
+    mut shadows: Query<(&ChildOf, &mut Transform), With<ShadowRenderLayer>>,
+    bounds: Query<&SpriteBounds>,
+) {
+    for (child_of, mut transform) in &mut shadows {
+        let Ok(bounds) = bounds.get(child_of.parent()) else {
+            continue;
+        };
+        let scale = Vec3::splat(shadow_diameter(bounds.radius));
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diameter_follows_the_sprite_within_limits() {
+        assert_eq!(shadow_diameter(10.0), 5.0);
+        assert_eq!(shadow_diameter(0.5), MIN_SHADOW_DIAMETER);
+        assert_eq!(shadow_diameter(200.0), MAX_SHADOW_DIAMETER);
+    }
+
+    #[test]
+    fn shadow_is_opaque_in_the_middle_and_clear_at_the_edge() {
+        let image = soft_shadow_image();
+        let data = image.data.unwrap();
+        let alpha = |x: u32, y: u32| data[((y * SHADOW_TEXTURE_SIZE + x) * 4 + 3) as usize];
+        let mid = SHADOW_TEXTURE_SIZE / 2;
+        assert_eq!(alpha(mid, mid), 255);
+        assert_eq!(alpha(0, 0), 0);
+        assert_eq!(alpha(0, mid), 0);
+    }
+
+    #[test]
+    fn blob_is_refit_to_the_measured_body() {
+        let mut app = App::new();
+        app.add_systems(Update, fit_unit_shadows);
+        let unit = app
+            .world_mut()
+            .spawn(SpriteBounds {
+                animation: AssetId::default(),
+                center: Vec3::ZERO,
+                radius: 20.0,
+            })
+            .id();
+        let shadow = app
+            .world_mut()
+            .spawn((ShadowRenderLayer, Transform::default(), ChildOf(unit)))
+            .id();
+        let unmeasured = app.world_mut().spawn_empty().id();
+        let waiting = app
+            .world_mut()
+            .spawn((ShadowRenderLayer, Transform::default(), ChildOf(unmeasured)))
+            .id();
+
+        app.update();
+
+        let scale = |entity| app.world().get::<Transform>(entity).unwrap().scale;
+        assert_eq!(scale(shadow), Vec3::splat(10.0));
+        assert_eq!(scale(waiting), Vec3::ONE);
+    }
+}
@@ -0,0 +1,200 @@
+//! Shared sprite-layer materials.
+//!
+//! Every sprite layer used to own a `StandardMaterial` whose texture and UV
+//! transform were rewritten each frame, so a crowded map prepared a new bind
+//! group per layer per frame and drew every quad on its own. Layers now draw
+//! with a [`SpriteLayerMaterial`] shared by everything showing a frame from the
+//! same atlas page with the same [`LayerShading`], and pick the frame through
+//! their `MeshTag`. Bevy batches the layers on one material and the shared
+//! quad into instanced draws.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use bevy::render::storage::ShaderBuffer;
+use bevy_auto_plugin::prelude::*;
+
+use crate::domain::entities::sprite_rendering::components::LayerShading;
+use crate::domain::system_sets::SpriteRenderingSystems;
+use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
+use crate::presentation::rendering::{SpriteLayerMaterial, frame_regions_buffer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct SharedMaterialKey {
+    animation: AssetId<RoAnimationAsset>,
+    page: usize,
+    depth_bias: u32,
+    tint: [u8; 4],
+}
+
+impl SharedMaterialKey {
+    fn new(animation: AssetId<RoAnimationAsset>, page: usize, shading: LayerShading) -> Self {
+        Self {
+            animation,
+            page,
+            depth_bias: shading.depth_bias.to_bits(),
+            tint: shading.tint.to_srgba().to_u8_array(),
+        }
+    }
+}
+
+/// The shared layer materials in use, and each animation's frame-region buffer.
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin)]
+pub struct SharedLayerMaterials {
+    regions: HashMap<AssetId<RoAnimationAsset>, Handle<ShaderBuffer>>,
+    materials: HashMap<SharedMaterialKey, Handle<SpriteLayerMaterial>>,
+}
+
+impl SharedLayerMaterials {
+    /// The material for frames of `animation` on atlas page `page`, created on
+    /// first use. `None` if the animation has no such page.
+    pub fn material(
+        &mut self,
+        materials: &mut Assets<SpriteLayerMaterial>,
+        buffers: &mut Assets<ShaderBuffer>,
+        animation: AssetId<RoAnimationAsset>,
+        asset: &RoAnimationAsset,
+        page: usize,
+        shading: LayerShading,
+    ) -> Option<Handle<SpriteLayerMaterial>> {
+        let key = SharedMaterialKey::new(animation, page, shading);
+        if let Some(material) = self.materials.get(&key) {
+            return Some(material.clone());
+        }
+
+        let texture = asset.atlas_pages.get(page)?.clone();
+        let regions = self
+            .regions
+            .entry(animation)
+            .or_insert_with(|| buffers.add(frame_regions_buffer(&asset.frame_regions)))
+            .clone();
+        let material = materials.add(SpriteLayerMaterial {
+            tint: shading.tint.to_linear(),
+            page: texture,
+            regions,
+            depth_bias: shading.depth_bias,
+        });
+        self.materials.insert(key, material.clone());
+        Some(material)
+    }
+
+    /// Drop the materials no layer draws with anymore, and the region buffers
+    /// of animations left without a material.
+    fn forget_unused(&mut self) {
+        self.materials.retain(|_, material| match material {
+            Handle::Strong(handle) => std::sync::Arc::strong_count(handle) > 1,
+            Handle::Uuid(..) => false,
+        });
+        let animations: HashSet<_> = self.materials.keys().map(|key| key.animation).collect();
+        self.regions
+            .retain(|animation, _| animations.contains(animation));
+    }
+}
+
+/// Evicts unused shared materials, then marks every remaining one modified.
+///
+/// NOTE: the write is deliberately unconditional. Bevy's retained transparent
+/// phase freezes an item's sort position (`mesh_center`) at queue time and
+/// only re-queues on respecialization (e.g. a material change). A unit's
+/// near-coplanar layer quads rely on that per-frame re-queue for a fresh,
+/// correctly ordered blend sort as units and the camera move; skipping it
+/// scrambles the body/head/headgear stacking. With shared materials this costs
+/// one write per material in use rather than one per layer.
+#[auto_add_system(
+    plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
+    schedule = Update,
+    config(in_set = SpriteRenderingSystems::AnimationPlayback)
+)]
+pub fn refresh_shared_layer_materials(
+    mut shared: ResMut<SharedLayerMaterials>,
+    mut materials: ResMut<Assets<SpriteLayerMaterial>>,
+) {
+    shared.forget_unused();
+    for material in shared.materials.values() {
+        if let Some(material) = materials.get_mut(material) {
+            material.into_inner();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::assets::AtlasRegion;
+
+    fn animation(pages: usize) -> RoAnimationAsset {
+        RoAnimationAsset {
+            atlas_pages: vec![Handle::default(); pages],
+            frame_regions: vec![AtlasRegion::default(); 3],
+            ..default()
+        }
+    }
+
+    #[test]
+    fn layers_share_a_material_per_page_and_shading() {
+        let mut shared = SharedLayerMaterials::default();
+        let mut materials = Assets::<SpriteLayerMaterial>::default();
+        let mut buffers = Assets::<ShaderBuffer>::default();
+        let asset = animation(2);
+        let id = AssetId::<RoAnimationAsset>::default();
+        let plain = LayerShading::new(0.1);
+        let mut material = |page, shading| {
+            shared
+                .material(&mut materials, &mut buffers, id, &asset, page, shading)
+                .unwrap()
+        };
+
+        let first = material(0, plain);
+        assert_eq!(material(0, plain), first);
+        assert_ne!(material(1, plain), first);
+        let tinted = LayerShading {
+            tint: Color::srgb(0.5, 0.5, 1.0),
+            ..plain
+        };
+        assert_ne!(material(0, tinted), first);
+        assert_ne!(material(0, LayerShading::new(0.2)), first);
+        assert_eq!(buffers.len(), 1, "one region buffer per animation");
+        assert!(
+            shared
+                .material(&mut materials, &mut buffers, id, &asset, 2, plain)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn materials_no_layer_holds_are_forgotten() {
+        let mut shared = SharedLayerMaterials::default();
+        let mut materials = Assets::<SpriteLayerMaterial>::default();
+        let mut buffers = Assets::<ShaderBuffer>::default();
+        let asset = animation(1);
+        let id = AssetId::<RoAnimationAsset>::default();
+
+        let held = shared
+            .material(
+                &mut materials,
+                &mut buffers,
+                id,
+                &asset,
+                0,
+                LayerShading::new(0.0),
+            )
+            .unwrap();
+        shared.material(
+            &mut materials,
+            &mut buffers,
+            id,
+            &asset,
+            0,
+            LayerShading::new(0.5),
+        );
+        shared.forget_unused();
+        assert_eq!(shared.materials.len(), 1);
+        assert_eq!(shared.regions.len(), 1);
+
+        drop(held);
+        shared.forget_unused();
+        assert!(shared.materials.is_empty());
+        assert!(shared.regions.is_empty());
+    }
+}
@@ -1,10 +1,12 @@
 use super::super::components::{
-    BodyAttachPoint, EntitySpriteData, HeadAttachPoint, HeadAttachment, HeadLayer, MobSprite,
-    PendingRenderLayers, PlayerAppearance, PlayerSprite, RenderLayer, SpriteHierarchyConfig,
+    BodyAttachPoint, EntitySpriteData, HeadAttachPoint, HeadAttachment, HeadLayer, IdleVariation,
+    IdleVariationConfig, LayerShading, MobSprite, PendingRenderLayers, PlayerAppearance,
+    PlayerSprite, RenderLayer, SpriteHierarchyConfig,
 };
 use super::super::events::{RequestSpriteSpawn, SpawnSpriteEvent};
 use crate::domain::assets::patterns;
 use crate::domain::entities::billboard::{Billboard, SharedSpriteQuad};
+use crate::domain::entities::sprite_rendering::systems::LayerMaterials;
 use crate::domain::sprite::tags::{
     LAYER_BODY, LAYER_HEAD, LAYER_SHADOW, SPRITE_BASE_Y_OFFSET, Z_OFFSET_PER_LAYER,
     layer_depth_bias, layer_order,
@@ -13,6 +15,8 @@ use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::animation_processing_system::PendingAnimations;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
 use crate::infrastructure::job::registry::JobSpriteRegistry;
+use crate::presentation::rendering::SpriteLayerMaterial;
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 use moonshine_tag::Tag;
@@ -29,6 +33,7 @@ pub fn spawn_sprite_hierarchy(
     mut commands: Commands,
     mut spawn_events: MessageReader<SpawnSpriteEvent>,
     _config: Res<SpriteHierarchyConfig>,
+    idle_config: Res<IdleVariationConfig>,
     asset_server: Res<AssetServer>,
     mut pending_animations: ResMut<PendingAnimations>,
     job_registry: Option<Res<JobSpriteRegistry>>,
@@ -85,6 +90,10 @@ pub fn spawn_sprite_hierarchy(
             }
         }
 
+        if let Some(profile) = idle_config.profile_for(&event.sprite_info.sprite_data) {
+            entity_commands.insert(IdleVariation::new(profile, entity.to_bits() as u32));
+        }
+
         debug!(
             "spawn_sprite_hierarchy: Processing SpawnSpriteEvent for entity {:?}",
             entity
@@ -253,7 +262,7 @@ pub fn finalize_render_layers(
     alive: Query<Entity>,
     config: Res<SpriteHierarchyConfig>,
     shared_quad: Res<SharedSpriteQuad>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: LayerMaterials,
 ) {
     // Claim only the layers this finalizer owns; cart and equipment completions
     // stay queued for their own finalizers instead of being stolen or dropped.
@@ -324,24 +333,14 @@ pub fn finalize_render_layers(
 
         let z_offset = layer_z_offset(pending.layer_tag, &config);
 
-        let first_texture = animation.textures.first().cloned();
-        if first_texture.is_none() {
+        if animation.atlas_pages.is_empty() {
             warn!(
-                "finalize_render_layers: No textures available for entity {:?}, layer {:?}. Animation has {} textures.",
-                entity,
-                pending.layer_tag,
-                animation.textures.len()
+                "finalize_render_layers: No textures available for entity {:?}, layer {:?}",
+                entity, pending.layer_tag
             );
         }
-        let first_texture = first_texture.unwrap_or_default();
-
-        debug!(
-            "finalize_render_layers: Using texture handle {:?} for entity {:?}, animation has {} textures, first in animation: {:?}",
-            first_texture,
-            entity,
-            animation.textures.len(),
-            animation.textures.first()
-        );
+        let shading = LayerShading::new(layer_depth_bias(pending.layer_tag));
+        let material = materials.frame_material(animation_handle.id(), animation, 0, shading);
 
         let _layer_entity = spawn_render_layer_child(
             &mut commands,
@@ -349,10 +348,10 @@ pub fn finalize_render_layers(
             animation_handle,
             pending.layer_tag,
             z_offset,
-            first_texture,
-            animation.textures.clone(),
+            material,
+            shading,
+            animation.atlas_pages.clone(),
             &shared_quad,
-            &mut materials,
         );
 
         debug!(
@@ -390,10 +389,10 @@ fn spawn_render_layer_child(
     animation: Handle<RoAnimationAsset>,
     layer: Tag,
     z_offset: f32,
-    initial_texture: Handle<Image>,
+    material: Handle<SpriteLayerMaterial>,
+    shading: LayerShading,
     textures: Vec<Handle<Image>>,
     shared_quad: &SharedSpriteQuad,
-    materials: &mut Assets<StandardMaterial>,
 ) -> Entity {
     let render_layer = RenderLayer::body(animation, layer, textures);
     let is_head = layer == LAYER_HEAD;
@@ -408,18 +407,11 @@ fn spawn_render_layer_child(
 
     let sprite_transform = Transform::from_translation(local_offset);
 
-    let material = materials.add(StandardMaterial {
-        base_color_texture: Some(initial_texture),
-        alpha_mode: AlphaMode::Blend,
-        unlit: true,
-        cull_mode: None,
-        depth_bias: layer_depth_bias(layer),
-        ..default()
-    });
-
     let mut entity_commands = commands.spawn((
         Mesh3d(shared_quad.mesh.clone()),
         MeshMaterial3d(material),
+        MeshTag(0),
+        shading,
         Billboard,
         sprite_transform,
         GlobalTransform::default(),
@@ -3,16 +3,17 @@ use std::collections::HashMap;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
 
+use crate::domain::audio::events::PlayMobSfx;
 use crate::domain::entities::billboard::EquipmentPreviewCamera;
 use crate::domain::entities::character::components::equipment::EquipmentSlot;
 use crate::domain::entities::sprite_rendering::components::{
-    BodyAttachPoint, HeadLayer, PlayerSprite, RenderLayer,
+    BodyAttachPoint, HeadLayer, PlayerSprite, RenderLayer, SpriteCulled,
 };
 use crate::domain::entities::sprite_rendering::layout::{ActionLayout, PlayerLayout};
 use crate::domain::entities::sprite_rendering::systems::head_sync::{
-    head_billboard_delta, head_screen_offset,
+    anchored_screen_offset, head_billboard_delta,
 };
-use crate::domain::entities::sprite_rendering::systems::set_layer_texture;
+use crate::domain::entities::sprite_rendering::systems::{LayerMaterialHandles, LayerMaterials};
 use crate::domain::sprite::tags::{LAYER_BODY, Z_OFFSET_PER_LAYER, layer_order};
 use crate::domain::system_sets::SpriteRenderingSystems;
 use crate::infrastructure::assets::ro_animation_asset::RoAnimationAsset;
@@ -22,9 +23,10 @@ type WeaponLayerQuery<'w, 's> = Query<
     'w,
     's,
     (
+        Entity,
         &'static RenderLayer,
         &'static ChildOf,
-        &'static MeshMaterial3d<StandardMaterial>,
+        LayerMaterialHandles,
         &'static mut Transform,
         &'static mut Visibility,
     ),
@@ -44,7 +46,7 @@ type CameraFilter = (
 
 /// Per-frame snapshot the body publishes for weapon/shield layers to align to.
 struct BodyAnchor {
-    attach_point: Vec2,
+    attach_point: Option<Vec2>,
     frame_index: usize,
     layer_pos: Vec2,
     translation: Vec3,
@@ -59,22 +61,30 @@ fn is_weapon_slot(slot: EquipmentSlot) -> bool {
 /// The body publishes its resolved attach point + frame via `BodyAttachPoint`; the
 /// weapon/shield reuse the same camera-rotated billboard-space delta math against
 /// it (not the head anchor headgear uses).
+///
+/// Attack swing sounds live in the weapon's ACT rather than the body's, so the
+/// weapon layer plays its frame's sound whenever it moves onto a new frame.
 #[auto_add_system(
     plugin = crate::app::sprite_rendering_domain_plugin::SpriteRenderingDomainPlugin,
     schedule = Update,
     config(in_set = SpriteRenderingSystems::TransformUpdate, after = crate::domain::entities::sprite_rendering::systems::body_sync::sync_player_body_layer)
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn sync_weapon_layer(
     animations: Res<Assets<RoAnimationAsset>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: LayerMaterials,
     camera_query: Query<&Transform, CameraFilter>,
-    parent_query: Query<&PlayerSprite>,
+    parent_query: Query<&PlayerSprite, Without<SpriteCulled>>,
     body_query: Query<(&BodyAttachPoint, &ChildOf, &Transform), Without<HeadLayer>>,
     mut weapon_query: WeaponLayerQuery,
+    mut sfx_writer: MessageWriter<PlayMobSfx>,
+    mut shown_frames: Local<HashMap<Entity, (usize, usize)>>,
 ) {
     let Ok(camera_transform) = camera_query.single() else {
         return;
     };
+    // Rebuilt every run, so layers that despawn or hide drop out of it.
+    let previous_frames = std::mem::take(&mut *shown_frames);
 
     let body_anchors: HashMap<Entity, BodyAnchor> = body_query
         .iter()
@@ -91,7 +101,7 @@ pub fn sync_weapon_layer(
         })
         .collect();
 
-    for (render_layer, child_of, material_handle, mut transform, mut visibility) in
+    for (entity, render_layer, child_of, material_handle, mut transform, mut visibility) in
         weapon_query.iter_mut()
     {
         let Some(slot) = render_layer.equipment_slot else {
@@ -120,11 +130,11 @@ pub fn sync_weapon_layer(
         // The weapon ACT only carries frames for the stance/attack actions; for
         // every other action (idle, walk, ...) the frame has no parts. Hide the
         // layer then, so the last drawn sprite doesn't linger floating in place.
-        let Some(frame) = animation.actions.get(action_index).and_then(|action| {
+        let Some((frame_index, frame)) = animation.actions.get(action_index).and_then(|action| {
             let frame_index = body_anchor
                 .frame_index
                 .min(action.frames.len().saturating_sub(1));
-            action.frames.get(frame_index)
+            Some((frame_index, action.frames.get(frame_index)?))
         }) else {
             visibility.set_if_neq(Visibility::Hidden);
             continue;
@@ -137,10 +147,24 @@ pub fn sync_weapon_layer(
 
         visibility.set_if_neq(Visibility::Inherited);
 
-        if let Some(texture) = animation.textures.get(part.texture_index) {
-            set_layer_texture(&mut materials, &material_handle.0, texture);
+        let shown = (action_index, frame_index);
+        shown_frames.insert(entity, shown);
+        if previous_frames.get(&entity) != Some(&shown)
+            && let Some(name) = animation.frame_sound(frame)
+        {
+            sfx_writer.write(PlayMobSfx {
+                emitter: child_of.parent(),
+                sound: name.to_string(),
+            });
         }
 
+        materials.show_frame(
+            material_handle,
+            render_layer.animation.id(),
+            animation,
+            part.texture_index,
+        );
+
         let mut scale_x = part.scale.x * part.texture_size.x * SPRITE_WORLD_SCALE;
         let scale_y = part.scale.y * part.texture_size.y * SPRITE_WORLD_SCALE;
 
@@ -152,12 +176,8 @@ pub fn sync_weapon_layer(
         // reference client then places them by their raw layer position relative
         // to the body (no attach correction). When an anchor is present, align it
         // to the body's attach point like the head does.
-        let screen_offset = match frame.attach_point {
-            Some(weapon_attach) => {
-                head_screen_offset(part.position, body_anchor.attach_point, weapon_attach)
-            }
-            None => part.position,
-        };
+        let screen_offset =
+            anchored_screen_offset(part.position, body_anchor.attach_point, frame.attach_point);
         let delta = head_billboard_delta(screen_offset, body_anchor.layer_pos) * SPRITE_WORLD_SCALE;
         let world_delta = camera_transform.rotation * delta.extend(0.0);
 
@@ -176,6 +196,8 @@ pub fn sync_weapon_layer(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::sprite_rendering::systems::head_sync::head_screen_offset;
+    use crate::domain::entities::sprite_rendering::systems::init_layer_materials;
 
     // Stored coordinates (Y negated during extraction, so Y is up). The body's
     // published attach data plays the same role for the weapon as it does for the
@@ -193,7 +215,8 @@ mod tests {
         let mut app = App::new();
         app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
         app.init_asset::<RoAnimationAsset>();
-        app.init_asset::<StandardMaterial>();
+        init_layer_materials(&mut app);
+        app.add_message::<PlayMobSfx>();
         app.add_systems(Update, sync_weapon_layer);
         app.update();
     }
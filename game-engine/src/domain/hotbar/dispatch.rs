@@ -3,6 +3,7 @@ use bevy_auto_plugin::prelude::{auto_add_message, auto_add_system};
 use leafwing_input_manager::prelude::ActionState;
 
 use crate::core::state::GameState;
+use crate::domain::combat::death::local_player_alive;
 use crate::domain::entities::markers::LocalPlayer;
 use crate::domain::hotbar::model::{Hotbar, HotbarSlot};
 use crate::domain::input::{HOTBAR_ACTIONS, PlayerAction, ui_unfocused};
@@ -19,7 +20,9 @@ pub struct HotbarSlotActivated {
 #[auto_add_system(
     plugin = crate::app::input_plugin::InputPlugin,
     schedule = Update,
-    config(run_if = in_state(GameState::InGame).and_then(ui_unfocused))
+    config(run_if = in_state(GameState::InGame)
+        .and_then(ui_unfocused)
+        .and_then(local_player_alive))
 )]
 pub fn activate_from_keys(
     player: Query<&ActionState<PlayerAction>, With<LocalPlayer>>,
@@ -26,6 +26,8 @@ pub enum PlayerAction {
     Guild,
     /// Toggle the emote picker window.
     Emote,
+    /// Save a screenshot of the game window.
+    Screenshot,
     /// Activate hotbar slot 1 (default F1).
     Slot1,
     /// Activate hotbar slot 2 (default F2).
@@ -93,7 +95,7 @@ impl PlayerAction {
     /// `Status` is the classic RO Alt+A chord. `Inventory` is the classic RO Alt+E chord.
     /// `Skills` is the classic RO Alt+S chord. `Equipment` is the classic RO Alt+Q chord.
     /// `Cart` uses Alt+W. `Party` uses the unmodified P key. `Guild` uses Alt+G.
-    /// `Emote` uses Alt+M.
+    /// `Emote` uses Alt+M. `Screenshot` is the classic RO Print Screen.
     pub fn default_input_map() -> InputMap<Self> {
         let mut map = InputMap::new([(Self::Sit, KeyCode::Insert), (Self::Sit, KeyCode::Help)])
             .with(
@@ -124,7 +126,8 @@ impl PlayerAction {
             .with(
                 Self::Emote,
                 ButtonlikeChord::modified(ModifierKey::Alt, KeyCode::KeyM),
-            );
+            )
+            .with(Self::Screenshot, KeyCode::PrintScreen);
         for (action, key) in HOTBAR_ACTIONS.into_iter().zip(HOTBAR_KEYS) {
             map.insert(action, key);
         }
@@ -0,0 +1,172 @@
+//! Ground click indicator: the pulsing ring and bobbing arrow the classic
+//! client draws on the cell the player clicked to walk to.
+//!
+//! It appears on the local player's [`MovementRequested`], moves to the
+//! server's destination on [`MovementConfirmed`] (the server may end the walk
+//! short of the clicked cell), and goes away on a [`MovementStopped`]: arrival
+//! at the marked cell, a server stop, a rolled-back prediction or a warp.
+//! Drawn with gizmos like the terrain cursor, so it needs no GRF sprite.
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource, auto_observer};
+
+use crate::core::state::GameState;
+use crate::domain::entities::markers::LocalPlayer;
+use crate::domain::entities::movement::events::{
+    MovementConfirmed, MovementRequested, MovementStopped, StopReason,
+};
+use crate::domain::system_sets::InputSystems;
+use crate::utils::coordinates::spawn_coords_to_world_position;
+
+use super::terrain_raycast::TerrainRaycastCache;
+
+/// One pulse of the ring, in seconds.
+const PULSE_SECS: f32 = 0.6;
+const RING_MAX_RADIUS: f32 = 3.5;
+const RING_MIN_RADIUS: f32 = 1.0;
+/// Arrow tip height above the ground (world up is `-Y`), and how far it bobs.
+const ARROW_TIP_LIFT: f32 = 2.0;
+const ARROW_LENGTH: f32 = 4.0;
+const ARROW_BOB: f32 = 1.0;
+const INDICATOR_COLOR: Srgba = Srgba::rgb(1.0, 0.82, 0.29);
+
+/// Where the local player is walking to, while the indicator is up.
+#[derive(Resource, Debug, Default)]
+#[auto_init_resource(plugin = crate::app::input_plugin::InputPlugin)]
+pub struct ClickIndicator {
+    target: Option<ClickTarget>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ClickTarget {
+    cell: (u16, u16),
+    /// Ground height at the click; cells carry no height of their own.
+    height: f32,
+}
+
+impl ClickIndicator {
+    /// The marked cell, if the indicator is showing.
+    pub fn cell(&self) -> Option<(u16, u16)> {
+        self.target.map(|target| target.cell)
+    }
+
+    fn place(&mut self, cell: (u16, u16), height: f32) {
+        self.target = Some(ClickTarget { cell, height });
+    }
+
+    fn retarget(&mut self, cell: (u16, u16)) {
+        if let Some(target) = &mut self.target {
+            target.cell = cell;
+        }
+    }
+
+    /// Drops the indicator unless this is an earlier walk arriving somewhere
+    /// other than the marked cell.
+    fn stop(&mut self, cell: (u16, u16), reason: StopReason) {
+        if reason != StopReason::ReachedDestination || self.cell() == Some(cell) {
+            self.target = None;
+        }
+    }
+}
+
+#[auto_observer(plugin = crate::app::input_plugin::InputPlugin)]
+pub fn show_click_indicator(
+    trigger: On<MovementRequested>,
+    players: Query<&GlobalTransform, With<LocalPlayer>>,
+    cache: Res<TerrainRaycastCache>,
+    mut indicator: ResMut<ClickIndicator>,
+) {
+    let event = trigger.event();
+    let Ok(player) = players.get(event.entity) else {
+        return;
+    };
+    let height = cache
+        .world_position
+        .map_or(player.translation().y, |position| position.y);
+    indicator.place((event.dest_x, event.dest_y), height);
+}
+
+#[auto_observer(plugin = crate::app::input_plugin::InputPlugin)]
+pub fn confirm_click_indicator(
+    trigger: On<MovementConfirmed>,
+    players: Query<(), With<LocalPlayer>>,
+    mut indicator: ResMut<ClickIndicator>,
+) {
+    let event = trigger.event();
+    if players.contains(event.entity) {
+        indicator.retarget((event.dest_x, event.dest_y));
+    }
+}
+
+#[auto_observer(plugin = crate::app::input_plugin::InputPlugin)]
+pub fn hide_click_indicator(
+    trigger: On<MovementStopped>,
+    players: Query<(), With<LocalPlayer>>,
+    mut indicator: ResMut<ClickIndicator>,
+) {
+    let event = trigger.event();
+    if players.contains(event.entity) {
+        indicator.stop((event.x, event.y), event.reason);
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::input_plugin::InputPlugin,
+    schedule = Update,
+    config(
+        in_set = InputSystems::Cursor,
+        run_if = in_state(GameState::InGame)
+    )
+)]
+pub fn render_click_indicator(mut gizmos: Gizmos, time: Res<Time>, indicator: Res<ClickIndicator>) {
+    let Some(target) = indicator.target else {
+        return;
+    };
+    let (x, y) = target.cell;
+    let ground = spawn_coords_to_world_position(x, y, 0, 0).with_y(target.height - 0.2);
+
+    let phase = (time.elapsed_secs() / PULSE_SECS).fract();
+    let radius = RING_MAX_RADIUS - (RING_MAX_RADIUS - RING_MIN_RADIUS) * phase;
+    let color = INDICATOR_COLOR.with_alpha(1.0 - 0.6 * phase);
+    gizmos.circle(
+        Isometry3d::new(ground, Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        radius,
+        color,
+    );
+
+    let bob = (phase * std::f32::consts::TAU).sin() * ARROW_BOB;
+    let tip = ground + Vec3::NEG_Y * (ARROW_TIP_LIFT + bob);
+    gizmos.arrow(tip + Vec3::NEG_Y * ARROW_LENGTH, tip, INDICATOR_COLOR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confirmation_moves_the_marker_to_the_server_destination() {
+        let mut indicator = ClickIndicator::default();
+        indicator.retarget((5, 5));
+        assert_eq!(indicator.cell(), None);
+
+        indicator.place((10, 12), 0.0);
+        indicator.retarget((10, 11));
+        assert_eq!(indicator.cell(), Some((10, 11)));
+    }
+
+    #[test]
+    fn arrival_elsewhere_keeps_the_marker_but_other_stops_drop_it() {
+        let mut indicator = ClickIndicator::default();
+        indicator.place((10, 12), 0.0);
+
+        indicator.stop((3, 4), StopReason::ReachedDestination);
+        assert_eq!(indicator.cell(), Some((10, 12)));
+
+        indicator.stop((10, 12), StopReason::ReachedDestination);
+        assert_eq!(indicator.cell(), None);
+
+        indicator.place((10, 12), 0.0);
+        indicator.stop((7, 7), StopReason::Blocked);
+        assert_eq!(indicator.cell(), None);
+    }
+}
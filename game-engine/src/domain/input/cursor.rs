@@ -11,6 +11,18 @@ pub enum CursorType {
     Attack,
     Impossible,
     Talk,
+    Warp,
+    Rotate,
+}
+
+/// What the pointer is over, as far as the cursor cares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorTarget {
+    Ground { walkable: bool },
+    Attackable,
+    Npc,
+    FloorItem,
+    Warp,
 }
 
 impl CursorType {
@@ -22,15 +34,35 @@ impl CursorType {
             CursorType::Attack => "attack",
             CursorType::Impossible => "impossible",
             CursorType::Talk => "talk",
+            CursorType::Warp => "warp",
+            CursorType::Rotate => "rotate",
+        }
+    }
+
+    /// The cursor the reference client shows over `target`.
+    pub fn for_target(target: CursorTarget) -> Self {
+        match target {
+            CursorTarget::Ground { walkable: true } => CursorType::Default,
+            CursorTarget::Ground { walkable: false } => CursorType::Impossible,
+            CursorTarget::Attackable => CursorType::Attack,
+            CursorTarget::Npc => CursorType::Talk,
+            CursorTarget::FloorItem => CursorType::Add,
+            CursorTarget::Warp => CursorType::Warp,
         }
     }
 }
 
-/// Resource tracking the current cursor type
+/// Resource tracking the current cursor type.
+///
+/// Hover and terrain systems request the cursor for whatever is under the
+/// pointer; dragging the camera (right button held) overrides it with
+/// [`CursorType::Rotate`] until the button is released, after which the last
+/// requested cursor shows again.
 #[derive(Resource, Debug)]
 #[auto_init_resource(plugin = crate::app::input_plugin::InputPlugin)]
 pub struct CurrentCursorType {
     cursor_type: CursorType,
+    rotating: bool,
 }
 
 impl CurrentCursorType {
@@ -38,12 +70,17 @@ impl CurrentCursorType {
     pub fn new() -> Self {
         Self {
             cursor_type: CursorType::Default,
+            rotating: false,
         }
     }
 
     /// Get the current cursor type
     pub fn get(&self) -> CursorType {
-        self.cursor_type
+        if self.rotating {
+            CursorType::Rotate
+        } else {
+            self.cursor_type
+        }
     }
 
     /// Set the cursor type, returns true if changed
@@ -55,6 +92,13 @@ impl CurrentCursorType {
             false
         }
     }
+
+    /// Enter or leave the camera-drag state, returns true if the shown cursor changed
+    pub fn set_rotating(&mut self, rotating: bool) -> bool {
+        let before = self.get();
+        self.rotating = rotating;
+        before != self.get()
+    }
 }
 
 impl Default for CurrentCursorType {
@@ -72,12 +116,17 @@ impl Default for CurrentCursorType {
 pub fn handle_cursor_change_requests(
     mut current_cursor: ResMut<CurrentCursorType>,
     mut messages: MessageReader<CursorChangeRequest>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
 ) {
     if let Some(last_message) = messages.read().last()
         && current_cursor.set(last_message.cursor_type)
     {
         trace!("Cursor changed to: {:?}", last_message.cursor_type);
     }
+    let rotating = buttons.is_some_and(|buttons| buttons.pressed(MouseButton::Right));
+    if current_cursor.set_rotating(rotating) {
+        trace!("Cursor changed to: {:?}", current_cursor.get());
+    }
 }
 
 #[cfg(test)]
@@ -92,6 +141,55 @@ mod tests {
         assert_eq!(CursorType::Attack.as_str(), "attack");
         assert_eq!(CursorType::Impossible.as_str(), "impossible");
         assert_eq!(CursorType::Talk.as_str(), "talk");
+        assert_eq!(CursorType::Warp.as_str(), "warp");
+        assert_eq!(CursorType::Rotate.as_str(), "rotate");
+    }
+
+    #[test]
+    fn hover_targets_pick_the_reference_cursors() {
+        let cases = [
+            (CursorTarget::Ground { walkable: true }, CursorType::Default),
+            (
+                CursorTarget::Ground { walkable: false },
+                CursorType::Impossible,
+            ),
+            (CursorTarget::Attackable, CursorType::Attack),
+            (CursorTarget::Npc, CursorType::Talk),
+            (CursorTarget::FloorItem, CursorType::Add),
+            (CursorTarget::Warp, CursorType::Warp),
+        ];
+        for (target, cursor) in cases {
+            assert_eq!(CursorType::for_target(target), cursor, "{target:?}");
+        }
+    }
+
+    #[test]
+    fn dragging_the_camera_shows_rotate_until_released() {
+        let mut app = App::new();
+        app.init_resource::<CurrentCursorType>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        app.add_message::<CursorChangeRequest>();
+        app.add_systems(Update, handle_cursor_change_requests);
+
+        app.world_mut()
+            .write_message(CursorChangeRequest::new(CursorType::Talk));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+        assert_eq!(
+            app.world().resource::<CurrentCursorType>().get(),
+            CursorType::Rotate
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Right);
+        app.update();
+        assert_eq!(
+            app.world().resource::<CurrentCursorType>().get(),
+            CursorType::Talk
+        );
     }
 
     #[test]
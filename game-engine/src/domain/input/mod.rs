@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod click_indicator;
 pub mod cursor;
 pub mod events;
 pub mod resources;
@@ -8,7 +9,8 @@ pub mod terrain_raycast;
 pub mod ui_focus;
 
 pub use actions::{HOTBAR_ACTIONS, PlayerAction};
-pub use cursor::{CurrentCursorType, CursorType};
+pub use click_indicator::ClickIndicator;
+pub use cursor::{CurrentCursorType, CursorTarget, CursorType};
 pub use events::CursorChangeRequest;
 pub use resources::{ForwardedCursorPosition, ForwardedMouseClick, LockedTarget};
 pub use targeting::TargetingMode;
@@ -1,9 +1,10 @@
 use crate::{
     core::state::GameState,
     domain::{
+        combat::death::local_player_alive,
         entities::{
             hover::CurrentlyHoveredEntity,
-            markers::LocalPlayer,
+            markers::{LocalPlayer, WarpPortal},
             movement::events::MovementRequested,
             pathfinding::{CurrentMapPathfindingGrid, WalkablePath, find_path},
         },
@@ -23,8 +24,11 @@ use crate::domain::entities::character::events::{SkillLearnRequested, StatIncrea
 use crate::domain::entities::character::states::AnimationState;
 
 use super::{
-    ForwardedMouseClick, LockedTarget, PlayerAction, cursor::CursorType,
-    events::CursorChangeRequest, targeting::TargetingMode, terrain_raycast::TerrainRaycastCache,
+    ForwardedMouseClick, LockedTarget, PlayerAction,
+    cursor::{CursorTarget, CursorType},
+    events::CursorChangeRequest,
+    targeting::TargetingMode,
+    terrain_raycast::TerrainRaycastCache,
     ui_focus::ui_unfocused,
 };
 
@@ -103,7 +107,7 @@ pub fn render_terrain_cursor(mut gizmos: Gizmos, cache: Res<TerrainRaycastCache>
     schedule = Update,
     config(
         in_set = InputSystems::Click,
-        run_if = in_state(GameState::InGame)
+        run_if = in_state(GameState::InGame).and_then(local_player_alive)
     )
 )]
 pub fn handle_terrain_click(
@@ -217,19 +221,33 @@ pub fn handle_terrain_click(
 pub fn update_cursor_for_terrain(
     cache: Res<TerrainRaycastCache>,
     currently_hovered: Res<CurrentlyHoveredEntity>,
+    warps: Query<&Transform, With<WarpPortal>>,
     mut cursor_messages: MessageWriter<CursorChangeRequest>,
 ) {
     if currently_hovered.entity.is_some() {
         return;
     }
 
-    let cursor_type = if cache.is_walkable {
-        CursorType::Default
+    let over_warp = cache
+        .cell_coords
+        .is_some_and(|cell| warps.iter().any(|warp| warp_covers_cell(warp, cell)));
+    let target = if over_warp {
+        CursorTarget::Warp
     } else {
-        CursorType::Impossible
+        CursorTarget::Ground {
+            walkable: cache.is_walkable,
+        }
     };
 
-    cursor_messages.write(CursorChangeRequest::new(cursor_type));
+    cursor_messages.write(CursorChangeRequest::new(CursorType::for_target(target)));
+}
+
+/// Warp portals have no pickable sprite, so the warp cursor follows the cells
+/// around the portal: the one it stands on and its eight neighbours, which is
+/// where stepping triggers the warp on a default (1×1 span) portal.
+fn warp_covers_cell(warp: &Transform, (x, y): (u16, u16)) -> bool {
+    let (warp_x, warp_y) = world_position_to_spawn_coords(warp.translation, 0, 0);
+    warp_x.abs_diff(x) <= 1 && warp_y.abs_diff(y) <= 1
 }
 
 #[auto_add_system(
@@ -237,7 +255,9 @@ pub fn update_cursor_for_terrain(
     schedule = Update,
     config(
         in_set = InputSystems::Click,
-        run_if = in_state(GameState::InGame).and_then(ui_unfocused)
+        run_if = in_state(GameState::InGame)
+            .and_then(ui_unfocused)
+            .and_then(local_player_alive)
     )
 )]
 pub fn handle_sit_toggle(
@@ -1,8 +1,16 @@
 use crate::{
-    domain::{system_sets::InputSystems, world::components::MapLoader},
-    infrastructure::assets::loaders::{RoAltitudeAsset, RoGroundAsset},
-    utils::coordinates::world_position_to_spawn_coords,
+    domain::{
+        camera::{collision::world_aabb, components::CameraOccluder},
+        system_sets::InputSystems,
+        world::components::MapLoader,
+    },
+    infrastructure::{assets::loaders::RoAltitudeAsset, ro_formats::RoAltitude},
+    presentation::rendering::models::MapModel,
+    utils::coordinates::{spawn_coords_to_world_position, world_position_to_spawn_coords},
 };
+use bevy::camera::primitives::Aabb;
+use bevy::ecs::system::SystemParam;
+use bevy::math::bounding::RayCast3d;
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource};
 
@@ -22,6 +30,9 @@ pub struct TerrainRaycastCache {
     pub cell_coords: Option<(u16, u16)>,
     pub world_position: Option<Vec3>,
     pub is_walkable: bool,
+    /// Map model the cursor is over, if one stands in front of the terrain.
+    /// Its click falls through to `cell_coords`, the nearest walkable cell.
+    pub prop: Option<Entity>,
     /// Cursor + camera pose of the last completed march. While both are
     /// unchanged the cached result is still valid and the (expensive) ray
     /// march is skipped. Only set once the map assets resolved, so frames
@@ -34,10 +45,99 @@ impl TerrainRaycastCache {
         self.cell_coords = None;
         self.world_position = None;
         self.is_walkable = false;
+        self.prop = None;
         self.last_input = None;
     }
 }
 
+/// How far, in cells, a click on a prop searches for walkable ground.
+const PROP_FALLTHROUGH_RADIUS: u16 = 6;
+
+/// Collision volumes of the map's models: the bounding boxes of their visible
+/// meshes, the same ones that keep the camera out of them.
+#[derive(SystemParam)]
+pub struct PropColliders<'w, 's> {
+    meshes: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Aabb,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+        ),
+        With<CameraOccluder>,
+    >,
+    parents: Query<'w, 's, &'static ChildOf>,
+    models: Query<'w, 's, (), With<MapModel>>,
+}
+
+impl PropColliders<'_, '_> {
+    /// The model whose box the ray enters first within `max_distance`, and
+    /// how far along the ray that is.
+    fn first_hit(&self, origin: Vec3, direction: Dir3, max_distance: f32) -> Option<(f32, Entity)> {
+        let ray = RayCast3d::new(origin, direction, max_distance);
+        let (distance, mesh) = self
+            .meshes
+            .iter()
+            .filter(|(_, _, _, visibility)| visibility.get())
+            .filter_map(|(entity, aabb, transform, _)| {
+                ray.aabb_intersection_at(&world_aabb(aabb, transform))
+                    .filter(|&t| t > 0.0)
+                    .map(|t| (t, entity))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))?;
+        // Meshes hang off RSM node entities; report the placed model itself.
+        let model = self
+            .parents
+            .iter_ancestors(mesh)
+            .find(|&entity| self.models.contains(entity))
+            .unwrap_or(mesh);
+        Some((distance, model))
+    }
+}
+
+/// The walkable cell closest to `center`, searching outwards ring by ring up
+/// to `radius` cells away.
+fn nearest_walkable(altitude: &RoAltitude, center: (u16, u16), radius: u16) -> Option<(u16, u16)> {
+    let (cx, cy) = (center.0 as i32, center.1 as i32);
+    (0..=radius as i32).find_map(|ring| {
+        let mut best: Option<((u16, u16), i32)> = None;
+        for dy in -ring..=ring {
+            for dx in -ring..=ring {
+                if dx.abs().max(dy.abs()) != ring {
+                    continue;
+                }
+                let (x, y) = (cx + dx, cy + dy);
+                if x < 0 || y < 0 || !altitude.is_walkable(x as usize, y as usize) {
+                    continue;
+                }
+                let distance = dx * dx + dy * dy;
+                if best.is_none_or(|(_, d)| distance < d) {
+                    best = Some(((x as u16, y as u16), distance));
+                }
+            }
+        }
+        best.map(|(cell, _)| cell)
+    })
+}
+
+/// The GAT cell a point on the terrain surface belongs to, or `None` past the
+/// altitude grid's edge. Cells are resolved in GAT space (twice the GND's
+/// resolution), so the result is always a cell the server knows about.
+///
+/// The march resolves one grid cell off from the cursor's true cell (-1 X, +1 Y).
+/// Correcting here, at the single source, keeps the gizmo, walkability and
+/// click-to-move in agreement.
+pub fn gat_cell_at(altitude: &RoAltitude, world_pos: Vec3) -> Option<(u16, u16)> {
+    let (raw_x, raw_y) = world_position_to_spawn_coords(world_pos, 0, 0);
+    let cell_x = raw_x.checked_sub(1)?;
+    let cell_y = raw_y + 1;
+    altitude
+        .get_cell(cell_x as usize, cell_y as usize)
+        .map(|_| (cell_x, cell_y))
+}
+
 #[auto_add_system(
     plugin = crate::app::input_plugin::InputPlugin,
     schedule = Update,
@@ -48,8 +148,8 @@ pub fn update_terrain_raycast_cache(
     cursor_pos: Res<ForwardedCursorPosition>,
     camera_query: Query<(&Camera, &GlobalTransform), GameCameraFilter>,
     map_loader_query: Query<&MapLoader>,
-    ground_assets: Res<Assets<RoGroundAsset>>,
     altitude_assets: Res<Assets<RoAltitudeAsset>>,
+    props: PropColliders,
 ) {
     let Some(cursor_position) = cursor_pos.position else {
         cache.clear();
@@ -70,11 +170,6 @@ pub fn update_terrain_raycast_cache(
         return;
     };
 
-    let Some(ground_asset) = ground_assets.get(&map_loader.ground) else {
-        cache.clear();
-        return;
-    };
-
     let Some(altitude_handle) = map_loader.altitude.as_ref() else {
         cache.clear();
         return;
@@ -131,6 +226,7 @@ pub fn update_terrain_raycast_cache(
         cache.cell_coords = None;
         cache.world_position = None;
         cache.is_walkable = false;
+        cache.prop = None;
         cache.last_input = Some((cursor_position, *camera_transform));
         return;
     };
@@ -143,28 +239,94 @@ pub fn update_terrain_raycast_cache(
             None => break,
         }
     }
-    let world_pos = (lo + hi) * 0.5;
-
-    let (raw_x, raw_y) = world_position_to_spawn_coords(
-        world_pos,
-        ground_asset.ground.width,
-        ground_asset.ground.height,
-    );
-
-    // The raycast resolves one grid cell off from the cursor's true cell (-1 X, +1 Y).
-    // Correct at this single source so the gizmo, walkability, and click-to-move agree.
-    // No upper clamp: cells are in GAT space, while ground.{width,height} are GND-resolution
-    // (half), so clamping against them froze the gizmo mid-map. is_walkable bounds-checks
-    // internally and the ray-march already returns None past the true map edge.
-    let cell_x = raw_x.saturating_sub(1);
-    let cell_y = raw_y + 1;
+    let mut world_pos = (lo + hi) * 0.5;
 
-    let is_walkable = altitude_asset
-        .altitude
-        .is_walkable(cell_x as usize, cell_y as usize);
+    // A tree or wall in front of the terrain takes the click: it lands on the
+    // walkable ground nearest the point the ray met the model, not on
+    // whatever terrain lies behind it.
+    let altitude = &altitude_asset.altitude;
+    let prop = props.first_hit(ray.origin, ray.direction, ray.origin.distance(world_pos));
+    let mut cell = None;
+    if let Some((distance, _)) = prop {
+        let hit = ray.origin + ray.direction * distance;
+        cell = gat_cell_at(altitude, hit)
+            .and_then(|cell| nearest_walkable(altitude, cell, PROP_FALLTHROUGH_RADIUS));
+        if let Some((x, y)) = cell {
+            let ground = spawn_coords_to_world_position(x, y, 0, 0);
+            let height = altitude.get_terrain_height_at_position(ground);
+            world_pos = ground.with_y(height.unwrap_or(world_pos.y));
+        }
+    }
+
+    let Some((cell_x, cell_y)) = cell.or_else(|| gat_cell_at(altitude, world_pos)) else {
+        cache.cell_coords = None;
+        cache.world_position = None;
+        cache.is_walkable = false;
+        cache.prop = None;
+        cache.last_input = Some((cursor_position, *camera_transform));
+        return;
+    };
+
+    let is_walkable = altitude.is_walkable(cell_x as usize, cell_y as usize);
 
     cache.cell_coords = Some((cell_x, cell_y));
     cache.world_position = Some(world_pos);
     cache.is_walkable = is_walkable;
+    cache.prop = prop.map(|(_, model)| model);
     cache.last_input = Some((cursor_position, *camera_transform));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ro_formats::{GatCell, GatCellType};
+
+    /// A `size`×`size` flat GAT; cells for which `blocked` holds are not walkable.
+    fn altitude_with(size: u32, blocked: impl Fn(u32, u32) -> bool) -> RoAltitude {
+        RoAltitude {
+            version: "1.2".to_string(),
+            width: size,
+            height: size,
+            cells: (0..size * size)
+                .map(|i| GatCell {
+                    height: [0.0; 4],
+                    cell_type: GatCellType::from(u32::from(blocked(i % size, i / size))),
+                })
+                .collect(),
+        }
+    }
+
+    fn altitude(size: u32) -> RoAltitude {
+        altitude_with(size, |_, _| false)
+    }
+
+    #[test]
+    fn cells_resolve_inside_the_gat_grid_only() {
+        let gat = altitude(8);
+        assert_eq!(
+            gat_cell_at(&gat, spawn_coords_to_world_position(3, 3, 0, 0)),
+            Some((2, 4))
+        );
+        // Off the west edge and past the north edge of the 8x8 grid.
+        assert_eq!(
+            gat_cell_at(&gat, spawn_coords_to_world_position(0, 3, 0, 0)),
+            None
+        );
+        assert_eq!(
+            gat_cell_at(&gat, spawn_coords_to_world_position(3, 7, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn prop_clicks_fall_through_to_the_nearest_walkable_cell() {
+        // A 3x3 building footprint centred on (5, 5).
+        let gat = altitude_with(12, |x, y| (4..=6).contains(&x) && (4..=6).contains(&y));
+
+        assert_eq!(nearest_walkable(&gat, (2, 2), 3), Some((2, 2)));
+        assert_eq!(nearest_walkable(&gat, (6, 5), 3), Some((7, 5)));
+        let from_centre = nearest_walkable(&gat, (5, 5), 3).unwrap();
+        assert_eq!(from_centre.0.abs_diff(5).max(from_centre.1.abs_diff(5)), 2);
+        assert_eq!(nearest_walkable(&gat, (5, 5), 1), None);
+    }
+}
@@ -5,13 +5,17 @@ use bevy_auto_plugin::prelude::auto_init_resource;
 ///
 /// While `text_input_active` is true, gameplay input systems are suppressed so
 /// keystrokes and clicks routed to a focused text field do not also drive the game.
+/// `cutscene_active` suppresses them the same way while a camera sequence that
+/// locks input is playing.
 #[derive(Resource, Default)]
 #[auto_init_resource(plugin = crate::app::input_plugin::InputPlugin)]
 pub struct UiFocus {
     pub text_input_active: bool,
+    pub cutscene_active: bool,
 }
 
-/// Run condition that is true when no UI text input holds focus.
+/// Run condition that is true when no UI text input holds focus and no cutscene
+/// has locked gameplay input.
 pub fn ui_unfocused(focus: Res<UiFocus>) -> bool {
-    !focus.text_input_active
+    !focus.text_input_active && !focus.cutscene_active
 }
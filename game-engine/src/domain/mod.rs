@@ -15,6 +15,8 @@ pub mod input;
 pub mod inventory;
 pub mod item_drop;
 pub mod party;
+pub mod requests;
+pub mod screenshot;
 pub mod settings;
 pub mod skill;
 pub mod skill_units;
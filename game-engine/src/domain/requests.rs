@@ -0,0 +1,262 @@
+//! Deadlines for UI requests that wait on a server reply.
+//!
+//! Character creation and rename each answer with a success or a failure
+//! message, and the screens only update when one arrives. If the reply is lost
+//! (a dropped stream, a server that never answers) the screen would sit there
+//! forever. Every such request is tracked here under a correlation id; a reply
+//! settles the oldest matching request, and one still open past its kind's
+//! timeout fails through the flow's usual failure message.
+//!
+//! The other requests are left to their own flows:
+//! - login fails through the adapter's `connect_timeout` (`LoginConnectionFailed`)
+//! - server and character selection answer with a state transition, not a
+//!   reply message; their connection failures belong to the char and zone
+//!   flows (`ZoneDisconnected` returns to login)
+//! - deletion has no failure message a screen waits on; the roster refreshes
+//!   when the server answers
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::{AutoPlugin, auto_add_system, auto_init_resource};
+use net_contract::events::CharacterRenamed;
+
+use crate::domain::character::events::{
+    CharacterCreatedEvent, CharacterCreationFailedEvent, CharacterRenameFailedEvent,
+    CreateCharacterRequestEvent, RenameCharacterRequestEvent,
+};
+
+/// Shown when a request's reply never arrives.
+const NO_REPLY: &str = "The server did not respond";
+
+/// A request that waits on a reply, with what its failure message needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestKind {
+    CreateCharacter,
+    RenameCharacter { character_id: u32 },
+}
+
+impl RequestKind {
+    /// How long the reply may take.
+    pub fn timeout(&self) -> Duration {
+        match self {
+            Self::CreateCharacter | Self::RenameCharacter { .. } => Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingRequest {
+    pub id: u64,
+    pub kind: RequestKind,
+    /// `Time::elapsed` past which the request fails.
+    pub deadline: Duration,
+}
+
+/// Requests still waiting on their reply, oldest first.
+#[derive(Resource, Default, Debug)]
+#[auto_init_resource(plugin = RequestDeadlinePlugin)]
+pub struct PendingRequests {
+    next_id: u64,
+    pending: Vec<PendingRequest>,
+}
+
+impl PendingRequests {
+    /// Tracks a new request sent at `now` and returns its correlation id.
+    pub fn begin(&mut self, kind: RequestKind, now: Duration) -> u64 {
+        self.next_id += 1;
+        let deadline = now + kind.timeout();
+        self.pending.push(PendingRequest {
+            id: self.next_id,
+            kind,
+            deadline,
+        });
+        self.next_id
+    }
+
+    /// Settles the oldest request a reply matches. `None` if nothing was
+    /// waiting on it (e.g. it already timed out).
+    pub fn settle(&mut self, matches: impl Fn(&RequestKind) -> bool) -> Option<PendingRequest> {
+        let index = self
+            .pending
+            .iter()
+            .position(|request| matches(&request.kind))?;
+        Some(self.pending.remove(index))
+    }
+
+    /// Removes and returns every request whose deadline passed by `now`.
+    pub fn expire(&mut self, now: Duration) -> Vec<PendingRequest> {
+        let (expired, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|request| request.deadline <= now);
+        self.pending = pending;
+        expired
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+pub struct RequestDeadlinePlugin;
+
+/// The messages that open and settle a tracked request.
+#[derive(bevy::ecs::system::SystemParam)]
+struct RequestTraffic<'w, 's> {
+    creations: MessageReader<'w, 's, CreateCharacterRequestEvent>,
+    renames: MessageReader<'w, 's, RenameCharacterRequestEvent>,
+    created: MessageReader<'w, 's, CharacterCreatedEvent>,
+    creation_failed: MessageReader<'w, 's, CharacterCreationFailedEvent>,
+    renamed: MessageReader<'w, 's, CharacterRenamed>,
+    rename_failed: MessageReader<'w, 's, CharacterRenameFailedEvent>,
+}
+
+#[auto_add_system(plugin = RequestDeadlinePlugin, schedule = Update)]
+fn track_requests(
+    time: Res<Time>,
+    mut requests: ResMut<PendingRequests>,
+    mut traffic: RequestTraffic,
+) {
+    let now = time.elapsed();
+    for _ in traffic.creations.read() {
+        requests.begin(RequestKind::CreateCharacter, now);
+    }
+    for rename in traffic.renames.read() {
+        let character_id = rename.character_id;
+        requests.begin(RequestKind::RenameCharacter { character_id }, now);
+    }
+
+    let creation = |kind: &RequestKind| *kind == RequestKind::CreateCharacter;
+    let replies = traffic.created.read().count() + traffic.creation_failed.read().count();
+    for _ in 0..replies {
+        requests.settle(creation);
+    }
+    let renamed = traffic.renamed.read().map(|event| event.char_id);
+    let rename_failed = traffic.rename_failed.read().map(|event| event.character_id);
+    for id in renamed.chain(rename_failed).collect::<Vec<_>>() {
+        requests.settle(|kind| *kind == RequestKind::RenameCharacter { character_id: id });
+    }
+}
+
+#[auto_add_system(
+    plugin = RequestDeadlinePlugin,
+    schedule = Update,
+    config(after = track_requests)
+)]
+fn expire_requests(
+    time: Res<Time>,
+    mut requests: ResMut<PendingRequests>,
+    mut creation_failures: MessageWriter<CharacterCreationFailedEvent>,
+    mut rename_failures: MessageWriter<CharacterRenameFailedEvent>,
+) {
+    for request in requests.expire(time.elapsed()) {
+        warn!(
+            "Request {} ({:?}) got no reply in time",
+            request.id, request.kind
+        );
+        match request.kind {
+            RequestKind::CreateCharacter => {
+                creation_failures.write(CharacterCreationFailedEvent {
+                    error: NO_REPLY.to_string(),
+                });
+            }
+            RequestKind::RenameCharacter { character_id } => {
+                rename_failures.write(CharacterRenameFailedEvent {
+                    character_id,
+                    error: NO_REPLY.to_string(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::character::CharacterCreationForm;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn replies_settle_the_oldest_matching_request() {
+        let mut requests = PendingRequests::default();
+        let first = requests.begin(RequestKind::CreateCharacter, Duration::ZERO);
+        let rename = requests.begin(RequestKind::RenameCharacter { character_id: 7 }, SECOND);
+        let second = requests.begin(RequestKind::CreateCharacter, SECOND);
+        assert_ne!(first, second);
+
+        let creation = |kind: &RequestKind| *kind == RequestKind::CreateCharacter;
+        assert_eq!(requests.settle(creation).map(|r| r.id), Some(first));
+        assert_eq!(requests.settle(creation).map(|r| r.id), Some(second));
+        assert_eq!(requests.settle(creation), None);
+        assert_eq!(
+            requests.expire(Duration::from_secs(60)).pop().map(|r| r.id),
+            Some(rename)
+        );
+        assert!(requests.is_empty());
+    }
+
+    #[test]
+    fn only_overdue_requests_expire() {
+        let mut requests = PendingRequests::default();
+        let kind = RequestKind::RenameCharacter { character_id: 7 };
+        requests.begin(kind.clone(), Duration::ZERO);
+        assert!(requests.expire(kind.timeout() - SECOND).is_empty());
+        assert_eq!(requests.expire(kind.timeout()).len(), 1);
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<CreateCharacterRequestEvent>()
+            .add_message::<RenameCharacterRequestEvent>()
+            .add_message::<CharacterCreatedEvent>()
+            .add_message::<CharacterCreationFailedEvent>()
+            .add_message::<CharacterRenamed>()
+            .add_message::<CharacterRenameFailedEvent>()
+            .add_plugins(RequestDeadlinePlugin);
+        app
+    }
+
+    fn creation_failures(app: &App) -> Vec<String> {
+        let messages = app
+            .world()
+            .resource::<Messages<CharacterCreationFailedEvent>>();
+        messages
+            .iter_current_update_messages()
+            .map(|failure| failure.error.clone())
+            .collect()
+    }
+
+    #[test]
+    fn an_unanswered_creation_fails_after_its_timeout() {
+        let mut app = app();
+        app.world_mut().write_message(CreateCharacterRequestEvent {
+            form: CharacterCreationForm::default(),
+        });
+        app.update();
+        assert!(creation_failures(&app).is_empty());
+
+        let timeout = RequestKind::CreateCharacter.timeout();
+        app.world_mut().resource_mut::<Time>().advance_by(timeout);
+        app.update();
+        assert_eq!(creation_failures(&app), vec![NO_REPLY.to_string()]);
+        assert!(app.world().resource::<PendingRequests>().is_empty());
+    }
+
+    #[test]
+    fn an_answered_creation_never_times_out() {
+        let mut app = app();
+        app.world_mut().write_message(CreateCharacterRequestEvent {
+            form: CharacterCreationForm::default(),
+        });
+        app.update();
+        app.world_mut().write_message(CharacterCreatedEvent);
+        let timeout = RequestKind::CreateCharacter.timeout();
+        app.world_mut().resource_mut::<Time>().advance_by(timeout);
+        app.update();
+        assert!(creation_failures(&app).is_empty());
+    }
+}
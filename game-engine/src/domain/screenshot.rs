@@ -0,0 +1,168 @@
+//! In-game screenshots.
+//!
+//! `PlayerAction::Screenshot` (Print Screen) captures the primary window and
+//! hands the frame to the background PNG encoder, so taking a screenshot
+//! mid-fight doesn't hitch. Files land in the folder set in
+//! `Settings::screenshots`, `<data dir>/lifthrasir/screenshots` by default,
+//! named like the reference client's `screenLifthrasir_YYMMDD_NNN`.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy_persistent::Persistent;
+use leafwing_input_manager::prelude::ActionState;
+use time::OffsetDateTime;
+
+use crate::core::state::GameState;
+use crate::domain::entities::markers::LocalPlayer;
+use crate::domain::input::PlayerAction;
+use crate::domain::settings::{ScreenshotConfig, Settings};
+use crate::infrastructure::png_encoder::{EncodePng, PngEncoded};
+
+const SCREENSHOT_PREFIX: &str = "screenLifthrasir";
+
+/// The configured folder, else `<data dir>/lifthrasir/screenshots`, or
+/// `./screenshots` on platforms without a data directory.
+pub fn screenshot_dir(config: &ScreenshotConfig) -> PathBuf {
+    config.folder.clone().unwrap_or_else(|| {
+        dirs::data_dir()
+            .map(|dir| dir.join("lifthrasir"))
+            .unwrap_or_default()
+            .join("screenshots")
+    })
+}
+
+/// `screenLifthrasir_YYMMDD_`: the per-day prefix every name shares. The date
+/// is UTC; the local offset can't be read soundly once the app is threaded.
+fn screenshot_day_prefix(taken_at: SystemTime) -> String {
+    let date = OffsetDateTime::from(taken_at).date();
+    format!(
+        "{SCREENSHOT_PREFIX}_{:02}{:02}{:02}_",
+        date.year().rem_euclid(100),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+/// `screenLifthrasir_YYMMDD_NNN.png` with the lowest counter not already in
+/// `existing`, so names stay unique per day and sort in capture order.
+fn screenshot_file_name<'a>(
+    taken_at: SystemTime,
+    existing: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let prefix = screenshot_day_prefix(taken_at);
+    let next = existing
+        .into_iter()
+        .filter_map(|name| name.strip_prefix(&prefix)?.strip_suffix(".png"))
+        .filter_map(|counter| counter.parse::<u32>().ok())
+        .map(|counter| counter + 1)
+        .max()
+        .unwrap_or(0);
+    format!("{prefix}{next:03}.png")
+}
+
+fn next_screenshot_path(dir: &Path, taken_at: SystemTime) -> PathBuf {
+    let existing: Vec<String> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    dir.join(screenshot_file_name(
+        taken_at,
+        existing.iter().map(String::as_str),
+    ))
+}
+
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (take_screenshot, report_saved_screenshots).run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn take_screenshot(
+    mut commands: Commands,
+    player: Query<&ActionState<PlayerAction>, With<LocalPlayer>>,
+    settings: Res<Persistent<Settings>>,
+) {
+    let Ok(actions) = player.single() else {
+        return;
+    };
+    if !actions.just_pressed(&PlayerAction::Screenshot) {
+        return;
+    }
+
+    let dir = screenshot_dir(&settings.screenshots);
+    let path = next_screenshot_path(&dir, SystemTime::now());
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |captured: On<ScreenshotCaptured>, mut encode: MessageWriter<EncodePng>| {
+            encode.write(EncodePng {
+                path: path.clone(),
+                image: captured.image.clone(),
+                opaque: true,
+            });
+        },
+    );
+}
+
+fn report_saved_screenshots(
+    mut encoded: MessageReader<PngEncoded>,
+    settings: Res<Persistent<Settings>>,
+) {
+    let dir = screenshot_dir(&settings.screenshots);
+    for done in encoded.read().filter(|done| done.path.starts_with(&dir)) {
+        match &done.result {
+            Ok(()) => info!("Screenshot saved to {}", done.path.display()),
+            Err(e) => error!("Cannot save screenshot {}: {}", done.path.display(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    /// 2023-11-14 22:13:20 UTC.
+    fn taken_at() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    #[test]
+    fn screenshot_names_follow_the_reference_pattern() {
+        assert_eq!(
+            screenshot_file_name(taken_at(), []),
+            "screenLifthrasir_231114_000.png"
+        );
+    }
+
+    #[test]
+    fn screenshot_counter_continues_after_the_days_last_file() {
+        let existing = [
+            "screenLifthrasir_231114_000.png",
+            "screenLifthrasir_231114_004.png",
+            "screenLifthrasir_231113_009.png",
+            "notes.txt",
+        ];
+        assert_eq!(
+            screenshot_file_name(taken_at(), existing),
+            "screenLifthrasir_231114_005.png"
+        );
+    }
+
+    #[test]
+    fn screenshot_folder_prefers_the_configured_one() {
+        let config = ScreenshotConfig {
+            folder: Some(PathBuf::from("/tmp/shots")),
+        };
+        assert_eq!(screenshot_dir(&config), PathBuf::from("/tmp/shots"));
+        assert!(screenshot_dir(&ScreenshotConfig::default()).ends_with("screenshots"));
+    }
+}
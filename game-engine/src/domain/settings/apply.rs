@@ -19,6 +19,7 @@ use crate::domain::audio::{
     AudioSettings, MuteAmbienceEvent, MuteBgmEvent, MuteSfxEvent, SetAmbienceVolumeEvent,
     SetBgmVolumeEvent, SetSfxVolumeEvent,
 };
+use crate::domain::camera::CameraControls;
 use crate::domain::camera::components::CameraFollowTarget;
 use crate::domain::entities::markers::LocalPlayer;
 use crate::domain::input::PlayerAction;
@@ -202,6 +203,20 @@ pub fn apply_input(
     *input_map = settings.keybinds.to_input_map();
 }
 
+/// Mirrors the persisted `Settings.camera` into the runtime `CameraControls`;
+/// the camera re-applies its limits when they change.
+#[auto_add_system(plugin = super::SettingsPlugin, schedule = Update)]
+pub fn apply_camera(
+    mut messages: MessageReader<ApplySettings>,
+    settings: Res<Persistent<Settings>>,
+    mut controls: ResMut<CameraControls>,
+) {
+    if messages.read().count() == 0 {
+        return;
+    }
+    controls.set_if_neq(settings.camera.to_controls());
+}
+
 /// Applies the current graphics settings to a freshly-spawned world camera, since
 /// the startup `ApplySettings` fires before the camera (which only spawns on
 /// entering InGame) exists.
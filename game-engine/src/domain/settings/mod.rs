@@ -9,9 +9,9 @@ use bevy_auto_plugin::prelude::{AutoPlugin, auto_add_system};
 pub use events::ApplySettings;
 pub use persistence::settings_path;
 pub use resources::{
-    ActionBinds, Anisotropy, AntiAliasing, AudioConfig, DisplayMode, FpsCap, GraphicsSettings,
-    KeyBind, Keybinds, Modifier, RESOLUTIONS, Settings, UiScaling, resolution_label,
-    resolution_next, resolution_prev,
+    ActionBinds, Anisotropy, AntiAliasing, AudioConfig, CameraConfig, DisplayMode, FpsCap,
+    GraphicsSettings, KeyBind, Keybinds, Modifier, RESOLUTIONS, ScreenshotConfig, Settings,
+    SpriteCacheBudget, UiScaling, resolution_label, resolution_next, resolution_prev,
 };
 
 /// Owns the persisted `Settings` resource: loads `settings.ron` (or writes
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use bevy::pbr::ScreenSpaceAmbientOcclusionQualityLevel;
 use bevy::prelude::*;
 use bevy::reflect::enums::DynamicEnum;
@@ -8,6 +10,7 @@ use bevy_framepace::Limiter;
 use leafwing_input_manager::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::camera::CameraControls;
 use crate::domain::input::{HOTBAR_ACTIONS, PlayerAction};
 
 /// Resolution presets offered in the settings UI.
@@ -200,7 +203,7 @@ impl Anisotropy {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Reflect, Debug, Default)]
 pub enum Upscaling {
     #[default]
     Off,
@@ -244,6 +247,46 @@ impl Upscaling {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug, Default)]
+pub enum TextureCompression {
+    #[default]
+    Off,
+    /// BC1 for opaque or cut-out textures, BC3 when smooth alpha is present.
+    Bc,
+}
+
+impl TextureCompression {
+    /// The variants in stepper order.
+    pub const ALL: [TextureCompression; 2] = [TextureCompression::Off, TextureCompression::Bc];
+
+    /// Display label for the stepper value.
+    pub fn label(self) -> &'static str {
+        match self {
+            TextureCompression::Off => "Off",
+            TextureCompression::Bc => "BC1/BC3",
+        }
+    }
+
+    /// Next variant, clamped at the last.
+    pub fn next(self) -> TextureCompression {
+        cycle_next(&TextureCompression::ALL, self)
+    }
+
+    /// Previous variant, clamped at the first.
+    pub fn prev(self) -> TextureCompression {
+        cycle_prev(&TextureCompression::ALL, self)
+    }
+
+    /// The mode actually used at load time: `Bc` falls back to `Off` when the
+    /// GPU does not expose BC texture sampling.
+    pub fn effective(self, bc_supported: bool) -> TextureCompression {
+        match self {
+            TextureCompression::Bc if bc_supported => TextureCompression::Bc,
+            _ => TextureCompression::Off,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug, Default)]
 pub enum DlssMode {
     #[default]
@@ -351,6 +394,59 @@ impl Ssao {
     }
 }
 
+/// Texture memory the sprite cache may hold. Sprites no unit is showing stay
+/// cached up to this, so one seen again soon (a monster respawning, a map
+/// revisited) needn't be rebuilt. Textures in use are never evicted, so a
+/// crowded scene may go over it.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug, Default)]
+pub enum SpriteCacheBudget {
+    Mb128,
+    #[default]
+    Mb256,
+    Mb512,
+    Mb1024,
+}
+
+impl SpriteCacheBudget {
+    /// The variants in stepper order.
+    pub const ALL: [SpriteCacheBudget; 4] = [
+        SpriteCacheBudget::Mb128,
+        SpriteCacheBudget::Mb256,
+        SpriteCacheBudget::Mb512,
+        SpriteCacheBudget::Mb1024,
+    ];
+
+    /// Display label for the stepper value.
+    pub fn label(self) -> &'static str {
+        match self {
+            SpriteCacheBudget::Mb128 => "128 MB",
+            SpriteCacheBudget::Mb256 => "256 MB",
+            SpriteCacheBudget::Mb512 => "512 MB",
+            SpriteCacheBudget::Mb1024 => "1 GB",
+        }
+    }
+
+    /// Next variant, clamped at the last.
+    pub fn next(self) -> SpriteCacheBudget {
+        cycle_next(&SpriteCacheBudget::ALL, self)
+    }
+
+    /// Previous variant, clamped at the first.
+    pub fn prev(self) -> SpriteCacheBudget {
+        cycle_prev(&SpriteCacheBudget::ALL, self)
+    }
+
+    pub fn bytes(self) -> u64 {
+        let mb = match self {
+            SpriteCacheBudget::Mb128 => 128,
+            SpriteCacheBudget::Mb256 => 256,
+            SpriteCacheBudget::Mb512 => 512,
+            SpriteCacheBudget::Mb1024 => 1024,
+        };
+        mb * 1024 * 1024
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug)]
 pub enum FpsCap {
     F30,
@@ -470,6 +566,8 @@ pub struct GraphicsSettings {
     pub anisotropy: Anisotropy,
     /// xBRZ pixel-art upscaling baked into sprite/terrain/model textures at load.
     pub upscaling: Upscaling,
+    /// Block compression of map terrain/model textures to cut VRAM use.
+    pub texture_compression: TextureCompression,
     pub vsync: bool,
     pub fps_cap: FpsCap,
     pub ui_scaling: UiScaling,
@@ -477,6 +575,9 @@ pub struct GraphicsSettings {
     pub bloom: bool,
     /// Directional-light (sun) shadow casting.
     pub shadows: bool,
+    /// Baked per-tile GND colors on the terrain (the map's painted light and
+    /// shadow). A material uniform, so it applies without a map reload.
+    pub tile_lighting: bool,
     /// DLSS Super Resolution render-scaling mode (NVIDIA RTX only). Orthogonal to
     /// `upscaling` (xBRZ texture baking): DLSS scales render resolution, xBRZ bakes textures.
     pub dlss: DlssMode,
@@ -484,6 +585,14 @@ pub struct GraphicsSettings {
     /// terrain/model crevices; forces MSAA off (needs the depth/normal prepass).
     /// Runs on all native backends including macOS Metal.
     pub ssao: Ssao,
+    /// Far-away units animate on a coarse tick and get no hover nameplate,
+    /// to keep large crowds playable on weaker GPUs.
+    pub sprite_lod: bool,
+    /// Units keep the same on-screen size at any camera zoom, like the
+    /// original client, instead of growing and shrinking with it.
+    pub fixed_sprite_size: bool,
+    /// How much texture memory the sprite cache may hold.
+    pub sprite_cache_budget: SpriteCacheBudget,
 }
 
 impl Default for GraphicsSettings {
@@ -494,13 +603,18 @@ impl Default for GraphicsSettings {
             antialiasing: AntiAliasing::Fxaa,
             anisotropy: Anisotropy::X8,
             upscaling: Upscaling::Off,
+            texture_compression: TextureCompression::Off,
             vsync: true,
             fps_cap: FpsCap::F60,
             ui_scaling: UiScaling::P100,
             bloom: true,
             shadows: true,
+            tile_lighting: true,
             dlss: DlssMode::Off,
             ssao: Ssao::Off,
+            sprite_lod: false,
+            fixed_sprite_size: false,
+            sprite_cache_budget: SpriteCacheBudget::default(),
         }
     }
 }
@@ -530,6 +644,56 @@ impl Default for AudioConfig {
     }
 }
 
+/// Persisted camera limits, mirrored into the runtime `CameraControls`. Pitch
+/// is in degrees here for a readable `settings.ron`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Reflect, Debug)]
+#[serde(default)]
+pub struct CameraConfig {
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub min_pitch_degrees: f32,
+    pub max_pitch_degrees: f32,
+    /// Ease rate for rotation and zoom; 0 snaps.
+    pub view_smoothing: f32,
+    /// Lifts the pitch limits.
+    pub free_look: bool,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        let controls = CameraControls::default();
+        Self {
+            min_distance: controls.min_distance,
+            max_distance: controls.max_distance,
+            min_pitch_degrees: controls.min_pitch.to_degrees(),
+            max_pitch_degrees: controls.max_pitch.to_degrees(),
+            view_smoothing: controls.view_smoothing_speed,
+            free_look: controls.free_look,
+        }
+    }
+}
+
+impl CameraConfig {
+    /// Runtime controls, with an inverted range from a hand-edited file
+    /// swapped back into order.
+    pub fn to_controls(self) -> CameraControls {
+        let (min_distance, max_distance) = ordered(self.min_distance, self.max_distance);
+        let (min_pitch, max_pitch) = ordered(self.min_pitch_degrees, self.max_pitch_degrees);
+        CameraControls {
+            min_distance,
+            max_distance,
+            min_pitch: min_pitch.to_radians(),
+            max_pitch: max_pitch.to_radians(),
+            view_smoothing_speed: self.view_smoothing.max(0.0),
+            free_look: self.free_look,
+        }
+    }
+}
+
+fn ordered(a: f32, b: f32) -> (f32, f32) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
 /// A held modifier in a key chord. Serde-only mirror of leafwing's `ModifierKey`;
 /// Task 4 owns the conversion.
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Reflect, Debug)]
@@ -651,6 +815,7 @@ pub struct Keybinds {
     pub party: ActionBinds,
     pub guild: ActionBinds,
     pub emote: ActionBinds,
+    pub screenshot: ActionBinds,
     #[serde(default = "default_hotbar_binds")]
     pub hotbar: [ActionBinds; 12],
 }
@@ -658,7 +823,7 @@ pub struct Keybinds {
 impl Default for Keybinds {
     /// Mirrors `PlayerAction::default_input_map()`:
     /// Sit = Insert / Help, Status = Alt+A, Inventory = Alt+E, Skills = Alt+S, Equipment = Alt+Q,
-    /// Cart = Alt+W, Party = P, Guild = Alt+G, Emote = Alt+M.
+    /// Cart = Alt+W, Party = P, Guild = Alt+G, Emote = Alt+M, Screenshot = Print Screen.
     fn default() -> Self {
         Self {
             sit: ActionBinds {
@@ -697,6 +862,10 @@ impl Default for Keybinds {
                 primary: Some(KeyBind::modified(Modifier::Alt, "KeyM")),
                 secondary: None,
             },
+            screenshot: ActionBinds {
+                primary: Some(KeyBind::new("PrintScreen")),
+                secondary: None,
+            },
             hotbar: default_hotbar_binds(),
         }
     }
@@ -718,6 +887,8 @@ impl Keybinds {
         self.party.insert_into(&mut map, PlayerAction::Party);
         self.guild.insert_into(&mut map, PlayerAction::Guild);
         self.emote.insert_into(&mut map, PlayerAction::Emote);
+        self.screenshot
+            .insert_into(&mut map, PlayerAction::Screenshot);
         for (binds, action) in self.hotbar.iter().zip(HOTBAR_ACTIONS) {
             binds.insert_into(&mut map, action);
         }
@@ -725,6 +896,14 @@ impl Keybinds {
     }
 }
 
+/// Where Print Screen saves to.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Reflect, Debug, Default)]
+#[serde(default)]
+pub struct ScreenshotConfig {
+    /// Target folder; unset uses `<data dir>/lifthrasir/screenshots`.
+    pub folder: Option<PathBuf>,
+}
+
 #[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Reflect, Debug, Default)]
 #[serde(default)]
 #[reflect(Resource)]
@@ -733,6 +912,8 @@ pub struct Settings {
     pub graphics: GraphicsSettings,
     pub audio: AudioConfig,
     pub keybinds: Keybinds,
+    pub camera: CameraConfig,
+    pub screenshots: ScreenshotConfig,
 }
 
 #[cfg(test)]
@@ -747,6 +928,22 @@ mod tests {
         assert_eq!(settings, decoded);
     }
 
+    #[test]
+    fn camera_config_defaults_to_the_runtime_controls_and_orders_ranges() {
+        let controls = CameraConfig::default().to_controls();
+        let defaults = CameraControls::default();
+        assert_eq!(controls.min_distance, defaults.min_distance);
+        assert!((controls.max_pitch - defaults.max_pitch).abs() < 1e-5);
+
+        let swapped = CameraConfig {
+            min_pitch_degrees: 70.0,
+            max_pitch_degrees: 20.0,
+            ..Default::default()
+        }
+        .to_controls();
+        assert!(swapped.min_pitch < swapped.max_pitch);
+    }
+
     #[test]
     fn default_equipment_bind_is_alt_q() {
         assert_eq!(
@@ -994,6 +1191,26 @@ mod tests {
         assert_eq!(Upscaling::X4.factor(), Some(4));
     }
 
+    #[test]
+    fn texture_compression_defaults_off_and_requires_gpu_support() {
+        assert_eq!(
+            GraphicsSettings::default().texture_compression,
+            TextureCompression::Off
+        );
+        assert_eq!(
+            TextureCompression::Bc.effective(true),
+            TextureCompression::Bc
+        );
+        assert_eq!(
+            TextureCompression::Bc.effective(false),
+            TextureCompression::Off
+        );
+        assert_eq!(
+            TextureCompression::Off.effective(true),
+            TextureCompression::Off
+        );
+    }
+
     #[test]
     fn upscaling_cycles_and_clamps() {
         assert_eq!(Upscaling::Off.next(), Upscaling::X2);
@@ -15,11 +15,13 @@ pub struct SkillUnitGroup {
 
 /// One cell of a group (one occupied tile). A child of the group root, positioned
 /// relative to the root so its world transform lands on the cell. HP is kept
-/// server-authoritative for future use (no HP bar in scope).
+/// server-authoritative for future use (no HP bar in scope). `cell` is its grid
+/// position, kept so a movement-blocking cell can free its tile on despawn.
 #[derive(Component, Debug)]
 pub struct SkillUnitCell {
     pub group_id: u64,
     pub cell_id: u32,
+    pub cell: (u16, u16),
     pub flags: SkillUnitCellFlags,
     pub hp: u32,
     pub max_hp: u32,
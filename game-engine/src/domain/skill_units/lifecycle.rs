@@ -8,6 +8,7 @@ use bevy::prelude::*;
 use net_contract::events::{SkillUnitDespawned, SkillUnitUpdated};
 
 use super::components::{SkillUnitCell, SkillUnitGroup};
+use crate::domain::entities::pathfinding::CurrentMapPathfindingGrid;
 use crate::domain::entities::registry::EntityRegistry;
 
 /// Apply server HP updates to a cell. An unknown group/cell (e.g. an update that
@@ -32,13 +33,14 @@ pub fn update_skill_units(
     }
 }
 
-/// Despawn the listed cells; when the group has no cells left, despawn the root
-/// (recursively removing any remaining visuals). An unknown group is warned and
-/// ignored.
+/// Despawn the listed cells, freeing the tiles of any that blocked movement;
+/// when the group has no cells left, despawn the root (recursively removing any
+/// remaining visuals). An unknown group is warned and ignored.
 pub fn despawn_skill_units(
     mut events: MessageReader<SkillUnitDespawned>,
     mut commands: Commands,
     mut entity_registry: ResMut<EntityRegistry>,
+    mut pathfinding_grid: Option<ResMut<CurrentMapPathfindingGrid>>,
     groups: Query<(Entity, &SkillUnitGroup)>,
     cells: Query<(Entity, &SkillUnitCell)>,
 ) {
@@ -58,6 +60,11 @@ pub fn despawn_skill_units(
                 if cell.flags.targetable {
                     entity_registry.unregister_entity_by_aid(cell.cell_id);
                 }
+                if cell.flags.blocks_movement
+                    && let Some(grid) = pathfinding_grid.as_deref_mut()
+                {
+                    grid.0.unblock_cell(cell.cell.0, cell.cell.1);
+                }
                 commands.entity(entity).despawn();
             } else {
                 remaining += 1;
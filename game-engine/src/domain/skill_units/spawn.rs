@@ -14,6 +14,7 @@ use super::components::{SkillUnitCell, SkillUnitGroup};
 use super::visuals::spawn_effect_child;
 use crate::domain::entities::character::components::core::Grounded;
 use crate::domain::entities::components::NetworkEntity;
+use crate::domain::entities::pathfinding::{CurrentMapPathfindingGrid, PathfindingGrid};
 use crate::domain::entities::picking::{on_sprite_click, on_sprite_out, on_sprite_over};
 use crate::domain::entities::registry::EntityRegistry;
 use crate::domain::entities::types::ObjectType;
@@ -50,6 +51,7 @@ pub fn spawn_skill_units(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut entity_registry: ResMut<EntityRegistry>,
+    mut pathfinding_grid: Option<ResMut<CurrentMapPathfindingGrid>>,
     existing: Query<(Entity, &SkillUnitGroup)>,
     existing_cells: Query<&SkillUnitCell>,
 ) {
@@ -61,6 +63,7 @@ pub fn spawn_skill_units(
             &mut meshes,
             &mut materials,
             &mut entity_registry,
+            pathfinding_grid.as_deref_mut().map(|grid| &mut grid.0),
             catalog,
             &existing,
             &existing_cells,
@@ -75,6 +78,7 @@ pub fn spawn_skill_units(
                 &mut meshes,
                 &mut materials,
                 &mut entity_registry,
+                pathfinding_grid.as_deref_mut().map(|grid| &mut grid.0),
                 catalog,
                 &existing,
                 &existing_cells,
@@ -91,6 +95,7 @@ fn spawn_group(
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<StandardMaterial>,
     entity_registry: &mut EntityRegistry,
+    mut pathfinding_grid: Option<&mut PathfindingGrid>,
     catalog: Option<&EffectCatalog>,
     existing: &Query<(Entity, &SkillUnitGroup)>,
     existing_cells: &Query<&SkillUnitCell>,
@@ -99,9 +104,16 @@ fn spawn_group(
     if let Some((old_root, _)) = existing.iter().find(|(_, g)| g.group_id == group.group_id) {
         for cell in existing_cells
             .iter()
-            .filter(|c| c.group_id == group.group_id && c.flags.targetable)
+            .filter(|c| c.group_id == group.group_id)
         {
-            entity_registry.unregister_entity_by_aid(cell.cell_id);
+            if cell.flags.targetable {
+                entity_registry.unregister_entity_by_aid(cell.cell_id);
+            }
+            if cell.flags.blocks_movement
+                && let Some(grid) = pathfinding_grid.as_deref_mut()
+            {
+                grid.unblock_cell(cell.cell.0, cell.cell.1);
+            }
         }
         commands.entity(old_root).despawn();
     }
@@ -148,6 +160,7 @@ fn spawn_group(
                 SkillUnitCell {
                     group_id: group.group_id,
                     cell_id: cell.cell_id,
+                    cell: (cell_x, cell_y),
                     flags: cell.flags,
                     hp: cell.hp,
                     max_hp: cell.max_hp,
@@ -167,6 +180,11 @@ fn spawn_group(
             entity_registry.register_entity(cell.cell_id, cell_entity);
             spawn_cell_collider(commands, meshes, cell_entity);
         }
+        if cell.flags.blocks_movement
+            && let Some(grid) = pathfinding_grid.as_deref_mut()
+        {
+            grid.block_cell(cell_x, cell_y);
+        }
 
         cell_entities.push((cell_entity, cell));
     }
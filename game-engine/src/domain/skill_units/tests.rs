@@ -15,6 +15,7 @@ use super::lifecycle::{despawn_skill_units, update_skill_units};
 use super::spawn::spawn_skill_units;
 use crate::domain::effects::EffectSprite;
 use crate::domain::effects::components::ActiveEffect;
+use crate::domain::entities::pathfinding::{CurrentMapPathfindingGrid, PathfindingGrid};
 use crate::domain::entities::registry::EntityRegistry;
 use crate::infrastructure::effect::{EffectCatalog, EffectDataAsset, LoadedEffectAsset};
 use crate::utils::coordinates::spawn_coords_to_world_position;
@@ -662,3 +663,66 @@ fn duplicate_spawn_unregisters_old_targetable_cells() {
     );
     assert!(registry.get_entity(200).is_some(), "new cell registers");
 }
+
+const ICE_WALL: u32 = 87;
+
+fn wall_cell(cell_id: u32, x: i32, y: i32) -> SkillUnitCellState {
+    SkillUnitCellState {
+        cell_id,
+        x,
+        y,
+        hp: 200,
+        max_hp: 200,
+        flags: SkillUnitCellFlags {
+            targetable: true,
+            blocks_movement: true,
+            visible: true,
+            ..Default::default()
+        },
+    }
+}
+
+fn walkable(app: &App, x: u16, y: u16) -> bool {
+    app.world()
+        .resource::<CurrentMapPathfindingGrid>()
+        .0
+        .is_walkable(x, y)
+}
+
+#[test]
+fn blocking_cells_block_their_tiles_until_despawned() {
+    let mut app = test_app(cell_anchored_vfx_catalog(ICE_WALL));
+    app.insert_resource(CurrentMapPathfindingGrid(PathfindingGrid::open(64, 64)));
+    app.world_mut().write_message(SkillUnitSpawned {
+        group: group(
+            1,
+            ICE_WALL,
+            vec![
+                wall_cell(100, 40, 50),
+                wall_cell(101, 41, 50),
+                cell(102, 42, 50, true),
+            ],
+        ),
+    });
+    app.update();
+    assert!(!walkable(&app, 40, 50));
+    assert!(!walkable(&app, 41, 50));
+    assert!(walkable(&app, 42, 50), "non-blocking cell stays walkable");
+
+    app.world_mut().write_message(SkillUnitDespawned {
+        group_id: 1,
+        cell_ids: vec![100],
+        reason: Default::default(),
+    });
+    app.update();
+    assert!(walkable(&app, 40, 50));
+    assert!(!walkable(&app, 41, 50));
+
+    // Re-entering view replaces the group: the old wall frees its tile.
+    app.world_mut().write_message(SkillUnitSpawned {
+        group: group(1, ICE_WALL, vec![wall_cell(103, 43, 50)]),
+    });
+    app.update();
+    assert!(walkable(&app, 41, 50));
+    assert!(!walkable(&app, 43, 50));
+}
@@ -39,7 +39,7 @@ pub fn layer_order(tag: Tag) -> u8 {
     }
 }
 
-/// Transparent-sort depth bias for a layer's `StandardMaterial`.
+/// Transparent-sort depth bias for a layer's material.
 ///
 /// A unit's billboard quads are near-coplanar, so the transparent pass
 /// distance sort alone is undecided between them (the 0.001-scale z nudges
@@ -139,6 +139,7 @@ pub enum CombatSystems {
 pub enum CameraSystems {
     TargetUpdate,
     Follow,
+    Sequence,
 }
 
 // =============================================================================
@@ -159,6 +160,7 @@ pub enum CharacterFlowSystems {
     CharacterSelection,
     CharacterCreation,
     CharacterDeletion,
+    CharacterRename,
     ZoneServerInfo,
     ZoneConnection,
     ZoneEntry,
@@ -6,6 +6,8 @@ pub mod map_scoped;
 pub mod spawn_context;
 pub mod systems;
 pub mod terrain;
+#[cfg(test)]
+pub(crate) mod test_map;
 pub mod warp;
 pub mod zone_readiness;
 
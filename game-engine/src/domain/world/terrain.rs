@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         entities::pathfinding::{CurrentMapPathfindingGrid, PathfindingGrid},
-        settings::{ApplySettings, Settings},
+        settings::{ApplySettings, Settings, resources::TextureCompression},
         system_sets::WorldLoadingSystems,
         world::{
             components::MapLoader, map::MapData, map_loader::MapRequestLoader,
@@ -12,13 +12,16 @@ use crate::{
         bmp_loader::BmpLoaderSettings,
         loaders::{RoAltitudeAsset, RoGroundAsset},
     },
+    presentation::rendering::terrain_material::{TerrainMaterial, terrain_material},
     utils::{
+        bcn::{bc_supported, compress_image_bcn},
         constants::CELL_SIZE,
         mipmap::{apply_anisotropic_sampler, generate_mipmaps_with_anisotropy},
     },
 };
 use bevy::{
     asset::{AssetEvent, RenderAssetUsages},
+    image::CompressedImageFormatSupport,
     mesh::{Indices, PrimitiveTopology},
     prelude::*,
 };
@@ -118,11 +121,13 @@ pub struct TerrainTexturesLoading {
 /// Generate mipmaps and enable anisotropic filtering on every loaded terrain
 /// texture, recording each id so the level can be retuned later. Runs once per
 /// texture (the generator is idempotent), so re-used textures across maps are
-/// only processed the first time.
+/// only processed the first time. Block compression runs after the mip chain is
+/// built, since mip generation needs the raw RGBA texels.
 fn apply_terrain_texture_filtering(
     handles: &[Handle<Image>],
     images: &mut Assets<Image>,
     anisotropy: u16,
+    compression: TextureCompression,
     texture_ids: &mut TerrainTextureIds,
 ) {
     for handle in handles {
@@ -131,6 +136,7 @@ fn apply_terrain_texture_filtering(
         }
         if let Some(mut image) = images.get_mut(handle) {
             generate_mipmaps_with_anisotropy(&mut image, anisotropy);
+            compress_image_bcn(&mut image, compression);
             texture_ids.0.insert(handle.id());
         }
     }
@@ -160,14 +166,43 @@ fn reapply_terrain_anisotropy(
     }
 }
 
+/// Flip baked tile lighting on every terrain material when settings are applied.
+/// Only the extension uniform changes; meshes and textures are untouched.
+#[auto_add_system(
+    plugin = crate::plugins::world_domain_plugin::WorldDomainPlugin,
+    schedule = Update
+)]
+fn reapply_terrain_tile_lighting(
+    mut messages: MessageReader<ApplySettings>,
+    settings: Res<Persistent<Settings>>,
+    mut materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    if messages.read().count() == 0 {
+        return;
+    }
+
+    let enabled = settings.graphics.tile_lighting;
+    let stale: Vec<_> = materials
+        .iter()
+        .filter(|(_, material)| material.extension.tile_lighting() != enabled)
+        .map(|(id, _)| id)
+        .collect();
+    for id in stale {
+        if let Some(mut material) = materials.get_mut(id) {
+            material.extension.set_tile_lighting(enabled);
+        }
+    }
+}
+
 /// Create materials from loaded texture handles
 /// Only called after textures are confirmed loaded/failed
 fn create_terrain_materials_from_loaded_textures(
     ground: &crate::infrastructure::ro_formats::RoGround,
     texture_handles: &[Handle<Image>],
     asset_server: &AssetServer,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Vec<Handle<StandardMaterial>> {
+    materials: &mut ResMut<Assets<TerrainMaterial>>,
+    tile_lighting: bool,
+) -> Vec<Handle<TerrainMaterial>> {
     use bevy::asset::LoadState;
     let mut texture_materials = Vec::new();
 
@@ -177,27 +212,30 @@ fn create_terrain_materials_from_loaded_textures(
             match asset_server.load_state(&texture_handles[i]) {
                 LoadState::Loaded => {
                     debug!("Using loaded texture #{}: {}", i, texture_name);
-                    materials.add(StandardMaterial {
-                        base_color_texture: Some(texture_handles[i].clone()),
-                        base_color: Color::WHITE,
-                        perceptual_roughness: TERRAIN_ROUGHNESS,
-                        metallic: TERRAIN_METALLIC,
-                        reflectance: TERRAIN_REFLECTANCE,
-                        cull_mode: None,
-                        alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
-                        ..default()
-                    })
+                    materials.add(terrain_material(
+                        StandardMaterial {
+                            base_color_texture: Some(texture_handles[i].clone()),
+                            base_color: Color::WHITE,
+                            perceptual_roughness: TERRAIN_ROUGHNESS,
+                            metallic: TERRAIN_METALLIC,
+                            reflectance: TERRAIN_REFLECTANCE,
+                            cull_mode: None,
+                            alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
+                            ..default()
+                        },
+                        tile_lighting,
+                    ))
                 }
                 _ => {
                     warn!(
                         "Texture failed, using colored fallback for: {}",
                         texture_name
                     );
-                    create_colored_fallback_material(i, materials)
+                    create_colored_fallback_material(i, materials, tile_lighting)
                 }
             }
         } else {
-            create_colored_fallback_material(i, materials)
+            create_colored_fallback_material(i, materials, tile_lighting)
         };
 
         texture_materials.push(material);
@@ -209,8 +247,9 @@ fn create_terrain_materials_from_loaded_textures(
 /// Create a colored fallback material
 fn create_colored_fallback_material(
     index: usize,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Handle<StandardMaterial> {
+    materials: &mut ResMut<Assets<TerrainMaterial>>,
+    tile_lighting: bool,
+) -> Handle<TerrainMaterial> {
     let color = match index % 10 {
         0 => Color::srgb(0.8, 0.6, 0.4), // Brown
         1 => Color::srgb(0.4, 0.8, 0.4), // Green
@@ -224,15 +263,18 @@ fn create_colored_fallback_material(
         _ => Color::srgb(0.7, 0.7, 0.7), // Grey
     };
 
-    materials.add(StandardMaterial {
-        base_color: color,
-        perceptual_roughness: TERRAIN_ROUGHNESS,
-        metallic: TERRAIN_METALLIC,
-        reflectance: TERRAIN_REFLECTANCE,
-        cull_mode: None,
-        alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
-        ..default()
-    })
+    materials.add(terrain_material(
+        StandardMaterial {
+            base_color: color,
+            perceptual_roughness: TERRAIN_ROUGHNESS,
+            metallic: TERRAIN_METALLIC,
+            reflectance: TERRAIN_REFLECTANCE,
+            cull_mode: None,
+            alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
+            ..default()
+        },
+        tile_lighting,
+    ))
 }
 
 /// Wall direction for parametric wall generation
@@ -628,10 +670,11 @@ type TerrainLoadingQuery<'w, 's> = Query<
 fn apply_loaded_terrain_textures(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: ResMut<Assets<TerrainMaterial>>,
     mut images: ResMut<Assets<Image>>,
     mut texture_ids: ResMut<TerrainTextureIds>,
     settings: Res<Persistent<Settings>>,
+    format_support: Option<Res<CompressedImageFormatSupport>>,
     ground_assets: Res<Assets<RoGroundAsset>>,
     altitude_assets: Res<Assets<RoAltitudeAsset>>,
     asset_server: Res<AssetServer>,
@@ -726,6 +769,10 @@ fn apply_loaded_terrain_textures(
             &textures_loading.texture_handles,
             &mut images,
             settings.graphics.anisotropy.to_clamp(),
+            settings
+                .graphics
+                .texture_compression
+                .effective(bc_supported(format_support.as_deref())),
             &mut texture_ids,
         );
 
@@ -734,6 +781,7 @@ fn apply_loaded_terrain_textures(
             &textures_loading.texture_handles,
             &asset_server,
             &mut materials,
+            settings.graphics.tile_lighting,
         );
 
         let meshes_by_texture = create_terrain_meshes(&ground.ground, altitude);
@@ -765,16 +813,19 @@ fn apply_loaded_terrain_textures(
                     "generate_terrain_mesh: Using fallback material for texture_idx {}",
                     texture_idx
                 );
-                materials.add(StandardMaterial {
-                    base_color: Color::srgb(0.0, 1.0, 1.0),
-                    perceptual_roughness: TERRAIN_ROUGHNESS,
-                    metallic: TERRAIN_METALLIC,
-                    reflectance: TERRAIN_REFLECTANCE,
-                    double_sided: true,
-                    cull_mode: None,
-                    alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
-                    ..default()
-                })
+                materials.add(terrain_material(
+                    StandardMaterial {
+                        base_color: Color::srgb(0.0, 1.0, 1.0),
+                        perceptual_roughness: TERRAIN_ROUGHNESS,
+                        metallic: TERRAIN_METALLIC,
+                        reflectance: TERRAIN_REFLECTANCE,
+                        double_sided: true,
+                        cull_mode: None,
+                        alpha_mode: AlphaMode::Mask(TERRAIN_ALPHA_THRESHOLD),
+                        ..default()
+                    },
+                    settings.graphics.tile_lighting,
+                ))
             };
 
             commands.spawn((
@@ -985,3 +1036,38 @@ fn create_terrain_meshes(
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::world::test_map::{self, GROUND_SIZE, RAISED_HEIGHT};
+    use crate::infrastructure::ro_formats::{RoAltitude, RoGround};
+
+    #[test]
+    fn test_map_builds_a_ground_mesh_and_a_wall_mesh() {
+        let ground = RoGround::from_bytes(&test_map::fixture("gnd")).expect("parse gnd");
+        let altitude = RoAltitude::from_bytes(&test_map::fixture("gat")).expect("parse gat");
+
+        let meshes: HashMap<usize, Mesh> = create_terrain_meshes(&ground, Some(&altitude))
+            .into_iter()
+            .collect();
+
+        // Six unshared vertices per cell on top, four per wall quad.
+        let cells = (GROUND_SIZE * GROUND_SIZE) as usize;
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(meshes[&0].count_vertices(), cells * 6);
+        assert_eq!(meshes[&1].count_vertices(), 4 * 4);
+
+        let Some(bevy::mesh::VertexAttributeValues::Float32x3(positions)) =
+            meshes[&0].attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("terrain positions are Float32x3");
+        };
+        assert!(positions.iter().any(|p| p[1] == RAISED_HEIGHT));
+        assert!(
+            positions
+                .iter()
+                .all(|p| p[0] >= 0.0 && p[0] <= GROUND_SIZE as f32 * CELL_SIZE)
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! A tiny synthetic map for headless map-pipeline tests.
+//!
+//! `lifthrasir_test` is a flat 6x6 GND ground with one raised cell walled on all
+//! four sides, the matching 12x12 GAT with that cell's four altitude cells
+//! blocked, and an RSW tying the two together. Nothing here comes from the
+//! game's data files, so CI can exercise the parsers, the terrain mesh and the
+//! pathfinding grid without shipping copyrighted assets.
+//!
+//! The encoders below are the source of truth; the committed copies under
+//! `tests/fixtures/maps/` are their output, checked byte-for-byte so the two
+//! can't drift. After changing an encoder, rewrite the files with
+//! `cargo test -p game-engine regenerate_test_map_fixtures -- --ignored`.
+
+use std::path::PathBuf;
+
+pub const MAP_NAME: &str = "lifthrasir_test";
+
+/// GND cells per side.
+pub const GROUND_SIZE: u32 = 6;
+/// The raised, walled GND cell.
+pub const RAISED_CELL: (u32, u32) = (2, 2);
+/// How far the raised cell sits above the ground (GND heights grow downward).
+pub const RAISED_HEIGHT: f32 = -10.0;
+pub const GROUND_TEXTURES: [&str; 2] = ["lifthrasir\\test_ground.bmp", "lifthrasir\\test_wall.bmp"];
+
+/// GAT cells per side: the altitude grid is twice the ground's resolution.
+pub const ALTITUDE_SIZE: u32 = GROUND_SIZE * 2;
+pub const WATER_LEVEL: f32 = 5.0;
+
+/// GAT cell types (see `GatCellType::from`).
+const GAT_WALKABLE: u32 = 0;
+const GAT_BLOCKED: u32 = 1;
+
+const GROUND_TILE: i32 = 0;
+const WALL_TILE: i32 = 1;
+
+pub fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/maps")
+}
+
+/// The committed fixture `lifthrasir_test.<extension>`.
+pub fn fixture(extension: &str) -> Vec<u8> {
+    let path = fixture_dir().join(format!("{MAP_NAME}.{extension}"));
+    std::fs::read(&path).unwrap_or_else(|e| panic!("read {}: {e}", path.display()))
+}
+
+fn is_raised(x: u32, y: u32) -> bool {
+    (x, y) == RAISED_CELL
+}
+
+/// Whether a GAT cell lies under the raised GND cell.
+pub fn is_blocked_altitude_cell(x: u32, y: u32) -> bool {
+    is_raised(x / 2, y / 2)
+}
+
+/// GND 1.7: two textures, an empty lightmap, a ground and a wall tile, then one
+/// surface per cell. Walls hang off the raised cell's edges: its own front and
+/// right, and the right/front of its west and south neighbours.
+pub fn ground_bytes() -> Vec<u8> {
+    let mut out = Writer::new(b"GRGN", 1, 7);
+    out.u32(GROUND_SIZE);
+    out.u32(GROUND_SIZE);
+    out.f32(10.0); // zoom
+
+    out.u32(GROUND_TEXTURES.len() as u32);
+    out.u32(80);
+    for texture in GROUND_TEXTURES {
+        out.string(texture, 80);
+    }
+
+    // Lightmaps: none, at the usual 8x8x1 slice size.
+    out.u32(0);
+    out.i32(8);
+    out.i32(8);
+    out.i32(1);
+
+    out.u32(2);
+    for texture in 0..2u16 {
+        for uv in [0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0] {
+            out.f32(uv);
+        }
+        out.u16(texture);
+        out.u16(0); // lightmap index
+        out.bytes(&[255, 255, 255, 255]);
+    }
+
+    for y in 0..GROUND_SIZE {
+        for x in 0..GROUND_SIZE {
+            let height = if is_raised(x, y) { RAISED_HEIGHT } else { 0.0 };
+            for _ in 0..4 {
+                out.f32(height);
+            }
+            let walled = is_raised(x, y);
+            let front = walled || is_raised(x, y + 1);
+            let right = walled || is_raised(x + 1, y);
+            out.i32(GROUND_TILE);
+            out.i32(if front { WALL_TILE } else { -1 });
+            out.i32(if right { WALL_TILE } else { -1 });
+        }
+    }
+    out.finish()
+}
+
+/// GAT 1.2: flat, walkable everywhere except under the raised ground cell.
+pub fn altitude_bytes() -> Vec<u8> {
+    let mut out = Writer::new(b"GRAT", 1, 2);
+    out.u32(ALTITUDE_SIZE);
+    out.u32(ALTITUDE_SIZE);
+    for y in 0..ALTITUDE_SIZE {
+        for x in 0..ALTITUDE_SIZE {
+            let blocked = is_blocked_altitude_cell(x, y);
+            let height = if blocked { RAISED_HEIGHT } else { 0.0 };
+            for _ in 0..4 {
+                out.f32(height);
+            }
+            out.u32(if blocked { GAT_BLOCKED } else { GAT_WALKABLE });
+        }
+    }
+    out.finish()
+}
+
+/// RSW 1.9 naming the ground and altitude files, with water, a sun and no
+/// objects.
+pub fn world_bytes() -> Vec<u8> {
+    let mut out = Writer::new(b"GRSW", 1, 9);
+    out.string("", 40); // ini
+    out.string(&format!("{MAP_NAME}.gnd"), 40);
+    out.string(&format!("{MAP_NAME}.gat"), 40);
+    out.string("", 40); // src
+
+    out.f32(WATER_LEVEL);
+    out.u32(0); // water type
+    out.f32(1.0); // wave height
+    out.f32(2.0); // wave speed
+    out.f32(50.0); // wave pitch
+    out.u32(3); // texture animation speed
+
+    out.u32(45); // light longitude
+    out.u32(45); // light latitude
+    for channel in [1.0, 1.0, 1.0, 0.3, 0.3, 0.3] {
+        out.f32(channel);
+    }
+    out.f32(0.5); // shadow opacity
+
+    for bound in [0u32; 4] {
+        out.u32(bound);
+    }
+    out.u32(0); // objects
+    out.finish()
+}
+
+/// Little-endian writer for the RO binary headers and fields.
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn new(magic: &[u8; 4], major: u8, minor: u8) -> Self {
+        let mut bytes = magic.to_vec();
+        bytes.extend([major, minor]);
+        Self(bytes)
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.0.extend(value);
+    }
+
+    /// A NUL-padded fixed-width string field.
+    fn string(&mut self, value: &str, width: usize) {
+        assert!(
+            value.len() < width,
+            "'{value}' overflows a {width}-byte field"
+        );
+        let start = self.0.len();
+        self.0.extend(value.as_bytes());
+        self.0.resize(start + width, 0);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+mod tests {
+    use super::*;
+    use crate::domain::entities::pathfinding::{PathfindingGrid, find_path};
+    use crate::infrastructure::ro_formats::{RoAltitude, RoGround, RoWorld};
+
+    /// A fixture's file extension and the generator that writes it.
+    type Fixture = (&'static str, fn() -> Vec<u8>);
+
+    const FIXTURES: [Fixture; 3] = [
+        ("gnd", ground_bytes),
+        ("gat", altitude_bytes),
+        ("rsw", world_bytes),
+    ];
+
+    #[test]
+    fn committed_fixtures_match_the_generator() {
+        for (extension, generate) in FIXTURES {
+            assert!(
+                fixture(extension) == generate(),
+                "tests/fixtures/maps/{MAP_NAME}.{extension} is stale; regenerate it"
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "rewrites the committed fixtures"]
+    fn regenerate_test_map_fixtures() {
+        std::fs::create_dir_all(fixture_dir()).unwrap();
+        for (extension, generate) in FIXTURES {
+            let path = fixture_dir().join(format!("{MAP_NAME}.{extension}"));
+            std::fs::write(path, generate()).unwrap();
+        }
+    }
+
+    #[test]
+    fn world_names_its_ground_and_altitude() {
+        let world = RoWorld::from_bytes(&fixture("rsw")).expect("parse rsw");
+        assert_eq!(world.version, "1.9");
+        assert_eq!(world.gnd_file, format!("{MAP_NAME}.gnd"));
+        assert_eq!(world.gat_file, format!("{MAP_NAME}.gat"));
+        assert_eq!(world.water.level, WATER_LEVEL);
+        assert!(world.objects.is_empty());
+    }
+
+    #[test]
+    fn ground_parses_with_its_textures_and_walls() {
+        let ground = RoGround::from_bytes(&fixture("gnd")).expect("parse gnd");
+        assert_eq!((ground.width, ground.height), (GROUND_SIZE, GROUND_SIZE));
+        assert_eq!(ground.textures, GROUND_TEXTURES);
+        assert_eq!(ground.tiles.len(), 2);
+        let walls = ground
+            .surfaces
+            .iter()
+            .map(|s| (s.tile_front >= 0) as usize + (s.tile_right >= 0) as usize)
+            .sum::<usize>();
+        assert_eq!(walls, 4);
+    }
+
+    #[test]
+    fn pathfinding_grid_routes_around_the_raised_cell() {
+        let altitude = RoAltitude::from_bytes(&fixture("gat")).expect("parse gat");
+        let grid = PathfindingGrid::from_gat(&altitude);
+        assert_eq!(
+            (grid.width(), grid.height()),
+            (ALTITUDE_SIZE, ALTITUDE_SIZE)
+        );
+        assert!(!grid.is_walkable(4, 4));
+        assert!(grid.is_walkable(3, 4));
+
+        let path = find_path(&grid, (2, 4), (8, 4)).expect("a way around");
+        assert_eq!(path.first(), Some(&(2, 4)));
+        assert_eq!(path.last(), Some(&(8, 4)));
+        assert!(path.len() > 2, "the straight line is blocked");
+        assert!(grid.is_path_walkable(&path));
+    }
+}
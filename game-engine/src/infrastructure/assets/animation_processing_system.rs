@@ -1,10 +1,14 @@
+use std::time::{Duration, Instant};
+
 use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
 use bevy_persistent::prelude::Persistent;
 use moonshine_tag::Tag;
 
-use super::animation_processor::RoAnimationProcessor;
+use super::animation_processor::{DecodedAnimation, RoAnimationProcessor};
 use super::loaders::{RoActAsset, RoSpriteAsset};
 use super::ro_animation_asset::RoAnimationAsset;
+use super::sprite_cache::{SpriteCacheKey, SpriteTextureCache, evict_sprite_textures};
 use crate::domain::settings::resources::Settings;
 
 /// A pending animation request waiting for SPR+ACT to load.
@@ -16,10 +20,18 @@ pub struct PendingAnimation {
     pub callback_entity: Option<Entity>,
 }
 
+/// An SPR+ACT pair being decoded on the compute pool.
+struct DecodeJob {
+    key: SpriteCacheKey,
+    request: PendingAnimation,
+    task: Task<DecodedAnimation>,
+}
+
 /// Resource tracking pending animation processing requests.
 #[derive(Resource, Default)]
 pub struct PendingAnimations {
     pending: Vec<PendingAnimation>,
+    decoding: Vec<DecodeJob>,
     completed: Vec<(PendingAnimation, Handle<RoAnimationAsset>)>,
 }
 
@@ -71,15 +83,44 @@ impl PendingAnimations {
         self.completed.extend(items);
     }
 
-    /// Check if there are pending requests.
+    /// Check if there are requests waiting to load or decode.
     pub fn has_pending(&self) -> bool {
-        !self.pending.is_empty()
+        !self.pending.is_empty() || !self.decoding.is_empty()
+    }
+}
+
+/// Per-frame wall-clock budget for turning decoded pairs into assets on the main
+/// thread. Decoding runs on the compute pool, but adding a whole crowd's atlas
+/// pages in one frame would still hitch; decodes past the budget are finished on
+/// the following frames while their units show a `SpritePlaceholder`.
+#[derive(Resource, Debug, Clone)]
+pub struct AnimationBuildBudget {
+    pub frame_budget: Duration,
+}
+
+impl Default for AnimationBuildBudget {
+    fn default() -> Self {
+        Self {
+            frame_budget: Duration::from_millis(4),
+        }
     }
 }
 
-/// System that processes pending SPR+ACT pairs when both are loaded.
+/// At least one pair is finished every frame so an over-budget one can't stall the queue.
+fn within_budget(finished: usize, elapsed: Duration, budget: Duration) -> bool {
+    finished == 0 || elapsed < budget
+}
+
+/// System that hands loaded SPR+ACT pairs to the compute pool for decoding and
+/// turns finished decodes into animations, stopping once the frame's
+/// `AnimationBuildBudget` is spent. A pair already in the [`SpriteTextureCache`]
+/// completes at once with the cached animation; a pair already being decoded
+/// is decoded once and waited on by every request for it.
+#[allow(clippy::too_many_arguments)]
 pub fn process_pending_animations(
     mut pending: ResMut<PendingAnimations>,
+    mut cache: ResMut<SpriteTextureCache>,
+    budget: Res<AnimationBuildBudget>,
     sprites: Res<Assets<RoSpriteAsset>>,
     actions: Res<Assets<RoActAsset>>,
     mut animations: ResMut<Assets<RoAnimationAsset>>,
@@ -87,33 +128,57 @@ pub fn process_pending_animations(
     settings: Res<Persistent<Settings>>,
 ) {
     let upscaling = settings.graphics.upscaling;
-    let mut still_pending = Vec::new();
     let mut newly_completed = Vec::new();
+    let mut finished = 0;
+    let started = Instant::now();
+
+    // Finish decodes first, so requests waiting on the same pair hit the cache.
+    let mut decoding = std::mem::take(&mut pending.decoding);
+    decoding.retain_mut(|job| {
+        if !within_budget(finished, started.elapsed(), budget.frame_budget) {
+            return true;
+        }
+        let Some(decoded) = block_on(poll_once(&mut job.task)) else {
+            return true;
+        };
+        let bytes = decoded.bytes();
+        let handle = animations.add(decoded.into_asset(&mut images));
+        cache.insert(job.key, handle.clone(), bytes);
+        newly_completed.push((job.request.clone(), handle));
+        finished += 1;
+        false
+    });
 
+    let mut still_pending = Vec::new();
     for request in std::mem::take(&mut pending.pending) {
-        let sprite_ready = sprites.get(&request.sprite_handle).is_some();
-        let action_ready = actions.get(&request.action_handle).is_some();
-
-        if sprite_ready && action_ready {
-            let sprite = sprites.get(&request.sprite_handle).unwrap();
-            let action = actions.get(&request.action_handle).unwrap();
-
-            let animation = RoAnimationProcessor::process(
-                &sprite.sprite,
-                &action.action,
-                request.layer_tag,
-                &mut images,
-                upscaling,
-            );
-
-            let handle = animations.add(animation);
-            newly_completed.push((request, handle));
-        } else {
+        let key = SpriteCacheKey::new(&request, upscaling);
+        if decoding.iter().any(|job| job.key == key) {
             still_pending.push(request);
+            continue;
         }
+        if let Some(handle) = cache.get(&key) {
+            newly_completed.push((request, handle));
+            continue;
+        }
+
+        let (Some(sprite), Some(action)) = (
+            sprites.get(&request.sprite_handle),
+            actions.get(&request.action_handle),
+        ) else {
+            still_pending.push(request);
+            continue;
+        };
+        let sprite = sprite.sprite.clone();
+        let action = action.action.clone();
+        let layer_tag = request.layer_tag;
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            RoAnimationProcessor::decode(&sprite, &action, layer_tag, upscaling)
+        });
+        decoding.push(DecodeJob { key, request, task });
     }
 
     pending.pending = still_pending;
+    pending.decoding = decoding;
     pending.completed.extend(newly_completed);
 }
 
@@ -124,10 +189,30 @@ impl Plugin for AnimationProcessingPlugin {
     fn build(&self, app: &mut App) {
         // Gated so the Assets<Image> ResMut access doesn't serialize the
         // schedule on every frame where nothing is queued (the steady state).
-        app.init_resource::<PendingAnimations>().add_systems(
-            Update,
-            process_pending_animations
-                .run_if(|pending: Res<PendingAnimations>| pending.has_pending()),
-        );
+        app.init_resource::<PendingAnimations>()
+            .init_resource::<AnimationBuildBudget>()
+            .init_resource::<SpriteTextureCache>()
+            .add_systems(
+                Update,
+                (
+                    process_pending_animations
+                        .run_if(|pending: Res<PendingAnimations>| pending.has_pending()),
+                    evict_sprite_textures,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_finish_of_a_frame_ignores_the_budget() {
+        let budget = Duration::from_millis(4);
+        assert!(within_budget(0, Duration::from_millis(50), budget));
+        assert!(within_budget(3, Duration::from_millis(1), budget));
+        assert!(!within_budget(1, Duration::from_millis(4), budget));
     }
 }
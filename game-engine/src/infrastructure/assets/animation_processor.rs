@@ -1,18 +1,52 @@
-use bevy::asset::RenderAssetUsages;
 use bevy::prelude::*;
-use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use moonshine_tag::Tag;
 
 use crate::domain::settings::resources::Upscaling;
+use crate::domain::sprite::tags::{LAYER_BODY, LAYER_HEAD};
 use crate::infrastructure::ro_formats::act::{Layer, RoAction};
 use crate::infrastructure::ro_formats::sprite::{Palette, RoSprite, SpriteFrame};
 
 use super::converters::{apply_magenta_transparency, convert_sprite_frame_to_rgba};
 use super::ro_animation_asset::{ActionData, FrameData, FramePart, RoAnimationAsset};
+use super::sprite_atlas::{AtlasRegion, build_atlas, build_index_atlas};
 use super::upscale;
 
 pub struct RoAnimationProcessor;
 
+/// An SPR+ACT pair decoded into atlas pages that aren't assets yet. Decoding
+/// needs no `World` access, so it can run on a worker thread; only
+/// [`DecodedAnimation::into_asset`] has to happen on the main thread.
+pub struct DecodedAnimation {
+    atlas_pages: Vec<Image>,
+    index_pages: Vec<Image>,
+    /// Everything but the page handles.
+    animation: RoAnimationAsset,
+}
+
+impl DecodedAnimation {
+    /// Texture memory of the atlas and index pages.
+    pub fn bytes(&self) -> u64 {
+        self.atlas_pages
+            .iter()
+            .chain(&self.index_pages)
+            .filter_map(|image| image.data.as_ref())
+            .map(|data| data.len() as u64)
+            .sum()
+    }
+
+    /// Adds the pages to `images` and returns the finished animation.
+    pub fn into_asset(self, images: &mut Assets<Image>) -> RoAnimationAsset {
+        let add = |pages: Vec<Image>, images: &mut Assets<Image>| -> Vec<Handle<Image>> {
+            pages.into_iter().map(|page| images.add(page)).collect()
+        };
+        RoAnimationAsset {
+            atlas_pages: add(self.atlas_pages, images),
+            index_pages: add(self.index_pages, images),
+            ..self.animation
+        }
+    }
+}
+
 impl RoAnimationProcessor {
     /// Process a single SPR+ACT pair into a RoAnimationAsset.
     /// Each layer (body, head, weapon) is processed separately.
@@ -23,47 +57,85 @@ impl RoAnimationProcessor {
         images: &mut Assets<Image>,
         upscaling: Upscaling,
     ) -> RoAnimationAsset {
-        let textures = Self::create_textures(sprite, images, upscaling);
+        Self::decode(sprite, action, layer_tag, upscaling).into_asset(images)
+    }
+
+    /// Convert and pack an SPR+ACT pair without touching any asset storage.
+    pub fn decode(
+        sprite: &RoSprite,
+        action: &RoAction,
+        layer_tag: Tag,
+        upscaling: Upscaling,
+    ) -> DecodedAnimation {
+        let (atlas_pages, frame_regions) = Self::create_atlas(sprite, upscaling);
+        let (index_pages, index_regions) = if layer_tag == LAYER_BODY || layer_tag == LAYER_HEAD {
+            Self::create_index_atlas(sprite)
+        } else {
+            (Vec::new(), Vec::new())
+        };
         let actions = Self::create_actions(action, sprite);
 
-        RoAnimationAsset {
-            textures,
-            actions,
-            layer: layer_tag,
-            sounds: action.sounds.clone(),
+        DecodedAnimation {
+            atlas_pages,
+            index_pages,
+            animation: RoAnimationAsset {
+                atlas_pages: Vec::new(),
+                frame_regions,
+                index_pages: Vec::new(),
+                index_regions,
+                actions,
+                layer: layer_tag,
+                sounds: action.sounds.clone(),
+            },
         }
     }
 
-    /// Convert all sprite frames to GPU textures once during loading.
-    fn create_textures(
-        sprite: &RoSprite,
-        images: &mut Assets<Image>,
-        upscaling: Upscaling,
-    ) -> Vec<Handle<Image>> {
-        let handles: Vec<_> = sprite
+    /// Convert all sprite frames and pack them into atlas pages once during
+    /// loading.
+    fn create_atlas(sprite: &RoSprite, upscaling: Upscaling) -> (Vec<Image>, Vec<AtlasRegion>) {
+        let frames: Vec<_> = sprite
+            .frames
+            .iter()
+            .map(|frame| Self::frame_pixels(frame, sprite.palette.as_ref(), upscaling))
+            .collect();
+        let (pages, regions) = build_atlas(&frames);
+        bevy::log::debug!(
+            "create_atlas: Packed {} frames into {} atlas page(s)",
+            regions.len(),
+            pages.len()
+        );
+        (pages, regions)
+    }
+
+    /// Pack the raw palette indices of the indexed frames for the dye material.
+    /// Body and head are the only layers that take dyes, so only they pay for
+    /// these pages. Indices can't be interpolated, so they are never upscaled.
+    fn create_index_atlas(sprite: &RoSprite) -> (Vec<Image>, Vec<AtlasRegion>) {
+        if sprite.indexed_count == 0 {
+            return (Vec::new(), Vec::new());
+        }
+        let frames: Vec<_> = sprite
             .frames
             .iter()
             .map(|frame| {
-                let image = Self::frame_to_image(frame, sprite.palette.as_ref(), upscaling);
-                images.add(image)
+                if frame.is_rgba {
+                    (Vec::new(), UVec2::ZERO)
+                } else {
+                    let mut indices = frame.data.clone();
+                    indices.resize(frame.width as usize * frame.height as usize, 0);
+                    (indices, UVec2::new(frame.width as u32, frame.height as u32))
+                }
             })
             .collect();
-        if let Some(first) = handles.first() {
-            bevy::log::debug!(
-                "create_textures: Created {} textures, first handle: {:?}",
-                handles.len(),
-                first
-            );
-        }
-        handles
+        build_index_atlas(&frames)
     }
 
-    /// Convert a sprite frame to a Bevy Image.
-    fn frame_to_image(
+    /// Convert a sprite frame to (upscaled) RGBA pixels and their size.
+    fn frame_pixels(
         frame: &SpriteFrame,
         palette: Option<&Palette>,
         upscaling: Upscaling,
-    ) -> Image {
+    ) -> (Vec<u8>, UVec2) {
         let mut rgba_data = convert_sprite_frame_to_rgba(frame, palette, None);
         apply_magenta_transparency(&mut rgba_data);
 
@@ -73,18 +145,7 @@ impl RoAnimationProcessor {
             frame.height as u32,
             upscaling,
         );
-
-        Image::new(
-            Extent3d {
-                width,
-                height,
-                depth_or_array_layers: 1,
-            },
-            TextureDimension::D2,
-            rgba_data,
-            TextureFormat::Rgba8UnormSrgb,
-            RenderAssetUsages::default(),
-        )
+        (rgba_data, UVec2::new(width, height))
     }
 
     /// Create ActionData for each action in the ACT file.
@@ -273,6 +334,15 @@ mod tests {
         );
     }
 
+    fn indexed_frame(width: u16, height: u16) -> SpriteFrame {
+        SpriteFrame {
+            width,
+            height,
+            data: vec![1; width as usize * height as usize],
+            is_rgba: false,
+        }
+    }
+
     fn rgba_frame(width: u16, height: u16) -> SpriteFrame {
         SpriteFrame {
             width,
@@ -283,19 +353,81 @@ mod tests {
     }
 
     #[test]
-    fn frame_to_image_keeps_extent_when_off() {
+    fn frame_pixels_keep_extent_when_off() {
         let frame = rgba_frame(2, 2);
-        let image = RoAnimationProcessor::frame_to_image(&frame, None, Upscaling::Off);
-        assert_eq!(image.texture_descriptor.size.width, 2);
-        assert_eq!(image.texture_descriptor.size.height, 2);
+        let (_, size) = RoAnimationProcessor::frame_pixels(&frame, None, Upscaling::Off);
+        assert_eq!(size, UVec2::new(2, 2));
     }
 
     #[test]
-    fn frame_to_image_scales_pixels_but_not_logical_size() {
+    fn frame_pixels_scale_pixels_but_not_logical_size() {
         let frame = rgba_frame(2, 2);
-        let image = RoAnimationProcessor::frame_to_image(&frame, None, Upscaling::X2);
-        assert_eq!(image.texture_descriptor.size.width, 4);
-        assert_eq!(image.texture_descriptor.size.height, 4);
+        let (rgba, size) = RoAnimationProcessor::frame_pixels(&frame, None, Upscaling::X2);
+        assert_eq!(size, UVec2::new(4, 4));
+        assert_eq!(rgba.len(), 4 * 4 * 4);
         assert_eq!((frame.width, frame.height), (2, 2));
     }
+
+    #[test]
+    fn every_frame_gets_an_atlas_region() {
+        let sprite = RoSprite {
+            version: 2.0,
+            indexed_count: 0,
+            rgba_count: 2,
+            frames: vec![rgba_frame(2, 2), rgba_frame(3, 1)],
+            palette: None,
+        };
+        let (pages, regions) = RoAnimationProcessor::create_atlas(&sprite, Upscaling::Off);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[1].rect.size(), UVec2::new(3, 1));
+    }
+
+    #[test]
+    fn index_atlas_covers_only_indexed_frames() {
+        let sprite = RoSprite {
+            version: 2.0,
+            indexed_count: 1,
+            rgba_count: 1,
+            frames: vec![indexed_frame(2, 3), rgba_frame(2, 2)],
+            palette: None,
+        };
+        let (pages, regions) = RoAnimationProcessor::create_index_atlas(&sprite);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(regions[0].rect.size(), UVec2::new(2, 3));
+        assert!(regions[1].rect.is_empty());
+
+        let rgba_only = RoSprite {
+            indexed_count: 0,
+            frames: vec![rgba_frame(2, 2)],
+            ..sprite
+        };
+        let (pages, regions) = RoAnimationProcessor::create_index_atlas(&rgba_only);
+        assert!(pages.is_empty() && regions.is_empty());
+    }
+
+    #[test]
+    fn decoded_pages_become_assets_when_finished() {
+        let sprite = RoSprite {
+            version: 2.0,
+            indexed_count: 1,
+            rgba_count: 1,
+            frames: vec![indexed_frame(2, 3), rgba_frame(2, 2)],
+            palette: None,
+        };
+        let action = RoAction {
+            version: 2.0,
+            actions: Vec::new(),
+            sounds: vec!["atk.wav".into()],
+        };
+        let decoded = RoAnimationProcessor::decode(&sprite, &action, LAYER_BODY, Upscaling::Off);
+        assert!(decoded.bytes() > 0);
+
+        let mut images = Assets::<Image>::default();
+        let animation = decoded.into_asset(&mut images);
+        assert_eq!(animation.atlas_pages.len(), 1);
+        assert_eq!(animation.index_pages.len(), 1);
+        assert_eq!(images.len(), 2);
+        assert_eq!(animation.sounds, ["atk.wav"]);
+    }
 }
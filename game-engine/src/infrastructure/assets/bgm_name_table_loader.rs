@@ -1,9 +1,9 @@
+use crate::infrastructure::encoding::decode_euc_kr;
 use bevy::{
     asset::{Asset, AssetLoader, LoadContext, io::Reader},
     prelude::*,
     reflect::TypePath,
 };
-use encoding_rs::EUC_KR;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -40,8 +40,7 @@ impl AssetLoader for BgmNameTableLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
 
-        let (decoded, _, _) = EUC_KR.decode(&bytes);
-        let content = decoded.into_owned();
+        let content = decode_euc_kr(&bytes);
 
         let mut table = HashMap::new();
         let mut parsed_count = 0;
@@ -1,5 +1,6 @@
-use crate::domain::settings::resources::Upscaling;
+use crate::domain::settings::resources::{TextureCompression, Upscaling};
 use crate::infrastructure::assets::{converters::apply_magenta_transparency, upscale};
+use crate::utils::bcn::compress_image_bcn;
 use bevy::{
     asset::{AssetLoader, LoadContext, RenderAssetUsages, io::Reader},
     prelude::*,
@@ -14,6 +15,11 @@ pub struct BmpLoader;
 #[derive(Default, Serialize, Deserialize)]
 pub struct BmpLoaderSettings {
     pub upscale: Upscaling,
+    /// Block-compress the decoded texture. Only for textures that are sampled
+    /// as-is; anything post-processed on the CPU (e.g. mip generation) must
+    /// load uncompressed and compress afterwards.
+    #[serde(default)]
+    pub compress: TextureCompression,
 }
 
 #[derive(Debug, Error)]
@@ -147,7 +153,7 @@ impl AssetLoader for BmpLoader {
         let (rgba_data, width, height) =
             upscale::scale(&rgba_data, width, height, settings.upscale);
 
-        Ok(Image::new(
+        let mut image = Image::new(
             Extent3d {
                 width,
                 height,
@@ -157,7 +163,10 @@ impl AssetLoader for BmpLoader {
             rgba_data,
             TextureFormat::Rgba8UnormSrgb,
             RenderAssetUsages::default(),
-        ))
+        );
+        compress_image_bcn(&mut image, settings.compress);
+
+        Ok(image)
     }
 
     fn extensions(&self) -> &[&str] {
@@ -172,14 +181,23 @@ mod tests {
     #[test]
     fn settings_default_is_off() {
         assert_eq!(BmpLoaderSettings::default().upscale, Upscaling::Off);
+        assert_eq!(
+            BmpLoaderSettings::default().compress,
+            TextureCompression::Off
+        );
     }
 
     #[test]
     fn settings_serde_round_trips() {
         for upscale in Upscaling::ALL {
-            let encoded = ron::to_string(&BmpLoaderSettings { upscale }).expect("serialize");
+            let settings = BmpLoaderSettings {
+                upscale,
+                compress: TextureCompression::Bc,
+            };
+            let encoded = ron::to_string(&settings).expect("serialize");
             let decoded: BmpLoaderSettings = ron::from_str(&encoded).expect("deserialize");
             assert_eq!(decoded.upscale, upscale);
+            assert_eq!(decoded.compress, TextureCompression::Bc);
         }
     }
 }
@@ -1,6 +1,7 @@
+use crate::infrastructure::encoding::decode_euc_kr;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
 pub struct AssetConfig {
@@ -13,9 +14,17 @@ pub struct AssetsSection {
     pub data_folder: String,
     #[serde(default)]
     pub grf: Vec<GrfConfig>,
+    /// The reference client's GRF list, looked for next to the GRFs. Empty
+    /// turns the lookup off.
+    #[serde(default = "default_data_ini")]
+    pub data_ini: String,
+    /// An official client install mounted whole, under the data folder and
+    /// GRFs above.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ro_install: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GrfConfig {
     pub path: String,
     pub priority: u32,
@@ -25,6 +34,10 @@ fn default_data_folder() -> String {
     "./assets/data/".to_string()
 }
 
+fn default_data_ini() -> String {
+    "DATA.INI".to_string()
+}
+
 impl Default for AssetConfig {
     fn default() -> Self {
         Self {
@@ -34,6 +47,8 @@ impl Default for AssetConfig {
                     path: "data.grf".to_string(),
                     priority: 0,
                 }],
+                data_ini: default_data_ini(),
+                ro_install: None,
             },
         }
     }
@@ -44,10 +59,37 @@ impl AssetConfig {
         PathBuf::from(&self.assets.data_folder)
     }
 
-    pub fn grf_files_by_priority(&self) -> Vec<&GrfConfig> {
-        let mut grf_files: Vec<&GrfConfig> = self.assets.grf.iter().collect();
-        grf_files.sort_by_key(|grf| grf.priority);
-        grf_files
+    /// The `[[assets.grf]]` entries plus any GRF only `DATA.INI` lists, lowest
+    /// priority first.
+    pub fn grf_files_by_priority(&self) -> Vec<GrfConfig> {
+        let listed = self
+            .find_data_ini()
+            .and_then(|path| match std::fs::read(&path) {
+                Ok(bytes) => {
+                    let grfs = parse_data_ini(&decode_euc_kr(&bytes));
+                    debug!("{} lists {} GRF(s)", path.display(), grfs.len());
+                    Some(grfs)
+                }
+                Err(e) => {
+                    warn!("Could not read {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        merge_data_ini_grfs(&self.assets.grf, listed)
+    }
+
+    /// `data_ini` where GRF paths resolve (as given, then under `assets/`),
+    /// in its own spelling or lower case.
+    fn find_data_ini(&self) -> Option<PathBuf> {
+        let name = &self.assets.data_ini;
+        if name.is_empty() {
+            return None;
+        }
+        [Path::new(""), Path::new("assets")]
+            .into_iter()
+            .flat_map(|dir| [dir.join(name), dir.join(name.to_lowercase())])
+            .find(|path| path.is_file())
     }
 
     pub fn generate_default_config_content() -> String {
@@ -80,6 +122,52 @@ priority = 0
     }
 }
 
+/// The `[Data]` section of a reference-client `DATA.INI` (`0=rdata.grf`,
+/// `1=data.grf`, ...); the key is the GRF's priority.
+pub fn parse_data_ini(content: &str) -> Vec<GrfConfig> {
+    let mut in_data = false;
+    let mut grfs = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_data = section.trim().eq_ignore_ascii_case("data");
+            continue;
+        }
+        if !in_data || line.starts_with(';') {
+            continue;
+        }
+        let Some((key, path)) = line.split_once('=') else {
+            continue;
+        };
+        let (Ok(priority), path) = (key.trim().parse(), path.trim()) else {
+            continue;
+        };
+        if !path.is_empty() {
+            grfs.push(GrfConfig {
+                path: path.to_string(),
+                priority,
+            });
+        }
+    }
+    grfs
+}
+
+/// `configured` plus the `listed` GRFs it doesn't name, sorted by priority.
+/// The TOML overrides `DATA.INI`: its entries keep their own priority and
+/// come first on a tie.
+fn merge_data_ini_grfs(configured: &[GrfConfig], listed: Vec<GrfConfig>) -> Vec<GrfConfig> {
+    let mut grfs = configured.to_vec();
+    for grf in listed {
+        if !configured
+            .iter()
+            .any(|known| known.path.eq_ignore_ascii_case(&grf.path))
+        {
+            grfs.push(grf);
+        }
+    }
+    grfs.sort_by_key(|grf| grf.priority);
+    grfs
+}
+
 #[derive(Resource, Debug)]
 pub struct AssetConfigHandle {
     pub handle: Handle<AssetConfig>,
@@ -94,3 +182,37 @@ impl AssetConfigHandle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grf(path: &str, priority: u32) -> GrfConfig {
+        GrfConfig {
+            path: path.to_string(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn data_ini_lists_the_data_section_by_key() {
+        let ini = "[Data]\r\n0=rdata.grf\r\n; a comment\r\n1 = sdata.grf\r\n2=data.grf\r\n\
+                   [Other]\r\n3=ignored.grf\r\n";
+
+        assert_eq!(
+            parse_data_ini(ini),
+            vec![grf("rdata.grf", 0), grf("sdata.grf", 1), grf("data.grf", 2)]
+        );
+    }
+
+    #[test]
+    fn the_toml_overrides_data_ini() {
+        let configured = [grf("en.grf", 0), grf("DATA.GRF", 5)];
+        let listed = vec![grf("rdata.grf", 0), grf("data.grf", 1)];
+
+        assert_eq!(
+            merge_data_ini_grfs(&configured, listed),
+            vec![grf("en.grf", 0), grf("rdata.grf", 0), grf("DATA.GRF", 5)]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::infrastructure::assets::loaders::RoPaletteAsset;
 use crate::infrastructure::ro_formats::{Palette, sprite::SpriteFrame};
+use bevy::asset::RenderAssetUsages;
 use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use std::collections::HashSet;
 
 /// Convert indexed sprite data to RGBA using palette
@@ -88,10 +90,33 @@ pub fn convert_indexed_to_rgba_with_custom_palette(
 
 /// Convert a sprite frame to RGBA, handling both indexed and RGBA formats
 /// Supports custom palettes for hair colors and other customizations
+/// The result is always exactly `width * height` pixels: a corrupt frame whose
+/// pixel data is short (or long) is padded with transparency (or cut) instead
+/// of producing an image whose size doesn't match its data.
 pub fn convert_sprite_frame_to_rgba(
     frame: &SpriteFrame,
     default_palette: Option<&Palette>,
     custom_palette: Option<&RoPaletteAsset>,
+) -> Vec<u8> {
+    let mut rgba_data = convert_frame_pixels(frame, default_palette, custom_palette);
+    let expected_len = frame.width as usize * frame.height as usize * 4;
+    if rgba_data.len() != expected_len {
+        warn!(
+            "Sprite frame {}x{} has {} bytes of RGBA data, expected {}; resizing",
+            frame.width,
+            frame.height,
+            rgba_data.len(),
+            expected_len
+        );
+        rgba_data.resize(expected_len, 0);
+    }
+    rgba_data
+}
+
+fn convert_frame_pixels(
+    frame: &SpriteFrame,
+    default_palette: Option<&Palette>,
+    custom_palette: Option<&RoPaletteAsset>,
 ) -> Vec<u8> {
     if frame.is_rgba {
         // Already RGBA format - return as-is
@@ -145,3 +170,58 @@ pub fn apply_magenta_transparency(rgba_data: &mut [u8]) {
         }
     }
 }
+
+/// Turn a palette into the 256x1 lookup texture the palette-indexed sprite
+/// material reads. Index 0 and magenta entries are transparent, as in
+/// `convert_indexed_to_rgba`; missing entries are transparent black.
+pub fn palette_lookup_image(colors: &[[u8; 4]]) -> Image {
+    let mut data = Vec::with_capacity(256 * 4);
+    for index in 0..256 {
+        let color = colors.get(index).copied().unwrap_or([0, 0, 0, 0]);
+        let is_magenta = color[0] == 255 && color[1] == 0 && color[2] == 255;
+        let alpha = if index == 0 || is_magenta { 0 } else { 255 };
+        data.extend_from_slice(&[color[0], color[1], color[2], alpha]);
+    }
+    Image::new(
+        Extent3d {
+            width: 256,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_indexed_frames_are_padded_to_their_size() {
+        let frame = SpriteFrame {
+            width: 2,
+            height: 2,
+            data: vec![1, 2, 3],
+            is_rgba: false,
+        };
+        let rgba = convert_sprite_frame_to_rgba(&frame, Some(&Palette::grayscale()), None);
+        assert_eq!(rgba.len(), 16);
+        assert_eq!(&rgba[8..], &[3, 3, 3, 255, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn palette_lookup_keys_out_index_zero_and_magenta() {
+        let mut colors = vec![[10, 20, 30, 0]; 3];
+        colors[2] = [255, 0, 255, 0];
+        let image = palette_lookup_image(&colors);
+        assert_eq!(image.texture_descriptor.size.width, 256);
+        let data = image.data.as_ref().unwrap();
+        assert_eq!(&data[0..4], &[10, 20, 30, 0]);
+        assert_eq!(&data[4..8], &[10, 20, 30, 255]);
+        assert_eq!(data[11], 0);
+        assert_eq!(&data[12..16], &[0, 0, 0, 0]);
+    }
+}
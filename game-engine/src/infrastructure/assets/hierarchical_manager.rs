@@ -1,6 +1,6 @@
 use super::{
     AssetConfig,
-    sources::{AssetSource, CompositeAssetSource},
+    sources::{AssetLookupStats, AssetSource, CompositeAssetSource},
 };
 use bevy::log::error;
 use bevy::prelude::*;
@@ -42,6 +42,17 @@ impl HierarchicalAssetManager {
         Ok(())
     }
 
+    /// Per-source hit/miss counters and negative-cache hits.
+    pub fn lookup_stats(&self) -> AssetLookupStats {
+        match self.composite_source.read() {
+            Ok(composite) => composite.lookup_stats(),
+            Err(e) => {
+                error!("Failed to acquire read lock for lookup_stats: {}", e);
+                AssetLookupStats::default()
+            }
+        }
+    }
+
     pub fn list_files(&self) -> Vec<String> {
         match self.composite_source.read() {
             Ok(composite) => composite.list_files(),
@@ -1,9 +1,9 @@
+use crate::infrastructure::encoding::decode_euc_kr;
 use bevy::{
     asset::{Asset, AssetLoader, LoadContext, io::Reader},
     prelude::*,
     reflect::TypePath,
 };
-use encoding_rs::EUC_KR;
 use std::collections::HashSet;
 use thiserror::Error;
 
@@ -41,7 +41,7 @@ impl AssetLoader for IndoorMapTableLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
 
-        let (decoded, _, _) = EUC_KR.decode(&bytes);
+        let decoded = decode_euc_kr(&bytes);
         let maps = parse_indoor_table(&decoded);
 
         debug!("Indoor map table loaded: {} indoor maps", maps.len());
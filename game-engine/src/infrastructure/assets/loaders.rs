@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use bevy::{
-    asset::{Asset, AssetLoader, LoadContext, io::Reader},
+    asset::{Asset, AssetLoader, AssetPath, LoadContext, io::Reader},
     prelude::*,
     reflect::TypePath,
 };
@@ -11,6 +13,8 @@ use crate::infrastructure::ro_formats::{
     parse_act, parse_spr as parse_sprite,
 };
 
+use super::palette_fallback::{PaletteFallback, repair_sprite_palette};
+
 // Re-export BGM name table types
 pub use super::bgm_name_table_loader::{
     BgmNameTableAsset, BgmNameTableLoader, BgmNameTableLoaderError,
@@ -18,12 +22,15 @@ pub use super::bgm_name_table_loader::{
 
 #[derive(Asset, TypePath, Debug)]
 pub struct RoSpriteAsset {
-    pub sprite: ParsedRoSprite,
+    /// Shared so animation decoding can take it to a worker thread uncopied.
+    pub sprite: Arc<ParsedRoSprite>,
+    /// Set when the sprite's own palette was unusable and got replaced.
+    pub palette_fallback: Option<PaletteFallback>,
 }
 
 #[derive(Asset, TypePath, Debug)]
 pub struct RoActAsset {
-    pub action: ParsedRoAction,
+    pub action: Arc<ParsedRoAction>,
 }
 
 #[derive(Asset, TypePath, Debug, Clone)]
@@ -153,12 +160,24 @@ impl AssetLoader for RoSpriteLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &Self::Settings,
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        let sprite = parse_sprite(&bytes)?;
-        Ok(RoSpriteAsset { sprite })
+        let mut sprite = parse_sprite(&bytes)?;
+
+        // Only go looking for `<name>.pal` next to the sprite when its own
+        // palette can't be used.
+        let sibling = match sprite.palette_defect() {
+            Some(_) => read_sibling_palette(load_context).await,
+            None => None,
+        };
+        let palette_fallback = repair_sprite_palette(&mut sprite, sibling.as_deref());
+
+        Ok(RoSpriteAsset {
+            sprite: Arc::new(sprite),
+            palette_fallback,
+        })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -166,6 +185,17 @@ impl AssetLoader for RoSpriteLoader {
     }
 }
 
+/// Raw bytes of the `<name>.pal` next to the sprite being loaded, if any.
+async fn read_sibling_palette(load_context: &mut LoadContext<'_>) -> Option<Vec<u8>> {
+    let path = load_context.path().clone();
+    let stem = path.path().file_stem()?.to_string_lossy();
+    let pal = AssetPath::from(format!("{stem}.pal"));
+    load_context
+        .read_asset_bytes(path.resolve_embed(&pal))
+        .await
+        .ok()
+}
+
 impl AssetLoader for RoActLoader {
     type Asset = RoActAsset;
     type Settings = ();
@@ -180,7 +210,9 @@ impl AssetLoader for RoActLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         let action = parse_act(&bytes)?;
-        Ok(RoActAsset { action })
+        Ok(RoActAsset {
+            action: Arc::new(action),
+        })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -9,10 +9,15 @@ pub mod hierarchical_reader;
 pub mod indoor_map_table_loader;
 pub mod loaders;
 pub mod loading_states;
+pub mod palette_fallback;
+pub mod preload;
 pub mod ro_animation_asset;
 pub mod ro_asset_source;
 pub mod ro_assets_plugin;
+pub mod ro_install;
 pub mod sources;
+pub mod sprite_atlas;
+pub mod sprite_cache;
 pub mod svg_loader;
 pub mod tga_loader;
 pub mod upscale;
@@ -31,5 +36,15 @@ pub use loaders::{
     RoPaletteLoader, RoSpriteAsset, RoSpriteLoader, RoWorldAsset, RoWorldLoader, RsmAsset,
     RsmLoader,
 };
+pub use palette_fallback::{
+    PaletteFallback, PaletteSource, SpritePaletteFallbackUsed, report_sprite_palette_fallbacks,
+};
+pub use preload::{
+    AssetLoadError, AssetLoadErrors, PreloadAppExt, PreloadEntry, PreloadFailure, PreloadFinished,
+    PreloadKind, PreloadManifest, PreloadPlugin, PreloadProgress, StatePreload,
+};
 pub use ro_animation_asset::{ActionData, FrameData, FramePart, RoAnimationAsset};
 pub use ro_assets_plugin::SharedCompositeAssetSource;
+pub use ro_install::RoInstallPlugin;
+pub use sprite_atlas::{AtlasRegion, atlas_uv_transform};
+pub use sprite_cache::{SpriteCacheStats, SpriteTextureCache};
@@ -0,0 +1,146 @@
+//! Fallback palettes for sprites whose own palette is missing or broken.
+//!
+//! Custom sprites sometimes ship with an all-black or truncated palette, or
+//! none at all, and draw as black silhouettes. The SPR loader swaps in the
+//! sibling `<name>.pal` when there is a usable one, else a grayscale ramp, and
+//! records what it did on the asset; `report_sprite_palette_fallbacks` turns
+//! that into a warning and a `SpritePaletteFallbackUsed` message once the
+//! sprite is loaded.
+
+use bevy::prelude::*;
+
+use super::loaders::RoSpriteAsset;
+use crate::infrastructure::ro_formats::{Palette, PaletteDefect, RoSprite};
+
+/// Where a replacement palette came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteSource {
+    /// The `.pal` file next to the sprite.
+    Sibling,
+    Grayscale,
+}
+
+/// What the loader had to do to make a sprite's indexed frames drawable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteFallback {
+    pub defect: PaletteDefect,
+    pub source: PaletteSource,
+}
+
+/// A sprite was loaded with a replacement palette.
+#[derive(Message, Debug, Clone)]
+pub struct SpritePaletteFallbackUsed {
+    pub sprite: AssetId<RoSpriteAsset>,
+    pub path: Option<String>,
+    pub fallback: PaletteFallback,
+}
+
+/// Replace an unusable palette with `sibling` (the raw bytes of the sprite's
+/// `.pal`, if one was found) or a grayscale ramp. Returns `None` and leaves the
+/// sprite alone when its palette is fine.
+pub fn repair_sprite_palette(
+    sprite: &mut RoSprite,
+    sibling: Option<&[u8]>,
+) -> Option<PaletteFallback> {
+    let defect = sprite.palette_defect()?;
+    let (palette, source) = match sibling
+        .and_then(Palette::from_pal_bytes)
+        .filter(|palette| palette.defect().is_none())
+    {
+        Some(palette) => (palette, PaletteSource::Sibling),
+        None => (Palette::grayscale(), PaletteSource::Grayscale),
+    };
+    sprite.palette = Some(palette);
+    Some(PaletteFallback { defect, source })
+}
+
+/// Warn about, and announce, every newly loaded sprite that needed a fallback.
+pub fn report_sprite_palette_fallbacks(
+    mut asset_events: MessageReader<AssetEvent<RoSpriteAsset>>,
+    sprites: Res<Assets<RoSpriteAsset>>,
+    asset_server: Res<AssetServer>,
+    mut fallbacks: MessageWriter<SpritePaletteFallbackUsed>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::Added { id } = event else {
+            continue;
+        };
+        let Some(fallback) = sprites.get(*id).and_then(|sprite| sprite.palette_fallback) else {
+            continue;
+        };
+
+        let path = asset_server.get_path(*id).map(|path| path.to_string());
+        warn!(
+            "Sprite {} has an unusable palette ({:?}); drawing it with a {:?} palette",
+            path.as_deref().unwrap_or("<unnamed>"),
+            fallback.defect,
+            fallback.source
+        );
+        fallbacks.write(SpritePaletteFallbackUsed {
+            sprite: *id,
+            path,
+            fallback,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::ro_formats::PALETTE_BYTES;
+
+    fn sprite_with(palette: Option<Palette>) -> RoSprite {
+        RoSprite {
+            version: 2.0,
+            indexed_count: 1,
+            rgba_count: 0,
+            frames: Vec::new(),
+            palette,
+        }
+    }
+
+    fn red_pal() -> Vec<u8> {
+        let mut bytes = vec![0u8; PALETTE_BYTES];
+        for (i, chunk) in bytes.chunks_exact_mut(4).enumerate() {
+            chunk[0] = i as u8;
+        }
+        bytes
+    }
+
+    #[test]
+    fn a_usable_palette_is_left_alone() {
+        let mut sprite = sprite_with(Palette::from_pal_bytes(&red_pal()));
+        assert_eq!(repair_sprite_palette(&mut sprite, None), None);
+        assert_eq!(sprite.palette.unwrap().colors[7], [7, 0, 0, 255]);
+    }
+
+    #[test]
+    fn a_black_palette_is_replaced_by_its_sibling() {
+        let mut sprite = sprite_with(Palette::from_pal_bytes(&[0; PALETTE_BYTES]));
+        let fallback = repair_sprite_palette(&mut sprite, Some(&red_pal()));
+        assert_eq!(
+            fallback,
+            Some(PaletteFallback {
+                defect: PaletteDefect::Flat,
+                source: PaletteSource::Sibling,
+            })
+        );
+        assert_eq!(sprite.palette.unwrap().colors[7], [7, 0, 0, 255]);
+    }
+
+    #[test]
+    fn without_a_usable_sibling_the_palette_goes_grayscale() {
+        for sibling in [None, Some(&[0u8; 12][..]), Some(&[0u8; PALETTE_BYTES][..])] {
+            let mut sprite = sprite_with(None);
+            let fallback = repair_sprite_palette(&mut sprite, sibling);
+            assert_eq!(
+                fallback,
+                Some(PaletteFallback {
+                    defect: PaletteDefect::Missing,
+                    source: PaletteSource::Grayscale,
+                })
+            );
+            assert_eq!(sprite.palette.unwrap().colors[7], [7, 7, 7, 255]);
+        }
+    }
+}
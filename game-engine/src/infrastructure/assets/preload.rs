@@ -0,0 +1,569 @@
+//! Per-state asset preloading.
+//!
+//! [`PreloadManifest`] lists what each `GameState` needs up front: login
+//! textures, the current map's files, and so on. Entering a state starts its
+//! entries loading. [`PreloadProgress`] is reported as they settle and
+//! [`PreloadFinished`] once all have, and the handles are held until the next
+//! state's batch replaces them. In `GameState::Loading` the batch also counts
+//! toward the loading screen's progress, so the game can't enter a map whose
+//! assets are still in flight. `ro://` entries that no asset source has fail
+//! immediately instead of going through the loader.
+//!
+//! Character-selection sprites depend on the roster, so they are warmed by
+//! `domain::character::preload` rather than listed here.
+//!
+//! Every failure — a configured GRF that couldn't be mounted, a preload entry
+//! that couldn't load — is also sent as an [`AssetLoadError`] naming the
+//! source, path and error, and kept in [`AssetLoadErrors`]. The client keeps
+//! running on whatever did load; the login screen lists the broken sources.
+
+use std::collections::HashMap;
+
+use bevy::asset::{LoadState, UntypedHandle};
+use bevy::prelude::*;
+use iyes_progress::prelude::*;
+
+use super::loaders::{
+    RoActAsset, RoAltitudeAsset, RoGroundAsset, RoPaletteAsset, RoSpriteAsset, RoWorldAsset,
+};
+use super::ro_assets_plugin::SharedCompositeAssetSource;
+use super::sources::{AssetSource, SourceSetupError};
+use crate::core::state::GameState;
+use crate::domain::assets::patterns;
+use crate::domain::world::spawn_context::MapSpawnContext;
+use crate::presentation::ui::events::{DialogSeverity, ShowSystemDialog, SystemDialogKind};
+
+/// Stands for the current map's name (no extension) in manifest paths.
+pub const MAP_PLACEHOLDER: &str = "{map}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreloadKind {
+    Texture,
+    Font,
+    Sprite,
+    Action,
+    Palette,
+    Ground,
+    Altitude,
+    World,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadEntry {
+    pub kind: PreloadKind,
+    pub path: String,
+}
+
+/// What to load on entering each state.
+#[derive(Resource, Debug, Clone)]
+pub struct PreloadManifest {
+    states: HashMap<GameState, Vec<PreloadEntry>>,
+}
+
+impl Default for PreloadManifest {
+    fn default() -> Self {
+        Self::empty()
+            .with(
+                GameState::Loading,
+                PreloadKind::Ground,
+                "ro://data/{map}.gnd",
+            )
+            .with(
+                GameState::Loading,
+                PreloadKind::Altitude,
+                "ro://data/{map}.gat",
+            )
+            .with(
+                GameState::Loading,
+                PreloadKind::World,
+                "ro://data/{map}.rsw",
+            )
+            .with(
+                GameState::Loading,
+                PreloadKind::Sprite,
+                patterns::emotion_sprite_path(),
+            )
+            .with(
+                GameState::Loading,
+                PreloadKind::Action,
+                patterns::emotion_action_path(),
+            )
+    }
+}
+
+impl PreloadManifest {
+    pub fn empty() -> Self {
+        Self {
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn with(mut self, state: GameState, kind: PreloadKind, path: impl Into<String>) -> Self {
+        self.add(state, kind, path);
+        self
+    }
+
+    pub fn add(&mut self, state: GameState, kind: PreloadKind, path: impl Into<String>) {
+        let entry = PreloadEntry {
+            kind,
+            path: path.into(),
+        };
+        let entries = self.states.entry(state).or_default();
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    pub fn entries(&self, state: &GameState) -> &[PreloadEntry] {
+        self.states.get(state).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Lets other plugins add their own assets to the manifest.
+pub trait PreloadAppExt {
+    fn preload(
+        &mut self,
+        state: GameState,
+        kind: PreloadKind,
+        path: impl Into<String>,
+    ) -> &mut Self;
+}
+
+impl PreloadAppExt for App {
+    fn preload(
+        &mut self,
+        state: GameState,
+        kind: PreloadKind,
+        path: impl Into<String>,
+    ) -> &mut Self {
+        self.init_resource::<PreloadManifest>();
+        self.world_mut()
+            .resource_mut::<PreloadManifest>()
+            .add(state, kind, path);
+        self
+    }
+}
+
+/// Sent whenever another entry of the current batch settles.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct PreloadProgress {
+    pub state: GameState,
+    pub done: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadFailure {
+    pub entry: PreloadEntry,
+    pub reason: String,
+}
+
+/// One asset or asset source that couldn't be loaded.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AssetLoadError {
+    /// The configured source (`data.grf`), or the kind of asset a preload
+    /// entry is.
+    pub source: String,
+    pub path: String,
+    pub error: String,
+}
+
+impl std::fmt::Display for AssetLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} at {}", self.source, self.error, self.path)
+    }
+}
+
+impl From<&SourceSetupError> for AssetLoadError {
+    fn from(error: &SourceSetupError) -> Self {
+        Self {
+            source: error.source.clone(),
+            path: error.path.clone(),
+            error: error.error.clone(),
+        }
+    }
+}
+
+/// Every [`AssetLoadError`] so far, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct AssetLoadErrors(pub Vec<AssetLoadError>);
+
+/// Every entry of the batch has loaded or failed.
+#[derive(Message, Debug, Clone)]
+pub struct PreloadFinished {
+    pub state: GameState,
+    pub failed: Vec<PreloadFailure>,
+}
+
+struct PreloadItem {
+    entry: PreloadEntry,
+    handle: Option<UntypedHandle>,
+    outcome: Option<Result<(), String>>,
+}
+
+/// The current state's batch.
+#[derive(Resource, Default)]
+pub struct StatePreload {
+    state: Option<GameState>,
+    items: Vec<PreloadItem>,
+    reported: Option<u32>,
+    finished: bool,
+}
+
+impl StatePreload {
+    pub fn progress(&self) -> Progress {
+        Progress {
+            done: self
+                .items
+                .iter()
+                .filter(|item| item.outcome.is_some())
+                .count() as u32,
+            total: self.items.len() as u32,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub struct PreloadPlugin;
+
+impl Plugin for PreloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PreloadManifest>()
+            .init_resource::<StatePreload>()
+            .init_resource::<AssetLoadErrors>()
+            .add_message::<PreloadProgress>()
+            .add_message::<PreloadFinished>()
+            .add_message::<AssetLoadError>()
+            .add_systems(Startup, report_source_setup_errors)
+            .add_systems(OnEnter(GameState::Login), show_source_setup_errors)
+            .add_systems(Last, collect_asset_load_errors)
+            .add_systems(
+                Update,
+                (
+                    start_state_preload,
+                    report_state_preload,
+                    track_loading_preload
+                        .track_progress::<GameState>()
+                        .run_if(in_state(GameState::Loading)),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// `entry` with the map placeholder filled in, or `None` when it needs a map
+/// and there is none.
+fn resolve_entry(entry: &PreloadEntry, map_name: Option<&str>) -> Option<PreloadEntry> {
+    if !entry.path.contains(MAP_PLACEHOLDER) {
+        return Some(entry.clone());
+    }
+    let map_name = map_name?.trim_end_matches(".gat");
+    Some(PreloadEntry {
+        kind: entry.kind,
+        path: entry.path.replace(MAP_PLACEHOLDER, map_name),
+    })
+}
+
+/// Whether an asset source can provide `path`. Only `ro://` paths are checked.
+fn source_has(sources: &SharedCompositeAssetSource, path: &str) -> bool {
+    let Some(relative) = path.strip_prefix("ro://") else {
+        return true;
+    };
+    sources
+        .0
+        .read()
+        .map_or(true, |composite| composite.exists(relative))
+}
+
+fn load_entry(asset_server: &AssetServer, entry: &PreloadEntry) -> UntypedHandle {
+    let path = entry.path.clone();
+    match entry.kind {
+        PreloadKind::Texture => asset_server.load::<Image>(path).untyped(),
+        PreloadKind::Font => asset_server.load::<Font>(path).untyped(),
+        PreloadKind::Sprite => asset_server.load::<RoSpriteAsset>(path).untyped(),
+        PreloadKind::Action => asset_server.load::<RoActAsset>(path).untyped(),
+        PreloadKind::Palette => asset_server.load::<RoPaletteAsset>(path).untyped(),
+        PreloadKind::Ground => asset_server.load::<RoGroundAsset>(path).untyped(),
+        PreloadKind::Altitude => asset_server.load::<RoAltitudeAsset>(path).untyped(),
+        PreloadKind::World => asset_server.load::<RoWorldAsset>(path).untyped(),
+    }
+}
+
+/// How a load ended, or `None` while it is still in progress.
+fn settled(state: LoadState) -> Option<Result<(), String>> {
+    match state {
+        LoadState::Loaded => Some(Ok(())),
+        LoadState::Failed(err) => Some(Err(err.to_string())),
+        LoadState::NotLoaded | LoadState::Loading => None,
+    }
+}
+
+/// Replace the batch with the new state's manifest on every state change.
+pub fn start_state_preload(
+    state: Res<State<GameState>>,
+    manifest: Res<PreloadManifest>,
+    map: Option<Res<MapSpawnContext>>,
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    asset_server: Res<AssetServer>,
+    mut preload: ResMut<StatePreload>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let map_name = map.as_deref().map(|map| map.map_name.as_str());
+    let items: Vec<PreloadItem> = manifest
+        .entries(state.get())
+        .iter()
+        .filter_map(|entry| resolve_entry(entry, map_name))
+        .map(|entry| {
+            if sources
+                .as_deref()
+                .is_some_and(|sources| !source_has(sources, &entry.path))
+            {
+                return PreloadItem {
+                    entry,
+                    handle: None,
+                    outcome: Some(Err("not found in any asset source".to_string())),
+                };
+            }
+            PreloadItem {
+                handle: Some(load_entry(&asset_server, &entry)),
+                entry,
+                outcome: None,
+            }
+        })
+        .collect();
+
+    debug!("Preloading {} assets for {:?}", items.len(), state.get());
+    *preload = StatePreload {
+        state: Some(state.get().clone()),
+        items,
+        reported: None,
+        finished: false,
+    };
+}
+
+pub fn report_state_preload(
+    asset_server: Res<AssetServer>,
+    mut preload: ResMut<StatePreload>,
+    mut progress_writer: MessageWriter<PreloadProgress>,
+    mut finished_writer: MessageWriter<PreloadFinished>,
+    mut error_writer: MessageWriter<AssetLoadError>,
+) {
+    if preload.finished {
+        return;
+    }
+    let Some(state) = preload.state.clone() else {
+        return;
+    };
+
+    for item in &mut preload.items {
+        if item.outcome.is_none()
+            && let Some(handle) = &item.handle
+        {
+            item.outcome = settled(asset_server.load_state(handle));
+        }
+    }
+
+    let Progress { done, total } = preload.progress();
+    if preload.reported != Some(done) {
+        preload.reported = Some(done);
+        progress_writer.write(PreloadProgress {
+            state: state.clone(),
+            done,
+            total,
+        });
+    }
+    if done < total {
+        return;
+    }
+
+    preload.finished = true;
+    let failed: Vec<PreloadFailure> = preload
+        .items
+        .iter()
+        .filter_map(|item| match &item.outcome {
+            Some(Err(reason)) => Some(PreloadFailure {
+                entry: item.entry.clone(),
+                reason: reason.clone(),
+            }),
+            _ => None,
+        })
+        .collect();
+    for failure in &failed {
+        warn!(
+            "Preload for {:?}: {} failed: {}",
+            state, failure.entry.path, failure.reason
+        );
+        error_writer.write(AssetLoadError {
+            source: format!("{:?}", failure.entry.kind),
+            path: failure.entry.path.clone(),
+            error: failure.reason.clone(),
+        });
+    }
+    finished_writer.write(PreloadFinished { state, failed });
+}
+
+/// Sends the GRFs the asset config names but that couldn't be mounted.
+fn report_source_setup_errors(
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    mut errors: MessageWriter<AssetLoadError>,
+) {
+    let Some(composite) = sources.as_ref().and_then(|sources| sources.0.read().ok()) else {
+        return;
+    };
+    errors.write_batch(composite.setup_errors().iter().map(AssetLoadError::from));
+}
+
+fn collect_asset_load_errors(
+    mut errors: MessageReader<AssetLoadError>,
+    mut collected: ResMut<AssetLoadErrors>,
+) {
+    collected.0.extend(errors.read().cloned());
+}
+
+/// Tells the user, once, which asset sources are missing, since nearly
+/// everything past the login screen will look broken without them. The dialog
+/// offers to pick an RO install instead (see [`super::ro_install`]).
+fn show_source_setup_errors(
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    mut shown: Local<bool>,
+    mut dialogs: MessageWriter<ShowSystemDialog>,
+) {
+    let Some(composite) = sources.as_ref().and_then(|sources| sources.0.read().ok()) else {
+        return;
+    };
+    if *shown || composite.setup_errors().is_empty() {
+        return;
+    }
+    *shown = true;
+    let lines: Vec<String> = composite
+        .setup_errors()
+        .iter()
+        .map(|error| AssetLoadError::from(error).to_string())
+        .collect();
+    dialogs.write(ShowSystemDialog {
+        severity: DialogSeverity::Error,
+        kind: SystemDialogKind::GameDataSetup,
+        kicker: "Game data".into(),
+        title: "Some game data could not be loaded".into(),
+        message: format!(
+            "{}\n\nCheck the GRF paths in assets/loader.toml, or use the data of an \
+             installed Ragnarok Online client.",
+            lines.join("\n")
+        ),
+        code: String::new(),
+        button_label: "Choose RO folder".into(),
+        secondary_label: "Dismiss".into(),
+        confirm_state: None,
+        correlation: None,
+    });
+}
+
+/// The map loading screen waits for the `Loading` batch too.
+fn track_loading_preload(preload: Res<StatePreload>) -> Progress {
+    preload.progress()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::assets::loaders::RoPaletteLoader;
+    use bevy::asset::AssetPlugin;
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn map_entries_resolve_against_the_current_map() {
+        let entry = PreloadEntry {
+            kind: PreloadKind::Ground,
+            path: "ro://data/{map}.gnd".to_string(),
+        };
+        assert_eq!(
+            resolve_entry(&entry, Some("prontera.gat")).map(|e| e.path),
+            Some("ro://data/prontera.gnd".to_string())
+        );
+        assert_eq!(resolve_entry(&entry, None), None);
+
+        let fixed = PreloadEntry {
+            kind: PreloadKind::Texture,
+            path: "ro://logo.png".to_string(),
+        };
+        assert_eq!(resolve_entry(&fixed, None), Some(fixed));
+    }
+
+    #[test]
+    fn manifest_ignores_duplicate_entries() {
+        let manifest = PreloadManifest::empty()
+            .with(GameState::Login, PreloadKind::Texture, "ro://logo.png")
+            .with(GameState::Login, PreloadKind::Texture, "ro://logo.png");
+        assert_eq!(manifest.entries(&GameState::Login).len(), 1);
+        assert!(manifest.entries(&GameState::InGame).is_empty());
+    }
+
+    #[test]
+    fn a_batch_with_a_missing_file_still_finishes_and_names_it() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_asset::<RoPaletteAsset>()
+        .init_asset_loader::<RoPaletteLoader>()
+        .init_state::<GameState>()
+        .insert_resource(PreloadManifest::empty().with(
+            GameState::Loading,
+            PreloadKind::Palette,
+            "missing/palette.pal",
+        ))
+        .init_resource::<StatePreload>()
+        .add_message::<PreloadProgress>()
+        .add_message::<PreloadFinished>()
+        .add_message::<AssetLoadError>()
+        .add_systems(Update, (start_state_preload, report_state_preload).chain());
+
+        let mut finished = Vec::new();
+        let mut errors = Vec::new();
+        for _ in 0..1000 {
+            app.update();
+            let messages = app.world().resource::<Messages<PreloadFinished>>();
+            finished.extend(messages.iter_current_update_messages().cloned());
+            let messages = app.world().resource::<Messages<AssetLoadError>>();
+            errors.extend(messages.iter_current_update_messages().cloned());
+            if app.world().resource::<StatePreload>().is_finished() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].state, GameState::Loading);
+        let failed: Vec<&str> = finished[0]
+            .failed
+            .iter()
+            .map(|failure| failure.entry.path.as_str())
+            .collect();
+        assert_eq!(failed, ["missing/palette.pal"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].source, "Palette");
+        assert_eq!(errors[0].path, "missing/palette.pal");
+        assert_eq!(
+            app.world().resource::<StatePreload>().progress(),
+            Progress { done: 1, total: 1 }
+        );
+    }
+
+    #[test]
+    fn missing_grfs_read_as_missing_at_their_path() {
+        let error = AssetLoadError {
+            source: "data.grf".to_string(),
+            path: "./assets/data.grf".to_string(),
+            error: "missing".to_string(),
+        };
+        assert_eq!(error.to_string(), "data.grf missing at ./assets/data.grf");
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::reflect::TypePath;
 use moonshine_tag::Tag;
 
+use super::sprite_atlas::AtlasRegion;
 use crate::domain::sprite::tags::LAYER_BODY;
 
 /// Pre-processed animation asset with all textures converted at load time.
@@ -9,8 +10,19 @@ use crate::domain::sprite::tags::LAYER_BODY;
 /// Players composite multiple assets at render time.
 #[derive(Asset, TypePath, Clone, Debug)]
 pub struct RoAnimationAsset {
-    /// Pre-converted GPU textures for all frames
-    pub textures: Vec<Handle<Image>>,
+    /// Atlas pages holding every SPR frame, converted at load time
+    pub atlas_pages: Vec<Handle<Image>>,
+
+    /// Where each SPR frame sits in `atlas_pages`. Index = SPR frame index.
+    pub frame_regions: Vec<AtlasRegion>,
+
+    /// Palette-index atlas pages of the indexed SPR frames, for layers that
+    /// take hair/clothes dyes. Empty for every other layer.
+    pub index_pages: Vec<Handle<Image>>,
+
+    /// Where each SPR frame sits in `index_pages`; RGBA frames have an empty
+    /// region. Index = SPR frame index.
+    pub index_regions: Vec<AtlasRegion>,
 
     /// Animation data per action+direction combo.
     /// Index = base_action * 8 + direction for 8-directional sprites.
@@ -59,7 +71,7 @@ pub struct FrameData {
 /// A single sprite part within a frame
 #[derive(Clone, Debug)]
 pub struct FramePart {
-    /// Index into RoAnimationAsset.textures
+    /// SPR frame index (into RoAnimationAsset.frame_regions)
     pub texture_index: usize,
 
     /// Pre-computed affine transform matrix
@@ -81,10 +93,43 @@ pub struct FramePart {
     pub mirror: bool,
 }
 
+impl RoAnimationAsset {
+    /// The atlas page holding SPR frame `index`, and the frame's region on it.
+    pub fn frame_texture(&self, index: usize) -> Option<(&Handle<Image>, AtlasRegion)> {
+        let region = *self.frame_regions.get(index)?;
+        let page = self.atlas_pages.get(region.page)?;
+        Some((page, region))
+    }
+
+    /// The index page holding indexed SPR frame `index`, and the frame's region
+    /// on it. `None` for RGBA frames and for assets built without index pages.
+    pub fn index_texture(&self, index: usize) -> Option<(&Handle<Image>, AtlasRegion)> {
+        let region = *self.index_regions.get(index)?;
+        if region.rect.is_empty() {
+            return None;
+        }
+        let page = self.index_pages.get(region.page)?;
+        Some((page, region))
+    }
+
+    /// The sound `frame` triggers when it starts showing, as named in the ACT
+    /// (e.g. "_attack_sword.wav").
+    pub fn frame_sound(&self, frame: &FrameData) -> Option<&str> {
+        let id = usize::try_from(frame.sound_id?).ok()?;
+        self.sounds
+            .get(id)
+            .map(String::as_str)
+            .filter(|name| !name.is_empty())
+    }
+}
+
 impl Default for RoAnimationAsset {
     fn default() -> Self {
         Self {
-            textures: Vec::new(),
+            atlas_pages: Vec::new(),
+            frame_regions: Vec::new(),
+            index_pages: Vec::new(),
+            index_regions: Vec::new(),
             actions: Vec::new(),
             layer: LAYER_BODY,
             sounds: Vec::new(),
@@ -113,3 +158,26 @@ impl Default for FrameData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_sound_names_the_acts_sound_for_the_frame() {
+        let asset = RoAnimationAsset {
+            sounds: vec!["atk".into(), String::new()],
+            ..default()
+        };
+        let frame = |sound_id| FrameData {
+            sound_id,
+            ..default()
+        };
+
+        assert_eq!(asset.frame_sound(&frame(Some(0))), Some("atk"));
+        assert_eq!(asset.frame_sound(&frame(Some(1))), None);
+        assert_eq!(asset.frame_sound(&frame(Some(2))), None);
+        assert_eq!(asset.frame_sound(&frame(Some(-1))), None);
+        assert_eq!(asset.frame_sound(&frame(None)), None);
+    }
+}
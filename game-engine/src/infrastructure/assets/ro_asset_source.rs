@@ -1,4 +1,7 @@
-use super::{AssetConfig, sources::CompositeAssetSource};
+use super::{
+    AssetConfig,
+    sources::{CompositeAssetSource, SourceSetupError},
+};
 use bevy::log::{debug, error};
 
 /// Sets up CompositeAssetSource from configuration, preserving the exact logic
@@ -6,7 +9,7 @@ use bevy::log::{debug, error};
 pub fn setup_composite_source_from_config(
     config: &AssetConfig,
 ) -> Result<CompositeAssetSource, Box<dyn std::error::Error>> {
-    use super::sources::{DataFolderSource, GrfSource};
+    use super::sources::{DataFolderSource, GrfSource, RoInstallSource};
     use std::path::Path;
 
     let mut composite = CompositeAssetSource::new();
@@ -26,6 +29,11 @@ pub fn setup_composite_source_from_config(
 
     // Add GRF sources sorted by priority
     let grf_files = config.grf_files_by_priority();
+    let install_priority = grf_files
+        .iter()
+        .map(|grf| grf.priority + 2)
+        .max()
+        .unwrap_or(1);
     for grf_config in grf_files {
         let grf_path = Path::new(&grf_config.path);
 
@@ -39,6 +47,13 @@ pub fn setup_composite_source_from_config(
                 .join(grf_path),
         ];
 
+        // Reported if no candidate loads: the last open error, else the GRF
+        // missing where the assets directory would hold it.
+        let mut setup_error = SourceSetupError {
+            source: grf_config.path.clone(),
+            path: potential_paths[1].display().to_string(),
+            error: "missing".to_string(),
+        };
         let mut grf_loaded = false;
         for potential_path in potential_paths {
             if potential_path.exists() {
@@ -56,6 +71,8 @@ pub fn setup_composite_source_from_config(
                     }
                     Err(e) => {
                         error!("Failed to load GRF {}: {}", potential_path.display(), e);
+                        setup_error.path = potential_path.display().to_string();
+                        setup_error.error = e.to_string();
                     }
                 }
             }
@@ -63,6 +80,25 @@ pub fn setup_composite_source_from_config(
 
         if !grf_loaded {
             error!("Could not find or load GRF file: {}", grf_config.path);
+            composite.record_setup_error(setup_error);
+        }
+    }
+
+    // The install goes last, so loose files and GRFs configured above win.
+    if let Some(root) = &config.assets.ro_install {
+        match RoInstallSource::open(Path::new(root), install_priority) {
+            Ok(install) => {
+                debug!("Loaded RO install: {root} (priority: {install_priority})");
+                composite.add_source(Box::new(install));
+            }
+            Err(e) => {
+                error!("Could not load the RO install at {root}: {e}");
+                composite.record_setup_error(SourceSetupError {
+                    source: "ro_install".to_string(),
+                    path: root.clone(),
+                    error: e.to_string(),
+                });
+            }
         }
     }
 
@@ -0,0 +1,364 @@
+//! First-run setup: pointing the client at an existing RO install.
+//!
+//! [`check_ro_install`] looks a folder over for what the client needs from it
+//! (`data.grf`, the other GRFs, the BGM folder). [`UseRoInstall`] adopts one:
+//! the `ro://` source is rebuilt in place with the install as `ro_install`
+//! (served by [`RoInstallSource`](super::sources::RoInstallSource)), and once
+//! that worked it is written into `assets/loader.toml`, so a setup screen can
+//! go straight on to the login without a restart.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::Serialize;
+use toml_edit::{DocumentMut, Item, value};
+
+use super::config::{AssetConfig, parse_data_ini};
+use super::ro_asset_source::setup_composite_source_from_config;
+use super::{HierarchicalAssetManager, SharedCompositeAssetSource};
+use crate::infrastructure::encoding::decode_euc_kr;
+
+/// The asset config [`RoAssetsPlugin`](super::ro_assets_plugin::RoAssetsPlugin)
+/// reads at startup.
+pub const LOADER_CONFIG_PATH: &str = "assets/loader.toml";
+
+/// GRFs looked for when the install has no `DATA.INI`, highest priority first.
+const KNOWN_GRFS: [&str; 2] = ["rdata.grf", "data.grf"];
+
+/// What [`check_ro_install`] found in a folder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RoInstallCheck {
+    pub root: PathBuf,
+    /// The GRFs to mount, highest priority first.
+    pub grfs: Vec<PathBuf>,
+    pub bgm: Option<PathBuf>,
+    /// Why the folder can't be used; empty when it can.
+    pub problems: Vec<String>,
+}
+
+impl RoInstallCheck {
+    pub fn is_usable(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// `name` in `dir`, matched case-insensitively as Windows installs expect.
+fn find_entry(dir: &Path, name: &str) -> Option<PathBuf> {
+    let exact = dir.join(name);
+    if exact.exists() {
+        return Some(exact);
+    }
+    fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .find(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .eq_ignore_ascii_case(name)
+        })
+        .map(|entry| entry.path())
+}
+
+pub fn check_ro_install(root: &Path) -> RoInstallCheck {
+    let mut check = RoInstallCheck {
+        root: root.to_path_buf(),
+        grfs: Vec::new(),
+        bgm: None,
+        problems: Vec::new(),
+    };
+    if !root.is_dir() {
+        check
+            .problems
+            .push(format!("{} is not a folder", root.display()));
+        return check;
+    }
+
+    let listed = find_entry(root, "DATA.INI")
+        .and_then(|ini| fs::read(ini).ok())
+        .map(|bytes| parse_data_ini(&decode_euc_kr(&bytes)))
+        .filter(|grfs| !grfs.is_empty());
+    let names: Vec<String> = match listed {
+        Some(mut grfs) => {
+            grfs.sort_by_key(|grf| grf.priority);
+            grfs.into_iter().map(|grf| grf.path).collect()
+        }
+        None => KNOWN_GRFS.iter().map(|name| name.to_string()).collect(),
+    };
+    check.grfs = names
+        .iter()
+        .filter_map(|name| find_entry(root, name))
+        .filter(|path| path.is_file())
+        .collect();
+    check.bgm = find_entry(root, "BGM").filter(|path| path.is_dir());
+
+    let has_data_grf = check.grfs.iter().any(|path| {
+        path.file_name()
+            .is_some_and(|name| name.eq_ignore_ascii_case("data.grf"))
+    });
+    if !has_data_grf {
+        check.problems.push(format!(
+            "data.grf missing at {}",
+            root.join("data.grf").display()
+        ));
+    }
+    check
+}
+
+/// The `loader.toml` `content` reading from the install, and the config it
+/// now holds. The install's source mounts its own GRFs, so only the
+/// `[[assets.grf]]` entries naming one of them are dropped; patch GRFs such as
+/// `en.grf` and the rest of the file, comments included, stay as written.
+pub fn ro_install_config(
+    content: &str,
+    check: &RoInstallCheck,
+) -> Result<(AssetConfig, String), String> {
+    let parse_error =
+        |e: &dyn std::fmt::Display| format!("couldn't parse {LOADER_CONFIG_PATH}: {e}");
+    toml::from_str::<AssetConfig>(content).map_err(|e| parse_error(&e))?;
+    let mut document = content
+        .parse::<DocumentMut>()
+        .map_err(|e| parse_error(&e))?;
+    let assets = document
+        .get_mut("assets")
+        .and_then(Item::as_table_like_mut)
+        .ok_or_else(|| parse_error(&"no [assets] table"))?;
+
+    let mounted: Vec<&std::ffi::OsStr> = check
+        .grfs
+        .iter()
+        .filter_map(|path| path.file_name())
+        .collect();
+    if let Some(grfs) = assets.get_mut("grf").and_then(Item::as_array_of_tables_mut) {
+        grfs.retain(|grf| {
+            let name = grf
+                .get("path")
+                .and_then(Item::as_str)
+                .and_then(|path| Path::new(path).file_name());
+            !name.is_some_and(|name| {
+                mounted
+                    .iter()
+                    .any(|mounted| mounted.eq_ignore_ascii_case(name))
+            })
+        });
+    }
+    assets.insert("ro_install", value(check.root.display().to_string()));
+
+    let content = document.to_string();
+    let config = toml::from_str(&content).map_err(|e| parse_error(&e))?;
+    Ok((config, content))
+}
+
+/// Checks the folder at `root` and reports it as [`RoInstallChecked`].
+#[derive(Message, Debug, Clone)]
+pub struct CheckRoInstall {
+    pub root: PathBuf,
+}
+
+#[derive(Message, Debug, Clone)]
+pub struct RoInstallChecked(pub RoInstallCheck);
+
+/// Switches the client's GRFs to the install at `root`, answered by
+/// [`RoInstallApplied`].
+#[derive(Message, Debug, Clone)]
+pub struct UseRoInstall {
+    pub root: PathBuf,
+}
+
+#[derive(Message, Debug, Clone)]
+pub struct RoInstallApplied {
+    /// The check the install passed, or why it wasn't used.
+    pub result: Result<RoInstallCheck, String>,
+}
+
+pub struct RoInstallPlugin;
+
+impl Plugin for RoInstallPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CheckRoInstall>()
+            .add_message::<RoInstallChecked>()
+            .add_message::<UseRoInstall>()
+            .add_message::<RoInstallApplied>()
+            .add_systems(Update, (check_ro_installs, use_ro_installs));
+    }
+}
+
+fn check_ro_installs(
+    mut requests: MessageReader<CheckRoInstall>,
+    mut checked: MessageWriter<RoInstallChecked>,
+) {
+    for request in requests.read() {
+        checked.write(RoInstallChecked(check_ro_install(&request.root)));
+    }
+}
+
+fn use_ro_installs(
+    mut requests: MessageReader<UseRoInstall>,
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    manager: Option<Res<HierarchicalAssetManager>>,
+    mut applied: MessageWriter<RoInstallApplied>,
+) {
+    for request in requests.read() {
+        let result = use_ro_install(&request.root, sources.as_deref(), manager.as_deref());
+        match &result {
+            Ok(check) => info!("Using the RO install at {}", check.root.display()),
+            Err(e) => warn!("Not using {}: {}", request.root.display(), e),
+        }
+        applied.write(RoInstallApplied { result });
+    }
+}
+
+fn use_ro_install(
+    root: &Path,
+    sources: Option<&SharedCompositeAssetSource>,
+    manager: Option<&HierarchicalAssetManager>,
+) -> Result<RoInstallCheck, String> {
+    let check = check_ro_install(root);
+    if !check.is_usable() {
+        return Err(check.problems.join("; "));
+    }
+
+    // Only a missing file falls back to the defaults; anything unreadable is
+    // left alone rather than overwritten.
+    let base = match fs::read_to_string(LOADER_CONFIG_PATH) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            toml::to_string_pretty(&AssetConfig::default()).map_err(|e| e.to_string())?
+        }
+        Err(e) => return Err(format!("couldn't read {LOADER_CONFIG_PATH}: {e}")),
+    };
+    let (config, content) = ro_install_config(&base, &check)?;
+
+    let composite = sources
+        .map(|_| setup_composite_source_from_config(&config))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    if let Some(manager) = manager {
+        manager
+            .setup_sources_from_config(&config)
+            .map_err(|e| e.to_string())?;
+    }
+    if let (Some(sources), Some(composite)) = (sources, composite) {
+        *sources
+            .0
+            .write()
+            .map_err(|e| format!("asset source lock poisoned: {e}"))? = composite;
+    }
+
+    fs::write(LOADER_CONFIG_PATH, content)
+        .map_err(|e| format!("couldn't write {LOADER_CONFIG_PATH}: {e}"))?;
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install(name: &str, files: &[&str]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("lifthrasir-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("BGM")).unwrap();
+        for file in files {
+            fs::write(root.join(file), b"").unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn finds_the_grfs_and_bgm_of_an_install() {
+        let root = install("ro-install", &["DATA.GRF", "rdata.grf"]);
+
+        let check = check_ro_install(&root);
+
+        assert!(check.is_usable(), "{:?}", check.problems);
+        let names: Vec<String> = check
+            .grfs
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["rdata.grf", "DATA.GRF"]);
+        assert_eq!(check.bgm, Some(root.join("BGM")));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn adopting_an_install_keeps_patch_grfs_and_comments() {
+        let check = RoInstallCheck {
+            root: PathBuf::from("/games/ro"),
+            grfs: vec![
+                PathBuf::from("/games/ro/rdata.grf"),
+                PathBuf::from("/games/ro/DATA.GRF"),
+            ],
+            bgm: None,
+            problems: Vec::new(),
+        };
+        let content = "\
+# My patches
+[assets]
+data_folder = \"./assets/data/\"
+
+[[assets.grf]]
+path = \"en.grf\"
+priority = 0
+
+[[assets.grf]]
+path = \"data.grf\"
+priority = 1
+";
+
+        let (config, written) = ro_install_config(content, &check).unwrap();
+
+        assert_eq!(config.assets.ro_install.as_deref(), Some("/games/ro"));
+        assert_eq!(
+            config.assets.grf,
+            [super::super::config::GrfConfig {
+                path: "en.grf".to_string(),
+                priority: 0,
+            }]
+        );
+        assert!(written.starts_with("# My patches\n"), "{written}");
+    }
+
+    #[test]
+    fn an_unparsable_loader_config_is_not_replaced() {
+        let check = check_ro_install(Path::new("nowhere"));
+
+        let error = ro_install_config("[assets\ngrf = ", &check).unwrap_err();
+
+        assert!(error.starts_with("couldn't parse"), "{error}");
+    }
+
+    #[test]
+    fn data_ini_decides_which_grfs_and_in_what_order() {
+        let root = install("ro-install-ini", &["data.grf", "extra.grf", "rdata.grf"]);
+        fs::write(
+            root.join("DATA.INI"),
+            "[Data]\r\n0=extra.grf\r\n1=data.grf\r\n",
+        )
+        .unwrap();
+
+        let check = check_ro_install(&root);
+
+        assert_eq!(check.grfs, [root.join("extra.grf"), root.join("data.grf")]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn a_folder_without_data_grf_is_not_usable() {
+        let root = install("ro-install-empty", &["rdata.grf"]);
+
+        let check = check_ro_install(&root);
+
+        assert_eq!(
+            check.problems,
+            [format!(
+                "data.grf missing at {}",
+                root.join("data.grf").display()
+            )]
+        );
+        assert!(!check_ro_install(&root.join("nowhere")).is_usable());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
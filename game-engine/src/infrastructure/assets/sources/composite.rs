@@ -1,11 +1,68 @@
-use super::{AssetSource, AssetSourceError};
+use super::{AssetSource, AssetSourceError, asset_path_key};
 use bevy::log::debug;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How long a path found in no source is answered from the negative cache
+/// before the sources are probed again.
+pub const NEGATIVE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Expired negative entries are swept once the cache grows past this.
+const NEGATIVE_CACHE_SWEEP_LEN: usize = 4096;
+
+/// A source plus how often lookups found (or missed) a file in it.
+struct CountedSource {
+    source: Box<dyn AssetSource>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Lookup counters of one source, in priority order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLookupStats {
+    pub name: String,
+    /// Lookups this source answered.
+    pub hits: u64,
+    /// Lookups that probed this source and didn't find the file.
+    pub misses: u64,
+}
+
+/// A configured source that couldn't be mounted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSetupError {
+    /// The source as configured, e.g. `data.grf`.
+    pub source: String,
+    /// Where it was looked for.
+    pub path: String,
+    pub error: String,
+}
+
+/// Lookup counters of a whole [`CompositeAssetSource`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetLookupStats {
+    pub sources: Vec<SourceLookupStats>,
+    /// Lookups no source could answer, probed or not.
+    pub not_found: u64,
+    /// The part of `not_found` answered by the negative cache without probing.
+    pub negative_cache_hits: u64,
+}
 
 pub struct CompositeAssetSource {
     name: String,
-    sources: Vec<Box<dyn AssetSource>>,
+    sources: Vec<CountedSource>,
     resolution_cache: HashMap<String, usize>, // path -> source index
+    /// `asset_path_key` -> (source index, stored path), highest priority first.
+    /// Built on the first lookup no source answers with the exact path.
+    path_index: OnceLock<HashMap<String, (usize, String)>>,
+    /// Paths no source had, with when that was found out.
+    negative_cache: Mutex<HashMap<String, Instant>>,
+    negative_cache_ttl: Duration,
+    not_found: AtomicU64,
+    negative_cache_hits: AtomicU64,
+    setup_errors: Vec<SourceSetupError>,
 }
 
 impl CompositeAssetSource {
@@ -14,59 +71,181 @@ impl CompositeAssetSource {
             name: "CompositeAssetSource".to_string(),
             sources: Vec::new(),
             resolution_cache: HashMap::new(),
+            path_index: OnceLock::new(),
+            negative_cache: Mutex::new(HashMap::new()),
+            negative_cache_ttl: NEGATIVE_CACHE_TTL,
+            not_found: AtomicU64::new(0),
+            negative_cache_hits: AtomicU64::new(0),
+            setup_errors: Vec::new(),
         }
     }
 
+    /// Override [`NEGATIVE_CACHE_TTL`]; `Duration::ZERO` turns the cache off.
+    pub fn with_negative_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_cache_ttl = ttl;
+        self
+    }
+
     pub fn add_source(&mut self, source: Box<dyn AssetSource>) {
         debug!(
             "Added asset source: {} (priority: {})",
             source.name(),
             source.priority()
         );
-        self.sources.push(source);
+        self.sources.push(CountedSource {
+            source,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        });
         self.sort_sources_by_priority();
         self.resolution_cache.clear(); // Clear cache when sources change
+        self.path_index = OnceLock::new();
+        self.clear_negative_cache();
     }
 
     fn sort_sources_by_priority(&mut self) {
         // Sort by priority (lower number = higher priority)
-        self.sources.sort_by_key(|source| source.priority());
+        self.sources
+            .sort_by_key(|counted| counted.source.priority());
+    }
+
+    /// Forget every cached miss, e.g. after files were added to a data folder.
+    pub fn clear_negative_cache(&self) {
+        if let Ok(mut cache) = self.negative_cache.lock() {
+            cache.clear();
+        }
+    }
+
+    fn cached_miss(&self, path: &str) -> bool {
+        let Ok(mut cache) = self.negative_cache.lock() else {
+            return false;
+        };
+        match cache.get(path) {
+            Some(at) if at.elapsed() < self.negative_cache_ttl => true,
+            Some(_) => {
+                cache.remove(path);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn remember_miss(&self, path: &str) {
+        if self.negative_cache_ttl.is_zero() {
+            return;
+        }
+        let Ok(mut cache) = self.negative_cache.lock() else {
+            return;
+        };
+        if cache.len() >= NEGATIVE_CACHE_SWEEP_LEN {
+            let ttl = self.negative_cache_ttl;
+            cache.retain(|_, at| at.elapsed() < ttl);
+        }
+        cache.insert(path.to_string(), Instant::now());
+    }
+
+    fn path_index(&self) -> &HashMap<String, (usize, String)> {
+        self.path_index.get_or_init(|| {
+            let mut index = HashMap::new();
+            for (idx, counted) in self.sources.iter().enumerate() {
+                for file in counted.source.list_files() {
+                    index.entry(asset_path_key(&file)).or_insert((idx, file));
+                }
+            }
+            debug!("Indexed {} asset paths by normalized key", index.len());
+            index
+        })
     }
 
     pub fn find_source_for_asset(&self, path: &str) -> Option<usize> {
+        self.resolve_asset(path).map(|(source_idx, _)| source_idx)
+    }
+
+    /// The source holding `path` and the path as that source stores it. An
+    /// exact match wins; otherwise any spelling with the same
+    /// [`asset_path_key`] resolves to the stored entry.
+    pub fn resolve_asset<'a>(&'a self, path: &'a str) -> Option<(usize, Cow<'a, str>)> {
         // Check cache first
         if let Some(&source_idx) = self.resolution_cache.get(path)
             && source_idx < self.sources.len()
-            && self.sources[source_idx].exists(path)
+            && self.sources[source_idx].source.exists(path)
         {
-            return Some(source_idx);
+            self.sources[source_idx]
+                .hits
+                .fetch_add(1, Ordering::Relaxed);
+            return Some((source_idx, Cow::Borrowed(path)));
+        }
+
+        if self.cached_miss(path) {
+            self.negative_cache_hits.fetch_add(1, Ordering::Relaxed);
+            self.not_found.fetch_add(1, Ordering::Relaxed);
+            return None;
         }
 
         // Search through sources by priority
-        for (idx, source) in self.sources.iter().enumerate() {
-            if source.exists(path) {
-                return Some(idx);
+        for (idx, counted) in self.sources.iter().enumerate() {
+            if counted.source.exists(path) {
+                counted.hits.fetch_add(1, Ordering::Relaxed);
+                return Some((idx, Cow::Borrowed(path)));
             }
+            counted.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if let Some((idx, stored)) = self.path_index().get(&asset_path_key(path)) {
+            debug!("Asset '{}' resolved as '{}'", path, stored);
+            self.sources[*idx].hits.fetch_add(1, Ordering::Relaxed);
+            return Some((*idx, Cow::Borrowed(stored.as_str())));
         }
 
         debug!("Asset '{}' not found in any source", path);
+        self.not_found.fetch_add(1, Ordering::Relaxed);
+        self.remember_miss(path);
         None
     }
 
+    /// Lookup counters since the sources were set up.
+    /// Notes a source the config asked for but that couldn't be added; the
+    /// rest keep serving.
+    pub fn record_setup_error(&mut self, error: SourceSetupError) {
+        self.setup_errors.push(error);
+    }
+
+    pub fn setup_errors(&self) -> &[SourceSetupError] {
+        &self.setup_errors
+    }
+
+    pub fn lookup_stats(&self) -> AssetLookupStats {
+        AssetLookupStats {
+            sources: self
+                .sources
+                .iter()
+                .map(|counted| SourceLookupStats {
+                    name: counted.source.name().to_string(),
+                    hits: counted.hits.load(Ordering::Relaxed),
+                    misses: counted.misses.load(Ordering::Relaxed),
+                })
+                .collect(),
+            not_found: self.not_found.load(Ordering::Relaxed),
+            negative_cache_hits: self.negative_cache_hits.load(Ordering::Relaxed),
+        }
+    }
+
     pub fn get_debug_info(&self) -> String {
         let mut info = format!(
             "CompositeAssetSource with {} sources:\n",
             self.sources.len()
         );
-        for (idx, source) in self.sources.iter().enumerate() {
+        for (idx, counted) in self.sources.iter().enumerate() {
             info.push_str(&format!(
                 "  [{}] {} (priority: {})\n",
                 idx,
-                source.name(),
-                source.priority()
+                counted.source.name(),
+                counted.source.priority()
             ));
         }
         info.push_str(&format!("Cache entries: {}\n", self.resolution_cache.len()));
+        let negative_entries = self.negative_cache.lock().map_or(0, |cache| cache.len());
+        info.push_str(&format!("Negative cache entries: {}\n", negative_entries));
         info
     }
 }
@@ -91,10 +270,10 @@ impl AssetSource for CompositeAssetSource {
     }
 
     fn load(&self, path: &str) -> Result<Vec<u8>, AssetSourceError> {
-        if let Some(source_idx) = self.find_source_for_asset(path)
-            && let Some(source) = self.sources.get(source_idx)
+        if let Some((source_idx, stored)) = self.resolve_asset(path)
+            && let Some(counted) = self.sources.get(source_idx)
         {
-            return source.load(path);
+            return counted.source.load(&stored);
         }
 
         Err(AssetSourceError::NotFound(path.to_string()))
@@ -102,8 +281,8 @@ impl AssetSource for CompositeAssetSource {
 
     fn list_files(&self) -> Vec<String> {
         let mut all_files = Vec::new();
-        for source in &self.sources {
-            all_files.extend(source.list_files());
+        for counted in &self.sources {
+            all_files.extend(counted.source.list_files());
         }
 
         // Remove duplicates while preserving priority order
@@ -119,3 +298,106 @@ impl AssetSource for CompositeAssetSource {
         unique_files
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Holds a fixed set of paths and counts `exists` probes.
+    struct FixedSource {
+        name: &'static str,
+        priority: u32,
+        files: &'static [&'static str],
+        probes: Arc<AtomicU64>,
+    }
+
+    impl AssetSource for FixedSource {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn priority(&self) -> u32 {
+            self.priority
+        }
+
+        fn exists(&self, path: &str) -> bool {
+            self.probes.fetch_add(1, Ordering::Relaxed);
+            self.files.contains(&path)
+        }
+
+        fn load(&self, path: &str) -> Result<Vec<u8>, AssetSourceError> {
+            if self.files.contains(&path) {
+                Ok(Vec::new())
+            } else {
+                Err(AssetSourceError::NotFound(path.to_string()))
+            }
+        }
+
+        fn list_files(&self) -> Vec<String> {
+            self.files.iter().map(|file| file.to_string()).collect()
+        }
+    }
+
+    fn two_sources(ttl: Duration) -> (CompositeAssetSource, Arc<AtomicU64>) {
+        let probes = Arc::new(AtomicU64::new(0));
+        let mut composite = CompositeAssetSource::new().with_negative_cache_ttl(ttl);
+        composite.add_source(Box::new(FixedSource {
+            name: "grf",
+            priority: 1,
+            files: &["data/a.spr", "data/b.spr"],
+            probes: probes.clone(),
+        }));
+        composite.add_source(Box::new(FixedSource {
+            name: "data",
+            priority: 0,
+            files: &["data/a.spr"],
+            probes: probes.clone(),
+        }));
+        (composite, probes)
+    }
+
+    #[test]
+    fn lookups_are_counted_per_source() {
+        let (composite, _) = two_sources(NEGATIVE_CACHE_TTL);
+        assert!(composite.exists("data/a.spr"));
+        assert!(composite.exists("data/b.spr"));
+        assert!(!composite.exists("data/c.spr"));
+
+        let stats = composite.lookup_stats();
+        let counts: Vec<_> = stats
+            .sources
+            .iter()
+            .map(|source| (source.name.as_str(), source.hits, source.misses))
+            .collect();
+        assert_eq!(counts, vec![("data", 1, 2), ("grf", 1, 1)]);
+        assert_eq!(stats.not_found, 1);
+    }
+
+    #[test]
+    fn any_spelling_resolves_to_the_stored_path() {
+        let (composite, _) = two_sources(NEGATIVE_CACHE_TTL);
+        let (source_idx, stored) = composite.resolve_asset("Data\\B.SPR").unwrap();
+        assert_eq!(composite.lookup_stats().sources[source_idx].name, "grf");
+        assert_eq!(stored, "data/b.spr");
+
+        // The data folder outranks the GRF for a file both hold.
+        let (source_idx, _) = composite.resolve_asset("DATA/A.spr").unwrap();
+        assert_eq!(composite.lookup_stats().sources[source_idx].name, "data");
+    }
+
+    #[test]
+    fn missing_files_are_not_probed_again_until_the_ttl_expires() {
+        let (composite, probes) = two_sources(NEGATIVE_CACHE_TTL);
+        assert!(!composite.exists("data/missing.spr"));
+        let after_first = probes.load(Ordering::Relaxed);
+        assert!(!composite.exists("data/missing.spr"));
+        assert_eq!(probes.load(Ordering::Relaxed), after_first);
+        assert_eq!(composite.lookup_stats().negative_cache_hits, 1);
+
+        let (uncached, probes) = two_sources(Duration::ZERO);
+        assert!(!uncached.exists("data/missing.spr"));
+        assert!(!uncached.exists("data/missing.spr"));
+        assert_eq!(probes.load(Ordering::Relaxed), 4);
+    }
+}
@@ -1,6 +1,8 @@
 pub mod composite;
 pub mod data_folder;
 pub mod grf_source;
+pub mod path_key;
+pub mod ro_install_source;
 
 use thiserror::Error;
 
@@ -36,3 +38,5 @@ impl std::fmt::Debug for dyn AssetSource {
 pub use composite::*;
 pub use data_folder::*;
 pub use grf_source::*;
+pub use path_key::asset_path_key;
+pub use ro_install_source::RoInstallSource;
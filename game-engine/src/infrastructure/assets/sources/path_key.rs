@@ -0,0 +1,56 @@
+use ro_formats::decode_euc_kr_mojibake;
+
+/// Lookup key for an asset path, so any reasonable spelling of a path finds
+/// the stored entry: separators unified to `/`, empty segments dropped,
+/// lowercased, and mojibake from EUC-KR names read as Windows-1252 (the way
+/// extraction tools often write them to disk) decoded back to Korean.
+///
+/// `Data\Sprite\À¯ÀúÀÎÅÍÆäÀÌ½º\Item.SPR` and `data/sprite/유저인터페이스/item.spr`
+/// get the same key.
+pub fn asset_path_key(path: &str) -> String {
+    let path = decode_euc_kr_mojibake(path);
+    let mut key = String::with_capacity(path.len());
+    for segment in path
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty())
+    {
+        if !key.is_empty() {
+            key.push('/');
+        }
+        key.push_str(&segment.to_lowercase());
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::{EUC_KR, WINDOWS_1252};
+
+    const KOREAN: &str = "data/sprite/인간족/몸통/남/초보자_남.spr";
+
+    #[test]
+    fn case_and_separators_do_not_matter() {
+        assert_eq!(
+            asset_path_key("Data\\Sprite\\\\인간족/몸통\\남\\초보자_남.SPR"),
+            KOREAN
+        );
+        assert_eq!(
+            asset_path_key("/data/texture/Logo.BMP"),
+            "data/texture/logo.bmp"
+        );
+    }
+
+    #[test]
+    fn euc_kr_mojibake_decodes_to_korean() {
+        let (euc_kr, _, _) = EUC_KR.encode(KOREAN);
+        let (mojibake, _) = WINDOWS_1252.decode_without_bom_handling(&euc_kr);
+        assert_ne!(mojibake, KOREAN);
+        assert_eq!(asset_path_key(&mojibake), KOREAN);
+    }
+
+    #[test]
+    fn latin_names_that_are_not_euc_kr_stay_put() {
+        assert_eq!(asset_path_key("data/wav/Café.wav"), "data/wav/café.wav");
+    }
+}
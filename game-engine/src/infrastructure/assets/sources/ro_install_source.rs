@@ -0,0 +1,133 @@
+use super::{AssetSource, AssetSourceError, CompositeAssetSource, DataFolderSource, GrfSource};
+use crate::infrastructure::assets::ro_install::check_ro_install;
+use std::path::Path;
+
+/// Top-level folders of an install whose loose files are served.
+const LOOSE_FOLDERS: [&str; 3] = ["data", "bgm", "system"];
+
+/// A whole official client install as one source: the loose files under its
+/// `data/`, `BGM/` and `System/` folders over the GRFs its `DATA.INI` lists
+/// (or `rdata.grf` and `data.grf`), each GRF over the next the way the
+/// reference client reads them. Paths are the client's own (`data/...`,
+/// `BGM/...`, `System/...`), so nothing has to be copied out of the install.
+pub struct RoInstallSource {
+    name: String,
+    priority: u32,
+    inner: CompositeAssetSource,
+}
+
+/// The install root's files, limited to [`LOOSE_FOLDERS`].
+struct LooseFiles(DataFolderSource);
+
+impl LooseFiles {
+    fn serves(path: &str) -> bool {
+        let path = path.trim_start_matches(['/', '\\']);
+        path.split(['/', '\\']).next().is_some_and(|folder| {
+            LOOSE_FOLDERS
+                .iter()
+                .any(|loose| loose.eq_ignore_ascii_case(folder))
+        }) && path.contains(['/', '\\'])
+    }
+}
+
+impl AssetSource for LooseFiles {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        Self::serves(path) && self.0.exists(path)
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<u8>, AssetSourceError> {
+        if !Self::serves(path) {
+            return Err(AssetSourceError::NotFound(path.to_string()));
+        }
+        self.0.load(path)
+    }
+
+    fn list_files(&self) -> Vec<String> {
+        self.0
+            .list_files()
+            .into_iter()
+            .filter(|path| Self::serves(path))
+            .collect()
+    }
+}
+
+impl RoInstallSource {
+    /// Mounts the install at `root`; fails when it has no `data.grf`.
+    pub fn open(root: &Path, priority: u32) -> Result<Self, AssetSourceError> {
+        let check = check_ro_install(root);
+        if !check.is_usable() {
+            return Err(AssetSourceError::NotFound(check.problems.join("; ")));
+        }
+
+        let mut inner = CompositeAssetSource::new();
+        inner.add_source(Box::new(LooseFiles(DataFolderSource::new(root))));
+        for (index, grf) in check.grfs.iter().enumerate() {
+            inner.add_source(Box::new(GrfSource::new(grf, index as u32 + 1)?));
+        }
+        Ok(Self {
+            name: format!("RoInstall({})", root.display()),
+            priority,
+            inner,
+        })
+    }
+}
+
+impl AssetSource for RoInstallSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.inner.exists(path)
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<u8>, AssetSourceError> {
+        self.inner.load(path)
+    }
+
+    fn list_files(&self) -> Vec<String> {
+        self.inner.list_files()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_client_folders_are_served_loose() {
+        assert!(LooseFiles::serves("data/sprite/몬스터/poring.spr"));
+        assert!(LooseFiles::serves("BGM\\01.mp3"));
+        assert!(LooseFiles::serves("System/iteminfo.lub"));
+        assert!(!LooseFiles::serves("Setup.exe"));
+        assert!(!LooseFiles::serves("savedata/option.ini"));
+        assert!(!LooseFiles::serves("data"));
+    }
+
+    #[test]
+    fn an_install_without_data_grf_is_refused() {
+        let root = std::env::temp_dir().join(format!(
+            "lifthrasir-ro-install-source-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(root.join("data")).unwrap();
+
+        assert!(matches!(
+            RoInstallSource::open(&root, 10),
+            Err(AssetSourceError::NotFound(problem)) if problem.contains("data.grf missing")
+        ));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,320 @@
+//! Packs every frame of an SPR into a few atlas textures.
+//!
+//! Each frame used to be its own `Image`, so a character with a few hundred
+//! frames per layer kept a few hundred small GPU textures alive and rebound a
+//! different one whenever its animation advanced. The frames are now shelf
+//! packed into pages of at most [`MAX_ATLAS_SIDE`] pixels per side, and each
+//! frame is addressed by an [`AtlasRegion`]: sprite layers look it up by frame
+//! index in the shader, other quads select it through a `uv_transform`. Dyeable sprites also get a one-byte-per-pixel
+//! page set of raw palette indices, laid out the same way.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::math::Affine2;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// The largest atlas page side. Comfortably under every desktop GPU's 2D
+/// texture limit, and above the upscaler's largest output side plus padding.
+pub const MAX_ATLAS_SIDE: u32 = 4096;
+
+/// Border around each frame, filled by repeating the frame's edge pixels so
+/// linear filtering at a frame's edge never samples its neighbour.
+const PADDING: u32 = 1;
+
+/// Where one frame sits in the atlas.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AtlasRegion {
+    /// Index of the page holding the frame.
+    pub page: usize,
+    /// The frame's pixels on that page, padding excluded.
+    pub rect: URect,
+    /// `rect` in normalized texture coordinates.
+    pub uv: Rect,
+}
+
+/// Page sizes and one region per frame, in frame order.
+#[derive(Debug, Default, PartialEq)]
+pub struct AtlasLayout {
+    pub pages: Vec<UVec2>,
+    pub regions: Vec<AtlasRegion>,
+}
+
+/// Shelf-pack frames of the given sizes, tallest first. Pages share one width,
+/// picked so a single page is roughly square, and are only as tall as their
+/// content. Empty frames get an empty region on page 0.
+pub fn pack_frames(sizes: &[UVec2]) -> AtlasLayout {
+    let padded = |size: UVec2| size + UVec2::splat(2 * PADDING);
+    let drawable = |size: &UVec2| size.x > 0 && size.y > 0;
+
+    let widest = sizes
+        .iter()
+        .filter(|size| drawable(size))
+        .map(|&size| padded(size).x)
+        .max();
+    let Some(widest) = widest else {
+        return AtlasLayout {
+            pages: Vec::new(),
+            regions: vec![AtlasRegion::default(); sizes.len()],
+        };
+    };
+    let area: u64 = sizes
+        .iter()
+        .filter(|size| drawable(size))
+        .map(|&size| {
+            let size = padded(size);
+            size.x as u64 * size.y as u64
+        })
+        .sum();
+    let width = ((area as f64).sqrt().ceil() as u32)
+        .next_power_of_two()
+        .clamp(widest, MAX_ATLAS_SIDE.max(widest));
+
+    let mut order: Vec<usize> = (0..sizes.len()).filter(|&i| drawable(&sizes[i])).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(sizes[i].y));
+
+    let mut pages = vec![UVec2::new(width, 0)];
+    let mut origins = vec![(0usize, UVec2::ZERO); sizes.len()];
+    let mut cursor = UVec2::ZERO;
+    let mut shelf_height = 0;
+    for index in order {
+        let size = padded(sizes[index]);
+        if cursor.x + size.x > width {
+            cursor = UVec2::new(0, cursor.y + shelf_height);
+            shelf_height = 0;
+        }
+        if cursor.y + size.y > MAX_ATLAS_SIDE && cursor.y > 0 {
+            pages.push(UVec2::new(width, 0));
+            cursor = UVec2::ZERO;
+            shelf_height = 0;
+        }
+
+        let page = pages.len() - 1;
+        origins[index] = (page, cursor + UVec2::splat(PADDING));
+        pages[page].y = pages[page].y.max(cursor.y + size.y);
+        cursor.x += size.x;
+        shelf_height = shelf_height.max(size.y);
+    }
+
+    let regions = sizes
+        .iter()
+        .zip(origins)
+        .map(|(&size, (page, min))| {
+            if !drawable(&size) {
+                return AtlasRegion::default();
+            }
+            let rect = URect::from_corners(min, min + size);
+            let page_size = pages[page].as_vec2();
+            AtlasRegion {
+                page,
+                rect,
+                uv: Rect::from_corners(
+                    rect.min.as_vec2() / page_size,
+                    rect.max.as_vec2() / page_size,
+                ),
+            }
+        })
+        .collect();
+
+    AtlasLayout { pages, regions }
+}
+
+/// Build the atlas pages for `frames` (RGBA pixels and size), returning the
+/// page images and each frame's region.
+pub fn build_atlas(frames: &[(Vec<u8>, UVec2)]) -> (Vec<Image>, Vec<AtlasRegion>) {
+    build_pages(frames, 4, TextureFormat::Rgba8UnormSrgb)
+}
+
+/// Build `R8Unorm` atlas pages for `frames` of 8-bit palette indices, for the
+/// palette-indexed sprite material. Frames without indices (RGBA frames) get an
+/// empty region.
+pub fn build_index_atlas(frames: &[(Vec<u8>, UVec2)]) -> (Vec<Image>, Vec<AtlasRegion>) {
+    build_pages(frames, 1, TextureFormat::R8Unorm)
+}
+
+fn build_pages(
+    frames: &[(Vec<u8>, UVec2)],
+    bytes_per_pixel: usize,
+    format: TextureFormat,
+) -> (Vec<Image>, Vec<AtlasRegion>) {
+    let sizes: Vec<UVec2> = frames.iter().map(|(_, size)| *size).collect();
+    let layout = pack_frames(&sizes);
+
+    let mut pixels: Vec<Vec<u8>> = layout
+        .pages
+        .iter()
+        .map(|page| vec![0u8; page.x as usize * page.y as usize * bytes_per_pixel])
+        .collect();
+    for ((data, _), region) in frames.iter().zip(&layout.regions) {
+        if region.rect.is_empty() {
+            continue;
+        }
+        let page_width = layout.pages[region.page].x;
+        blit_extruded(
+            &mut pixels[region.page],
+            page_width,
+            data,
+            region.rect,
+            bytes_per_pixel,
+        );
+    }
+
+    let images = layout
+        .pages
+        .iter()
+        .zip(pixels)
+        .map(|(page, data)| {
+            Image::new(
+                Extent3d {
+                    width: page.x,
+                    height: page.y,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                data,
+                format,
+                RenderAssetUsages::default(),
+            )
+        })
+        .collect();
+
+    (images, layout.regions)
+}
+
+/// Copy `pixels` into `rect` of the page, plus a [`PADDING`] border that
+/// repeats the frame's outermost pixels.
+fn blit_extruded(
+    page: &mut [u8],
+    page_width: u32,
+    pixels: &[u8],
+    rect: URect,
+    bytes_per_pixel: usize,
+) {
+    let size = rect.size();
+    let (width, height) = (size.x as i64, size.y as i64);
+    let pad = PADDING as i64;
+    for y in -pad..height + pad {
+        let src_y = y.clamp(0, height - 1);
+        let dst_y = (rect.min.y as i64 + y) as usize;
+        for x in -pad..width + pad {
+            let src_x = x.clamp(0, width - 1);
+            let dst_x = (rect.min.x as i64 + x) as usize;
+            let src = (src_y * width + src_x) as usize * bytes_per_pixel;
+            let dst = (dst_y * page_width as usize + dst_x) * bytes_per_pixel;
+            if let Some(pixel) = pixels.get(src..src + bytes_per_pixel) {
+                page[dst..dst + bytes_per_pixel].copy_from_slice(pixel);
+            }
+        }
+    }
+}
+
+/// The material `uv_transform` that maps the sprite quad's 0..1 UVs onto `uv`.
+pub fn atlas_uv_transform(uv: Rect) -> Affine2 {
+    Affine2::from_scale_angle_translation(uv.size(), 0.0, uv.min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlaps(a: &AtlasRegion, b: &AtlasRegion) -> bool {
+        let pad = UVec2::splat(PADDING);
+        a.page == b.page
+            && !URect::from_corners(a.rect.min - pad, a.rect.max + pad)
+                .intersect(URect::from_corners(b.rect.min - pad, b.rect.max + pad))
+                .is_empty()
+    }
+
+    #[test]
+    fn frames_are_packed_without_overlap_inside_their_page() {
+        let sizes: Vec<UVec2> = (1..40)
+            .map(|i| UVec2::new(8 + (i * 7) % 50, 10 + (i * 13) % 60))
+            .collect();
+        let layout = pack_frames(&sizes);
+
+        assert_eq!(layout.pages.len(), 1);
+        for (i, (region, size)) in layout.regions.iter().zip(&sizes).enumerate() {
+            assert_eq!(region.rect.size(), *size);
+            let page = layout.pages[region.page];
+            assert!(region.rect.max.x + PADDING <= page.x);
+            assert!(region.rect.max.y + PADDING <= page.y);
+            for other in &layout.regions[i + 1..] {
+                assert!(!overlaps(region, other), "{region:?} overlaps {other:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn frames_spill_onto_new_pages_past_the_size_limit() {
+        let layout = pack_frames(&[UVec2::splat(2048); 5]);
+        assert_eq!(layout.pages.len(), 5);
+        assert!(layout.pages.iter().all(|page| page.y <= MAX_ATLAS_SIDE));
+        let pages: Vec<usize> = layout.regions.iter().map(|r| r.page).collect();
+        assert_eq!(pages, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn empty_frames_get_empty_regions() {
+        let layout = pack_frames(&[UVec2::ZERO, UVec2::new(4, 2)]);
+        assert_eq!(layout.regions[0], AtlasRegion::default());
+        assert_eq!(layout.regions[1].rect.size(), UVec2::new(4, 2));
+        assert_eq!(pack_frames(&[UVec2::ZERO]).pages, Vec::<UVec2>::new());
+    }
+
+    #[test]
+    fn uv_and_uv_transform_address_the_frame() {
+        let layout = pack_frames(&[UVec2::new(4, 2)]);
+        let region = layout.regions[0];
+        let page = layout.pages[0].as_vec2();
+        assert_eq!(region.uv.min, region.rect.min.as_vec2() / page);
+        assert_eq!(region.uv.max, region.rect.max.as_vec2() / page);
+
+        let transform = atlas_uv_transform(region.uv);
+        assert!(
+            transform
+                .transform_point2(Vec2::ZERO)
+                .abs_diff_eq(region.uv.min, 1e-6)
+        );
+        assert!(
+            transform
+                .transform_point2(Vec2::ONE)
+                .abs_diff_eq(region.uv.max, 1e-6)
+        );
+    }
+
+    #[test]
+    fn padding_repeats_the_frame_edges() {
+        // 2x1 frame: red, blue.
+        let rgba = vec![255, 0, 0, 255, 0, 0, 255, 255];
+        let (images, regions) = build_atlas(&[(rgba, UVec2::new(2, 1))]);
+        let image = &images[0];
+        let width = image.texture_descriptor.size.width as usize;
+        let data = image.data.as_ref().unwrap();
+        let pixel = |x: u32, y: u32| {
+            let i = (y as usize * width + x as usize) * 4;
+            &data[i..i + 4]
+        };
+
+        let min = regions[0].rect.min;
+        assert_eq!(pixel(min.x, min.y), [255, 0, 0, 255]);
+        assert_eq!(pixel(min.x + 1, min.y), [0, 0, 255, 255]);
+        assert_eq!(pixel(min.x - 1, min.y - 1), [255, 0, 0, 255]);
+        assert_eq!(pixel(min.x + 2, min.y + 1), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn index_pages_hold_one_byte_per_pixel() {
+        let (images, regions) = build_index_atlas(&[
+            (vec![1, 2, 3, 4], UVec2::new(2, 2)),
+            (Vec::new(), UVec2::ZERO),
+        ]);
+        let image = &images[0];
+        assert_eq!(image.texture_descriptor.format, TextureFormat::R8Unorm);
+        let width = image.texture_descriptor.size.width as usize;
+        let data = image.data.as_ref().unwrap();
+        let min = regions[0].rect.min;
+        let at = |x: u32, y: u32| data[y as usize * width + x as usize];
+        assert_eq!([at(min.x, min.y), at(min.x + 1, min.y + 1)], [1, 4]);
+        assert_eq!(at(min.x - 1, min.y - 1), 1);
+        assert!(regions[1].rect.is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! Cache of built sprite animations and their atlas textures.
+//!
+//! Building an SPR+ACT pair packs its frames into atlas pages on the GPU.
+//! Every unit showing the same sprite shares one build: a pack of fifty
+//! Porings holds one set of pages, not fifty. An animation is in use while a
+//! layer still holds its handle. Once none does, it stays cached so the sprite
+//! can come back without a rebuild. Unused animations are evicted
+//! least-recently-used first once the cache outgrows the
+//! [`SpriteCacheBudget`](crate::domain::settings::SpriteCacheBudget) in the
+//! graphics settings. Dropping the last handle frees the asset, and with it
+//! its pages.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_persistent::prelude::Persistent;
+use moonshine_tag::Tag;
+
+use super::animation_processing_system::PendingAnimation;
+use super::loaders::{RoActAsset, RoSpriteAsset};
+use super::ro_animation_asset::RoAnimationAsset;
+use crate::domain::settings::resources::{Settings, Upscaling};
+
+/// What an animation was built from. Upscaling is part of the key so a
+/// settings change builds fresh pages instead of reusing the old ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpriteCacheKey {
+    sprite: AssetId<RoSpriteAsset>,
+    action: AssetId<RoActAsset>,
+    layer: Tag,
+    upscaling: Upscaling,
+}
+
+impl SpriteCacheKey {
+    pub fn new(request: &PendingAnimation, upscaling: Upscaling) -> Self {
+        Self {
+            sprite: request.sprite_handle.id(),
+            action: request.action_handle.id(),
+            layer: request.layer_tag,
+            upscaling,
+        }
+    }
+}
+
+struct CacheEntry {
+    animation: Handle<RoAnimationAsset>,
+    bytes: u64,
+    /// Cache clock when the animation was last handed out or seen in use.
+    last_used: u64,
+}
+
+impl CacheEntry {
+    /// Whether anything besides the cache holds the animation.
+    fn in_use(&self) -> bool {
+        match &self.animation {
+            Handle::Strong(handle) => std::sync::Arc::strong_count(handle) > 1,
+            Handle::Uuid(..) => false,
+        }
+    }
+}
+
+/// Counters for the diagnostics overlay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpriteCacheStats {
+    pub entries: usize,
+    pub in_use: usize,
+    pub bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+#[derive(Resource, Default)]
+pub struct SpriteTextureCache {
+    entries: HashMap<SpriteCacheKey, CacheEntry>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl SpriteTextureCache {
+    /// The animation already built for `key`, if it is cached.
+    pub fn get(&mut self, key: &SpriteCacheKey) -> Option<Handle<RoAnimationAsset>> {
+        self.clock += 1;
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                entry.last_used = self.clock;
+                self.hits += 1;
+                Some(entry.animation.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Caches a freshly built animation whose pages take `bytes`.
+    pub fn insert(&mut self, key: SpriteCacheKey, animation: Handle<RoAnimationAsset>, bytes: u64) {
+        self.clock += 1;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                animation,
+                bytes,
+                last_used: self.clock,
+            },
+        );
+    }
+
+    /// Drops unused animations, least recently used first, until the cache
+    /// fits `budget` bytes or only animations in use are left. Returns how
+    /// many were dropped.
+    pub fn evict(&mut self, budget: u64) -> usize {
+        self.clock += 1;
+        let clock = self.clock;
+        let mut total = 0;
+        let mut unused = Vec::new();
+        for (key, entry) in &mut self.entries {
+            total += entry.bytes;
+            if entry.in_use() {
+                entry.last_used = clock;
+            } else {
+                unused.push((entry.last_used, entry.bytes, *key));
+            }
+        }
+        if total <= budget {
+            return 0;
+        }
+
+        unused.sort_unstable_by_key(|&(last_used, ..)| last_used);
+        let mut evicted = 0;
+        for (_, bytes, key) in unused {
+            if total <= budget {
+                break;
+            }
+            self.entries.remove(&key);
+            total -= bytes;
+            evicted += 1;
+        }
+        self.evictions += evicted as u64;
+        evicted
+    }
+
+    pub fn stats(&self) -> SpriteCacheStats {
+        SpriteCacheStats {
+            entries: self.entries.len(),
+            in_use: self.entries.values().filter(|entry| entry.in_use()).count(),
+            bytes: self.entries.values().map(|entry| entry.bytes).sum(),
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+}
+
+/// Keeps the sprite cache within the budget from the graphics settings.
+pub fn evict_sprite_textures(
+    mut cache: ResMut<SpriteTextureCache>,
+    settings: Res<Persistent<Settings>>,
+) {
+    let evicted = cache.evict(settings.graphics.sprite_cache_budget.bytes());
+    if evicted > 0 {
+        debug!(
+            "evict_sprite_textures: Dropped {} unused sprite(s)",
+            evicted
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sprite::tags::LAYER_BODY;
+
+    fn key(index: u128) -> SpriteCacheKey {
+        SpriteCacheKey {
+            sprite: AssetId::Uuid {
+                uuid: bevy::asset::uuid::Uuid::from_u128(index),
+            },
+            action: AssetId::default(),
+            layer: LAYER_BODY,
+            upscaling: Upscaling::Off,
+        }
+    }
+
+    #[test]
+    fn unused_sprites_are_evicted_oldest_first_down_to_the_budget() {
+        let mut animations = Assets::<RoAnimationAsset>::default();
+        let mut cache = SpriteTextureCache::default();
+        for index in 0..3 {
+            cache.insert(key(index), animations.add(RoAnimationAsset::default()), 100);
+        }
+        // Touching the oldest makes the second the least recently used.
+        assert!(cache.get(&key(0)).is_some());
+
+        assert_eq!(cache.evict(300), 0);
+        assert_eq!(cache.evict(200), 1);
+        assert!(cache.get(&key(1)).is_none());
+        assert!(cache.get(&key(0)).is_some());
+        assert!(cache.get(&key(2)).is_some());
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn sprites_in_use_are_kept_over_budget() {
+        let mut animations = Assets::<RoAnimationAsset>::default();
+        let mut cache = SpriteTextureCache::default();
+        cache.insert(key(0), animations.add(RoAnimationAsset::default()), 100);
+        let held = cache.get(&key(0)).unwrap();
+
+        assert_eq!(cache.evict(0), 0);
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.in_use, stats.bytes), (1, 1, 100));
+
+        drop(held);
+        assert_eq!(cache.evict(0), 1);
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
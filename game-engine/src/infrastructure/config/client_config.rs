@@ -8,19 +8,79 @@ pub struct ClientConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
+    /// Login server host: a DNS name, an IPv4 or an IPv6 literal (unbracketed).
     pub ip: String,
     pub port: u16,
     #[serde(default = "default_client_version")]
     pub client_version: u32,
+    /// Seconds the login connection may take to open.
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Pre-step some servers put in front of the login server.
+    #[serde(default)]
+    pub auth: AuthBackendConfig,
 }
 
-fn default_client_version() -> u32 {
+/// `[server.auth]`: how the typed credentials become the ones the login
+/// server receives.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AuthBackendConfig {
+    /// Send the credentials to the login server as typed.
+    #[default]
+    Classic,
+    /// POST `{"username", "password"}` as JSON to `url` and log in with the
+    /// string the response carries under `token_field` as the password.
+    HttpToken {
+        url: String,
+        #[serde(default = "default_token_field")]
+        token_field: String,
+    },
+}
+
+impl AuthBackendConfig {
+    /// Whether the backend can work at all; checked when the config loads.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Classic => Ok(()),
+            Self::HttpToken { url, token_field } => {
+                let host = url
+                    .strip_prefix("https://")
+                    .or_else(|| url.strip_prefix("http://"))
+                    .ok_or_else(|| format!("http_token url must be http(s), got `{url}`"))?;
+                if host.is_empty() || host.starts_with('/') {
+                    return Err(format!("http_token url has no host: `{url}`"));
+                }
+                if token_field.is_empty() {
+                    return Err("http_token token_field is empty".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn default_token_field() -> String {
+    "token".to_string()
+}
+
+pub(crate) fn default_client_version() -> u32 {
     20180620
 }
 
+pub(crate) fn default_connect_timeout_secs() -> u64 {
+    10
+}
+
 impl ServerConfig {
+    /// `host:port`, with an IPv6 literal bracketed so the port stays separable.
     pub fn to_address(&self) -> String {
-        format!("{}:{}", self.ip, self.port)
+        let host = self.ip.trim_start_matches('[').trim_end_matches(']');
+        if host.parse::<std::net::Ipv6Addr>().is_ok() {
+            format!("[{host}]:{}", self.port)
+        } else {
+            format!("{host}:{}", self.port)
+        }
     }
 }
 
@@ -31,7 +91,74 @@ impl Default for ClientConfig {
                 ip: "127.0.0.1".to_string(),
                 port: 6900,
                 client_version: default_client_version(),
+                connect_timeout_secs: default_connect_timeout_secs(),
+                auth: AuthBackendConfig::default(),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ipv6_literals_are_bracketed_in_the_address() {
+        let server = |ip: &str| ServerConfig {
+            ip: ip.to_string(),
+            ..ClientConfig::default().server
+        };
+        assert_eq!(server("127.0.0.1").to_address(), "127.0.0.1:6900");
+        assert_eq!(
+            server("login.example.org").to_address(),
+            "login.example.org:6900"
+        );
+        assert_eq!(server("2001:db8::1").to_address(), "[2001:db8::1]:6900");
+        assert_eq!(server("[::1]").to_address(), "[::1]:6900");
+    }
+
+    #[test]
+    fn auth_backend_defaults_to_classic_and_parses_http_token() {
+        let plain: ClientConfig =
+            toml::from_str("[server]\nip = \"127.0.0.1\"\nport = 6900\n").unwrap();
+        assert_eq!(plain.server.auth, AuthBackendConfig::Classic);
+
+        let http: ClientConfig = toml::from_str(
+            "[server]\nip = \"127.0.0.1\"\nport = 6900\n\
+             [server.auth]\nkind = \"http_token\"\nurl = \"https://example.org/api/login\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            http.server.auth,
+            AuthBackendConfig::HttpToken {
+                url: "https://example.org/api/login".to_string(),
+                token_field: "token".to_string(),
+            }
+        );
+        assert_eq!(http.server.auth.validate(), Ok(()));
+    }
+
+    #[test]
+    fn http_token_needs_an_http_url_and_a_token_field() {
+        let http_token = |url: &str, token_field: &str| AuthBackendConfig::HttpToken {
+            url: url.to_string(),
+            token_field: token_field.to_string(),
+        };
+        assert!(
+            http_token("http://127.0.0.1:8080/login", "token")
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            http_token("ftp://example.org/login", "token")
+                .validate()
+                .is_err()
+        );
+        assert!(http_token("https:///login", "token").validate().is_err());
+        assert!(
+            http_token("https://example.org/login", "")
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! `clientinfo.xml` support.
+//!
+//! Server packs made for the reference client ship their login address as
+//! `data/clientinfo.xml` rather than our `clientinfo.toml`. [`ClientInfoXmlLoader`]
+//! reads the first `<connection>` into the same [`ClientConfig`], decoding the
+//! file with the charset its XML declaration names (usually `euc-kr`).
+//!
+//! `<version>` there is the packet obfuscation version, not a client date, so
+//! `client_version` keeps its default.
+
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use encoding_rs::{Encoding, UTF_8};
+use thiserror::Error;
+
+use super::{
+    AuthBackendConfig, ClientConfig, ServerConfig, default_client_version,
+    default_connect_timeout_secs,
+};
+
+#[derive(Default, TypePath)]
+pub struct ClientInfoXmlLoader;
+
+#[derive(Debug, Error)]
+pub enum ClientInfoXmlError {
+    #[error("Could not read clientinfo.xml: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("clientinfo.xml is not valid XML: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("clientinfo.xml has no <connection>")]
+    NoConnection,
+    #[error("clientinfo.xml <connection> has no <{0}>")]
+    MissingField(&'static str),
+    #[error("clientinfo.xml <port> is not a port: `{0}`")]
+    InvalidPort(String),
+}
+
+impl AssetLoader for ClientInfoXmlLoader {
+    type Asset = ClientConfig;
+    type Settings = ();
+    type Error = ClientInfoXmlError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        parse_client_info(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["xml"]
+    }
+}
+
+/// The charset named by the `<?xml ... encoding="..."?>` declaration; UTF-8
+/// when there is none or it is unknown.
+fn declared_encoding(bytes: &[u8]) -> &'static Encoding {
+    let head = &bytes[..bytes.len().min(128)];
+    let head = String::from_utf8_lossy(head);
+    head.strip_prefix("<?xml")
+        .and_then(|decl| decl.split("?>").next())
+        .and_then(|decl| decl.split("encoding=").nth(1))
+        .and_then(|value| {
+            let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            value[1..].split(quote).next()
+        })
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .unwrap_or(UTF_8)
+}
+
+pub fn parse_client_info(bytes: &[u8]) -> Result<ClientConfig, ClientInfoXmlError> {
+    let (text, _, _) = declared_encoding(bytes).decode(bytes);
+    // roxmltree only reads UTF-8 and rejects declarations naming anything else.
+    let text = match text.find("?>") {
+        Some(end) if text.starts_with("<?xml") => &text[end + 2..],
+        _ => &text[..],
+    };
+    let document = roxmltree::Document::parse(text)?;
+    let connection = document
+        .descendants()
+        .find(|node| node.has_tag_name("connection"))
+        .ok_or(ClientInfoXmlError::NoConnection)?;
+    let field = |name: &'static str| {
+        connection
+            .children()
+            .find(|node| node.has_tag_name(name))
+            .and_then(|node| node.text())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or(ClientInfoXmlError::MissingField(name))
+    };
+
+    let ip = field("address")?.to_string();
+    let port = field("port")?;
+    let port = port
+        .parse()
+        .map_err(|_| ClientInfoXmlError::InvalidPort(port.to_string()))?;
+    Ok(ClientConfig {
+        server: ServerConfig {
+            ip,
+            port,
+            client_version: default_client_version(),
+            connect_timeout_secs: default_connect_timeout_secs(),
+            auth: AuthBackendConfig::default(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_first_connection() {
+        let xml = br#"<?xml version="1.0" encoding="euc-kr" ?>
+<clientinfo>
+    <servicetype>korea</servicetype>
+    <!-- the live server -->
+    <connection>
+        <display>Endurnir</display>
+        <address> 192.168.0.10 </address>
+        <port>6900</port>
+        <version>55</version>
+    </connection>
+    <connection>
+        <display>Test</display>
+        <address>127.0.0.1</address>
+        <port>6901</port>
+    </connection>
+</clientinfo>"#;
+
+        let config = parse_client_info(xml).unwrap();
+
+        assert_eq!(config.server.to_address(), "192.168.0.10:6900");
+        assert_eq!(config.server.client_version, default_client_version());
+        assert_eq!(config.server.auth, AuthBackendConfig::Classic);
+    }
+
+    #[test]
+    fn decodes_the_declared_encoding() {
+        // "<display>서버</display>" in EUC-KR next to the address.
+        let mut xml =
+            br#"<?xml version="1.0" encoding="euc-kr"?><clientinfo><connection><display>"#.to_vec();
+        xml.extend_from_slice(&[0xBC, 0xAD, 0xB9, 0xF6]);
+        xml.extend_from_slice(
+            b"</display><address>10.0.0.1</address><port>7000</port></connection></clientinfo>",
+        );
+
+        assert_eq!(declared_encoding(&xml), encoding_rs::EUC_KR);
+        assert_eq!(
+            parse_client_info(&xml).unwrap().server.to_address(),
+            "10.0.0.1:7000"
+        );
+    }
+
+    #[test]
+    fn reports_what_is_missing() {
+        assert!(matches!(
+            parse_client_info(b"<clientinfo></clientinfo>"),
+            Err(ClientInfoXmlError::NoConnection)
+        ));
+        assert!(matches!(
+            parse_client_info(
+                b"<clientinfo><connection><port>6900</port></connection></clientinfo>"
+            ),
+            Err(ClientInfoXmlError::MissingField("address"))
+        ));
+        assert!(matches!(
+            parse_client_info(
+                b"<clientinfo><connection><address>a</address><port>x</port></connection></clientinfo>"
+            ),
+            Err(ClientInfoXmlError::InvalidPort(_))
+        ));
+    }
+}
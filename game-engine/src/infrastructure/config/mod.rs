@@ -1,3 +1,5 @@
 pub mod client_config;
+pub mod clientinfo_xml;
 
 pub use client_config::*;
+pub use clientinfo_xml::*;
@@ -0,0 +1,92 @@
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use crate::infrastructure::assets::SharedCompositeAssetSource;
+use crate::infrastructure::assets::sources::AssetLookupStats;
+
+/// `ro://` lookups no source could answer.
+pub const ASSET_LOOKUP_NOT_FOUND: DiagnosticPath = DiagnosticPath::const_new("assets/not_found");
+/// The part of those answered from the negative cache.
+pub const ASSET_LOOKUP_NEGATIVE_CACHE_HITS: DiagnosticPath =
+    DiagnosticPath::const_new("assets/negative_cache_hits");
+
+/// `assets/sources/<index>/hits` and `.../misses`, index in priority order.
+pub fn source_lookup_path(index: usize, counter: &str) -> DiagnosticPath {
+    DiagnosticPath::from_components(["assets", "sources", &index.to_string(), counter])
+}
+
+/// Register a diagnostic per source once the `ro://` sources exist. The source
+/// list is logged alongside, since the paths only carry the index.
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Startup
+)]
+pub fn register_asset_lookup_diagnostics(
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    store: Option<ResMut<DiagnosticsStore>>,
+) {
+    let (Some(sources), Some(mut store)) = (sources, store) else {
+        return;
+    };
+    let Ok(composite) = sources.0.read() else {
+        return;
+    };
+
+    store.add(Diagnostic::new(ASSET_LOOKUP_NOT_FOUND));
+    store.add(Diagnostic::new(ASSET_LOOKUP_NEGATIVE_CACHE_HITS));
+    for (index, source) in composite.lookup_stats().sources.iter().enumerate() {
+        store.add(Diagnostic::new(source_lookup_path(index, "hits")));
+        store.add(Diagnostic::new(source_lookup_path(index, "misses")));
+        debug!("assets/sources/{} = {}", index, source.name);
+    }
+}
+
+/// Publish the lookup counters, and log them every few seconds when they moved.
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn update_asset_lookup_diagnostics(
+    sources: Option<Res<SharedCompositeAssetSource>>,
+    mut diagnostics: Diagnostics,
+    time: Res<Time>,
+    mut timer: Local<f32>,
+    mut last_logged: Local<AssetLookupStats>,
+) {
+    let Some(sources) = sources else {
+        return;
+    };
+    let Ok(stats) = sources.0.read().map(|composite| composite.lookup_stats()) else {
+        return;
+    };
+
+    diagnostics.add_measurement(&ASSET_LOOKUP_NOT_FOUND, || stats.not_found as f64);
+    diagnostics.add_measurement(&ASSET_LOOKUP_NEGATIVE_CACHE_HITS, || {
+        stats.negative_cache_hits as f64
+    });
+    for (index, source) in stats.sources.iter().enumerate() {
+        diagnostics.add_measurement(&source_lookup_path(index, "hits"), || source.hits as f64);
+        diagnostics.add_measurement(&source_lookup_path(index, "misses"), || {
+            source.misses as f64
+        });
+    }
+
+    *timer += time.delta_secs();
+    if *timer >= 5.0 {
+        *timer = 0.0;
+        if stats != *last_logged {
+            for source in &stats.sources {
+                debug!(
+                    "Asset lookups: {} hits={} misses={}",
+                    source.name, source.hits, source.misses
+                );
+            }
+            debug!(
+                "Asset lookups: not found={} (negative cache hits={})",
+                stats.not_found, stats.negative_cache_hits
+            );
+            *last_logged = stats;
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Crash reports.
+//!
+//! [`install_crash_reporter`] chains a panic hook that writes the panic, a
+//! backtrace and what the session was doing — game state, map, the last
+//! packets' body tags — to `<data dir>/lifthrasir/crashes/last_crash.txt`
+//! before the default hook runs. The next launch files it away as
+//! `crash_<unix seconds>.txt` and points the user at it on the login screen,
+//! so "the client closed suddenly" arrives with something to go on.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use net_contract::diagnostics::{PacketTrace, RecentPackets};
+
+use crate::core::state::GameState;
+use crate::domain::world::spawn_context::MapSpawnContext;
+use crate::presentation::ui::events::{DialogSeverity, ShowSystemDialog, SystemDialogKind};
+
+const LAST_CRASH_FILE: &str = "last_crash.txt";
+
+/// `<data dir>/lifthrasir/crashes`.
+pub fn crash_report_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join("lifthrasir"))
+        .unwrap_or_default()
+        .join("crashes")
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionSnapshot {
+    pub game_state: String,
+    /// Map name and spawn cell of the last zone entry.
+    pub map: Option<(String, u16, u16)>,
+}
+
+/// What the session is doing, kept current for the panic hook, which may run
+/// on any thread.
+#[derive(Resource, Clone, Default)]
+#[auto_init_resource(plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin)]
+pub struct SessionJournal(Arc<Mutex<SessionSnapshot>>);
+
+impl SessionJournal {
+    pub fn snapshot(&self) -> SessionSnapshot {
+        // A panic while the lock was held still leaves a readable journal.
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn update_session_journal(
+    journal: Res<SessionJournal>,
+    state: Option<Res<State<GameState>>>,
+    map: Option<Res<MapSpawnContext>>,
+) {
+    let state_changed = state.as_ref().is_some_and(|state| state.is_changed());
+    let map_changed = map.as_ref().is_some_and(|map| map.is_changed());
+    if !state_changed && !map_changed {
+        return;
+    }
+    let Ok(mut snapshot) = journal.0.lock() else {
+        return;
+    };
+    if let Some(state) = state {
+        snapshot.game_state = format!("{:?}", state.get());
+    }
+    snapshot.map = map.map(|map| (map.map_name.clone(), map.spawn_x, map.spawn_y));
+}
+
+/// The report text for a panic with `message` raised at `location`.
+pub fn crash_report_text(
+    version: &str,
+    message: &str,
+    location: &str,
+    session: &SessionSnapshot,
+    packets: &[PacketTrace],
+    backtrace: &str,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Lifthrasir {version} crash report");
+    let _ = writeln!(out, "panic: {message}");
+    let _ = writeln!(out, "at: {location}");
+    let _ = writeln!(out, "state: {}", session.game_state);
+    match &session.map {
+        Some((name, x, y)) => {
+            let _ = writeln!(out, "map: {name} ({x}, {y})");
+        }
+        None => {
+            let _ = writeln!(out, "map: none");
+        }
+    }
+    let _ = writeln!(out, "\nlast {} packets, oldest first:", packets.len());
+    for packet in packets {
+        let _ = writeln!(out, "  {packet}");
+    }
+    let _ = writeln!(out, "\nbacktrace:\n{backtrace}");
+    out
+}
+
+fn panic_message(info: &PanicHookInfo<'_>) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".to_string())
+}
+
+/// Chains the crash-report panic hook in front of the current one, and files
+/// away the previous session's report, if any, for [`offer_previous_crash_report`].
+pub fn install_crash_reporter(app: &mut App, version: &'static str) {
+    let dir = crash_report_dir();
+    match take_previous_crash(&dir, SystemTime::now()) {
+        Ok(Some(report)) => {
+            app.insert_resource(report);
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Couldn't file away the last crash report: {e}"),
+    }
+
+    let journal = app
+        .world_mut()
+        .get_resource_or_init::<SessionJournal>()
+        .clone();
+    let packets = app
+        .world_mut()
+        .get_resource_or_init::<RecentPackets>()
+        .clone();
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let location = info
+            .location()
+            .map(|location| location.to_string())
+            .unwrap_or_default();
+        let report = crash_report_text(
+            version,
+            &panic_message(info),
+            &location,
+            &journal.snapshot(),
+            &packets.snapshot(),
+            &std::backtrace::Backtrace::force_capture().to_string(),
+        );
+        let _ =
+            fs::create_dir_all(&dir).and_then(|()| fs::write(dir.join(LAST_CRASH_FILE), report));
+        previous_hook(info);
+    }));
+}
+
+/// The last session's crash report, filed under its own name.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct PreviousCrash {
+    pub path: PathBuf,
+    /// The report's `panic:` line.
+    pub summary: String,
+}
+
+/// Renames a pending `last_crash.txt` in `dir` to `crash_<taken_at>.txt`.
+fn take_previous_crash(dir: &Path, taken_at: SystemTime) -> io::Result<Option<PreviousCrash>> {
+    let pending = dir.join(LAST_CRASH_FILE);
+    let report = match fs::read_to_string(&pending) {
+        Ok(report) => report,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let secs = taken_at
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or_default();
+    let path = dir.join(format!("crash_{secs}.txt"));
+    fs::rename(&pending, &path)?;
+    let summary = report
+        .lines()
+        .find_map(|line| line.strip_prefix("panic: "))
+        .unwrap_or_default()
+        .to_string();
+    Ok(Some(PreviousCrash { path, summary }))
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = OnEnter(GameState::Login)
+)]
+pub fn offer_previous_crash_report(
+    mut commands: Commands,
+    crash: Option<Res<PreviousCrash>>,
+    mut dialogs: MessageWriter<ShowSystemDialog>,
+) {
+    let Some(crash) = crash else {
+        return;
+    };
+    dialogs.write(ShowSystemDialog {
+        severity: DialogSeverity::Warn,
+        kind: SystemDialogKind::Generic,
+        kicker: "Crash report".into(),
+        title: "The client closed unexpectedly".into(),
+        message: format!(
+            "A report of the last session was saved to {}. Please attach it when reporting the problem.",
+            crash.path.display()
+        ),
+        code: crash.summary.clone(),
+        button_label: "OK".into(),
+        secondary_label: String::new(),
+        confirm_state: None,
+        correlation: None,
+    });
+    commands.remove_resource::<PreviousCrash>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_the_session_and_packets() {
+        let session = SessionSnapshot {
+            game_state: "InGame".to_string(),
+            map: Some(("prontera".to_string(), 156, 191)),
+        };
+        let packets = [
+            PacketTrace {
+                outbound: true,
+                channel: 0,
+                tag: Some(12),
+            },
+            PacketTrace {
+                outbound: false,
+                channel: 2,
+                tag: None,
+            },
+        ];
+
+        let report = crash_report_text(
+            "v1.2.3",
+            "index out of bounds",
+            "src/map.rs:10:5",
+            &session,
+            &packets,
+            "<backtrace>",
+        );
+
+        assert!(report.starts_with("Lifthrasir v1.2.3 crash report\npanic: index out of bounds\n"));
+        assert!(report.contains("state: InGame\nmap: prontera (156, 191)\n"));
+        assert!(report.contains("  out channel 0 tag 12\n  in  channel 2 tag ?\n"));
+        assert!(report.ends_with("backtrace:\n<backtrace>\n"));
+    }
+
+    #[test]
+    fn the_last_crash_is_filed_once() {
+        let dir = std::env::temp_dir().join(format!("lifthrasir-crash-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(LAST_CRASH_FILE),
+            "Lifthrasir v1 crash report\npanic: boom\n",
+        )
+        .unwrap();
+        let taken_at = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+
+        let crash = take_previous_crash(&dir, taken_at).unwrap().unwrap();
+
+        assert_eq!(crash.path, dir.join("crash_1700000000.txt"));
+        assert_eq!(crash.summary, "boom");
+        assert!(crash.path.exists());
+        assert_eq!(take_previous_crash(&dir, taken_at).unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use crate::domain::entities::markers::{
+    Elemental, Homunculus, LocalPlayer, Mercenary, Mob, Npc, RemotePlayer,
+};
+use crate::domain::item_drop::components::FloorItem;
+
+pub const ENTITIES_PLAYERS: DiagnosticPath = DiagnosticPath::const_new("entities/players");
+pub const ENTITIES_MOBS: DiagnosticPath = DiagnosticPath::const_new("entities/mobs");
+pub const ENTITIES_NPCS: DiagnosticPath = DiagnosticPath::const_new("entities/npcs");
+/// Homunculi, mercenaries and elementals.
+pub const ENTITIES_COMPANIONS: DiagnosticPath = DiagnosticPath::const_new("entities/companions");
+pub const ENTITIES_FLOOR_ITEMS: DiagnosticPath = DiagnosticPath::const_new("entities/floor_items");
+
+/// Every per-kind count, in overlay order.
+pub const ENTITY_KIND_PATHS: [(&str, DiagnosticPath); 5] = [
+    ("players", ENTITIES_PLAYERS),
+    ("mobs", ENTITIES_MOBS),
+    ("npcs", ENTITIES_NPCS),
+    ("companions", ENTITIES_COMPANIONS),
+    ("items", ENTITIES_FLOOR_ITEMS),
+];
+
+type PlayerFilter = Or<(With<LocalPlayer>, With<RemotePlayer>)>;
+type CompanionFilter = Or<(With<Homunculus>, With<Mercenary>, With<Elemental>)>;
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Startup
+)]
+pub fn register_entity_kind_diagnostics(store: Option<ResMut<DiagnosticsStore>>) {
+    let Some(mut store) = store else {
+        return;
+    };
+    for (_, path) in ENTITY_KIND_PATHS {
+        store.add(Diagnostic::new(path).with_max_history_length(1));
+    }
+}
+
+/// Counts the world's game entities by kind; the engine-wide total is bevy's
+/// `EntityCountDiagnosticsPlugin`.
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn update_entity_kind_diagnostics(
+    mut diagnostics: Diagnostics,
+    players: Query<(), PlayerFilter>,
+    mobs: Query<(), With<Mob>>,
+    npcs: Query<(), With<Npc>>,
+    companions: Query<(), CompanionFilter>,
+    floor_items: Query<(), With<FloorItem>>,
+) {
+    diagnostics.add_measurement(&ENTITIES_PLAYERS, || players.iter().len() as f64);
+    diagnostics.add_measurement(&ENTITIES_MOBS, || mobs.iter().len() as f64);
+    diagnostics.add_measurement(&ENTITIES_NPCS, || npcs.iter().len() as f64);
+    diagnostics.add_measurement(&ENTITIES_COMPANIONS, || companions.iter().len() as f64);
+    diagnostics.add_measurement(&ENTITIES_FLOOR_ITEMS, || floor_items.iter().len() as f64);
+}
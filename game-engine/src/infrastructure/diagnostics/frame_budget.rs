@@ -0,0 +1,335 @@
+//! Frame-time budget per system set.
+//!
+//! [`track_frame_budget`] brackets a chained run of system sets with a system
+//! ordered before its first set and one ordered after its last; the wall time
+//! between the two is that run's span for the frame. The multi-threaded
+//! executor may run unrelated systems inside the window, so a span is an upper
+//! bound on the sets' own cost. That is enough to see which part of the frame
+//! grew between two releases.
+//!
+//! Spans are taken in `InGame` only, where every tracked set runs. Each one is
+//! published as the `frame_budget/<label>` diagnostic (ms), and Ctrl+Shift+B
+//! writes [`FrameBudget::report`] as JSON to the data folder.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use bevy_auto_plugin::prelude::*;
+use serde::Serialize;
+
+use crate::core::state::GameState;
+use crate::domain::input::UiFocus;
+use crate::domain::system_sets::{
+    CameraSystems, CombatSystems, EntityLifecycleSystems, InputSystems, MovementSystems,
+    SpriteRenderingSystems,
+};
+
+/// Spans kept per label: ten seconds at 60 fps.
+const FRAME_BUDGET_HISTORY: usize = 600;
+
+pub fn frame_budget_path(label: &str) -> DiagnosticPath {
+    DiagnosticPath::new(format!("frame_budget/{label}"))
+}
+
+#[derive(Debug)]
+struct SetSpans {
+    label: &'static str,
+    started: Option<Instant>,
+    spans_ms: VecDeque<f64>,
+}
+
+/// Recent spans of every tracked label, in registration order.
+#[derive(Resource, Debug, Default)]
+#[auto_init_resource(plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin)]
+pub struct FrameBudget {
+    sets: Vec<SetSpans>,
+}
+
+impl FrameBudget {
+    fn register(&mut self, label: &'static str) {
+        if !self.sets.iter().any(|set| set.label == label) {
+            self.sets.push(SetSpans {
+                label,
+                started: None,
+                spans_ms: VecDeque::with_capacity(FRAME_BUDGET_HISTORY),
+            });
+        }
+    }
+
+    fn set_mut(&mut self, label: &str) -> Option<&mut SetSpans> {
+        self.sets.iter_mut().find(|set| set.label == label)
+    }
+
+    fn begin(&mut self, label: &str, now: Instant) {
+        if let Some(set) = self.set_mut(label) {
+            set.started = Some(now);
+        }
+    }
+
+    /// Closes the span opened by `begin` and returns it in milliseconds.
+    fn end(&mut self, label: &str, now: Instant) -> Option<f64> {
+        let set = self.set_mut(label)?;
+        let span_ms = now.duration_since(set.started.take()?).as_secs_f64() * 1000.0;
+        if set.spans_ms.len() == FRAME_BUDGET_HISTORY {
+            set.spans_ms.pop_front();
+        }
+        set.spans_ms.push_back(span_ms);
+        Some(span_ms)
+    }
+
+    /// Mean, 95th percentile and worst span of each label that has samples.
+    pub fn report(&self) -> FrameBudgetReport {
+        let sets = self
+            .sets
+            .iter()
+            .filter(|set| !set.spans_ms.is_empty())
+            .map(|set| {
+                let mut sorted: Vec<f64> = set.spans_ms.iter().copied().collect();
+                sorted.sort_by(f64::total_cmp);
+                let p95 = (sorted.len() * 95).div_ceil(100).saturating_sub(1);
+                SetBudget {
+                    label: set.label.to_string(),
+                    samples: sorted.len(),
+                    mean_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+                    p95_ms: sorted[p95],
+                    max_ms: sorted[sorted.len() - 1],
+                }
+            })
+            .collect();
+        FrameBudgetReport { sets }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SetBudget {
+    pub label: String,
+    pub samples: usize,
+    pub mean_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FrameBudgetReport {
+    pub sets: Vec<SetBudget>,
+}
+
+/// Times the chained sets `first..=last` of `schedule` under `label`. Pass
+/// the same set twice to time a single one.
+pub fn track_frame_budget(
+    app: &mut App,
+    schedule: impl ScheduleLabel + Clone,
+    label: &'static str,
+    first: impl SystemSet,
+    last: impl SystemSet,
+) {
+    app.init_resource::<FrameBudget>();
+    app.world_mut()
+        .resource_mut::<FrameBudget>()
+        .register(label);
+    if let Some(mut store) = app.world_mut().get_resource_mut::<DiagnosticsStore>() {
+        store.add(Diagnostic::new(frame_budget_path(label)).with_suffix("ms"));
+    }
+
+    let path = frame_budget_path(label);
+    app.add_systems(
+        schedule.clone(),
+        (move |mut budget: ResMut<FrameBudget>| budget.begin(label, Instant::now()))
+            .before(first)
+            .run_if(in_state(GameState::InGame)),
+    )
+    .add_systems(
+        schedule,
+        (move |mut budget: ResMut<FrameBudget>, mut diagnostics: Diagnostics| {
+            if let Some(span_ms) = budget.end(label, Instant::now()) {
+                diagnostics.add_measurement(&path, || span_ms);
+            }
+        })
+        .after(last)
+        .run_if(in_state(GameState::InGame)),
+    );
+}
+
+/// Tracks the engine's own system sets and bevy's UI layout. Adapters track
+/// theirs with [`track_frame_budget`] where they are added.
+pub struct FrameBudgetPlugin;
+
+impl Plugin for FrameBudgetPlugin {
+    fn build(&self, app: &mut App) {
+        track_frame_budget(
+            app,
+            Update,
+            "input",
+            InputSystems::Raycast,
+            InputSystems::Click,
+        );
+        track_frame_budget(
+            app,
+            Update,
+            "entity_lifecycle",
+            EntityLifecycleSystems::Vanishing,
+            EntityLifecycleSystems::Despawning,
+        );
+        track_frame_budget(
+            app,
+            Update,
+            "movement",
+            MovementSystems::Confirm,
+            MovementSystems::TerrainAlignment,
+        );
+        track_frame_budget(
+            app,
+            Update,
+            "sprite_rendering",
+            SpriteRenderingSystems::HierarchySpawn,
+            SpriteRenderingSystems::OrphanCleanup,
+        );
+        track_frame_budget(
+            app,
+            Update,
+            "combat",
+            CombatSystems::ProcessActions,
+            CombatSystems::HandleDeath,
+        );
+        track_frame_budget(
+            app,
+            Update,
+            "camera",
+            CameraSystems::TargetUpdate,
+            CameraSystems::Sequence,
+        );
+        track_frame_budget(
+            app,
+            PostUpdate,
+            "ui_layout",
+            UiSystems::Prepare,
+            UiSystems::PostLayout,
+        );
+    }
+}
+
+/// `<data dir>/lifthrasir/frame_budget_<unix seconds>.json`.
+fn frame_budget_dump_path(taken_at: SystemTime) -> PathBuf {
+    let secs = taken_at
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or_default();
+    dirs::data_dir()
+        .map(|dir| dir.join("lifthrasir"))
+        .unwrap_or_default()
+        .join(format!("frame_budget_{secs}.json"))
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn dump_frame_budget(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    focus: Option<Res<UiFocus>>,
+    budget: Res<FrameBudget>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    if focus.is_some_and(|focus| focus.text_input_active) {
+        return;
+    }
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if !(ctrl && shift && keys.just_pressed(KeyCode::KeyB)) {
+        return;
+    }
+
+    let path = frame_budget_dump_path(SystemTime::now());
+    let written = serde_json::to_vec_pretty(&budget.report())
+        .map_err(std::io::Error::other)
+        .and_then(|json| {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&path, json)
+        });
+    match written {
+        Ok(()) => info!("Frame budget written to {}", path.display()),
+        Err(e) => error!("Failed to write frame budget to {}: {e}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use std::time::Duration;
+
+    #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct SlowSystems;
+
+    #[test]
+    fn report_summarises_each_label() {
+        let mut budget = FrameBudget::default();
+        budget.register("movement");
+        budget.register("idle");
+        let start = Instant::now();
+        for ms in 1..=20 {
+            budget.begin("movement", start);
+            budget.end("movement", start + Duration::from_millis(ms));
+        }
+
+        let report = budget.report();
+
+        assert_eq!(report.sets.len(), 1, "labels without samples are left out");
+        let movement = &report.sets[0];
+        assert_eq!(movement.label, "movement");
+        assert_eq!(movement.samples, 20);
+        assert!((movement.mean_ms - 10.5).abs() < 1e-6);
+        assert!((movement.p95_ms - 19.0).abs() < 1e-6);
+        assert!((movement.max_ms - 20.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn tracked_sets_are_timed_in_game_only() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<DiagnosticsStore>();
+        track_frame_budget(&mut app, Update, "slow", SlowSystems, SlowSystems);
+        app.add_systems(
+            Update,
+            (|| std::thread::sleep(Duration::from_millis(2))).in_set(SlowSystems),
+        );
+
+        app.update();
+        assert!(
+            app.world()
+                .resource::<FrameBudget>()
+                .report()
+                .sets
+                .is_empty()
+        );
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+        app.update();
+
+        let report = app.world().resource::<FrameBudget>().report();
+        assert!(report.sets[0].max_ms >= 2.0, "{report:?}");
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert!(
+            store
+                .get(&frame_budget_path("slow"))
+                .unwrap()
+                .value()
+                .unwrap()
+                >= 2.0
+        );
+    }
+}
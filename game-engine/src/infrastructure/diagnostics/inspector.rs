@@ -0,0 +1,225 @@
+//! Live entity inspector.
+//!
+//! [`EntityInspection`] gathers the components a bug report usually needs
+//! about one unit — where it stands, what it is, what it is doing and what it
+//! wears — keyed by the server's gid. The diagnostics window shows the last
+//! entity hovered; dev builds serve any gid over BRP.
+
+use std::fmt::Write;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::domain::entities::character::components::visual::CharacterDirection;
+use crate::domain::entities::character::components::{CharacterData, EquipmentSet};
+use crate::domain::entities::components::{EntityName, NetworkEntity};
+use crate::domain::entities::movement::components::{MovementState, MovementTarget};
+use crate::domain::entities::sprite_rendering::components::{MobSprite, PlayerSprite};
+use crate::domain::entities::sprite_rendering::{EntitySpriteData, EntitySpriteInfo};
+use crate::utils::coordinates::world_position_to_spawn_coords;
+
+/// Components [`EntityInspection::new`] reads.
+pub type InspectedComponents = (
+    &'static NetworkEntity,
+    &'static Transform,
+    Option<&'static EntityName>,
+    Option<&'static CharacterData>,
+    Option<&'static EntitySpriteInfo>,
+    Option<&'static CharacterDirection>,
+    Option<&'static PlayerSprite>,
+    Option<&'static MobSprite>,
+    Option<&'static MovementState>,
+    Option<&'static MovementTarget>,
+    Option<&'static EquipmentSet>,
+);
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct EntityInspection {
+    pub gid: u32,
+    pub aid: u32,
+    pub kind: String,
+    pub name: Option<String>,
+    pub cell: (u16, u16),
+    pub position: [f32; 3],
+    pub job: Option<u16>,
+    pub sprite: Option<String>,
+    pub facing: Option<String>,
+    pub action: Option<String>,
+    pub movement: Option<String>,
+    pub destination: Option<(u16, u16)>,
+    /// `(slot, item id)` of every equipped slot.
+    pub equipment: Vec<(String, u32)>,
+}
+
+impl EntityInspection {
+    pub fn new(
+        (
+            network,
+            transform,
+            name,
+            character,
+            sprite_info,
+            direction,
+            player_sprite,
+            mob_sprite,
+            movement,
+            target,
+            equipment,
+        ): <InspectedComponents as bevy::ecs::query::QueryData>::Item<'_, '_>,
+    ) -> Self {
+        let (job, sprite) = match sprite_info.map(|info| &info.sprite_data) {
+            Some(EntitySpriteData::Character { job_id, .. }) => (Some(*job_id), None),
+            Some(
+                EntitySpriteData::Mob { sprite_name }
+                | EntitySpriteData::Npc { sprite_name }
+                | EntitySpriteData::Item { sprite_name },
+            ) => (None, Some(sprite_name.clone())),
+            None => (character.map(|character| character.job_id), None),
+        };
+        let action = player_sprite
+            .map(|sprite| sprite.action_type)
+            .or(mob_sprite.map(|sprite| sprite.action_type));
+        let equipment = equipment
+            .map(|set| {
+                [
+                    ("head_top", &set.head_top),
+                    ("head_mid", &set.head_mid),
+                    ("head_bottom", &set.head_bottom),
+                    ("weapon", &set.weapon),
+                    ("shield", &set.shield),
+                    ("armor", &set.armor),
+                    ("garment", &set.garment),
+                    ("shoes", &set.shoes),
+                    ("accessory_left", &set.accessories[0]),
+                    ("accessory_right", &set.accessories[1]),
+                ]
+                .into_iter()
+                .filter_map(|(slot, item)| Some((slot.to_string(), item.as_ref()?.item_id)))
+                .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            gid: network.gid,
+            aid: network.aid,
+            kind: format!("{:?}", network.object_type),
+            name: name.map(|name| name.name.clone()),
+            cell: world_position_to_spawn_coords(transform.translation, 0, 0),
+            position: transform.translation.to_array(),
+            job,
+            sprite,
+            facing: direction.map(|direction| format!("{:?}", direction.facing)),
+            action: action.map(|action| format!("{action:?}")),
+            movement: movement.map(|state| format!("{state:?}")),
+            destination: target.map(|target| (target.dest_x, target.dest_y)),
+            equipment,
+        }
+    }
+
+    /// Multi-line text for the diagnostics window.
+    pub fn report(&self) -> String {
+        let mut out = format!("Entity {} ({})", self.gid, self.kind);
+        let unknown = || "-".to_string();
+        let _ = write!(
+            out,
+            "\n  name: {}",
+            self.name.clone().unwrap_or_else(unknown)
+        );
+        let _ = write!(out, "\n  cell: {:?}", self.cell);
+        if let Some(job) = self.job {
+            let _ = write!(out, "\n  job: {job}");
+        }
+        if let Some(sprite) = &self.sprite {
+            let _ = write!(out, "\n  sprite: {sprite}");
+        }
+        let _ = write!(
+            out,
+            "\n  action: {} facing {}",
+            self.action.clone().unwrap_or_else(unknown),
+            self.facing.clone().unwrap_or_else(unknown)
+        );
+        let _ = write!(
+            out,
+            "\n  movement: {}",
+            self.movement.clone().unwrap_or_else(unknown)
+        );
+        if let Some((x, y)) = self.destination {
+            let _ = write!(out, " to ({x}, {y})");
+        }
+        for (slot, item_id) in &self.equipment {
+            let _ = write!(out, "\n  {slot}: {item_id}");
+        }
+        out
+    }
+}
+
+/// The network entity with `gid`, inspected; `None` if it isn't spawned.
+pub fn inspect_entity(world: &mut World, gid: u32) -> Option<EntityInspection> {
+    let mut query = world.query::<InspectedComponents>();
+    query
+        .iter(world)
+        .find(|(network, ..)| network.gid == gid)
+        .map(EntityInspection::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::character::components::EquipmentItem;
+    use crate::domain::entities::types::ObjectType;
+    use crate::utils::coordinates::spawn_coords_to_world_position;
+
+    #[test]
+    fn inspects_a_spawned_mob_by_gid() {
+        let mut world = World::new();
+        world.spawn((
+            NetworkEntity::new(7, 110_000, ObjectType::Mob),
+            Transform::from_translation(spawn_coords_to_world_position(150, 180, 0, 0)),
+            EntityName::new("Poring".to_string()),
+            EntitySpriteInfo {
+                sprite_data: EntitySpriteData::Mob {
+                    sprite_name: "poring".to_string(),
+                },
+            },
+            MovementState::Moving,
+        ));
+
+        let inspection = inspect_entity(&mut world, 110_000).expect("spawned");
+
+        assert_eq!(inspection.kind, "Mob");
+        assert_eq!(inspection.name.as_deref(), Some("Poring"));
+        assert_eq!(inspection.cell, (150, 180));
+        assert_eq!(inspection.sprite.as_deref(), Some("poring"));
+        assert_eq!(inspection.movement.as_deref(), Some("Moving"));
+        assert!(inspection.report().contains("movement: Moving"));
+        assert_eq!(inspect_entity(&mut world, 1), None);
+    }
+
+    #[test]
+    fn lists_equipped_slots_only() {
+        let mut world = World::new();
+        let item = |item_id| EquipmentItem {
+            item_id,
+            sprite_id: 0,
+            refinement: 0,
+            enchantments: Vec::new(),
+            options: Vec::new(),
+        };
+        world.spawn((
+            NetworkEntity::new(2_000_001, 150_000, ObjectType::Pc),
+            Transform::default(),
+            EquipmentSet {
+                weapon: Some(item(1201)),
+                head_top: Some(item(2220)),
+                ..default()
+            },
+        ));
+
+        let inspection = inspect_entity(&mut world, 150_000).expect("spawned");
+
+        assert_eq!(
+            inspection.equipment,
+            vec![("head_top".to_string(), 2220), ("weapon".to_string(), 1201)]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Session logs on disk.
+//!
+//! [`client_log_layers`] tees what the console shows into
+//! `<data dir>/lifthrasir/logs/lifthrasir.log`, without colour codes, so users
+//! can attach it to a bug report. Each launch starts a fresh file and shifts
+//! the previous ones to `lifthrasir.1.log`, `lifthrasir.2.log`, …; a file that
+//! outgrows [`LogFileConfig::max_bytes`] is rotated the same way mid-session.
+//!
+//! `LIFTHRASIR_LOG_DIR=<dir>` writes the files elsewhere and an empty value
+//! turns them off.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use bevy::log::BoxedLayer;
+use bevy::log::tracing_subscriber::fmt;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use super::capture_recent_warnings;
+
+const LOG_DIR_ENV: &str = "LIFTHRASIR_LOG_DIR";
+const LOG_FILE_STEM: &str = "lifthrasir";
+
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LogFileConfig {
+    /// Folder for the log files; `None` keeps logs on the console only.
+    pub dir: Option<PathBuf>,
+    /// Size at which the current file is rotated.
+    pub max_bytes: u64,
+    /// Files kept, the current one included.
+    pub max_files: usize,
+}
+
+impl Default for LogFileConfig {
+    fn default() -> Self {
+        Self {
+            dir: Some(
+                dirs::data_dir()
+                    .map(|dir| dir.join("lifthrasir"))
+                    .unwrap_or_default()
+                    .join("logs"),
+            ),
+            max_bytes: 10 * 1024 * 1024,
+            max_files: 5,
+        }
+    }
+}
+
+impl LogFileConfig {
+    /// The defaults, with the folder taken from `LIFTHRASIR_LOG_DIR` if set.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(dir) = std::env::var_os(LOG_DIR_ENV) {
+            config.dir = (!dir.is_empty()).then(|| PathBuf::from(dir));
+        }
+        config
+    }
+}
+
+/// `lifthrasir.log` for `index` 0, else `lifthrasir.<index>.log`.
+pub fn log_file_path(dir: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        dir.join(format!("{LOG_FILE_STEM}.log"))
+    } else {
+        dir.join(format!("{LOG_FILE_STEM}.{index}.log"))
+    }
+}
+
+/// Shifts every file up one index, overwriting the oldest, so that index 0 is
+/// free.
+fn rotate_log_files(dir: &Path, max_files: usize) -> io::Result<()> {
+    for index in (0..max_files.saturating_sub(1)).rev() {
+        match fs::rename(log_file_path(dir, index), log_file_path(dir, index + 1)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+struct RotatingLogFile {
+    dir: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    written: u64,
+}
+
+impl RotatingLogFile {
+    fn open(dir: &Path, config: &LogFileConfig) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        rotate_log_files(dir, config.max_files)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            max_bytes: config.max_bytes,
+            max_files: config.max_files,
+            file: File::create(log_file_path(dir, 0))?,
+            written: 0,
+        })
+    }
+}
+
+impl Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.file.flush()?;
+            rotate_log_files(&self.dir, self.max_files)?;
+            self.file = File::create(log_file_path(&self.dir, 0))?;
+            self.written = 0;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Where this session's logs are written.
+#[derive(Resource, Debug, Clone)]
+pub struct LogFiles {
+    pub dir: PathBuf,
+}
+
+impl LogFiles {
+    pub fn current(&self) -> PathBuf {
+        log_file_path(&self.dir, 0)
+    }
+
+    /// The last `limit` lines of this session's log, oldest first.
+    pub fn recent_lines(&self, limit: usize) -> io::Result<Vec<String>> {
+        let log = fs::read_to_string(self.current())?;
+        let lines: Vec<&str> = log.lines().collect();
+        let start = lines.len().saturating_sub(limit);
+        Ok(lines[start..].iter().map(|line| line.to_string()).collect())
+    }
+}
+
+/// Why the log file couldn't be opened, reported once logging is up.
+#[derive(Resource, Debug)]
+struct LogFileError(String);
+
+/// `LogPlugin::custom_layer` hook: the file tee, configured by the app's
+/// [`LogFileConfig`] (from the environment if none was inserted).
+pub fn capture_log_file(app: &mut App) -> Option<BoxedLayer> {
+    let config = app
+        .world_mut()
+        .get_resource_or_insert_with(LogFileConfig::from_env)
+        .clone();
+    let dir = config.dir.clone()?;
+    match RotatingLogFile::open(&dir, &config) {
+        Ok(file) => {
+            app.insert_resource(LogFiles { dir });
+            Some(Box::new(
+                fmt::layer().with_ansi(false).with_writer(Mutex::new(file)),
+            ))
+        }
+        Err(e) => {
+            app.insert_resource(LogFileError(format!("{}: {e}", dir.display())));
+            None
+        }
+    }
+}
+
+/// The client's `LogPlugin::custom_layer`: [`capture_recent_warnings`] and
+/// [`capture_log_file`] together.
+pub fn client_log_layers(app: &mut App) -> Option<BoxedLayer> {
+    let layers: Vec<BoxedLayer> = [capture_recent_warnings(app), capture_log_file(app)]
+        .into_iter()
+        .flatten()
+        .collect();
+    Some(Box::new(layers))
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Startup
+)]
+fn report_log_file_error(mut commands: Commands, error: Option<Res<LogFileError>>) {
+    if let Some(error) = error {
+        warn!(
+            "Logging to the console only; couldn't open the log file {}",
+            error.0
+        );
+        commands.remove_resource::<LogFileError>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::log::tracing_subscriber::layer::SubscriberExt;
+    use bevy::log::tracing_subscriber::registry::Registry;
+
+    fn temp_log_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("lifthrasir-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn each_launch_starts_a_fresh_file_and_keeps_the_last_few() {
+        let dir = temp_log_dir("log-launch");
+        let config = LogFileConfig {
+            dir: Some(dir.clone()),
+            max_bytes: 1024,
+            max_files: 3,
+        };
+        for session in 0..4 {
+            let mut file = RotatingLogFile::open(&dir, &config).unwrap();
+            writeln!(file, "session {session}").unwrap();
+        }
+
+        let read = |index| fs::read_to_string(log_file_path(&dir, index)).unwrap();
+        assert_eq!(read(0), "session 3\n");
+        assert_eq!(read(1), "session 2\n");
+        assert_eq!(read(2), "session 1\n");
+        assert!(!log_file_path(&dir, 3).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_full_file_is_rotated_mid_session() {
+        let dir = temp_log_dir("log-size");
+        let config = LogFileConfig {
+            dir: Some(dir.clone()),
+            max_bytes: 16,
+            max_files: 2,
+        };
+        let mut file = RotatingLogFile::open(&dir, &config).unwrap();
+        file.write_all(b"0123456789\n").unwrap();
+        file.write_all(b"abcdefghij\n").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(log_file_path(&dir, 1)).unwrap(),
+            "0123456789\n"
+        );
+        assert_eq!(
+            fs::read_to_string(log_file_path(&dir, 0)).unwrap(),
+            "abcdefghij\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn logged_events_can_be_read_back() {
+        let dir = temp_log_dir("log-tee");
+        let mut app = App::new();
+        app.insert_resource(LogFileConfig {
+            dir: Some(dir.clone()),
+            ..default()
+        });
+        let layer = client_log_layers(&mut app).expect("layer");
+
+        let subscriber = Registry::default().with(layer);
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            info!("map loaded");
+            warn!("asset missing");
+        });
+
+        let lines = app.world().resource::<LogFiles>().recent_lines(1).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("WARN"), "{lines:?}");
+        assert!(lines[0].ends_with("asset missing"), "{lines:?}");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
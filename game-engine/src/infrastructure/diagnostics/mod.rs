@@ -1,8 +1,28 @@
 mod animation_diagnostics;
+mod asset_lookup_diagnostics;
+mod crash_report;
+mod entity_kind_diagnostics;
+mod frame_budget;
+mod inspector;
+mod log_files;
+mod overlay;
 mod performance_logger;
+mod recent_warnings;
+mod sprite_cache_diagnostics;
+mod window;
 
 pub use animation_diagnostics::*;
+pub use asset_lookup_diagnostics::*;
+pub use crash_report::*;
+pub use entity_kind_diagnostics::*;
+pub use frame_budget::*;
+pub use inspector::*;
+pub use log_files::*;
+pub use overlay::*;
 pub use performance_logger::*;
+pub use recent_warnings::*;
+pub use sprite_cache_diagnostics::*;
+pub use window::*;
 
 use bevy_auto_plugin::prelude::*;
 
@@ -10,6 +30,12 @@ use bevy_auto_plugin::prelude::*;
 /// Note: FrameTimeDiagnosticsPlugin, EntityCountDiagnosticsPlugin, and RenderDiagnosticsPlugin
 /// are already added by DefaultPlugins
 /// SystemInformationDiagnosticsPlugin is NOT added as it's not supported on macOS
+///
+/// Ctrl+Shift+D toggles the in-game [`DiagnosticsOverlay`]. Its warning list
+/// needs [`capture_recent_warnings`] installed as the `LogPlugin::custom_layer`;
+/// [`client_log_layers`] installs it along with the session log files.
+/// Ctrl+Shift+B writes the [`FrameBudget`] report to the data folder.
+/// Ctrl+Shift+W opens the same report in a separate [`DiagnosticsWindow`].
 #[derive(AutoPlugin)]
 #[auto_plugin(impl_plugin_trait)]
 pub struct RoDiagnosticsPlugin;
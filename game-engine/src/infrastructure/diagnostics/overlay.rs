@@ -0,0 +1,307 @@
+//! Toggleable in-game diagnostics overlay (Ctrl+Shift+D).
+//!
+//! Gathers what users are asked for in bug reports into one panel: frame
+//! rate, round-trip time per network connection, packets per second, `ro://`
+//! asset lookups per source, game entities by kind, sprite texture memory and
+//! the last few warnings.
+//! Everything is read from the `DiagnosticsStore` and [`RecentWarnings`], so
+//! the panel costs nothing while hidden.
+
+use std::fmt::Write;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+    FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use net_contract::diagnostics::{CONNECTION_RTT_PREFIX, NETWORK_RX_PACKETS, NETWORK_TX_PACKETS};
+
+use super::{
+    ASSET_LOOKUP_NEGATIVE_CACHE_HITS, ASSET_LOOKUP_NOT_FOUND, ENTITY_KIND_PATHS, RecentWarnings,
+    SPRITE_CACHE_BUDGET_MB, SPRITE_CACHE_ENTRIES, SPRITE_CACHE_EVICTIONS, SPRITE_CACHE_IN_USE,
+    SPRITE_CACHE_MB,
+};
+use crate::domain::input::UiFocus;
+
+/// Seconds between text refreshes while the overlay is shown.
+const OVERLAY_REFRESH_SECS: f32 = 0.25;
+const OVERLAY_FONT_SIZE: f32 = 12.0;
+/// Above the game UI, below the fade overlay (`i32::MAX - 1`).
+const OVERLAY_Z: i32 = 1000;
+
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin)]
+pub struct DiagnosticsOverlay {
+    pub visible: bool,
+}
+
+#[derive(Component)]
+pub struct DiagnosticsOverlayRoot;
+
+#[derive(Component)]
+pub struct DiagnosticsOverlayText;
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn toggle_diagnostics_overlay(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    focus: Option<Res<UiFocus>>,
+    mut overlay: ResMut<DiagnosticsOverlay>,
+    roots: Query<Entity, With<DiagnosticsOverlayRoot>>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    if focus.is_some_and(|focus| focus.text_input_active) {
+        return;
+    }
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if !(ctrl && shift && keys.just_pressed(KeyCode::KeyD)) {
+        return;
+    }
+
+    overlay.visible = !overlay.visible;
+    if !overlay.visible {
+        for root in &roots {
+            commands.entity(root).despawn();
+        }
+        return;
+    }
+    commands.spawn((
+        DiagnosticsOverlayRoot,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(40.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        GlobalZIndex(OVERLAY_Z),
+        Pickable::IGNORE,
+        children![(
+            DiagnosticsOverlayText,
+            Text::new(""),
+            TextFont {
+                font_size: OVERLAY_FONT_SIZE.into(),
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update,
+    config(after = toggle_diagnostics_overlay)
+)]
+pub fn refresh_diagnostics_overlay(
+    overlay: Res<DiagnosticsOverlay>,
+    store: Option<Res<DiagnosticsStore>>,
+    warnings: Option<Res<RecentWarnings>>,
+    time: Res<Time<Real>>,
+    mut since_refresh: Local<f32>,
+    mut texts: Query<&mut Text, With<DiagnosticsOverlayText>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    *since_refresh += time.delta_secs();
+    let just_opened = texts.iter().any(|text| text.0.is_empty());
+    if *since_refresh < OVERLAY_REFRESH_SECS && !just_opened {
+        return;
+    }
+    *since_refresh = 0.0;
+
+    let Some(store) = store else {
+        return;
+    };
+    let warnings = warnings.map(|w| w.snapshot()).unwrap_or_default();
+    let report = overlay_report(&store, &warnings);
+    for mut text in &mut texts {
+        text.0.clone_from(&report);
+    }
+}
+
+fn latest(store: &DiagnosticsStore, path: &DiagnosticPath) -> Option<f64> {
+    store.get(path).and_then(Diagnostic::value)
+}
+
+fn smoothed(store: &DiagnosticsStore, path: &DiagnosticPath) -> Option<f64> {
+    store.get(path).and_then(Diagnostic::smoothed)
+}
+
+/// Renders the overlay text; sections whose diagnostics aren't registered
+/// (no network adapter, no `ro://` sources) are left out.
+pub fn overlay_report(store: &DiagnosticsStore, warnings: &[String]) -> String {
+    let mut out = String::new();
+
+    if let Some(fps) = smoothed(store, &FrameTimeDiagnosticsPlugin::FPS) {
+        let frame_ms = smoothed(store, &FrameTimeDiagnosticsPlugin::FRAME_TIME).unwrap_or(0.0);
+        let _ = writeln!(out, "FPS {fps:.0} ({frame_ms:.1} ms)");
+    }
+
+    let rtts: Vec<(&str, f64)> = store
+        .iter()
+        .filter_map(|diagnostic| {
+            let id = diagnostic
+                .path()
+                .as_str()
+                .strip_prefix(CONNECTION_RTT_PREFIX)?
+                .strip_prefix('/')?
+                .strip_suffix("/rtt")?;
+            Some((id, diagnostic.smoothed()?))
+        })
+        .collect();
+    let rx = smoothed(store, &NETWORK_RX_PACKETS);
+    let tx = smoothed(store, &NETWORK_TX_PACKETS);
+    if !rtts.is_empty() || rx.is_some() {
+        let _ = writeln!(out, "Network");
+        for (id, rtt) in rtts {
+            let _ = writeln!(out, "  connection {id}: rtt {rtt:.0} ms");
+        }
+        let _ = writeln!(
+            out,
+            "  packets/s: in {:.0}, out {:.0}",
+            rx.unwrap_or(0.0),
+            tx.unwrap_or(0.0)
+        );
+    }
+
+    if let Some(not_found) = latest(store, &ASSET_LOOKUP_NOT_FOUND) {
+        let _ = writeln!(out, "Assets");
+        for index in 0.. {
+            let hits = latest(store, &super::source_lookup_path(index, "hits"));
+            let misses = latest(store, &super::source_lookup_path(index, "misses"));
+            let (Some(hits), Some(misses)) = (hits, misses) else {
+                break;
+            };
+            let lookups = hits + misses;
+            let rate = if lookups > 0.0 {
+                hits / lookups * 100.0
+            } else {
+                0.0
+            };
+            let _ = writeln!(
+                out,
+                "  source {index}: {hits:.0} hits, {misses:.0} misses ({rate:.0}% hit)"
+            );
+        }
+        let negative = latest(store, &ASSET_LOOKUP_NEGATIVE_CACHE_HITS).unwrap_or(0.0);
+        let _ = writeln!(
+            out,
+            "  not found: {not_found:.0} ({negative:.0} from the negative cache)"
+        );
+    }
+
+    let kinds: Vec<String> = ENTITY_KIND_PATHS
+        .iter()
+        .filter_map(|(label, path)| Some(format!("{label} {:.0}", latest(store, path)?)))
+        .collect();
+    if !kinds.is_empty() {
+        let _ = writeln!(out, "Entities");
+        let _ = write!(out, "  {}", kinds.join(", "));
+        if let Some(total) = latest(store, &EntityCountDiagnosticsPlugin::ENTITY_COUNT) {
+            let _ = write!(out, " (total {total:.0})");
+        }
+        out.push('\n');
+    }
+
+    if let Some(entries) = latest(store, &SPRITE_CACHE_ENTRIES) {
+        let in_use = latest(store, &SPRITE_CACHE_IN_USE).unwrap_or(0.0);
+        let mb = latest(store, &SPRITE_CACHE_MB).unwrap_or(0.0);
+        let budget = latest(store, &SPRITE_CACHE_BUDGET_MB).unwrap_or(0.0);
+        let evictions = latest(store, &SPRITE_CACHE_EVICTIONS).unwrap_or(0.0);
+        let _ = writeln!(out, "Sprite cache");
+        let _ = writeln!(
+            out,
+            "  {entries:.0} sprites ({in_use:.0} in use), {mb:.0} / {budget:.0} MB, {evictions:.0} evicted"
+        );
+    }
+
+    if !warnings.is_empty() {
+        let _ = writeln!(out, "Warnings");
+        for warning in warnings {
+            let _ = writeln!(out, "  {warning}");
+        }
+    }
+
+    out.truncate(out.trim_end().len());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::diagnostic::DiagnosticMeasurement;
+    use bevy::platform::time::Instant;
+    use net_contract::diagnostics::connection_rtt_path;
+
+    fn measure(store: &mut DiagnosticsStore, path: DiagnosticPath, value: f64) {
+        store.add(Diagnostic::new(path.clone()));
+        store
+            .get_mut(&path)
+            .unwrap()
+            .add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+    }
+
+    #[test]
+    fn report_lists_each_connection_assets_entities_sprites_and_warnings() {
+        let mut store = DiagnosticsStore::default();
+        measure(&mut store, connection_rtt_path(3), 42.0);
+        measure(&mut store, NETWORK_RX_PACKETS, 30.0);
+        measure(&mut store, NETWORK_TX_PACKETS, 12.0);
+        measure(&mut store, ASSET_LOOKUP_NOT_FOUND, 2.0);
+        measure(&mut store, ASSET_LOOKUP_NEGATIVE_CACHE_HITS, 1.0);
+        measure(
+            &mut store,
+            super::super::source_lookup_path(0, "hits"),
+            90.0,
+        );
+        measure(
+            &mut store,
+            super::super::source_lookup_path(0, "misses"),
+            10.0,
+        );
+        for (_, path) in ENTITY_KIND_PATHS {
+            measure(&mut store, path, 1.0);
+        }
+        measure(&mut store, SPRITE_CACHE_ENTRIES, 40.0);
+        measure(&mut store, SPRITE_CACHE_IN_USE, 12.0);
+        measure(&mut store, SPRITE_CACHE_MB, 180.0);
+        measure(&mut store, SPRITE_CACHE_BUDGET_MB, 256.0);
+        measure(&mut store, SPRITE_CACHE_EVICTIONS, 3.0);
+
+        let report = overlay_report(&store, &["WARN lifthrasir: slow frame".to_string()]);
+
+        assert!(report.contains("connection 3: rtt 42 ms"), "{report}");
+        assert!(report.contains("packets/s: in 30, out 12"), "{report}");
+        assert!(
+            report.contains("source 0: 90 hits, 10 misses (90% hit)"),
+            "{report}"
+        );
+        assert!(report.contains("not found: 2 (1 from the negative cache)"));
+        assert!(report.contains("players 1, mobs 1, npcs 1, companions 1, items 1"));
+        assert!(
+            report.contains("40 sprites (12 in use), 180 / 256 MB, 3 evicted"),
+            "{report}"
+        );
+        assert!(report.ends_with("WARN lifthrasir: slow frame"), "{report}");
+    }
+
+    #[test]
+    fn report_leaves_out_unregistered_sections() {
+        let report = overlay_report(&DiagnosticsStore::default(), &[]);
+        assert!(report.is_empty(), "{report}");
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use bevy::log::BoxedLayer;
+use bevy::log::tracing::field::{Field, Visit};
+use bevy::log::tracing::{Event, Level, Subscriber};
+use bevy::log::tracing_subscriber::Layer;
+use bevy::log::tracing_subscriber::layer::Context;
+use bevy::prelude::*;
+
+/// Warnings kept for the diagnostics overlay.
+pub const RECENT_WARNING_CAPACITY: usize = 8;
+
+/// The last [`RECENT_WARNING_CAPACITY`] warnings and errors logged, oldest
+/// first. Shared with the tracing layer from [`capture_recent_warnings`],
+/// which fills it from whatever thread logs.
+#[derive(Resource, Clone, Default)]
+pub struct RecentWarnings(Arc<Mutex<VecDeque<String>>>);
+
+impl RecentWarnings {
+    pub fn push(&self, line: String) {
+        let Ok(mut lines) = self.0.lock() else {
+            return;
+        };
+        if lines.len() == RECENT_WARNING_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    pub fn snapshot(&self) -> Vec<String> {
+        self.0
+            .lock()
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// `LogPlugin::custom_layer` hook: feeds WARN and ERROR events into the app's
+/// [`RecentWarnings`].
+pub fn capture_recent_warnings(app: &mut App) -> Option<BoxedLayer> {
+    let warnings = app
+        .world_mut()
+        .get_resource_or_init::<RecentWarnings>()
+        .clone();
+    Some(Box::new(WarningLayer(warnings)))
+}
+
+struct WarningLayer(RecentWarnings);
+
+impl<S: Subscriber> Layer<S> for WarningLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // Levels order by verbosity: ERROR < WARN < INFO.
+        if *metadata.level() > Level::WARN {
+            return;
+        }
+        let mut message = MessageField(String::new());
+        event.record(&mut message);
+        self.0.push(format!(
+            "{} {}: {}",
+            metadata.level(),
+            metadata.target(),
+            message.0
+        ));
+    }
+}
+
+struct MessageField(String);
+
+impl Visit for MessageField {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0 = value.to_string();
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::log::tracing_subscriber::layer::SubscriberExt;
+    use bevy::log::tracing_subscriber::registry::Registry;
+
+    #[test]
+    fn warnings_and_errors_are_kept_newest_last() {
+        let mut app = App::new();
+        let layer = capture_recent_warnings(&mut app).expect("layer");
+        let subscriber = Registry::default().with(layer);
+
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            info!("not kept");
+            for n in 0..RECENT_WARNING_CAPACITY {
+                warn!("warning {n}");
+            }
+            error!("asset missing");
+        });
+
+        let lines = app.world().resource::<RecentWarnings>().snapshot();
+        assert_eq!(lines.len(), RECENT_WARNING_CAPACITY);
+        assert!(lines[0].ends_with("warning 1"), "{lines:?}");
+        assert!(lines.last().unwrap().starts_with("ERROR"));
+        assert!(lines.last().unwrap().ends_with("asset missing"));
+    }
+}
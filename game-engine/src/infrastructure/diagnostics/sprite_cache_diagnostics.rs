@@ -0,0 +1,65 @@
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_persistent::prelude::Persistent;
+
+use crate::domain::settings::resources::Settings;
+use crate::infrastructure::assets::SpriteTextureCache;
+
+/// Built sprite animations held by the cache.
+pub const SPRITE_CACHE_ENTRIES: DiagnosticPath = DiagnosticPath::const_new("sprites/cache/entries");
+/// The part of those a unit on screen still uses.
+pub const SPRITE_CACHE_IN_USE: DiagnosticPath = DiagnosticPath::const_new("sprites/cache/in_use");
+/// Texture memory of the cached atlas pages, in MB.
+pub const SPRITE_CACHE_MB: DiagnosticPath = DiagnosticPath::const_new("sprites/cache/mb");
+/// The budget from the graphics settings, in MB.
+pub const SPRITE_CACHE_BUDGET_MB: DiagnosticPath =
+    DiagnosticPath::const_new("sprites/cache/budget_mb");
+/// Animations evicted since startup.
+pub const SPRITE_CACHE_EVICTIONS: DiagnosticPath =
+    DiagnosticPath::const_new("sprites/cache/evictions");
+
+const SPRITE_CACHE_PATHS: [DiagnosticPath; 5] = [
+    SPRITE_CACHE_ENTRIES,
+    SPRITE_CACHE_IN_USE,
+    SPRITE_CACHE_MB,
+    SPRITE_CACHE_BUDGET_MB,
+    SPRITE_CACHE_EVICTIONS,
+];
+
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Startup
+)]
+pub fn register_sprite_cache_diagnostics(store: Option<ResMut<DiagnosticsStore>>) {
+    let Some(mut store) = store else {
+        return;
+    };
+    for path in SPRITE_CACHE_PATHS {
+        store.add(Diagnostic::new(path));
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn update_sprite_cache_diagnostics(
+    cache: Option<Res<SpriteTextureCache>>,
+    settings: Option<Res<Persistent<Settings>>>,
+    mut diagnostics: Diagnostics,
+) {
+    let (Some(cache), Some(settings)) = (cache, settings) else {
+        return;
+    };
+    let stats = cache.stats();
+    let budget = settings.graphics.sprite_cache_budget.bytes();
+
+    diagnostics.add_measurement(&SPRITE_CACHE_ENTRIES, || stats.entries as f64);
+    diagnostics.add_measurement(&SPRITE_CACHE_IN_USE, || stats.in_use as f64);
+    diagnostics.add_measurement(&SPRITE_CACHE_MB, || stats.bytes as f64 / BYTES_PER_MB);
+    diagnostics.add_measurement(&SPRITE_CACHE_BUDGET_MB, || budget as f64 / BYTES_PER_MB);
+    diagnostics.add_measurement(&SPRITE_CACHE_EVICTIONS, || stats.evictions as f64);
+}
@@ -0,0 +1,214 @@
+//! Detached diagnostics window (Ctrl+Shift+W).
+//!
+//! Opens a second OS window with its own UI camera showing the overlay's
+//! report, the recent packet log and an [`EntityInspection`] of the last
+//! entity hovered, so developers can watch the numbers without covering the
+//! game. Closing it, or the chord again, tears down the
+//! window, camera and text together.
+
+use std::fmt::Write;
+
+use bevy::camera::RenderTarget;
+use bevy::diagnostic::DiagnosticsStore;
+use bevy::prelude::*;
+use bevy::window::WindowRef;
+use bevy_auto_plugin::prelude::*;
+use net_contract::diagnostics::{PacketTrace, RecentPackets};
+
+use super::{EntityInspection, InspectedComponents, RecentWarnings, overlay_report};
+use crate::domain::entities::hover::CurrentlyHoveredEntity;
+use crate::domain::input::UiFocus;
+
+/// Seconds between text refreshes while the window is open.
+const WINDOW_REFRESH_SECS: f32 = 0.25;
+const WINDOW_FONT_SIZE: f32 = 13.0;
+
+/// The open diagnostics window, if any.
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin)]
+pub struct DiagnosticsWindow {
+    window: Option<Entity>,
+}
+
+/// The camera and UI root drawn into the diagnostics window.
+#[derive(Component)]
+pub struct DiagnosticsWindowView;
+
+#[derive(Component)]
+pub struct DiagnosticsWindowText;
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update
+)]
+pub fn toggle_diagnostics_window(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    focus: Option<Res<UiFocus>>,
+    mut state: ResMut<DiagnosticsWindow>,
+    windows: Query<(), With<Window>>,
+    views: Query<Entity, With<DiagnosticsWindowView>>,
+) {
+    // Closed from the title bar: the window entity is gone, its view isn't.
+    if state.window.is_some_and(|window| !windows.contains(window)) {
+        state.window = None;
+        for view in &views {
+            commands.entity(view).despawn();
+        }
+    }
+
+    let Some(keys) = keys else {
+        return;
+    };
+    if focus.is_some_and(|focus| focus.text_input_active) {
+        return;
+    }
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if !(ctrl && shift && keys.just_pressed(KeyCode::KeyW)) {
+        return;
+    }
+
+    if let Some(window) = state.window.take() {
+        commands.entity(window).despawn();
+        for view in &views {
+            commands.entity(view).despawn();
+        }
+        return;
+    }
+    let window = commands
+        .spawn(Window {
+            title: "Lifthrasir diagnostics".into(),
+            resolution: (520, 720).into(),
+            ..default()
+        })
+        .id();
+    let camera = commands
+        .spawn((
+            DiagnosticsWindowView,
+            Camera2d,
+            RenderTarget::Window(WindowRef::Entity(window)),
+            Name::new("DiagnosticsWindowCamera"),
+        ))
+        .id();
+    commands.spawn((
+        DiagnosticsWindowView,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.05, 0.05, 0.07)),
+        UiTargetCamera(camera),
+        children![(
+            DiagnosticsWindowText,
+            Text::new(""),
+            TextFont {
+                font_size: WINDOW_FONT_SIZE.into(),
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        )],
+    ));
+    state.window = Some(window);
+}
+
+#[auto_add_system(
+    plugin = crate::infrastructure::diagnostics::RoDiagnosticsPlugin,
+    schedule = Update,
+    config(after = toggle_diagnostics_window)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_diagnostics_window(
+    state: Res<DiagnosticsWindow>,
+    store: Option<Res<DiagnosticsStore>>,
+    warnings: Option<Res<RecentWarnings>>,
+    packets: Option<Res<RecentPackets>>,
+    hovered: Option<Res<CurrentlyHoveredEntity>>,
+    inspected: Query<InspectedComponents>,
+    time: Res<Time<Real>>,
+    mut since_refresh: Local<f32>,
+    mut last_hovered: Local<Option<Entity>>,
+    mut texts: Query<&mut Text, With<DiagnosticsWindowText>>,
+) {
+    if state.window.is_none() {
+        return;
+    }
+    // Keep inspecting the last entity once the cursor moves off it.
+    if let Some(entity) = hovered.and_then(|hovered| hovered.entity) {
+        *last_hovered = Some(entity);
+    }
+    *since_refresh += time.delta_secs();
+    let just_opened = texts.iter().any(|text| text.0.is_empty());
+    if *since_refresh < WINDOW_REFRESH_SECS && !just_opened {
+        return;
+    }
+    *since_refresh = 0.0;
+
+    let Some(store) = store else {
+        return;
+    };
+    let warnings = warnings.map(|w| w.snapshot()).unwrap_or_default();
+    let packets = packets.map(|p| p.snapshot()).unwrap_or_default();
+    let mut report = overlay_report(&store, &warnings);
+    let packet_log = packet_log_report(&packets);
+    if !packet_log.is_empty() {
+        if !report.is_empty() {
+            report.push_str("\n\n");
+        }
+        report.push_str(&packet_log);
+    }
+    if let Some(inspection) = last_hovered
+        .and_then(|entity| inspected.get(entity).ok())
+        .map(EntityInspection::new)
+    {
+        if !report.is_empty() {
+            report.push_str("\n\n");
+        }
+        report.push_str(&inspection.report());
+    }
+    for mut text in &mut texts {
+        text.0.clone_from(&report);
+    }
+}
+
+/// The packet trail, newest first; empty before any packet.
+pub fn packet_log_report(packets: &[PacketTrace]) -> String {
+    let mut out = String::new();
+    if packets.is_empty() {
+        return out;
+    }
+    let _ = write!(out, "Packets (newest first)");
+    for packet in packets.iter().rev() {
+        let _ = write!(out, "\n  {packet}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packet_log_lists_the_newest_first() {
+        let packets = [
+            PacketTrace {
+                outbound: true,
+                channel: 0,
+                tag: Some(3),
+            },
+            PacketTrace {
+                outbound: false,
+                channel: 1,
+                tag: Some(40),
+            },
+        ];
+
+        assert_eq!(
+            packet_log_report(&packets),
+            "Packets (newest first)\n  in  channel 1 tag 40\n  out channel 0 tag 3"
+        );
+        assert!(packet_log_report(&[]).is_empty());
+    }
+}
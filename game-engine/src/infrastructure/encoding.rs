@@ -0,0 +1,148 @@
+//! EUC-KR text and the Korean names of the data tree.
+//!
+//! The reference client's tables, GRF file names and sprite folders are
+//! EUC-KR Korean. Loaders decode through [`decode_euc_kr`], path builders take
+//! their folder names from [`names`], and [`ResourceNames`] caches the lookup
+//! key and English gloss of paths that are resolved over and over, so every
+//! part of the client spells a resource the same way.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use bevy::prelude::*;
+use encoding_rs::EUC_KR;
+pub use ro_formats::decode_euc_kr_mojibake;
+
+use super::assets::sources::asset_path_key;
+
+/// Korean folder and file-name parts of the data tree.
+pub mod names {
+    pub const HUMAN: &str = "인간족";
+    pub const DORAM: &str = "도람족";
+    pub const MONSTER: &str = "몬스터";
+    pub const USER_INTERFACE: &str = "유저인터페이스";
+    pub const BODY: &str = "몸통";
+    pub const HEAD: &str = "머리통";
+    pub const ACCESSORY: &str = "악세사리";
+    pub const SHIELD: &str = "방패";
+    pub const MALE: &str = "남";
+    pub const FEMALE: &str = "여";
+}
+
+/// English for the [`names`], as [`gloss_path`] shows them.
+const GLOSSES: &[(&str, &str)] = &[
+    (names::HUMAN, "human"),
+    (names::DORAM, "doram"),
+    (names::MONSTER, "monster"),
+    (names::USER_INTERFACE, "user_interface"),
+    (names::BODY, "body"),
+    (names::HEAD, "head"),
+    (names::ACCESSORY, "accessory"),
+    (names::SHIELD, "shield"),
+    (names::MALE, "male"),
+    (names::FEMALE, "female"),
+];
+
+/// `bytes` as EUC-KR, malformed sequences replaced.
+pub fn decode_euc_kr(bytes: &[u8]) -> String {
+    EUC_KR.decode(bytes).0.into_owned()
+}
+
+/// `path` with every folder or file stem in [`names`] replaced by its English
+/// gloss, for showing Korean paths to people who don't read Korean. Not a
+/// path anything can be loaded from.
+pub fn gloss_path(path: &str) -> String {
+    let path = decode_euc_kr_mojibake(path);
+    path.split_inclusive(['/', '\\'])
+        .map(|segment| {
+            let (name, separator) = match segment.strip_suffix(['/', '\\']) {
+                Some(name) => (name, &segment[name.len()..]),
+                None => (segment, ""),
+            };
+            let (stem, extension) = match name.rsplit_once('.') {
+                Some((stem, extension)) => (stem, Some(extension)),
+                None => (name, None),
+            };
+            let gloss = GLOSSES
+                .iter()
+                .find(|(korean, _)| *korean == stem)
+                .map_or(stem, |(_, english)| english);
+            match extension {
+                Some(extension) => format!("{gloss}.{extension}{separator}"),
+                None => format!("{gloss}{separator}"),
+            }
+        })
+        .collect()
+}
+
+/// Cached [`asset_path_key`]s and [`gloss_path`]s.
+#[derive(Resource, Clone, Default)]
+pub struct ResourceNames {
+    keys: Arc<RwLock<HashMap<String, String>>>,
+    glosses: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl ResourceNames {
+    pub fn key(&self, path: &str) -> String {
+        Self::cached(&self.keys, path, asset_path_key)
+    }
+
+    pub fn gloss(&self, path: &str) -> String {
+        Self::cached(&self.glosses, path, gloss_path)
+    }
+
+    fn cached(
+        cache: &RwLock<HashMap<String, String>>,
+        path: &str,
+        convert: fn(&str) -> String,
+    ) -> String {
+        if let Some(hit) = cache.read().ok().and_then(|cache| cache.get(path).cloned()) {
+            return hit;
+        }
+        let converted = convert(path);
+        if let Ok(mut cache) = cache.write() {
+            cache.insert(path.to_string(), converted.clone());
+        }
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::WINDOWS_1252;
+
+    #[test]
+    fn decodes_euc_kr() {
+        let (bytes, _, _) = EUC_KR.encode("몬스터/포링");
+        assert_eq!(decode_euc_kr(&bytes), "몬스터/포링");
+    }
+
+    #[test]
+    fn glosses_known_folders_and_keeps_the_rest() {
+        assert_eq!(
+            gloss_path("data\\sprite\\인간족\\몸통\\남\\초보자_남.spr"),
+            "data\\sprite\\human\\body\\male\\초보자_남.spr"
+        );
+        assert_eq!(
+            gloss_path("data/texture/유저인터페이스/item/apple.bmp"),
+            "data/texture/user_interface/item/apple.bmp"
+        );
+    }
+
+    #[test]
+    fn mojibake_paths_get_the_same_key_and_gloss() {
+        let korean = "data/sprite/몬스터/poring.spr";
+        let (euc_kr, _, _) = EUC_KR.encode(korean);
+        let (mojibake, _) = WINDOWS_1252.decode_without_bom_handling(&euc_kr);
+        let names = ResourceNames::default();
+
+        assert_eq!(
+            names.key(&mojibake),
+            names.key("Data\\Sprite\\몬스터\\PORING.SPR")
+        );
+        assert_eq!(names.gloss(&mojibake), "data/sprite/monster/poring.spr");
+        assert_eq!(names.keys.read().unwrap().len(), 2);
+        assert_eq!(names.key(korean), korean);
+    }
+}
@@ -1,120 +1,224 @@
 use std::collections::HashMap;
 
-pub fn get_player_job_sprite_mapping() -> HashMap<u32, &'static str> {
-    let mut map = HashMap::new();
-
-    map.insert(0, "초보자");
-    map.insert(1, "검사");
-    map.insert(2, "마법사");
-    map.insert(3, "궁수");
-    map.insert(4, "성직자");
-    map.insert(5, "상인");
-    map.insert(6, "도둑");
-    map.insert(7, "기사");
-    map.insert(8, "프리스트");
-    map.insert(9, "위저드");
-    map.insert(10, "제철공");
-    map.insert(11, "헌터");
-    map.insert(12, "어세신");
-    map.insert(14, "크루세이더");
-    map.insert(15, "몽크");
-    map.insert(16, "세이지");
-    map.insert(17, "로그");
-    map.insert(18, "연금술사");
-    map.insert(19, "바드");
-    map.insert(20, "무희");
-    map.insert(23, "슈퍼노비스");
-    map.insert(24, "건너");
-    map.insert(25, "닌자");
-
-    map.insert(4001, "초보자");
-    map.insert(4002, "검사");
-    map.insert(4003, "마법사");
-    map.insert(4004, "궁수");
-    map.insert(4005, "성직자");
-    map.insert(4006, "상인");
-    map.insert(4007, "도둑");
-    map.insert(4008, "기사");
-    map.insert(4009, "프리스트");
-    map.insert(4010, "위저드");
-    map.insert(4011, "제철공");
-    map.insert(4012, "헌터");
-    map.insert(4013, "어세신");
-    map.insert(4015, "크루세이더");
-    map.insert(4016, "몽크");
-    map.insert(4017, "세이지");
-    map.insert(4018, "로그");
-    map.insert(4019, "연금술사");
-    map.insert(4020, "바드");
-    map.insert(4021, "무희");
-    map.insert(4023, "슈퍼노비스");
-    map.insert(4024, "건너");
-    map.insert(4025, "닌자");
-    map.insert(4046, "태권소년");
-    map.insert(4047, "권성");
-    map.insert(4049, "소울링커");
+use crate::domain::entities::character::components::core::Gender;
 
-    map.insert(4054, "로드나이트");
-    map.insert(4055, "하이프리");
-    map.insert(4056, "하이위저드");
-    map.insert(4057, "화이트스미스");
-    map.insert(4058, "스나이퍼");
-    map.insert(4059, "어쌔신크로스");
-    map.insert(4060, "로드페코");
-    map.insert(4061, "팔라딘");
-    map.insert(4062, "챔피온");
-    map.insert(4063, "프로페서");
-    map.insert(4064, "스토커");
-    map.insert(4065, "크리에이터");
-    map.insert(4066, "클라운");
-    map.insert(4067, "클라운");
+/// Body sprite name of every player job, as the client's `jobname.lub` spells
+/// them: the folder under `인간족/몸통/{sex}/` (`도람족/` for Doram jobs) and
+/// the file stem before `_{sex}`. Mounted jobs (peco, dragon, gryphon, warg,
+/// madogear) have their own ids and sprites; baby jobs reuse the adult ones.
+const PLAYER_JOB_SPRITES: &[(u32, &str)] = &[
+    (0, "초보자"),
+    (1, "검사"),
+    (2, "마법사"),
+    (3, "궁수"),
+    (4, "성직자"),
+    (5, "상인"),
+    (6, "도둑"),
+    (7, "기사"),
+    (8, "프리스트"),
+    (9, "위저드"),
+    (10, "제철공"),
+    (11, "헌터"),
+    (12, "어세신"),
+    (13, "페코페코_기사"),
+    (14, "크루세이더"),
+    (15, "몽크"),
+    (16, "세이지"),
+    (17, "로그"),
+    (18, "연금술사"),
+    (19, "바드"),
+    (20, "무희"),
+    (21, "신페코크루세이더"),
+    (22, "결혼"),
+    (23, "슈퍼노비스"),
+    (24, "건너"),
+    (25, "닌자"),
+    (26, "산타"),
+    (27, "여름"),
+    (28, "한복"),
+    (29, "오크토버"),
+    (30, "여름2"),
+    // Transcendent
+    (4001, "초보자"),
+    (4002, "검사"),
+    (4003, "마법사"),
+    (4004, "궁수"),
+    (4005, "성직자"),
+    (4006, "상인"),
+    (4007, "도둑"),
+    (4008, "로드나이트"),
+    (4009, "하이프리"),
+    (4010, "하이위저드"),
+    (4011, "화이트스미스"),
+    (4012, "스나이퍼"),
+    (4013, "어쌔신크로스"),
+    (4014, "로드페코"),
+    (4015, "팔라딘"),
+    (4016, "챔피온"),
+    (4017, "프로페서"),
+    (4018, "스토커"),
+    (4019, "크리에이터"),
+    (4020, "클라운"),
+    (4021, "집시"),
+    (4022, "페코팔라딘"),
+    // Baby
+    (4023, "초보자"),
+    (4024, "검사"),
+    (4025, "마법사"),
+    (4026, "궁수"),
+    (4027, "성직자"),
+    (4028, "상인"),
+    (4029, "도둑"),
+    (4030, "기사"),
+    (4031, "프리스트"),
+    (4032, "위저드"),
+    (4033, "제철공"),
+    (4034, "헌터"),
+    (4035, "어세신"),
+    (4036, "페코페코_기사"),
+    (4037, "크루세이더"),
+    (4038, "몽크"),
+    (4039, "세이지"),
+    (4040, "로그"),
+    (4041, "연금술사"),
+    (4042, "바드"),
+    (4043, "무희"),
+    (4044, "신페코크루세이더"),
+    (4045, "슈퍼노비스"),
+    // Expanded
+    (4046, "태권소년"),
+    (4047, "권성"),
+    (4048, "권성융합"),
+    (4049, "소울링커"),
+    // Third
+    (4054, "룬나이트"),
+    (4055, "워록"),
+    (4056, "레인져"),
+    (4057, "아크비숍"),
+    (4058, "미케닉"),
+    (4059, "길로틴크로스"),
+    (4060, "룬나이트"),
+    (4061, "워록"),
+    (4062, "레인져"),
+    (4063, "아크비숍"),
+    (4064, "미케닉"),
+    (4065, "길로틴크로스"),
+    (4066, "가드"),
+    (4067, "소서러"),
+    (4068, "민스트럴"),
+    (4069, "원더러"),
+    (4070, "슈라"),
+    (4071, "제네릭"),
+    (4072, "쉐도우체이서"),
+    (4073, "가드"),
+    (4074, "소서러"),
+    (4075, "민스트럴"),
+    (4076, "원더러"),
+    (4077, "슈라"),
+    (4078, "제네릭"),
+    (4079, "쉐도우체이서"),
+    (4080, "룬나이트쁘띠"),
+    (4081, "룬나이트쁘띠"),
+    (4082, "그리폰가드"),
+    (4083, "그리폰가드"),
+    (4084, "레인져늑대"),
+    (4085, "레인져늑대"),
+    (4086, "마도기어"),
+    (4087, "마도기어"),
+    // Baby third
+    (4096, "룬나이트"),
+    (4097, "워록"),
+    (4098, "레인져"),
+    (4099, "아크비숍"),
+    (4100, "미케닉"),
+    (4101, "길로틴크로스"),
+    (4102, "가드"),
+    (4103, "소서러"),
+    (4104, "민스트럴"),
+    (4105, "원더러"),
+    (4106, "슈라"),
+    (4107, "제네릭"),
+    (4108, "쉐도우체이서"),
+    (4109, "룬나이트쁘띠"),
+    (4110, "그리폰가드"),
+    (4111, "레인져늑대"),
+    (4112, "마도기어"),
+    (4190, "슈퍼노비스"),
+    (4191, "슈퍼노비스"),
+    (4211, "kagerou"),
+    (4212, "oboro"),
+    (4215, "rebellion"),
+    (4218, "소환사"),
+    (4220, "소환사"),
+    (4222, "닌자"),
+    (4223, "kagerou"),
+    (4224, "oboro"),
+    (4225, "태권소년"),
+    (4226, "권성"),
+    (4227, "소울링커"),
+    (4228, "건너"),
+    (4229, "rebellion"),
+    (4238, "권성융합"),
+    (4239, "성제"),
+    (4240, "소울리퍼"),
+    (4241, "성제"),
+    (4242, "소울리퍼"),
+    (4243, "성제융합"),
+    (4244, "성제융합"),
+    // Fourth
+    (4252, "dragon_knight"),
+    (4253, "meister"),
+    (4254, "shadow_cross"),
+    (4255, "arch_mage"),
+    (4256, "cardinal"),
+    (4257, "windhawk"),
+    (4258, "imperial_guard"),
+    (4259, "biolo"),
+    (4260, "abyss_chaser"),
+    (4261, "elemetal_master"),
+    (4262, "inquisitor"),
+    (4263, "troubadour"),
+    (4264, "trouvere"),
+    (4278, "windhawk2"),
+    (4279, "meister_madogear2"),
+    (4280, "dragon_knight_chicken"),
+    (4281, "imperial_guard_chicken"),
+    (4302, "sky_emperor"),
+    (4303, "soul_ascetic"),
+    (4304, "shinkiro"),
+    (4305, "shiranui"),
+    (4306, "night_watch"),
+    (4307, "hyper_novice"),
+    (4308, "spirit_handler"),
+    (4316, "sky_emperor2"),
+];
 
-    map.insert(4096, "드래곤나이트");
-    map.insert(4097, "성투사2");
-    map.insert(4098, "소서러");
-    map.insert(4099, "미케닉");
-    map.insert(4100, "레인져");
-    map.insert(4101, "길로틴크로스");
-    map.insert(4103, "가드");
-    map.insert(4104, "슈라");
-    map.insert(4105, "제네릭");
-    map.insert(4106, "쉐도우체이서");
-    map.insert(4107, "아크비숍");
-    map.insert(4108, "워록");
-    map.insert(4109, "민스트럴");
+/// Body sprite used for a player job id missing from the table, so an
+/// unknown class still shows a character instead of nothing.
+pub const FALLBACK_PLAYER_SPRITE: &str = "초보자";
 
-    map.insert(4211, "dragon_knight");
-    map.insert(4212, "imperial_guard");
-    map.insert(4213, "arch_mage");
-    map.insert(4214, "cardinal");
-    map.insert(4215, "meister");
-    map.insert(4216, "shadow_cross");
-    map.insert(4217, "arch_mage");
-    map.insert(4218, "cardinal");
-    map.insert(4219, "windhawk");
-    map.insert(4220, "imperial_guard");
-    map.insert(4221, "biolo");
-    map.insert(4222, "abyss_chaser");
-    map.insert(4223, "elemetal_master");
-    map.insert(4224, "inquisitor");
-    map.insert(4225, "troubadour");
-    map.insert(4226, "trouvere");
-
-    map.insert(4239, "rebellion");
-    map.insert(4240, "kagerou");
-    map.insert(4241, "oboro");
+pub fn get_player_job_sprite_mapping() -> HashMap<u32, &'static str> {
+    PLAYER_JOB_SPRITES.iter().copied().collect()
+}
 
-    map.insert(4252, "소울리퍼");
-    map.insert(4253, "성제");
+pub fn is_player_job(job_id: u32) -> bool {
+    (job_id <= 150) || (4001..=4316).contains(&job_id)
+}
 
-    map
+/// Doram jobs, whose bodies live under `도람족/` instead of `인간족/`.
+pub fn is_doram_job(job_id: u32) -> bool {
+    matches!(job_id, 4218 | 4220 | 4308)
 }
 
-pub fn is_player_job(job_id: u32) -> bool {
-    (job_id <= 150)
-        || (4001..=4300).contains(&job_id)
-        || (21..=25).contains(&job_id)
-        || (4046..=4049).contains(&job_id)
+/// The sex whose sprites a gender-locked job ships. Bard and Dancer lines
+/// (and Kagerou/Oboro) only have sprites for their own sex, so a character
+/// whose stored sex disagrees still draws the job's real body.
+pub fn job_sprite_gender(job_id: u32) -> Option<Gender> {
+    match job_id {
+        19 | 4020 | 4042 | 4068 | 4075 | 4104 | 4211 | 4223 | 4263 | 4304 => Some(Gender::Male),
+        20 | 4021 | 4043 | 4069 | 4076 | 4105 | 4212 | 4224 | 4264 | 4305 => Some(Gender::Female),
+        _ => None,
+    }
 }
 
 // Source: aesir TraitJobs @trait_job_ids (apps/zone_server/lib/aesir/zone_server/mmo/job_management/trait_jobs.ex).
@@ -148,4 +252,48 @@ mod tests {
         assert!(!is_fourth_job(4315));
         assert!(!is_fourth_job(4317));
     }
+
+    #[test]
+    fn every_class_line_has_a_sprite() {
+        let sprites = get_player_job_sprite_mapping();
+        let ranges = [
+            0..=30,
+            4001..=4049,
+            4054..=4087,
+            4096..=4112,
+            4252..=4264,
+            4278..=4281,
+            4302..=4308,
+        ];
+        for id in ranges.into_iter().flatten() {
+            assert!(sprites.contains_key(&id), "job {id} has no sprite");
+            assert!(is_player_job(id), "job {id} is not a player job");
+        }
+        assert!(is_player_job(4316));
+    }
+
+    #[test]
+    fn transcendent_third_and_baby_jobs_reuse_their_class_sprites() {
+        let sprites = get_player_job_sprite_mapping();
+        // Transcendent first classes and baby classes look like the base class.
+        assert_eq!(sprites[&4002], sprites[&1]);
+        assert_eq!(sprites[&4030], sprites[&7]);
+        // Transcendent thirds share the regular third-class bodies.
+        assert_eq!(sprites[&4060], sprites[&4054]);
+        assert_eq!(sprites[&4096], sprites[&4054]);
+        // Mounts are their own sprites.
+        assert_eq!(sprites[&4014], "로드페코");
+        assert_eq!(sprites[&4080], "룬나이트쁘띠");
+        assert_eq!(sprites[&4086], "마도기어");
+        assert_eq!(sprites[&4280], "dragon_knight_chicken");
+    }
+
+    #[test]
+    fn gender_locked_jobs_name_their_sex() {
+        assert_eq!(job_sprite_gender(19), Some(Gender::Male));
+        assert_eq!(job_sprite_gender(4021), Some(Gender::Female));
+        assert_eq!(job_sprite_gender(4263), Some(Gender::Male));
+        assert_eq!(job_sprite_gender(4212), Some(Gender::Female));
+        assert_eq!(job_sprite_gender(7), None);
+    }
 }
@@ -3,7 +3,10 @@ use crate::domain::entities::character::components::core::Gender;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
-use super::player_jobs::{get_player_job_sprite_mapping, is_player_job};
+use super::player_jobs::{
+    FALLBACK_PLAYER_SPRITE, get_player_job_sprite_mapping, is_doram_job, is_player_job,
+    job_sprite_gender,
+};
 
 /// JT_WARPNPC. The official jobname.lub maps this to a placeholder ("1_ETC_01");
 /// the real client special-cases it to the animated portal sprite instead.
@@ -41,6 +44,12 @@ impl JobSpriteRegistry {
             return Some(sprite.as_str());
         }
 
+        // A class newer than the table still draws as a character.
+        if jt_id >= 4001 && is_player_job(jt_id) {
+            warn!("Unknown player job ID: {}, drawing a novice body", jt_id);
+            return Some(FALLBACK_PLAYER_SPRITE);
+        }
+
         warn!("Unknown job ID: {}, using fallback", jt_id);
         None
     }
@@ -59,10 +68,36 @@ impl JobSpriteRegistry {
         self.display_names.get(&jt_id).map(|s| s.as_str())
     }
 
+    /// Gender-locked jobs (Bard, Dancer, ...) use their own sex's sprite
+    /// whatever `gender` says, since only that one exists.
     pub fn get_body_sprite_path(&self, jt_id: u32, gender: u8) -> Option<String> {
         let sprite_name = self.get_sprite_name(jt_id)?;
-        let gender_enum = Gender::from(gender);
-        Some(patterns::body_sprite_path(gender_enum, sprite_name))
+        let gender_enum = job_sprite_gender(jt_id).unwrap_or(Gender::from(gender));
+        if is_doram_job(jt_id) {
+            Some(patterns::doram_body_sprite_path(gender_enum, sprite_name))
+        } else {
+            Some(patterns::body_sprite_path(gender_enum, sprite_name))
+        }
+    }
+
+    /// Body sprite for alternate outfit `outfit`; outfit 0 is the regular
+    /// body, and Doram have no alternates.
+    pub fn get_alternate_body_sprite_path(
+        &self,
+        jt_id: u32,
+        gender: u8,
+        outfit: u16,
+    ) -> Option<String> {
+        if outfit == 0 || is_doram_job(jt_id) {
+            return self.get_body_sprite_path(jt_id, gender);
+        }
+        let sprite_name = self.get_sprite_name(jt_id)?;
+        let gender_enum = job_sprite_gender(jt_id).unwrap_or(Gender::from(gender));
+        Some(patterns::costume_body_sprite_path(
+            gender_enum,
+            sprite_name,
+            outfit,
+        ))
     }
 
     pub fn get_hair_sprite_path(&self, hair_id: u16, gender: u8) -> String {
@@ -109,4 +144,42 @@ mod tests {
 
         assert_eq!(registry.get_sprite_name(WARP_JOB_ID), Some("portal"));
     }
+
+    #[test]
+    fn body_paths_follow_race_gender_lock_and_outfit() {
+        let registry = JobSpriteRegistry::from_job_data(fixture());
+        let (male, female) = (1, 0);
+
+        assert_eq!(
+            registry.get_body_sprite_path(4008, female).as_deref(),
+            Some("ro://data/sprite/인간족/몸통/여/로드나이트_여.spr")
+        );
+        // A dancer is drawn female even when stored as male.
+        assert_eq!(
+            registry.get_body_sprite_path(20, male).as_deref(),
+            Some("ro://data/sprite/인간족/몸통/여/무희_여.spr")
+        );
+        assert_eq!(
+            registry.get_body_sprite_path(4218, male).as_deref(),
+            Some("ro://data/sprite/도람족/몸통/남/소환사_남.spr")
+        );
+        assert_eq!(
+            registry
+                .get_alternate_body_sprite_path(7, male, 1)
+                .as_deref(),
+            Some("ro://data/sprite/인간족/몸통/남/costume_1/기사_남_1.spr")
+        );
+        assert_eq!(
+            registry.get_alternate_body_sprite_path(7, male, 0),
+            registry.get_body_sprite_path(7, male)
+        );
+    }
+
+    #[test]
+    fn unknown_player_jobs_fall_back_to_novice() {
+        let registry = JobSpriteRegistry::from_job_data(fixture());
+
+        assert_eq!(registry.get_sprite_name(4300), Some(FALLBACK_PLAYER_SPRITE));
+        assert_eq!(registry.get_sprite_name(9999), None);
+    }
 }
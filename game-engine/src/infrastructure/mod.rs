@@ -3,8 +3,10 @@ pub mod assets;
 pub mod config;
 pub mod diagnostics;
 pub mod effect;
+pub mod encoding;
 pub mod item;
 pub mod job;
+pub mod png_encoder;
 pub mod ro_formats;
 pub mod skill;
 pub mod status;
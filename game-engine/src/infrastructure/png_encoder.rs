@@ -0,0 +1,200 @@
+//! Background PNG encoding.
+//!
+//! Encoding a full-window screenshot takes long enough to hitch a frame, so
+//! nothing encodes on the main thread: callers send [`EncodePng`] and get a
+//! [`PngEncoded`] back once the file is written. Jobs run on the async compute
+//! pool, at most [`PngEncodeWorkers::max_in_flight`] at a time; the rest wait
+//! in a queue, so a burst of requests can't starve the pool.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
+
+/// Write `image` to `path` as a PNG. Any texture format `Image::try_into_dynamic`
+/// understands is accepted; the alpha channel is dropped when `opaque` is set
+/// (screenshots keep HDR brightness there, not coverage).
+#[derive(Message, Debug, Clone)]
+pub struct EncodePng {
+    pub path: PathBuf,
+    pub image: Image,
+    pub opaque: bool,
+}
+
+/// An [`EncodePng`] job finished, successfully or not.
+#[derive(Message, Debug, Clone)]
+pub struct PngEncoded {
+    pub path: PathBuf,
+    pub result: Result<(), String>,
+}
+
+struct EncodeJob {
+    path: PathBuf,
+    task: Task<Result<(), String>>,
+}
+
+#[derive(Resource)]
+pub struct PngEncodeWorkers {
+    /// How many encodes may run at once.
+    pub max_in_flight: usize,
+    queued: VecDeque<EncodePng>,
+    in_flight: Vec<EncodeJob>,
+}
+
+impl Default for PngEncodeWorkers {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 2,
+            queued: VecDeque::new(),
+            in_flight: Vec::new(),
+        }
+    }
+}
+
+impl PngEncodeWorkers {
+    /// Jobs waiting for a worker plus jobs being encoded.
+    pub fn pending(&self) -> usize {
+        self.queued.len() + self.in_flight.len()
+    }
+}
+
+pub struct PngEncoderPlugin;
+
+impl Plugin for PngEncoderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PngEncodeWorkers>()
+            .add_message::<EncodePng>()
+            .add_message::<PngEncoded>()
+            .add_systems(Last, (dispatch_png_encodes, collect_png_encodes).chain());
+    }
+}
+
+/// The work done off the main thread.
+fn encode_png(path: &Path, image: Image, opaque: bool) -> Result<(), String> {
+    let image = image
+        .try_into_dynamic()
+        .map_err(|e| format!("unsupported image format: {e}"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let saved = if opaque {
+        image
+            .to_rgb8()
+            .save_with_format(path, image::ImageFormat::Png)
+    } else {
+        image
+            .to_rgba8()
+            .save_with_format(path, image::ImageFormat::Png)
+    };
+    saved.map_err(|e| e.to_string())
+}
+
+/// Queue new requests and start as many as there are free workers.
+pub fn dispatch_png_encodes(
+    mut requests: MessageReader<EncodePng>,
+    mut workers: ResMut<PngEncodeWorkers>,
+) {
+    workers.queued.extend(requests.read().cloned());
+
+    while workers.in_flight.len() < workers.max_in_flight.max(1) {
+        let Some(EncodePng {
+            path,
+            image,
+            opaque,
+        }) = workers.queued.pop_front()
+        else {
+            break;
+        };
+        let job_path = path.clone();
+        let task =
+            AsyncComputeTaskPool::get().spawn(async move { encode_png(&job_path, image, opaque) });
+        workers.in_flight.push(EncodeJob { path, task });
+    }
+}
+
+/// Report finished jobs, freeing their workers for the next frame.
+pub fn collect_png_encodes(
+    mut workers: ResMut<PngEncodeWorkers>,
+    mut finished: MessageWriter<PngEncoded>,
+) {
+    workers.in_flight.retain_mut(|job| {
+        let Some(result) = block_on(poll_once(&mut job.task)) else {
+            return true;
+        };
+        match &result {
+            Ok(()) => debug!("Encoded {}", job.path.display()),
+            Err(e) => warn!("Failed to encode {}: {}", job.path.display(), e),
+        }
+        finished.write(PngEncoded {
+            path: job.path.clone(),
+            result,
+        });
+        false
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+    use bevy::tasks::TaskPool;
+
+    fn red_square() -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    #[test]
+    fn requests_beyond_the_worker_limit_wait_their_turn() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let dir = std::env::temp_dir().join(format!("png-encoder-{}", std::process::id()));
+
+        let mut app = App::new();
+        app.add_plugins(PngEncoderPlugin);
+        app.world_mut()
+            .resource_mut::<PngEncodeWorkers>()
+            .max_in_flight = 1;
+        for i in 0..3 {
+            app.world_mut().write_message(EncodePng {
+                path: dir.join(format!("{i}.png")),
+                image: red_square(),
+                opaque: true,
+            });
+        }
+
+        let mut written = Vec::new();
+        for _ in 0..1000 {
+            app.update();
+            assert!(app.world().resource::<PngEncodeWorkers>().in_flight.len() <= 1);
+            let messages = app.world().resource::<Messages<PngEncoded>>();
+            written.extend(
+                messages
+                    .iter_current_update_messages()
+                    .map(|done| (done.path.clone(), done.result.clone())),
+            );
+            if app.world().resource::<PngEncodeWorkers>().pending() == 0 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert_eq!(written.len(), 3);
+        for (path, result) in &written {
+            assert_eq!(result, &Ok(()));
+            let decoded = image::open(path).expect("a readable png");
+            assert_eq!((decoded.width(), decoded.height()), (4, 4));
+        }
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::infrastructure::encoding::names::USER_INTERFACE;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -20,7 +21,7 @@ impl SkillCatalog {
     pub fn icon_path(&self, id: u32) -> Option<String> {
         let skill = self.skills.get(&id)?;
         Some(format!(
-            "ro://data/texture/유저인터페이스/item/{}.bmp",
+            "ro://data/texture/{USER_INTERFACE}/item/{}.bmp",
             skill.name.to_lowercase()
         ))
     }
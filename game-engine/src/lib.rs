@@ -8,7 +8,10 @@ pub mod presentation;
 pub mod utils;
 
 // Re-export commonly used types
-pub use app::{AuthenticationPlugin, LifthrasirPlugin, MapPlugin, NativeInputPlugin};
+pub use app::{
+    AuthenticationPlugin, HeadlessPlugins, LifthrasirPlugin, MapPlugin, NativeInputPlugin,
+};
+pub use domain::authentication::teardown::SessionTeardownPlugin;
 pub use domain::camera::CameraPlugin;
 pub use domain::cart::CartPlugin;
 pub use domain::character::CharacterDomainPlugin;
@@ -23,14 +26,18 @@ pub use domain::guild::GuildPlugin;
 pub use domain::inventory::InventoryPlugin;
 pub use domain::item_drop::ItemDropPlugin;
 pub use domain::party::PartyPlugin;
+pub use domain::requests::RequestDeadlinePlugin;
+pub use domain::screenshot::ScreenshotPlugin;
 pub use domain::settings::SettingsPlugin;
 pub use domain::skill_units::SkillUnitsPlugin;
 pub use domain::storage::StoragePlugin;
 pub use infrastructure::accessory::{AccessoryDb, AccessoryDbPlugin};
-pub use infrastructure::diagnostics::RoDiagnosticsPlugin;
+pub use infrastructure::assets::{PreloadPlugin, RoInstallPlugin};
+pub use infrastructure::diagnostics::{FrameBudgetPlugin, RoDiagnosticsPlugin};
 pub use infrastructure::effect::EffectsPlugin;
 pub use infrastructure::item::{ItemDb, ItemDbPlugin};
 pub use infrastructure::job::JobSystemPlugin;
+pub use infrastructure::png_encoder::PngEncoderPlugin;
 pub use infrastructure::skill::SkillSystemPlugin;
 pub use infrastructure::status::StatusIconPlugin;
 pub use infrastructure::weapon::{WeaponDb, WeaponDbPlugin};
@@ -48,10 +55,13 @@ impl PluginGroup for CoreGamePlugins {
         PluginGroupBuilder::start::<Self>()
             .add(net_contract::NetContractPlugin)
             .add(RoDiagnosticsPlugin)
+            .add(FrameBudgetPlugin)
             .add(LifthrasirPlugin)
             .add(SettingsPlugin)
             .add(CameraPlugin)
             .add(AssetsPlugin)
+            .add(PreloadPlugin)
+            .add(RoInstallPlugin)
             .add(JobSystemPlugin)
             .add(SkillSystemPlugin)
             .add(EffectsPlugin)
@@ -65,6 +75,7 @@ impl PluginGroup for CoreGamePlugins {
             .add(EntitySpawningPlugin)
             .add(CharacterDomainPlugin)
             .add(AuthenticationPlugin)
+            .add(SessionTeardownPlugin)
             .add(WorldPlugin)
             .add(MovementPlugin)
             .add(EntityHoverPlugin)
@@ -77,6 +88,9 @@ impl PluginGroup for CoreGamePlugins {
             .add(GuildPlugin)
             .add(ItemDropPlugin)
             .add(EquipmentPlugin)
+            .add(PngEncoderPlugin)
+            .add(ScreenshotPlugin)
+            .add(RequestDeadlinePlugin)
             .add(InputPlugin)
             .add(NativeInputPlugin)
             .add(FpsCounterPlugin)
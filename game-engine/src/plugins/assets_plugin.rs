@@ -2,10 +2,11 @@ use crate::infrastructure::accessory::AccessoryDataAsset;
 use crate::infrastructure::assets::{
     bmp_loader::BmpLoader, svg_loader::SvgLoader, tga_loader::TgaLoader, *,
 };
-use crate::infrastructure::config::ClientConfig;
+use crate::infrastructure::config::{ClientConfig, ClientInfoXmlLoader};
 use crate::infrastructure::effect::{
     AuthoredEffectLoader, EffectDataAsset, LoadedEffectAsset, StrEffectLoader,
 };
+use crate::infrastructure::encoding::ResourceNames;
 use crate::infrastructure::item::ItemDataAsset;
 use crate::infrastructure::job::JobDataAsset;
 use crate::infrastructure::skill::SkillDataAsset;
@@ -20,6 +21,7 @@ pub struct AssetsPlugin;
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<ClientConfig>()
+            .init_asset_loader::<ClientInfoXmlLoader>()
             .init_asset::<AssetConfig>()
             .init_asset::<RoSpriteAsset>()
             .init_asset_loader::<RoSpriteLoader>()
@@ -48,6 +50,9 @@ impl Plugin for AssetsPlugin {
             .init_asset_loader::<BmpLoader>()
             .init_asset_loader::<TgaLoader>()
             .init_asset_loader::<SvgLoader>()
+            .init_resource::<ResourceNames>()
+            .add_message::<SpritePaletteFallbackUsed>()
+            .add_systems(Update, report_sprite_palette_fallbacks)
             .add_plugins((
                 TomlAssetPlugin::<AssetConfig>::new(&["data.toml"]),
                 TomlAssetPlugin::<ClientConfig>::new(&["client.toml"]),
@@ -6,6 +6,7 @@ pub mod portal;
 pub mod skill_fx;
 
 use bevy::prelude::*;
+use bevy::render::RenderApp;
 use bevy_hanabi::prelude::*;
 
 pub use ambient::MapAmbientVfxPlugin;
@@ -31,8 +32,14 @@ pub struct VfxPlugin;
 
 impl Plugin for VfxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(HanabiPlugin)
-            .configure_sets(Update, VfxSystems)
+        // Hanabi needs the GPU device. Without a render world (headless apps)
+        // only its asset type is registered so effect setup still runs.
+        if app.get_sub_app(RenderApp).is_some() {
+            app.add_plugins(HanabiPlugin);
+        } else {
+            app.init_asset::<EffectAsset>();
+        }
+        app.configure_sets(Update, VfxSystems)
             .add_plugins(PortalVfxPlugin)
             .add_plugins(ImpactVfxPlugin)
             .add_plugins(SkillFxPlugin)
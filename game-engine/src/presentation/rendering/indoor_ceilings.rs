@@ -0,0 +1,202 @@
+//! Ceiling culling for indoor maps.
+//!
+//! Guild dungeons and building interiors are modelled with their roofs and
+//! upper floors in place, and from the indoor camera those cover the player.
+//! On maps listed in the indoor map table two rules hide them:
+//!
+//! - a model placed in a "top" group (an RSW object name with a `top` path
+//!   segment, such as `top\roof01`) is hidden whole;
+//! - a model mesh whose lowest point is more than
+//!   [`IndoorCeilingRules::focus_clearance`] above the camera's focus (the
+//!   player's feet) is hidden, so a roof or an upper storey disappears while
+//!   the walls around the player stay.
+//!
+//! Hidden geometry no longer blocks the camera or catches cursor rays, since
+//! both skip meshes that are not visible. Leaving an indoor map shows
+//! everything again.
+
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+use crate::core::state::GameState;
+use crate::domain::camera::collision::world_aabb;
+use crate::domain::camera::components::{CameraFollowTarget, CameraOccluder};
+use crate::domain::camera::resources::ActiveCameraProfile;
+use crate::domain::system_sets::{CameraSystems, ModelRenderingSystems};
+
+use super::models::MapModel;
+
+/// Tunables for hiding ceilings on indoor maps.
+#[derive(Resource, Debug, Clone)]
+#[auto_init_resource(plugin = crate::app::map_domain_plugin::MapDomainPlugin)]
+pub struct IndoorCeilingRules {
+    /// A mesh whose lowest point is higher than this above the camera focus
+    /// counts as ceiling. Tall enough for doorframes and wall tops.
+    pub focus_clearance: f32,
+}
+
+impl Default for IndoorCeilingRules {
+    fn default() -> Self {
+        Self {
+            focus_clearance: 30.0,
+        }
+    }
+}
+
+type CeilingMeshQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Aabb,
+        &'static GlobalTransform,
+        &'static mut Visibility,
+    ),
+    (With<CameraOccluder>, Without<MapModel>),
+>;
+
+/// Whether an RSW object name places the model in a "top" group.
+fn in_top_group(name: &str) -> bool {
+    name.split(['\\', '/'])
+        .rev()
+        .skip(1)
+        .any(|segment| segment.eq_ignore_ascii_case("top"))
+}
+
+/// Whether geometry whose lowest point is at `bottom_y` is ceiling for a
+/// camera focused at `focus_y`. World up is `-Y`.
+fn is_ceiling(bottom_y: f32, focus_y: f32, clearance: f32) -> bool {
+    bottom_y < focus_y - clearance
+}
+
+fn visibility(hidden: bool) -> Visibility {
+    if hidden {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    }
+}
+
+#[auto_add_system(
+    plugin = crate::app::map_domain_plugin::MapDomainPlugin,
+    schedule = Update,
+    config(
+        after = ModelRenderingSystems::ModelAnimation,
+        after = CameraSystems::Follow,
+        run_if = in_state(GameState::InGame)
+    )
+)]
+pub fn cull_indoor_ceilings(
+    profile: Res<ActiveCameraProfile>,
+    rules: Res<IndoorCeilingRules>,
+    focus: Query<&CameraFollowTarget, With<Camera3d>>,
+    mut models: Query<(&MapModel, &mut Visibility), Without<CameraOccluder>>,
+    mut meshes: CeilingMeshQuery,
+) {
+    if !profile.indoor {
+        // Outdoors nothing is culled; only undo what the last map hid.
+        if profile.is_changed() {
+            for (_, mut visible) in &mut models {
+                visible.set_if_neq(Visibility::Inherited);
+            }
+            for (_, _, mut visible) in &mut meshes {
+                visible.set_if_neq(Visibility::Inherited);
+            }
+        }
+        return;
+    }
+    let Ok(focus) = focus.single() else {
+        return;
+    };
+    let focus_y = focus.cached_position.y;
+
+    for (model, mut visible) in &mut models {
+        visible.set_if_neq(visibility(in_top_group(&model.name)));
+    }
+    for (aabb, transform, mut visible) in &mut meshes {
+        let bottom_y = world_aabb(aabb, transform).max.y;
+        visible.set_if_neq(visibility(is_ceiling(
+            bottom_y,
+            focus_y,
+            rules.focus_clearance,
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::Vec3A;
+
+    #[test]
+    fn top_group_is_a_folder_segment_not_a_file_name() {
+        assert!(in_top_group("top\\roof01"));
+        assert!(in_top_group("guild\\TOP\\beam"));
+        assert!(!in_top_group("top"));
+        assert!(!in_top_group("wall\\top_trim"));
+        assert!(!in_top_group("stopper\\pillar"));
+    }
+
+    #[test]
+    fn ceiling_is_geometry_well_above_the_focus() {
+        // World up is -Y: higher geometry has a smaller Y.
+        assert!(is_ceiling(-40.0, 0.0, 30.0));
+        assert!(!is_ceiling(-20.0, 0.0, 30.0));
+        assert!(!is_ceiling(5.0, 0.0, 30.0));
+    }
+
+    fn mesh(app: &mut App, bottom_y: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                CameraOccluder,
+                Aabb {
+                    center: Vec3A::new(0.0, bottom_y - 5.0, 0.0),
+                    half_extents: Vec3A::splat(5.0),
+                },
+                GlobalTransform::IDENTITY,
+                Visibility::Inherited,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn indoors_hides_ceilings_and_outdoors_restores_them() {
+        let mut app = App::new();
+        app.init_resource::<IndoorCeilingRules>()
+            .insert_resource(ActiveCameraProfile {
+                map_name: "gld_dun01".into(),
+                indoor: true,
+            })
+            .add_systems(Update, cull_indoor_ceilings);
+        app.world_mut().spawn((
+            Camera3d::default(),
+            CameraFollowTarget::new(Entity::PLACEHOLDER, Vec3::ZERO),
+        ));
+        let roof = mesh(&mut app, -50.0);
+        let wall = mesh(&mut app, 0.0);
+        let top = app
+            .world_mut()
+            .spawn((
+                MapModel {
+                    name: "top\\roof01".into(),
+                    filename: String::new(),
+                    node_name: String::new(),
+                },
+                Visibility::Inherited,
+            ))
+            .id();
+
+        app.update();
+
+        let visibility = |app: &App, entity| *app.world().get::<Visibility>(entity).unwrap();
+        assert_eq!(visibility(&app, roof), Visibility::Hidden);
+        assert_eq!(visibility(&app, wall), Visibility::Inherited);
+        assert_eq!(visibility(&app, top), Visibility::Hidden);
+
+        app.world_mut().resource_mut::<ActiveCameraProfile>().indoor = false;
+        app.update();
+
+        assert_eq!(visibility(&app, roof), Visibility::Inherited);
+        assert_eq!(visibility(&app, top), Visibility::Inherited);
+    }
+}
@@ -1,8 +1,14 @@
 pub mod effect_material;
 pub mod effects;
+pub mod indoor_ceilings;
 pub mod lighting;
 pub mod models;
+pub mod palette_sprite_material;
+pub mod sprite_layer_material;
+pub mod terrain_material;
 pub mod water;
 
 pub use effect_material::{EffectMaterial, alpha_mode_for};
 pub use effects::{PortalVfx, VfxPlugin, VfxSystems};
+pub use palette_sprite_material::{PaletteSpriteMaterial, PaletteSpriteParams};
+pub use sprite_layer_material::{SpriteLayerMaterial, frame_regions_buffer};
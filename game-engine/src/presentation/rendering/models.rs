@@ -1,3 +1,5 @@
+use crate::core::state::GameState;
+use crate::domain::camera::components::CameraOccluder;
 use crate::domain::entities::systems::{
     AnimatedTransform, AnimationType, RsmAnimationController, RsmNodeAnimation,
 };
@@ -8,8 +10,9 @@ use crate::domain::world::map_scoped::MapScoped;
 use crate::infrastructure::assets::bmp_loader::BmpLoaderSettings;
 use crate::infrastructure::assets::loaders::{RoGroundAsset, RoWorldAsset, RsmAsset};
 use crate::infrastructure::ro_formats::{RsmFile, RswObject};
-use crate::utils::{get_map_dimensions_from_ground, rsw_to_bevy_transform};
+use crate::utils::{bc_supported, get_map_dimensions_from_ground, rsw_to_bevy_transform};
 use bevy::asset::RenderAssetUsages;
+use bevy::image::CompressedImageFormatSupport;
 use bevy::math::{Mat4, Vec4};
 use bevy::mesh::{Indices, PrimitiveTopology};
 use bevy::prelude::*;
@@ -20,6 +23,9 @@ use std::sync::Arc;
 
 #[derive(Component)]
 pub struct MapModel {
+    /// The RSW object name; map makers group objects with path-like names
+    /// (e.g. `top\roof01`).
+    pub name: String,
     pub filename: String,
     pub node_name: String,
 }
@@ -51,6 +57,28 @@ pub struct ModelTexturesLoading {
     pub anim_speed: f32,
 }
 
+/// GPU handles built once per RSM file: per-node mesh handles tagged with their
+/// texture id, and the material for each texture id.
+struct SharedModel {
+    node_meshes: HashMap<usize, Vec<(i32, Handle<Mesh>)>>,
+    materials: HashMap<i32, Handle<StandardMaterial>>,
+}
+
+/// Per-map cache of `SharedModel`s keyed by RSM filename. Maps place the same tree or
+/// fence hundreds of times; sharing one mesh/material set across every placement lets
+/// Bevy batch them into instanced draws instead of one draw per copy.
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = crate::app::map_domain_plugin::MapDomainPlugin)]
+pub struct SharedModelAssets {
+    models: HashMap<String, SharedModel>,
+}
+
+impl SharedModelAssets {
+    pub fn contains(&self, filename: &str) -> bool {
+        self.models.contains_key(filename)
+    }
+}
+
 /// Component to mark and identify RSM node entities
 #[derive(Component, Debug)]
 pub struct RsmNode {
@@ -202,6 +230,7 @@ pub fn spawn_map_models(
                         ViewVisibility::default(),
                         InheritedVisibility::default(),
                         MapModel {
+                            name: model.name.clone(),
                             filename: model.filename.clone(),
                             node_name: model.node_name.clone(),
                         },
@@ -262,8 +291,14 @@ pub fn update_model_meshes(
     asset_server: Res<AssetServer>,
     rsm_assets: Res<Assets<RsmAsset>>,
     settings: Res<Persistent<Settings>>,
+    format_support: Option<Res<CompressedImageFormatSupport>>,
+    shared: Res<SharedModelAssets>,
 ) {
     let factor = settings.graphics.upscaling;
+    let compress = settings
+        .graphics
+        .texture_compression
+        .effective(bc_supported(format_support.as_deref()));
     for (entity, map_model, rsm_loading, anim_type, anim_speed) in model_query.iter() {
         if map_model.filename.is_empty() {
             continue;
@@ -275,7 +310,12 @@ pub fn update_model_meshes(
         };
         let rsm = Arc::new(rsm_asset.model.clone());
 
-        let node_meshes = convert_rsm_to_mesh(&rsm);
+        // Later placements of an already-built model reuse its cached handles.
+        let node_meshes = if shared.contains(&map_model.filename) {
+            HashMap::new()
+        } else {
+            convert_rsm_to_mesh(&rsm)
+        };
 
         // Create entity hierarchy: Model -> Node Entities -> Mesh Children
         let mut node_entities = vec![None; rsm.nodes.len()];
@@ -363,7 +403,10 @@ pub fn update_model_meshes(
                 let texture_path = format!("ro://data\\texture\\{}", texture_name);
                 let handle: Handle<Image> = asset_server
                     .load_builder()
-                    .with_settings(move |s: &mut BmpLoaderSettings| s.upscale = factor)
+                    .with_settings(move |s: &mut BmpLoaderSettings| {
+                        s.upscale = factor;
+                        s.compress = compress;
+                    })
                     .load(&texture_path);
                 texture_handles.push(handle);
                 texture_names.push(texture_name.clone());
@@ -398,11 +441,12 @@ pub fn create_model_materials_when_textures_ready(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
-    query: Query<(Entity, &ModelTexturesLoading)>,
+    mut shared: ResMut<SharedModelAssets>,
+    query: Query<(Entity, &MapModel, &ModelTexturesLoading)>,
 ) {
     use bevy::asset::LoadState;
 
-    for (entity, textures_loading) in query.iter() {
+    for (entity, map_model, textures_loading) in query.iter() {
         // Check if all textures are loaded or failed
         let mut all_ready = true;
         let mut loaded_count = 0;
@@ -446,45 +490,35 @@ pub fn create_model_materials_when_textures_ready(
             textures_loading.texture_handles.len()
         );
 
-        // Create materials from loaded textures
-        let texture_materials = create_model_materials_from_loaded_textures(
-            &textures_loading.rsm,
-            &textures_loading.texture_handles,
-            &textures_loading.texture_names,
-            &asset_server,
-            &mut materials,
-        );
-
-        // Spawn mesh children with materials
-        for (node_idx, node_mesh_list) in &textures_loading.node_meshes {
-            let node_entity = textures_loading.node_entities[*node_idx].unwrap();
-
-            for (texture_id, mesh) in node_mesh_list {
-                let mesh_handle = meshes.add(mesh.clone());
-
-                // Get material for this texture ID
-                let material_handle =
-                    texture_materials
-                        .get(texture_id)
-                        .cloned()
-                        .unwrap_or_else(|| {
-                            debug!(
-                                "No material found for texture ID {}, using fallback",
-                                texture_id
-                            );
-                            create_colored_fallback_material_for_model(
-                                *texture_id as usize,
-                                &mut materials,
-                            )
-                        });
+        let model = shared
+            .models
+            .entry(map_model.filename.clone())
+            .or_insert_with(|| {
+                build_shared_model(textures_loading, &asset_server, &mut meshes, &mut materials)
+            });
+
+        // Spawn mesh children sharing the cached handles
+        for (node_idx, node_mesh_list) in &model.node_meshes {
+            let Some(node_entity) = textures_loading
+                .node_entities
+                .get(*node_idx)
+                .copied()
+                .flatten()
+            else {
+                continue;
+            };
+
+            for (texture_id, mesh_handle) in node_mesh_list {
+                let material_handle = model.materials[texture_id].clone();
 
                 // Create mesh entity with local space transform (IDENTITY)
                 let mesh_entity = commands
                     .spawn((
-                        Mesh3d(mesh_handle),
+                        Mesh3d(mesh_handle.clone()),
                         MeshMaterial3d(material_handle),
                         Transform::IDENTITY, // Local space - let Bevy handle hierarchy transforms
                         GlobalTransform::default(),
+                        CameraOccluder,
                     ))
                     .id();
 
@@ -501,6 +535,56 @@ pub fn create_model_materials_when_textures_ready(
     }
 }
 
+/// Uploads a model's meshes and builds its materials once; every texture id used by
+/// a mesh gets a material, falling back to a colored one when the texture is missing.
+fn build_shared_model(
+    textures_loading: &ModelTexturesLoading,
+    asset_server: &AssetServer,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+) -> SharedModel {
+    let mut texture_materials = create_model_materials_from_loaded_textures(
+        &textures_loading.rsm,
+        &textures_loading.texture_handles,
+        &textures_loading.texture_names,
+        asset_server,
+        materials,
+    );
+
+    let mut node_meshes = HashMap::new();
+    for (node_idx, node_mesh_list) in &textures_loading.node_meshes {
+        let handles = node_mesh_list
+            .iter()
+            .map(|(texture_id, mesh)| {
+                texture_materials.entry(*texture_id).or_insert_with(|| {
+                    debug!(
+                        "No material found for texture ID {}, using fallback",
+                        texture_id
+                    );
+                    create_colored_fallback_material_for_model(*texture_id as usize, materials)
+                });
+                (*texture_id, meshes.add(mesh.clone()))
+            })
+            .collect();
+        node_meshes.insert(*node_idx, handles);
+    }
+
+    SharedModel {
+        node_meshes,
+        materials: texture_materials,
+    }
+}
+
+/// Drops the shared model handles on map exit (and on every warp) so the next map
+/// starts from an empty cache and unused meshes/materials can be freed.
+#[auto_add_system(
+    plugin = crate::app::map_domain_plugin::MapDomainPlugin,
+    schedule = OnExit(GameState::InGame)
+)]
+pub fn clear_shared_model_assets(mut shared: ResMut<SharedModelAssets>) {
+    shared.models.clear();
+}
+
 fn create_model_materials_from_loaded_textures(
     rsm: &RsmFile,
     texture_handles: &[Handle<Image>],
@@ -0,0 +1,107 @@
+use bevy::mesh::MeshVertexBufferLayoutRef;
+use bevy::pbr::{MaterialPipeline, MaterialPipelineKey};
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    AsBindGroup, RenderPipelineDescriptor, ShaderType, SpecializedMeshPipelineError,
+};
+use bevy::shader::ShaderRef;
+
+use crate::infrastructure::assets::AtlasRegion;
+
+/// Sprite-layer material for dyed hair and clothes. Instead of an RGBA atlas
+/// page per palette, it samples the SPR's raw 8-bit palette indices (an
+/// `R8Unorm` index atlas page) and looks each one up in a 256x1 palette
+/// texture, so a dye change only swaps `palette`. Unlit and alpha-blended like
+/// the `StandardMaterial` the other sprite layers use.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct PaletteSpriteMaterial {
+    #[uniform(0)]
+    pub params: PaletteSpriteParams,
+    /// Index atlas page holding the current frame.
+    #[texture(1)]
+    pub indices: Handle<Image>,
+    /// 256x1 lookup texture, see `palette_lookup_image`.
+    #[texture(2)]
+    pub palette: Handle<Image>,
+    pub depth_bias: f32,
+}
+
+#[derive(Debug, Clone, Copy, ShaderType)]
+pub struct PaletteSpriteParams {
+    /// The frame's atlas UVs: xy = min, zw = size.
+    pub uv_rect: Vec4,
+    /// Multiplied into the palette color (status tints).
+    pub tint: LinearRgba,
+}
+
+impl PaletteSpriteMaterial {
+    pub fn new(indices: Handle<Image>, palette: Handle<Image>, uv: Rect) -> Self {
+        let mut material = Self {
+            params: PaletteSpriteParams {
+                uv_rect: Vec4::ZERO,
+                tint: LinearRgba::WHITE,
+            },
+            indices,
+            palette,
+            depth_bias: 0.0,
+        };
+        material.set_uv(uv);
+        material
+    }
+
+    /// Show the frame at `region` of the index page `indices`.
+    pub fn set_frame(&mut self, indices: &Handle<Image>, region: AtlasRegion) {
+        self.indices = indices.clone();
+        self.set_uv(region.uv);
+    }
+
+    pub fn set_uv(&mut self, uv: Rect) {
+        self.params.uv_rect = Vec4::new(uv.min.x, uv.min.y, uv.width(), uv.height());
+    }
+
+    pub fn set_tint(&mut self, tint: Color) {
+        self.params.tint = tint.to_linear();
+    }
+}
+
+impl Material for PaletteSpriteMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/palette_sprite.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Mirrored frames flip the quad's winding, as on the other layers.
+        descriptor.primitive.cull_mode = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_region_becomes_the_uv_rect() {
+        let mut material =
+            PaletteSpriteMaterial::new(Handle::default(), Handle::default(), Rect::default());
+        let region = AtlasRegion {
+            uv: Rect::new(0.25, 0.5, 0.75, 1.0),
+            ..default()
+        };
+        material.set_frame(&Handle::default(), region);
+        assert_eq!(material.params.uv_rect, Vec4::new(0.25, 0.5, 0.5, 0.5));
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::mesh::MeshVertexBufferLayoutRef;
+use bevy::pbr::{MaterialPipeline, MaterialPipelineKey};
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    AsBindGroup, RenderPipelineDescriptor, SpecializedMeshPipelineError,
+};
+use bevy::render::storage::ShaderBuffer;
+use bevy::shader::ShaderRef;
+
+use crate::infrastructure::assets::AtlasRegion;
+
+/// Sprite-layer material shared by every layer showing a frame from the same
+/// atlas page. The frame is chosen per instance instead of per material: a
+/// layer's `MeshTag` is its SPR frame index, and the vertex shader looks that
+/// frame's UVs up in `regions`. Layers on one material and the shared sprite
+/// quad batch into instanced draws. Unlit and alpha-blended.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct SpriteLayerMaterial {
+    /// Multiplied into the texture color (status tints).
+    #[uniform(0)]
+    pub tint: LinearRgba,
+    /// The atlas page.
+    #[texture(1)]
+    #[sampler(2)]
+    pub page: Handle<Image>,
+    /// Every frame's atlas UVs, see [`frame_regions_buffer`].
+    #[storage(3, read_only)]
+    pub regions: Handle<ShaderBuffer>,
+    pub depth_bias: f32,
+}
+
+/// One `vec4` per SPR frame, xy = UV min and zw = UV size, indexed by the
+/// frame's `MeshTag`. Never empty, since a zero-sized storage binding is
+/// invalid.
+pub fn frame_regions_buffer(regions: &[AtlasRegion]) -> ShaderBuffer {
+    let mut rects: Vec<Vec4> = regions
+        .iter()
+        .map(|region| {
+            let uv = region.uv;
+            Vec4::new(uv.min.x, uv.min.y, uv.width(), uv.height())
+        })
+        .collect();
+    if rects.is_empty() {
+        rects.push(Vec4::ZERO);
+    }
+    ShaderBuffer::from(rects)
+}
+
+impl Material for SpriteLayerMaterial {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/sprite_layer.wgsl".into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        "shaders/sprite_layer.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Mirrored frames flip the quad's winding.
+        descriptor.primitive.cull_mode = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rects(buffer: &ShaderBuffer) -> Vec<f32> {
+        buffer
+            .data
+            .as_deref()
+            .unwrap()
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn each_frame_region_becomes_a_uv_rect() {
+        let regions = [
+            AtlasRegion {
+                uv: Rect::new(0.0, 0.0, 0.5, 0.25),
+                ..default()
+            },
+            AtlasRegion {
+                page: 1,
+                uv: Rect::new(0.25, 0.5, 0.75, 1.0),
+                ..default()
+            },
+        ];
+        assert_eq!(
+            rects(&frame_regions_buffer(&regions)),
+            vec![0.0, 0.0, 0.5, 0.25, 0.25, 0.5, 0.5, 0.5]
+        );
+    }
+
+    #[test]
+    fn a_sprite_without_frames_still_gets_a_buffer() {
+        assert_eq!(rects(&frame_regions_buffer(&[])), vec![0.0; 4]);
+    }
+}
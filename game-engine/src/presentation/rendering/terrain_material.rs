@@ -0,0 +1,70 @@
+use bevy::{
+    pbr::{ExtendedMaterial, MaterialExtension, StandardMaterial},
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+};
+
+/// Terrain material: the standard PBR path plus a uniform that scales the baked
+/// per-tile GND color (the map's painted light and shadow, RO's lightmap stand-in
+/// here) between off and full. Flipping it only rewrites the uniform, so the
+/// graphics setting applies to a loaded map without regenerating its meshes.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct TerrainExtension {
+    #[uniform(100)]
+    pub lighting: TerrainLighting,
+}
+
+#[derive(Debug, Clone, Copy, ShaderType)]
+pub struct TerrainLighting {
+    /// x = tile color strength (0 = untinted texture, 1 = full GND color); yzw unused.
+    pub params: Vec4,
+}
+
+impl TerrainExtension {
+    pub fn new(tile_lighting: bool) -> Self {
+        let mut extension = Self {
+            lighting: TerrainLighting { params: Vec4::ZERO },
+        };
+        extension.set_tile_lighting(tile_lighting);
+        extension
+    }
+
+    pub fn tile_lighting(&self) -> bool {
+        self.lighting.params.x > 0.0
+    }
+
+    pub fn set_tile_lighting(&mut self, enabled: bool) {
+        self.lighting.params.x = if enabled { 1.0 } else { 0.0 };
+    }
+}
+
+impl MaterialExtension for TerrainExtension {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/terrain.wgsl".into()
+    }
+}
+
+pub type TerrainMaterial = ExtendedMaterial<StandardMaterial, TerrainExtension>;
+
+/// Wrap a terrain `StandardMaterial` with the current tile-lighting setting.
+pub fn terrain_material(base: StandardMaterial, tile_lighting: bool) -> TerrainMaterial {
+    ExtendedMaterial {
+        base,
+        extension: TerrainExtension::new(tile_lighting),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_lighting_round_trips_through_the_uniform() {
+        let mut extension = TerrainExtension::new(true);
+        assert_eq!(extension.lighting.params.x, 1.0);
+        extension.set_tile_lighting(false);
+        assert!(!extension.tile_lighting());
+        assert_eq!(extension.lighting.params.x, 0.0);
+    }
+}
@@ -39,6 +39,8 @@ pub enum SystemDialogKind {
     GuildInvite,
     GuildLeave,
     GuildExpel,
+    /// Game data is missing; the primary button picks an RO install to use.
+    GameDataSetup,
 }
 
 /// Summons the reusable system-dialog modal (see `lifthrasir-ui` `SystemDialog`).
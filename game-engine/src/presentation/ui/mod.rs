@@ -1,3 +1,4 @@
 pub mod events;
 pub mod fps_counter;
+pub mod window_resize;
 mod zone_disconnect;
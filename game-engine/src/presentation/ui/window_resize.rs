@@ -0,0 +1,102 @@
+//! Settled window-resize notification.
+//!
+//! A drag-resize produces a `WindowResized` per OS event, often dozens per second.
+//! The surface itself follows each one within the frame; work that should only run
+//! once the size stops changing (layout fix-ups, re-clamping windows) listens for
+//! [`WindowResizeSettled`] instead, written once per resize gesture.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+use bevy_auto_plugin::prelude::{auto_add_message, auto_add_system, auto_init_resource};
+
+/// Quiet period after the last resize event before the size counts as settled.
+const SETTLE_DELAY: Duration = Duration::from_millis(150);
+
+/// The primary window stopped resizing at this logical size.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+#[auto_add_message(plugin = crate::LifthrasirPlugin)]
+pub struct WindowResizeSettled {
+    pub width: f32,
+    pub height: f32,
+}
+
+/// The latest unsettled size and the quiet-period timer restarted by each event.
+#[derive(Resource, Debug)]
+#[auto_init_resource(plugin = crate::LifthrasirPlugin)]
+pub struct ResizeDebounce {
+    pending: Option<Vec2>,
+    quiet: Timer,
+}
+
+impl Default for ResizeDebounce {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            quiet: Timer::new(SETTLE_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+impl ResizeDebounce {
+    fn resized(&mut self, size: Vec2) {
+        self.pending = Some(size);
+        self.quiet.reset();
+    }
+
+    /// Advance the quiet period; the settled size once it elapses.
+    fn tick(&mut self, delta: Duration) -> Option<Vec2> {
+        self.pending?;
+        if !self.quiet.tick(delta).is_finished() {
+            return None;
+        }
+        self.pending.take()
+    }
+}
+
+#[auto_add_system(plugin = crate::LifthrasirPlugin, schedule = Update)]
+pub fn settle_window_resize(
+    time: Res<Time<Real>>,
+    mut resized: MessageReader<WindowResized>,
+    primary: Query<(), With<PrimaryWindow>>,
+    mut debounce: ResMut<ResizeDebounce>,
+    mut settled: MessageWriter<WindowResizeSettled>,
+) {
+    if let Some(event) = resized
+        .read()
+        .filter(|event| primary.contains(event.window))
+        .last()
+    {
+        debounce.resized(Vec2::new(event.width, event.height));
+        return;
+    }
+    if let Some(size) = debounce.tick(time.delta()) {
+        debug!("window resize settled at {}x{}", size.x, size.y);
+        settled.write(WindowResizeSettled {
+            width: size.x,
+            height: size.y,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settles_once_after_the_quiet_period() {
+        let mut debounce = ResizeDebounce::default();
+        assert_eq!(debounce.tick(SETTLE_DELAY), None);
+
+        debounce.resized(Vec2::new(800.0, 600.0));
+        assert_eq!(debounce.tick(Duration::from_millis(100)), None);
+        debounce.resized(Vec2::new(1024.0, 768.0));
+        assert_eq!(debounce.tick(Duration::from_millis(100)), None);
+        assert_eq!(
+            debounce.tick(Duration::from_millis(60)),
+            Some(Vec2::new(1024.0, 768.0))
+        );
+        assert_eq!(debounce.tick(SETTLE_DELAY), None);
+    }
+}
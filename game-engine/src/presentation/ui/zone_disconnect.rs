@@ -3,7 +3,7 @@ use crate::domain::system_sets::CharacterFlowSystems;
 use crate::presentation::ui::events::{DialogSeverity, ShowSystemDialog, SystemDialogKind};
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::*;
-use net_contract::events::ZoneDisconnected;
+use net_contract::events::{ZoneDisconnected, ZoneResumeFailed};
 
 fn disconnect_message(reason: &str) -> String {
     format!(
@@ -11,6 +11,12 @@ fn disconnect_message(reason: &str) -> String {
     )
 }
 
+fn resume_failed_message(reason: &str) -> String {
+    format!(
+        "The connection to the realm could not be restored. Returning to character selection.\n\n{reason}"
+    )
+}
+
 #[auto_add_system(
     plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
     schedule = Update,
@@ -37,6 +43,33 @@ pub fn handle_zone_disconnected(
     }
 }
 
+/// Tells the player why they are back at character selection after the adapter
+/// gave up resuming the zone session (the domain fallback handles the navigation).
+#[auto_add_system(
+    plugin = crate::app::character_domain_plugin::CharacterDomainAutoPlugin,
+    schedule = Update,
+    config(in_set = CharacterFlowSystems::ZoneEntry)
+)]
+pub fn handle_zone_resume_failed(
+    mut events: MessageReader<ZoneResumeFailed>,
+    mut dialogs: MessageWriter<ShowSystemDialog>,
+) {
+    for event in events.read() {
+        dialogs.write(ShowSystemDialog {
+            severity: DialogSeverity::Warn,
+            kind: SystemDialogKind::Generic,
+            kicker: "Connection".into(),
+            title: "Connection Lost".into(),
+            message: resume_failed_message(&event.reason),
+            code: String::new(),
+            button_label: "OK".into(),
+            secondary_label: String::new(),
+            confirm_state: None,
+            correlation: None,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,4 +80,11 @@ mod tests {
         assert!(text.contains("disconnected from the realm"));
         assert!(text.ends_with("connection lost"));
     }
+
+    #[test]
+    fn resume_failed_message_mentions_character_selection() {
+        let text = resume_failed_message("could not resume");
+        assert!(text.contains("character selection"));
+        assert!(text.ends_with("could not resume"));
+    }
 }
@@ -0,0 +1,355 @@
+use bevy::image::{CompressedImageFormatSupport, CompressedImageFormats};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+
+use crate::domain::settings::resources::TextureCompression;
+
+/// Block side length of every BCn format.
+const BLOCK: u32 = 4;
+
+/// Whether the active GPU can sample BC-compressed textures. Absent support
+/// info (headless / no renderer yet) counts as unsupported.
+pub fn bc_supported(support: Option<&CompressedImageFormatSupport>) -> bool {
+    support.is_some_and(|s| s.0.contains(CompressedImageFormats::BC))
+}
+
+/// Compress an RGBA8 image (including any mip chain already on it) to BC1 when
+/// its alpha is opaque or cut-out, or BC3 when it carries smooth alpha.
+///
+/// Returns `false` and leaves the image untouched when compression is off, the
+/// image is not RGBA8, or its base size is not a multiple of the 4x4 block.
+/// Idempotent: an already-compressed image is not RGBA8 and is skipped.
+pub fn compress_image_bcn(image: &mut Image, mode: TextureCompression) -> bool {
+    if mode == TextureCompression::Off {
+        return false;
+    }
+
+    let srgb = match image.texture_descriptor.format {
+        TextureFormat::Rgba8UnormSrgb => true,
+        TextureFormat::Rgba8Unorm => false,
+        _ => return false,
+    };
+
+    let (width, height) = (image.width(), image.height());
+    if width % BLOCK != 0 || height % BLOCK != 0 {
+        return false;
+    }
+
+    let Some(data) = image.data.as_ref() else {
+        return false;
+    };
+
+    let smooth_alpha = has_smooth_alpha(data);
+    let levels = image.texture_descriptor.mip_level_count.max(1);
+
+    let mut compressed = Vec::with_capacity(data.len() / if smooth_alpha { 4 } else { 8 });
+    let mut offset = 0;
+    let (mut w, mut h) = (width, height);
+    for _ in 0..levels {
+        let len = (w * h * 4) as usize;
+        let Some(level) = data.get(offset..offset + len) else {
+            return false;
+        };
+        compress_level(level, w, h, smooth_alpha, &mut compressed);
+        offset += len;
+        w = (w / 2).max(1);
+        h = (h / 2).max(1);
+    }
+
+    image.texture_descriptor.format = match (smooth_alpha, srgb) {
+        (true, true) => TextureFormat::Bc3RgbaUnormSrgb,
+        (true, false) => TextureFormat::Bc3RgbaUnorm,
+        (false, true) => TextureFormat::Bc1RgbaUnormSrgb,
+        (false, false) => TextureFormat::Bc1RgbaUnorm,
+    };
+    image.data = Some(compressed);
+    true
+}
+
+/// True when any texel has alpha other than fully opaque or fully transparent,
+/// which BC1's 1-bit alpha cannot represent.
+fn has_smooth_alpha(rgba: &[u8]) -> bool {
+    rgba.chunks_exact(4).any(|px| px[3] != 0 && px[3] != 255)
+}
+
+fn compress_level(rgba: &[u8], width: u32, height: u32, smooth_alpha: bool, out: &mut Vec<u8>) {
+    for by in 0..height.div_ceil(BLOCK) {
+        for bx in 0..width.div_ceil(BLOCK) {
+            let block = fetch_block(rgba, width, height, bx, by);
+            if smooth_alpha {
+                out.extend_from_slice(&encode_alpha_block(&block));
+                out.extend_from_slice(&encode_color_block(&block, false));
+            } else {
+                out.extend_from_slice(&encode_color_block(&block, true));
+            }
+        }
+    }
+}
+
+/// Gather the 4x4 texels of a block, clamping to the edge for partial blocks
+/// in the small mip levels.
+fn fetch_block(rgba: &[u8], width: u32, height: u32, bx: u32, by: u32) -> [[u8; 4]; 16] {
+    let mut block = [[0u8; 4]; 16];
+    for (i, texel) in block.iter_mut().enumerate() {
+        let x = (bx * BLOCK + i as u32 % BLOCK).min(width - 1);
+        let y = (by * BLOCK + i as u32 / BLOCK).min(height - 1);
+        let idx = ((y * width + x) * 4) as usize;
+        texel.copy_from_slice(&rgba[idx..idx + 4]);
+    }
+    block
+}
+
+fn to_565(c: [u8; 3]) -> u16 {
+    ((c[0] as u16 >> 3) << 11) | ((c[1] as u16 >> 2) << 5) | (c[2] as u16 >> 3)
+}
+
+fn from_565(c: u16) -> [i32; 3] {
+    let r = ((c >> 11) & 0x1f) as i32;
+    let g = ((c >> 5) & 0x3f) as i32;
+    let b = (c & 0x1f) as i32;
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+    ]
+}
+
+fn lerp_color(a: [i32; 3], b: [i32; 3], wa: i32, wb: i32) -> [i32; 3] {
+    let total = wa + wb;
+    [
+        (a[0] * wa + b[0] * wb) / total,
+        (a[1] * wa + b[1] * wb) / total,
+        (a[2] * wa + b[2] * wb) / total,
+    ]
+}
+
+fn color_distance(a: [i32; 3], px: [u8; 4]) -> i32 {
+    (0..3).map(|c| (a[c] - px[c] as i32).pow(2)).sum()
+}
+
+/// Pick the two corners of the colours' bounding box that lie along the block's
+/// dominant gradient: channels that fall while the widest channel rises have
+/// their min/max swapped, so e.g. a red-to-blue ramp is not fit along the
+/// red+blue diagonal. All-transparent blocks collapse to black.
+fn color_endpoints<'a>(texels: impl Iterator<Item = &'a [u8; 4]> + Clone) -> ([u8; 3], [u8; 3]) {
+    let mut min = [255u8; 3];
+    let mut max = [0u8; 3];
+    let mut sum = [0i32; 3];
+    let mut count = 0;
+    for px in texels.clone() {
+        for (c, &value) in px[..3].iter().enumerate() {
+            min[c] = min[c].min(value);
+            max[c] = max[c].max(value);
+            sum[c] += value as i32;
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return ([0; 3], [0; 3]);
+    }
+
+    let axis = (0..3).max_by_key(|&c| max[c] - min[c]).unwrap_or(0);
+    let mean = sum.map(|v| v / count);
+    for c in (0..3).filter(|&c| c != axis) {
+        let covariance: i32 = texels
+            .clone()
+            .map(|px| (px[axis] as i32 - mean[axis]) * (px[c] as i32 - mean[c]))
+            .sum();
+        if covariance < 0 {
+            std::mem::swap(&mut min[c], &mut max[c]);
+        }
+    }
+    (min, max)
+}
+
+/// Encode the colour half of a block (a full BC1 block). Endpoints come from
+/// [`color_endpoints`]. With `punch_through`, texels
+/// with alpha below 128 use BC1's transparent index (3-colour mode); otherwise
+/// the 4-colour mode is used, as BC3 requires.
+fn encode_color_block(block: &[[u8; 4]; 16], punch_through: bool) -> [u8; 8] {
+    let transparent = |px: &[u8; 4]| punch_through && px[3] < 128;
+
+    let (min, max) = color_endpoints(block.iter().filter(|px| !transparent(px)));
+    let hi = to_565(max);
+    let lo = to_565(min);
+    let any_transparent = block.iter().any(transparent);
+
+    let (c0, c1) = if any_transparent {
+        (hi.min(lo), hi.max(lo))
+    } else {
+        (hi.max(lo), hi.min(lo))
+    };
+    let (e0, e1) = (from_565(c0), from_565(c1));
+
+    let palette: Vec<[i32; 3]> = if c0 > c1 {
+        vec![e0, e1, lerp_color(e0, e1, 2, 1), lerp_color(e0, e1, 1, 2)]
+    } else {
+        vec![e0, e1, lerp_color(e0, e1, 1, 1)]
+    };
+
+    let mut indices = 0u32;
+    for (i, px) in block.iter().enumerate() {
+        let index = if transparent(px) {
+            3
+        } else {
+            (0..palette.len())
+                .min_by_key(|&p| color_distance(palette[p], *px))
+                .unwrap_or(0) as u32
+        };
+        indices |= index << (i * 2);
+    }
+
+    let mut out = [0u8; 8];
+    out[0..2].copy_from_slice(&c0.to_le_bytes());
+    out[2..4].copy_from_slice(&c1.to_le_bytes());
+    out[4..8].copy_from_slice(&indices.to_le_bytes());
+    out
+}
+
+/// Encode the BC3 alpha half of a block using the 8-level interpolated mode
+/// between the block's min and max alpha.
+fn encode_alpha_block(block: &[[u8; 4]; 16]) -> [u8; 8] {
+    let a0 = block.iter().map(|px| px[3]).max().unwrap_or(255);
+    let a1 = block.iter().map(|px| px[3]).min().unwrap_or(255);
+
+    let mut palette = [a0 as i32; 8];
+    palette[1] = a1 as i32;
+    for (i, slot) in palette.iter_mut().enumerate().skip(2) {
+        let i = i as i32;
+        *slot = ((8 - i) * a0 as i32 + (i - 1) * a1 as i32) / 7;
+    }
+
+    let mut indices = 0u64;
+    if a0 > a1 {
+        for (i, px) in block.iter().enumerate() {
+            let index = (0..palette.len())
+                .min_by_key(|&p| (palette[p] - px[3] as i32).abs())
+                .unwrap_or(0) as u64;
+            indices |= index << (i * 3);
+        }
+    }
+
+    let mut out = [0u8; 8];
+    out[0] = a0;
+    out[1] = a1;
+    out[2..8].copy_from_slice(&indices.to_le_bytes()[..6]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension};
+
+    fn rgba_image(width: u32, height: u32, texel: [u8; 4]) -> Image {
+        Image::new(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            texel.repeat((width * height) as usize),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    #[test]
+    fn opaque_image_compresses_to_bc1() {
+        let mut image = rgba_image(8, 8, [200, 100, 50, 255]);
+        assert!(compress_image_bcn(&mut image, TextureCompression::Bc));
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Bc1RgbaUnormSrgb
+        );
+        assert_eq!(image.data.as_ref().map(Vec::len), Some(4 * 8));
+    }
+
+    #[test]
+    fn smooth_alpha_compresses_to_bc3() {
+        let mut image = rgba_image(4, 4, [200, 100, 50, 128]);
+        assert!(compress_image_bcn(&mut image, TextureCompression::Bc));
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Bc3RgbaUnormSrgb
+        );
+        assert_eq!(image.data.as_ref().map(Vec::len), Some(16));
+    }
+
+    #[test]
+    fn off_unaligned_or_compressed_images_are_left_alone() {
+        let mut image = rgba_image(4, 4, [0, 0, 0, 255]);
+        assert!(!compress_image_bcn(&mut image, TextureCompression::Off));
+
+        let mut odd = rgba_image(6, 4, [0, 0, 0, 255]);
+        assert!(!compress_image_bcn(&mut odd, TextureCompression::Bc));
+        assert_eq!(odd.texture_descriptor.format, TextureFormat::Rgba8UnormSrgb);
+
+        assert!(compress_image_bcn(&mut image, TextureCompression::Bc));
+        assert!(!compress_image_bcn(&mut image, TextureCompression::Bc));
+    }
+
+    #[test]
+    fn mip_chain_compresses_every_level() {
+        let mut image = rgba_image(8, 8, [10, 20, 30, 255]);
+        let mut data = image.data.clone().unwrap();
+        data.extend([10, 20, 30, 255].repeat(4 * 4 + 2 * 2 + 1));
+        image.data = Some(data);
+        image.texture_descriptor.mip_level_count = 4;
+
+        assert!(compress_image_bcn(&mut image, TextureCompression::Bc));
+        // 8x8 = 4 blocks, then one (partial) block for each of 4x4, 2x2, 1x1.
+        assert_eq!(image.data.as_ref().map(Vec::len), Some(7 * 8));
+    }
+
+    #[test]
+    fn cutout_texels_use_the_transparent_index() {
+        let mut block = [[255u8, 0, 0, 255]; 16];
+        block[5] = [255, 0, 255, 0];
+        let encoded = encode_color_block(&block, true);
+        let c0 = u16::from_le_bytes([encoded[0], encoded[1]]);
+        let c1 = u16::from_le_bytes([encoded[2], encoded[3]]);
+        let indices = u32::from_le_bytes([encoded[4], encoded[5], encoded[6], encoded[7]]);
+        assert!(c0 <= c1, "punch-through needs 3-colour mode");
+        assert_eq!((indices >> 10) & 0b11, 3);
+        assert_eq!(indices & 0b11, 0);
+    }
+
+    #[test]
+    fn anti_correlated_gradient_fits_its_own_diagonal() {
+        let mut block = [[0u8; 4]; 16];
+        for (i, px) in block.iter_mut().enumerate() {
+            let t = (i * 17) as u8;
+            *px = [t, 0, 255 - t, 255];
+        }
+        let encoded = encode_color_block(&block, false);
+        let e0 = from_565(u16::from_le_bytes([encoded[0], encoded[1]]));
+        let e1 = from_565(u16::from_le_bytes([encoded[2], encoded[3]]));
+        // One endpoint is red-heavy, the other blue-heavy.
+        assert!((e0[0] - e0[2]) * (e1[0] - e1[2]) < 0);
+    }
+
+    #[test]
+    fn alpha_block_hits_endpoints_exactly() {
+        let mut block = [[0u8, 0, 0, 255]; 16];
+        block[0][3] = 0;
+        let encoded = encode_alpha_block(&block);
+        assert_eq!((encoded[0], encoded[1]), (255, 0));
+        let mut bits = [0u8; 8];
+        bits[..6].copy_from_slice(&encoded[2..8]);
+        let indices = u64::from_le_bytes(bits);
+        assert_eq!(indices & 0b111, 1);
+        assert_eq!((indices >> 3) & 0b111, 0);
+    }
+
+    #[test]
+    fn bc_support_requires_the_bc_flag() {
+        assert!(!bc_supported(None));
+        let none = CompressedImageFormatSupport(CompressedImageFormats::NONE);
+        assert!(!bc_supported(Some(&none)));
+        let bc = CompressedImageFormatSupport(CompressedImageFormats::BC);
+        assert!(bc_supported(Some(&bc)));
+    }
+}
@@ -1,8 +1,10 @@
+pub mod bcn;
 pub mod constants;
 pub mod coordinates;
 pub mod mipmap;
 pub mod time;
 
+pub use bcn::*;
 pub use constants::*;
 pub use coordinates::*;
 pub use mipmap::*;
@@ -1,14 +1,29 @@
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use encoding_rs::EUC_KR;
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
 use indicatif::{ProgressBar, ProgressStyle};
-use ro_formats::GrfFile;
+use rayon::prelude::*;
+use regex::Regex;
+use ro_formats::{
+    GrfEntry, GrfFile, Palette, RoAction, SpriteFrame, decode_euc_kr_mojibake, parse_act, parse_spr,
+};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(name = "grf-utils")]
 #[command(about = "A CLI utility for extracting and inspecting GRF archive files", long_about = None)]
 struct Cli {
+    /// Print a single JSON document on stdout instead of human-readable text
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -19,6 +34,9 @@ enum Commands {
     List {
         /// Path to the GRF file
         grf_file: PathBuf,
+
+        #[command(flatten)]
+        filter: FilterArgs,
     },
     /// Extract files from the GRF archive
     Extract {
@@ -32,72 +50,495 @@ enum Commands {
         /// Output directory (default: "output")
         #[arg(short, long, default_value = "output")]
         output: PathBuf,
+
+        /// Write Korean names as UTF-8, undoing EUC-KR mojibake in entry names
+        #[arg(long)]
+        utf8_paths: bool,
+
+        /// Lowercase output paths
+        #[arg(long)]
+        lowercase: bool,
+
+        /// Write every file straight into the output directory
+        #[arg(long)]
+        flatten: bool,
+
+        #[command(flatten)]
+        filter: FilterArgs,
+
+        /// Worker threads decompressing and writing files (default: one per core)
+        #[arg(long, value_name = "N")]
+        threads: Option<usize>,
     },
     /// Show information about the GRF archive
     Info {
         /// Path to the GRF file
         grf_file: PathBuf,
     },
+    /// Find files whose path matches a pattern
+    Search {
+        /// Path to the GRF file
+        grf_file: PathBuf,
+
+        /// Case-insensitive substring, or a glob with `*` and `?`
+        pattern: String,
+    },
+    /// Compare two GRF archives
+    Diff {
+        /// The older GRF file
+        old_grf: PathBuf,
+
+        /// The newer GRF file
+        new_grf: PathBuf,
+
+        /// Compare sizes only instead of file contents
+        #[arg(long)]
+        quick: bool,
+    },
+    /// Read every file in the archive and report the ones that fail to decode
+    Verify {
+        /// Path to the GRF file
+        grf_file: PathBuf,
+    },
+    /// Export a sprite's frames as PNGs and its ACT animation as JSON
+    Convert {
+        /// The .spr (or .act) to convert; its partner is found next to it
+        sprite: String,
+
+        /// Read the sprite from this GRF instead of the filesystem
+        #[arg(long, value_name = "GRF")]
+        grf: Option<PathBuf>,
+
+        /// Output directory (default: "output")
+        #[arg(short, long, default_value = "output")]
+        output: PathBuf,
+    },
+    /// Merge archives into one; a later archive's file replaces an earlier one's
+    Merge {
+        /// The merged GRF to write
+        output: PathBuf,
+
+        /// Archives to merge, lowest priority first (e.g. data.grf rdata.grf patch.grf)
+        #[arg(required = true)]
+        inputs: Vec<PathBuf>,
+    },
 }
 
-fn main() {
-    if let Err(e) = run() {
-        eprintln!("Error: {:#}", e);
-        std::process::exit(1);
+/// Narrows `list` and whole-archive `extract` to matching entries. Every given
+/// criterion must hold. Paths match with `/` or `\` separators, and Korean
+/// names match in UTF-8 as well as in the archive's EUC-KR mojibake.
+#[derive(Args, Default)]
+struct FilterArgs {
+    /// Only paths matching this glob (`*` and `?`, case-insensitive)
+    #[arg(long, value_name = "GLOB")]
+    filter: Option<String>,
+
+    /// Only these extensions, comma separated (e.g. `spr,act`)
+    #[arg(long, value_name = "EXT", value_delimiter = ',')]
+    ext: Vec<String>,
+
+    /// Only paths matching this regular expression
+    #[arg(long, value_name = "REGEX")]
+    regex: Option<String>,
+}
+
+impl FilterArgs {
+    fn is_empty(&self) -> bool {
+        self.filter.is_none() && self.ext.is_empty() && self.regex.is_none()
     }
+
+    fn compile(self) -> Result<PathFilter> {
+        let regex = self
+            .regex
+            .map(|pattern| {
+                Regex::new(&pattern).with_context(|| format!("Invalid --regex '{}'", pattern))
+            })
+            .transpose()?;
+        Ok(PathFilter {
+            glob: self.filter,
+            extensions: self
+                .ext
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_lowercase())
+                .collect(),
+            regex,
+        })
+    }
+}
+
+/// The compiled form of [`FilterArgs`]; the default matches everything.
+#[derive(Default)]
+struct PathFilter {
+    glob: Option<String>,
+    extensions: Vec<String>,
+    regex: Option<Regex>,
 }
 
-fn run() -> Result<()> {
+impl PathFilter {
+    fn matches(&self, path: &str) -> bool {
+        let decoded = decode_euc_kr_mojibake(path);
+        let names = [Cow::Borrowed(path), decoded];
+        names.iter().any(|name| self.matches_name(name))
+    }
+
+    fn matches_name(&self, name: &str) -> bool {
+        let name = name.replace('\\', "/");
+        let glob = self
+            .glob
+            .as_ref()
+            .is_none_or(|glob| path_matches(&name, glob));
+        let extension = self.extensions.is_empty()
+            || name
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| self.extensions.contains(&ext.to_lowercase()));
+        let regex = self
+            .regex
+            .as_ref()
+            .is_none_or(|regex| regex.is_match(&name));
+        glob && extension && regex
+    }
+}
+
+/// JSON shape of a failed run; the process still exits with status 1.
+#[derive(Serialize)]
+struct ErrorReport {
+    error: String,
+}
+
+fn main() {
     let cli = Cli::parse();
+    let json = cli.json;
+
+    if let Err(e) = run(cli) {
+        if json {
+            print_json(&ErrorReport {
+                error: format!("{:#}", e),
+            });
+        } else {
+            eprintln!("Error: {:#}", e);
+        }
+        std::process::exit(1);
+    }
+}
 
+fn run(cli: Cli) -> Result<()> {
     match cli.command {
-        Commands::List { grf_file } => {
+        Commands::List { grf_file, filter } => {
+            let filter = filter.compile()?;
             let grf = load_grf(&grf_file)?;
-            list_files(&grf);
+            let entries: Vec<GrfEntry> = grf
+                .entries
+                .into_iter()
+                .filter(|entry| filter.matches(&entry.filename))
+                .collect();
+            let report = list_report(&entries);
+            if cli.json {
+                print_json(&report);
+            } else {
+                list_files(&report);
+            }
         }
         Commands::Extract {
             grf_file,
             files,
             output,
+            utf8_paths,
+            lowercase,
+            flatten,
+            filter,
+            threads,
         } => {
+            if !files.is_empty() && !filter.is_empty() {
+                anyhow::bail!(
+                    "--filter, --ext and --regex select from the whole archive; drop the FILE arguments"
+                );
+            }
+            let filter = filter.compile()?;
             let grf = load_grf(&grf_file)?;
-            extract_files(&grf, &files, &output)?;
+            let naming = OutputNaming {
+                utf8_paths,
+                lowercase,
+                flatten,
+            };
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads.unwrap_or(0))
+                .build()
+                .context("Failed to start the extraction workers")?;
+            let report =
+                pool.install(|| extract_files(&grf, &files, &filter, &output, &naming, cli.json))?;
+            if cli.json {
+                print_json(&report);
+            }
         }
         Commands::Info { grf_file } => {
             let grf = load_grf(&grf_file)?;
-            show_info(&grf);
+            let report = info_report(&grf.entries);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_info(&report);
+            }
+        }
+        Commands::Search { grf_file, pattern } => {
+            let grf = load_grf(&grf_file)?;
+            let report = search_report(&grf.entries, &pattern);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_search(&report);
+            }
+        }
+        Commands::Diff {
+            old_grf,
+            new_grf,
+            quick,
+        } => {
+            let old = load_grf(&old_grf)?;
+            let new = load_grf(&new_grf)?;
+            let report = diff_report(&old, &new, quick);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_diff(&report);
+            }
+        }
+        Commands::Verify { grf_file } => {
+            let grf = load_grf(&grf_file)?;
+            let archive_size = fs::metadata(&grf_file)
+                .with_context(|| format!("Failed to read {}", grf_file.display()))?
+                .len();
+            let report = verify_report(&grf, archive_size, cli.json);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_verify(&report);
+            }
+            // A failed verification still printed its report; only the exit
+            // status tells a pipeline about it.
+            if !report.failed.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Commands::Convert {
+            sprite,
+            grf,
+            output,
+        } => {
+            let grf = grf.as_deref().map(load_grf).transpose()?;
+            let report = convert_sprite(grf.as_ref(), &sprite, &output)?;
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_convert(&report, &output);
+            }
+        }
+        Commands::Merge { output, inputs } => {
+            let report = merge_archives(&inputs, &output, cli.json)?;
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_merge(&report);
+            }
+            // Like verify: files that couldn't be read are missing from the
+            // merged archive, which the exit status reports.
+            if !report.unreadable.is_empty() {
+                std::process::exit(1);
+            }
         }
     }
 
     Ok(())
 }
 
+fn print_json<T: Serialize>(report: &T) {
+    match serde_json::to_string_pretty(report) {
+        Ok(text) => println!("{}", text),
+        Err(e) => eprintln!("Error: failed to serialize output: {}", e),
+    }
+}
+
 fn load_grf(path: &Path) -> Result<GrfFile> {
     GrfFile::from_path(path.to_path_buf())
         .with_context(|| format!("Failed to load GRF file: {}", path.display()))
 }
 
-fn list_files(grf: &GrfFile) {
+/// GRF entries with either encryption bit set (mixed or header-only DES).
+fn is_encrypted(file_type: u8) -> bool {
+    file_type & 0x06 != 0
+}
+
+#[derive(Serialize)]
+struct ListEntry {
+    path: String,
+    size: u32,
+    compressed_size: u32,
+    encrypted: bool,
+}
+
+#[derive(Serialize)]
+struct ListReport {
+    total_files: usize,
+    files: Vec<ListEntry>,
+}
+
+impl From<&GrfEntry> for ListEntry {
+    fn from(entry: &GrfEntry) -> Self {
+        Self {
+            path: entry.filename.clone(),
+            size: entry.real_size,
+            compressed_size: entry.pack_size,
+            encrypted: is_encrypted(entry.file_type),
+        }
+    }
+}
+
+fn list_report(entries: &[GrfEntry]) -> ListReport {
+    ListReport {
+        total_files: entries.len(),
+        files: entries.iter().map(ListEntry::from).collect(),
+    }
+}
+
+fn format_size(size: u32) -> String {
+    if size < 1024 {
+        format!("{} B", size)
+    } else if size < 1024 * 1024 {
+        format!("{:.2} KB", size as f64 / 1024.0)
+    } else {
+        format!("{:.2} MB", size as f64 / (1024.0 * 1024.0))
+    }
+}
+
+fn list_files(report: &ListReport) {
     println!("Files in archive:");
     println!("{:-<80}", "");
 
-    for entry in &grf.entries {
-        let size = if entry.real_size < 1024 {
-            format!("{} B", entry.real_size)
-        } else if entry.real_size < 1024 * 1024 {
-            format!("{:.2} KB", entry.real_size as f64 / 1024.0)
+    for entry in &report.files {
+        println!("{:<60} {:>15}", entry.path, format_size(entry.size));
+    }
+
+    println!("{:-<80}", "");
+    println!("Total files: {}", report.total_files);
+}
+
+/// Why a requested or archived file wasn't written.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum SkipReason {
+    NotFound,
+    PathTraversal,
+    WriteFailed,
+    /// Another entry already went to the same output path.
+    NameCollision,
+}
+
+#[derive(Serialize)]
+struct SkippedFile {
+    path: String,
+    reason: SkipReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RenamedFile {
+    path: String,
+    written_as: String,
+}
+
+#[derive(Serialize, Default)]
+struct ExtractReport {
+    output: PathBuf,
+    extracted: Vec<String>,
+    /// Entries written under a different path than their archive name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    renamed: Vec<RenamedFile>,
+    skipped: Vec<SkippedFile>,
+}
+
+impl ExtractReport {
+    fn written(&mut self, path: &str, default_name: &str, written_as: String) {
+        self.extracted.push(path.to_string());
+        if written_as != default_name {
+            self.renamed.push(RenamedFile {
+                path: path.to_string(),
+                written_as,
+            });
+        }
+    }
+
+    fn skip(&mut self, path: &str, reason: SkipReason, detail: Option<String>) {
+        self.skipped.push(SkippedFile {
+            path: path.to_string(),
+            reason,
+            detail,
+        });
+    }
+}
+
+/// How archive names become paths under the output directory.
+struct OutputNaming {
+    utf8_paths: bool,
+    lowercase: bool,
+    flatten: bool,
+}
+
+impl OutputNaming {
+    /// Output path (forward slashes, relative to the output directory) for an
+    /// archive or user-supplied name.
+    fn output_name(&self, name: &str) -> String {
+        let mut name = if self.utf8_paths {
+            decode_euc_kr_mojibake(name).into_owned()
         } else {
-            format!("{:.2} MB", entry.real_size as f64 / (1024.0 * 1024.0))
+            name.to_string()
         };
+        name = name.replace('\\', "/");
+        if self.flatten {
+            name = name.rsplit('/').next().unwrap_or_default().to_string();
+        }
+        if self.lowercase {
+            name = name.to_lowercase();
+        }
+        name
+    }
+}
 
-        println!("{:<60} {:>15}", entry.filename, size);
+/// Output paths already taken in this run. Normalized names can collide, and
+/// the first entry keeps the path rather than being silently overwritten.
+/// Names that differ only in case are distinct unless `--lowercase` made them
+/// equal.
+#[derive(Default)]
+struct ClaimedPaths(HashSet<String>);
+
+impl ClaimedPaths {
+    fn claim(&mut self, output_name: &str) -> bool {
+        self.0.insert(output_name.to_string())
     }
+}
 
-    println!("{:-<80}", "");
-    println!("Total files: {}", grf.entries.len());
+/// A progress bar for text output; hidden when stdout carries JSON.
+fn progress_bar(len: u64, template: &str, quiet: bool) -> ProgressBar {
+    if quiet {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(len);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template(template)
+            .unwrap()
+            .progress_chars("#>-"),
+    );
+    pb
 }
 
-fn extract_files(grf: &GrfFile, files: &[String], output_path: &Path) -> Result<()> {
+fn extract_files(
+    grf: &GrfFile,
+    files: &[String],
+    filter: &PathFilter,
+    output_path: &Path,
+    naming: &OutputNaming,
+    quiet: bool,
+) -> Result<ExtractReport> {
     // Create and canonicalize output directory for path traversal protection
     fs::create_dir_all(output_path).with_context(|| {
         format!(
@@ -113,15 +554,20 @@ fn extract_files(grf: &GrfFile, files: &[String], output_path: &Path) -> Result<
         )
     })?;
 
+    let mut report = ExtractReport {
+        output: canonical_output.clone(),
+        ..Default::default()
+    };
+
     if files.is_empty() {
-        // Extract all files
-        extract_all_files(grf, &canonical_output)?;
+        // Extract all (matching) files
+        extract_all_files(grf, filter, &canonical_output, naming, &mut report, quiet);
     } else {
         // Extract specific files
-        extract_specific_files(grf, files, &canonical_output)?;
+        extract_specific_files(grf, files, &canonical_output, naming, &mut report, quiet);
     }
 
-    Ok(())
+    Ok(report)
 }
 
 enum WriteOutcome {
@@ -175,75 +621,124 @@ fn write_entry(
     }
 }
 
-fn extract_all_files(grf: &GrfFile, canonical_output: &Path) -> Result<()> {
-    let entries_count = grf.entries.len() as u64;
+fn extract_all_files(
+    grf: &GrfFile,
+    filter: &PathFilter,
+    canonical_output: &Path,
+    naming: &OutputNaming,
+    report: &mut ExtractReport,
+    quiet: bool,
+) {
+    let entries: Vec<&GrfEntry> = grf
+        .entries
+        .iter()
+        .filter(|entry| filter.matches(&entry.filename))
+        .collect();
+    let entries_count = entries.len() as u64;
 
-    println!("Extracting {} files...", entries_count);
+    if !quiet {
+        println!("Extracting {} files...", entries_count);
+    }
 
-    let pb = ProgressBar::new(entries_count);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) - {msg}")
-            .unwrap()
-            .progress_chars("#>-"),
+    let pb = progress_bar(
+        entries_count,
+        "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) - {msg}",
+        quiet,
     );
 
-    let mut extracted_count = 0;
-    let mut skipped_count = 0;
-
-    for entry in &grf.entries {
-        // Normalize path (convert backslashes to forward slashes)
-        let normalized_path = entry.filename.replace('\\', "/");
-        pb.set_message(normalized_path.clone());
+    // Claim output paths in archive order first, so the same entry wins a
+    // collision no matter which worker finishes first.
+    let mut claimed = ClaimedPaths::default();
+    let mut jobs = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let normalized_path = naming.output_name(&entry.filename);
+        if claimed.claim(&normalized_path) {
+            jobs.push((entry, normalized_path));
+        } else {
+            report.skip(
+                &entry.filename,
+                SkipReason::NameCollision,
+                Some(normalized_path),
+            );
+            pb.inc(1);
+        }
+    }
 
-        let output_file_path = canonical_output.join(&normalized_path);
+    // Each worker reads, inflates and writes its own entries; only the report
+    // is assembled afterwards, in archive order.
+    let outcomes: Vec<Option<WriteOutcome>> = jobs
+        .par_iter()
+        .map(|(entry, normalized_path)| {
+            pb.set_message(normalized_path.clone());
+            let output_file_path = canonical_output.join(normalized_path);
+            let outcome = grf.get_file(&entry.filename).map(|data| {
+                write_entry(canonical_output, &output_file_path, &entry.filename, &data)
+            });
+            pb.inc(1);
+            outcome
+        })
+        .collect();
 
-        if let Some(data) = grf.get_file(&entry.filename) {
-            match write_entry(canonical_output, &output_file_path, &entry.filename, &data) {
-                WriteOutcome::Written => extracted_count += 1,
-                WriteOutcome::PathTraversalBlocked => skipped_count += 1,
-                WriteOutcome::WriteFailed(e) => {
-                    eprintln!(
-                        "Failed to write file '{}': {}",
-                        output_file_path.display(),
-                        e
-                    );
-                    skipped_count += 1;
-                }
+    for ((entry, normalized_path), outcome) in jobs.into_iter().zip(outcomes) {
+        match outcome {
+            Some(WriteOutcome::Written) => report.written(
+                &entry.filename,
+                &entry.filename.replace('\\', "/"),
+                normalized_path,
+            ),
+            Some(WriteOutcome::PathTraversalBlocked) => {
+                report.skip(&entry.filename, SkipReason::PathTraversal, None)
             }
-        } else {
-            skipped_count += 1;
+            Some(WriteOutcome::WriteFailed(e)) => {
+                eprintln!(
+                    "Failed to write file '{}': {}",
+                    canonical_output.join(&normalized_path).display(),
+                    e
+                );
+                report.skip(
+                    &entry.filename,
+                    SkipReason::WriteFailed,
+                    Some(e.to_string()),
+                );
+            }
+            None => report.skip(&entry.filename, SkipReason::NotFound, None),
         }
-
-        pb.inc(1);
     }
 
     pb.finish_with_message("Extraction complete");
 
-    println!("\nSummary:");
-    println!("  Extracted: {}", extracted_count);
-    if skipped_count > 0 {
-        println!("  Skipped:   {}", skipped_count);
+    if !quiet {
+        println!("\nSummary:");
+        println!("  Extracted: {}", report.extracted.len());
+        if !report.renamed.is_empty() {
+            println!("  Renamed:   {}", report.renamed.len());
+        }
+        if !report.skipped.is_empty() {
+            println!("  Skipped:   {}", report.skipped.len());
+        }
     }
-
-    Ok(())
 }
 
-fn extract_specific_files(grf: &GrfFile, files: &[String], canonical_output: &Path) -> Result<()> {
-    println!("Extracting {} specific file(s)...", files.len());
+fn extract_specific_files(
+    grf: &GrfFile,
+    files: &[String],
+    canonical_output: &Path,
+    naming: &OutputNaming,
+    report: &mut ExtractReport,
+    quiet: bool,
+) {
+    if !quiet {
+        println!("Extracting {} specific file(s)...", files.len());
+    }
 
-    let pb = ProgressBar::new(files.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template(
-                "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} - {msg}",
-            )
-            .unwrap()
-            .progress_chars("#>-"),
+    let pb = progress_bar(
+        files.len() as u64,
+        "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} - {msg}",
+        quiet,
     );
 
-    let mut extracted_count = 0;
     let mut not_found_count = 0;
+    let mut claimed = ClaimedPaths::default();
 
     for file_name in files {
         pb.set_message(file_name.clone());
@@ -252,21 +747,33 @@ fn extract_specific_files(grf: &GrfFile, files: &[String], canonical_output: &Pa
         let normalized_name = file_name.replace('/', "\\");
 
         if let Some(data) = grf.get_file(&normalized_name) {
-            // Use the user-provided name for output (with forward slashes)
-            let output_file_path = canonical_output.join(file_name);
+            let output_name = naming.output_name(file_name);
+            if !claimed.claim(&output_name) {
+                eprintln!("  ✗ '{}' collides with an earlier file", output_name);
+                report.skip(file_name, SkipReason::NameCollision, Some(output_name));
+                pb.inc(1);
+                continue;
+            }
+            let output_file_path = canonical_output.join(&output_name);
 
             match write_entry(canonical_output, &output_file_path, file_name, &data) {
                 WriteOutcome::Written => {
-                    extracted_count += 1;
-                    println!("  ✓ {}", file_name);
+                    if !quiet {
+                        println!("  ✓ {}", output_name);
+                    }
+                    report.written(file_name, &file_name.replace('\\', "/"), output_name);
+                }
+                WriteOutcome::PathTraversalBlocked => {
+                    report.skip(file_name, SkipReason::PathTraversal, None)
                 }
-                WriteOutcome::PathTraversalBlocked => {}
                 WriteOutcome::WriteFailed(e) => {
                     eprintln!("  ✗ Failed to write '{}': {}", file_name, e);
+                    report.skip(file_name, SkipReason::WriteFailed, Some(e.to_string()));
                 }
             }
         } else {
             eprintln!("  ✗ File not found in archive: '{}'", file_name);
+            report.skip(file_name, SkipReason::NotFound, None);
             not_found_count += 1;
         }
 
@@ -275,49 +782,1235 @@ fn extract_specific_files(grf: &GrfFile, files: &[String], canonical_output: &Pa
 
     pb.finish_with_message("Extraction complete");
 
-    println!("\nSummary:");
-    println!("  Extracted: {}", extracted_count);
-    if not_found_count > 0 {
-        println!("  Not found: {}", not_found_count);
+    if !quiet {
+        println!("\nSummary:");
+        println!("  Extracted: {}", report.extracted.len());
+        if not_found_count > 0 {
+            println!("  Not found: {}", not_found_count);
+        }
     }
+}
 
-    Ok(())
+#[derive(Serialize)]
+struct InfoReport {
+    total_files: usize,
+    compressed_bytes: u64,
+    uncompressed_bytes: u64,
+    /// Compressed size as a percentage of the uncompressed size.
+    compression_percent: f64,
+    encrypted_files: usize,
 }
 
-fn show_info(grf: &GrfFile) {
+fn info_report(entries: &[GrfEntry]) -> InfoReport {
+    // Calculate total sizes
+    let compressed_bytes: u64 = entries.iter().map(|e| e.pack_size as u64).sum();
+    let uncompressed_bytes: u64 = entries.iter().map(|e| e.real_size as u64).sum();
+
+    let compression_percent = if uncompressed_bytes > 0 {
+        (compressed_bytes as f64 / uncompressed_bytes as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    InfoReport {
+        total_files: entries.len(),
+        compressed_bytes,
+        uncompressed_bytes,
+        compression_percent,
+        encrypted_files: entries.iter().filter(|e| is_encrypted(e.file_type)).count(),
+    }
+}
+
+fn show_info(report: &InfoReport) {
     println!("GRF Archive Information:");
     println!("{:=<80}", "");
-    println!("Total files:    {}", grf.entries.len());
-
-    // Calculate total sizes
-    let total_compressed: u64 = grf.entries.iter().map(|e| e.pack_size as u64).sum();
-    let total_uncompressed: u64 = grf.entries.iter().map(|e| e.real_size as u64).sum();
+    println!("Total files:    {}", report.total_files);
 
     println!(
         "Compressed:     {:.2} MB",
-        total_compressed as f64 / (1024.0 * 1024.0)
+        report.compressed_bytes as f64 / (1024.0 * 1024.0)
     );
     println!(
         "Uncompressed:   {:.2} MB",
-        total_uncompressed as f64 / (1024.0 * 1024.0)
+        report.uncompressed_bytes as f64 / (1024.0 * 1024.0)
+    );
+    println!("Compression:    {:.1}%", report.compression_percent);
+
+    // File type statistics
+    if report.encrypted_files > 0 {
+        println!("Encrypted:      {} files", report.encrypted_files);
+    }
+
+    println!("{:=<80}", "");
+}
+
+#[derive(Serialize)]
+struct SearchReport {
+    pattern: String,
+    matches: Vec<ListEntry>,
+}
+
+/// `*` and `?` wildcards over the whole path, otherwise a substring match.
+/// Case-insensitive, and `/` matches the archive's `\\`.
+fn path_matches(path: &str, pattern: &str) -> bool {
+    let path = path.to_lowercase().replace('\\', "/");
+    let pattern = pattern.to_lowercase().replace('\\', "/");
+    if !pattern.contains(['*', '?']) {
+        return path.contains(&pattern);
+    }
+    let path: Vec<char> = path.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    // Backtracking wildcard match: remember the last `*` and retry from it.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < path.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == path[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+fn search_report(entries: &[GrfEntry], pattern: &str) -> SearchReport {
+    SearchReport {
+        pattern: pattern.to_string(),
+        matches: entries
+            .iter()
+            .filter(|entry| path_matches(&entry.filename, pattern))
+            .map(ListEntry::from)
+            .collect(),
+    }
+}
+
+fn show_search(report: &SearchReport) {
+    for entry in &report.matches {
+        println!("{:<60} {:>15}", entry.path, format_size(entry.size));
+    }
+    println!(
+        "{} match(es) for '{}'",
+        report.matches.len(),
+        report.pattern
     );
+}
+
+#[derive(Serialize)]
+struct ChangedFile {
+    path: String,
+    old_size: u32,
+    new_size: u32,
+}
 
-    let compression_ratio = if total_uncompressed > 0 {
-        (total_compressed as f64 / total_uncompressed as f64) * 100.0
+#[derive(Serialize, Default)]
+struct DiffReport {
+    added: Vec<ListEntry>,
+    removed: Vec<ListEntry>,
+    changed: Vec<ChangedFile>,
+    unchanged: usize,
+}
+
+/// Entries present in both archives are matched by case-insensitive path.
+/// Without `quick`, same-sized entries are read and compared byte for byte.
+fn diff_report(old: &GrfFile, new: &GrfFile, quick: bool) -> DiffReport {
+    diff_entries(&old.entries, &new.entries, |old_entry, new_entry| {
+        quick || old.get_file(&old_entry.filename) == new.get_file(&new_entry.filename)
+    })
+}
+
+/// The diff over two entry lists; `same_content` decides entries whose sizes match.
+fn diff_entries(
+    old: &[GrfEntry],
+    new: &[GrfEntry],
+    mut same_content: impl FnMut(&GrfEntry, &GrfEntry) -> bool,
+) -> DiffReport {
+    let key = |entry: &GrfEntry| entry.filename.to_lowercase();
+    let old_by_key: HashMap<String, &GrfEntry> = old.iter().map(|e| (key(e), e)).collect();
+    let new_keys: HashSet<String> = new.iter().map(key).collect();
+
+    let mut report = DiffReport::default();
+    for new_entry in new {
+        let Some(old_entry) = old_by_key.get(&key(new_entry)) else {
+            report.added.push(new_entry.into());
+            continue;
+        };
+        if old_entry.real_size == new_entry.real_size && same_content(old_entry, new_entry) {
+            report.unchanged += 1;
+        } else {
+            report.changed.push(ChangedFile {
+                path: new_entry.filename.clone(),
+                old_size: old_entry.real_size,
+                new_size: new_entry.real_size,
+            });
+        }
+    }
+    report.removed = old
+        .iter()
+        .filter(|entry| !new_keys.contains(&key(entry)))
+        .map(ListEntry::from)
+        .collect();
+    report
+}
+
+fn show_diff(report: &DiffReport) {
+    for entry in &report.added {
+        println!("+ {}", entry.path);
+    }
+    for entry in &report.removed {
+        println!("- {}", entry.path);
+    }
+    for entry in &report.changed {
+        println!(
+            "~ {} ({} -> {})",
+            entry.path,
+            format_size(entry.old_size),
+            format_size(entry.new_size)
+        );
+    }
+    println!(
+        "Added: {}, removed: {}, changed: {}, unchanged: {}",
+        report.added.len(),
+        report.removed.len(),
+        report.changed.len(),
+        report.unchanged
+    );
+}
+
+/// Why an entry failed verification.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum VerifyFailure {
+    /// Its data runs past the end of the archive, as after a cut-off download.
+    Truncated { archive_size: u64 },
+    /// Reading, decrypting or inflating the entry failed. Inflating checks the
+    /// zlib stream's Adler-32, the only checksum a GRF stores.
+    Unreadable,
+    /// It decoded to a different length than the table says.
+    SizeMismatch { expected: u32, actual: usize },
+}
+
+#[derive(Serialize)]
+struct FailedFile {
+    path: String,
+    /// Byte offset of the entry's data in the archive file.
+    offset: u64,
+    #[serde(flatten)]
+    failure: VerifyFailure,
+}
+
+#[derive(Serialize)]
+struct VerifyReport {
+    total_files: usize,
+    ok: usize,
+    failed: Vec<FailedFile>,
+}
+
+/// Checks one entry; `read` decodes it and is skipped when its data cannot be
+/// in the archive at all.
+fn verify_entry(
+    entry: &GrfEntry,
+    archive_size: u64,
+    read: impl FnOnce() -> Option<Vec<u8>>,
+) -> Result<(), VerifyFailure> {
+    if entry.data_range().end > archive_size {
+        return Err(VerifyFailure::Truncated { archive_size });
+    }
+    let data = read().ok_or(VerifyFailure::Unreadable)?;
+    if data.len() != entry.real_size as usize {
+        return Err(VerifyFailure::SizeMismatch {
+            expected: entry.real_size,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+fn verify_report(grf: &GrfFile, archive_size: u64, quiet: bool) -> VerifyReport {
+    // Directory entries have no data to check.
+    let files: Vec<&GrfEntry> = grf
+        .entries
+        .iter()
+        .filter(|e| e.file_type & 0x01 != 0)
+        .collect();
+    let pb = progress_bar(
+        files.len() as u64,
+        "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} - {msg}",
+        quiet,
+    );
+
+    let results: Vec<Result<(), VerifyFailure>> = files
+        .par_iter()
+        .map(|entry| {
+            pb.set_message(entry.filename.clone());
+            let result = verify_entry(entry, archive_size, || grf.get_file(&entry.filename));
+            pb.inc(1);
+            result
+        })
+        .collect();
+    pb.finish_and_clear();
+
+    let mut report = VerifyReport {
+        total_files: grf.entries.len(),
+        ok: 0,
+        failed: Vec::new(),
+    };
+    for (entry, result) in files.into_iter().zip(results) {
+        match result {
+            Ok(()) => report.ok += 1,
+            Err(failure) => report.failed.push(FailedFile {
+                path: entry.filename.clone(),
+                offset: entry.data_range().start,
+                failure,
+            }),
+        }
+    }
+    report
+}
+
+fn show_verify(report: &VerifyReport) {
+    for file in &report.failed {
+        let problem = match file.failure {
+            VerifyFailure::Truncated { archive_size } => {
+                format!("data ends past the archive's {} bytes", archive_size)
+            }
+            VerifyFailure::Unreadable => "unreadable".to_string(),
+            VerifyFailure::SizeMismatch { expected, actual } => {
+                format!("{} bytes, expected {}", actual, expected)
+            }
+        };
+        println!("  ✗ {} (offset {:#x}): {}", file.path, file.offset, problem);
+    }
+    println!(
+        "Verified {} files: {} ok, {} failed",
+        report.total_files,
+        report.ok,
+        report.failed.len()
+    );
+}
+
+/// Reads `path` from `grf`, or from disk without one. Archive paths may use
+/// `/` separators and spell Korean names in UTF-8.
+fn read_asset(grf: Option<&GrfFile>, path: &str) -> Result<Vec<u8>> {
+    let Some(grf) = grf else {
+        return fs::read(path).with_context(|| format!("Failed to read {}", path));
+    };
+    let archive_path = path.replace('/', "\\");
+    if let Some(data) = grf.get_file(&archive_path) {
+        return Ok(data);
+    }
+    grf.entries
+        .iter()
+        .find(|entry| decode_euc_kr_mojibake(&entry.filename).eq_ignore_ascii_case(&archive_path))
+        .and_then(|entry| grf.get_file(&entry.filename))
+        .with_context(|| format!("{} is not in the archive", path))
+}
+
+/// A sprite frame as straight RGBA, drawn the way the game draws it: indexed
+/// frames go through `palette` and index 0 and magenta are transparent.
+fn frame_rgba(frame: &SpriteFrame, palette: &Palette) -> Vec<u8> {
+    let mut rgba: Vec<u8> = if frame.is_rgba {
+        frame.data.clone()
     } else {
-        0.0
+        frame
+            .data
+            .iter()
+            .flat_map(|&index| {
+                palette
+                    .colors
+                    .get(index as usize)
+                    .copied()
+                    .unwrap_or([0; 4])
+            })
+            .collect()
     };
-    println!("Compression:    {:.1}%", compression_ratio);
+    // Corrupt frames are padded or cut to their stated size.
+    rgba.resize(frame.width as usize * frame.height as usize * 4, 0);
+    for pixel in rgba.chunks_exact_mut(4) {
+        if pixel[..3] == [255, 0, 255] {
+            pixel.fill(0);
+        }
+    }
+    rgba
+}
 
-    // File type statistics
-    let encrypted_count = grf
-        .entries
+/// The ACT half of a converted sprite, written next to its frame PNGs.
+#[derive(Serialize)]
+struct AnimationFile {
+    /// PNG per SPR frame, `None` for empty frames; layers index into this.
+    frames: Vec<Option<String>>,
+    /// In ACT order: eight directions per action.
+    actions: Vec<AnimationAction>,
+    sounds: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct AnimationAction {
+    delay_ms: f32,
+    frames: Vec<AnimationFrame>,
+}
+
+#[derive(Serialize)]
+struct AnimationFrame {
+    layers: Vec<AnimationLayer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sound: Option<String>,
+    /// Where another sprite (a head on a body) anchors, relative to the
+    /// sprite's origin with +y down.
+    attach_points: Vec<[i32; 2]>,
+}
+
+#[derive(Serialize)]
+struct AnimationLayer {
+    /// SPR frame drawn by this layer, `None` for an empty layer.
+    frame: Option<usize>,
+    /// Center of the frame relative to the sprite's origin, +y down.
+    x: i32,
+    y: i32,
+    mirror: bool,
+    scale: [f32; 2],
+    angle: i32,
+    color: [f32; 4],
+}
+
+fn animation_file(action: &RoAction, frames: Vec<Option<String>>) -> AnimationFile {
+    let actions = action
+        .actions
         .iter()
-        .filter(|e| e.file_type & 0x06 != 0)
-        .count();
-    if encrypted_count > 0 {
-        println!("Encrypted:      {} files", encrypted_count);
+        .map(|sequence| AnimationAction {
+            delay_ms: sequence.delay,
+            frames: sequence
+                .animations
+                .iter()
+                .map(|animation| AnimationFrame {
+                    layers: animation
+                        .layers
+                        .iter()
+                        .map(|layer| AnimationLayer {
+                            frame: usize::try_from(layer.sprite_index).ok(),
+                            x: layer.pos[0],
+                            y: layer.pos[1],
+                            mirror: layer.is_mirror,
+                            scale: layer.scale,
+                            angle: layer.angle,
+                            color: layer.color,
+                        })
+                        .collect(),
+                    sound: usize::try_from(animation.sound_id)
+                        .ok()
+                        .and_then(|id| action.sounds.get(id))
+                        .cloned(),
+                    attach_points: animation
+                        .positions
+                        .iter()
+                        .map(|position| [position.x, position.y])
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+    AnimationFile {
+        frames,
+        actions,
+        sounds: action.sounds.clone(),
     }
+}
 
-    println!("{:=<80}", "");
+#[derive(Serialize)]
+struct ConvertReport {
+    animation: PathBuf,
+    frames: usize,
+    actions: usize,
+}
+
+/// Writes `<name>_NNN.png` per frame and `<name>.json` for the `.spr`/`.act`
+/// pair `path` names, either file of it.
+fn convert_sprite(grf: Option<&GrfFile>, path: &str, output: &Path) -> Result<ConvertReport> {
+    let stem = match path.rsplit_once('.') {
+        Some((stem, ext)) if ext.eq_ignore_ascii_case("spr") || ext.eq_ignore_ascii_case("act") => {
+            stem
+        }
+        _ => path,
+    };
+    let sprite_path = format!("{}.spr", stem);
+    let action_path = format!("{}.act", stem);
+    let sprite = parse_spr(&read_asset(grf, &sprite_path)?)
+        .with_context(|| format!("Failed to parse {}", sprite_path))?;
+    let action = parse_act(&read_asset(grf, &action_path)?)
+        .with_context(|| format!("Failed to parse {}", action_path))?;
+
+    let name = stem.rsplit(['/', '\\']).next().unwrap_or(stem);
+    let name = decode_euc_kr_mojibake(name);
+    fs::create_dir_all(output).with_context(|| format!("Failed to create {}", output.display()))?;
+
+    let palette = sprite.palette.clone().unwrap_or_else(Palette::grayscale);
+    let mut frames = Vec::with_capacity(sprite.frames.len());
+    for (index, frame) in sprite.frames.iter().enumerate() {
+        if frame.width == 0 || frame.height == 0 {
+            frames.push(None);
+            continue;
+        }
+        let file = format!("{}_{:03}.png", name, index);
+        let image = image::RgbaImage::from_raw(
+            frame.width.into(),
+            frame.height.into(),
+            frame_rgba(frame, &palette),
+        )
+        .context("frame_rgba sizes the buffer to the frame")?;
+        image
+            .save(output.join(&file))
+            .with_context(|| format!("Failed to write {}", file))?;
+        frames.push(Some(file));
+    }
+
+    let animation = animation_file(&action, frames);
+    let animation_path = output.join(format!("{}.json", name));
+    let text = serde_json::to_string_pretty(&animation)?;
+    fs::write(&animation_path, text)
+        .with_context(|| format!("Failed to write {}", animation_path.display()))?;
+
+    Ok(ConvertReport {
+        animation: animation_path,
+        frames: sprite.frames.len(),
+        actions: action.actions.len(),
+    })
+}
+
+fn show_convert(report: &ConvertReport, output: &Path) {
+    println!(
+        "Wrote {} frames to {} and {} actions to {}",
+        report.frames,
+        output.display(),
+        report.actions,
+        report.animation.display()
+    );
+}
+
+#[derive(Serialize)]
+struct MergeReport {
+    output: PathBuf,
+    total_files: usize,
+    /// Files replaced by a later archive's copy.
+    overridden: usize,
+    /// Winning entries that failed to decode, left out of the output.
+    unreadable: Vec<String>,
+}
+
+const GRF_HEADER_SIZE: u64 = 46;
+
+/// Entries to write per batch: compressed in parallel, written in order.
+const MERGE_BATCH: usize = 256;
+
+/// Writes every file of `inputs` to `output`, sorted by path and
+/// recompressed. Paths compare case-insensitively and the last archive
+/// holding a path wins. Encrypted entries come out decrypted.
+fn merge_archives(inputs: &[PathBuf], output: &Path, quiet: bool) -> Result<MergeReport> {
+    let target = fs::canonicalize(output).ok();
+    if target.is_some()
+        && inputs
+            .iter()
+            .any(|input| fs::canonicalize(input).ok() == target)
+    {
+        anyhow::bail!("{} is one of the inputs", output.display());
+    }
+    let archives = inputs
+        .iter()
+        .map(|path| load_grf(path))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut winners: BTreeMap<String, (&GrfFile, &GrfEntry)> = BTreeMap::new();
+    let mut overridden = 0;
+    for grf in &archives {
+        // Directory entries have no data and are implied by the paths.
+        for entry in grf.entries.iter().filter(|e| e.file_type & 0x01 != 0) {
+            let key = entry.filename.to_lowercase().replace('/', "\\");
+            if winners.insert(key, (grf, entry)).is_some() {
+                overridden += 1;
+            }
+        }
+    }
+
+    let file = fs::File::create(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+    let mut writer = BufWriter::new(file);
+    writer.write_all(&[0; GRF_HEADER_SIZE as usize])?;
+
+    let pb = progress_bar(
+        winners.len() as u64,
+        "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} - {msg}",
+        quiet,
+    );
+    let winners: Vec<_> = winners.into_values().collect();
+    let mut table = Vec::new();
+    let mut written = Vec::new();
+    let mut unreadable = Vec::new();
+    let mut offset = 0u64;
+    for batch in winners.chunks(MERGE_BATCH) {
+        let packed: Vec<Option<(Vec<u8>, u32)>> = batch
+            .par_iter()
+            .map(|(grf, entry)| {
+                pb.set_message(entry.filename.clone());
+                let data = grf.get_file(&entry.filename)?;
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+                encoder.write_all(&data).ok()?;
+                let compressed = encoder.finish().ok()?;
+                let real_size = u32::try_from(data.len()).ok()?;
+                // Readers take equal sizes to mean stored as-is, which is
+                // also the better choice when zlib doesn't shrink the file.
+                if compressed.len() < data.len() {
+                    Some((compressed, real_size))
+                } else {
+                    Some((data, real_size))
+                }
+            })
+            .collect();
+        for ((_, entry), packed) in batch.iter().zip(packed) {
+            pb.inc(1);
+            let Some((data, real_size)) = packed else {
+                unreadable.push(entry.filename.clone());
+                continue;
+            };
+            writer.write_all(&data)?;
+            let (name, _, _) = EUC_KR.encode(&entry.filename);
+            table.push(MergedEntry {
+                name: name.into_owned(),
+                pack_size: data.len() as u32,
+                real_size,
+                offset,
+            });
+            written.push(entry.filename.clone());
+            offset += data.len() as u64;
+        }
+    }
+    pb.finish_and_clear();
+
+    write_file_table(&mut writer, &table, offset)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    Ok(MergeReport {
+        output: output.to_path_buf(),
+        total_files: written.len(),
+        overridden,
+        unreadable,
+    })
+}
+
+/// A file as written to the merged archive.
+struct MergedEntry {
+    /// EUC-KR bytes, as the file table stores them.
+    name: Vec<u8>,
+    pack_size: u32,
+    real_size: u32,
+    /// Relative to the end of the header.
+    offset: u64,
+}
+
+/// Appends the file table at `table_offset` and fills in the header. The
+/// archive is v0x200, which every client reads, unless its data outgrows
+/// 32-bit offsets and needs v0x300.
+fn write_file_table(
+    writer: &mut BufWriter<fs::File>,
+    entries: &[MergedEntry],
+    table_offset: u64,
+) -> Result<()> {
+    let wide = u32::try_from(table_offset).is_err();
+    let mut table = Vec::new();
+    for entry in entries {
+        table.extend_from_slice(&entry.name);
+        table.push(0);
+        table.extend_from_slice(&entry.pack_size.to_le_bytes());
+        table.extend_from_slice(&entry.pack_size.to_le_bytes());
+        table.extend_from_slice(&entry.real_size.to_le_bytes());
+        table.push(0x01);
+        if wide {
+            table.extend_from_slice(&entry.offset.to_le_bytes());
+        } else {
+            table.extend_from_slice(&(entry.offset as u32).to_le_bytes());
+        }
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(&table)?;
+    let packed_table = encoder.finish()?;
+
+    if wide {
+        writer.write_all(&0u32.to_le_bytes())?;
+    }
+    writer.write_all(&(packed_table.len() as u32).to_le_bytes())?;
+    writer.write_all(&(table.len() as u32).to_le_bytes())?;
+    writer.write_all(&packed_table)?;
+
+    let count = entries.len() as u32;
+    let mut header = [0u8; GRF_HEADER_SIZE as usize];
+    header[..15].copy_from_slice(b"Master of Magic");
+    if wide {
+        header[30..38].copy_from_slice(&table_offset.to_le_bytes());
+        header[38..42].copy_from_slice(&count.to_le_bytes());
+        header[42..46].copy_from_slice(&0x300u32.to_le_bytes());
+    } else {
+        header[30..34].copy_from_slice(&(table_offset as u32).to_le_bytes());
+        // v0x200 counts seven more files than it holds.
+        header[38..42].copy_from_slice(&(count + 7).to_le_bytes());
+        header[42..46].copy_from_slice(&0x200u32.to_le_bytes());
+    }
+    writer.seek(SeekFrom::Start(0))?;
+    writer.write_all(&header)?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn show_merge(report: &MergeReport) {
+    for path in &report.unreadable {
+        println!("  ✗ {}: unreadable, left out", path);
+    }
+    println!(
+        "Wrote {} files to {} ({} replaced by later archives)",
+        report.total_files,
+        report.output.display(),
+        report.overridden
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(filename: &str, real_size: u32, pack_size: u32, file_type: u8) -> GrfEntry {
+        GrfEntry {
+            filename: filename.to_string(),
+            pack_size,
+            length_aligned: pack_size,
+            real_size,
+            file_type,
+            offset: 0,
+        }
+    }
+
+    /// Zlib stream made of stored blocks, so tests need no compressor.
+    fn zlib_stored(data: &[u8]) -> Vec<u8> {
+        let mut out = vec![0x78, 0x01];
+        let mut chunks = data.chunks(0xffff).peekable();
+        if chunks.peek().is_none() {
+            out.extend_from_slice(&[0x01, 0x00, 0x00, 0xff, 0xff]);
+        }
+        while let Some(chunk) = chunks.next() {
+            out.push(chunks.peek().is_none() as u8);
+            let len = chunk.len() as u16;
+            out.extend_from_slice(&len.to_le_bytes());
+            out.extend_from_slice(&(!len).to_le_bytes());
+            out.extend_from_slice(chunk);
+        }
+        let (mut a, mut b) = (1u32, 0u32);
+        for &byte in data {
+            a = (a + byte as u32) % 65521;
+            b = (b + a) % 65521;
+        }
+        out.extend_from_slice(&((b << 16) | a).to_be_bytes());
+        out
+    }
+
+    /// A v0x300 GRF with uncompressed file data, written to a fresh temp file.
+    fn write_grf(name: &str, files: &[(&str, &[u8])]) -> PathBuf {
+        const HEADER_SIZE: usize = 46;
+        let mut buf = vec![0u8; HEADER_SIZE];
+        buf[..15].copy_from_slice(b"Master of Magic");
+        for (i, byte) in buf[15..29].iter_mut().enumerate() {
+            *byte = (i + 1) as u8;
+        }
+
+        let mut table = Vec::new();
+        for (filename, content) in files {
+            let offset = (buf.len() - HEADER_SIZE) as u64;
+            buf.extend_from_slice(content);
+            let size = content.len() as u32;
+            table.extend_from_slice(filename.as_bytes());
+            table.push(0);
+            table.extend_from_slice(&size.to_le_bytes());
+            table.extend_from_slice(&size.to_le_bytes());
+            table.extend_from_slice(&size.to_le_bytes());
+            table.push(0x01);
+            table.extend_from_slice(&offset.to_le_bytes());
+        }
+        let table_comp = zlib_stored(&table);
+
+        let file_table_offset = (buf.len() - HEADER_SIZE) as u64;
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        buf.extend_from_slice(&(table_comp.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&(table.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&table_comp);
+        buf[30..38].copy_from_slice(&file_table_offset.to_le_bytes());
+        buf[38..42].copy_from_slice(&(files.len() as u32).to_le_bytes());
+        buf[42..46].copy_from_slice(&0x300u32.to_le_bytes());
+
+        let path = temp_dir(name).join("test.grf");
+        fs::write(&path, buf).unwrap();
+        path
+    }
+
+    /// An empty directory unique to this test run.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("grf-utils-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn list_and_info_reports_serialize_stably() {
+        let entries = [
+            entry("data\\a.txt", 100, 40, 0x01),
+            entry("data\\b.spr", 300, 60, 0x03),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(list_report(&entries)).unwrap(),
+            json!({
+                "total_files": 2,
+                "files": [
+                    {"path": "data\\a.txt", "size": 100, "compressed_size": 40, "encrypted": false},
+                    {"path": "data\\b.spr", "size": 300, "compressed_size": 60, "encrypted": true},
+                ],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(info_report(&entries)).unwrap(),
+            json!({
+                "total_files": 2,
+                "compressed_bytes": 100,
+                "uncompressed_bytes": 400,
+                "compression_percent": 25.0,
+                "encrypted_files": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn skip_reasons_serialize_as_snake_case() {
+        let mut report = ExtractReport::default();
+        report.skip("a", SkipReason::NotFound, None);
+        report.skip("b", SkipReason::PathTraversal, None);
+        report.skip("c", SkipReason::WriteFailed, Some("denied".into()));
+        report.skip("d", SkipReason::NameCollision, Some("x/d".into()));
+
+        assert_eq!(
+            serde_json::to_value(&report.skipped).unwrap(),
+            json!([
+                {"path": "a", "reason": "not_found"},
+                {"path": "b", "reason": "path_traversal"},
+                {"path": "c", "reason": "write_failed", "detail": "denied"},
+                {"path": "d", "reason": "name_collision", "detail": "x/d"},
+            ])
+        );
+    }
+
+    #[test]
+    fn search_matches_substrings_and_globs_case_insensitively() {
+        let entries = [
+            entry("data\\sprite\\Poring.spr", 1, 1, 0x01),
+            entry("data\\sprite\\poring.act", 1, 1, 0x01),
+            entry("data\\texture\\logo.bmp", 1, 1, 0x01),
+        ];
+        let paths = |pattern: &str| -> Vec<String> {
+            search_report(&entries, pattern)
+                .matches
+                .into_iter()
+                .map(|m| m.path)
+                .collect()
+        };
+
+        assert_eq!(paths("PORING").len(), 2);
+        assert_eq!(paths("data/sprite/*.spr"), ["data\\sprite\\Poring.spr"]);
+        assert_eq!(paths("*logo.bm?"), ["data\\texture\\logo.bmp"]);
+        assert!(paths("*.gat").is_empty());
+    }
+
+    #[test]
+    fn path_filters_combine_glob_extension_and_regex() {
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode("data\\sprite\\몬스터\\poring.spr");
+        let (mojibake, _) = encoding_rs::WINDOWS_1252.decode_without_bom_handling(&euc_kr);
+        let paths = [
+            mojibake.as_ref(),
+            "data\\sprite\\npc\\kafra.act",
+            "data\\texture\\logo.bmp",
+        ];
+        let matching = |filter: FilterArgs| -> Vec<&str> {
+            let filter = filter.compile().unwrap();
+            paths.into_iter().filter(|p| filter.matches(p)).collect()
+        };
+
+        assert_eq!(matching(FilterArgs::default()).len(), 3);
+        assert_eq!(
+            matching(FilterArgs {
+                filter: Some("data/sprite/몬스터/*".into()),
+                ..Default::default()
+            }),
+            [mojibake.as_ref()]
+        );
+        assert_eq!(
+            matching(FilterArgs {
+                ext: vec!["SPR".into(), ".act".into()],
+                regex: Some(r"/npc/".into()),
+                ..Default::default()
+            }),
+            ["data\\sprite\\npc\\kafra.act"]
+        );
+        assert!(
+            FilterArgs {
+                regex: Some("(".into()),
+                ..Default::default()
+            }
+            .compile()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_entries() {
+        let old = [
+            entry("data\\same.txt", 10, 10, 0x01),
+            entry("data\\resized.txt", 10, 10, 0x01),
+            entry("data\\edited.txt", 10, 10, 0x01),
+            entry("data\\gone.txt", 10, 10, 0x01),
+        ];
+        let new = [
+            entry("DATA\\same.txt", 10, 10, 0x01),
+            entry("data\\resized.txt", 20, 20, 0x01),
+            entry("data\\edited.txt", 10, 10, 0x01),
+            entry("data\\new.txt", 10, 10, 0x01),
+        ];
+
+        let report = diff_entries(&old, &new, |_, new| !new.filename.contains("edited"));
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            json!({
+                "added": [{"path": "data\\new.txt", "size": 10, "compressed_size": 10, "encrypted": false}],
+                "removed": [{"path": "data\\gone.txt", "size": 10, "compressed_size": 10, "encrypted": false}],
+                "changed": [
+                    {"path": "data\\resized.txt", "old_size": 10, "new_size": 20},
+                    {"path": "data\\edited.txt", "old_size": 10, "new_size": 10},
+                ],
+                "unchanged": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn diff_and_verify_read_real_archives() {
+        let old = GrfFile::from_path(write_grf(
+            "diff-old",
+            &[("data\\a.txt", b"hello"), ("data\\b.txt", b"world")],
+        ))
+        .unwrap();
+        let new_path = write_grf(
+            "diff-new",
+            &[("data\\a.txt", b"hello"), ("data\\b.txt", b"WORLD")],
+        );
+        let new = GrfFile::from_path(new_path.clone()).unwrap();
+
+        let full = diff_report(&old, &new, false);
+        assert_eq!(full.unchanged, 1);
+        assert_eq!(full.changed.len(), 1);
+        assert_eq!(diff_report(&old, &new, true).unchanged, 2);
+
+        let archive_size = fs::metadata(&new_path).unwrap().len();
+        let verify = verify_report(&new, archive_size, true);
+        assert_eq!(verify.ok, 2);
+        assert!(verify.failed.is_empty());
+
+        // Pretend the archive stops inside the second entry's data.
+        let cut = new.entries[1].data_range().start + 1;
+        let verify = verify_report(&new, cut, true);
+        assert_eq!(verify.ok, 1);
+        assert_eq!(
+            serde_json::to_value(&verify.failed).unwrap(),
+            json!([{"path": "data\\b.txt", "offset": 51, "truncated": {"archive_size": 52}}])
+        );
+    }
+
+    #[test]
+    fn verify_flags_unreadable_and_truncated_entries() {
+        let file = entry("data\\a.txt", 5, 5, 0x01);
+        let size = file.data_range().end;
+        let verify = |data: Option<&[u8]>| verify_entry(&file, size, || data.map(<[u8]>::to_vec));
+        assert_eq!(verify(Some(b"hello")), Ok(()));
+        assert_eq!(verify(None), Err(VerifyFailure::Unreadable));
+        assert_eq!(
+            verify_entry(&file, size - 1, || unreachable!(
+                "truncated data is never read"
+            )),
+            Err(VerifyFailure::Truncated {
+                archive_size: size - 1
+            })
+        );
+        let failure = verify(Some(b"hi")).unwrap_err();
+        assert_eq!(
+            serde_json::to_value(FailedFile {
+                path: file.filename.clone(),
+                offset: file.data_range().start,
+                failure,
+            })
+            .unwrap(),
+            json!({"path": "data\\a.txt", "offset": 46, "size_mismatch": {"expected": 5, "actual": 2}})
+        );
+    }
+
+    fn naming(utf8_paths: bool, lowercase: bool, flatten: bool) -> OutputNaming {
+        OutputNaming {
+            utf8_paths,
+            lowercase,
+            flatten,
+        }
+    }
+
+    #[test]
+    fn output_names_follow_each_flag_combination() {
+        let korean = "data\\sprite\\인간족\\Novice.SPR";
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode(korean);
+        let (mojibake, _) = encoding_rs::WINDOWS_1252.decode_without_bom_handling(&euc_kr);
+
+        let cases = [
+            ((false, false, false), "data/sprite/{m}/Novice.SPR"),
+            ((true, false, false), "data/sprite/인간족/Novice.SPR"),
+            ((false, true, false), "data/sprite/{m_lower}/novice.spr"),
+            ((false, false, true), "Novice.SPR"),
+            ((true, true, false), "data/sprite/인간족/novice.spr"),
+            ((true, false, true), "Novice.SPR"),
+            ((false, true, true), "novice.spr"),
+            ((true, true, true), "novice.spr"),
+        ];
+        let mojibake_dir = mojibake.split('\\').nth(2).unwrap();
+        for ((utf8, lower, flat), expected) in cases {
+            let expected = expected
+                .replace("{m_lower}", &mojibake_dir.to_lowercase())
+                .replace("{m}", mojibake_dir);
+            assert_eq!(
+                naming(utf8, lower, flat).output_name(&mojibake),
+                expected,
+                "utf8_paths={utf8} lowercase={lower} flatten={flat}"
+            );
+        }
+    }
+
+    #[test]
+    fn claimed_paths_fold_case_only_through_lowercase() {
+        let names = ["data\\Logo.bmp", "data\\logo.bmp"];
+        for (lowercase, collides) in [(false, false), (true, true)] {
+            let naming = naming(false, lowercase, false);
+            let mut claimed = ClaimedPaths::default();
+            assert!(claimed.claim(&naming.output_name(names[0])));
+            assert_eq!(!claimed.claim(&naming.output_name(names[1])), collides);
+        }
+    }
+
+    #[test]
+    fn extract_skips_collisions_and_reports_renames() {
+        let grf = GrfFile::from_path(write_grf(
+            "extract-collide",
+            &[
+                ("data\\a\\item.txt", b"first"),
+                ("data\\b\\ITEM.txt", b"second"),
+                ("data\\b\\other.txt", b"third"),
+            ],
+        ))
+        .unwrap();
+
+        let plain = temp_dir("extract-plain");
+        let report = extract_files(
+            &grf,
+            &[],
+            &PathFilter::default(),
+            &plain,
+            &naming(false, false, false),
+            true,
+        )
+        .unwrap();
+        assert_eq!(report.extracted.len(), 3);
+        assert!(report.renamed.is_empty() && report.skipped.is_empty());
+
+        let flat = temp_dir("extract-flat");
+        let report = extract_files(
+            &grf,
+            &[],
+            &PathFilter::default(),
+            &flat,
+            &naming(false, true, true),
+            true,
+        )
+        .unwrap();
+        assert_eq!(report.extracted.len(), 2);
+        assert_eq!(fs::read(flat.join("item.txt")).unwrap(), b"first");
+        assert_eq!(
+            serde_json::to_value(&report.skipped).unwrap(),
+            json!([{"path": "data\\b\\ITEM.txt", "reason": "name_collision", "detail": "item.txt"}])
+        );
+        assert_eq!(report.renamed.len(), 2);
+    }
+
+    #[test]
+    fn parallel_extraction_reports_in_archive_order() {
+        let names: Vec<String> = (0..64).map(|i| format!("data\\{i}.txt")).collect();
+        let files: Vec<(&str, &[u8])> = names.iter().map(|n| (n.as_str(), n.as_bytes())).collect();
+        let grf = GrfFile::from_path(write_grf("extract-parallel", &files)).unwrap();
+
+        let out = temp_dir("extract-parallel-out");
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        let report = pool
+            .install(|| {
+                let naming = naming(false, false, false);
+                extract_files(&grf, &[], &PathFilter::default(), &out, &naming, true)
+            })
+            .unwrap();
+        assert_eq!(report.extracted, names);
+        assert_eq!(fs::read(out.join("data/63.txt")).unwrap(), b"data\\63.txt");
+    }
+
+    #[test]
+    fn backslashed_requests_are_not_reported_as_renamed() {
+        let grf =
+            GrfFile::from_path(write_grf("extract-specific", &[("data\\a.txt", b"a")])).unwrap();
+        let out = temp_dir("extract-specific-out");
+        let files = ["data\\a.txt".to_string()];
+        let report = extract_files(
+            &grf,
+            &files,
+            &PathFilter::default(),
+            &out,
+            &naming(false, false, false),
+            true,
+        )
+        .unwrap();
+        assert_eq!(report.extracted, files);
+        assert!(report.renamed.is_empty());
+        assert_eq!(fs::read(out.join("data/a.txt")).unwrap(), b"a");
+    }
+
+    #[test]
+    fn convert_exports_frames_and_the_act_layout() {
+        let mut spr = b"SP\x00\x02".to_vec();
+        spr.extend_from_slice(&2u16.to_le_bytes()); // indexed frames
+        spr.extend_from_slice(&0u16.to_le_bytes()); // rgba frames
+        spr.extend_from_slice(&[2, 0, 1, 0, 0, 1]); // 2x1: transparent, red
+        spr.extend_from_slice(&[0, 0, 0, 0]); // empty frame
+        let mut palette = [0u8; 1024];
+        palette[4..8].copy_from_slice(&[200, 10, 20, 0]);
+        spr.extend_from_slice(&palette);
+
+        let mut act = b"AC\x03\x02".to_vec();
+        act.extend_from_slice(&1u16.to_le_bytes());
+        act.extend_from_slice(&[0; 10]);
+        act.extend_from_slice(&1u32.to_le_bytes()); // frames
+        act.extend_from_slice(&[0; 32]);
+        act.extend_from_slice(&2u32.to_le_bytes()); // layers
+        for (x, y, index, mirror, scale, angle) in
+            [(-1, -5, 0, 1, 2.0f32, 90), (0, 0, -1, 0, 1.0, 0)]
+        {
+            for value in [x, y, index, mirror] {
+                act.extend_from_slice(&i32::to_le_bytes(value));
+            }
+            act.extend_from_slice(&[255, 255, 255, 255]);
+            act.extend_from_slice(&scale.to_le_bytes());
+            act.extend_from_slice(&i32::to_le_bytes(angle));
+            act.extend_from_slice(&0i32.to_le_bytes()); // sprite type
+        }
+        act.extend_from_slice(&0i32.to_le_bytes()); // sound
+        act.extend_from_slice(&1u32.to_le_bytes()); // attach points
+        for value in [0, 3, -40, 0] {
+            act.extend_from_slice(&i32::to_le_bytes(value));
+        }
+        act.extend_from_slice(&1u32.to_le_bytes()); // sounds
+        let mut sound = [0u8; 40];
+        sound[..10].copy_from_slice(b"attack.wav");
+        act.extend_from_slice(&sound);
+        act.extend_from_slice(&4.0f32.to_le_bytes()); // 4 ticks of 25 ms
+
+        let grf = GrfFile::from_path(write_grf(
+            "convert",
+            &[
+                ("data\\sprite\\poring.spr", &spr),
+                ("data\\sprite\\poring.act", &act),
+            ],
+        ))
+        .unwrap();
+        let out = temp_dir("convert-out");
+        let report = convert_sprite(Some(&grf), "data/sprite/poring.act", &out).unwrap();
+        assert_eq!((report.frames, report.actions), (2, 1));
+
+        let image = image::open(out.join("poring_000.png")).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (2, 1));
+        assert_eq!(image.get_pixel(0, 0).0, [0, 0, 0, 0]);
+        assert_eq!(image.get_pixel(1, 0).0, [200, 10, 20, 255]);
+
+        let animation: serde_json::Value =
+            serde_json::from_slice(&fs::read(out.join("poring.json")).unwrap()).unwrap();
+        assert_eq!(
+            animation,
+            json!({
+                "frames": ["poring_000.png", null],
+                "actions": [{
+                    "delay_ms": 100.0,
+                    "frames": [{
+                        "layers": [
+                            {"frame": 0, "x": -1, "y": -5, "mirror": true, "scale": [2.0, 2.0],
+                             "angle": 90, "color": [1.0, 1.0, 1.0, 1.0]},
+                            {"frame": null, "x": 0, "y": 0, "mirror": false, "scale": [1.0, 1.0],
+                             "angle": 0, "color": [1.0, 1.0, 1.0, 1.0]},
+                        ],
+                        "sound": "attack.wav",
+                        "attach_points": [[3, -40]],
+                    }],
+                }],
+                "sounds": ["attack.wav"],
+            })
+        );
+    }
+
+    #[test]
+    fn merge_lets_later_archives_win_and_sorts_the_table() {
+        let zs = [b'z'; 4096];
+        let base = write_grf(
+            "merge-base",
+            &[("data\\z.txt", &zs), ("data\\a.txt", b"old")],
+        );
+        let patch = write_grf(
+            "merge-patch",
+            &[("DATA\\A.TXT", b"new"), ("data\\m.txt", b"m")],
+        );
+        let out = temp_dir("merge-out").join("merged.grf");
+
+        let report = merge_archives(&[base.clone(), patch], &out, true).unwrap();
+        assert_eq!((report.total_files, report.overridden), (3, 1));
+        assert!(report.unreadable.is_empty());
+
+        let merged = GrfFile::from_path(out.clone()).unwrap();
+        let names: Vec<&str> = merged.entries.iter().map(|e| e.filename.as_str()).collect();
+        assert_eq!(names, ["DATA\\A.TXT", "data\\m.txt", "data\\z.txt"]);
+        assert_eq!(merged.get_file("data\\a.txt").unwrap(), b"new");
+        assert_eq!(merged.get_file("data\\z.txt").unwrap(), zs);
+        // Recompressed where that shrinks the file, stored as-is otherwise.
+        let sizes: Vec<(u32, u32)> = merged
+            .entries
+            .iter()
+            .map(|e| (e.pack_size, e.real_size))
+            .collect();
+        assert_eq!(sizes[..2], [(3, 3), (1, 1)]);
+        assert!(sizes[2].0 < sizes[2].1);
+
+        assert!(merge_archives(std::slice::from_ref(&base), &base, true).is_err());
+    }
 }
@@ -38,6 +38,8 @@ struct CursorTextures {
     attack: Handle<Image>,
     impossible: Handle<Image>,
     talk: Handle<Image>,
+    warp: Handle<Image>,
+    rotate: Handle<Image>,
 }
 
 impl CursorTextures {
@@ -48,6 +50,8 @@ impl CursorTextures {
             CursorType::Attack => self.attack.clone(),
             CursorType::Impossible => self.impossible.clone(),
             CursorType::Talk => self.talk.clone(),
+            CursorType::Warp => self.warp.clone(),
+            CursorType::Rotate => self.rotate.clone(),
         }
     }
 }
@@ -74,16 +78,21 @@ fn load_cursor_textures(mut commands: Commands, asset_server: Res<AssetServer>)
         attack: load("cursor_attack.png"),
         impossible: load("cursor_impossible.png"),
         talk: load("cursor_talk.png"),
+        warp: load("cursor_warp.png"),
+        rotate: load("cursor_rotate.png"),
     });
 }
 
 /// Feeds the current cursor image into Feathers' `OverrideCursor` once its PNG has
 /// loaded. Gating on load avoids winit's per-frame "image not loaded yet" warning,
 /// and the `AppliedCursor` guard rebuilds the override only when the type changes.
+/// A cursor whose PNG failed to load (older asset extractions ship no warp or
+/// rotate image) shows the default arrow instead.
 fn apply_cursor(
     current: Res<CurrentCursorType>,
     textures: Res<CursorTextures>,
     images: Res<Assets<Image>>,
+    asset_server: Res<AssetServer>,
     mut applied: ResMut<AppliedCursor>,
     mut override_cursor: ResMut<OverrideCursor>,
 ) {
@@ -91,7 +100,10 @@ fn apply_cursor(
     if applied.0 == Some(desired) {
         return;
     }
-    let handle = textures.handle(desired);
+    let mut handle = textures.handle(desired);
+    if asset_server.load_state(&handle).is_failed() {
+        handle = textures.default.clone();
+    }
     if images.get(&handle).is_none() {
         return;
     }
@@ -120,6 +132,8 @@ mod tests {
             CursorType::Attack,
             CursorType::Impossible,
             CursorType::Talk,
+            CursorType::Warp,
+            CursorType::Rotate,
         ] {
             assert_eq!(hotspot(cursor), (1, 1));
         }
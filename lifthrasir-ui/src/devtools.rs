@@ -0,0 +1,134 @@
+//! UI state snapshot for live inspection.
+//!
+//! Cross-layer UI desyncs (a dialog the engine thinks is closed, a hover stuck on
+//! a despawned unit) are hard to reproduce from logs alone. [`ui_state_snapshot`]
+//! gathers what is on screen in one serializable value; the `dev` build of the
+//! client serves it over BRP as `lifthrasir/ui_state`.
+
+use bevy::prelude::*;
+use game_engine::core::state::GameState;
+use game_engine::domain::entities::hover::CurrentlyHoveredEntity;
+use game_engine::domain::input::UiFocus;
+use game_engine::domain::item_drop::hover::HoveredFloorItem;
+use serde::Serialize;
+
+use crate::widgets::character_window::CharacterWindowRoot;
+use crate::widgets::death_dialog::DeathDialogRoot;
+use crate::widgets::emote::EmotePickerRoot;
+use crate::widgets::escape_menu::EscapeMenuRoot;
+use crate::widgets::guild_window::GuildWindowRoot;
+use crate::widgets::info_modal::InfoModalRoot;
+use crate::widgets::npc_dialog::ActiveNpcDialog;
+use crate::widgets::party::PartyWindowRoot;
+use crate::widgets::party::create_dialog::CreatePartyDialogRoot;
+use crate::widgets::player_context_menu::PlayerContextMenuRoot;
+use crate::widgets::pushcart_window::CartWindowRoot;
+use crate::widgets::settings_window::SettingsWindowRoot;
+use crate::widgets::shop_window::ShopWindowRoot;
+use crate::widgets::storage_window::StorageWindowRoot;
+use crate::widgets::system_dialog::SystemDialogRoot;
+
+/// What the UI is showing right now.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UiStateSnapshot {
+    /// Current `GameState`, `None` before the state machine is installed.
+    pub game_state: Option<String>,
+    /// Toggleable windows that are currently visible.
+    pub windows: Vec<&'static str>,
+    /// Modal dialogs on screen.
+    pub dialogs: Vec<&'static str>,
+    /// Menus and pickers that close on the next click.
+    pub popups: Vec<&'static str>,
+    /// The NPC whose conversation is live.
+    pub npc_dialog: Option<u32>,
+    /// The hovered unit, as `Entity::to_bits`.
+    pub hovered_entity: Option<u64>,
+    /// The hovered floor item, as `Entity::to_bits`.
+    pub hovered_floor_item: Option<u64>,
+    pub text_input_active: bool,
+    pub cutscene_active: bool,
+}
+
+/// Gathers the [`UiStateSnapshot`] from `world`.
+pub fn ui_state_snapshot(world: &mut World) -> UiStateSnapshot {
+    let mut windows = Vec::new();
+    shown::<CharacterWindowRoot>(world, "character", &mut windows);
+    shown::<GuildWindowRoot>(world, "guild", &mut windows);
+    shown::<PartyWindowRoot>(world, "party", &mut windows);
+    shown::<CartWindowRoot>(world, "pushcart", &mut windows);
+    shown::<SettingsWindowRoot>(world, "settings", &mut windows);
+    shown::<ShopWindowRoot>(world, "shop", &mut windows);
+    shown::<StorageWindowRoot>(world, "storage", &mut windows);
+
+    let mut dialogs = Vec::new();
+    shown::<SystemDialogRoot>(world, "system", &mut dialogs);
+    shown::<InfoModalRoot>(world, "info", &mut dialogs);
+    shown::<DeathDialogRoot>(world, "death", &mut dialogs);
+    shown::<CreatePartyDialogRoot>(world, "create_party", &mut dialogs);
+    shown::<EscapeMenuRoot>(world, "escape_menu", &mut dialogs);
+
+    let mut popups = Vec::new();
+    shown::<PlayerContextMenuRoot>(world, "player_context_menu", &mut popups);
+    shown::<EmotePickerRoot>(world, "emote_picker", &mut popups);
+
+    let focus = world.get_resource::<UiFocus>();
+    UiStateSnapshot {
+        game_state: world
+            .get_resource::<State<GameState>>()
+            .map(|state| format!("{:?}", state.get())),
+        windows,
+        dialogs,
+        popups,
+        npc_dialog: world
+            .get_resource::<ActiveNpcDialog>()
+            .map(|dialog| dialog.npc_id),
+        hovered_entity: world
+            .get_resource::<CurrentlyHoveredEntity>()
+            .and_then(|hovered| hovered.entity)
+            .map(Entity::to_bits),
+        hovered_floor_item: world
+            .get_resource::<HoveredFloorItem>()
+            .and_then(|hovered| hovered.0)
+            .map(Entity::to_bits),
+        text_input_active: focus.is_some_and(|focus| focus.text_input_active),
+        cutscene_active: focus.is_some_and(|focus| focus.cutscene_active),
+    }
+}
+
+/// Pushes `name` if any `T` root is spawned and not hidden.
+fn shown<T: Component>(world: &mut World, name: &'static str, into: &mut Vec<&'static str>) {
+    let visible = world
+        .query_filtered::<Option<&Visibility>, With<T>>()
+        .iter(world)
+        .any(|visibility| visibility != Some(&Visibility::Hidden));
+    if visible {
+        into.push(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_lists_what_is_on_screen() {
+        let mut world = World::new();
+        world.spawn((SettingsWindowRoot, Visibility::Hidden));
+        world.spawn((StorageWindowRoot, Visibility::Inherited));
+        world.spawn(EscapeMenuRoot);
+        let unit = world.spawn_empty().id();
+        world.insert_resource(CurrentlyHoveredEntity { entity: Some(unit) });
+        world.insert_resource(UiFocus {
+            text_input_active: true,
+            ..default()
+        });
+
+        let snapshot = ui_state_snapshot(&mut world);
+        assert_eq!(snapshot.windows, vec!["storage"]);
+        assert_eq!(snapshot.dialogs, vec!["escape_menu"]);
+        assert!(snapshot.popups.is_empty());
+        assert_eq!(snapshot.hovered_entity, Some(unit.to_bits()));
+        assert!(snapshot.text_input_active);
+        assert_eq!(snapshot.game_state, None);
+    }
+}
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy::ui::IsDefaultUiCamera;
 
 pub mod cursor;
+pub mod devtools;
 pub mod focus;
 pub mod rich_text;
 pub mod screens;
@@ -35,6 +36,7 @@ impl Plugin for LifthrasirUiPlugin {
             widgets::escape_menu::EscapeMenuPlugin,
             widgets::info_modal::InfoModalPlugin,
             widgets::system_dialog::SystemDialogPlugin,
+            screens::ro_install::RoInstallSetupPlugin,
             worldspace::WorldspaceUiPlugin,
         ));
     }
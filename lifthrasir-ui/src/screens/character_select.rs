@@ -3,14 +3,24 @@
 //! A raw `bevy_ui` stage with a hero-panel and a character-grid container. The hero
 //! panel features the selected character with a live diorama crop, name, job/level,
 //! and action buttons. The roster grid shows compact slot cards; clicking a card
-//! updates the selected slot and the hero panel rebuilds.
+//! updates the selected slot and the hero panel rebuilds. Hovering a card draws the
+//! gold highlight frame and reports the slot as a `CharacterHoveredEvent`, which
+//! the engine answers with the classic hover sound. Sprites the roster preload
+//! could not load are named on their character's card.
+
+use std::collections::BTreeMap;
 
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
 use game_engine::domain::character::events::{
-    CharacterInfoWithJobName, CharacterListReceivedEvent, DeleteCharacterRequestEvent,
-    RequestCharacterListEvent, SelectCharacterEvent,
+    CharacterHoveredEvent, CharacterInfoWithJobName, CharacterListReceivedEvent,
+    DeleteCharacterRequestEvent, RequestCharacterListEvent, SelectCharacterEvent,
+};
+use game_engine::domain::character::preload::{
+    CharacterSelectionAssetsPreloaded, PreloadAssetKind, PreloadCharacterSelectionAssets,
 };
+use net_contract::dto::ServerInfo;
+use net_contract::state::UserSession;
 
 use crate::screens::character_create::CreationSlot;
 use crate::screens::character_preview::{COLUMN_PX, CharacterDiorama, ROW_PX};
@@ -24,7 +34,9 @@ impl Plugin for CharacterSelectScreenPlugin {
         app.init_resource::<CardsBuilt>();
         app.init_resource::<PendingDeletion>();
         app.init_resource::<SelectedSlot>();
+        app.init_resource::<HoveredSlot>();
         app.init_resource::<RosterPage>();
+        app.init_resource::<MissingAssets>();
         app.add_systems(
             OnEnter(GameState::CharacterSelection),
             show_character_select_screen,
@@ -33,6 +45,7 @@ impl Plugin for CharacterSelectScreenPlugin {
             Update,
             (
                 receive_character_list,
+                receive_preload_report,
                 build_cards,
                 rebuild_hero_panel,
                 update_delete_labels,
@@ -82,6 +95,14 @@ struct PendingDeletion(Option<u32>);
 #[derive(Resource, Default)]
 struct SelectedSlot(usize);
 
+/// Assets of each slot that the roster preload could not load, in load order.
+#[derive(Resource, Default)]
+struct MissingAssets(BTreeMap<u8, Vec<PreloadAssetKind>>);
+
+/// Roster slot under the pointer, if any; its card gets a hover border.
+#[derive(Resource, Default)]
+struct HoveredSlot(Option<usize>);
+
 /// Marks a runtime-spawned slot card so the grid can be cleared on rebuild.
 #[derive(Component)]
 struct CharacterCard;
@@ -108,6 +129,7 @@ struct HeroPanel;
 #[derive(Component)]
 struct CharacterGrid;
 
+#[allow(clippy::too_many_arguments)]
 fn show_character_select_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -115,6 +137,7 @@ fn show_character_select_screen(
     mut pending: ResMut<PendingDeletion>,
     mut selected: ResMut<SelectedSlot>,
     mut roster_page: ResMut<RosterPage>,
+    session: Option<Res<UserSession>>,
     mut requests: MessageWriter<RequestCharacterListEvent>,
 ) {
     built.0 = false;
@@ -151,7 +174,12 @@ fn show_character_select_screen(
         ))
         .id();
     commands.spawn((
-        label("Endurnir", font_body, 11.0, theme::GOLD.with_alpha(0.55)),
+        label(
+            server_kicker(session.and_then(|s| s.selected_server.clone()).as_ref()),
+            font_body,
+            11.0,
+            theme::GOLD.with_alpha(0.55),
+        ),
         ChildOf(head),
     ));
     commands.spawn((
@@ -210,23 +238,71 @@ fn show_character_select_screen(
     requests.write(RequestCharacterListEvent);
 }
 
-/// Stores the latest character list and arms a card rebuild.
+/// The line above the title: the char server the roster lives on and its
+/// population when it was picked.
+fn server_kicker(server: Option<&ServerInfo>) -> String {
+    match server {
+        Some(server) => format!("{} · {} online", server.name, server.users),
+        None => "Endurnir".to_string(),
+    }
+}
+
+/// Stores the latest character list, arms a card rebuild and has the engine
+/// warm the roster's sprites in one batch.
 fn receive_character_list(
     mut events: MessageReader<CharacterListReceivedEvent>,
+    mut preloads: MessageWriter<PreloadCharacterSelectionAssets>,
     mut data: ResMut<CharacterSelectionData>,
     mut built: ResMut<CardsBuilt>,
     mut pending: ResMut<PendingDeletion>,
     mut roster_page: ResMut<RosterPage>,
+    mut missing: ResMut<MissingAssets>,
 ) {
     let Some(event) = events.read().last() else {
         return;
     };
     data.characters = event.characters.clone();
+    missing.0.clear();
     data.max_slots = event.max_slots;
     data.display_pages = event.display_pages.max(1);
     built.0 = false;
     pending.0 = None;
     roster_page.0 = 0;
+    preloads.write(PreloadCharacterSelectionAssets);
+}
+
+/// Records which sprites the finished preload could not load and rebuilds the
+/// cards to show them.
+fn receive_preload_report(
+    mut reports: MessageReader<CharacterSelectionAssetsPreloaded>,
+    mut missing: ResMut<MissingAssets>,
+    mut built: ResMut<CardsBuilt>,
+) {
+    let Some(report) = reports.read().last() else {
+        return;
+    };
+    missing.0.clear();
+    for item in report.failures() {
+        missing.0.entry(item.slot).or_default().push(item.kind);
+    }
+    if !missing.0.is_empty() {
+        built.0 = false;
+    }
+}
+
+/// Card line naming the assets a character is missing.
+fn missing_assets_line(kinds: &[PreloadAssetKind]) -> String {
+    let names: Vec<&str> = kinds
+        .iter()
+        .map(|kind| match kind {
+            PreloadAssetKind::BodySprite => "body sprite",
+            PreloadAssetKind::BodyAction => "body actions",
+            PreloadAssetKind::HairSprite => "hair sprite",
+            PreloadAssetKind::HairAction => "hair actions",
+            PreloadAssetKind::HairPalette => "hair palette",
+        })
+        .collect();
+    format!("Missing {}", names.join(", "))
 }
 
 /// Builds (or rebuilds) the compact slot cards under the grid container.
@@ -238,6 +314,7 @@ fn build_cards(
     data: Res<CharacterSelectionData>,
     diorama: Res<CharacterDiorama>,
     page: Res<RosterPage>,
+    missing: Res<MissingAssets>,
     mut built: ResMut<CardsBuilt>,
     container: Query<Entity, With<CharacterGrid>>,
     existing_cards: Query<Entity, With<CharacterCard>>,
@@ -287,6 +364,7 @@ fn build_cards(
                 container,
                 slot,
                 info,
+                missing.0.get(&slot).map_or(&[], Vec::as_slice),
                 font_bold.clone(),
                 font_body.clone(),
             ),
@@ -431,6 +509,7 @@ fn spawn_occupied_card(
     container: Entity,
     slot: u8,
     info: &CharacterInfoWithJobName,
+    missing: &[PreloadAssetKind],
     font_bold: Handle<Font>,
     font_body: Handle<Font>,
 ) {
@@ -482,6 +561,24 @@ fn spawn_occupied_card(
         ChildOf(badge),
     ));
 
+    if !missing.is_empty() {
+        commands.spawn((
+            label(
+                missing_assets_line(missing),
+                font_body.clone(),
+                10.0,
+                theme::WARN,
+            ),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(5.0),
+                right: Val::Px(9.0),
+                ..default()
+            },
+            ChildOf(card),
+        ));
+    }
+
     // Glyph lives as a child so the avatar's flex centering actually centers it
     // (a node's own Text isn't affected by align/justify).
     let avatar = commands
@@ -524,12 +621,7 @@ fn spawn_occupied_card(
         ChildOf(col),
     ));
 
-    let selected_slot = slot as usize;
-    commands.entity(card).observe(
-        move |_: On<Pointer<Click>>, mut sel: ResMut<SelectedSlot>| {
-            sel.0 = selected_slot;
-        },
-    );
+    observe_card_pointer(commands, card, slot);
 }
 
 fn spawn_empty_card(
@@ -587,31 +679,65 @@ fn spawn_empty_card(
         ChildOf(card),
     ));
 
-    let selected_slot = slot as usize;
-    commands.entity(card).observe(
-        move |_: On<Pointer<Click>>, mut sel: ResMut<SelectedSlot>| {
-            sel.0 = selected_slot;
-        },
-    );
+    observe_card_pointer(commands, card, slot);
 }
 
-/// Highlights the selected slot card with an emerald border (mirrors the mockup's
-/// selected state). Runs after a rebuild and whenever the selection changes.
+/// Wires a slot card's pointer: click selects it, entering/leaving tracks the
+/// hovered slot and reports it as a `CharacterHoveredEvent`.
+fn observe_card_pointer(commands: &mut Commands, card: Entity, slot: u8) {
+    commands
+        .entity(card)
+        .observe(
+            move |_: On<Pointer<Click>>, mut sel: ResMut<SelectedSlot>| {
+                sel.0 = slot as usize;
+            },
+        )
+        .observe(
+            move |_: On<Pointer<Over>>,
+                  mut hovered: ResMut<HoveredSlot>,
+                  mut writer: MessageWriter<CharacterHoveredEvent>| {
+                hovered.0 = Some(slot as usize);
+                writer.write(CharacterHoveredEvent { slot: Some(slot) });
+            },
+        )
+        .observe(
+            move |_: On<Pointer<Out>>,
+                  mut hovered: ResMut<HoveredSlot>,
+                  mut writer: MessageWriter<CharacterHoveredEvent>| {
+                if hovered.0 != Some(slot as usize) {
+                    return;
+                }
+                hovered.0 = None;
+                writer.write(CharacterHoveredEvent { slot: None });
+            },
+        );
+}
+
+/// Frame colour for a slot card: emerald when selected (mirrors the mockup's
+/// selected state), the gold highlight frame while hovered, plain stroke otherwise.
+fn card_border_color(slot: usize, selected: usize, hovered: Option<usize>) -> Color {
+    if slot == selected {
+        theme::EMERALD
+    } else if hovered == Some(slot) {
+        theme::GOLD
+    } else {
+        theme::STROKE
+    }
+}
+
+/// Repaints slot card frames after a rebuild and whenever the selection or the
+/// hovered slot changes.
 fn highlight_selected_cards(
     selected: Res<SelectedSlot>,
+    hovered: Res<HoveredSlot>,
     built: Res<CardsBuilt>,
     mut cards: Query<(&CardSlot, &mut BorderColor)>,
 ) {
-    if !selected.is_changed() && !built.is_changed() {
+    if !selected.is_changed() && !hovered.is_changed() && !built.is_changed() {
         return;
     }
     for (slot, mut border) in &mut cards {
-        let color = if slot.0 as usize == selected.0 {
-            theme::EMERALD
-        } else {
-            theme::STROKE
-        };
-        *border = BorderColor::all(color);
+        *border = BorderColor::all(card_border_color(slot.0 as usize, selected.0, hovered.0));
     }
 }
 
@@ -912,6 +1038,14 @@ mod tests {
     use super::*;
     use net_contract::dto::CharacterInfo as ProtocolCharacterInfo;
 
+    #[test]
+    fn card_border_prefers_selection_over_hover() {
+        assert_eq!(card_border_color(1, 1, Some(1)), theme::EMERALD);
+        assert_eq!(card_border_color(2, 1, Some(2)), theme::GOLD);
+        assert_eq!(card_border_color(3, 1, Some(2)), theme::STROKE);
+        assert_eq!(card_border_color(3, 1, None), theme::STROKE);
+    }
+
     #[test]
     fn featured_returns_occupied_slot() {
         let chars = vec![Some(with_job("Hero", 1, 0, 50, "Swordman")), None];
@@ -931,6 +1065,20 @@ mod tests {
         assert!(featured(&chars, 9).is_none());
     }
 
+    #[test]
+    fn kicker_names_the_selected_server_and_its_population() {
+        let server = ServerInfo {
+            ip: 0,
+            port: 6121,
+            name: "Asgard".into(),
+            users: 142,
+            server_type: net_contract::dto::ServerType::Normal,
+            new_server: 0,
+        };
+        assert_eq!(server_kicker(Some(&server)), "Asgard · 142 online");
+        assert_eq!(server_kicker(None), "Endurnir");
+    }
+
     fn protocol_char(name: &str, char_id: u32, slot: u8, base_level: u16) -> ProtocolCharacterInfo {
         ProtocolCharacterInfo {
             char_id,
@@ -1009,6 +1157,7 @@ mod tests {
         app.init_resource::<PendingDeletion>();
         app.init_resource::<SelectedSlot>();
         app.init_resource::<RosterPage>();
+        app.init_resource::<MissingAssets>();
         app.insert_resource(data);
         app.insert_resource(diorama);
         app.world_mut().spawn(CharacterGrid);
@@ -1075,6 +1224,46 @@ mod tests {
         assert!(app.world().resource::<CardsBuilt>().0);
     }
 
+    #[test]
+    fn failed_preload_items_are_named_on_their_card() {
+        use game_engine::domain::character::preload::{PreloadItem, PreloadOutcome};
+
+        let data = CharacterSelectionData {
+            characters: vec![Some(with_job("Hero", 1, 0, 50, "Swordman")), None],
+            max_slots: 2,
+            display_pages: 1,
+        };
+        let mut app = card_app(data, occupied_diorama());
+        app.add_message::<CharacterSelectionAssetsPreloaded>();
+        app.add_systems(Update, receive_preload_report.before(build_cards));
+        app.update();
+        assert!(!all_texts(&mut app).iter().any(|t| t.starts_with("Missing")));
+
+        let item = |kind, outcome| PreloadItem {
+            slot: 0,
+            kind,
+            path: "hair.pal".to_string(),
+            outcome,
+        };
+        app.world_mut()
+            .write_message(CharacterSelectionAssetsPreloaded {
+                items: vec![
+                    item(PreloadAssetKind::BodySprite, PreloadOutcome::Loaded),
+                    item(
+                        PreloadAssetKind::HairPalette,
+                        PreloadOutcome::Failed("not found".to_string()),
+                    ),
+                ],
+            });
+        app.update();
+
+        assert!(
+            all_texts(&mut app)
+                .iter()
+                .any(|t| t == "Missing hair palette")
+        );
+    }
+
     #[test]
     fn is_idempotent_across_frames() {
         let data = CharacterSelectionData {
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
+use game_engine::domain::camera::CinematicFade;
 
 use crate::theme;
 
@@ -9,8 +10,12 @@ pub struct FadeTransitionPlugin;
 
 impl Plugin for FadeTransitionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_fade_overlay);
+        app.add_systems(Startup, (spawn_fade_overlay, spawn_cinematic_overlay));
         app.add_systems(Update, (start_fade_on_transition, tick_fade).chain());
+        app.add_systems(
+            Update,
+            sync_cinematic_overlay.run_if(resource_exists_and_changed::<CinematicFade>),
+        );
     }
 }
 
@@ -38,6 +43,33 @@ fn spawn_fade_overlay(mut commands: Commands) {
     ));
 }
 
+/// Full-screen black overlay driven by camera sequences' fade steps. Sits just
+/// below the transition fade so a state change still reveals cleanly.
+#[derive(Component)]
+struct CinematicOverlay;
+
+fn spawn_cinematic_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(i32::MAX - 2),
+        Pickable::IGNORE,
+        CinematicOverlay,
+    ));
+}
+
+fn sync_cinematic_overlay(
+    fade: Res<CinematicFade>,
+    mut overlay: Single<&mut BackgroundColor, With<CinematicOverlay>>,
+) {
+    overlay.0.set_alpha(fade.alpha);
+}
+
 fn start_fade_on_transition(
     mut transitions: MessageReader<StateTransitionEvent<GameState>>,
     mut fade: Single<(&mut ScreenFade, &mut BackgroundColor)>,
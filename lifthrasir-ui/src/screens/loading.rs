@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
+use game_engine::infrastructure::assets::{PreloadFinished, PreloadProgress};
 use iyes_progress::prelude::ProgressTracker;
 
 use crate::theme;
@@ -11,7 +12,7 @@ impl Plugin for LoadingScreenPlugin {
         app.add_systems(OnEnter(GameState::Loading), show_loading_screen)
             .add_systems(
                 Update,
-                update_loading_bar.run_if(in_state(GameState::Loading)),
+                (update_loading_bar, update_loading_caption).run_if(in_state(GameState::Loading)),
             );
     }
 }
@@ -19,6 +20,10 @@ impl Plugin for LoadingScreenPlugin {
 #[derive(Component)]
 struct LoadingBarFill;
 
+/// Names how far the map's asset preload has got, and what failed.
+#[derive(Component)]
+struct LoadingCaption;
+
 fn show_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn((
         Node {
@@ -59,6 +64,16 @@ fn show_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
                     LoadingBarFill,
                 )],
             ),
+            (
+                Text::new(""),
+                TextFont {
+                    font: asset_server.load(theme::FONT_BODY).into(),
+                    font_size: 14.0.into(),
+                    ..default()
+                },
+                TextColor(theme::TEXT_DIM),
+                LoadingCaption,
+            ),
         ],
     ));
 }
@@ -77,3 +92,39 @@ fn update_loading_bar(
         node.width = Val::Percent(percent);
     }
 }
+
+fn update_loading_caption(
+    mut progress: MessageReader<PreloadProgress>,
+    mut finished: MessageReader<PreloadFinished>,
+    mut captions: Query<&mut Text, With<LoadingCaption>>,
+) {
+    let mut caption = progress
+        .read()
+        .filter(|p| p.state == GameState::Loading)
+        .last()
+        .map(|p| format!("Loading assets {}/{}", p.done, p.total));
+    if let Some(done) = finished
+        .read()
+        .filter(|f| f.state == GameState::Loading)
+        .last()
+    {
+        caption = Some(match done.failed.len() {
+            0 => String::new(),
+            1 => format!(
+                "Missing asset: {} ({})",
+                done.failed[0].entry.path, done.failed[0].reason
+            ),
+            n => format!(
+                "{n} assets could not be loaded, first {} ({})",
+                done.failed[0].entry.path, done.failed[0].reason
+            ),
+        });
+    }
+
+    let Some(caption) = caption else {
+        return;
+    };
+    for mut text in &mut captions {
+        text.0.clone_from(&caption);
+    }
+}
@@ -11,6 +11,8 @@ use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
 use game_engine::domain::authentication::events::LoginFailureEvent;
+use game_engine::domain::authentication::retry::{LOGIN_RETRY_LIMIT, LoginRetry};
+use game_engine::infrastructure::assets::{PreloadAppExt, PreloadKind};
 use game_engine::presentation::ui::events::LoginAttemptEvent;
 use net_contract::dto::NetworkError;
 use secrecy::SecretString;
@@ -49,6 +51,9 @@ struct LoginError;
 
 impl Plugin for LoginScreenPlugin {
     fn build(&self, app: &mut App) {
+        app.preload(GameState::Login, PreloadKind::Texture, LOGO_IMAGE)
+            .preload(GameState::Login, PreloadKind::Font, theme::FONT_BODY)
+            .preload(GameState::Login, PreloadKind::Font, theme::FONT_TITLE);
         app.add_systems(OnEnter(GameState::Login), show_login_screen);
         app.add_systems(
             Update,
@@ -56,6 +61,7 @@ impl Plugin for LoginScreenPlugin {
                 handle_login_input,
                 render_login_fields,
                 surface_login_failure,
+                show_login_retry.after(surface_login_failure),
             )
                 .run_if(in_state(GameState::Login)),
         );
@@ -349,6 +355,11 @@ fn login_error_text(error: &NetworkError) -> String {
     error.to_string()
 }
 
+/// The error line while a refused login waits to be retried.
+fn login_retry_text(reason: &str, seconds_left: u64, attempt: u32) -> String {
+    format!("{reason} Retrying in {seconds_left}s ({attempt}/{LOGIN_RETRY_LIMIT})")
+}
+
 fn submit_button(
     _click: On<Pointer<Click>>,
     fields: Query<(&TextField, &LoginField)>,
@@ -466,6 +477,30 @@ fn surface_login_failure(
     }
 }
 
+/// Counts down a pending login retry in the error line, and clears the line
+/// once the retry has gone out.
+fn show_login_retry(
+    retry: Option<Res<LoginRetry>>,
+    mut showing: Local<bool>,
+    mut errors: Query<&mut Text, With<LoginError>>,
+) {
+    let text = match &retry {
+        Some(retry) => login_retry_text(
+            retry.reason().description(),
+            retry.remaining().as_secs_f32().ceil() as u64,
+            retry.attempt(),
+        ),
+        None if *showing => String::new(),
+        None => return,
+    };
+    *showing = retry.is_some();
+    for mut error in &mut errors {
+        if error.0 != text {
+            error.0.clone_from(&text);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,4 +564,12 @@ mod tests {
             "Server refused login with code: 1"
         );
     }
+
+    #[test]
+    fn retry_text_counts_down_the_attempts() {
+        assert_eq!(
+            login_retry_text("The server is full.", 10, 2),
+            "The server is full. Retrying in 10s (2/5)"
+        );
+    }
 }
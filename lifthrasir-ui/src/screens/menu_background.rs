@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
+use game_engine::infrastructure::assets::{PreloadAppExt, PreloadKind};
 
 /// Loaded through the `ro://` composite source. Paths are joined onto the data
 /// folder root (`assets/data`), so the bare filename maps to `assets/data/main_bg.png`.
@@ -9,6 +10,7 @@ pub struct MenuBackgroundPlugin;
 
 impl Plugin for MenuBackgroundPlugin {
     fn build(&self, app: &mut App) {
+        app.preload(GameState::Login, PreloadKind::Texture, BACKGROUND_IMAGE);
         app.add_systems(Startup, spawn_menu_background);
         app.add_systems(Update, toggle_on_transition);
     }
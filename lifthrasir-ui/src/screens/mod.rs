@@ -5,4 +5,5 @@ pub mod fade;
 pub mod loading;
 pub mod login;
 pub mod menu_background;
+pub mod ro_install;
 pub mod server_select;
@@ -0,0 +1,163 @@
+//! Game-data setup from the login screen.
+//!
+//! When asset sources fail to load, the engine's "Game data" dialog offers to
+//! choose an RO install instead. Its primary button opens a native folder
+//! picker; the chosen folder goes through [`CheckRoInstall`] first, and only a
+//! usable one is adopted with [`UseRoInstall`]. Every outcome is reported back
+//! through the system dialog, which offers the picker again on failure.
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task, poll_once};
+use game_engine::infrastructure::assets::ro_install::{
+    CheckRoInstall, RoInstallApplied, RoInstallCheck, RoInstallChecked, UseRoInstall,
+};
+use game_engine::presentation::ui::events::{
+    DialogSeverity, ShowSystemDialog, SystemDialogChoice, SystemDialogKind,
+};
+
+pub struct RoInstallSetupPlugin;
+
+impl Plugin for RoInstallSetupPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoInstallPicker>().add_systems(
+            Update,
+            (
+                open_folder_picker,
+                poll_folder_picker,
+                adopt_checked_install,
+                report_applied_install,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The native folder picker while it is open.
+#[derive(Resource, Default)]
+struct RoInstallPicker {
+    task: Option<Task<Option<PathBuf>>>,
+}
+
+fn open_folder_picker(
+    mut choices: MessageReader<SystemDialogChoice>,
+    mut picker: ResMut<RoInstallPicker>,
+) {
+    let chosen = choices
+        .read()
+        .any(|choice| choice.kind == SystemDialogKind::GameDataSetup && choice.primary);
+    if !chosen || picker.task.is_some() {
+        return;
+    }
+    picker.task = Some(IoTaskPool::get().spawn(async {
+        let folder = rfd::AsyncFileDialog::new()
+            .set_title("Choose your Ragnarok Online folder")
+            .pick_folder()
+            .await?;
+        Some(folder.path().to_path_buf())
+    }));
+}
+
+fn poll_folder_picker(
+    mut picker: ResMut<RoInstallPicker>,
+    mut checks: MessageWriter<CheckRoInstall>,
+) {
+    let Some(task) = picker.task.as_mut() else {
+        return;
+    };
+    let Some(folder) = bevy::tasks::block_on(poll_once(task)) else {
+        return;
+    };
+    picker.task = None;
+    if let Some(root) = folder {
+        checks.write(CheckRoInstall { root });
+    }
+}
+
+fn adopt_checked_install(
+    mut checked: MessageReader<RoInstallChecked>,
+    mut adopt: MessageWriter<UseRoInstall>,
+    mut dialogs: MessageWriter<ShowSystemDialog>,
+) {
+    for RoInstallChecked(check) in checked.read() {
+        if check.is_usable() {
+            adopt.write(UseRoInstall {
+                root: check.root.clone(),
+            });
+        } else {
+            dialogs.write(unusable_install_dialog(check));
+        }
+    }
+}
+
+fn report_applied_install(
+    mut applied: MessageReader<RoInstallApplied>,
+    mut dialogs: MessageWriter<ShowSystemDialog>,
+) {
+    for RoInstallApplied { result } in applied.read() {
+        dialogs.write(match result {
+            Ok(check) => ShowSystemDialog {
+                severity: DialogSeverity::Ok,
+                kind: SystemDialogKind::Generic,
+                kicker: "Game data".into(),
+                title: "Using your RO install".into(),
+                message: format!(
+                    "Game data now loads from {} ({} GRF file(s)).",
+                    check.root.display(),
+                    check.grfs.len()
+                ),
+                code: String::new(),
+                button_label: "OK".into(),
+                secondary_label: String::new(),
+                confirm_state: None,
+                correlation: None,
+            },
+            Err(reason) => setup_failed_dialog("The RO install could not be used", reason),
+        });
+    }
+}
+
+fn unusable_install_dialog(check: &RoInstallCheck) -> ShowSystemDialog {
+    setup_failed_dialog(
+        "That folder is not an RO install",
+        &check.problems.join("\n"),
+    )
+}
+
+/// A failure that offers the folder picker again.
+fn setup_failed_dialog(title: &str, message: &str) -> ShowSystemDialog {
+    ShowSystemDialog {
+        severity: DialogSeverity::Error,
+        kind: SystemDialogKind::GameDataSetup,
+        kicker: "Game data".into(),
+        title: title.into(),
+        message: message.into(),
+        code: String::new(),
+        button_label: "Choose RO folder".into(),
+        secondary_label: "Dismiss".into(),
+        confirm_state: None,
+        correlation: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unusable_folder_lists_its_problems_and_offers_the_picker_again() {
+        let check = RoInstallCheck {
+            root: PathBuf::from("/games/ro"),
+            grfs: Vec::new(),
+            bgm: None,
+            problems: vec!["data.grf missing at /games/ro/data.grf".into()],
+        };
+
+        let dialog = unusable_install_dialog(&check);
+
+        assert_eq!(dialog.kind, SystemDialogKind::GameDataSetup);
+        assert_eq!(dialog.message, "data.grf missing at /games/ro/data.grf");
+        assert_eq!(dialog.button_label, "Choose RO folder");
+    }
+}
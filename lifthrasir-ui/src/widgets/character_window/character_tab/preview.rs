@@ -44,7 +44,7 @@ use game_engine::domain::entities::character::components::{
     CharacterAppearance, CharacterData, EquipmentItem, EquipmentSet, EquipmentSlot,
 };
 use game_engine::domain::entities::markers::LocalPlayer;
-use game_engine::domain::entities::sprite_rendering::EquipmentChangeEvent;
+use game_engine::domain::entities::sprite_rendering::{EquipmentChangeEvent, ShadowRenderLayer};
 
 use crate::screens::character_preview::create_render_target;
 use crate::theme;
@@ -333,12 +333,14 @@ pub fn forward_preview_headgear(
 }
 
 /// Put freshly spawned preview billboards (body / head / headgear children) onto the
-/// preview render layer and tag them so the preview camera faces them. Engine sprite
-/// children spawn over several frames, so this runs every frame.
+/// preview render layer and tag them so the preview camera faces them. The flat shadow
+/// blob only moves layers. Engine sprite children spawn over several frames, so this
+/// runs every frame.
 pub fn tag_preview_billboards(
     mut commands: Commands,
     preview: Query<&Children, With<ConsolePreviewCharacter>>,
     billboards: Query<(), (With<Billboard>, Without<PreviewBillboard>)>,
+    shadows: Query<(), (With<ShadowRenderLayer>, Without<RenderLayers>)>,
 ) {
     for children in preview.iter() {
         for child in children.iter() {
@@ -346,6 +348,10 @@ pub fn tag_preview_billboards(
                 commands
                     .entity(child)
                     .insert((RenderLayers::layer(PREVIEW_LAYER), PreviewBillboard));
+            } else if shadows.contains(child) {
+                commands
+                    .entity(child)
+                    .insert(RenderLayers::layer(PREVIEW_LAYER));
             }
         }
     }
@@ -13,11 +13,13 @@ use bevy::text::EditableText;
 use game_engine::core::state::GameState;
 use game_engine::domain::character::chat::ChatSendRequested;
 use game_engine::domain::emote::EmoteRequested;
+use net_contract::commands::SimulateNetwork;
 use net_contract::events::ChatHeard;
 
 use crate::rich_text::spawn_colored_text;
 use crate::theme;
 use crate::widgets::emote::slash::parse_emote_slash;
+use crate::widgets::netsim_slash::parse_netsim_slash;
 use crate::widgets::party::slash::{PartySlashSubmitted, parse_party_slash};
 use crate::widgets::placeholder::Placeholder;
 
@@ -416,7 +418,8 @@ fn append_incoming_chat(
 /// - Focused + Escape releases it without sending.
 /// - Focused + Enter submits: a non-empty message is sent and the field cleared and
 ///   unfocused; an empty submit (e.g. the Enter that opened the chat) leaves it focused.
-///   A recognized emote slash (`parse_emote_slash`) is tried first and writes
+///   In debug builds `/netsim` (`parse_netsim_slash`) is tried first and writes
+///   `SimulateNetwork`. A recognized emote slash (`parse_emote_slash`) writes
 ///   `EmoteRequested`; otherwise a recognized party slash command
 ///   (`parse_party_slash`) is queued as `PartySlashSubmitted`; otherwise it is sent as
 ///   a normal chat message.
@@ -427,6 +430,7 @@ fn chat_input_control(
     mut writer: MessageWriter<ChatSendRequested>,
     mut slash_writer: MessageWriter<PartySlashSubmitted>,
     mut emote_writer: MessageWriter<EmoteRequested>,
+    mut netsim_writer: MessageWriter<SimulateNetwork>,
     mut input_focus: ResMut<InputFocus>,
 ) {
     let Ok((entity, mut field)) = chat_input.single_mut() else {
@@ -450,7 +454,17 @@ fn chat_input_control(
         let value = field.value().to_string();
         let message = value.trim();
         if !message.is_empty() {
-            if let Some(emote_type) = parse_emote_slash(message) {
+            let netsim = cfg!(debug_assertions)
+                .then(|| parse_netsim_slash(message))
+                .flatten();
+            if let Some(netsim) = netsim {
+                match netsim {
+                    Ok(conditions) => {
+                        netsim_writer.write(SimulateNetwork(conditions));
+                    }
+                    Err(e) => warn!("/netsim: {e}"),
+                }
+            } else if let Some(emote_type) = parse_emote_slash(message) {
                 emote_writer.write(EmoteRequested { emote_type });
             } else if let Some(slash) = parse_party_slash(message) {
                 slash_writer.write(PartySlashSubmitted(slash));
@@ -512,6 +526,7 @@ mod tests {
         app.add_message::<ChatSendRequested>();
         app.add_message::<PartySlashSubmitted>();
         app.add_message::<EmoteRequested>();
+        app.add_message::<SimulateNetwork>();
         app.add_systems(Update, chat_input_control);
         let chat = app
             .world_mut()
@@ -630,6 +645,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enter_with_netsim_slash_writes_simulate_network() {
+        let (mut app, chat) = chat_control_app("/netsim off");
+        app.world_mut()
+            .resource_mut::<InputFocus>()
+            .set(chat, FocusCause::Navigated);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        app.update();
+
+        let messages = app.world().resource::<Messages<SimulateNetwork>>();
+        let requested: Vec<_> = messages.get_cursor().read(messages).cloned().collect();
+        assert_eq!(requested.len(), 1, "one simulation toggle queued");
+        assert!(requested[0].0.is_none());
+        assert!(chat_messages(&app).is_empty());
+    }
+
     #[test]
     fn append_colored_line_caps_oldest_children() {
         let mut app = App::new();
@@ -0,0 +1,123 @@
+//! Zone-connection resume progress as colored chat lines, mirroring
+//! `party/feedback.rs`.
+//!
+//! The adapter owns the resume itself; this module only tells the player what is
+//! happening: a drop, each backoff attempt, and the recovery. A resume that gives
+//! up is reported by the system dialog raised in `game-engine`, since the HUD is
+//! torn down on the way back to character selection.
+
+use bevy::prelude::*;
+use game_engine::core::state::GameState;
+use net_contract::events::{ZoneConnectionLost, ZoneReconnected, ZoneReconnecting};
+
+use crate::theme;
+use crate::widgets::chat_box::{ChatHistory, append_colored_line};
+
+pub struct ConnectionStatusPlugin;
+
+impl Plugin for ConnectionStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            ingest_connection_status.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn lost_text(reason: &str) -> String {
+    format!("Connection to the map server lost ({reason}).")
+}
+
+fn reconnecting_text(event: &ZoneReconnecting) -> String {
+    format!(
+        "Reconnecting... (attempt {}/{})",
+        event.attempt, event.max_attempts
+    )
+}
+
+const RECONNECTED_TEXT: &str = "Connection restored.";
+
+/// Echoes each resume event as one chat line: red for the drop, amber for an
+/// attempt, green for the recovery.
+fn ingest_connection_status(
+    mut lost: MessageReader<ZoneConnectionLost>,
+    mut reconnecting: MessageReader<ZoneReconnecting>,
+    mut reconnected: MessageReader<ZoneReconnected>,
+    container: Query<Entity, With<ChatHistory>>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    if lost.is_empty() && reconnecting.is_empty() && reconnected.is_empty() {
+        return;
+    }
+    let Ok(container) = container.single() else {
+        return;
+    };
+    let font = asset_server.load(theme::FONT_BODY);
+
+    let lines = lost
+        .read()
+        .map(|event| (lost_text(&event.reason), theme::BAD))
+        .chain(
+            reconnecting
+                .read()
+                .map(|event| (reconnecting_text(event), theme::WARN)),
+        )
+        .chain(
+            reconnected
+                .read()
+                .map(|_| (RECONNECTED_TEXT.to_string(), theme::EMERALD)),
+        );
+    for (text, color) in lines {
+        append_colored_line(&mut commands, container, &text, color, font.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnecting_text_shows_attempt_progress() {
+        let text = reconnecting_text(&ZoneReconnecting {
+            attempt: 2,
+            max_attempts: 5,
+        });
+        assert_eq!(text, "Reconnecting... (attempt 2/5)");
+        assert!(lost_text("connection lost").contains("connection lost"));
+    }
+
+    #[test]
+    fn ingest_appends_a_line_per_event() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Font>();
+        app.add_message::<ZoneConnectionLost>();
+        app.add_message::<ZoneReconnecting>();
+        app.add_message::<ZoneReconnected>();
+        app.world_mut().spawn(ChatHistory);
+        app.add_systems(Update, ingest_connection_status);
+
+        app.world_mut().write_message(ZoneConnectionLost {
+            reason: "connection lost".into(),
+        });
+        app.world_mut().write_message(ZoneReconnecting {
+            attempt: 1,
+            max_attempts: 5,
+        });
+        app.world_mut().write_message(ZoneReconnected);
+        app.update();
+
+        let container = app
+            .world_mut()
+            .query_filtered::<Entity, With<ChatHistory>>()
+            .single(app.world())
+            .unwrap();
+        let lines = app
+            .world()
+            .get::<Children>(container)
+            .map(|c| c.len())
+            .unwrap_or(0);
+        assert_eq!(lines, 3);
+    }
+}
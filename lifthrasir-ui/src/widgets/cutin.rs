@@ -0,0 +1,159 @@
+//! NPC cutin illustrations (script `cutin`): one full-size illustration at a time,
+//! anchored to the screen edge the script picked and faded in, drawn above the HUD
+//! but below the NPC dialogue window so the conversation stays readable.
+//!
+//! A new [`NpcCutinReceived`] replaces the current illustration; `Clear` removes it
+//! at once, as the classic client does.
+
+use bevy::prelude::*;
+use game_engine::core::state::GameState;
+use game_engine::domain::assets::patterns::cutin_illust_path;
+use net_contract::dto::CutinPosition;
+use net_contract::events::NpcCutinReceived;
+
+/// Below the NPC dialogue window (900), above announcement overlays (500).
+const CUTIN_Z: i32 = 800;
+const FADE_IN_S: f32 = 0.25;
+
+pub struct CutinPlugin;
+
+impl Plugin for CutinPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (show_cutin, fade_in_cutin)
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+/// The active illustration, fading in over `fade`.
+#[derive(Component)]
+struct CutinIllustration {
+    fade: Timer,
+}
+
+/// Full-screen wrapper alignment that pins the illustration to `position`.
+fn anchor(position: CutinPosition) -> (JustifyContent, AlignItems) {
+    match position {
+        CutinPosition::BottomLeft => (JustifyContent::FlexStart, AlignItems::FlexEnd),
+        CutinPosition::BottomMiddle => (JustifyContent::Center, AlignItems::FlexEnd),
+        CutinPosition::BottomRight => (JustifyContent::FlexEnd, AlignItems::FlexEnd),
+        CutinPosition::Center | CutinPosition::Clear => {
+            (JustifyContent::Center, AlignItems::Center)
+        }
+    }
+}
+
+fn show_cutin(
+    mut events: MessageReader<NpcCutinReceived>,
+    active: Query<Entity, With<CutinIllustration>>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    for wrapper in &active {
+        commands.entity(wrapper).despawn();
+    }
+    if event.position == CutinPosition::Clear || event.image.is_empty() {
+        return;
+    }
+
+    let (justify_content, align_items) = anchor(event.position);
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content,
+            align_items,
+            ..default()
+        },
+        GlobalZIndex(CUTIN_Z),
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::InGame),
+        CutinIllustration {
+            fade: Timer::from_seconds(FADE_IN_S, TimerMode::Once),
+        },
+        children![(
+            ImageNode::new(asset_server.load(cutin_illust_path(&event.image)))
+                .with_color(Color::WHITE.with_alpha(0.0)),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+fn fade_in_cutin(
+    time: Res<Time>,
+    mut wrappers: Query<(&mut CutinIllustration, &Children)>,
+    mut images: Query<&mut ImageNode>,
+) {
+    for (mut cutin, children) in &mut wrappers {
+        if cutin.fade.is_finished() {
+            continue;
+        }
+        let alpha = cutin.fade.tick(time.delta()).fraction();
+        for child in children.iter() {
+            if let Ok(mut image) = images.get_mut(child) {
+                image.color.set_alpha(alpha);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cutin_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.add_message::<NpcCutinReceived>();
+        app.add_systems(Update, (show_cutin, fade_in_cutin).chain());
+        app
+    }
+
+    fn illustrations(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<CutinIllustration>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn anchors_follow_script_position() {
+        assert_eq!(
+            anchor(CutinPosition::BottomRight),
+            (JustifyContent::FlexEnd, AlignItems::FlexEnd)
+        );
+        assert_eq!(
+            anchor(CutinPosition::Center),
+            (JustifyContent::Center, AlignItems::Center)
+        );
+    }
+
+    #[test]
+    fn new_cutin_replaces_and_clear_removes() {
+        let mut app = cutin_app();
+        for image in ["kafra_01", "kafra_02"] {
+            app.world_mut().write_message(NpcCutinReceived {
+                image: image.into(),
+                position: CutinPosition::BottomRight,
+            });
+            app.update();
+        }
+        assert_eq!(illustrations(&mut app), 1);
+
+        app.world_mut().write_message(NpcCutinReceived {
+            image: String::new(),
+            position: CutinPosition::Clear,
+        });
+        app.update();
+        assert_eq!(illustrations(&mut app), 0);
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Attach `make_draggable` to a window's titlebar; dragging it offsets the window
 //! root `Node`'s `left`/`top` by the pointer delta. This is the pattern future
-//! Inventory/Skill/etc. windows reuse.
+//! Inventory/Skill/etc. windows reuse. Once a window resize settles, dragged
+//! windows are pulled back inside the smaller viewport.
 
 use bevy::prelude::*;
+use game_engine::presentation::ui::window_resize::WindowResizeSettled;
 
 /// Marks a titlebar as a drag handle, carrying the window-root `Entity` to move.
 #[derive(Component)]
@@ -36,6 +38,39 @@ fn on_drag(drag: On<Pointer<Drag>>, handles: Query<&DraggableWindow>, mut nodes:
     node.top = Val::Px(px_or_zero(node.top) + drag.delta.y);
 }
 
+/// `left`/`top` that keep a window of `size` fully inside `viewport`, pinning it
+/// to the top-left when it is larger than the viewport.
+fn clamp_into_viewport(left: f32, top: f32, size: Vec2, viewport: Vec2) -> (f32, f32) {
+    let max = (viewport - size).max(Vec2::ZERO);
+    (left.clamp(0.0, max.x), top.clamp(0.0, max.y))
+}
+
+/// Re-clamps every draggable window into the viewport after a settled resize.
+/// Only px offsets are touched; windows still at their layout default stay put.
+pub fn keep_windows_on_screen(
+    mut settled: MessageReader<WindowResizeSettled>,
+    ui_scale: Res<UiScale>,
+    handles: Query<&DraggableWindow>,
+    mut roots: Query<(&mut Node, &ComputedNode)>,
+) {
+    let Some(event) = settled.read().last() else {
+        return;
+    };
+    let viewport = Vec2::new(event.width, event.height) / ui_scale.0;
+    for handle in &handles {
+        let Ok((mut node, computed)) = roots.get_mut(handle.window_root) else {
+            continue;
+        };
+        let (Val::Px(left), Val::Px(top)) = (node.left, node.top) else {
+            continue;
+        };
+        let size = computed.size() * computed.inverse_scale_factor();
+        let (left, top) = clamp_into_viewport(left, top, size, viewport);
+        node.left = Val::Px(left);
+        node.top = Val::Px(top);
+    }
+}
+
 pub fn px_or_zero(val: Val) -> f32 {
     match val {
         Val::Px(px) => px,
@@ -54,6 +89,24 @@ mod tests {
         assert_eq!(px_or_zero(Val::Percent(50.0)), 0.0);
     }
 
+    #[test]
+    fn clamp_pulls_windows_back_inside_the_viewport() {
+        let size = Vec2::new(300.0, 200.0);
+        let viewport = Vec2::new(800.0, 600.0);
+        assert_eq!(
+            clamp_into_viewport(700.0, 500.0, size, viewport),
+            (500.0, 400.0)
+        );
+        assert_eq!(
+            clamp_into_viewport(-20.0, 50.0, size, viewport),
+            (0.0, 50.0)
+        );
+        assert_eq!(
+            clamp_into_viewport(100.0, 100.0, Vec2::new(900.0, 700.0), viewport),
+            (0.0, 0.0)
+        );
+    }
+
     #[test]
     fn make_draggable_attaches_handle_to_titlebar() {
         let mut world = World::new();
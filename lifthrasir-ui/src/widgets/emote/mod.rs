@@ -80,8 +80,9 @@ pub fn toggle_emote_picker(
 
 /// Fill each cell's caption `ImageNode` from `EmoteAssets.thumbnails[id]`. Runs while
 /// the resource exists rather than once on add, so it also re-fills a HUD respawned on
-/// re-entering gameplay. Idempotent: the handle is written only when it differs, so a
-/// settled grid is a no-op with no change-detection churn.
+/// re-entering gameplay. Each thumbnail is a frame rect on a shared atlas page, so both
+/// the image and its `rect` are set. Idempotent: they are written only when they differ,
+/// so a settled grid is a no-op with no change-detection churn.
 pub fn populate_emote_thumbnails(
     assets: Res<EmoteAssets>,
     buttons: Query<(&EmoteButton, &Children)>,
@@ -95,8 +96,9 @@ pub fn populate_emote_thumbnails(
             let Ok(mut image) = images.get_mut(child) else {
                 continue;
             };
-            if image.image != *thumbnail {
-                image.image = thumbnail.clone();
+            if image.image != thumbnail.image || image.rect != thumbnail.rect {
+                image.image = thumbnail.image.clone();
+                image.rect = thumbnail.rect;
             }
         }
     }
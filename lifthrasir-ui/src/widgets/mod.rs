@@ -12,6 +12,8 @@ pub mod character_info;
 pub mod character_window;
 pub mod chat_box;
 pub mod chrome;
+pub mod connection_status;
+pub mod cutin;
 pub mod death_dialog;
 pub mod draggable;
 pub mod emote;
@@ -20,6 +22,7 @@ pub mod guild_window;
 pub mod hotbar;
 pub mod info_modal;
 pub mod minimap;
+pub mod netsim_slash;
 pub mod npc_dialog;
 pub mod party;
 pub mod placeholder;
@@ -36,6 +39,7 @@ pub struct InGameHudPlugin;
 impl Plugin for InGameHudPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::InGame), show_hud);
+        app.add_systems(Update, draggable::keep_windows_on_screen);
         app.add_plugins((
             announcement::AnnouncementPlugin,
             character_info::CharacterInfoPlugin,
@@ -53,6 +57,10 @@ impl Plugin for InGameHudPlugin {
             status_icons::StatusIconsPlugin,
             storage_window::StorageWindowPlugin,
         ));
+        app.add_plugins((
+            connection_status::ConnectionStatusPlugin,
+            cutin::CutinPlugin,
+        ));
     }
 }
 
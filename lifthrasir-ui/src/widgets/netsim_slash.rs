@@ -0,0 +1,59 @@
+//! `/netsim` slash command for the development network simulation.
+//!
+//! `chat_input_control` (`chat_box.rs`) tries [`parse_netsim_slash`] first in
+//! debug builds: `/netsim latency=200,jitter=50,loss=0.05` switches the
+//! simulation on (same spec as `LIFTHRASIR_NET_SIM`), `/netsim off` switches
+//! it off. Release builds send the line as normal chat.
+
+use net_contract::dto::NetworkConditions;
+
+/// Parse one chat line as `/netsim`. `None` when the line is not `/netsim`;
+/// otherwise the conditions to apply (`None` to switch off) or why the spec is
+/// unusable.
+pub fn parse_netsim_slash(input: &str) -> Option<Result<Option<NetworkConditions>, String>> {
+    let rest = input.trim().strip_prefix("/netsim")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(match rest.trim() {
+        "" => Err(
+            "usage: /netsim latency=200,jitter=50,loss=0.05,bandwidth=256,batch=100 | /netsim off"
+                .to_string(),
+        ),
+        "off" => Ok(None),
+        spec => NetworkConditions::parse(spec).map(Some),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spec_switches_on() {
+        let conditions = parse_netsim_slash("/netsim latency=120,loss=0.1")
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(conditions.latency_ms, 120);
+        assert_eq!(conditions.loss, 0.1);
+    }
+
+    #[test]
+    fn off_switches_off() {
+        assert_eq!(parse_netsim_slash("  /netsim off "), Some(Ok(None)));
+    }
+
+    #[test]
+    fn bad_or_missing_spec_is_an_error() {
+        assert!(parse_netsim_slash("/netsim").unwrap().is_err());
+        assert!(parse_netsim_slash("/netsim loss=3").unwrap().is_err());
+    }
+
+    #[test]
+    fn other_lines_are_none() {
+        assert_eq!(parse_netsim_slash("netsim off"), None);
+        assert_eq!(parse_netsim_slash("/netsimoff"), None);
+        assert_eq!(parse_netsim_slash("/pinvite bob"), None);
+    }
+}
@@ -248,11 +248,16 @@ enum GraphicsField {
     Antialiasing,
     Anisotropy,
     Upscaling,
+    TextureCompression,
+    SpriteCacheBudget,
     Dlss,
     Ssao,
     Vsync,
     Bloom,
     Shadows,
+    TileLighting,
+    SpriteLod,
+    FixedSpriteSize,
     FpsCap,
     UiScaling,
 }
@@ -299,6 +304,8 @@ fn field_label(graphics: &GraphicsSettings, field: GraphicsField) -> String {
         GraphicsField::Antialiasing => graphics.antialiasing.label().to_string(),
         GraphicsField::Anisotropy => graphics.anisotropy.label().to_string(),
         GraphicsField::Upscaling => graphics.upscaling.label().to_string(),
+        GraphicsField::TextureCompression => graphics.texture_compression.label().to_string(),
+        GraphicsField::SpriteCacheBudget => graphics.sprite_cache_budget.label().to_string(),
         GraphicsField::Dlss => graphics.dlss.label().to_string(),
         GraphicsField::Ssao => graphics.ssao.label().to_string(),
         GraphicsField::FpsCap => graphics.fps_cap.label().to_string(),
@@ -306,7 +313,10 @@ fn field_label(graphics: &GraphicsSettings, field: GraphicsField) -> String {
         GraphicsField::DisplayMode
         | GraphicsField::Vsync
         | GraphicsField::Bloom
-        | GraphicsField::Shadows => String::new(),
+        | GraphicsField::Shadows
+        | GraphicsField::TileLighting
+        | GraphicsField::SpriteLod
+        | GraphicsField::FixedSpriteSize => String::new(),
     }
 }
 
@@ -317,6 +327,9 @@ fn switch_value(graphics: &GraphicsSettings, field: GraphicsField) -> Option<boo
         GraphicsField::Vsync => Some(graphics.vsync),
         GraphicsField::Bloom => Some(graphics.bloom),
         GraphicsField::Shadows => Some(graphics.shadows),
+        GraphicsField::TileLighting => Some(graphics.tile_lighting),
+        GraphicsField::SpriteLod => Some(graphics.sprite_lod),
+        GraphicsField::FixedSpriteSize => Some(graphics.fixed_sprite_size),
         _ => None,
     }
 }
@@ -327,6 +340,9 @@ fn toggle_switch(graphics: &mut GraphicsSettings, field: GraphicsField) {
         GraphicsField::Vsync => graphics.vsync = !graphics.vsync,
         GraphicsField::Bloom => graphics.bloom = !graphics.bloom,
         GraphicsField::Shadows => graphics.shadows = !graphics.shadows,
+        GraphicsField::TileLighting => graphics.tile_lighting = !graphics.tile_lighting,
+        GraphicsField::SpriteLod => graphics.sprite_lod = !graphics.sprite_lod,
+        GraphicsField::FixedSpriteSize => graphics.fixed_sprite_size = !graphics.fixed_sprite_size,
         _ => {}
     }
 }
@@ -354,6 +370,18 @@ fn step_field(graphics: &mut GraphicsSettings, field: GraphicsField, dir: StepDi
         }
         (GraphicsField::Upscaling, StepDir::Next) => graphics.upscaling = graphics.upscaling.next(),
         (GraphicsField::Upscaling, StepDir::Prev) => graphics.upscaling = graphics.upscaling.prev(),
+        (GraphicsField::TextureCompression, StepDir::Next) => {
+            graphics.texture_compression = graphics.texture_compression.next()
+        }
+        (GraphicsField::TextureCompression, StepDir::Prev) => {
+            graphics.texture_compression = graphics.texture_compression.prev()
+        }
+        (GraphicsField::SpriteCacheBudget, StepDir::Next) => {
+            graphics.sprite_cache_budget = graphics.sprite_cache_budget.next()
+        }
+        (GraphicsField::SpriteCacheBudget, StepDir::Prev) => {
+            graphics.sprite_cache_budget = graphics.sprite_cache_budget.prev()
+        }
         (GraphicsField::Dlss, StepDir::Next) => graphics.dlss = graphics.dlss.next(),
         (GraphicsField::Dlss, StepDir::Prev) => graphics.dlss = graphics.dlss.prev(),
         (GraphicsField::Ssao, StepDir::Next) => graphics.ssao = graphics.ssao.next(),
@@ -1032,6 +1060,7 @@ mod tests {
             party: ActionBinds::default(),
             guild: ActionBinds::default(),
             emote: ActionBinds::default(),
+            screenshot: ActionBinds::default(),
             hotbar: Default::default(),
         };
         let expected = {
@@ -1059,6 +1088,7 @@ mod tests {
             party: ActionBinds::default(),
             guild: ActionBinds::default(),
             emote: ActionBinds::default(),
+            screenshot: ActionBinds::default(),
             hotbar: Default::default(),
         };
         let expected = {
@@ -195,10 +195,15 @@ fn graphics_body() -> impl Scene {
             row("Antialiasing", "Smooths jagged edges", stepper(GraphicsField::Antialiasing)),
             row("Anisotropic Filtering", "Sharpens ground textures at grazing angles", stepper(GraphicsField::Anisotropy)),
             row("Upscaling", "xBRZ sprite & texture upscaling (applies on map reload)", stepper(GraphicsField::Upscaling)),
+            row("Texture Compression", "BC1/BC3 map textures to save video memory (applies on map reload)", stepper(GraphicsField::TextureCompression)),
+            row("Sprite Memory", "Texture memory for sprites; ones off screen are dropped past it", stepper(GraphicsField::SpriteCacheBudget)),
             {dlss},
             row("Ambient Occlusion", "Contact shadows in crevices (SSAO); forces MSAA off", stepper(GraphicsField::Ssao)),
             row("Bloom", "Glow around bright lights", switch(GraphicsField::Bloom)),
             row("Shadows", "Sun shadow casting", switch(GraphicsField::Shadows)),
+            row("Tile Lighting", "Baked light and shade painted on the ground", switch(GraphicsField::TileLighting)),
+            row("Sprite LOD", "Simplify far-away units in crowded scenes", switch(GraphicsField::SpriteLod)),
+            row("Fixed Sprite Size", "Keep units the same size on screen at any zoom", switch(GraphicsField::FixedSpriteSize)),
             row("VSync", "Sync frames to display refresh", switch(GraphicsField::Vsync)),
             row("Frame Rate Cap", "Maximum frames per second", stepper(GraphicsField::FpsCap)),
             section("Interface"),
@@ -1,6 +1,6 @@
 //! Hover label for ground item drops: a screen-space text node showing the
-//! item's display name while `HoveredFloorItem` points at it. Mirrors
-//! `nameplates.rs` (spawn-on-hover, project-and-follow, despawn-on-exit) but
+//! item's display name and stack size while `HoveredFloorItem` points at it.
+//! Mirrors `nameplates.rs` (spawn-on-hover, project-and-follow, despawn-on-exit) but
 //! is keyed off the single `HoveredFloorItem` resource rather than a
 //! per-entity hover marker, since at most one drop can be hovered at a time.
 
@@ -37,6 +37,16 @@ struct FloorItemLabel {
     target: Entity,
 }
 
+/// The reference client's drop label: the bare name for a single item, the
+/// stack size appended as `"<name>: <n> ea"` otherwise.
+fn floor_item_label_text(name: &str, amount: u32) -> String {
+    if amount > 1 {
+        format!("{name}: {amount} ea")
+    } else {
+        name.to_string()
+    }
+}
+
 fn spawn_floor_item_label(
     commands: &mut Commands,
     font: &WorldspaceFont,
@@ -91,13 +101,16 @@ fn sync_floor_item_labels(
 ) {
     if let Some(target) = hovered.0 {
         let already_labeled = labels.iter().any(|(_, label)| label.target == target);
-        let name = floor_items
+        let text = floor_items
             .get(target)
             .ok()
             .zip(item_db.as_deref())
-            .and_then(|(item, db)| db.name(item.nameid, item.identified));
-        if !already_labeled && let Some(name) = name {
-            spawn_floor_item_label(&mut commands, &font, target, name);
+            .and_then(|(item, db)| {
+                db.name(item.nameid, item.identified)
+                    .map(|name| floor_item_label_text(name, item.amount))
+            });
+        if !already_labeled && let Some(text) = text {
+            spawn_floor_item_label(&mut commands, &font, target, &text);
         }
     }
 
@@ -212,6 +225,31 @@ mod tests {
         assert_eq!(label_count(&mut app), 0);
     }
 
+    #[test]
+    fn stacked_drops_show_their_amount() {
+        let mut app = test_app(Some(item_db_with(501, "Red Potion")));
+        let target = app
+            .world_mut()
+            .spawn(FloorItem {
+                ground_id: 1,
+                nameid: 501,
+                amount: 12,
+                identified: true,
+            })
+            .id();
+        app.world_mut().resource_mut::<HoveredFloorItem>().0 = Some(target);
+
+        app.update();
+
+        let world = app.world_mut();
+        let text = world
+            .query::<&Text>()
+            .iter(world)
+            .map(|text| text.0.clone())
+            .next();
+        assert_eq!(text.as_deref(), Some("Red Potion: 12 ea"));
+    }
+
     #[test]
     fn hovering_a_different_item_moves_the_label() {
         let mut app = test_app(Some(item_db_with(501, "Red Potion")));
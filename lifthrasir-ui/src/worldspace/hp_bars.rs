@@ -0,0 +1,228 @@
+//! Monster HP bars: a thin screen-space bar just above a monster's feet that
+//! appears once it has taken damage and depletes with each `UnitHpChanged`
+//! (ZC_NOTIFY_MONSTER_HP). The HP itself lives on the engine's [`Health`]
+//! component; this module only mirrors it. A bar is dropped when the monster
+//! heals back to full, loses its `Health`, or despawns.
+
+use bevy::prelude::*;
+use game_engine::core::state::GameState;
+use game_engine::domain::entities::character::components::Health;
+use game_engine::domain::entities::components::NetworkEntity;
+use game_engine::domain::entities::types::ObjectType;
+
+use crate::theme;
+use crate::worldspace::{WorldCameraFilter, viewport_to_ui};
+
+const BAR_WIDTH: f32 = 48.0;
+const BAR_HEIGHT: f32 = 5.0;
+/// Pixels above the monster's projected origin (the feet), so the bar sits
+/// between the sprite and the hover nameplate below the feet. NOTE: fixed
+/// screen offset, not zoom-scaled — tune live via BRP if it drifts.
+const BAR_FOOT_LIFT: f32 = 4.0;
+/// Just under nameplates (100) so a hovered name reads over an overlapping bar.
+const BAR_Z: i32 = 90;
+
+pub struct HpBarPlugin;
+
+impl Plugin for HpBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (sync_hp_bars, fill_hp_bars, follow_hp_bars)
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+        app.add_systems(OnExit(GameState::InGame), despawn_all_hp_bars);
+    }
+}
+
+#[derive(Component)]
+struct HpBar {
+    target: Entity,
+}
+
+/// Fill node of an HP bar; width tracks the target's [`Health`] fraction.
+#[derive(Component)]
+struct HpBarFill;
+
+fn shows_bar(network: &NetworkEntity, health: &Health) -> bool {
+    network.object_type == ObjectType::Mob && health.is_damaged()
+}
+
+/// Spawns a bar for each damaged monster that lacks one and drops bars whose
+/// target no longer qualifies.
+fn sync_hp_bars(
+    mut commands: Commands,
+    units: Query<(Entity, &NetworkEntity, &Health)>,
+    bars: Query<(Entity, &HpBar)>,
+) {
+    let mut barred = Vec::new();
+    for (bar_entity, bar) in &bars {
+        let keep = units
+            .get(bar.target)
+            .is_ok_and(|(_, network, health)| shows_bar(network, health));
+        if keep {
+            barred.push(bar.target);
+        } else {
+            commands.entity(bar_entity).despawn();
+        }
+    }
+
+    for (target, network, health) in &units {
+        if shows_bar(network, health) && !barred.contains(&target) {
+            spawn_bar(&mut commands, target);
+        }
+    }
+}
+
+fn spawn_bar(commands: &mut Commands, target: Entity) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(BAR_WIDTH),
+            height: Val::Px(BAR_HEIGHT),
+            border: UiRect::all(Val::Px(1.0)),
+            border_radius: BorderRadius::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(theme::FIELD),
+        BorderColor::all(theme::GOLD_FAINT),
+        GlobalZIndex(BAR_Z),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        HpBar { target },
+        children![(
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                border_radius: BorderRadius::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(theme::HEALTH_RED),
+            HpBarFill,
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+fn fill_hp_bars(
+    bars: Query<&HpBar>,
+    health: Query<&Health>,
+    parents: Query<&ChildOf>,
+    mut fills: Query<(Entity, &mut Node), With<HpBarFill>>,
+) {
+    for (entity, mut node) in &mut fills {
+        let Some(health) = parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| bars.get(ancestor).ok())
+            .and_then(|bar| health.get(bar.target).ok())
+        else {
+            continue;
+        };
+        let width = Val::Percent(health.fraction() * 100.0);
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+fn follow_hp_bars(
+    camera: Query<(&Camera, &GlobalTransform), WorldCameraFilter>,
+    targets: Query<&GlobalTransform>,
+    ui_scale: Res<UiScale>,
+    mut bars: Query<(&HpBar, &mut Node, &mut Visibility)>,
+) {
+    let Ok((camera, camera_transform)) = camera.single() else {
+        return;
+    };
+    for (bar, mut node, mut visibility) in &mut bars {
+        let screen = targets.get(bar.target).ok().and_then(|transform| {
+            camera
+                .world_to_viewport(camera_transform, transform.translation())
+                .ok()
+        });
+        match screen {
+            Some(screen) => {
+                let pos = viewport_to_ui(screen, &ui_scale);
+                node.left = Val::Px(pos.x - BAR_WIDTH / 2.0);
+                node.top = Val::Px(pos.y - BAR_FOOT_LIFT - BAR_HEIGHT);
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn despawn_all_hp_bars(mut commands: Commands, bars: Query<Entity, With<HpBar>>) {
+    for entity in &bars {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, (sync_hp_bars, fill_hp_bars).chain());
+        app
+    }
+
+    fn bar_count(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query::<&HpBar>().iter(world).count()
+    }
+
+    fn spawn_unit(app: &mut App, object_type: ObjectType, hp: u32) -> Entity {
+        app.world_mut()
+            .spawn((
+                NetworkEntity::new(0, 7, object_type),
+                Health { hp, max_hp: 100 },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn damaged_monster_gets_a_bar_filled_to_its_hp() {
+        let mut app = app();
+        spawn_unit(&mut app, ObjectType::Mob, 25);
+
+        app.update();
+        app.update();
+
+        assert_eq!(bar_count(&mut app), 1);
+        let world = app.world_mut();
+        let width = world
+            .query_filtered::<&Node, With<HpBarFill>>()
+            .single(world)
+            .unwrap()
+            .width;
+        assert_eq!(width, Val::Percent(25.0));
+    }
+
+    #[test]
+    fn full_hp_and_non_monsters_get_no_bar() {
+        let mut app = app();
+        spawn_unit(&mut app, ObjectType::Mob, 100);
+        spawn_unit(&mut app, ObjectType::Pc, 10);
+
+        app.update();
+
+        assert_eq!(bar_count(&mut app), 0);
+    }
+
+    #[test]
+    fn bar_despawns_with_its_monster() {
+        let mut app = app();
+        let mob = spawn_unit(&mut app, ObjectType::Mob, 50);
+        app.update();
+        assert_eq!(bar_count(&mut app), 1);
+
+        app.world_mut().entity_mut(mob).despawn();
+        app.update();
+
+        assert_eq!(bar_count(&mut app), 0);
+    }
+}
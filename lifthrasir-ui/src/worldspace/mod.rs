@@ -1,4 +1,5 @@
-//! World-anchored overlays: hover nameplates and floating damage numbers.
+//! World-anchored overlays: hover nameplates, floating damage numbers and
+//! monster HP bars.
 //!
 //! These are screen-projected `bevy_ui` text nodes (not `bevy_lunex` worldspace
 //! UI): each frame an anchored node's `left`/`top` is set from
@@ -14,6 +15,7 @@ use crate::theme;
 
 pub mod damage_numbers;
 pub mod floor_item_labels;
+pub mod hp_bars;
 pub mod nameplates;
 pub mod skill_cast_labels;
 
@@ -44,6 +46,7 @@ impl Plugin for WorldspaceUiPlugin {
             damage_numbers::DamageNumberPlugin,
             floor_item_labels::FloorItemLabelPlugin,
             skill_cast_labels::SkillCastLabelPlugin,
+            hp_bars::HpBarPlugin,
         ));
     }
 }
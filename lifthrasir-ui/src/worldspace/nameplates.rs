@@ -2,6 +2,9 @@
 //! (any entity with an `EntityName`, including the local player). Driven each frame by
 //! the `HoveredEntity` marker so it picks up names that arrive asynchronously after the
 //! on-hover server name request; positioned by projecting the target's world position.
+//! When several plates land on top of each other, a layout pass stacks the lower-priority
+//! ones below the higher-priority ones (self > party > others) and hides what won't fit.
+//! Units drawn at low detail (`SpriteLod`) get no plate.
 
 use bevy::prelude::*;
 use game_engine::core::state::GameState;
@@ -9,6 +12,7 @@ use game_engine::domain::entities::EntityRegistry;
 use game_engine::domain::entities::components::{EntityName, GuildIdentity};
 use game_engine::domain::entities::hover::HoveredEntity;
 use game_engine::domain::entities::markers::LocalPlayer;
+use game_engine::domain::entities::sprite_rendering::SpriteLod;
 use game_engine::domain::guild::{GuildState, GuildSystems};
 use game_engine::domain::party::PartyState;
 
@@ -27,6 +31,13 @@ const PARTY_FONT_SIZE: f32 = 11.0;
 const NAMEPLATE_FOOT_GAP: f32 = 6.0;
 /// Above the world camera, below the fade overlay (`i32::MAX - 1`) and cursor.
 const NAMEPLATE_Z: i32 = 100;
+/// Vertical gap between stacked plates when resolving overlaps.
+const NAMEPLATE_STACK_GAP: f32 = 2.0;
+/// How many rows a plate may be pushed down before it is hidden instead.
+const NAMEPLATE_MAX_STACK: usize = 3;
+/// A displaced plate only snaps back to its anchor once the anchor is clear by this
+/// many pixels, so two plates brushing past each other don't flicker between rows.
+const NAMEPLATE_HYSTERESIS: f32 = 6.0;
 
 pub struct NameplatePlugin;
 
@@ -37,6 +48,7 @@ impl Plugin for NameplatePlugin {
             (
                 sync_nameplates,
                 follow_targets,
+                resolve_overlaps,
                 request_visible_emblems,
                 sync_nameplate_emblems,
             )
@@ -54,6 +66,33 @@ struct Nameplate {
     guild_key: Option<EmblemKey>,
 }
 
+/// Layout precedence when plates overlap; higher wins the anchor position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NameplatePriority {
+    Other,
+    Party,
+    LocalPlayer,
+}
+
+/// Per-frame layout state: the projected anchor (UI px, top-left of the wrapper) and the
+/// stacking offset chosen last frame, kept for hysteresis.
+#[derive(Component)]
+struct NameplateLayout {
+    priority: NameplatePriority,
+    anchor: Option<Vec2>,
+    offset: Option<f32>,
+}
+
+impl NameplateLayout {
+    fn new(priority: NameplatePriority) -> Self {
+        Self {
+            priority,
+            anchor: None,
+            offset: Some(0.0),
+        }
+    }
+}
+
 #[derive(Component)]
 struct NameplateGuildEmblem {
     key: EmblemKey,
@@ -64,6 +103,14 @@ struct NameplateGuildFallback {
     key: Option<EmblemKey>,
 }
 
+/// Hovered units that get a plate: named, and not drawn at low detail.
+type HoveredNamedQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static EntityName, Option<&'static GuildIdentity>),
+    (With<HoveredEntity>, Without<SpriteLod>),
+>;
+
 fn has_nameplate(nameplates: &Query<&Nameplate>, target: Entity) -> bool {
     nameplates.iter().any(|plate| plate.target == target)
 }
@@ -87,6 +134,16 @@ fn party_name_for<'a>(
         .then_some(party.name.as_str())
 }
 
+fn nameplate_priority(is_self: bool, party_member: bool) -> NameplatePriority {
+    if is_self {
+        NameplatePriority::LocalPlayer
+    } else if party_member {
+        NameplatePriority::Party
+    } else {
+        NameplatePriority::Other
+    }
+}
+
 fn spawn_guild_mark(commands: &mut Commands, row: Entity, key: Option<EmblemKey>) {
     commands.spawn((
         NameplateGuildFallback { key },
@@ -128,10 +185,11 @@ fn spawn_nameplate(
     font: &WorldspaceFont,
     target: Entity,
     name: &str,
-    is_self: bool,
+    priority: NameplatePriority,
     party: Option<&str>,
     guild: Option<&GuildIdentity>,
 ) {
+    let is_self = priority == NameplatePriority::LocalPlayer;
     let name_color = if is_self {
         theme::EMERALD_BRI
     } else {
@@ -152,6 +210,7 @@ fn spawn_nameplate(
             Visibility::Hidden,
             Pickable::IGNORE,
             Nameplate { target, guild_key },
+            NameplateLayout::new(priority),
         ))
         .id();
 
@@ -246,11 +305,11 @@ fn spawn_nameplate(
 #[allow(clippy::too_many_arguments)]
 fn sync_nameplates(
     mut commands: Commands,
-    hovered: Query<(Entity, &EntityName, Option<&GuildIdentity>), With<HoveredEntity>>,
+    hovered: HoveredNamedQuery,
     local_player: Query<(), With<LocalPlayer>>,
     nameplates: Query<&Nameplate>,
     stale: Query<(Entity, &Nameplate)>,
-    still_hovered: Query<(), With<HoveredEntity>>,
+    still_hovered: Query<(), (With<HoveredEntity>, Without<SpriteLod>)>,
     registry: Res<EntityRegistry>,
     party: Res<PartyState>,
     local_guild: Res<GuildState>,
@@ -264,10 +323,8 @@ fn sync_nameplates(
         // The server's name-all reply carries the unit's party name for any party
         // (ours or another), so prefer it; fall back to our own roster for units named
         // without that reply — notably the local player, spawned without a name request.
-        let party_name = name
-            .party_name
-            .as_deref()
-            .or_else(|| party_name_for(&registry, &party, target));
+        let own_party = party_name_for(&registry, &party, target);
+        let party_name = name.party_name.as_deref().or(own_party);
         let local_guild = is_self
             .then(|| {
                 local_guild.info().map(|info| GuildIdentity {
@@ -282,7 +339,7 @@ fn sync_nameplates(
             &font,
             target,
             &name.name,
-            is_self,
+            nameplate_priority(is_self, own_party.is_some()),
             party_name,
             guild.or(local_guild.as_ref()),
         );
@@ -357,26 +414,114 @@ fn follow_targets(
     camera: Query<(&Camera, &GlobalTransform), WorldCameraFilter>,
     targets: Query<&GlobalTransform>,
     ui_scale: Res<UiScale>,
-    mut nameplates: Query<(Entity, &Nameplate, &mut Node, &mut Visibility)>,
+    mut nameplates: Query<(Entity, &Nameplate, &mut NameplateLayout)>,
     mut commands: Commands,
 ) {
     let Ok((camera, camera_transform)) = camera.single() else {
         return;
     };
-    for (entity, plate, mut node, mut visibility) in &mut nameplates {
+    for (entity, plate, mut layout) in &mut nameplates {
         let Ok(target_transform) = targets.get(plate.target) else {
             commands.entity(entity).despawn();
             continue;
         };
-        match camera.world_to_viewport(camera_transform, target_transform.translation()) {
-            Ok(screen) => {
+        layout.anchor = camera
+            .world_to_viewport(camera_transform, target_transform.translation())
+            .ok()
+            .map(|screen| {
                 let pos = viewport_to_ui(screen, &ui_scale);
-                node.left = Val::Px(pos.x - NAMEPLATE_WIDTH / 2.0);
-                node.top = Val::Px(pos.y + NAMEPLATE_FOOT_GAP);
-                *visibility = Visibility::Visible;
-            }
-            Err(_) => *visibility = Visibility::Hidden,
+                Vec2::new(pos.x - NAMEPLATE_WIDTH / 2.0, pos.y + NAMEPLATE_FOOT_GAP)
+            });
+    }
+}
+
+/// One plate's input to the overlap solver: its priority, the pill's rect at offset 0,
+/// and the offset it held last frame.
+#[derive(Debug, Clone, Copy)]
+struct PlateSlot {
+    priority: NameplatePriority,
+    rect: Rect,
+    previous: Option<f32>,
+}
+
+fn overlaps_any(rect: Rect, placed: &[Rect]) -> bool {
+    placed
+        .iter()
+        .any(|other| !rect.intersect(*other).is_empty())
+}
+
+/// Greedy label layout: plates are placed in priority order (ties keep input order); each
+/// takes the first free row among its anchor, last frame's row, and up to
+/// `NAMEPLATE_MAX_STACK` rows below. A plate with no free row is hidden (`None`).
+fn resolve_plate_offsets(slots: &[PlateSlot]) -> Vec<Option<f32>> {
+    let mut order: Vec<usize> = (0..slots.len()).collect();
+    order.sort_by(|&a, &b| slots[b].priority.cmp(&slots[a].priority));
+
+    let mut placed: Vec<Rect> = Vec::with_capacity(slots.len());
+    let mut offsets = vec![None; slots.len()];
+    for index in order {
+        let slot = slots[index];
+        let row = slot.rect.height() + NAMEPLATE_STACK_GAP;
+        let at = |offset: f32| {
+            Rect::from_corners(
+                slot.rect.min + Vec2::Y * offset,
+                slot.rect.max + Vec2::Y * offset,
+            )
+        };
+        let anchor_clear = !overlaps_any(at(0.0).inflate(NAMEPLATE_HYSTERESIS), &placed);
+        let mut candidates = anchor_clear
+            .then_some(0.0)
+            .into_iter()
+            .chain(slot.previous)
+            .chain((0..=NAMEPLATE_MAX_STACK).map(|step| step as f32 * row));
+        let chosen = candidates.find(|&offset| !overlaps_any(at(offset), &placed));
+        if let Some(offset) = chosen {
+            placed.push(at(offset));
         }
+        offsets[index] = chosen;
+    }
+    offsets
+}
+
+/// Applies the anchor plus the overlap solver's offset to every plate, hiding plates
+/// that are off-screen or could not find a free row.
+fn resolve_overlaps(
+    mut nameplates: Query<(&mut NameplateLayout, &Children, &mut Node, &mut Visibility)>,
+    pills: Query<&ComputedNode>,
+) {
+    let mut entries = Vec::new();
+    let mut slots = Vec::new();
+    for (layout, children, node, mut visibility) in &mut nameplates {
+        let Some(anchor) = layout.anchor else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        // The wrapper is a fixed-width centering box; the visible pill is its first child.
+        let size = children
+            .first()
+            .and_then(|pill| pills.get(*pill).ok())
+            .map(|computed| computed.size() * computed.inverse_scale_factor())
+            .unwrap_or(Vec2::ZERO);
+        let min = Vec2::new(anchor.x + (NAMEPLATE_WIDTH - size.x) / 2.0, anchor.y);
+        slots.push(PlateSlot {
+            priority: layout.priority,
+            rect: Rect::from_corners(min, min + size),
+            previous: layout.offset,
+        });
+        entries.push((layout, node, visibility, anchor));
+    }
+
+    let offsets = resolve_plate_offsets(&slots);
+    for ((mut layout, mut node, mut visibility, anchor), offset) in entries.into_iter().zip(offsets)
+    {
+        layout.offset = offset;
+        let Some(offset) = offset else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        node.left = Val::Px(anchor.x);
+        node.top = Val::Px(anchor.y + offset);
+        *visibility = Visibility::Visible;
     }
 }
 
@@ -745,6 +890,56 @@ mod tests {
         assert_eq!(labels, vec!["Stranger".to_string()]);
     }
 
+    fn slot(priority: NameplatePriority, x: f32, y: f32, previous: Option<f32>) -> PlateSlot {
+        PlateSlot {
+            priority,
+            rect: Rect::new(x, y, x + 80.0, y + 20.0),
+            previous,
+        }
+    }
+
+    #[test]
+    fn overlapping_plates_stack_below_the_higher_priority_one() {
+        let slots = [
+            slot(NameplatePriority::Other, 100.0, 100.0, None),
+            slot(NameplatePriority::LocalPlayer, 110.0, 100.0, None),
+        ];
+
+        let offsets = resolve_plate_offsets(&slots);
+
+        assert_eq!(offsets[1], Some(0.0));
+        assert_eq!(offsets[0], Some(20.0 + NAMEPLATE_STACK_GAP));
+    }
+
+    #[test]
+    fn displaced_plate_keeps_its_row_until_the_anchor_is_clearly_free() {
+        // The anchor is free by 2px only: inside the hysteresis margin, so stay put.
+        let slots = [
+            slot(NameplatePriority::Party, 100.0, 100.0, Some(0.0)),
+            slot(NameplatePriority::Other, 182.0, 100.0, Some(22.0)),
+        ];
+        assert_eq!(resolve_plate_offsets(&slots)[1], Some(22.0));
+
+        // Clear by more than the margin: snap back to the anchor.
+        let slots = [
+            slot(NameplatePriority::Party, 100.0, 100.0, Some(0.0)),
+            slot(NameplatePriority::Other, 200.0, 100.0, Some(22.0)),
+        ];
+        assert_eq!(resolve_plate_offsets(&slots)[1], Some(0.0));
+    }
+
+    #[test]
+    fn plates_past_the_stack_limit_are_hidden() {
+        let slots: Vec<PlateSlot> = (0..NAMEPLATE_MAX_STACK + 2)
+            .map(|_| slot(NameplatePriority::Other, 100.0, 100.0, None))
+            .collect();
+
+        let offsets = resolve_plate_offsets(&slots);
+
+        assert!(offsets[..=NAMEPLATE_MAX_STACK].iter().all(Option::is_some));
+        assert_eq!(offsets[NAMEPLATE_MAX_STACK + 1], None);
+    }
+
     #[test]
     fn hovered_unnamed_entity_spawns_nothing() {
         let mut app = test_app();
@@ -0,0 +1,137 @@
+//! Client-specific BRP methods for the `dev` build.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::remote::{BrpError, BrpResult, RemoteMethodSystemId, RemoteMethods, error_codes};
+use game_engine::infrastructure::diagnostics::{
+    EntityInspection, FrameBudget, LogFiles, inspect_entity,
+};
+use game_engine::infrastructure::encoding::ResourceNames;
+use lifthrasir_ui::devtools::ui_state_snapshot;
+use serde_json::Value;
+
+/// Returns [`lifthrasir_ui::devtools::UiStateSnapshot`] as JSON.
+pub const UI_STATE_METHOD: &str = "lifthrasir/ui_state";
+
+/// Returns [`game_engine::infrastructure::diagnostics::FrameBudgetReport`] as JSON.
+pub const FRAME_BUDGET_METHOD: &str = "lifthrasir/frame_budget";
+
+/// Returns the last `limit` (default 200) lines of this session's log file.
+pub const RECENT_LOGS_METHOD: &str = "lifthrasir/recent_logs";
+
+const DEFAULT_RECENT_LOG_LINES: usize = 200;
+
+/// Returns the [`EntityInspection`] of the entity with params `{"gid": n}`, or
+/// `null` if it isn't spawned.
+pub const INSPECT_ENTITY_METHOD: &str = "lifthrasir/inspect_entity";
+
+/// [`INSPECT_ENTITY_METHOD`], sent again each time the inspection changes.
+pub const INSPECT_ENTITY_WATCH_METHOD: &str = "lifthrasir/inspect_entity+watch";
+
+/// Returns `{"key", "gloss"}` for params `{"path": "..."}`: the asset lookup
+/// key and the path with its Korean folder names in English.
+pub const RESOURCE_NAME_METHOD: &str = "lifthrasir/resource_name";
+
+/// Registers the client's BRP methods. Must run after `RemotePlugin` is added.
+pub fn register_methods(app: &mut App) {
+    let world = app.world_mut();
+    let ui_state = world.register_system(ui_state);
+    let frame_budget = world.register_system(frame_budget);
+    let recent_logs = world.register_system(recent_logs);
+    let inspect = world.register_system(inspect);
+    let inspect_watch = world.register_system(inspect_watch);
+    let resource_name = world.register_system(resource_name);
+    let mut methods = world.resource_mut::<RemoteMethods>();
+    methods.insert(UI_STATE_METHOD, RemoteMethodSystemId::Instant(ui_state));
+    methods.insert(
+        FRAME_BUDGET_METHOD,
+        RemoteMethodSystemId::Instant(frame_budget),
+    );
+    methods.insert(
+        RECENT_LOGS_METHOD,
+        RemoteMethodSystemId::Instant(recent_logs),
+    );
+    methods.insert(
+        INSPECT_ENTITY_METHOD,
+        RemoteMethodSystemId::Instant(inspect),
+    );
+    methods.insert(
+        INSPECT_ENTITY_WATCH_METHOD,
+        RemoteMethodSystemId::Watching(inspect_watch),
+    );
+    methods.insert(
+        RESOURCE_NAME_METHOD,
+        RemoteMethodSystemId::Instant(resource_name),
+    );
+}
+
+fn ui_state(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
+    serde_json::to_value(ui_state_snapshot(world)).map_err(BrpError::internal)
+}
+
+fn frame_budget(In(_): In<Option<Value>>, budget: Res<FrameBudget>) -> BrpResult {
+    serde_json::to_value(budget.report()).map_err(BrpError::internal)
+}
+
+fn recent_logs(In(params): In<Option<Value>>, files: Option<Res<LogFiles>>) -> BrpResult {
+    let limit = params
+        .as_ref()
+        .and_then(|params| params.get("limit"))
+        .and_then(Value::as_u64)
+        .map_or(DEFAULT_RECENT_LOG_LINES, |limit| limit as usize);
+    let Some(files) = files else {
+        return Err(BrpError::internal("file logging is off"));
+    };
+    let lines = files.recent_lines(limit).map_err(BrpError::internal)?;
+    Ok(Value::from(lines))
+}
+
+fn gid_param(params: Option<&Value>) -> Result<u32, BrpError> {
+    params
+        .and_then(|params| params.get("gid"))
+        .and_then(Value::as_u64)
+        .and_then(|gid| u32::try_from(gid).ok())
+        .ok_or_else(|| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: "expected params {\"gid\": <u32>}".to_string(),
+            data: None,
+        })
+}
+
+fn inspect(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let gid = gid_param(params.as_ref())?;
+    serde_json::to_value(inspect_entity(world, gid)).map_err(BrpError::internal)
+}
+
+/// Answers only when the gid's inspection differs from the one last sent.
+fn inspect_watch(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+    mut sent: Local<HashMap<u32, Option<EntityInspection>>>,
+) -> BrpResult<Option<Value>> {
+    let gid = gid_param(params.as_ref())?;
+    let inspection = inspect_entity(world, gid);
+    if sent.get(&gid) == Some(&inspection) {
+        return Ok(None);
+    }
+    let value = serde_json::to_value(&inspection).map_err(BrpError::internal)?;
+    sent.insert(gid, inspection);
+    Ok(Some(value))
+}
+
+fn resource_name(In(params): In<Option<Value>>, names: Res<ResourceNames>) -> BrpResult {
+    let path = params
+        .as_ref()
+        .and_then(|params| params.get("path"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: "expected params {\"path\": <string>}".to_string(),
+            data: None,
+        })?;
+    Ok(serde_json::json!({
+        "key": names.key(path),
+        "gloss": names.gloss(path),
+    }))
+}
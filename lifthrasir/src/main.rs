@@ -1,4 +1,6 @@
 mod assets;
+#[cfg(feature = "dev")]
+mod devtools;
 
 use bevy::prelude::*;
 use bevy::window::{Window, WindowPlugin, WindowResolution};
@@ -20,53 +22,81 @@ fn main() {
         bevy::asset::uuid::uuid!("45e9d9b0-1a0d-4da9-83d1-cf5f8af1ff17"),
     ));
 
-    app.add_plugins(
-        DefaultPlugins
-            .set(WindowPlugin {
-                primary_window: Some(Window {
-                    title: format!("Lifthrasir {VERSION}"),
-                    resolution: WindowResolution::new(1280, 720),
+    // Silence bevy_hanabi's benign per-load "Failed to find material bind
+    // group layout" error: textured particle effects (map smoke/emitter)
+    // hit a one-frame layout-caching race at spawn; the frame is skipped
+    // harmlessly and the effect renders fine. Warnings and errors are also
+    // kept for the diagnostics overlay, and everything is written to the
+    // session log files.
+    let log = bevy::log::LogPlugin {
+        filter: format!("{},bevy_hanabi::render=off", bevy::log::DEFAULT_FILTER),
+        custom_layer: game_engine::infrastructure::diagnostics::client_log_layers,
+        ..default()
+    };
+
+    // `--headless` runs the engine with no window, GPU or UI, for bots and
+    // scripted login → zone runs in CI.
+    let headless = std::env::args().any(|arg| arg == "--headless");
+    if headless {
+        app.add_plugins(game_engine::HeadlessPlugins.set(log));
+    } else {
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: format!("Lifthrasir {VERSION}"),
+                        resolution: WindowResolution::new(1280, 720),
+                        ..default()
+                    }),
                     ..default()
-                }),
-                ..default()
-            })
-            // Silence bevy_hanabi's benign per-load "Failed to find material bind
-            // group layout" error: textured particle effects (map smoke/emitter)
-            // hit a one-frame layout-caching race at spawn; the frame is skipped
-            // harmlessly and the effect renders fine.
-            .set(bevy::log::LogPlugin {
-                filter: format!("{},bevy_hanabi::render=off", bevy::log::DEFAULT_FILTER),
-                ..default()
-            }),
-    );
+                })
+                .set(log),
+        );
+    }
 
     info!("Lifthrasir {VERSION}");
+    game_engine::infrastructure::diagnostics::install_crash_reporter(&mut app, VERSION);
 
-    // World-entity picking (attack/pickup/talk) is routed by bevy_picking mesh hits
-    // instead of a shared-click race. `require_markers` keeps it opt-in: only the
-    // camera (MeshPickingCamera) and the sprite bodies (Pickable) participate, so the
-    // terrain and effect meshes are never picked.
-    app.add_plugins(bevy::picking::mesh_picking::MeshPickingPlugin);
-    app.insert_resource(bevy::picking::mesh_picking::MeshPickingSettings {
-        require_markers: true,
-        ..default()
-    });
+    if !headless {
+        // World-entity picking (attack/pickup/talk) is routed by bevy_picking mesh hits
+        // instead of a shared-click race. `require_markers` keeps it opt-in: only the
+        // camera (MeshPickingCamera) and the sprite bodies (Pickable) participate, so the
+        // terrain and effect meshes are never picked.
+        app.add_plugins(bevy::picking::mesh_picking::MeshPickingPlugin);
+        app.insert_resource(bevy::picking::mesh_picking::MeshPickingSettings {
+            require_markers: true,
+            ..default()
+        });
 
-    app.add_plugins(bevy_framepace::FramepacePlugin);
+        app.add_plugins(bevy_framepace::FramepacePlugin);
+    }
 
     #[cfg(feature = "dev")]
     app.add_plugins((
         bevy::diagnostic::FrameTimeDiagnosticsPlugin::default(),
         bevy_brp_extras::BrpExtrasPlugin::default(),
     ));
+    #[cfg(feature = "dev")]
+    devtools::register_methods(&mut app);
 
     app.add_plugins(game_engine::MapPlugin);
     app.add_plugins(game_engine::CoreGamePlugins);
 
     #[cfg(feature = "net-aesir")]
-    app.add_plugins(net_aesir::AesirNetPlugin);
+    {
+        app.add_plugins(net_aesir::AesirNetPlugin);
+        game_engine::infrastructure::diagnostics::track_frame_budget(
+            &mut app,
+            PreUpdate,
+            "network",
+            net_aesir::dispatch::NetworkReceiveSystems,
+            net_aesir::dispatch::NetworkReceiveSystems,
+        );
+    }
 
-    app.add_plugins(lifthrasir_ui::LifthrasirUiPlugin);
+    if !headless {
+        app.add_plugins(lifthrasir_ui::LifthrasirUiPlugin);
+    }
 
     app.run();
 }
@@ -0,0 +1,691 @@
+use anyhow::{Context, Result, anyhow};
+use clap::{Parser, Subcommand};
+use image::{GrayImage, Luma, Rgb, RgbImage};
+use ro_formats::{GrfFile, RoAltitude, RoGround, RoWorld, RswObject};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(name = "map-utils")]
+#[command(about = "A CLI utility for inspecting RSW/GND/GAT map files", long_about = None)]
+struct Cli {
+    /// Print a single JSON document on stdout instead of human-readable text
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Read maps out of this GRF; MAP is then a map name such as `prontera`
+    #[arg(long, global = true, value_name = "GRF")]
+    grf: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Parse the map's RSW, GND and GAT and summarize each one
+    Stats {
+        /// Path to the map's .rsw file, or a map name with --grf
+        map: String,
+    },
+    /// Export the GND terrain heights as a grayscale PNG, highest ground brightest
+    Heightmap {
+        /// Path to the map's .rsw file, or a map name with --grf
+        map: String,
+
+        /// Output PNG (default: "<map>_height.png")
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Export GAT walkability as a PNG: white walkable, blue water, grey
+    /// shoot-over only, black blocked
+    Walkability {
+        /// Path to the map's .rsw file, or a map name with --grf
+        map: String,
+
+        /// Output PNG (default: "<map>_walk.png")
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// List the models, lights, sounds and effects the RSW places
+    Objects {
+        /// Path to the map's .rsw file, or a map name with --grf
+        map: String,
+    },
+}
+
+/// JSON shape of a failed run; the process still exits with status 1.
+#[derive(Serialize)]
+struct ErrorReport {
+    error: String,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let json = cli.json;
+
+    if let Err(e) = run(cli) {
+        if json {
+            print_json(&ErrorReport {
+                error: format!("{:#}", e),
+            });
+        } else {
+            eprintln!("Error: {:#}", e);
+        }
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let grf = cli.grf.as_deref().map(load_grf).transpose()?;
+    match cli.command {
+        Commands::Stats { map } => {
+            let map = MapFiles::open(grf, &map);
+            let report = stats_report(&map);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_stats(&report);
+            }
+            // Like `grf-utils verify`: the report is printed either way, the
+            // exit status tells a pipeline whether every file parsed.
+            if !report.errors.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Commands::Heightmap { map, output } => {
+            let map = MapFiles::open(grf, &map);
+            let image = height_image(&map.gnd()?);
+            let output = output.unwrap_or_else(|| format!("{}_height.png", map.name).into());
+            let report = save_image(&image, &output)?;
+            show_export(&report, cli.json);
+        }
+        Commands::Walkability { map, output } => {
+            let map = MapFiles::open(grf, &map);
+            let image = walkability_image(&map.gat()?);
+            let output = output.unwrap_or_else(|| format!("{}_walk.png", map.name).into());
+            let report = save_image(&image, &output)?;
+            show_export(&report, cli.json);
+        }
+        Commands::Objects { map } => {
+            let map = MapFiles::open(grf, &map);
+            let report = objects_report(&map.rsw()?.objects);
+            if cli.json {
+                print_json(&report);
+            } else {
+                show_objects(&report);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_json<T: Serialize>(report: &T) {
+    match serde_json::to_string_pretty(report) {
+        Ok(text) => println!("{}", text),
+        Err(e) => eprintln!("Error: failed to serialize output: {}", e),
+    }
+}
+
+fn load_grf(path: &Path) -> Result<GrfFile> {
+    GrfFile::from_path(path.to_path_buf())
+        .with_context(|| format!("Failed to load GRF file: {}", path.display()))
+}
+
+/// Where a map's files are read from.
+enum MapSource {
+    Grf(GrfFile),
+    Dir(PathBuf),
+}
+
+/// The three files of one map, found next to each other by name.
+struct MapFiles {
+    source: MapSource,
+    /// Map name without extension, e.g. `prontera`.
+    name: String,
+}
+
+impl MapFiles {
+    /// With a GRF, `map` is a name under `data\`; otherwise a path whose
+    /// directory holds the sibling files. Any extension is dropped.
+    fn open(grf: Option<GrfFile>, map: &str) -> Self {
+        let path = Path::new(map);
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| map.to_string());
+        let source = match grf {
+            Some(grf) => MapSource::Grf(grf),
+            None => MapSource::Dir(path.parent().unwrap_or(Path::new("")).to_path_buf()),
+        };
+        Self { source, name }
+    }
+
+    fn file_name(&self, extension: &str) -> String {
+        format!("{}.{}", self.name, extension)
+    }
+
+    fn read(&self, file_name: &str) -> Result<Vec<u8>> {
+        match &self.source {
+            MapSource::Grf(grf) => grf
+                .get_file(&format!("data\\{}", file_name))
+                .ok_or_else(|| anyhow!("data\\{} is not in the GRF", file_name)),
+            MapSource::Dir(dir) => {
+                let path = dir.join(file_name);
+                fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))
+            }
+        }
+    }
+
+    fn rsw(&self) -> Result<RoWorld> {
+        let file = self.file_name("rsw");
+        RoWorld::from_bytes(&self.read(&file)?).with_context(|| format!("Failed to parse {file}"))
+    }
+
+    fn gnd(&self) -> Result<RoGround> {
+        let file = self.file_name("gnd");
+        RoGround::from_bytes(&self.read(&file)?).with_context(|| format!("Failed to parse {file}"))
+    }
+
+    fn gat(&self) -> Result<RoAltitude> {
+        let file = self.file_name("gat");
+        RoAltitude::from_bytes(&self.read(&file)?)
+            .with_context(|| format!("Failed to parse {file}"))
+    }
+}
+
+/// Lowest and highest value seen; `None` for an empty map.
+#[derive(Serialize, Debug, PartialEq)]
+struct HeightRange {
+    min: f32,
+    max: f32,
+}
+
+fn height_range(heights: impl Iterator<Item = f32>) -> Option<HeightRange> {
+    heights.fold(None, |range, h| match range {
+        None => Some(HeightRange { min: h, max: h }),
+        Some(r) => Some(HeightRange {
+            min: r.min.min(h),
+            max: r.max.max(h),
+        }),
+    })
+}
+
+#[derive(Serialize)]
+struct RswStats {
+    version: String,
+    /// Ground and altitude files the RSW names; normally `<map>.gnd`/`<map>.gat`.
+    gnd_file: String,
+    gat_file: String,
+    water_level: f32,
+    models: usize,
+    lights: usize,
+    sounds: usize,
+    effects: usize,
+}
+
+#[derive(Serialize)]
+struct GndStats {
+    version: String,
+    width: u32,
+    height: u32,
+    textures: usize,
+    tiles: usize,
+    surfaces: usize,
+    heights: Option<HeightRange>,
+}
+
+#[derive(Serialize)]
+struct GatStats {
+    version: String,
+    width: u32,
+    height: u32,
+    walkable_cells: usize,
+    water_cells: usize,
+    blocked_cells: usize,
+    heights: Option<HeightRange>,
+}
+
+#[derive(Serialize)]
+struct FileError {
+    file: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct StatsReport {
+    map: String,
+    rsw: Option<RswStats>,
+    gnd: Option<GndStats>,
+    gat: Option<GatStats>,
+    /// Files that could not be read or parsed, the reason a map fails to load.
+    errors: Vec<FileError>,
+}
+
+fn rsw_stats(rsw: &RoWorld) -> RswStats {
+    let count = |matches: fn(&RswObject) -> bool| rsw.objects.iter().filter(|o| matches(o)).count();
+    RswStats {
+        version: rsw.version.clone(),
+        gnd_file: rsw.gnd_file.clone(),
+        gat_file: rsw.gat_file.clone(),
+        water_level: rsw.water.level,
+        models: count(|o| matches!(o, RswObject::Model(_))),
+        lights: count(|o| matches!(o, RswObject::Light(_))),
+        sounds: count(|o| matches!(o, RswObject::Sound(_))),
+        effects: count(|o| matches!(o, RswObject::Effect(_))),
+    }
+}
+
+fn gnd_stats(gnd: &RoGround) -> GndStats {
+    GndStats {
+        version: gnd.version.clone(),
+        width: gnd.width,
+        height: gnd.height,
+        textures: gnd.textures.len(),
+        tiles: gnd.tiles.len(),
+        surfaces: gnd.surfaces.len(),
+        heights: height_range(gnd.surfaces.iter().flat_map(|s| s.height)),
+    }
+}
+
+fn gat_stats(gat: &RoAltitude) -> GatStats {
+    let cells = || gat.cells.iter().map(|cell| cell.cell_type);
+    GatStats {
+        version: gat.version.clone(),
+        width: gat.width,
+        height: gat.height,
+        walkable_cells: cells().filter(|t| t.is_walkable()).count(),
+        water_cells: cells().filter(|t| t.is_water()).count(),
+        blocked_cells: cells().filter(|t| !t.is_walkable()).count(),
+        heights: height_range(gat.cells.iter().flat_map(|c| c.height)),
+    }
+}
+
+/// `result`'s value, or `None` with its error recorded against `file`.
+fn keep<T>(errors: &mut Vec<FileError>, file: String, result: Result<T>) -> Option<T> {
+    result
+        .map_err(|e| {
+            errors.push(FileError {
+                file,
+                error: format!("{:#}", e),
+            })
+        })
+        .ok()
+}
+
+fn stats_report(map: &MapFiles) -> StatsReport {
+    let mut errors = Vec::new();
+    let rsw = map.rsw().map(|rsw| rsw_stats(&rsw));
+    let rsw = keep(&mut errors, map.file_name("rsw"), rsw);
+    let gnd = map.gnd().map(|gnd| gnd_stats(&gnd));
+    let gnd = keep(&mut errors, map.file_name("gnd"), gnd);
+    let gat = map.gat().map(|gat| gat_stats(&gat));
+    let gat = keep(&mut errors, map.file_name("gat"), gat);
+    StatsReport {
+        map: map.name.clone(),
+        rsw,
+        gnd,
+        gat,
+        errors,
+    }
+}
+
+fn show_range(range: &Option<HeightRange>) -> String {
+    match range {
+        Some(r) => format!("{:.1} .. {:.1}", r.min, r.max),
+        None => "-".to_string(),
+    }
+}
+
+fn show_stats(report: &StatsReport) {
+    println!("Map: {}", report.map);
+    println!("{:=<80}", "");
+    if let Some(rsw) = &report.rsw {
+        println!("RSW {}", rsw.version);
+        println!("  Ground/altitude: {} / {}", rsw.gnd_file, rsw.gat_file);
+        println!("  Water level:     {:.1}", rsw.water_level);
+        println!(
+            "  Objects:         {} models, {} lights, {} sounds, {} effects",
+            rsw.models, rsw.lights, rsw.sounds, rsw.effects
+        );
+    }
+    if let Some(gnd) = &report.gnd {
+        println!("GND {}", gnd.version);
+        println!("  Size:            {}x{}", gnd.width, gnd.height);
+        println!(
+            "  Textures:        {} ({} tiles, {} surfaces)",
+            gnd.textures, gnd.tiles, gnd.surfaces
+        );
+        println!("  Heights:         {}", show_range(&gnd.heights));
+    }
+    if let Some(gat) = &report.gat {
+        println!("GAT {}", gat.version);
+        println!("  Size:            {}x{}", gat.width, gat.height);
+        println!(
+            "  Cells:           {} walkable ({} water), {} blocked",
+            gat.walkable_cells, gat.water_cells, gat.blocked_cells
+        );
+        println!("  Heights:         {}", show_range(&gat.heights));
+    }
+    for error in &report.errors {
+        println!("  ✗ {}: {}", error.file, error.error);
+    }
+    println!("{:=<80}", "");
+}
+
+/// Image row for map row `y`: maps grow northward from y = 0, images downward.
+fn image_row(y: u32, height: u32) -> u32 {
+    height - 1 - y
+}
+
+/// One pixel per GND cube, its corners averaged. RO altitudes grow downward, so
+/// the smallest value is the highest ground and comes out white.
+fn height_image(gnd: &RoGround) -> GrayImage {
+    let heights: Vec<f32> = gnd
+        .surfaces
+        .iter()
+        .map(|s| s.height.iter().sum::<f32>() / 4.0)
+        .collect();
+    let range = height_range(heights.iter().copied());
+    let mut image = GrayImage::new(gnd.width, gnd.height);
+    for (i, h) in heights
+        .iter()
+        .enumerate()
+        .take((gnd.width * gnd.height) as usize)
+    {
+        let shade = match &range {
+            Some(r) if r.max > r.min => (r.max - h) / (r.max - r.min) * 255.0,
+            _ => 128.0,
+        };
+        let (x, y) = (i as u32 % gnd.width, i as u32 / gnd.width);
+        image.put_pixel(x, image_row(y, gnd.height), Luma([shade.round() as u8]));
+    }
+    image
+}
+
+const WALKABLE: Rgb<u8> = Rgb([255, 255, 255]);
+const WATER: Rgb<u8> = Rgb([48, 112, 255]);
+const SHOOT_OVER: Rgb<u8> = Rgb([128, 128, 128]);
+const BLOCKED: Rgb<u8> = Rgb([0, 0, 0]);
+
+/// One pixel per GAT cell.
+fn walkability_image(gat: &RoAltitude) -> RgbImage {
+    let mut image = RgbImage::new(gat.width, gat.height);
+    for (i, cell) in gat
+        .cells
+        .iter()
+        .enumerate()
+        .take((gat.width * gat.height) as usize)
+    {
+        let cell = cell.cell_type;
+        let color = if cell.is_water() {
+            WATER
+        } else if cell.is_walkable() {
+            WALKABLE
+        } else if cell.is_snipable() {
+            SHOOT_OVER
+        } else {
+            BLOCKED
+        };
+        let (x, y) = (i as u32 % gat.width, i as u32 / gat.width);
+        image.put_pixel(x, image_row(y, gat.height), color);
+    }
+    image
+}
+
+#[derive(Serialize)]
+struct ExportReport {
+    output: PathBuf,
+    width: u32,
+    height: u32,
+}
+
+fn save_image<P>(image: &image::ImageBuffer<P, Vec<u8>>, output: &Path) -> Result<ExportReport>
+where
+    P: image::PixelWithColorType<Subpixel = u8>,
+{
+    image
+        .save(output)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+    Ok(ExportReport {
+        output: output.to_path_buf(),
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+fn show_export(report: &ExportReport, json: bool) {
+    if json {
+        print_json(report);
+    } else {
+        println!(
+            "Wrote {} ({}x{})",
+            report.output.display(),
+            report.width,
+            report.height
+        );
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct ModelUse {
+    file: String,
+    /// How many times the RSW places it.
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct LightEntry {
+    name: String,
+    position: [f32; 3],
+    color: [f32; 3],
+    range: f32,
+}
+
+#[derive(Serialize)]
+struct SoundEntry {
+    name: String,
+    file: String,
+    position: [f32; 3],
+    volume: f32,
+    range: f32,
+}
+
+#[derive(Serialize)]
+struct EffectEntry {
+    name: String,
+    effect_type: u32,
+    position: [f32; 3],
+}
+
+#[derive(Serialize)]
+struct ObjectsReport {
+    /// Distinct RSM files, sorted by path.
+    models: Vec<ModelUse>,
+    lights: Vec<LightEntry>,
+    sounds: Vec<SoundEntry>,
+    effects: Vec<EffectEntry>,
+}
+
+fn objects_report(objects: &[RswObject]) -> ObjectsReport {
+    let mut models: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut report = ObjectsReport {
+        models: Vec::new(),
+        lights: Vec::new(),
+        sounds: Vec::new(),
+        effects: Vec::new(),
+    };
+    for object in objects {
+        match object {
+            RswObject::Model(model) => *models.entry(&model.filename).or_default() += 1,
+            RswObject::Light(light) => report.lights.push(LightEntry {
+                name: light.name.clone(),
+                position: light.position,
+                color: light.color,
+                range: light.range,
+            }),
+            RswObject::Sound(sound) => report.sounds.push(SoundEntry {
+                name: sound.name.clone(),
+                file: sound.wav_file.clone(),
+                position: sound.position,
+                volume: sound.volume,
+                range: sound.range,
+            }),
+            RswObject::Effect(effect) => report.effects.push(EffectEntry {
+                name: effect.name.clone(),
+                effect_type: effect.effect_type,
+                position: effect.position,
+            }),
+        }
+    }
+    report.models = models
+        .into_iter()
+        .map(|(file, count)| ModelUse {
+            file: file.to_string(),
+            count,
+        })
+        .collect();
+    report
+}
+
+fn show_objects(report: &ObjectsReport) {
+    println!("Models ({}):", report.models.len());
+    for model in &report.models {
+        println!("  {:<60} x{}", model.file, model.count);
+    }
+    println!("Lights ({}):", report.lights.len());
+    for light in &report.lights {
+        println!(
+            "  {:<40} at {:?} range {:.1}",
+            light.name, light.position, light.range
+        );
+    }
+    println!("Sounds ({}):", report.sounds.len());
+    for sound in &report.sounds {
+        println!(
+            "  {:<40} {} at {:?}",
+            sound.name, sound.file, sound.position
+        );
+    }
+    println!("Effects ({}):", report.effects.len());
+    for effect in &report.effects {
+        println!(
+            "  {:<40} type {} at {:?}",
+            effect.name, effect.effect_type, effect.position
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ro_formats::{GatCell, GatCellType, GndSurface, RswModel, RswSound};
+
+    fn surface(height: f32) -> GndSurface {
+        GndSurface {
+            height: [height; 4],
+            tile_up: -1,
+            tile_front: -1,
+            tile_right: -1,
+        }
+    }
+
+    fn cell(raw_type: u32) -> GatCell {
+        GatCell {
+            height: [0.0; 4],
+            cell_type: GatCellType::from(raw_type),
+        }
+    }
+
+    #[test]
+    fn heightmap_puts_the_highest_ground_in_white_with_north_up() {
+        let gnd = RoGround {
+            version: "1.7".into(),
+            width: 2,
+            height: 2,
+            textures: Vec::new(),
+            texture_indexes: Vec::new(),
+            tiles: Vec::new(),
+            // Row y = 0 (south) first; -20 is the highest ground.
+            surfaces: vec![surface(0.0), surface(-10.0), surface(-20.0), surface(0.0)],
+        };
+        let image = height_image(&gnd);
+        assert_eq!(image.get_pixel(0, 1), &Luma([0]));
+        assert_eq!(image.get_pixel(1, 1), &Luma([128]));
+        assert_eq!(image.get_pixel(0, 0), &Luma([255]));
+    }
+
+    #[test]
+    fn walkability_colors_each_cell_type() {
+        let gat = RoAltitude {
+            version: "1.2".into(),
+            width: 4,
+            height: 1,
+            cells: vec![cell(0), cell(1), cell(3), cell(5)],
+        };
+        let image = walkability_image(&gat);
+        let colors: Vec<_> = (0..4).map(|x| *image.get_pixel(x, 0)).collect();
+        assert_eq!(colors, [WALKABLE, BLOCKED, WATER, SHOOT_OVER]);
+        assert_eq!(gat_stats(&gat).walkable_cells, 2);
+    }
+
+    #[test]
+    fn objects_group_models_by_file() {
+        let model = |filename: &str| {
+            RswObject::Model(RswModel {
+                name: String::new(),
+                anim_type: 0,
+                anim_speed: 1.0,
+                block_type: 0,
+                filename: filename.into(),
+                node_name: String::new(),
+                position: [0.0; 3],
+                rotation: [0.0; 3],
+                scale: [1.0; 3],
+            })
+        };
+        let objects = [
+            model("tree.rsm"),
+            model("house.rsm"),
+            model("tree.rsm"),
+            RswObject::Sound(RswSound {
+                name: "fountain".into(),
+                wav_file: "water.wav".into(),
+                position: [0.0; 3],
+                volume: 1.0,
+                width: 0,
+                height: 0,
+                range: 50.0,
+                cycle: 4.0,
+            }),
+        ];
+        let report = objects_report(&objects);
+        assert_eq!(
+            report.models,
+            [
+                ModelUse {
+                    file: "house.rsm".into(),
+                    count: 1
+                },
+                ModelUse {
+                    file: "tree.rsm".into(),
+                    count: 2
+                },
+            ]
+        );
+        assert_eq!(report.sounds[0].file, "water.wav");
+    }
+
+    #[test]
+    fn stats_reports_missing_files_instead_of_failing() {
+        let dir = std::env::temp_dir().join(format!("map-utils-{}-missing", std::process::id()));
+        let map = MapFiles::open(None, &dir.join("nowhere.rsw").to_string_lossy());
+        let report = stats_report(&map);
+        assert_eq!(report.map, "nowhere");
+        let files: Vec<_> = report.errors.iter().map(|e| e.file.as_str()).collect();
+        assert_eq!(files, ["nowhere.rsw", "nowhere.gnd", "nowhere.gat"]);
+    }
+}
@@ -0,0 +1,409 @@
+//! Packet capture and replay for protocol debugging.
+//!
+//! Set `LIFTHRASIR_PACKET_CAPTURE=<file>` to record every inbound and outbound
+//! aesir frame of the session to a binary log. The log holds the raw envelope
+//! bytes, so it decodes exactly as the live session did.
+//!
+//! Credentials never reach the log: [`redact_frame`] blanks passwords, session
+//! ids and auth tokens before a frame is written.
+//!
+//! Layout: the [`CAPTURE_MAGIC`] header, then one record per frame:
+//! `direction: u8 | channel: u8 | elapsed_ms: u64 LE | len: u32 LE | frame`.
+//!
+//! [`read_capture`] parses a log back into [`CapturedPacket`]s and
+//! [`replay_inbound`] turns the inbound ones into [`IncomingMessage`]s. A test can
+//! then write them into an app and run the same flow systems as a live session.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_add_system;
+use bytes::Bytes;
+
+use crate::character::QuicCharState;
+use crate::connection::QuicConnection;
+use crate::dispatch::IncomingMessage;
+use crate::envelope::{self, Body};
+use crate::login::QuicLoginState;
+use crate::zone::QuicZoneState;
+
+/// File header: format tag plus version byte.
+pub const CAPTURE_MAGIC: &[u8; 5] = b"LFPC\x01";
+
+const RECORD_HEADER_LEN: usize = 1 + 1 + 8 + 4;
+
+/// Which way a captured frame travelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Inbound,
+    Outbound,
+}
+
+impl Direction {
+    fn tag(self) -> u8 {
+        match self {
+            Self::Inbound => 0,
+            Self::Outbound => 1,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Inbound),
+            1 => Some(Self::Outbound),
+            _ => None,
+        }
+    }
+}
+
+/// One frame from a capture log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedPacket {
+    pub direction: Direction,
+    pub channel: u8,
+    /// Milliseconds since the capture started.
+    pub elapsed_ms: u64,
+    /// Raw envelope bytes as sent or received.
+    pub frame: Bytes,
+}
+
+/// Append one record to `out`.
+pub fn write_record(out: &mut impl Write, packet: &CapturedPacket) -> io::Result<()> {
+    let len = u32::try_from(packet.frame.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+    out.write_all(&[packet.direction.tag(), packet.channel])?;
+    out.write_all(&packet.elapsed_ms.to_le_bytes())?;
+    out.write_all(&len.to_le_bytes())?;
+    out.write_all(&packet.frame)
+}
+
+/// Parse a whole capture log.
+pub fn read_capture(bytes: &[u8]) -> io::Result<Vec<CapturedPacket>> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    let mut rest = bytes
+        .strip_prefix(CAPTURE_MAGIC.as_slice())
+        .ok_or_else(|| invalid("not a packet capture"))?;
+    let mut packets = Vec::new();
+    while !rest.is_empty() {
+        if rest.len() < RECORD_HEADER_LEN {
+            return Err(invalid("truncated record header"));
+        }
+        let (header, tail) = rest.split_at(RECORD_HEADER_LEN);
+        let direction =
+            Direction::from_tag(header[0]).ok_or_else(|| invalid("unknown direction"))?;
+        let elapsed_ms = u64::from_le_bytes(header[2..10].try_into().expect("8 bytes"));
+        let len = u32::from_le_bytes(header[10..14].try_into().expect("4 bytes")) as usize;
+        if tail.len() < len {
+            return Err(invalid("truncated frame"));
+        }
+        let (frame, tail) = tail.split_at(len);
+        packets.push(CapturedPacket {
+            direction,
+            channel: header[1],
+            elapsed_ms,
+            frame: Bytes::copy_from_slice(frame),
+        });
+        rest = tail;
+    }
+    Ok(packets)
+}
+
+/// `frame` with its credentials blanked: the login password, session ids and
+/// auth tokens. Frames without any, and frames that don't decode, are returned
+/// as they are.
+pub fn redact_frame(frame: Bytes) -> Bytes {
+    let Ok(envelope) = envelope::decode(&frame) else {
+        return frame;
+    };
+    let Some(mut body) = envelope.body else {
+        return frame;
+    };
+    match &mut body {
+        Body::LoginRequest(request) => request.password.clear(),
+        Body::LoginResponse(response) => {
+            response.login_id1 = 0;
+            response.login_id2 = 0;
+            response.auth_token.clear();
+        }
+        Body::SessionAuth(auth) => {
+            auth.login_id1 = 0;
+            auth.login_id2 = 0;
+            auth.zone_auth_token.clear();
+        }
+        Body::ZoneServerInfo(info) => info.auth_token.clear(),
+        _ => return frame,
+    }
+    envelope::encode(envelope.seq, body)
+}
+
+/// Decode the inbound frames of a capture into the messages the flow systems read.
+pub fn replay_inbound(packets: &[CapturedPacket]) -> Vec<IncomingMessage> {
+    packets
+        .iter()
+        .filter(|packet| packet.direction == Direction::Inbound)
+        .filter_map(|packet| {
+            let body = QuicConnection::decode_frame(packet.channel, &packet.frame)?;
+            Some(IncomingMessage {
+                channel: packet.channel,
+                body,
+            })
+        })
+        .collect()
+}
+
+/// Live recorder; present only while a capture is running.
+#[derive(Resource)]
+pub struct PacketCapture {
+    out: Box<dyn Write + Send + Sync>,
+    started: Instant,
+}
+
+impl PacketCapture {
+    /// Start a capture on `out`, writing the header immediately.
+    pub fn new(mut out: Box<dyn Write + Send + Sync>) -> io::Result<Self> {
+        out.write_all(CAPTURE_MAGIC)?;
+        Ok(Self {
+            out,
+            started: Instant::now(),
+        })
+    }
+
+    /// Record `frame`, redacted. A write error stops the capture rather than
+    /// the session.
+    pub fn record(&mut self, direction: Direction, channel: u8, frame: Bytes) -> io::Result<()> {
+        let packet = CapturedPacket {
+            direction,
+            channel,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            frame: redact_frame(frame),
+        };
+        write_record(&mut self.out, &packet)
+    }
+}
+
+fn capture_path() -> Option<PathBuf> {
+    std::env::var_os("LIFTHRASIR_PACKET_CAPTURE").map(PathBuf::from)
+}
+
+/// Opens the capture file when `LIFTHRASIR_PACKET_CAPTURE` is set.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Startup)]
+pub fn open_packet_capture(mut commands: Commands) {
+    let Some(path) = capture_path() else {
+        return;
+    };
+    let capture =
+        File::create(&path).and_then(|file| PacketCapture::new(Box::new(BufWriter::new(file))));
+    match capture {
+        Ok(capture) => {
+            info!("capturing aesir packets to {}", path.display());
+            commands.insert_resource(capture);
+        }
+        Err(e) => error!("failed to open packet capture {}: {e}", path.display()),
+    }
+}
+
+/// Turns the connections' outboxes on while a capture runs, and off (and
+/// empty) otherwise, so nothing is buffered without a capture to write it.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = First)]
+pub fn sync_capture_outboxes(
+    capture: Option<Res<PacketCapture>>,
+    mut login: ResMut<QuicLoginState>,
+    mut char_state: ResMut<QuicCharState>,
+    mut zone: ResMut<QuicZoneState>,
+) {
+    let capturing = capture.is_some();
+    for conn in [&mut login.conn, &mut char_state.conn, &mut zone.conn] {
+        conn.set_capturing(capturing);
+    }
+}
+
+/// Collects the frames each connection sent this frame.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Last)]
+pub fn record_outbound_packets(
+    mut capture: Option<ResMut<PacketCapture>>,
+    mut login: ResMut<QuicLoginState>,
+    mut char_state: ResMut<QuicCharState>,
+    mut zone: ResMut<QuicZoneState>,
+    mut commands: Commands,
+) {
+    let sent: Vec<_> = [&mut login.conn, &mut char_state.conn, &mut zone.conn]
+        .into_iter()
+        .flat_map(QuicConnection::take_sent)
+        .collect();
+    let Some(capture) = capture.as_mut() else {
+        return;
+    };
+    for (channel, frame) in sent {
+        if let Err(e) = capture.record(Direction::Outbound, channel, frame) {
+            error!("packet capture stopped: {e}");
+            commands.remove_resource::<PacketCapture>();
+            return;
+        }
+    }
+    if let Err(e) = capture.out.flush() {
+        error!("packet capture stopped: {e}");
+        commands.remove_resource::<PacketCapture>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channels::{CONTROL, WORLD};
+    use crate::proto::aesir::net;
+    use crate::zone::flow::npc::zone_drain_npc;
+    use net_contract::events::{NpcCutinReceived, NpcDialogReceived};
+    use std::sync::{Arc, Mutex};
+
+    /// Shared in-memory sink so a test can read back what the recorder wrote.
+    #[derive(Clone, Default)]
+    struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn dialog_frame() -> Bytes {
+        envelope::encode(
+            7,
+            Body::NpcDialog(net::NpcDialog {
+                npc_id: 150001,
+                text: "hello".into(),
+                expect: net::npc_dialog::Expect::Next as i32,
+                options: vec![],
+            }),
+        )
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let packets = vec![
+            CapturedPacket {
+                direction: Direction::Outbound,
+                channel: CONTROL,
+                elapsed_ms: 0,
+                frame: envelope::encode(0, Body::MapLoaded(net::MapLoaded {})),
+            },
+            CapturedPacket {
+                direction: Direction::Inbound,
+                channel: WORLD,
+                elapsed_ms: 1_234,
+                frame: dialog_frame(),
+            },
+        ];
+        let mut log = CAPTURE_MAGIC.to_vec();
+        for packet in &packets {
+            write_record(&mut log, packet).unwrap();
+        }
+        assert_eq!(read_capture(&log).unwrap(), packets);
+    }
+
+    #[test]
+    fn credentials_are_redacted_before_writing() {
+        let sink = SharedSink::default();
+        let mut capture = PacketCapture::new(Box::new(sink.clone())).unwrap();
+        let login = envelope::encode(
+            3,
+            Body::LoginRequest(net::LoginRequest {
+                username: "player".into(),
+                password: "hunter2".into(),
+                client_version: 1,
+            }),
+        );
+        capture.record(Direction::Outbound, CONTROL, login).unwrap();
+        capture
+            .record(
+                Direction::Inbound,
+                CONTROL,
+                envelope::encode(
+                    4,
+                    Body::ZoneServerInfo(net::ZoneServerInfo {
+                        auth_token: b"one-time".to_vec(),
+                        ..Default::default()
+                    }),
+                ),
+            )
+            .unwrap();
+
+        let log = sink.0.lock().unwrap().clone();
+        let packets = read_capture(&log).unwrap();
+        let bodies: Vec<_> = packets
+            .iter()
+            .map(|p| envelope::decode(&p.frame).unwrap())
+            .collect();
+        assert_eq!(bodies[0].seq, 3);
+        match &bodies[0].body {
+            Some(Body::LoginRequest(request)) => {
+                assert_eq!(request.username, "player");
+                assert!(request.password.is_empty());
+            }
+            other => panic!("expected LoginRequest, got {other:?}"),
+        }
+        match &bodies[1].body {
+            Some(Body::ZoneServerInfo(info)) => assert!(info.auth_token.is_empty()),
+            other => panic!("expected ZoneServerInfo, got {other:?}"),
+        }
+        assert!(!log.windows(7).any(|w| w == b"hunter2"));
+    }
+
+    #[test]
+    fn rejects_foreign_and_truncated_logs() {
+        assert!(read_capture(b"nope").is_err());
+        let mut log = CAPTURE_MAGIC.to_vec();
+        log.extend_from_slice(&[0, 1, 0]);
+        assert!(read_capture(&log).is_err());
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_logs_never_panic(
+            tail in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..256),
+        ) {
+            let mut log = CAPTURE_MAGIC.to_vec();
+            log.extend_from_slice(&tail);
+            let _ = read_capture(&log);
+        }
+    }
+
+    #[test]
+    fn recorded_session_replays_through_the_flow_systems() {
+        let sink = SharedSink::default();
+        let mut capture = PacketCapture::new(Box::new(sink.clone())).unwrap();
+        capture
+            .record(Direction::Inbound, WORLD, dialog_frame())
+            .unwrap();
+        capture
+            .record(
+                Direction::Outbound,
+                CONTROL,
+                envelope::encode(1, Body::MapLoaded(net::MapLoaded {})),
+            )
+            .unwrap();
+
+        let packets = read_capture(&sink.0.lock().unwrap()).unwrap();
+        let mut app = App::new();
+        app.add_message::<IncomingMessage>()
+            .add_message::<NpcDialogReceived>()
+            .add_message::<NpcCutinReceived>()
+            .add_systems(Update, zone_drain_npc);
+        for message in replay_inbound(&packets) {
+            app.world_mut().write_message(message);
+        }
+        app.update();
+
+        let dialog = app.world().resource::<Messages<NpcDialogReceived>>();
+        let events: Vec<_> = dialog.iter_current_update_messages().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].text, "hello");
+    }
+}
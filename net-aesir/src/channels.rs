@@ -6,7 +6,10 @@ pub const WORLD: u8 = 2;
 pub const BULK: u8 = 3;
 pub const SNAPSHOTS: u8 = 4;
 
-/// The 5 channels in aesir's fixed order; quinnet assigns ids 0..4 by position.
+/// How many channels aesir opens; ids run `0..CHANNEL_COUNT`.
+pub const CHANNEL_COUNT: usize = 5;
+
+/// The channels in aesir's fixed order; quinnet assigns ids by position.
 pub fn channel_configs() -> Vec<ChannelConfig> {
     vec![
         ChannelConfig::default_ordered_reliable(), // CONTROL
@@ -27,8 +30,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn config_has_five_channels() {
-        assert_eq!(channel_configs().len(), 5);
+    fn config_has_every_channel() {
+        assert_eq!(channel_configs().len(), CHANNEL_COUNT);
     }
 
     #[test]
@@ -14,7 +14,8 @@ use super::{CharPhase, QuicCharState};
 use crate::channels::CONTROL;
 use crate::dispatch::IncomingMessage;
 use crate::envelope::Body;
-use crate::proto::aesir::net::{Hello, SessionAuth};
+use crate::proto::aesir::net::SessionAuth;
+use crate::protocol;
 use net_contract::events::{
     CharacterCreated, CharacterCreationFailed, CharacterDeleted, CharacterDeletionFailed,
     CharacterServerConnected, CharacterSlotInfoReceived, ZoneDisconnected, ZoneServerInfoReceived,
@@ -55,10 +56,7 @@ pub fn char_send_hello(
         if state.phase != CharPhase::Connecting {
             continue;
         }
-        let hello = Body::Hello(Hello {
-            protocol_version: 1,
-            build: "lifthrasir".into(),
-        });
+        let hello = protocol::hello("lifthrasir".into());
         if let Err(e) = state.conn.send(client.connection_mut(), CONTROL, hello) {
             error!("failed to send char Hello: {e}");
             state.phase = CharPhase::Failed;
@@ -126,10 +124,13 @@ pub fn char_drain_control(
             }
             Body::CharList(list) => {
                 // Emit the roster on every list (initial + create/delete refreshes) so
-                // the domain rebuilds its char-select view; slot info only changes on the
-                // initial list, so keep that initial-only.
+                // the domain rebuilds its char-select view, except an unchanged answer to
+                // a keep-alive refresh; slot info only changes on the initial list, so
+                // keep that initial-only.
                 let initial = state.phase == CharPhase::AuthSent;
-                connected.write(char_list_to_connected(&list));
+                if state.accept_list(&list) {
+                    connected.write(char_list_to_connected(&list));
+                }
                 if initial {
                     slot_info.write(char_list_to_slot_info(&list));
                 }
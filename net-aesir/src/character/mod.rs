@@ -1,17 +1,16 @@
 pub mod flow;
 pub mod mapping;
 
+use std::net::SocketAddr;
+
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_init_resource;
-use bevy_quinnet::client::certificate::CertificateVerificationMode;
-use bevy_quinnet::client::connection::{ClientAddrConfiguration, ConnectionLocalId};
-use bevy_quinnet::client::{
-    ClientConnectionConfiguration, ClientConnectionConfigurationDefaultables, QuinnetClient,
-};
-use bevy_quinnet::shared::error::AsyncChannelError;
+use bevy_quinnet::client::QuinnetClient;
+use bevy_quinnet::client::connection::ConnectionLocalId;
+use net_contract::dto::NetworkError;
 
-use crate::channels;
-use crate::connection::QuicConnection;
+use crate::connection::{self, QuicConnection, Resolving};
+use crate::proto::aesir::net::CharList;
 
 /// Phase of the long-lived QUIC char-server session.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -44,38 +43,44 @@ pub struct QuicCharState {
     pub phase: CharPhase,
     pub conn: QuicConnection,
     pub auth: PendingAuth,
+    /// The last roster the server sent.
+    pub(crate) last_list: Option<CharList>,
+    /// Keep-alive `CharListRefresh`es whose answer hasn't arrived yet.
+    pub(crate) keep_alive_pending: u32,
+    /// The server address lookup, until the connection can be opened.
+    pub resolving: Option<Resolving>,
 }
 
 impl QuicCharState {
     /// Begin a fresh char-server session: reset the seq counter, stash credentials,
     /// and arm the `Connecting` phase so `char_send_hello` fires once the connection opens.
     pub fn start_connecting(&mut self, auth: PendingAuth) {
-        self.conn = QuicConnection::default();
+        self.resolving = None;
+        self.conn.reset();
         self.auth = auth;
         self.phase = CharPhase::Connecting;
+        self.last_list = None;
+        self.keep_alive_pending = 0;
+    }
+
+    /// Record a received roster. False when it answers a keep-alive refresh
+    /// and matches the last one, so the domain needn't rebuild its view.
+    pub(crate) fn accept_list(&mut self, list: &CharList) -> bool {
+        let keep_alive_answer = self.keep_alive_pending > 0;
+        self.keep_alive_pending = self.keep_alive_pending.saturating_sub(1);
+        let unchanged = self.last_list.as_ref() == Some(list);
+        self.last_list = Some(list.clone());
+        !(keep_alive_answer && unchanged)
     }
 }
 
-/// Opens the QUIC connection to the aesir char server.
-///
-/// Closes any existing connection first so the new char connection becomes the
-/// unambiguous default for `client.connection_mut()` (one-active-connection invariant;
-/// login leaves its connection open). Dev cert handling: `SkipVerification` (self-signed).
+/// Opens the QUIC connection to the aesir char server (see
+/// [`connection::open`]); login leaves its connection open, which this closes.
 pub fn connect(
     client: &mut QuinnetClient,
-    addr: &str,
-) -> Result<ConnectionLocalId, AsyncChannelError> {
-    client.close_all_connections();
-    let addr_config = ClientAddrConfiguration::from_strings(addr, "0.0.0.0:0")
-        .expect("valid char server address");
-    client.open_connection(ClientConnectionConfiguration {
-        addr_config,
-        cert_mode: CertificateVerificationMode::SkipVerification,
-        defaultables: ClientConnectionConfigurationDefaultables {
-            send_channels_cfg: channels::send_channels_config(),
-            ..Default::default()
-        },
-    })
+    server: SocketAddr,
+) -> Result<ConnectionLocalId, NetworkError> {
+    connection::open(client, server)
 }
 
 #[cfg(test)]
@@ -1,5 +1,16 @@
-use bevy::log::{debug, warn};
-use bevy_quinnet::client::{ClientSendError, connection::ClientSideConnection};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+
+use bevy::log::debug;
+use bevy::tasks::{IoTaskPool, Task, block_on, futures_lite::future::poll_once};
+use bevy_quinnet::client::certificate::CertificateVerificationMode;
+use bevy_quinnet::client::connection::{ClientAddrConfiguration, ConnectionLocalId};
+use bevy_quinnet::client::{
+    ClientConnectionConfiguration, ClientConnectionConfigurationDefaultables, ClientSendError,
+    QuinnetClient, connection::ClientSideConnection,
+};
+use bytes::Bytes;
+use net_contract::diagnostics::PacketTrace;
+use net_contract::dto::NetworkError;
 
 use super::{
     channels,
@@ -9,6 +20,14 @@ use super::{
 #[derive(Default)]
 pub struct QuicConnection {
     seq: u32,
+    /// Whether a packet capture wants this connection's outbound frames.
+    capturing: bool,
+    /// Frames handed to the transport since the last `take_sent`, kept for the
+    /// packet capture (cheap `Bytes` clones). Empty unless `capturing`.
+    sent: Vec<(u8, Bytes)>,
+    /// Every frame sent since the last `take_traced`, for the crash report's
+    /// packet trail.
+    traced: Vec<PacketTrace>,
 }
 
 impl QuicConnection {
@@ -16,7 +35,13 @@ impl QuicConnection {
         Self::default()
     }
 
-    pub(crate) fn next_frame(&mut self, body: Body) -> bytes::Bytes {
+    /// Restart the seq counter for a fresh connection. Frames not yet collected
+    /// by the capture are kept so a reconnect in the same frame loses none.
+    pub fn reset(&mut self) {
+        self.seq = 0;
+    }
+
+    pub(crate) fn next_frame(&mut self, body: Body) -> Bytes {
         let frame = envelope::encode(self.seq, body);
         self.seq += 1;
         frame
@@ -29,28 +54,43 @@ impl QuicConnection {
         body: Body,
     ) -> Result<(), ClientSendError> {
         let payload = self.next_frame(body);
+        self.traced.push(PacketTrace {
+            outbound: true,
+            channel,
+            tag: envelope::body_tag(&payload),
+        });
+        if self.capturing {
+            self.sent.push((channel, payload.clone()));
+        }
         conn.send_payload_on(channel, payload)
     }
 
-    pub fn drain(conn: &mut ClientSideConnection) -> Vec<(u8, Body)> {
-        let all_channels = [
-            channels::CONTROL,
-            channels::GAMEPLAY,
-            channels::WORLD,
-            channels::BULK,
-            channels::SNAPSHOTS,
-        ];
+    /// Start or stop keeping sent frames for the packet capture. Stopping
+    /// drops whatever was kept.
+    pub(crate) fn set_capturing(&mut self, capturing: bool) {
+        self.capturing = capturing;
+        if !capturing {
+            self.sent.clear();
+        }
+    }
+
+    /// Outbound frames sent since the last call, in send order.
+    pub(crate) fn take_sent(&mut self) -> Vec<(u8, Bytes)> {
+        std::mem::take(&mut self.sent)
+    }
+
+    /// Traces of the frames sent since the last call, in send order.
+    pub(crate) fn take_traced(&mut self) -> Vec<PacketTrace> {
+        std::mem::take(&mut self.traced)
+    }
+
+    /// Pops every pending payload off every channel, undecoded.
+    pub fn drain_raw(conn: &mut ClientSideConnection) -> Vec<(u8, Bytes)> {
         let mut out = Vec::new();
-        for ch in all_channels {
+        for ch in 0..channels::CHANNEL_COUNT as u8 {
             loop {
                 match conn.receive_payload(ch) {
-                    Ok(Some(bytes)) => match envelope::decode(&bytes) {
-                        Ok(env) => match env.body {
-                            Some(body) => out.push((ch, body)),
-                            None => warn!("received envelope with no body on channel {ch}"),
-                        },
-                        Err(e) => warn!("failed to decode envelope on channel {ch}: {e}"),
-                    },
+                    Ok(Some(bytes)) => out.push((ch, bytes)),
                     Ok(None) => break,
                     Err(e) => {
                         debug!("receive_payload closed on channel {ch}: {e}");
@@ -61,6 +101,86 @@ impl QuicConnection {
         }
         out
     }
+
+    /// Decodes one received frame, logging (and dropping) malformed or unknown ones.
+    pub fn decode_frame(channel: u8, bytes: &[u8]) -> Option<Body> {
+        envelope::decode_body(bytes)
+            .inspect_err(|e| envelope::report_bad_frame(channel, bytes, e, true))
+            .ok()
+    }
+
+    pub fn drain(conn: &mut ClientSideConnection) -> Vec<(u8, Body)> {
+        Self::drain_raw(conn)
+            .into_iter()
+            .filter_map(|(ch, bytes)| Some((ch, Self::decode_frame(ch, &bytes)?)))
+            .collect()
+    }
+}
+
+/// Resolves a `host:port` server address. The host may be a DNS name, an IPv4
+/// literal or a bracketed IPv6 literal (`[::1]:6900`); the first address the
+/// resolver returns wins.
+pub fn resolve_server_addr(addr: &str) -> Result<SocketAddr, NetworkError> {
+    let invalid = |reason: String| NetworkError::InvalidAddress {
+        address: addr.to_string(),
+        reason,
+    };
+    addr.to_socket_addrs()
+        .map_err(|e| invalid(e.to_string()))?
+        .next()
+        .ok_or_else(|| invalid("host resolved to no address".to_string()))
+}
+
+/// A server address being resolved on the [`IoTaskPool`], so a slow DNS lookup
+/// never stalls the frame that asked for the connection. Dropping it abandons
+/// the lookup.
+pub struct Resolving {
+    task: Task<Result<SocketAddr, NetworkError>>,
+}
+
+impl Resolving {
+    pub fn start(addr: &str) -> Self {
+        let addr = addr.to_string();
+        Self {
+            task: IoTaskPool::get().spawn(async move { resolve_server_addr(&addr) }),
+        }
+    }
+
+    /// The resolved address, once the lookup is done.
+    pub fn poll(&mut self) -> Option<Result<SocketAddr, NetworkError>> {
+        block_on(poll_once(&mut self.task))
+    }
+}
+
+/// Wildcard local bind in the same family as `server`: an IPv4 socket can't
+/// reach an IPv6 server, nor the other way round.
+fn local_bind_addr(server: SocketAddr) -> SocketAddr {
+    match server {
+        SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    }
+}
+
+/// Closes every open connection and opens one to `server`, so the new
+/// connection becomes the unambiguous default for `client.connection_mut()`
+/// (one-active-connection invariant). Dev cert handling: `SkipVerification`
+/// (self-signed). The send channels use aesir's fixed order so channel ids line
+/// up; recv channels keep their defaults.
+pub fn open(
+    client: &mut QuinnetClient,
+    server: SocketAddr,
+) -> Result<ConnectionLocalId, NetworkError> {
+    client.close_all_connections();
+    client
+        .open_connection(ClientConnectionConfiguration {
+            addr_config: ClientAddrConfiguration::from_addrs(server, local_bind_addr(server)),
+            cert_mode: CertificateVerificationMode::SkipVerification,
+            defaultables: ClientConnectionConfigurationDefaultables {
+                send_channels_cfg: channels::send_channels_config(),
+                ..Default::default()
+            },
+        })
+        .map_err(|e| NetworkError::ConnectionFailed(e.to_string()))
 }
 
 #[cfg(test)]
@@ -101,4 +221,39 @@ mod tests {
     fn decode_garbage_errors() {
         assert!(envelope::decode(b"not a protobuf").is_err());
     }
+
+    #[test]
+    fn resolves_ipv4_and_bracketed_ipv6_literals() {
+        let v4 = resolve_server_addr("127.0.0.1:6900").unwrap();
+        assert_eq!(v4, "127.0.0.1:6900".parse().unwrap());
+        assert!(local_bind_addr(v4).is_ipv4());
+
+        let v6 = resolve_server_addr("[::1]:6900").unwrap();
+        assert_eq!(v6, "[::1]:6900".parse().unwrap());
+        assert!(local_bind_addr(v6).is_ipv6());
+    }
+
+    #[test]
+    fn resolving_hands_back_the_address_once_done() {
+        IoTaskPool::get_or_init(bevy::tasks::TaskPool::new);
+        let mut resolving = Resolving::start("[::1]:6900");
+
+        let resolved = loop {
+            if let Some(resolved) = resolving.poll() {
+                break resolved;
+            }
+            std::thread::yield_now();
+        };
+        assert_eq!(resolved.unwrap(), "[::1]:6900".parse().unwrap());
+    }
+
+    #[test]
+    fn bad_address_is_a_typed_error_not_a_panic() {
+        for addr in ["::1:6900", "127.0.0.1", "127.0.0.1:port"] {
+            match resolve_server_addr(addr) {
+                Err(NetworkError::InvalidAddress { address, .. }) => assert_eq!(address, addr),
+                other => panic!("expected InvalidAddress for {addr}, got {other:?}"),
+            }
+        }
+    }
 }
@@ -0,0 +1,85 @@
+//! Publishes transport statistics to the `net_contract::diagnostics` paths:
+//! round-trip time per open quinn connection and datagrams per second in
+//! each direction.
+
+use std::collections::HashMap;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticsStore};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_add_system;
+use bevy_quinnet::client::QuinnetClient;
+use bevy_quinnet::client::connection::ConnectionLocalId;
+use net_contract::diagnostics::{NETWORK_RX_PACKETS, NETWORK_TX_PACKETS, connection_rtt_path};
+
+/// Datagram counters per connection at the last sample.
+#[derive(Default)]
+pub struct DatagramCounts(HashMap<ConnectionLocalId, (u64, u64)>);
+
+fn record(store: &mut DiagnosticsStore, diagnostic: Diagnostic, now: Instant, value: f64) {
+    let path = diagnostic.path().clone();
+    if store.get(&path).is_none() {
+        store.add(diagnostic);
+    }
+    if let Some(diagnostic) = store.get_mut(&path) {
+        diagnostic.add_measurement(DiagnosticMeasurement { time: now, value });
+    }
+}
+
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Last)]
+pub fn publish_network_diagnostics(
+    client: Option<Res<QuinnetClient>>,
+    store: Option<ResMut<DiagnosticsStore>>,
+    time: Res<Time<Real>>,
+    mut counts: Local<DatagramCounts>,
+) {
+    let (Some(client), Some(mut store)) = (client, store) else {
+        return;
+    };
+    let now = Instant::now();
+    let dt = time.delta_secs_f64();
+
+    let mut rx = 0;
+    let mut tx = 0;
+    let mut seen = HashMap::new();
+    for (&id, connection) in client.connections() {
+        let Some(stats) = connection.quinn_connection_stats() else {
+            continue;
+        };
+        record(
+            &mut store,
+            Diagnostic::new(connection_rtt_path(id)).with_suffix("ms"),
+            now,
+            stats.path.rtt.as_secs_f64() * 1000.0,
+        );
+        let current = (stats.udp_rx.datagrams, stats.udp_tx.datagrams);
+        // A connection's first sample only sets its baseline.
+        if let Some(&(last_rx, last_tx)) = counts.0.get(&id) {
+            rx += current.0.saturating_sub(last_rx);
+            tx += current.1.saturating_sub(last_tx);
+        }
+        seen.insert(id, current);
+    }
+
+    for closed in counts.0.keys().filter(|id| !seen.contains_key(id)) {
+        if let Some(diagnostic) = store.get_mut(&connection_rtt_path(*closed)) {
+            diagnostic.clear_history();
+        }
+    }
+    counts.0 = seen;
+
+    if dt > 0.0 {
+        record(
+            &mut store,
+            Diagnostic::new(NETWORK_RX_PACKETS).with_suffix("/s"),
+            now,
+            rx as f64 / dt,
+        );
+        record(
+            &mut store,
+            Diagnostic::new(NETWORK_TX_PACKETS).with_suffix("/s"),
+            now,
+            tx as f64 / dt,
+        );
+    }
+}
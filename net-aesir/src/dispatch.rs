@@ -1,10 +1,18 @@
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::{auto_add_message, auto_add_system};
 use bevy_quinnet::client::QuinnetClient;
 use bevy_quinnet::client::client_connected;
+use net_contract::diagnostics::{PacketTrace, RecentPackets};
 
+use super::capture::{Direction, PacketCapture};
+use super::character::QuicCharState;
 use super::connection::QuicConnection;
-use super::envelope::Body;
+use super::envelope::{self, Body, FrameError};
+use super::login::QuicLoginState;
+use super::simulate::NetworkSimulation;
+use super::zone::QuicZoneState;
 
 /// A single decoded inbound message drained from the shared QUIC connection.
 ///
@@ -25,17 +33,81 @@ pub struct IncomingMessage {
     pub body: Body,
 }
 
+/// The `PreUpdate` systems that turn inbound frames into messages, so other
+/// plugins can order around (or measure) the receive path.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NetworkReceiveSystems;
+
 /// Drains every channel of the default connection once per frame and
 /// republishes the decoded bodies as [`IncomingMessage`]s for the flow systems.
 ///
 /// Runs in `PreUpdate` so the `Update` flow consumers see this frame's payloads.
+/// While a [`PacketCapture`] runs, every raw frame is recorded before decoding.
+/// Each frame's body tag also goes to [`RecentPackets`] for the crash report.
+/// The [`NetworkSimulation`] then holds frames back or loses them while it is on.
+///
+/// A frame that does not decode is dropped on its own; QUIC frames each payload,
+/// so it can never shift the next one. Each unknown body tag (a newer server's
+/// message) is logged with a hexdump once, then only at debug level.
 #[auto_add_system(
     plugin = crate::AesirNetPlugin,
     schedule = PreUpdate,
-    config(run_if = client_connected)
+    config(in_set = NetworkReceiveSystems, run_if = client_connected)
 )]
-pub fn drain_incoming(mut client: ResMut<QuinnetClient>, mut out: MessageWriter<IncomingMessage>) {
-    for (channel, body) in QuicConnection::drain(client.connection_mut()) {
-        out.write(IncomingMessage { channel, body });
+#[allow(clippy::too_many_arguments)]
+pub fn drain_incoming(
+    mut client: ResMut<QuinnetClient>,
+    mut capture: Option<ResMut<PacketCapture>>,
+    mut simulation: ResMut<NetworkSimulation>,
+    packets: Res<RecentPackets>,
+    time: Res<Time<Real>>,
+    mut out: MessageWriter<IncomingMessage>,
+    mut commands: Commands,
+    mut reported_tags: Local<HashSet<u32>>,
+) {
+    let frames = QuicConnection::drain_raw(client.connection_mut());
+    for (channel, frame) in &frames {
+        packets.record(PacketTrace {
+            outbound: false,
+            channel: *channel,
+            tag: envelope::body_tag(frame),
+        });
+        if let Some(recorder) = capture.as_mut()
+            && let Err(e) = recorder.record(Direction::Inbound, *channel, frame.clone())
+        {
+            error!("packet capture stopped: {e}");
+            commands.remove_resource::<PacketCapture>();
+            capture = None;
+        }
+    }
+    let now_ms = time.elapsed().as_millis() as u64;
+    for (channel, frame) in simulation.pass(frames, now_ms) {
+        match envelope::decode_body(&frame) {
+            Ok(body) => {
+                out.write(IncomingMessage { channel, body });
+            }
+            Err(e) => {
+                let loud = match e {
+                    FrameError::UnknownBody { tag: Some(tag) } => reported_tags.insert(tag),
+                    _ => true,
+                };
+                envelope::report_bad_frame(channel, &frame, &e, loud);
+            }
+        }
+    }
+}
+
+/// Adds the frames each connection sent this frame to [`RecentPackets`].
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Last)]
+pub fn trace_outbound_packets(
+    packets: Res<RecentPackets>,
+    mut login: ResMut<QuicLoginState>,
+    mut char_state: ResMut<QuicCharState>,
+    mut zone: ResMut<QuicZoneState>,
+) {
+    for conn in [&mut login.conn, &mut char_state.conn, &mut zone.conn] {
+        for trace in conn.take_traced() {
+            packets.record(trace);
+        }
     }
 }
@@ -1,3 +1,6 @@
+use std::fmt;
+
+use bevy::log::{debug, warn};
 use bytes::Bytes;
 use prost::Message;
 
@@ -19,11 +22,107 @@ pub fn decode(bytes: &[u8]) -> Result<Envelope, prost::DecodeError> {
     Envelope::decode(bytes)
 }
 
+/// Envelope field number of `seq`; every other top-level field is the body.
+const SEQ_FIELD: u32 = 1;
+
+/// Bytes of a bad frame included in its log line.
+const HEXDUMP_LIMIT: usize = 64;
+
+/// Why a received frame yielded no body.
+#[derive(Debug)]
+pub enum FrameError {
+    /// Not a valid envelope at all.
+    Malformed(prost::DecodeError),
+    /// A valid envelope whose body this build does not know (typically a newer
+    /// server); `tag` is the body's field number when one is present.
+    UnknownBody { tag: Option<u32> },
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(e) => write!(f, "malformed envelope: {e}"),
+            Self::UnknownBody { tag: Some(tag) } => write!(f, "unknown body tag {tag}"),
+            Self::UnknownBody { tag: None } => write!(f, "envelope with no body"),
+        }
+    }
+}
+
+/// Decode a frame down to its body. Frames are length-delimited by the transport,
+/// so a frame that fails here is dropped on its own and never affects the next one.
+pub fn decode_body(bytes: &[u8]) -> Result<Body, FrameError> {
+    let envelope = decode(bytes).map_err(FrameError::Malformed)?;
+    envelope.body.ok_or_else(|| FrameError::UnknownBody {
+        tag: body_tag(bytes),
+    })
+}
+
+/// Field number of the first non-`seq` top-level field, read straight from the
+/// wire format (prost skips fields it does not know, so the decoded envelope has
+/// no trace of them). Also what the crash report lists for each frame.
+pub fn body_tag(mut bytes: &[u8]) -> Option<u32> {
+    while !bytes.is_empty() {
+        let key = prost::encoding::decode_varint(&mut bytes).ok()?;
+        let field = u32::try_from(key >> 3).ok()?;
+        if field != SEQ_FIELD {
+            return Some(field);
+        }
+        let skip = match key & 0b111 {
+            0 => {
+                prost::encoding::decode_varint(&mut bytes).ok()?;
+                0
+            }
+            1 => 8,
+            2 => usize::try_from(prost::encoding::decode_varint(&mut bytes).ok()?).ok()?,
+            5 => 4,
+            _ => return None,
+        };
+        bytes = bytes.get(skip..)?;
+    }
+    None
+}
+
+/// `offset: hex  ascii` rows of 16 bytes, truncated after `limit` bytes.
+pub fn hexdump(bytes: &[u8], limit: usize) -> String {
+    let shown = &bytes[..bytes.len().min(limit)];
+    let mut out = String::new();
+    for (row, chunk) in shown.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{b:02x}")).collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() { b as char } else { '.' })
+            .collect();
+        out.push_str(&format!(
+            "{:04x}: {:<47}  {ascii}\n",
+            row * 16,
+            hex.join(" ")
+        ));
+    }
+    if bytes.len() > shown.len() {
+        out.push_str(&format!("... {} more byte(s)", bytes.len() - shown.len()));
+    }
+    out.trim_end().to_string()
+}
+
+/// Log a frame that was dropped. `loud` frames go out as warnings with a
+/// hexdump; repeats of an already-reported unknown body drop to debug.
+pub fn report_bad_frame(channel: u8, bytes: &[u8], error: &FrameError, loud: bool) {
+    if !loud {
+        debug!("dropped frame on channel {channel}: {error}");
+        return;
+    }
+    warn!(
+        "dropped {}-byte frame on channel {channel}: {error}\n{}",
+        bytes.len(),
+        hexdump(bytes, HEXDUMP_LIMIT)
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::proto::aesir::net::{
-        CharServerInfo, Hello, LoginFailed, LoginRequest, LoginResponse,
+        CharServerInfo, Hello, LoginFailed, LoginRequest, LoginResponse, MapLoaded,
     };
 
     fn roundtrip(seq: u32, body: Body) -> Envelope {
@@ -86,4 +185,161 @@ mod tests {
         assert_eq!(env.seq, 99);
         assert_eq!(env.body, Some(body));
     }
+
+    #[test]
+    fn unknown_body_reports_its_tag() {
+        // seq = 3, then field 999 (length-delimited, 2 bytes) this build never heard of.
+        let mut frame = vec![0x08, 0x03];
+        prost::encoding::encode_key(999, prost::encoding::WireType::LengthDelimited, &mut frame);
+        frame.extend_from_slice(&[0x02, 0xaa, 0xbb]);
+
+        match decode_body(&frame) {
+            Err(FrameError::UnknownBody { tag }) => assert_eq!(tag, Some(999)),
+            other => panic!("expected unknown body, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn known_body_and_garbage() {
+        let frame = encode(0, Body::MapLoaded(MapLoaded {}));
+        assert!(decode_body(&frame).is_ok());
+        assert!(matches!(
+            decode_body(b"not a protobuf"),
+            Err(FrameError::Malformed(_))
+        ));
+        assert!(matches!(
+            decode_body(&[0x08, 0x03]),
+            Err(FrameError::UnknownBody { tag: None })
+        ));
+    }
+
+    #[test]
+    fn hexdump_truncates_long_frames() {
+        let dump = hexdump(&[0x41; 20], 16);
+        assert!(dump.starts_with("0000: 41 41"));
+        assert!(dump.contains("AAAAAAAAAAAAAAAA"));
+        assert!(dump.ends_with("... 4 more byte(s)"));
+    }
+
+    /// Property tests: every body this build knows round-trips, and no frame the
+    /// network can hand us (random, truncated, or lying about a length) panics
+    /// the decoder, the tag sniffer, the hexdump or the capture redactor.
+    mod properties {
+        use super::*;
+        use crate::capture::redact_frame;
+        use proptest::prelude::*;
+        use prost::encoding::{WireType, encode_key, encode_varint};
+        use std::sync::LazyLock;
+
+        /// Highest field number probed for bodies; well above the last one in use.
+        const MAX_PROBED_TAG: u32 = 1024;
+
+        /// Every body field number this build decodes, found by probing rather
+        /// than listed, so a body added to the proto is covered without edits here.
+        static KNOWN_TAGS: LazyLock<Vec<u32>> = LazyLock::new(|| {
+            (SEQ_FIELD + 1..=MAX_PROBED_TAG)
+                .filter(|&tag| decode_body(&frame(0, tag, &[])).is_ok())
+                .collect()
+        });
+
+        /// `seq`, then `payload` as the length-delimited body field `tag`.
+        fn frame(seq: u32, tag: u32, payload: &[u8]) -> Vec<u8> {
+            let mut out = Vec::new();
+            encode_key(SEQ_FIELD, WireType::Varint, &mut out);
+            encode_varint(u64::from(seq), &mut out);
+            encode_key(tag, WireType::LengthDelimited, &mut out);
+            encode_varint(payload.len() as u64, &mut out);
+            out.extend_from_slice(payload);
+            out
+        }
+
+        /// One protobuf field with a random number, wire type and value. Wire
+        /// types deliberately disagree with the schema some of the time.
+        fn field() -> impl Strategy<Value = Vec<u8>> {
+            let varint = (1u32..24, any::<u64>()).prop_map(|(number, value)| {
+                let mut out = Vec::new();
+                encode_key(number, WireType::Varint, &mut out);
+                encode_varint(value, &mut out);
+                out
+            });
+            let bytes = (1u32..24, prop::collection::vec(any::<u8>(), 0..32)).prop_map(
+                |(number, value)| {
+                    let mut out = Vec::new();
+                    encode_key(number, WireType::LengthDelimited, &mut out);
+                    encode_varint(value.len() as u64, &mut out);
+                    out.extend_from_slice(&value);
+                    out
+                },
+            );
+            let fixed = (1u32..24, any::<[u8; 4]>()).prop_map(|(number, value)| {
+                let mut out = Vec::new();
+                encode_key(number, WireType::ThirtyTwoBit, &mut out);
+                out.extend_from_slice(&value);
+                out
+            });
+            prop_oneof![varint, bytes, fixed]
+        }
+
+        fn message() -> impl Strategy<Value = Vec<u8>> {
+            prop::collection::vec(field(), 0..8).prop_map(|fields| fields.concat())
+        }
+
+        fn known_tag() -> impl Strategy<Value = u32> {
+            prop::sample::select(KNOWN_TAGS.clone())
+        }
+
+        fn survives(bytes: &[u8]) {
+            let _ = decode_body(bytes);
+            let _ = body_tag(bytes);
+            let _ = hexdump(bytes, HEXDUMP_LIMIT);
+            let _ = redact_frame(Bytes::copy_from_slice(bytes));
+        }
+
+        #[test]
+        fn every_known_body_round_trips_empty() {
+            assert!(!KNOWN_TAGS.is_empty());
+            for &tag in KNOWN_TAGS.iter() {
+                let body = decode_body(&frame(3, tag, &[])).unwrap();
+                let reencoded = encode(3, body.clone());
+                assert_eq!(body_tag(&reencoded), Some(tag));
+                assert_eq!(decode(&reencoded).unwrap().body, Some(body));
+            }
+        }
+
+        #[test]
+        fn length_past_the_end_is_malformed() {
+            let mut bytes = frame(1, KNOWN_TAGS[0], &[]);
+            bytes.pop();
+            encode_varint(u64::from(u32::MAX), &mut bytes);
+            assert!(matches!(decode_body(&bytes), Err(FrameError::Malformed(_))));
+        }
+
+        proptest! {
+            #[test]
+            fn random_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+                survives(&bytes);
+            }
+
+            #[test]
+            fn decoded_bodies_round_trip(
+                seq in any::<u32>(),
+                tag in known_tag(),
+                payload in message(),
+            ) {
+                if let Ok(body) = decode_body(&frame(seq, tag, &payload)) {
+                    let envelope = decode(&encode(seq, body.clone())).unwrap();
+                    prop_assert_eq!(envelope.seq, seq);
+                    prop_assert_eq!(envelope.body, Some(body));
+                }
+            }
+
+            #[test]
+            fn truncated_frames_never_panic(tag in known_tag(), payload in message()) {
+                let bytes = frame(7, tag, &payload);
+                for end in 0..bytes.len() {
+                    survives(&bytes[..end]);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,204 @@
+//! Keep-alive scheduling for the three aesir connections.
+//!
+//! The aesir protocol has no `CA_/CH_PING` equivalent, and bevy_quinnet's client
+//! doesn't expose QUIC's keep-alive or idle timeout, so the client keeps its
+//! connections busy itself instead of trusting the server to ping.
+//!
+//! - **Login** needs nothing: the connection is only used until `LoginResponse`,
+//!   and losing it once the login is `Done` is ignored. The char handoff carries
+//!   the session ids, not the connection.
+//! - **Char** sends a `CharListRefresh` every `CHAR_KEEP_ALIVE_INTERVAL` while the
+//!   character-select screen is idle (`Ready`), well inside QUIC's default 30s
+//!   idle timeout. An unchanged roster in answer isn't passed on to the domain.
+//! - **Zone** sends `TimeSync` (the `CZ_REQUEST_TIME` replacement). It doubles as
+//!   the session keep-alive and refreshes the server clock offset. It is sent as
+//!   soon as a zone connection is authenticated (including each resumed
+//!   connection) and then on a fixed cadence, and never while the default
+//!   connection still belongs to the char server.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource};
+use bevy_quinnet::client::{QuinnetClient, client_connected};
+
+use crate::channels::CONTROL;
+use crate::character::{CharPhase, QuicCharState};
+use crate::envelope::Body;
+use crate::proto::aesir::net::{CharListRefresh, TimeSync};
+use crate::zone::{QuicZoneState, ZonePhase};
+
+/// Zone `TimeSync` cadence, preserving the legacy TCP zone path's 30s interval.
+const TIME_SYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Char keep-alive cadence; half of QUIC's default idle timeout.
+const CHAR_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Char-select keep-alive schedule: runs only while the session is `Ready` and
+/// restarts whenever it leaves that phase.
+#[derive(Resource, Debug)]
+#[auto_init_resource(plugin = crate::AesirNetPlugin)]
+pub struct CharKeepAlive {
+    timer: Timer,
+}
+
+impl Default for CharKeepAlive {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(CHAR_KEEP_ALIVE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl CharKeepAlive {
+    /// Advance the schedule by `delta`; true when a refresh should go out.
+    fn due(&mut self, phase: CharPhase, delta: Duration) -> bool {
+        if phase != CharPhase::Ready {
+            self.timer.reset();
+            return false;
+        }
+        self.timer.tick(delta).just_finished()
+    }
+}
+
+/// Sends a `CharListRefresh` on the char control channel per the schedule.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update,
+    config(run_if = client_connected)
+)]
+pub fn char_keep_alive(
+    time: Res<Time>,
+    mut keep_alive: ResMut<CharKeepAlive>,
+    mut client: ResMut<QuinnetClient>,
+    mut state: ResMut<QuicCharState>,
+) {
+    if !keep_alive.due(state.phase, time.delta()) {
+        return;
+    }
+    let body = Body::CharListRefresh(CharListRefresh {});
+    match state.conn.send(client.connection_mut(), CONTROL, body) {
+        Ok(()) => state.keep_alive_pending += 1,
+        Err(e) => error!("failed to send keep-alive CharListRefresh: {e}"),
+    }
+}
+
+/// Zone phases where the server knows the session and expects `TimeSync`.
+fn zone_session_authenticated(phase: ZonePhase) -> bool {
+    matches!(
+        phase,
+        ZonePhase::Entering | ZonePhase::MapReady | ZonePhase::Playing
+    )
+}
+
+/// Per-connection `TimeSync` schedule: fires immediately on a new connection
+/// epoch, then every `TIME_SYNC_INTERVAL`.
+#[derive(Resource, Debug)]
+#[auto_init_resource(plugin = crate::AesirNetPlugin)]
+pub struct ZoneKeepAlive {
+    epoch: Option<u64>,
+    timer: Timer,
+}
+
+impl Default for ZoneKeepAlive {
+    fn default() -> Self {
+        Self {
+            epoch: None,
+            timer: Timer::new(TIME_SYNC_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl ZoneKeepAlive {
+    /// Advance the schedule by `delta` for the connection `epoch`; true when a
+    /// `TimeSync` should go out this frame.
+    fn due(&mut self, epoch: u64, delta: Duration) -> bool {
+        if self.epoch != Some(epoch) {
+            self.epoch = Some(epoch);
+            self.timer.reset();
+            return true;
+        }
+        self.timer.tick(delta).just_finished()
+    }
+}
+
+/// Sends `TimeSync { client_tick }` on the zone control channel per the schedule.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update,
+    config(run_if = client_connected)
+)]
+pub fn zone_keep_alive(
+    time: Res<Time>,
+    mut keep_alive: ResMut<ZoneKeepAlive>,
+    mut client: ResMut<QuinnetClient>,
+    mut state: ResMut<QuicZoneState>,
+) {
+    if !zone_session_authenticated(state.phase) {
+        return;
+    }
+    if !keep_alive.due(state.connection_epoch, time.delta()) {
+        return;
+    }
+    let client_tick = (time.elapsed_secs() * 1000.0) as u32;
+    let body = Body::TimeSync(TimeSync { client_tick });
+    if let Err(e) = state.send(&mut client, CONTROL, body) {
+        error!("failed to send TimeSync: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_sync_only_for_authenticated_zone_sessions() {
+        assert!(!zone_session_authenticated(ZonePhase::Disconnected));
+        assert!(!zone_session_authenticated(ZonePhase::AuthSent));
+        assert!(!zone_session_authenticated(ZonePhase::Reconnecting));
+        assert!(zone_session_authenticated(ZonePhase::Entering));
+        assert!(zone_session_authenticated(ZonePhase::Playing));
+    }
+
+    #[test]
+    fn char_keep_alive_runs_only_while_ready() {
+        let mut keep_alive = CharKeepAlive::default();
+        assert!(!keep_alive.due(CharPhase::Ready, Duration::from_secs(10)));
+        assert!(!keep_alive.due(CharPhase::Selecting, Duration::from_secs(10)));
+        assert!(!keep_alive.due(CharPhase::Ready, Duration::from_secs(10)));
+        assert!(keep_alive.due(CharPhase::Ready, Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn unchanged_keep_alive_answers_are_not_passed_on() {
+        let list = |slots: u32| crate::proto::aesir::net::CharList {
+            normal_slots: slots,
+            ..Default::default()
+        };
+        let mut state = QuicCharState::default();
+        assert!(state.accept_list(&list(9)));
+
+        state.keep_alive_pending = 1;
+        assert!(!state.accept_list(&list(9)));
+        // A roster the player asked for is always passed on.
+        assert!(state.accept_list(&list(9)));
+
+        state.keep_alive_pending = 1;
+        assert!(state.accept_list(&list(12)));
+        assert_eq!(state.keep_alive_pending, 0);
+    }
+
+    #[test]
+    fn schedule_fires_per_connection_then_on_cadence() {
+        let mut keep_alive = ZoneKeepAlive::default();
+        assert!(keep_alive.due(1, Duration::ZERO));
+        assert!(!keep_alive.due(1, Duration::from_secs(10)));
+        assert!(keep_alive.due(1, Duration::from_secs(20)));
+        assert!(!keep_alive.due(1, Duration::from_secs(1)));
+
+        // A resumed connection syncs right away and restarts the cadence.
+        assert!(keep_alive.due(2, Duration::from_secs(1)));
+        assert!(!keep_alive.due(2, Duration::from_secs(29)));
+        assert!(keep_alive.due(2, Duration::from_secs(1)));
+    }
+}
@@ -1,13 +1,20 @@
 use bevy_auto_plugin::prelude::{AutoPlugin, auto_add_plugin};
 
+pub mod capture;
 pub mod channels;
 pub mod character;
 pub mod connection;
+pub mod diagnostics;
 pub mod dispatch;
 pub mod envelope;
+pub mod keep_alive;
 pub mod login;
+#[cfg(any(test, feature = "mock-server"))]
+pub mod mock_server;
 pub mod proto;
+pub mod protocol;
 pub mod send;
+pub mod simulate;
 pub mod zone;
 
 #[auto_add_plugin(plugin = AesirNetPlugin, init)]
@@ -11,8 +11,10 @@ use super::{LoginPhase, QuicLoginState};
 use crate::channels::CONTROL;
 use crate::dispatch::IncomingMessage;
 use crate::envelope::Body;
-use crate::proto::aesir::net::{Hello, LoginRequest};
-use net_contract::events::{LoginAccepted, LoginRefused};
+use crate::proto::aesir::net::LoginRequest;
+use crate::protocol;
+use net_contract::dto::NetworkError;
+use net_contract::events::{LoginAccepted, LoginConnectionFailed, LoginRefused};
 
 /// On a fresh quinnet connection, send the `Hello` handshake on the control channel.
 #[auto_add_system(
@@ -28,10 +30,7 @@ pub fn quic_send_hello(
         if state.phase != LoginPhase::Connecting {
             continue;
         }
-        let hello = Body::Hello(Hello {
-            protocol_version: 1,
-            build: state.pending.build.clone(),
-        });
+        let hello = protocol::hello(state.pending.build.clone());
         if let Err(e) = state.conn.send(client.connection_mut(), CONTROL, hello) {
             error!("failed to send Hello: {e}");
             state.phase = LoginPhase::Failed;
@@ -73,7 +72,7 @@ pub fn quic_drain_control(
                     warn!("server rejected Hello handshake");
                     refused.write(LoginRefused {
                         username: state.pending.username.clone(),
-                        error_code: 3,
+                        error_code: LoginRefused::REJECTED_FROM_SERVER,
                         error_message: "server rejected handshake".to_string(),
                         block_date: None,
                     });
@@ -106,7 +105,7 @@ pub fn quic_drain_control(
                         warn!("login response carried an invalid server address: {reason}");
                         refused.write(LoginRefused {
                             username: state.pending.username.clone(),
-                            error_code: 3,
+                            error_code: LoginRefused::REJECTED_FROM_SERVER,
                             error_message: reason,
                             block_date: None,
                         });
@@ -129,7 +128,8 @@ pub fn quic_drain_control(
     }
 }
 
-/// Maps quinnet connection failure / loss onto a `LoginRefused`.
+/// Maps quinnet connection failure / loss before the login finished onto a
+/// `LoginConnectionFailed`.
 #[auto_add_system(
     plugin = crate::AesirNetPlugin,
     schedule = Update
@@ -138,25 +138,58 @@ pub fn quic_handle_connection_lost(
     mut failed_events: MessageReader<ConnectionFailedEvent>,
     mut lost_events: MessageReader<ConnectionLostEvent>,
     mut state: ResMut<QuicLoginState>,
-    mut refused: MessageWriter<LoginRefused>,
+    mut failed: MessageWriter<LoginConnectionFailed>,
 ) {
-    let mut fail = |state: &mut QuicLoginState, message: String| {
-        if state.phase == LoginPhase::Done || state.phase == LoginPhase::Disconnected {
+    let mut fail = |state: &mut QuicLoginState, error: NetworkError| {
+        if matches!(
+            state.phase,
+            LoginPhase::Done | LoginPhase::Disconnected | LoginPhase::Failed
+        ) {
             return;
         }
-        refused.write(LoginRefused {
+        failed.write(LoginConnectionFailed {
             username: state.pending.username.clone(),
-            error_code: 3,
-            error_message: message,
-            block_date: None,
+            error,
         });
         state.phase = LoginPhase::Failed;
     };
 
     for event in failed_events.read() {
-        fail(&mut state, format!("connection failed: {}", event.err));
+        fail(
+            &mut state,
+            NetworkError::ConnectionFailed(event.err.to_string()),
+        );
     }
     for _ in lost_events.read() {
-        fail(&mut state, "connection lost".to_string());
+        fail(&mut state, NetworkError::UnexpectedDisconnect);
+    }
+}
+
+/// Gives up on a login connection that is still opening after the configured
+/// connect timeout. QUIC itself only gives up after its idle timeout, which is
+/// far longer than a player should stare at the login screen.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update
+)]
+pub fn quic_login_connect_timeout(
+    time: Res<Time<Real>>,
+    mut client: ResMut<QuinnetClient>,
+    mut state: ResMut<QuicLoginState>,
+    mut failed: MessageWriter<LoginConnectionFailed>,
+) {
+    if !state.connect_timed_out(time.delta()) {
+        return;
     }
+    warn!(
+        "login connection not open after {:?}; giving up",
+        state.pending.connect_timeout
+    );
+    client.close_all_connections();
+    state.resolving = None;
+    failed.write(LoginConnectionFailed {
+        username: state.pending.username.clone(),
+        error: NetworkError::Timeout,
+    });
+    state.phase = LoginPhase::Failed;
 }
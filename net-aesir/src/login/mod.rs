@@ -1,17 +1,16 @@
 pub mod flow;
 pub mod mapping;
 
+use std::net::SocketAddr;
+use std::time::Duration;
+
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_init_resource;
-use bevy_quinnet::client::certificate::CertificateVerificationMode;
-use bevy_quinnet::client::connection::{ClientAddrConfiguration, ConnectionLocalId};
-use bevy_quinnet::client::{
-    ClientConnectionConfiguration, ClientConnectionConfigurationDefaultables, QuinnetClient,
-};
-use bevy_quinnet::shared::error::AsyncChannelError;
+use bevy_quinnet::client::QuinnetClient;
+use bevy_quinnet::client::connection::ConnectionLocalId;
+use net_contract::dto::NetworkError;
 
-use crate::channels;
-use crate::connection::QuicConnection;
+use crate::connection::{self, QuicConnection, Resolving};
 
 /// Phase of the QUIC login handshake.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -33,6 +32,8 @@ pub struct Pending {
     pub password: String,
     pub client_version: u32,
     pub build: String,
+    /// How long the connection may stay in `Connecting` before the attempt fails.
+    pub connect_timeout: Duration,
 }
 
 /// Drives the QUIC login flow: tracks the handshake phase, owns the seq-counting
@@ -43,37 +44,65 @@ pub struct QuicLoginState {
     pub phase: LoginPhase,
     pub conn: QuicConnection,
     pub pending: Pending,
+    /// Time spent in `Connecting` for the current attempt, the address lookup
+    /// included.
+    pub connecting_for: Duration,
+    /// The server address lookup, until the connection can be opened.
+    pub resolving: Option<Resolving>,
 }
 
 impl QuicLoginState {
     /// Begin a fresh login attempt: reset the seq counter, stash credentials, and
     /// arm the `Connecting` phase so `quic_send_hello` fires once the connection opens.
     pub fn start_connecting(&mut self, pending: Pending) {
-        self.conn = QuicConnection::default();
+        self.conn.reset();
         self.pending = pending;
         self.phase = LoginPhase::Connecting;
+        self.connecting_for = Duration::ZERO;
+        self.resolving = None;
+    }
+
+    /// Advance the connect clock by `delta`; true the moment a still-opening
+    /// connection outlives its timeout.
+    pub fn connect_timed_out(&mut self, delta: Duration) -> bool {
+        if self.phase != LoginPhase::Connecting {
+            return false;
+        }
+        self.connecting_for += delta;
+        self.connecting_for >= self.pending.connect_timeout
     }
 }
 
-/// Opens the QUIC connection to the aesir account server.
-///
-/// Closes any existing connection first so reconnecting after a zone disconnect
-/// restores the one-active-connection invariant. Dev cert handling:
-/// `SkipVerification` (self-signed). The send channels use aesir's fixed order so
-/// channel ids line up; recv channels keep their defaults.
+/// Opens the QUIC connection to the aesir account server (see
+/// [`connection::open`]); closing the old ones first restores the
+/// one-active-connection invariant after a zone disconnect.
 pub fn connect(
     client: &mut QuinnetClient,
-    addr: &str,
-) -> Result<ConnectionLocalId, AsyncChannelError> {
-    client.close_all_connections();
-    let addr_config = ClientAddrConfiguration::from_strings(addr, "0.0.0.0:0")
-        .expect("valid login server address");
-    client.open_connection(ClientConnectionConfiguration {
-        addr_config,
-        cert_mode: CertificateVerificationMode::SkipVerification,
-        defaultables: ClientConnectionConfigurationDefaultables {
-            send_channels_cfg: channels::send_channels_config(),
+    server: SocketAddr,
+) -> Result<ConnectionLocalId, NetworkError> {
+    connection::open(client, server)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_times_out_only_while_still_connecting() {
+        let mut state = QuicLoginState::default();
+        state.start_connecting(Pending {
+            connect_timeout: Duration::from_secs(5),
             ..Default::default()
-        },
-    })
+        });
+
+        assert!(!state.connect_timed_out(Duration::from_secs(3)));
+        assert!(state.connect_timed_out(Duration::from_secs(2)));
+
+        state.start_connecting(Pending {
+            connect_timeout: Duration::from_secs(5),
+            ..Default::default()
+        });
+        state.phase = LoginPhase::HelloSent;
+        assert!(!state.connect_timed_out(Duration::from_secs(60)));
+    }
 }
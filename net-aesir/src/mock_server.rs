@@ -0,0 +1,256 @@
+//! Scriptable in-process aesir server for tests.
+//!
+//! [`MockAesirServer::start`] opens a real quinnet endpoint on loopback with a
+//! self-signed certificate (the client already skips verification) inside the
+//! test's own app. Every frame a client sends is decoded, kept in
+//! [`MockAesirServer::received`] and handed to the script, whose replies go back
+//! on the channels it names. The client side runs unchanged, so a test drives
+//! login, character selection and zone entry through the real flow systems
+//! without a live server.
+//!
+//! [`scripted_session`] answers just enough of the protocol for that happy
+//! path; tests wanting other answers write their own script.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use bevy::prelude::*;
+use bevy_quinnet::server::certificate::CertificateRetrievalMode;
+use bevy_quinnet::server::{
+    EndpointAddrConfiguration, QuinnetServer, QuinnetServerPlugin, ServerEndpointConfiguration,
+    ServerEndpointConfigurationDefaultables,
+};
+
+use crate::channels::{self, CONTROL};
+use crate::envelope::{self, Body};
+use crate::proto::aesir::net::{
+    CharList, CharServerInfo, Character, EnterAck, HelloAck, LoginResponse, ZoneServerInfo,
+};
+
+/// Answers one received frame with `(channel, body)` replies.
+pub type Script = Box<dyn FnMut(&Body) -> Vec<(u8, Body)> + Send + Sync>;
+
+/// The running mock server.
+#[derive(Resource)]
+pub struct MockAesirServer {
+    script: Script,
+    seq: u32,
+    /// Every frame received so far, in arrival order.
+    pub received: Vec<(u8, Body)>,
+}
+
+impl MockAesirServer {
+    /// Starts listening on a free loopback port and returns its address.
+    /// `script` gets the address so it can point login and char replies back
+    /// at the same server.
+    pub fn start<S>(app: &mut App, script: impl FnOnce(SocketAddr) -> S) -> SocketAddr
+    where
+        S: FnMut(&Body) -> Vec<(u8, Body)> + Send + Sync + 'static,
+    {
+        if !app.is_plugin_added::<QuinnetServerPlugin>() {
+            app.add_plugins(QuinnetServerPlugin::default());
+        }
+        let mut server = app.world_mut().resource_mut::<QuinnetServer>();
+        server
+            .start_endpoint(ServerEndpointConfiguration {
+                addr_config: EndpointAddrConfiguration::from_ip(Ipv4Addr::LOCALHOST, 0),
+                cert_mode: CertificateRetrievalMode::GenerateSelfSigned {
+                    server_hostname: Ipv4Addr::LOCALHOST.to_string(),
+                },
+                defaultables: ServerEndpointConfigurationDefaultables {
+                    send_channels_cfg: channels::send_channels_config(),
+                    ..Default::default()
+                },
+            })
+            .expect("mock server endpoint starts");
+        let addr = server.endpoint().local_addr();
+
+        app.insert_resource(Self {
+            script: Box::new(script(addr)),
+            seq: 0,
+            received: Vec::new(),
+        })
+        .add_systems(Update, answer_clients);
+        addr
+    }
+}
+
+fn answer_clients(mut server: ResMut<QuinnetServer>, mut mock: ResMut<MockAesirServer>) {
+    let Some(endpoint) = server.get_endpoint_mut() else {
+        return;
+    };
+    for client in endpoint.clients() {
+        for channel in 0..channels::CHANNEL_COUNT as u8 {
+            while let Ok(Some(frame)) = endpoint.receive_payload(client, channel) {
+                let Ok(body) = envelope::decode_body(&frame) else {
+                    warn!("mock server: undecodable frame on channel {channel}");
+                    continue;
+                };
+                let replies = (mock.script)(&body);
+                mock.received.push((channel, body));
+                for (reply_channel, reply) in replies {
+                    let frame = envelope::encode(mock.seq, reply);
+                    mock.seq += 1;
+                    if let Err(e) = endpoint.send_payload_on(client, reply_channel, frame) {
+                        error!("mock server: failed to reply: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Account the [`scripted_session`] logs in.
+pub const MOCK_ACCOUNT_ID: u32 = 2_000_001;
+/// The one character on the [`scripted_session`] roster, in slot 0.
+pub const MOCK_CHAR_ID: u32 = 150_000;
+pub const MOCK_MAP: &str = "prontera";
+/// Cell the [`scripted_session`] spawns the character on.
+pub const MOCK_SPAWN: (u32, u32) = (156, 191);
+
+/// Accepts every handshake and login, lists one character, and sends it into
+/// [`MOCK_MAP`]. Char and zone hops are pointed back at `addr`.
+pub fn scripted_session(addr: SocketAddr) -> impl FnMut(&Body) -> Vec<(u8, Body)> {
+    let ip = match addr.ip() {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(_) => Ipv4Addr::LOCALHOST.to_string(),
+    };
+    let port = u32::from(addr.port());
+    move |body| {
+        let reply = match body {
+            Body::Hello(hello) => Body::HelloAck(HelloAck {
+                protocol_version: hello.protocol_version,
+                accepted: true,
+            }),
+            Body::LoginRequest(_) => Body::LoginResponse(LoginResponse {
+                account_id: MOCK_ACCOUNT_ID,
+                login_id1: 1,
+                login_id2: 2,
+                sex: 1,
+                char_servers: vec![CharServerInfo {
+                    name: "Mock".into(),
+                    ip: ip.clone(),
+                    port,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            // Char-server entry; the zone one names its character.
+            Body::SessionAuth(auth) if auth.char_id == 0 => Body::CharList(CharList {
+                account_id: auth.account_id,
+                normal_slots: 9,
+                valid_slots: 9,
+                page_count: 1,
+                characters: vec![Character {
+                    gid: MOCK_CHAR_ID,
+                    name: "Mock".into(),
+                    char_num: 0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            Body::SelectChar(_) => Body::ZoneServerInfo(ZoneServerInfo {
+                char_id: MOCK_CHAR_ID,
+                map_name: MOCK_MAP.into(),
+                ip: ip.clone(),
+                port,
+                auth_token: b"mock".to_vec(),
+            }),
+            Body::SessionAuth(auth) => Body::EnterAck(EnterAck {
+                account_id: auth.account_id,
+                x: MOCK_SPAWN.0,
+                y: MOCK_SPAWN.1,
+                ..Default::default()
+            }),
+            _ => return Vec::new(),
+        };
+        vec![(CONTROL, reply)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use bevy::ecs::message::MessageCursor;
+    use net_contract::commands::{ConnectCharServer, ConnectLogin, ConnectZone, SelectCharacter};
+    use net_contract::events::{
+        CharacterServerConnected, LoginAccepted, ZoneEntered, ZoneServerInfoReceived,
+    };
+
+    use super::*;
+
+    /// Updates `app` until an `M` arrives, failing after a few seconds.
+    fn pump<M: Message + Clone>(app: &mut App) -> M {
+        let mut cursor = MessageCursor::<M>::default();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while Instant::now() < deadline {
+            app.update();
+            let messages = app.world().resource::<Messages<M>>();
+            if let Some(message) = cursor.read(messages).last() {
+                return message.clone();
+            }
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        panic!("no {} from the mock server", std::any::type_name::<M>());
+    }
+
+    #[test]
+    fn client_logs_in_selects_and_enters_the_zone() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            net_contract::NetContractPlugin,
+            crate::AesirNetPlugin,
+        ));
+        let addr = MockAesirServer::start(&mut app, scripted_session);
+
+        app.world_mut().write_message(ConnectLogin {
+            address: addr.to_string(),
+            username: "mock".into(),
+            password: "secret".into(),
+            client_version: 0,
+            build: "test".into(),
+            connect_timeout: std::time::Duration::from_secs(10),
+        });
+        let accepted = pump::<LoginAccepted>(&mut app);
+        assert_eq!(accepted.account_id, MOCK_ACCOUNT_ID);
+
+        let server = &accepted.server_list[0];
+        app.world_mut().write_message(ConnectCharServer {
+            address: format!("{}:{}", server.ip_string(), server.port),
+            account_id: accepted.account_id,
+            login_id1: accepted.login_id1,
+            login_id2: accepted.login_id2,
+            sex: u32::from(accepted.sex),
+        });
+        let roster = pump::<CharacterServerConnected>(&mut app);
+        assert_eq!(roster.characters[0].char_id, MOCK_CHAR_ID);
+
+        app.world_mut().write_message(SelectCharacter { slot: 0 });
+        let zone = pump::<ZoneServerInfoReceived>(&mut app).zone_server_info;
+        assert_eq!(zone.map_name, MOCK_MAP);
+
+        app.world_mut().write_message(ConnectZone {
+            address: format!("{}:{}", zone.ip_string(), zone.port),
+            account_id: accepted.account_id,
+            login_id1: accepted.login_id1,
+            login_id2: accepted.login_id2,
+            sex: u32::from(accepted.sex),
+            char_id: zone.char_id,
+            zone_auth_token: zone.auth_token,
+            map_name: zone.map_name,
+        });
+        let entered = pump::<ZoneEntered>(&mut app);
+        assert_eq!((entered.x, entered.y), MOCK_SPAWN);
+
+        let received = &app.world().resource::<MockAesirServer>().received;
+        let login = received
+            .iter()
+            .find_map(|(_, body)| match body {
+                Body::LoginRequest(request) => Some(request),
+                _ => None,
+            })
+            .expect("the client sent a LoginRequest");
+        assert_eq!(login.username, "mock");
+    }
+}
@@ -0,0 +1,36 @@
+//! The aesir wire revision a session speaks.
+//!
+//! aesir has one protobuf layout, negotiated by revision in the `Hello` handshake, so
+//! unlike the classic packet tables the client date does not pick opcodes or struct
+//! layouts. Every handshake (login, char, zone) opens with the same [`hello`] at
+//! [`PROTOCOL_VERSION`].
+
+use crate::envelope::Body;
+use crate::proto::aesir::net::Hello;
+
+/// The aesir protocol revision this client speaks.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The `Hello` opening every aesir connection.
+pub fn hello(build: String) -> Body {
+    Body::Hello(Hello {
+        protocol_version: PROTOCOL_VERSION,
+        build,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hello_carries_the_protocol_version() {
+        match hello("test".into()) {
+            Body::Hello(hello) => {
+                assert_eq!(hello.protocol_version, PROTOCOL_VERSION);
+                assert_eq!(hello.build, "test");
+            }
+            other => panic!("expected Body::Hello, got {other:?}"),
+        }
+    }
+}
@@ -2,8 +2,10 @@ use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use bevy_quinnet::client::{QuinnetClient, client_connected};
 use net_contract::commands::{
-    CreateCharacter, DeleteCharacter, RefreshCharacterList, SelectCharacter,
+    CreateCharacter, DeleteCharacter, RefreshCharacterList, RenameCharacter, SelectCharacter,
 };
+use net_contract::dto::CharRenameError;
+use net_contract::events::CharacterRenameFailed;
 
 use crate::channels::CONTROL;
 use crate::character::{CharPhase, QuicCharState};
@@ -128,6 +130,28 @@ pub fn send_refresh_character_list(
     }
 }
 
+/// aesir's char protocol has no rename message, so every rename request is
+/// answered locally with `Unsupported` instead of leaving the UI waiting.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update
+)]
+pub fn reject_rename_character(
+    mut events: MessageReader<RenameCharacter>,
+    mut failed: MessageWriter<CharacterRenameFailed>,
+) {
+    for ev in events.read() {
+        warn!(
+            "aesir has no rename message; rejecting rename of char {}",
+            ev.char_id
+        );
+        failed.write(CharacterRenameFailed {
+            char_id: ev.char_id,
+            error: CharRenameError::Unsupported,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
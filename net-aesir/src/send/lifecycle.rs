@@ -2,15 +2,16 @@ use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use bevy_quinnet::client::{QuinnetClient, client_connected};
 use net_contract::commands::{
-    ConnectCharServer, ConnectLogin, ConnectZone, LeaveZone, LocalMapLoaded, LocalPlayerReady,
-    RespawnRequested,
+    ConnectCharServer, ConnectLogin, ConnectZone, EndSession, LeaveZone, LocalMapLoaded,
+    LocalPlayerReady, RespawnRequested,
 };
-use net_contract::events::{LoginRefused, MapChangeRequested, ZoneDisconnected};
+use net_contract::events::{LoginConnectionFailed, MapChangeRequested, ZoneDisconnected};
 
 use crate::channels::{CONTROL, GAMEPLAY};
-use crate::character::{self, PendingAuth, QuicCharState};
+use crate::character::{self, CharPhase, PendingAuth, QuicCharState};
+use crate::connection::Resolving;
 use crate::envelope::Body;
-use crate::login::{self, Pending, QuicLoginState};
+use crate::login::{self, LoginPhase, Pending, QuicLoginState};
 use crate::proto::aesir::net::{MapLoaded, Respawn};
 use crate::zone::{self, QuicZoneState, ZoneAuth, ZonePhase};
 
@@ -25,83 +26,111 @@ fn player_ready_next(phase: ZonePhase) -> Option<ZonePhase> {
     (phase == ZonePhase::MapReady).then_some(ZonePhase::Playing)
 }
 
-/// Open the login-server connection and arm the login handshake.
-///
-/// On an immediate connect error, surface the existing `LoginRefused` contract
-/// event (mirroring `login::flow::quic_handle_connection_lost`) so the domain's
-/// failure path stays identical whether the failure is at connect or in-flight.
+/// Arm the login handshake and start resolving the login-server address; the
+/// connection opens in [`open_login_connection`].
 #[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
 pub fn handle_connect_login(
     mut events: MessageReader<ConnectLogin>,
-    mut client: ResMut<QuinnetClient>,
     mut login_state: ResMut<QuicLoginState>,
-    mut refused: MessageWriter<LoginRefused>,
 ) {
     for cmd in events.read() {
-        if let Err(e) = login::connect(&mut client, &cmd.address) {
-            error!("failed to connect to login server {}: {e}", cmd.address);
-            refused.write(LoginRefused {
-                username: cmd.username.clone(),
-                error_code: 3,
-                error_message: format!("connection failed: {e}"),
-                block_date: None,
-            });
-            continue;
-        }
         login_state.start_connecting(Pending {
             username: cmd.username.clone(),
             password: cmd.password.clone(),
             client_version: cmd.client_version,
             build: cmd.build.clone(),
+            connect_timeout: cmd.connect_timeout,
         });
+        login_state.resolving = Some(Resolving::start(&cmd.address));
     }
 }
 
-/// Open the char-server connection and arm the char-session handshake.
+/// Open the login-server connection once its address resolved.
 ///
-/// There is no contract char-failure event (the adapter's
-/// `char_handle_connection_lost` only logs and sets `CharPhase::Failed`), so an
-/// immediate connect error is logged here and not surfaced to the domain.
+/// A connect error (an address that doesn't resolve, a socket that won't open)
+/// surfaces as `LoginConnectionFailed`, the same event
+/// `login::flow::quic_handle_connection_lost` writes for in-flight failures. The
+/// lookup counts against the connect timeout, which drops it when it runs out.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update,
+    config(after = handle_connect_login)
+)]
+pub fn open_login_connection(
+    mut client: ResMut<QuinnetClient>,
+    mut login_state: ResMut<QuicLoginState>,
+    mut failed: MessageWriter<LoginConnectionFailed>,
+) {
+    let Some(resolved) = login_state.resolving.as_mut().and_then(Resolving::poll) else {
+        return;
+    };
+    login_state.resolving = None;
+    if login_state.phase != LoginPhase::Connecting {
+        return;
+    }
+    if let Err(error) = resolved.and_then(|server| login::connect(&mut client, server)) {
+        error!("failed to connect to login server: {error}");
+        failed.write(LoginConnectionFailed {
+            username: login_state.pending.username.clone(),
+            error,
+        });
+        login_state.phase = LoginPhase::Failed;
+    }
+}
+
+/// Arm the char-session handshake and start resolving the char-server address;
+/// the connection opens in [`open_char_connection`].
 #[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
 pub fn handle_connect_char_server(
     mut events: MessageReader<ConnectCharServer>,
-    mut client: ResMut<QuinnetClient>,
     mut char_state: ResMut<QuicCharState>,
 ) {
     for cmd in events.read() {
-        if let Err(e) = character::connect(&mut client, &cmd.address) {
-            error!("failed to connect to char server {}: {e}", cmd.address);
-            continue;
-        }
         char_state.start_connecting(PendingAuth {
             account_id: cmd.account_id,
             login_id1: cmd.login_id1,
             login_id2: cmd.login_id2,
             sex: cmd.sex,
         });
+        char_state.resolving = Some(Resolving::start(&cmd.address));
     }
 }
 
-/// Open the zone-server connection and arm the zone handshake.
+/// Open the char-server connection once its address resolved.
 ///
-/// `zone::connect` closes any existing connection first (the char hop), so the
-/// handoff-close the domain used to log is preserved inside the connect call. On
-/// an immediate connect error, surface the existing `ZoneDisconnected` event.
+/// There is no contract char-failure event (the adapter's
+/// `char_handle_connection_lost` only logs and sets `CharPhase::Failed`), so a
+/// connect error is logged here and not surfaced to the domain.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update,
+    config(after = handle_connect_char_server)
+)]
+pub fn open_char_connection(
+    mut client: ResMut<QuinnetClient>,
+    mut char_state: ResMut<QuicCharState>,
+) {
+    let Some(resolved) = char_state.resolving.as_mut().and_then(Resolving::poll) else {
+        return;
+    };
+    char_state.resolving = None;
+    if char_state.phase != CharPhase::Connecting {
+        return;
+    }
+    if let Err(e) = resolved.and_then(|server| character::connect(&mut client, server)) {
+        error!("failed to connect to char server: {e}");
+        char_state.phase = CharPhase::Failed;
+    }
+}
+
+/// Arm the zone handshake and start resolving the zone-server address; the
+/// connection opens in [`open_zone_connection`].
 #[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
 pub fn handle_connect_zone(
     mut events: MessageReader<ConnectZone>,
-    mut client: ResMut<QuinnetClient>,
     mut zone_state: ResMut<QuicZoneState>,
-    mut disconnected: MessageWriter<ZoneDisconnected>,
 ) {
     for cmd in events.read() {
-        if let Err(e) = zone::connect(&mut client, &cmd.address) {
-            error!("failed to connect to zone server {}: {e}", cmd.address);
-            disconnected.write(ZoneDisconnected {
-                reason: format!("connection failed: {e}"),
-            });
-            continue;
-        }
         zone_state.start_connecting(
             ZoneAuth {
                 account_id: cmd.account_id,
@@ -113,6 +142,45 @@ pub fn handle_connect_zone(
             },
             cmd.map_name.clone(),
         );
+        zone_state.address.clone_from(&cmd.address);
+        zone_state.resolving = Some(Resolving::start(&cmd.address));
+    }
+}
+
+/// Open the zone-server connection once its address resolved.
+///
+/// `zone::connect` closes any existing connection first (the char hop), so the
+/// handoff-close the domain used to log is preserved inside the connect call. On
+/// a connect error, surface the existing `ZoneDisconnected` event.
+#[auto_add_system(
+    plugin = crate::AesirNetPlugin,
+    schedule = Update,
+    config(after = handle_connect_zone)
+)]
+pub fn open_zone_connection(
+    mut client: ResMut<QuinnetClient>,
+    mut zone_state: ResMut<QuicZoneState>,
+    mut disconnected: MessageWriter<ZoneDisconnected>,
+) {
+    let Some(resolved) = zone_state.resolving.as_mut().and_then(Resolving::poll) else {
+        return;
+    };
+    zone_state.resolving = None;
+    if zone_state.phase != ZonePhase::Connecting {
+        return;
+    }
+    match resolved.and_then(|server| zone::connect(&mut client, server).map(|_| server)) {
+        Ok(server) => zone_state.server = Some(server),
+        Err(e) => {
+            error!(
+                "failed to connect to zone server {}: {e}",
+                zone_state.address
+            );
+            disconnected.write(ZoneDisconnected {
+                reason: format!("connection failed: {e}"),
+            });
+            zone_state.phase = ZonePhase::Failed;
+        }
     }
 }
 
@@ -170,17 +238,66 @@ pub fn advance_zone_handshake(
 
 /// Tear down the zone session when the domain leaves the zone (return to login).
 ///
-/// Resets the phase to `Disconnected` and clears the handshake latches so a later
-/// re-entry starts from a clean state machine rather than a stale `Playing`/latched one.
+/// Resets the phase to `Disconnected` and clears the handshake latches (and any
+/// pending resume) so a later re-entry starts from a clean state machine rather
+/// than a stale `Playing`/latched one.
 #[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
 pub fn handle_leave_zone(mut events: MessageReader<LeaveZone>, mut state: ResMut<QuicZoneState>) {
     for _ in events.read() {
-        state.phase = ZonePhase::Disconnected;
-        state.map_loaded_signal = false;
-        state.player_ready_signal = false;
+        leave_zone(&mut state);
     }
 }
 
+fn leave_zone(state: &mut QuicZoneState) {
+    state.phase = ZonePhase::Disconnected;
+    state.resolving = None;
+    state.map_loaded_signal = false;
+    state.player_ready_signal = false;
+    state.resume = None;
+}
+
+/// Return every flow to `Disconnected` with fresh seq counters. The zone keeps
+/// its connection epoch, so the keep-alive still sees the next zone as new.
+fn end_session(
+    login: &mut QuicLoginState,
+    char_state: &mut QuicCharState,
+    zone: &mut QuicZoneState,
+) {
+    login.phase = LoginPhase::Disconnected;
+    login.conn.reset();
+    login.pending = Pending::default();
+    login.resolving = None;
+    char_state.phase = CharPhase::Disconnected;
+    char_state.conn.reset();
+    char_state.resolving = None;
+    char_state.auth = PendingAuth::default();
+    zone.conn.reset();
+    leave_zone(zone);
+}
+
+/// Close every connection and reset all three flows when the domain ends the
+/// account session (return to login).
+///
+/// The drainers only stand down in `Done`/`Failed`, so a char flow left `Ready`
+/// by character selection would otherwise also read the next login
+/// connection's control traffic and replay it as char events.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
+pub fn handle_end_session(
+    mut events: MessageReader<EndSession>,
+    mut client: ResMut<QuinnetClient>,
+    mut login_state: ResMut<QuicLoginState>,
+    mut char_state: ResMut<QuicCharState>,
+    mut zone_state: ResMut<QuicZoneState>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    client.close_all_connections();
+    end_session(&mut login_state, &mut char_state, &mut zone_state);
+    debug!("session ended: all aesir connections closed");
+}
+
 /// Re-arm the map-load handshake on a server warp.
 ///
 /// `MapChangeRequested` means the client is unloading and reloading a map, so the
@@ -256,6 +373,32 @@ mod tests {
         assert_eq!(player_ready_next(ZonePhase::Playing), None);
     }
 
+    #[test]
+    fn ending_the_session_disconnects_every_flow() {
+        let mut login = QuicLoginState {
+            phase: LoginPhase::Done,
+            ..Default::default()
+        };
+        let mut char_state = QuicCharState {
+            phase: CharPhase::Ready,
+            ..Default::default()
+        };
+        let mut zone = QuicZoneState {
+            phase: ZonePhase::Playing,
+            connection_epoch: 3,
+            player_ready_signal: true,
+            ..Default::default()
+        };
+
+        end_session(&mut login, &mut char_state, &mut zone);
+
+        assert_eq!(login.phase, LoginPhase::Disconnected);
+        assert_eq!(char_state.phase, CharPhase::Disconnected);
+        assert_eq!(zone.phase, ZonePhase::Disconnected);
+        assert!(!zone.player_ready_signal);
+        assert_eq!(zone.connection_epoch, 3);
+    }
+
     #[test]
     fn respawn_body_carries_type_save_point() {
         match respawn_body(&RespawnRequested { type_: 0 }) {
@@ -0,0 +1,339 @@
+//! Simulated bad network for development.
+//!
+//! Set `LIFTHRASIR_NET_SIM=latency=200,jitter=50,loss=0.05` in a debug build to
+//! hold back every inbound frame by `latency` ± `jitter` milliseconds and drop
+//! `loss` of the snapshot frames. `bandwidth=256` (kbit/s) makes frames queue
+//! behind each other on a slow link, so a burst of spawns or a map load trickles
+//! in; `batch=100` delivers only on 100 ms ticks, the way a server that flushes
+//! its send buffer per tick does. That exercises movement prediction,
+//! reconnection and UI timeouts without a real bad link. A
+//! [`SimulateNetwork`] command switches it on, changes it or switches it off
+//! at runtime; switching off delivers whatever is still held back.
+//!
+//! Only the receive side is delayed, so `latency` is effectively the added
+//! round trip. A lost frame on a reliable channel is resent by QUIC, not lost,
+//! so there it costs an extra `2 * latency`. Frames on one channel never
+//! overtake each other, except on the unreliable snapshot channel.
+//!
+//! The [`PacketCapture`](crate::capture::PacketCapture) records frames as they
+//! came off the wire, before the simulation.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::{auto_add_system, auto_init_resource};
+use bytes::Bytes;
+use net_contract::commands::SimulateNetwork;
+use net_contract::dto::NetworkConditions;
+
+use crate::channels::{CHANNEL_COUNT, SNAPSHOTS};
+
+struct HeldFrame {
+    release_ms: u64,
+    channel: u8,
+    frame: Bytes,
+}
+
+/// The simulated link. Off until it is given conditions.
+#[derive(Resource)]
+#[auto_init_resource(plugin = crate::AesirNetPlugin)]
+pub struct NetworkSimulation {
+    conditions: Option<NetworkConditions>,
+    held: VecDeque<HeldFrame>,
+    /// Latest release time handed out per channel, to keep reliable channels in order.
+    channel_release_ms: [u64; CHANNEL_COUNT],
+    /// When the simulated link finishes sending the last admitted frame.
+    link_free_ms: u64,
+    rng: u64,
+}
+
+impl Default for NetworkSimulation {
+    fn default() -> Self {
+        Self::with_seed(0x9e37_79b9_7f4a_7c15)
+    }
+}
+
+impl NetworkSimulation {
+    /// A switched-off simulation with a fixed random sequence, for tests.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            conditions: None,
+            held: VecDeque::new(),
+            channel_release_ms: [0; CHANNEL_COUNT],
+            link_free_ms: 0,
+            rng: seed.max(1),
+        }
+    }
+
+    pub fn conditions(&self) -> Option<NetworkConditions> {
+        self.conditions
+    }
+
+    /// Switch on with `conditions`, or off with `None`. Frames already held
+    /// keep their release times; switching off hands them all to the next
+    /// [`pass`](Self::pass).
+    pub fn set_conditions(&mut self, conditions: Option<NetworkConditions>) {
+        self.conditions = conditions;
+    }
+
+    /// xorshift64*: uniform in 0.0..1.0.
+    fn next_unit(&mut self) -> f32 {
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        (self.rng.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Run the frames that arrived at `now_ms` through the link and return
+    /// the ones to deliver now, in order. While switched off, that is every
+    /// frame still held followed by the new ones.
+    pub fn pass(&mut self, frames: Vec<(u8, Bytes)>, now_ms: u64) -> Vec<(u8, Bytes)> {
+        let Some(conditions) = self.conditions else {
+            if self.held.is_empty() {
+                return frames;
+            }
+            let mut flushed = self.release(u64::MAX);
+            self.channel_release_ms = [0; CHANNEL_COUNT];
+            self.link_free_ms = 0;
+            flushed.extend(frames);
+            return flushed;
+        };
+        for (channel, frame) in frames {
+            self.admit(conditions, channel, frame, now_ms);
+        }
+        self.release(now_ms)
+    }
+
+    fn admit(&mut self, conditions: NetworkConditions, channel: u8, frame: Bytes, now_ms: u64) {
+        let NetworkConditions {
+            latency_ms,
+            jitter_ms,
+            loss,
+            bandwidth_kbps,
+            batch_ms,
+        } = conditions;
+        // kbit/s is bits per millisecond. A dropped frame still used the link.
+        let mut sent_ms = now_ms;
+        if bandwidth_kbps > 0 {
+            let bits = frame.len() as u64 * 8;
+            sent_ms = self.link_free_ms.max(now_ms) + bits.div_ceil(bandwidth_kbps as u64);
+            self.link_free_ms = sent_ms;
+        }
+
+        let lost = loss > 0.0 && self.next_unit() < loss;
+        if lost && channel == SNAPSHOTS {
+            return;
+        }
+
+        let jitter = (self.next_unit() * 2.0 - 1.0) * jitter_ms as f32;
+        let mut delay = (latency_ms as f32 + jitter).max(0.0) as u64;
+        if lost {
+            delay += 2 * latency_ms as u64;
+        }
+        let mut release_ms = sent_ms + delay;
+        if batch_ms > 0 {
+            release_ms = release_ms.next_multiple_of(batch_ms as u64);
+        }
+        if let Some(last) = self.channel_release_ms.get_mut(channel as usize)
+            && channel != SNAPSHOTS
+        {
+            release_ms = release_ms.max(*last);
+            *last = release_ms;
+        }
+
+        self.held.push_back(HeldFrame {
+            release_ms,
+            channel,
+            frame,
+        });
+    }
+
+    /// Frames whose delay is over at `now_ms`, in release order.
+    fn release(&mut self, now_ms: u64) -> Vec<(u8, Bytes)> {
+        let (mut due, held): (Vec<_>, Vec<_>) = self
+            .held
+            .drain(..)
+            .partition(|held| held.release_ms <= now_ms);
+        self.held = held.into();
+        due.sort_by_key(|held| held.release_ms);
+        due.into_iter()
+            .map(|held| (held.channel, held.frame))
+            .collect()
+    }
+
+    /// Frames still being held back.
+    pub fn pending(&self) -> usize {
+        self.held.len()
+    }
+}
+
+/// Switches the simulation on when `LIFTHRASIR_NET_SIM` is set in a debug build.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Startup)]
+pub fn start_network_simulation(mut simulation: ResMut<NetworkSimulation>) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let Ok(spec) = std::env::var("LIFTHRASIR_NET_SIM") else {
+        return;
+    };
+    match NetworkConditions::parse(&spec) {
+        Ok(conditions) => {
+            warn!("simulating a bad network: {conditions:?}");
+            simulation.set_conditions(Some(conditions));
+        }
+        Err(e) => error!("ignoring LIFTHRASIR_NET_SIM: {e}"),
+    }
+}
+
+/// Applies [`SimulateNetwork`] commands; release builds ignore them.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
+pub fn apply_simulate_network(
+    mut requests: MessageReader<SimulateNetwork>,
+    mut simulation: ResMut<NetworkSimulation>,
+) {
+    for SimulateNetwork(conditions) in requests.read() {
+        if !cfg!(debug_assertions) {
+            warn!("ignoring SimulateNetwork: network simulation is debug-only");
+            continue;
+        }
+        match conditions {
+            Some(conditions) => warn!("simulating a bad network: {conditions:?}"),
+            None if simulation.conditions().is_some() => {
+                info!(
+                    "network simulation off, delivering {} held frames",
+                    simulation.pending()
+                );
+            }
+            None => {}
+        }
+        simulation.set_conditions(*conditions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channels::WORLD;
+
+    fn simulation(latency_ms: u32, jitter_ms: u32, loss: f32, seed: u64) -> NetworkSimulation {
+        let mut sim = NetworkSimulation::with_seed(seed);
+        sim.set_conditions(Some(NetworkConditions {
+            latency_ms,
+            jitter_ms,
+            loss,
+            bandwidth_kbps: 0,
+            batch_ms: 0,
+        }));
+        sim
+    }
+
+    #[test]
+    fn frames_wait_out_the_latency_in_order() {
+        let mut sim = simulation(100, 40, 0.0, 7);
+        for i in 0..20u8 {
+            let now = 1_000 + i as u64;
+            assert!(
+                sim.pass(vec![(WORLD, Bytes::from(vec![i]))], now)
+                    .is_empty()
+            );
+        }
+
+        assert!(sim.pass(Vec::new(), 1_059).is_empty());
+        let mut released = Vec::new();
+        for now in (1_060..=1_200).step_by(5) {
+            released.extend(sim.pass(Vec::new(), now));
+        }
+        let order: Vec<u8> = released.iter().map(|(_, frame)| frame[0]).collect();
+        assert_eq!(order, (0..20).collect::<Vec<_>>());
+        assert_eq!(sim.pending(), 0);
+    }
+
+    #[test]
+    fn loss_drops_snapshots_and_delays_reliable_frames() {
+        let mut sim = simulation(50, 0, 1.0, 3);
+        let frames = vec![
+            (SNAPSHOTS, Bytes::from_static(b"s")),
+            (WORLD, Bytes::from_static(b"w")),
+        ];
+        assert!(sim.pass(frames, 0).is_empty());
+        assert_eq!(sim.pending(), 1);
+
+        assert!(sim.pass(Vec::new(), 149).is_empty());
+        assert_eq!(
+            sim.pass(Vec::new(), 150),
+            vec![(WORLD, Bytes::from_static(b"w"))]
+        );
+    }
+
+    #[test]
+    fn switching_off_delivers_held_frames_before_new_ones() {
+        let mut sim = simulation(1_000, 0, 0.0, 5);
+        assert!(
+            sim.pass(vec![(WORLD, Bytes::from_static(b"a"))], 0)
+                .is_empty()
+        );
+
+        sim.set_conditions(None);
+        assert_eq!(
+            sim.pass(vec![(WORLD, Bytes::from_static(b"b"))], 10),
+            vec![
+                (WORLD, Bytes::from_static(b"a")),
+                (WORLD, Bytes::from_static(b"b")),
+            ]
+        );
+        assert_eq!(sim.pending(), 0);
+
+        // Back on, new frames are not queued behind the old release times.
+        sim.set_conditions(Some(NetworkConditions {
+            latency_ms: 5,
+            jitter_ms: 0,
+            loss: 0.0,
+            bandwidth_kbps: 0,
+            batch_ms: 0,
+        }));
+        assert!(
+            sim.pass(vec![(WORLD, Bytes::from_static(b"c"))], 20)
+                .is_empty()
+        );
+        assert_eq!(sim.pass(Vec::new(), 25).len(), 1);
+    }
+
+    #[test]
+    fn slow_link_queues_frames_behind_each_other() {
+        let mut sim = NetworkSimulation::with_seed(1);
+        sim.set_conditions(Some(NetworkConditions {
+            bandwidth_kbps: 8,
+            ..NetworkConditions::parse("").unwrap()
+        }));
+        // 8 kbit/s sends one byte per millisecond.
+        let frames = vec![
+            (WORLD, Bytes::from(vec![1; 10])),
+            (SNAPSHOTS, Bytes::from(vec![2; 10])),
+        ];
+        assert!(sim.pass(frames, 0).is_empty());
+
+        assert!(sim.pass(Vec::new(), 9).is_empty());
+        assert_eq!(sim.pass(Vec::new(), 10).len(), 1);
+        assert!(sim.pass(Vec::new(), 19).is_empty());
+        assert_eq!(sim.pass(Vec::new(), 20).len(), 1);
+    }
+
+    #[test]
+    fn batching_releases_frames_on_tick_boundaries() {
+        let mut sim = NetworkSimulation::with_seed(1);
+        sim.set_conditions(Some(NetworkConditions {
+            latency_ms: 10,
+            batch_ms: 100,
+            ..NetworkConditions::parse("").unwrap()
+        }));
+        for now in [5, 30, 60] {
+            assert!(
+                sim.pass(vec![(WORLD, Bytes::from_static(b"f"))], now)
+                    .is_empty()
+            );
+        }
+
+        assert!(sim.pass(Vec::new(), 99).is_empty());
+        assert_eq!(sim.pass(Vec::new(), 100).len(), 3);
+    }
+}
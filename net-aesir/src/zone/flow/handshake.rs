@@ -1,5 +1,3 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use bevy_quinnet::client::QuinnetClient;
@@ -9,15 +7,16 @@ use bevy_quinnet::client::connection::{
 };
 
 use super::super::mapping::handshake::enter_ack;
+use super::super::resume::{LossOutcome, ZoneResume, on_connection_loss};
 use super::super::{QuicZoneState, ZonePhase, ZoneSpawn};
 use crate::channels::CONTROL;
 use crate::dispatch::IncomingMessage;
 use crate::envelope::Body;
-use crate::proto::aesir::net::{Hello, SessionAuth, TimeSync};
-use net_contract::events::{ZoneDisconnected, ZoneEntered};
-
-/// Periodic time-sync cadence, preserving the legacy TCP zone path's 30s interval.
-const TIME_SYNC_INTERVAL: Duration = Duration::from_secs(30);
+use crate::proto::aesir::net::SessionAuth;
+use crate::protocol;
+use net_contract::events::{
+    MapChangeRequested, ZoneConnectionLost, ZoneDisconnected, ZoneEntered, ZoneReconnected,
+};
 
 /// Pure outcome of receiving a `HelloAck`: the next phase, or `None` when out of phase.
 fn hello_ack_next(phase: ZonePhase, accepted: bool) -> Option<ZonePhase> {
@@ -50,10 +49,7 @@ pub fn zone_send_hello(
         if state.phase != ZonePhase::Connecting {
             continue;
         }
-        let hello = Body::Hello(Hello {
-            protocol_version: 1,
-            build: "lifthrasir".into(),
-        });
+        let hello = protocol::hello("lifthrasir".into());
         if let Err(e) = state.send(&mut client, CONTROL, hello) {
             error!("failed to send zone Hello: {e}");
             state.phase = ZonePhase::Failed;
@@ -64,6 +60,10 @@ pub fn zone_send_hello(
 }
 
 /// Drains the control channel and advances the zone-server session.
+///
+/// An `EnterAck` that completes a resume re-enters the current map through the
+/// warp path (`MapChangeRequested`) instead of a first-entry `ZoneEntered`, so
+/// the surviving local player is repositioned rather than respawned.
 #[auto_add_system(
     plugin = crate::AesirNetPlugin,
     schedule = Update,
@@ -74,6 +74,8 @@ pub fn zone_drain_control(
     mut client: ResMut<QuinnetClient>,
     mut state: ResMut<QuicZoneState>,
     mut entered: MessageWriter<ZoneEntered>,
+    mut warps: MessageWriter<MapChangeRequested>,
+    mut reconnected: MessageWriter<ZoneReconnected>,
 ) {
     for msg in incoming.read() {
         if msg.channel != CONTROL {
@@ -110,8 +112,18 @@ pub fn zone_drain_control(
                     continue;
                 };
                 state.spawn = Some(ZoneSpawn::from_enter_ack(&ack));
-                entered.write(enter_ack(ack));
                 state.phase = next;
+                if state.resume.take().is_some() {
+                    info!("zone session resumed");
+                    reconnected.write(ZoneReconnected);
+                    warps.write(MapChangeRequested {
+                        map_name: state.map_name.clone(),
+                        x: ack.x,
+                        y: ack.y,
+                    });
+                    continue;
+                }
+                entered.write(enter_ack(ack));
             }
             Body::TimeSyncAck(reply) => {
                 state.clock_offset = reply.server_tick as i64;
@@ -121,30 +133,12 @@ pub fn zone_drain_control(
     }
 }
 
-/// Periodically sends `TimeSync { client_tick }` on the control channel.
-#[auto_add_system(
-    plugin = crate::AesirNetPlugin,
-    schedule = Update,
-    config(run_if = client_connected)
-)]
-pub fn zone_time_sync(
-    time: Res<Time>,
-    mut timer: Local<Option<Timer>>,
-    mut client: ResMut<QuinnetClient>,
-    mut state: ResMut<QuicZoneState>,
-) {
-    let timer = timer.get_or_insert_with(|| Timer::new(TIME_SYNC_INTERVAL, TimerMode::Repeating));
-    if !timer.tick(time.delta()).just_finished() {
-        return;
-    }
-    let client_tick = (time.elapsed_secs() * 1000.0) as u32;
-    let body = Body::TimeSync(TimeSync { client_tick });
-    if let Err(e) = state.send(&mut client, CONTROL, body) {
-        error!("failed to send TimeSync: {e}");
-    }
-}
-
-/// Maps quinnet connection failure / loss onto a failed zone session.
+/// Maps quinnet connection failure / loss onto the zone session.
+///
+/// A drop while `Playing` (or during a resume attempt) schedules the next resume
+/// attempt with backoff; running out of attempts fails the session, which
+/// `zone_report_resume_failure` surfaces. Any other drop fails the session
+/// outright as `ZoneDisconnected`.
 #[auto_add_system(
     plugin = crate::AesirNetPlugin,
     schedule = Update
@@ -154,14 +148,36 @@ pub fn zone_handle_connection_lost(
     mut lost_events: MessageReader<ConnectionLostEvent>,
     mut state: ResMut<QuicZoneState>,
     mut disconnected: MessageWriter<ZoneDisconnected>,
+    mut lost: MessageWriter<ZoneConnectionLost>,
 ) {
     let mut fail = |state: &mut QuicZoneState, message: String| {
-        if state.phase == ZonePhase::Disconnected {
+        if matches!(
+            state.phase,
+            ZonePhase::Disconnected | ZonePhase::Reconnecting
+        ) {
             return;
         }
-        error!("zone connection lost: {message}");
-        state.phase = ZonePhase::Failed;
-        disconnected.write(ZoneDisconnected { reason: message });
+        let attempt = state.resume.as_ref().map(|r| r.attempt);
+        match on_connection_loss(state.phase, attempt) {
+            LossOutcome::Fail => {
+                error!("zone connection lost: {message}");
+                state.phase = ZonePhase::Failed;
+                disconnected.write(ZoneDisconnected { reason: message });
+            }
+            LossOutcome::GiveUp => state.phase = ZonePhase::Failed,
+            LossOutcome::Resume {
+                attempt,
+                delay,
+                first,
+            } => {
+                warn!("zone connection lost ({message}); resume attempt {attempt} in {delay:?}");
+                if first {
+                    lost.write(ZoneConnectionLost { reason: message });
+                }
+                state.resume = Some(ZoneResume::new(attempt, delay));
+                state.phase = ZonePhase::Reconnecting;
+            }
+        }
     };
 
     for event in failed_events.read() {
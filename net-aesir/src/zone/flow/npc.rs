@@ -2,10 +2,10 @@ use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_system;
 use bevy_quinnet::client::client_connected;
 
-use super::super::mapping::npc::npc_dialog;
+use super::super::mapping::npc::{npc_cutin, npc_dialog};
 use crate::dispatch::IncomingMessage;
 use crate::envelope::Body;
-use net_contract::events::NpcDialogReceived;
+use net_contract::events::{NpcCutinReceived, NpcDialogReceived};
 
 #[auto_add_system(
     plugin = crate::AesirNetPlugin,
@@ -15,10 +15,17 @@ use net_contract::events::NpcDialogReceived;
 pub fn zone_drain_npc(
     mut incoming: MessageReader<IncomingMessage>,
     mut dialog: MessageWriter<NpcDialogReceived>,
+    mut cutin: MessageWriter<NpcCutinReceived>,
 ) {
     for msg in incoming.read() {
-        if let Body::NpcDialog(d) = msg.body.clone() {
-            dialog.write(npc_dialog(d));
+        match msg.body.clone() {
+            Body::NpcDialog(d) => {
+                dialog.write(npc_dialog(d));
+            }
+            Body::Cutin(c) => {
+                cutin.write(npc_cutin(c));
+            }
+            _ => {}
         }
     }
 }
@@ -33,6 +40,7 @@ mod tests {
         let mut app = App::new();
         app.add_message::<IncomingMessage>()
             .add_message::<NpcDialogReceived>()
+            .add_message::<NpcCutinReceived>()
             .add_systems(Update, zone_drain_npc);
 
         let mut incoming = app.world_mut().resource_mut::<Messages<IncomingMessage>>();
@@ -76,4 +84,20 @@ mod tests {
         let events: Vec<_> = dialog.iter_current_update_messages().collect();
         assert_eq!(events.len(), 0);
     }
+
+    #[test]
+    fn cutin_produces_one_cutin_received() {
+        let app = drain(vec![(
+            WORLD,
+            Body::Cutin(net::Cutin {
+                image: "kafra_01".into(),
+                r#type: 2,
+            }),
+        )]);
+
+        let cutin = app.world().resource::<Messages<NpcCutinReceived>>();
+        let events: Vec<_> = cutin.iter_current_update_messages().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].image, "kafra_01");
+    }
 }
@@ -2,16 +2,39 @@ use crate::proto::aesir::net;
 use net::announcement::Style;
 use net_contract::events::{AnnouncementReceived, AnnouncementStyle};
 
+/// rAthena still marks blue broadcasts (`bc_blue`, `@kamib`) the ZC_BROADCAST
+/// way, with a `blue` text prefix, and tags some yellow ones with `ssss`.
+const BLUE_PREFIX: &str = "blue";
+const YELLOW_PREFIX: &str = "ssss";
+/// The reference client's blue broadcast color.
+const BROADCAST_BLUE: u32 = 0x00ffff;
+
+/// Strips a legacy color prefix from `text`, returning the color it asked for
+/// (`0` keeps the client default).
+fn legacy_broadcast_color(text: String, color: u32) -> (String, u32) {
+    if color != 0 {
+        return (text, color);
+    }
+    if let Some(rest) = text.strip_prefix(BLUE_PREFIX) {
+        (rest.to_string(), BROADCAST_BLUE)
+    } else if let Some(rest) = text.strip_prefix(YELLOW_PREFIX) {
+        (rest.to_string(), 0)
+    } else {
+        (text, 0)
+    }
+}
+
 pub fn announcement(a: net::Announcement) -> AnnouncementReceived {
     let style = match Style::try_from(a.style) {
         Ok(Style::Top) => AnnouncementStyle::Top,
         Ok(Style::Center) => AnnouncementStyle::Center,
         Ok(Style::Local) | Err(_) => AnnouncementStyle::Local,
     };
+    let (text, color) = legacy_broadcast_color(a.text, a.color);
 
     AnnouncementReceived {
-        text: a.text,
-        color: a.color,
+        text,
+        color,
         style,
         source_name: a.source_name,
     }
@@ -59,6 +82,41 @@ mod tests {
         assert_eq!(announcement(proto(99)).style, AnnouncementStyle::Local);
     }
 
+    #[test]
+    fn blue_prefix_becomes_the_blue_broadcast_color() {
+        let received = announcement(net::Announcement {
+            text: "blueWoE starts now".into(),
+            color: 0,
+            ..proto(Style::Top as i32)
+        });
+
+        assert_eq!(received.text, "WoE starts now");
+        assert_eq!(received.color, BROADCAST_BLUE);
+    }
+
+    #[test]
+    fn yellow_prefix_is_stripped_and_keeps_the_default_color() {
+        let received = announcement(net::Announcement {
+            text: "ssssHello".into(),
+            color: 0,
+            ..proto(Style::Top as i32)
+        });
+
+        assert_eq!(received.text, "Hello");
+        assert_eq!(received.color, 0);
+    }
+
+    #[test]
+    fn explicit_color_leaves_the_text_alone() {
+        let received = announcement(net::Announcement {
+            text: "blueberries".into(),
+            ..proto(Style::Top as i32)
+        });
+
+        assert_eq!(received.text, "blueberries");
+        assert_eq!(received.color, 0x00ff00);
+    }
+
     #[test]
     fn passes_fields_through_unchanged() {
         let received = announcement(proto(Style::Center as i32));
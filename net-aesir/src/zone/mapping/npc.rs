@@ -1,8 +1,8 @@
 use crate::proto::aesir::net;
 use crate::proto::aesir::net::npc_dialog::Expect;
 use bevy::prelude::warn;
-use net_contract::dto::NpcDialogExpect;
-use net_contract::events::NpcDialogReceived;
+use net_contract::dto::{CutinPosition, NpcDialogExpect};
+use net_contract::events::{NpcCutinReceived, NpcDialogReceived};
 
 pub fn npc_dialog(d: net::NpcDialog) -> NpcDialogReceived {
     let expect = match Expect::try_from(d.expect) {
@@ -28,6 +28,27 @@ pub fn npc_dialog(d: net::NpcDialog) -> NpcDialogReceived {
     }
 }
 
+/// Maps the rAthena `cutin` type: 0-2 along the bottom edge, 3 (window) and 4
+/// (no window) both centered, 255 clears.
+pub fn npc_cutin(c: net::Cutin) -> NpcCutinReceived {
+    let position = match c.r#type {
+        0 => CutinPosition::BottomLeft,
+        1 => CutinPosition::BottomMiddle,
+        2 => CutinPosition::BottomRight,
+        3 | 4 => CutinPosition::Center,
+        255 => CutinPosition::Clear,
+        other => {
+            warn!("unknown Cutin.type {other} for '{}'; centering", c.image);
+            CutinPosition::Center
+        }
+    };
+
+    NpcCutinReceived {
+        image: c.image,
+        position,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +94,37 @@ mod tests {
         let d = npc_dialog(dialog(Expect::Close, vec![]));
         assert_eq!(d.expect, NpcDialogExpect::Close);
     }
+
+    fn cutin(image: &str, r#type: u32) -> net::Cutin {
+        net::Cutin {
+            image: image.into(),
+            r#type,
+        }
+    }
+
+    #[test]
+    fn maps_cutin_positions() {
+        assert_eq!(
+            npc_cutin(cutin("kafra_01", 2)).position,
+            CutinPosition::BottomRight
+        );
+        assert_eq!(
+            npc_cutin(cutin("kafra_01", 3)).position,
+            CutinPosition::Center
+        );
+        assert_eq!(
+            npc_cutin(cutin("kafra_01", 4)).position,
+            CutinPosition::Center
+        );
+        assert_eq!(npc_cutin(cutin("", 255)).position, CutinPosition::Clear);
+        assert_eq!(npc_cutin(cutin("kafra_01", 0)).image, "kafra_01");
+    }
+
+    #[test]
+    fn unknown_cutin_type_is_centered() {
+        assert_eq!(
+            npc_cutin(cutin("kafra_01", 9)).position,
+            CutinPosition::Center
+        );
+    }
 }
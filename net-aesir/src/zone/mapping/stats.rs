@@ -10,7 +10,6 @@ pub fn param_change(p: net::ParamChange) -> ParamChanged {
     }
 }
 
-// NOTE: no client consumer yet; kept for future implementation.
 pub fn unit_hp(u: net::UnitHp) -> UnitHpChanged {
     UnitHpChanged {
         gid: u.id,
@@ -1,21 +1,20 @@
 pub mod flow;
 pub mod mapping;
+pub mod resume;
 pub mod session;
 
+use std::net::SocketAddr;
+
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_init_resource;
-use bevy_quinnet::client::certificate::CertificateVerificationMode;
-use bevy_quinnet::client::connection::{ClientAddrConfiguration, ConnectionLocalId};
-use bevy_quinnet::client::{
-    ClientConnectionConfiguration, ClientConnectionConfigurationDefaultables, ClientSendError,
-    QuinnetClient,
-};
-use bevy_quinnet::shared::error::AsyncChannelError;
-
-use crate::channels;
-use crate::connection::QuicConnection;
+use bevy_quinnet::client::connection::ConnectionLocalId;
+use bevy_quinnet::client::{ClientSendError, QuinnetClient};
+use net_contract::dto::NetworkError;
+
+use crate::connection::{self, QuicConnection, Resolving};
 use crate::envelope::Body;
 use crate::proto::aesir::net;
+use resume::ZoneResume;
 
 /// Phase of the long-lived QUIC zone-server session.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -28,6 +27,9 @@ pub enum ZonePhase {
     Entering,
     MapReady,
     Playing,
+    /// The connection dropped mid-game; waiting out the backoff before the next
+    /// resume attempt.
+    Reconnecting,
     Failed,
 }
 
@@ -65,6 +67,12 @@ pub struct QuicZoneState {
     /// Monotonic epoch advanced by each successfully opened zone connection.
     pub connection_epoch: u64,
     pub auth: ZoneAuth,
+    /// Zone-server address of the current session, as the char server sent it.
+    pub address: String,
+    /// `address` resolved, reused by resume attempts without another lookup.
+    pub server: Option<SocketAddr>,
+    /// The server address lookup, until the connection can be opened.
+    pub resolving: Option<Resolving>,
     pub map_name: String,
     pub spawn: Option<ZoneSpawn>,
     pub clock_offset: i64,
@@ -73,6 +81,8 @@ pub struct QuicZoneState {
     /// Latched `LocalPlayerReady` signal; gates the `MapReady -> Playing` advance.
     /// Survives a warp (the player entity persists), so it is not cleared on map change.
     pub player_ready_signal: bool,
+    /// Present while a dropped session is being resumed.
+    pub resume: Option<ZoneResume>,
 }
 
 impl QuicZoneState {
@@ -81,13 +91,26 @@ impl QuicZoneState {
     /// `zone_send_hello` fires once the connection opens.
     pub fn start_connecting(&mut self, auth: ZoneAuth, map_name: String) {
         self.connection_epoch = self.connection_epoch.saturating_add(1);
-        self.conn = QuicConnection::default();
+        self.conn.reset();
         self.auth = auth;
         self.map_name = map_name;
         self.spawn = None;
         self.phase = ZonePhase::Connecting;
         self.map_loaded_signal = false;
         self.player_ready_signal = false;
+        self.resume = None;
+        self.server = None;
+        self.resolving = None;
+    }
+
+    /// Re-open the current session on a fresh connection after a drop: same
+    /// credentials and map, new seq counter. The local player survives the drop,
+    /// so `player_ready_signal` is kept while the map handshake is re-armed.
+    pub fn resume_connecting(&mut self) {
+        self.connection_epoch = self.connection_epoch.saturating_add(1);
+        self.conn.reset();
+        self.phase = ZonePhase::Connecting;
+        self.map_loaded_signal = false;
     }
 
     /// Encode and send a body on the given channel via the seq-counting connection.
@@ -101,26 +124,13 @@ impl QuicZoneState {
     }
 }
 
-/// Opens the QUIC connection to the aesir zone server.
-///
-/// Closes any existing connection first so the new zone connection becomes the
-/// unambiguous default for `client.connection_mut()` (one-active-connection
-/// invariant). Dev cert handling: `SkipVerification` (self-signed).
+/// Opens the QUIC connection to the aesir zone server (see
+/// [`connection::open`]), replacing the char connection.
 pub fn connect(
     client: &mut QuinnetClient,
-    addr: &str,
-) -> Result<ConnectionLocalId, AsyncChannelError> {
-    client.close_all_connections();
-    let addr_config = ClientAddrConfiguration::from_strings(addr, "0.0.0.0:0")
-        .expect("valid zone server address");
-    client.open_connection(ClientConnectionConfiguration {
-        addr_config,
-        cert_mode: CertificateVerificationMode::SkipVerification,
-        defaultables: ClientConnectionConfigurationDefaultables {
-            send_channels_cfg: channels::send_channels_config(),
-            ..Default::default()
-        },
-    })
+    server: SocketAddr,
+) -> Result<ConnectionLocalId, NetworkError> {
+    connection::open(client, server)
 }
 
 impl ZoneSpawn {
@@ -168,6 +178,28 @@ mod tests {
         assert!(!state.player_ready_signal);
     }
 
+    #[test]
+    fn resume_connecting_keeps_session_and_player_latch() {
+        let mut state = QuicZoneState {
+            phase: ZonePhase::Reconnecting,
+            auth: ZoneAuth {
+                char_id: 4,
+                ..Default::default()
+            },
+            map_name: "prontera".into(),
+            map_loaded_signal: true,
+            player_ready_signal: true,
+            ..Default::default()
+        };
+        state.resume_connecting();
+        assert_eq!(state.phase, ZonePhase::Connecting);
+        assert_eq!(state.connection_epoch, 1);
+        assert_eq!(state.auth.char_id, 4);
+        assert_eq!(state.map_name, "prontera");
+        assert!(!state.map_loaded_signal);
+        assert!(state.player_ready_signal);
+    }
+
     #[test]
     fn spawn_from_enter_ack_copies_cell() {
         let spawn = ZoneSpawn::from_enter_ack(&net::EnterAck {
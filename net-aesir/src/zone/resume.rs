@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::auto_add_system;
+use bevy_quinnet::client::QuinnetClient;
+use net_contract::events::{ZoneReconnecting, ZoneResumeFailed};
+
+use super::{QuicZoneState, ZonePhase};
+
+/// Resume attempts made after a mid-game drop before the session is given up.
+pub const MAX_RESUME_ATTEMPTS: u32 = 5;
+
+/// Backoff before the first attempt; doubled for each later one.
+const BASE_RESUME_DELAY: Duration = Duration::from_secs(1);
+
+/// Upper bound on the backoff between attempts.
+const MAX_RESUME_DELAY: Duration = Duration::from_secs(16);
+
+/// An in-progress resume of a dropped zone session.
+#[derive(Debug, Clone)]
+pub struct ZoneResume {
+    /// 1-based attempt number currently waiting or in flight.
+    pub attempt: u32,
+    /// Backoff before `attempt` opens its connection.
+    pub backoff: Timer,
+}
+
+impl ZoneResume {
+    /// Attempt `attempt`, opening its connection once `delay` has elapsed.
+    pub fn new(attempt: u32, delay: Duration) -> Self {
+        Self {
+            attempt,
+            backoff: Timer::new(delay, TimerMode::Once),
+        }
+    }
+}
+
+/// Pure outcome of a zone connection failing or dropping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LossOutcome {
+    /// Not a resumable session: fail it as before.
+    Fail,
+    /// Schedule resume `attempt` after `delay`; `first` when the drop just happened.
+    Resume {
+        attempt: u32,
+        delay: Duration,
+        first: bool,
+    },
+    /// Every resume attempt has been used up.
+    GiveUp,
+}
+
+/// Exponential backoff before resume `attempt` (1-based), or `None` once the
+/// attempts are exhausted.
+pub(crate) fn resume_delay(attempt: u32) -> Option<Duration> {
+    if attempt == 0 || attempt > MAX_RESUME_ATTEMPTS {
+        return None;
+    }
+    let delay = BASE_RESUME_DELAY.saturating_mul(1 << (attempt - 1));
+    Some(delay.min(MAX_RESUME_DELAY))
+}
+
+/// Decide how to react to a lost/failed connection. Only a session that was
+/// `Playing` (or is already being resumed) is worth resuming; a drop during the
+/// initial handshake fails as before, and a resume the server already refused
+/// (`Failed`) is not retried.
+pub(crate) fn on_connection_loss(phase: ZonePhase, resume_attempt: Option<u32>) -> LossOutcome {
+    let (attempt, first) = match resume_attempt {
+        Some(_) if phase == ZonePhase::Failed => return LossOutcome::GiveUp,
+        Some(attempt) => (attempt + 1, false),
+        None if phase == ZonePhase::Playing => (1, true),
+        None => return LossOutcome::Fail,
+    };
+    match resume_delay(attempt) {
+        Some(delay) => LossOutcome::Resume {
+            attempt,
+            delay,
+            first,
+        },
+        None => LossOutcome::GiveUp,
+    }
+}
+
+/// Once the backoff for the pending attempt elapses, reopen the zone connection
+/// and re-arm the handshake with the stored session credentials.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
+pub fn zone_drive_resume(
+    time: Res<Time>,
+    mut client: ResMut<QuinnetClient>,
+    mut state: ResMut<QuicZoneState>,
+    mut reconnecting: MessageWriter<ZoneReconnecting>,
+) {
+    if state.phase != ZonePhase::Reconnecting {
+        return;
+    }
+    let Some(resume) = state.resume.as_mut() else {
+        return;
+    };
+    if !resume.backoff.tick(time.delta()).just_finished() {
+        return;
+    }
+    let attempt = resume.attempt;
+
+    info!("resuming zone session: attempt {attempt}/{MAX_RESUME_ATTEMPTS}");
+    reconnecting.write(ZoneReconnecting {
+        attempt,
+        max_attempts: MAX_RESUME_ATTEMPTS,
+    });
+
+    let Some(server) = state.server else {
+        warn!("zone resume attempt {attempt} has no resolved address");
+        schedule_next_or_fail(&mut state);
+        return;
+    };
+    if let Err(e) = super::connect(&mut client, server) {
+        warn!("zone resume attempt {attempt} failed to connect: {e}");
+        schedule_next_or_fail(&mut state);
+        return;
+    }
+    state.resume_connecting();
+}
+
+/// Queue the attempt after the current one, or fail the session when none remain.
+fn schedule_next_or_fail(state: &mut QuicZoneState) {
+    let attempt = state.resume.as_ref().map(|r| r.attempt);
+    match on_connection_loss(state.phase, attempt) {
+        LossOutcome::Resume { attempt, delay, .. } => {
+            state.resume = Some(ZoneResume::new(attempt, delay));
+            state.phase = ZonePhase::Reconnecting;
+        }
+        LossOutcome::GiveUp | LossOutcome::Fail => state.phase = ZonePhase::Failed,
+    }
+}
+
+/// A resume ends in `Failed` either when the attempts run out or when the zone
+/// server refuses the handshake (e.g. the session already expired server-side).
+/// Surface that once and park the session so late connection events are ignored.
+#[auto_add_system(plugin = crate::AesirNetPlugin, schedule = Update)]
+pub fn zone_report_resume_failure(
+    mut state: ResMut<QuicZoneState>,
+    mut failed: MessageWriter<ZoneResumeFailed>,
+) {
+    if state.phase != ZonePhase::Failed || state.resume.is_none() {
+        return;
+    }
+    let attempts = state.resume.take().map_or(0, |r| r.attempt);
+    error!("zone session could not be resumed after {attempts} attempt(s)");
+    failed.write(ZoneResumeFailed {
+        reason: format!("could not resume the session after {attempts} attempt(s)"),
+    });
+    state.phase = ZonePhase::Disconnected;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume_delay_doubles_and_caps() {
+        assert_eq!(resume_delay(0), None);
+        assert_eq!(resume_delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(resume_delay(2), Some(Duration::from_secs(2)));
+        assert_eq!(resume_delay(4), Some(Duration::from_secs(8)));
+        assert_eq!(resume_delay(5), Some(Duration::from_secs(16)));
+        assert_eq!(resume_delay(MAX_RESUME_ATTEMPTS + 1), None);
+    }
+
+    #[test]
+    fn drop_while_playing_starts_resume() {
+        assert_eq!(
+            on_connection_loss(ZonePhase::Playing, None),
+            LossOutcome::Resume {
+                attempt: 1,
+                delay: Duration::from_secs(1),
+                first: true,
+            }
+        );
+    }
+
+    #[test]
+    fn drop_during_initial_handshake_fails() {
+        assert_eq!(
+            on_connection_loss(ZonePhase::HelloSent, None),
+            LossOutcome::Fail
+        );
+        assert_eq!(
+            on_connection_loss(ZonePhase::Connecting, None),
+            LossOutcome::Fail
+        );
+    }
+
+    #[test]
+    fn failed_resume_attempt_backs_off_then_gives_up() {
+        assert_eq!(
+            on_connection_loss(ZonePhase::Connecting, Some(1)),
+            LossOutcome::Resume {
+                attempt: 2,
+                delay: Duration::from_secs(2),
+                first: false,
+            }
+        );
+        assert_eq!(
+            on_connection_loss(ZonePhase::Connecting, Some(MAX_RESUME_ATTEMPTS)),
+            LossOutcome::GiveUp
+        );
+        assert_eq!(
+            on_connection_loss(ZonePhase::Failed, Some(1)),
+            LossOutcome::GiveUp
+        );
+    }
+
+    #[test]
+    fn refused_resume_is_reported_once_and_parks_the_session() {
+        let mut app = App::new();
+        app.add_message::<ZoneResumeFailed>()
+            .insert_resource(QuicZoneState {
+                phase: ZonePhase::Failed,
+                resume: Some(ZoneResume::new(2, Duration::ZERO)),
+                ..Default::default()
+            })
+            .add_systems(Update, zone_report_resume_failure);
+
+        app.update();
+        app.update();
+
+        let state = app.world().resource::<QuicZoneState>();
+        assert_eq!(state.phase, ZonePhase::Disconnected);
+        assert!(state.resume.is_none());
+        assert_eq!(
+            app.world_mut()
+                .resource_mut::<Messages<ZoneResumeFailed>>()
+                .drain()
+                .count(),
+            1
+        );
+    }
+}
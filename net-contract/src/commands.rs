@@ -1,8 +1,9 @@
 //! Outbound command Messages (client to server).
 
-use crate::dto::{BuyEntry, NpcResponse, SellEntry};
+use crate::dto::{BuyEntry, NetworkConditions, NpcResponse, SellEntry};
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_message;
+use std::time::Duration;
 
 /// Request to move the local player to a destination cell.
 #[derive(Message, Debug, Clone)]
@@ -136,6 +137,14 @@ pub struct DeleteCharacter {
     pub char_id: u32,
 }
 
+/// Request to rename the character identified by `char_id` (consumes a rename ticket).
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct RenameCharacter {
+    pub char_id: u32,
+    pub new_name: String,
+}
+
 /// Request a fresh character list from the char server.
 #[derive(Message, Debug, Clone)]
 #[auto_add_message(plugin = crate::NetContractPlugin)]
@@ -145,11 +154,14 @@ pub struct RefreshCharacterList;
 #[derive(Message, Debug, Clone)]
 #[auto_add_message(plugin = crate::NetContractPlugin)]
 pub struct ConnectLogin {
+    /// `host:port`; the host may be a name, an IPv4 or a bracketed IPv6 literal.
     pub address: String,
     pub username: String,
     pub password: String,
     pub client_version: u32,
     pub build: String,
+    /// How long the connection may take to open before the attempt fails.
+    pub connect_timeout: Duration,
 }
 
 /// Request to open the char-server connection and begin the char-session handshake.
@@ -182,6 +194,13 @@ pub struct ConnectZone {
 #[auto_add_message(plugin = crate::NetContractPlugin)]
 pub struct LeaveZone;
 
+/// Request to close every adapter connection and forget the account session
+/// (return to the login screen). Unlike `LeaveZone` this also ends the login and
+/// char sessions, so nothing from the old account survives into the next login.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct EndSession;
+
 /// Domain to adapter readiness signal: the local map asset finished loading.
 #[derive(Message, Debug, Clone)]
 #[auto_add_message(plugin = crate::NetContractPlugin)]
@@ -380,3 +399,9 @@ pub struct GuildEmblemFetchRequested {
     pub guild_id: u32,
     pub emblem_id: u32,
 }
+
+/// Switch the development network simulation on with these conditions, or off
+/// with `None`. Ignored outside debug builds.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct SimulateNetwork(pub Option<NetworkConditions>);
@@ -0,0 +1,100 @@
+//! Diagnostic paths the network adapter publishes to bevy's
+//! `DiagnosticsStore`, named here so the engine's overlay can read them
+//! without depending on the adapter, and the packet trail the crash report
+//! includes.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use bevy::diagnostic::DiagnosticPath;
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+/// Datagrams received per second, over every open connection.
+pub const NETWORK_RX_PACKETS: DiagnosticPath = DiagnosticPath::const_new("network/rx_packets");
+/// Datagrams sent per second, over every open connection.
+pub const NETWORK_TX_PACKETS: DiagnosticPath = DiagnosticPath::const_new("network/tx_packets");
+
+/// Prefix of the per-connection round-trip diagnostics.
+pub const CONNECTION_RTT_PREFIX: &str = "network/connections";
+
+/// `network/connections/<id>/rtt`, in milliseconds. A closed connection's
+/// diagnostic stays registered with its history cleared.
+pub fn connection_rtt_path(connection: u64) -> DiagnosticPath {
+    DiagnosticPath::from_components(["network", "connections", &connection.to_string(), "rtt"])
+}
+
+/// Packets kept in [`RecentPackets`].
+pub const RECENT_PACKET_CAPACITY: usize = 50;
+
+/// One frame as the crash report lists it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketTrace {
+    pub outbound: bool,
+    pub channel: u8,
+    /// The body's protobuf field number, the aesir counterpart of an opcode.
+    pub tag: Option<u32>,
+}
+
+/// `out channel 0 tag 12`; `in ` is padded to line up with `out`.
+impl fmt::Display for PacketTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = if self.outbound { "out" } else { "in " };
+        write!(f, "{direction} channel {} tag ", self.channel)?;
+        match self.tag {
+            Some(tag) => write!(f, "{tag}"),
+            None => f.write_str("?"),
+        }
+    }
+}
+
+/// The last [`RECENT_PACKET_CAPACITY`] frames sent or received, oldest first.
+/// The adapter fills it; the crash reporter reads it from the panicking
+/// thread, hence the shared lock.
+#[derive(Resource, Clone, Default)]
+#[auto_init_resource(plugin = crate::NetContractPlugin)]
+pub struct RecentPackets(Arc<Mutex<VecDeque<PacketTrace>>>);
+
+impl RecentPackets {
+    pub fn record(&self, packet: PacketTrace) {
+        let Ok(mut packets) = self.0.lock() else {
+            return;
+        };
+        if packets.len() == RECENT_PACKET_CAPACITY {
+            packets.pop_front();
+        }
+        packets.push_back(packet);
+    }
+
+    pub fn snapshot(&self) -> Vec<PacketTrace> {
+        // A panic while the lock was held still leaves readable traces.
+        let packets = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        packets.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_packets_keep_the_newest() {
+        let packets = RecentPackets::default();
+        for tag in 0..RECENT_PACKET_CAPACITY as u32 + 5 {
+            packets.record(PacketTrace {
+                outbound: false,
+                channel: 0,
+                tag: Some(tag),
+            });
+        }
+
+        let snapshot = packets.snapshot();
+        assert_eq!(snapshot.len(), RECENT_PACKET_CAPACITY);
+        assert_eq!(snapshot[0].tag, Some(5));
+        assert_eq!(snapshot.last().unwrap().tag, Some(54));
+    }
+}
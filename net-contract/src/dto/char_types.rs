@@ -144,6 +144,56 @@ impl CharDeletionError {
     }
 }
 
+/// Character rename error codes (HC_ACK_CHANGE_CHARNAME result codes)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CharRenameError {
+    /// Character has already used its rename
+    AlreadyRenamed,
+    /// Account/character information did not match
+    InvalidRequest,
+    /// Server failed to apply the rename
+    Failed,
+    /// Requested name is already taken
+    NameExists,
+    /// Character is a guild member
+    InGuild,
+    /// Character is a party member
+    InParty,
+    /// The active adapter's protocol has no rename message
+    Unsupported,
+    /// Other error
+    Unknown(u32),
+}
+
+impl From<u32> for CharRenameError {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => CharRenameError::AlreadyRenamed,
+            2 => CharRenameError::InvalidRequest,
+            3 => CharRenameError::Failed,
+            4 => CharRenameError::NameExists,
+            5 => CharRenameError::InGuild,
+            6 => CharRenameError::InParty,
+            other => CharRenameError::Unknown(other),
+        }
+    }
+}
+
+impl CharRenameError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            CharRenameError::AlreadyRenamed => "Character has already been renamed",
+            CharRenameError::InvalidRequest => "Invalid rename request",
+            CharRenameError::Failed => "Failed to rename character",
+            CharRenameError::NameExists => "Character name already exists",
+            CharRenameError::InGuild => "Leave your guild before renaming",
+            CharRenameError::InParty => "Leave your party before renaming",
+            CharRenameError::Unsupported => "Character rename is not supported by this server",
+            CharRenameError::Unknown(_) => "Unknown error",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,6 +213,13 @@ mod tests {
         assert_eq!(CharDeletionError::from(2), CharDeletionError::NotFound);
     }
 
+    #[test]
+    fn char_rename_error_from_code() {
+        assert_eq!(CharRenameError::from(4), CharRenameError::NameExists);
+        assert_eq!(CharRenameError::from(6), CharRenameError::InParty);
+        assert_eq!(CharRenameError::from(42), CharRenameError::Unknown(42));
+    }
+
     #[test]
     fn zone_server_info_ip_string() {
         let info = ZoneServerInfo {
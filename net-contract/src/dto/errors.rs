@@ -7,6 +7,9 @@ pub enum NetworkError {
     #[error("Connection failed: {0}")]
     ConnectionFailed(String),
 
+    #[error("Invalid server address `{address}`: {reason}")]
+    InvalidAddress { address: String, reason: String },
+
     #[error("Authentication failed: {reason}")]
     AuthenticationFailed { reason: String },
 
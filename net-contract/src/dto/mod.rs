@@ -4,6 +4,7 @@ mod cart;
 mod char_types;
 mod errors;
 mod guild;
+mod network_conditions;
 mod npc;
 mod party;
 mod server_info;
@@ -15,6 +16,7 @@ pub use cart::*;
 pub use char_types::*;
 pub use errors::*;
 pub use guild::*;
+pub use network_conditions::*;
 pub use npc::*;
 pub use party::*;
 pub use server_info::*;
@@ -0,0 +1,85 @@
+/// How bad a simulated link is, for development builds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkConditions {
+    /// Added delay for every inbound frame, in milliseconds.
+    pub latency_ms: u32,
+    /// Up to this many milliseconds more or less than `latency_ms`.
+    pub jitter_ms: u32,
+    /// Fraction of frames lost, 0.0..=1.0.
+    pub loss: f32,
+    /// Link throughput in kilobits per second; frames queue behind each
+    /// other while the link is busy. Zero means unlimited.
+    pub bandwidth_kbps: u32,
+    /// Deliver frames only on multiples of this many milliseconds, so they
+    /// arrive in bursts. Zero delivers each frame as soon as it is due.
+    pub batch_ms: u32,
+}
+
+impl NetworkConditions {
+    /// Parse `latency=200,jitter=50,loss=0.05,bandwidth=256,batch=100`.
+    /// Missing keys are zero.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut conditions = Self {
+            latency_ms: 0,
+            jitter_ms: 0,
+            loss: 0.0,
+            bandwidth_kbps: 0,
+            batch_ms: 0,
+        };
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected key=value, got `{pair}`"))?;
+            let value = value.trim();
+            let bad_value = || format!("bad value for `{key}`: `{value}`");
+            match key.trim() {
+                "latency" => conditions.latency_ms = value.parse().map_err(|_| bad_value())?,
+                "jitter" => conditions.jitter_ms = value.parse().map_err(|_| bad_value())?,
+                "bandwidth" => {
+                    conditions.bandwidth_kbps = value.parse().map_err(|_| bad_value())?
+                }
+                "batch" => conditions.batch_ms = value.parse().map_err(|_| bad_value())?,
+                "loss" => {
+                    let loss: f32 = value.parse().map_err(|_| bad_value())?;
+                    if !(0.0..=1.0).contains(&loss) {
+                        return Err(format!("loss must be between 0 and 1, got {loss}"));
+                    }
+                    conditions.loss = loss;
+                }
+                other => return Err(format!("unknown key `{other}`")),
+            }
+        }
+        Ok(conditions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conditions(latency_ms: u32, jitter_ms: u32, loss: f32) -> NetworkConditions {
+        NetworkConditions {
+            latency_ms,
+            jitter_ms,
+            loss,
+            bandwidth_kbps: 0,
+            batch_ms: 0,
+        }
+    }
+
+    #[test]
+    fn parses_the_spec() {
+        assert_eq!(
+            NetworkConditions::parse("latency=200, jitter=50,loss=0.05"),
+            Ok(conditions(200, 50, 0.05))
+        );
+        assert_eq!(NetworkConditions::parse(""), Ok(conditions(0, 0, 0.0)));
+        assert!(NetworkConditions::parse("latency").is_err());
+        assert!(NetworkConditions::parse("loss=2").is_err());
+        assert!(NetworkConditions::parse("speed=9").is_err());
+
+        let shaped = NetworkConditions::parse("bandwidth=256,batch=100").unwrap();
+        assert_eq!((shaped.bandwidth_kbps, shaped.batch_ms), (256, 100));
+        assert!(NetworkConditions::parse("batch=-1").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-//! Protocol-neutral NPC dialogue types.
+//! Protocol-neutral NPC dialogue and cutin types.
 
 /// What kind of response the server expects for the current NPC dialogue frame.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,3 +20,13 @@ pub enum NpcResponse {
     Input(String),
     Cancel,
 }
+
+/// Where a script cutin illustration is drawn, or `Clear` to remove it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CutinPosition {
+    BottomLeft,
+    BottomMiddle,
+    BottomRight,
+    Center,
+    Clear,
+}
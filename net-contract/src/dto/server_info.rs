@@ -51,6 +51,11 @@ impl ServerInfo {
         let bytes = self.ip.to_be_bytes();
         format!("{}.{}.{}.{}", bytes[0], bytes[1], bytes[2], bytes[3])
     }
+
+    /// `ip:port`, as `ConnectCharServer` expects it.
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.ip_string(), self.port)
+    }
 }
 
 #[cfg(test)]
@@ -77,5 +82,6 @@ mod tests {
             new_server: 0,
         };
         assert_eq!(info.ip_string(), "127.0.0.1");
+        assert_eq!(info.address(), "127.0.0.1:6900");
     }
 }
@@ -1,5 +1,6 @@
 use crate::dto::{
-    CharCreationError, CharDeletionError, CharacterInfo, CharacterSlotInfo, ZoneServerInfo,
+    CharCreationError, CharDeletionError, CharRenameError, CharacterInfo, CharacterSlotInfo,
+    ZoneServerInfo,
 };
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_message;
@@ -59,3 +60,19 @@ pub struct CharacterDeletionFailed {
     pub char_id: u32,
     pub error: CharDeletionError,
 }
+
+/// Event emitted when a character rename succeeds
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct CharacterRenamed {
+    pub char_id: u32,
+    pub new_name: String,
+}
+
+/// Event emitted when a character rename fails
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct CharacterRenameFailed {
+    pub char_id: u32,
+    pub error: CharRenameError,
+}
@@ -1,4 +1,4 @@
-use crate::dto::ServerInfo;
+use crate::dto::{NetworkError, ServerInfo};
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_message;
 
@@ -15,6 +15,16 @@ pub struct LoginAccepted {
     pub auth_token: String,
 }
 
+/// The login connection could not be opened or dropped before the server
+/// answered: a bad address, an unreachable host, a connect timeout. Server-sent
+/// refusals arrive as [`LoginRefused`] instead.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct LoginConnectionFailed {
+    pub username: String,
+    pub error: NetworkError,
+}
+
 /// Event emitted when login is refused
 #[derive(Message, Debug, Clone)]
 #[auto_add_message(plugin = crate::NetContractPlugin)]
@@ -24,3 +34,167 @@ pub struct LoginRefused {
     pub error_message: String,
     pub block_date: Option<String>,
 }
+
+impl LoginRefused {
+    /// RO's "rejected from server" code, used for failures the client detects
+    /// itself (handshake, connection, malformed response) rather than a
+    /// refusal reason the server sent.
+    pub const REJECTED_FROM_SERVER: u8 = 3;
+
+    pub fn reason(&self) -> LoginRefusalReason {
+        LoginRefusalReason::from_code(self.error_code)
+    }
+
+    /// The server's own message, else the reference client's text for the
+    /// code.
+    pub fn description(&self) -> String {
+        if !self.error_message.is_empty() {
+            return self.error_message.clone();
+        }
+        match (self.reason(), &self.block_date) {
+            (LoginRefusalReason::BlockedUntil, Some(date)) => {
+                format!("This account is prohibited from logging in until {date}.")
+            }
+            (reason, _) => reason.description().to_string(),
+        }
+    }
+}
+
+/// `LoginRefused::error_code`, typed. The codes are the reference client's
+/// login refusal codes, which aesir keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginRefusalReason {
+    UnregisteredId,
+    IncorrectPassword,
+    IdExpired,
+    RejectedFromServer,
+    BlockedByGm,
+    OutdatedClient,
+    BlockedUntil,
+    ServerFull,
+    CompanyLimit,
+    Banned,
+    EmailNotConfirmed,
+    Maintenance,
+    SelfLocked,
+    GroupNotPermitted,
+    IdErased,
+    /// The previous session hasn't been logged out yet.
+    SessionRemains,
+    UnderInvestigation,
+    CharacterBeingDeleted,
+    Other(u8),
+}
+
+impl LoginRefusalReason {
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0 => Self::UnregisteredId,
+            1 => Self::IncorrectPassword,
+            2 => Self::IdExpired,
+            3 => Self::RejectedFromServer,
+            4 => Self::BlockedByGm,
+            5 => Self::OutdatedClient,
+            6 => Self::BlockedUntil,
+            7 => Self::ServerFull,
+            8 => Self::CompanyLimit,
+            9 | 11 => Self::Banned,
+            10 => Self::EmailNotConfirmed,
+            12 => Self::Maintenance,
+            13 => Self::SelfLocked,
+            14 | 15 => Self::GroupNotPermitted,
+            99 => Self::IdErased,
+            100 => Self::SessionRemains,
+            101 | 102 => Self::UnderInvestigation,
+            103 | 104 => Self::CharacterBeingDeleted,
+            other => Self::Other(other),
+        }
+    }
+
+    /// Refusals that clear up on their own, so the login is worth retrying
+    /// after a pause.
+    pub fn is_transient(self) -> bool {
+        matches!(
+            self,
+            Self::ServerFull | Self::Maintenance | Self::SessionRemains
+        )
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::UnregisteredId => "This account does not exist.",
+            Self::IncorrectPassword => "Incorrect password.",
+            Self::IdExpired => "This account has expired.",
+            Self::RejectedFromServer => "Rejected from the server.",
+            Self::BlockedByGm => "This account has been blocked by the GM team.",
+            Self::OutdatedClient => "This client version is not supported by the server.",
+            Self::BlockedUntil => "This account is temporarily prohibited from logging in.",
+            Self::ServerFull => "The server is full.",
+            Self::CompanyLimit => "No more accounts may connect from this location.",
+            Self::Banned => "This account has been banned.",
+            Self::EmailNotConfirmed => "This account's e-mail address is not confirmed yet.",
+            Self::Maintenance => "The server is under maintenance.",
+            Self::SelfLocked => "This account has been locked by its owner.",
+            Self::GroupNotPermitted => "This account is not allowed to log in to this server.",
+            Self::IdErased => "This account has been deleted.",
+            Self::SessionRemains => "The previous session is still being logged out.",
+            Self::UnderInvestigation => "This account is locked pending an investigation.",
+            Self::CharacterBeingDeleted => {
+                "A character on this account is being deleted; try again later."
+            }
+            Self::Other(_) => "The server refused the login.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refused(error_code: u8, error_message: &str, block_date: Option<&str>) -> LoginRefused {
+        LoginRefused {
+            username: "player".to_string(),
+            error_code,
+            error_message: error_message.to_string(),
+            block_date: block_date.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn codes_map_to_typed_reasons() {
+        assert_eq!(
+            refused(1, "", None).reason(),
+            LoginRefusalReason::IncorrectPassword
+        );
+        assert_eq!(
+            refused(7, "", None).reason(),
+            LoginRefusalReason::ServerFull
+        );
+        assert_eq!(
+            refused(101, "", None).reason(),
+            LoginRefusalReason::UnderInvestigation
+        );
+        assert_eq!(
+            refused(200, "", None).reason(),
+            LoginRefusalReason::Other(200)
+        );
+        assert!(LoginRefusalReason::ServerFull.is_transient());
+        assert!(!LoginRefusalReason::IncorrectPassword.is_transient());
+    }
+
+    #[test]
+    fn description_prefers_the_server_message() {
+        assert_eq!(
+            refused(12, "Back at 18:00", None).description(),
+            "Back at 18:00"
+        );
+        assert_eq!(
+            refused(12, "", None).description(),
+            "The server is under maintenance."
+        );
+        assert_eq!(
+            refused(6, "", Some("2026-11-01 12:00")).description(),
+            "This account is prohibited from logging in until 2026-11-01 12:00."
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::dto::NpcDialogExpect;
+use crate::dto::{CutinPosition, NpcDialogExpect};
 use bevy::prelude::*;
 use bevy_auto_plugin::prelude::auto_add_message;
 
@@ -11,3 +11,12 @@ pub struct NpcDialogReceived {
     pub expect: NpcDialogExpect,
     pub options: Vec<String>,
 }
+
+/// A script cutin: show the illustration `image` (a bare bitmap name) at
+/// `position`, replacing the current one, or remove it when `position` is `Clear`.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct NpcCutinReceived {
+    pub image: String,
+    pub position: CutinPosition,
+}
@@ -61,6 +61,7 @@ pub struct UnitEntered {
     pub body_state: u32,
     pub health_state: u32,
     pub effect_state: u32,
+    pub virtue: u32,
     pub head: u32,
     pub weapon: u32,
     pub shield: u32,
@@ -134,7 +135,6 @@ pub struct DamageReceived {
 }
 
 /// An entity's HP changed.
-// NOTE: no client consumer yet; kept for future implementation.
 #[derive(Message, Debug, Clone)]
 #[auto_add_message(plugin = crate::NetContractPlugin)]
 pub struct UnitHpChanged {
@@ -529,6 +529,35 @@ pub struct ZoneDisconnected {
     pub reason: String,
 }
 
+/// The zone connection dropped mid-game; the adapter will try to resume the session.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct ZoneConnectionLost {
+    pub reason: String,
+}
+
+/// A zone resume attempt is starting (1-based `attempt` out of `max_attempts`).
+#[derive(Message, Debug, Clone, Copy)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct ZoneReconnecting {
+    pub attempt: u32,
+    pub max_attempts: u32,
+}
+
+/// The zone session was resumed. The map is re-entered through the regular
+/// `MapChangeRequested` warp path, which the adapter emits alongside this.
+#[derive(Message, Debug, Clone, Copy)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct ZoneReconnected;
+
+/// Every resume attempt failed or the zone server refused the resume; the
+/// session is gone and the client should fall back to character selection.
+#[derive(Message, Debug, Clone)]
+#[auto_add_message(plugin = crate::NetContractPlugin)]
+pub struct ZoneResumeFailed {
+    pub reason: String,
+}
+
 /// One entity's authoritative position/state within a snapshot.
 #[derive(Debug, Clone, Copy)]
 pub struct ZoneSnapshotEntity {
@@ -1,6 +1,7 @@
 use bevy_auto_plugin::prelude::*;
 
 pub mod commands;
+pub mod diagnostics;
 pub mod dto;
 pub mod events;
 pub mod state;
@@ -31,10 +32,30 @@ mod tests {
             app.world()
                 .contains_resource::<Messages<events::LoginAccepted>>()
         );
+        assert!(
+            app.world()
+                .contains_resource::<Messages<events::CharacterRenamed>>()
+        );
+        assert!(
+            app.world()
+                .contains_resource::<Messages<commands::RenameCharacter>>()
+        );
+        assert!(
+            app.world()
+                .contains_resource::<Messages<events::ZoneReconnecting>>()
+        );
+        assert!(
+            app.world()
+                .contains_resource::<Messages<events::ZoneResumeFailed>>()
+        );
         assert!(
             app.world()
                 .contains_resource::<Messages<events::NpcDialogReceived>>()
         );
+        assert!(
+            app.world()
+                .contains_resource::<Messages<events::NpcCutinReceived>>()
+        );
         assert!(
             app.world()
                 .contains_resource::<Messages<commands::TalkToNpc>>()
@@ -250,10 +250,14 @@ pub fn parse_act(data: &[u8]) -> Result<RoAction, ActError> {
         (remaining, Vec::new())
     };
 
-    if version >= 2.2 && !input.is_empty() {
-        for (i, action) in actions.iter_mut().enumerate() {
-            if let Ok((_, delay)) = le_f32::<&[u8], NomError<&[u8]>>(&input[i * 4..]) {
-                action.delay = delay * 25.0;
+    // v2.2+ ends with one frame interval per action, in 25 ms ticks. A file
+    // cut short keeps the default for the actions whose interval is missing,
+    // and so does an unusable (zero, negative or NaN) interval.
+    if version >= 2.2 {
+        for (action, bytes) in actions.iter_mut().zip(input.chunks_exact(4)) {
+            let delay = f32::from_le_bytes(bytes.try_into().unwrap()) * 25.0;
+            if delay.is_finite() && delay > 0.0 {
+                action.delay = delay;
             }
         }
     }
@@ -283,4 +287,22 @@ mod tests {
         let (_, sounds) = parse_sounds(&data).expect("parse sounds");
         assert_eq!(sounds, vec![name.to_string()]);
     }
+
+    #[test]
+    fn action_intervals_default_when_missing_or_unusable() {
+        let mut data = b"AC\x02\x02".to_vec();
+        data.extend_from_slice(&4u16.to_le_bytes());
+        data.extend_from_slice(&[0; 10]);
+        for _ in 0..4 {
+            data.extend_from_slice(&0u32.to_le_bytes()); // no frames
+        }
+        data.extend_from_slice(&0u32.to_le_bytes()); // no sounds
+        for ticks in [4.0f32, 0.0, f32::NAN] {
+            data.extend_from_slice(&ticks.to_le_bytes());
+        }
+
+        let action = parse_act(&data).expect("parse act");
+        let delays: Vec<f32> = action.actions.iter().map(|a| a.delay).collect();
+        assert_eq!(delays, [100.0, 150.0, 150.0, 150.0]);
+    }
 }
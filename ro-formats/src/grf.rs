@@ -67,6 +67,15 @@ pub struct GrfEntry {
     pub offset: u64,
 }
 
+impl GrfEntry {
+    /// Where the entry's stored (compressed, possibly encrypted) bytes sit in
+    /// the archive file.
+    pub fn data_range(&self) -> std::ops::Range<u64> {
+        let start = self.offset + HEADER_SIZE;
+        start..start + self.length_aligned as u64
+    }
+}
+
 #[derive(Debug)]
 pub struct GrfFile {
     pub entries: Vec<GrfEntry>,
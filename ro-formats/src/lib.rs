@@ -18,6 +18,7 @@ pub use rsm::*;
 pub use rsw::*;
 pub use sprite::*;
 pub use str::*;
+pub use string_utils::decode_euc_kr_mojibake;
 
 /// World units per GAT/GND cell. Intrinsic to the format's cell-to-world scale.
 pub const CELL_SIZE: f32 = 10.0;
@@ -37,6 +37,75 @@ pub struct Palette {
     pub colors: Vec<[u8; 4]>, // RGBA
 }
 
+/// 256 colors * 4 bytes (RGB + reserved byte), embedded or as a `.pal` file.
+pub const PALETTE_BYTES: usize = 1024;
+
+/// Why a sprite's palette can't be used to draw its indexed frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteDefect {
+    /// Indexed frames but no palette at all (e.g. a 1.0 sprite, or a file
+    /// too short to hold one).
+    Missing,
+    /// Fewer than 256 colors; holds how many there are.
+    Truncated(usize),
+    /// Every opaque entry is the same color, so frames draw as a silhouette.
+    Flat,
+}
+
+impl Palette {
+    /// Read a 1024-byte palette, from a `.pal` file or the tail of an SPR.
+    /// The fourth byte of each entry is reserved, not alpha: index 0 is
+    /// transparent and every other color is fully opaque.
+    pub fn from_pal_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PALETTE_BYTES {
+            return None;
+        }
+        let colors = bytes
+            .chunks_exact(4)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let alpha = if i == 0 { 0 } else { 255 };
+                [chunk[0], chunk[1], chunk[2], alpha]
+            })
+            .collect();
+        Some(Self { colors })
+    }
+
+    /// A black-to-white ramp, so an indexed frame at least keeps its shading
+    /// when its real palette is unusable.
+    pub fn grayscale() -> Self {
+        let colors = (0..=255u8)
+            .map(|i| [i, i, i, if i == 0 { 0 } else { 255 }])
+            .collect();
+        Self { colors }
+    }
+
+    pub fn defect(&self) -> Option<PaletteDefect> {
+        if self.colors.len() < 256 {
+            return Some(PaletteDefect::Truncated(self.colors.len()));
+        }
+        let opaque = &self.colors[1..];
+        let first = opaque[0];
+        opaque
+            .iter()
+            .all(|color| color[..3] == first[..3])
+            .then_some(PaletteDefect::Flat)
+    }
+}
+
+impl RoSprite {
+    /// What's wrong with the palette, if the sprite needs one to draw.
+    pub fn palette_defect(&self) -> Option<PaletteDefect> {
+        if self.indexed_count == 0 {
+            return None;
+        }
+        match &self.palette {
+            Some(palette) => palette.defect(),
+            None => Some(PaletteDefect::Missing),
+        }
+    }
+}
+
 pub fn parse_spr(data: &[u8]) -> Result<RoSprite, SpriteError> {
     let (mut remaining_data, (version, indexed_count, rgba_count)) = parse_header(data)
         .map_err(|e| SpriteError::ParseError(format!("Header parse error: {e:?}")))?;
@@ -66,25 +135,8 @@ pub fn parse_spr(data: &[u8]) -> Result<RoSprite, SpriteError> {
     }
 
     // Parse palette from end of file if we have indexed frames and version > 1.0
-    let palette = if indexed_count > 0 && version > 1.0 {
-        if data.len() >= 1024 {
-            // 256 colors * 4 bytes (RGB + reserved byte)
-            let palette_data = &data[data.len() - 1024..];
-
-            let mut colors = Vec::with_capacity(256);
-            for (i, chunk) in palette_data.chunks(4).enumerate() {
-                if chunk.len() >= 4 {
-                    // RO palette format: RGB + reserved byte (not alpha)
-                    // Index 0 is reserved for transparency
-                    // All other colors should be fully opaque (alpha=255)
-                    let alpha = if i == 0 { 0 } else { 255 };
-                    colors.push([chunk[0], chunk[1], chunk[2], alpha]);
-                }
-            }
-            Some(Palette { colors })
-        } else {
-            None
-        }
+    let palette = if indexed_count > 0 && version > 1.0 && data.len() >= PALETTE_BYTES {
+        Palette::from_pal_bytes(&data[data.len() - PALETTE_BYTES..])
     } else {
         None
     };
@@ -223,4 +275,55 @@ mod tests {
         assert_eq!((frame.width, frame.height), (1, 2));
         assert_eq!(frame.data, vec![80, 70, 60, 50, 40, 30, 20, 10]);
     }
+
+    fn indexed_sprite(palette: Option<Palette>) -> RoSprite {
+        RoSprite {
+            version: 2.0,
+            indexed_count: 1,
+            rgba_count: 0,
+            frames: Vec::new(),
+            palette,
+        }
+    }
+
+    #[test]
+    fn palette_defects_are_detected() {
+        let mut ramp = vec![0u8; PALETTE_BYTES];
+        for (i, chunk) in ramp.chunks_exact_mut(4).enumerate() {
+            chunk[..3].copy_from_slice(&[i as u8, 0, 255 - i as u8]);
+        }
+        let good = Palette::from_pal_bytes(&ramp).unwrap();
+        assert_eq!(indexed_sprite(Some(good.clone())).palette_defect(), None);
+
+        let black = Palette::from_pal_bytes(&[0; PALETTE_BYTES]).unwrap();
+        assert_eq!(
+            indexed_sprite(Some(black)).palette_defect(),
+            Some(PaletteDefect::Flat)
+        );
+
+        let mut short = good;
+        short.colors.truncate(16);
+        assert_eq!(
+            indexed_sprite(Some(short)).palette_defect(),
+            Some(PaletteDefect::Truncated(16))
+        );
+
+        assert_eq!(
+            indexed_sprite(None).palette_defect(),
+            Some(PaletteDefect::Missing)
+        );
+
+        let mut rgba_only = indexed_sprite(None);
+        rgba_only.indexed_count = 0;
+        assert_eq!(rgba_only.palette_defect(), None);
+    }
+
+    #[test]
+    fn grayscale_palette_is_a_usable_ramp() {
+        let gray = Palette::grayscale();
+        assert_eq!(gray.defect(), None);
+        assert_eq!(gray.colors[0], [0, 0, 0, 0]);
+        assert_eq!(gray.colors[128], [128, 128, 128, 255]);
+        assert!(Palette::from_pal_bytes(&[0; 1000]).is_none());
+    }
 }
@@ -1,5 +1,6 @@
-use encoding_rs::EUC_KR;
+use encoding_rs::{EUC_KR, WINDOWS_1252};
 use nom::{IResult, bytes::complete::take};
+use std::borrow::Cow;
 
 pub fn parse_korean_string(input: &[u8], length: usize) -> IResult<&[u8], String> {
     let (input, bytes) = take(length)(input)?;
@@ -11,3 +12,42 @@ pub fn parse_korean_string(input: &[u8], length: usize) -> IResult<&[u8], String
 
     Ok((input, filename))
 }
+
+/// `name` with EUC-KR mojibake undone: Korean names whose EUC-KR bytes were
+/// read as Windows-1252, the way extraction tools often write them to disk,
+/// decoded back to Korean. Returned unchanged when it isn't mojibake: plain
+/// ASCII, real Unicode beyond Windows-1252, or bytes that don't form valid
+/// EUC-KR.
+pub fn decode_euc_kr_mojibake(name: &str) -> Cow<'_, str> {
+    if name.is_ascii() {
+        return Cow::Borrowed(name);
+    }
+    let (bytes, _, unmappable) = WINDOWS_1252.encode(name);
+    if unmappable {
+        return Cow::Borrowed(name);
+    }
+    match EUC_KR.decode_without_bom_handling_and_without_replacement(&bytes) {
+        Some(decoded) => Cow::Owned(decoded.into_owned()),
+        None => Cow::Borrowed(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mojibake_decodes_back_to_korean() {
+        let korean = "data\\sprite\\인간족\\몸통\\남\\초보자_남.spr";
+        let (euc_kr, _, _) = EUC_KR.encode(korean);
+        let (mojibake, _) = WINDOWS_1252.decode_without_bom_handling(&euc_kr);
+        assert_ne!(mojibake, korean);
+        assert_eq!(decode_euc_kr_mojibake(&mojibake), korean);
+
+        assert_eq!(decode_euc_kr_mojibake(korean), korean);
+        assert_eq!(
+            decode_euc_kr_mojibake("data\\wav\\Café.wav"),
+            "data\\wav\\Café.wav"
+        );
+    }
+}